@@ -7,13 +7,16 @@
 use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
 use embassy_sync::mutex::Mutex as AsyncMutex;
 use embassy_sync::pubsub::PubSubChannel;
+use embassy_time::Instant;
 use thiserror_no_std::Error;
 
 use crate::config::DeviceConfig;
+use crate::sensors::SensorCalibration;
 use crate::storage::{
     accumulator::{
         EVENT_CHANNEL_CAPACITY, EVENT_PUBLISHERS, EVENT_SUBSCRIBERS, RollupAccumulator, RollupEvent,
     },
+    backend::Backend,
     manager::StorageManager,
 };
 
@@ -34,10 +37,41 @@ pub enum AppRunState {
     WifiConnected,
     TimeSyncing,
     TimeKnown,
+    /// Time sync finished without NTP; timestamps are estimated or count from 0
+    TimeUnsynced,
     SensorsRunning,
     Error,
 }
 
+impl AppRunState {
+    /// State to leave `TimeSyncing` for once the time has been resolved
+    pub const fn after_time_sync(status: TimeStatus) -> Self {
+        match status {
+            TimeStatus::Synced => Self::TimeKnown,
+            TimeStatus::Estimated | TimeStatus::Unknown => Self::TimeUnsynced,
+        }
+    }
+}
+
+/// How far the wall-clock time can be trusted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeStatus {
+    /// No time source; timestamps count up from 0
+    #[default]
+    Unknown,
+    /// Seeded from the last time persisted to the SD card (NTP unavailable)
+    Estimated,
+    /// Synchronized with an NTP server
+    Synced,
+}
+
+impl TimeStatus {
+    /// Whether timestamps are roughly real Unix time (synced or estimated)
+    pub const fn is_known(self) -> bool {
+        !matches!(self, Self::Unknown)
+    }
+}
+
 /// Main application state container
 ///
 /// This struct holds all the major components and state of the application.
@@ -49,11 +83,18 @@ where
     T: embedded_sdmmc::TimeSource,
 {
     pub run_state: AppRunState,
-    pub time_known: bool,
+    pub time_status: TimeStatus,
     pub wifi_connected: bool,
+    /// Last polled WiFi signal strength in dBm (`None` until first poll)
+    pub wifi_rssi: Option<i8>,
     pub device_config: DeviceConfig,
+    /// Per-sensor calibration applied to readings before they are stored
+    pub calibration: SensorCalibration,
+    /// When the sensor task last stored a sample (`None` until the first),
+    /// watched by `sensor_watchdog`
+    pub last_sample_at: Option<Instant>,
     pub accumulator: Option<RollupAccumulator<'a>>,
-    pub storage_manager: Option<StorageManager<S, D, T>>,
+    pub storage_manager: Option<StorageManager<Backend<S, D, T>>>,
 }
 
 impl<'a, S, D, T> Default for AppState<'a, S, D, T>
@@ -77,9 +118,12 @@ where
     pub fn new() -> Self {
         Self {
             run_state: AppRunState::Uninitialized,
-            time_known: false,
+            time_status: TimeStatus::Unknown,
             wifi_connected: false,
+            wifi_rssi: None,
             device_config: DeviceConfig::default(),
+            calibration: SensorCalibration::identity(),
+            last_sample_at: None,
             accumulator: None,
             storage_manager: None,
         }
@@ -90,11 +134,14 @@ where
         let publisher = ROLLUP_CHANNEL
             .publisher()
             .expect("Failed to create publisher");
-        self.accumulator = Some(RollupAccumulator::new(publisher));
+        self.accumulator = Some(RollupAccumulator::new(
+            publisher,
+            self.device_config.rollup_interval_secs,
+        ));
     }
 
     /// Set the storage manager
-    pub fn set_storage_manager(&mut self, storage_manager: StorageManager<S, D, T>) {
+    pub fn set_storage_manager(&mut self, storage_manager: StorageManager<Backend<S, D, T>>) {
         self.storage_manager = Some(storage_manager);
     }
 
@@ -109,12 +156,12 @@ where
     }
 
     /// Get a reference to the storage manager
-    pub fn storage_manager(&self) -> Option<&StorageManager<S, D, T>> {
+    pub fn storage_manager(&self) -> Option<&StorageManager<Backend<S, D, T>>> {
         self.storage_manager.as_ref()
     }
 
     /// Get a mutable reference to the storage manager
-    pub fn storage_manager_mut(&mut self) -> Option<&mut StorageManager<S, D, T>> {
+    pub fn storage_manager_mut(&mut self) -> Option<&mut StorageManager<Backend<S, D, T>>> {
         self.storage_manager.as_mut()
     }
 }
@@ -142,6 +189,21 @@ pub enum TimeSyncError {
 
     #[error("NTP response too short: {len} bytes")]
     ResponseTooShort { len: usize },
+
+    #[error("NTP response has unexpected mode {mode}")]
+    UnexpectedMode { mode: u8 },
+
+    #[error("NTP server is not synchronized")]
+    ServerUnsynchronized,
+
+    #[error("NTP server sent a kiss-of-death response")]
+    KissOfDeath,
+
+    #[error("NTP response has a zero transmit timestamp")]
+    ZeroTransmitTimestamp,
+
+    #[error("NTP time {unix_time} is implausibly old")]
+    ImplausibleTime { unix_time: u32 },
 }
 
 /// Top-level application error type
@@ -162,3 +224,36 @@ pub enum AppError {
     #[error("Sensor error: {0}")]
     Sensor(#[from] crate::sensors::SensorError),
 }
+
+impl AppError {
+    /// Short hint telling the user what to try next
+    pub fn suggested_action(&self) -> &'static str {
+        match self {
+            AppError::Wifi(_) => "Check the Wi-Fi network and credentials, then restart.",
+            AppError::TimeSync(_) => "Check the internet connection. Time will be retried.",
+            AppError::Storage(_) => "Reseat the SD card and restart the device.",
+            AppError::Sensor(_) => "Check the sensor wiring and restart the device.",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_sync_ends_in_known_only_when_ntp_synced() {
+        assert_eq!(
+            AppRunState::after_time_sync(TimeStatus::Synced),
+            AppRunState::TimeKnown
+        );
+        assert_eq!(
+            AppRunState::after_time_sync(TimeStatus::Estimated),
+            AppRunState::TimeUnsynced
+        );
+        assert_eq!(
+            AppRunState::after_time_sync(TimeStatus::Unknown),
+            AppRunState::TimeUnsynced
+        );
+    }
+}
@@ -6,6 +6,51 @@
 use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
 use embassy_sync::mutex::Mutex;
 use embedded_hal_async::i2c::{ErrorType, I2c, Operation};
+use heapless::Vec;
+
+/// Lowest address probed by [`scan_i2c`] (0x00-0x07 are reserved)
+pub const I2C_SCAN_FIRST_ADDRESS: u8 = 0x08;
+
+/// Highest address probed by [`scan_i2c`] (0x78-0x7F are reserved)
+pub const I2C_SCAN_LAST_ADDRESS: u8 = 0x77;
+
+/// Probe every non-reserved 7-bit address and return the ones that ACK
+///
+/// Each address gets a zero-length write, which a present device
+/// acknowledges without acting on. Devices behind a multiplexer only
+/// answer while their channel is selected.
+///
+/// # Examples
+/// ```ignore
+/// let found = scan_i2c(&mut AsyncI2cDevice::new(i2c_bus)).await;
+/// info!("I2C devices: {:02X?}", found.as_slice());
+/// ```
+pub async fn scan_i2c<B: I2c>(bus: &mut B) -> Vec<u8, 128> {
+    let mut found = Vec::new();
+    for address in I2C_SCAN_FIRST_ADDRESS..=I2C_SCAN_LAST_ADDRESS {
+        if bus.write(address, &[]).await.is_ok() {
+            // Capacity covers every 7-bit address
+            let _ = found.push(address);
+        }
+    }
+    found
+}
+
+/// One step of a grouped bus sequence: an operation and the address it targets
+pub struct BusOperation<'a> {
+    /// 7-bit device address
+    pub address: u8,
+    /// Read or write to perform
+    pub operation: Operation<'a>,
+}
+
+impl<'a> BusOperation<'a> {
+    /// Create a step targeting `address`
+    #[inline]
+    pub fn new(address: u8, operation: Operation<'a>) -> Self {
+        Self { address, operation }
+    }
+}
 
 /// Async I2C bus sharing device using Embassy's async Mutex.
 ///
@@ -46,6 +91,25 @@ impl<'a, T> AsyncI2cDevice<'a, T> {
     }
 }
 
+impl<T> AsyncI2cDevice<'_, T>
+where
+    T: I2c,
+{
+    /// Runs a grouped sequence of operations while holding the bus lock once.
+    ///
+    /// Each step is issued as its own I2C transaction (so steps may target
+    /// different addresses), but no other device can use the bus between
+    /// steps. Stops at the first failing step.
+    pub async fn batch(&mut self, operations: &mut [BusOperation<'_>]) -> Result<(), T::Error> {
+        let mut bus = self.bus.lock().await;
+        for step in operations.iter_mut() {
+            bus.transaction(step.address, core::slice::from_mut(&mut step.operation))
+                .await?;
+        }
+        Ok(())
+    }
+}
+
 impl<T> ErrorType for AsyncI2cDevice<'_, T>
 where
     T: ErrorType,
@@ -110,3 +174,56 @@ where
 // Safety: AsyncI2cDevice can be sent across thread boundaries if the underlying
 // bus type is Send. The Mutex ensures exclusive access.
 unsafe impl<T: Send> Send for AsyncI2cDevice<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{I2cExpect, MockI2c, block_on};
+    use alloc::vec::Vec as AllocVec;
+
+    /// AXP2101, FT6336U, AW9523 and TCA9548A
+    const PRESENT: [u8; 4] = [0x34, 0x38, 0x58, 0x70];
+
+    /// Bus that ACKs a zero-length write to each address in `present`
+    fn bus_with(present: &[u8]) -> MockI2c {
+        MockI2c::new(
+            (I2C_SCAN_FIRST_ADDRESS..=I2C_SCAN_LAST_ADDRESS)
+                .map(|address| {
+                    if present.contains(&address) {
+                        I2cExpect::write(address, &[])
+                    } else {
+                        I2cExpect::Fail
+                    }
+                })
+                .collect::<AllocVec<_>>(),
+        )
+    }
+
+    #[test]
+    fn scan_returns_the_acking_addresses_in_order() {
+        let mut bus = bus_with(&PRESENT);
+
+        let found = block_on(scan_i2c(&mut bus));
+
+        assert_eq!(found.as_slice(), PRESENT);
+        bus.done();
+    }
+
+    #[test]
+    fn scan_through_a_shared_device_probes_every_address_once() {
+        let bus = Mutex::<CriticalSectionRawMutex, _>::new(bus_with(&[0x08, 0x77]));
+
+        let found = block_on(scan_i2c(&mut AsyncI2cDevice::new(&bus)));
+
+        // Both ends of the probed range are included
+        assert_eq!(found.as_slice(), [0x08, 0x77]);
+        block_on(bus.lock()).done();
+    }
+
+    #[test]
+    fn empty_bus_finds_nothing() {
+        let mut bus = bus_with(&[]);
+        assert!(block_on(scan_i2c(&mut bus)).is_empty());
+        bus.done();
+    }
+}
@@ -1,5 +1,33 @@
+use log::warn;
 use serde::{Deserialize, Serialize};
 
+use crate::sensors::{SLOWEST_MEASUREMENT_SECS, SensorType};
+use crate::storage::{StorageError, TimeWindow};
+
+/// Layout version written as the first byte of the persisted config
+///
+/// Bump it whenever the serialized layout of [`Config`] changes, such as a
+/// field appended to [`DeviceConfig`]. A file written under another version
+/// is replaced by the defaults instead of failing to decode.
+pub const CONFIG_FORMAT_VERSION: u8 = 1;
+
+/// Size of the buffer a config file is read into and encoded in
+///
+/// Generous, since `size_of::<Config>()` measures the in-memory
+/// representation (with references), not the serialized form.
+pub const CONFIG_BUFFER_SIZE: usize = 128;
+
+/// Storage holding the config file (the SD card's root volume on the device)
+pub trait ConfigVolume {
+    /// Read the config file into `buffer`, returning the number of bytes read
+    ///
+    /// A missing file reads as 0 bytes.
+    fn read_config_file(&self, buffer: &mut [u8]) -> Result<usize, StorageError>;
+
+    /// Replace the config file with `bytes`
+    fn write_config_file(&self, bytes: &[u8]) -> Result<(), StorageError>;
+}
+
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(bound(deserialize = "'de: 'a"))]
 pub struct Config<'a> {
@@ -7,12 +35,105 @@ pub struct Config<'a> {
     pub device: DeviceConfig,
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+impl<'a> Config<'a> {
+    /// Decode a config from the bytes persisted on the SD card
+    ///
+    /// Empty input (a missing or freshly created file), a file written under
+    /// another [`CONFIG_FORMAT_VERSION`] and a corrupt file all yield the
+    /// defaults, so a bad file never blocks saving a fresh one over it.
+    pub fn from_bytes(bytes: &'a [u8]) -> Self {
+        match bytes.split_first() {
+            None => Self::default(),
+            Some((&CONFIG_FORMAT_VERSION, body)) => {
+                postcard::from_bytes(body).unwrap_or_else(|e| {
+                    warn!("Config file is corrupt ({:?}), using defaults", e);
+                    Self::default()
+                })
+            }
+            Some((version, _)) => {
+                warn!(
+                    "Config file has format version {}, expected {}; using defaults",
+                    version, CONFIG_FORMAT_VERSION
+                );
+                Self::default()
+            }
+        }
+    }
+
+    /// Read the config file from `volume`, borrowing strings from `buffer`
+    ///
+    /// A missing, outdated or corrupt file yields the defaults (see
+    /// [`from_bytes`](Self::from_bytes)); only a failure to read the volume
+    /// is an error. Values are not range-checked.
+    pub fn load<V: ConfigVolume>(
+        volume: &V,
+        buffer: &'a mut [u8; CONFIG_BUFFER_SIZE],
+    ) -> Result<Self, StorageError> {
+        let len = volume.read_config_file(buffer)?;
+        Ok(Self::from_bytes(&buffer[..len]))
+    }
+
+    /// Encode this config and replace the config file on `volume` with it
+    pub fn save<V: ConfigVolume>(&self, volume: &V) -> Result<(), StorageError> {
+        let mut buffer = [0u8; CONFIG_BUFFER_SIZE];
+        let bytes = self
+            .to_slice(&mut buffer)
+            .map_err(StorageError::EncodeConfig)?;
+        volume.write_config_file(bytes)
+    }
+
+    /// Encode this config into `buffer` behind the format version byte,
+    /// returning the used prefix
+    pub fn to_slice<'b>(&self, buffer: &'b mut [u8]) -> Result<&'b mut [u8], postcard::Error> {
+        let (version, body) = buffer
+            .split_first_mut()
+            .ok_or(postcard::Error::SerializeBufferFull)?;
+        *version = CONFIG_FORMAT_VERSION;
+        let body_len = postcard::to_slice(self, body)?.len();
+        Ok(&mut buffer[..=body_len])
+    }
+}
+
+/// WiFi credentials for a single network
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy)]
 pub struct InternetConfig<'a> {
     pub ssid: &'a str,
     pub password: &'a str,
 }
 
+/// Default MQTT broker TCP port
+pub const DEFAULT_MQTT_PORT: u16 = 1883;
+
+/// Default prefix prepended to every published MQTT topic
+pub const DEFAULT_MQTT_TOPIC_PREFIX: &str = "baro";
+
+/// MQTT broker connection settings
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct MqttConfig<'a> {
+    /// Broker IPv4 address
+    pub broker: [u8; 4],
+    pub port: u16,
+    pub client_id: &'a str,
+    /// Empty for brokers that allow anonymous clients
+    pub username: &'a str,
+    pub password: &'a str,
+    /// Topics are published as `<prefix>/<sensor>`
+    pub topic_prefix: &'a str,
+}
+
+impl Default for MqttConfig<'_> {
+    fn default() -> Self {
+        Self {
+            broker: [0, 0, 0, 0],
+            port: DEFAULT_MQTT_PORT,
+            client_id: "baro",
+            username: "",
+            password: "",
+            topic_prefix: DEFAULT_MQTT_TOPIC_PREFIX,
+        }
+    }
+}
+
 /// Which home page style to use
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum HomePageMode {
@@ -23,6 +144,38 @@ pub enum HomePageMode {
     Home,
 }
 
+/// Display color scheme
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemeMode {
+    /// Light text on dark surfaces
+    #[default]
+    Dark,
+    /// Dark text on light surfaces, for bright rooms and outdoor use
+    Light,
+    /// White text on black with thicker borders and larger text, for low vision
+    HighContrast,
+}
+
+impl ThemeMode {
+    /// The mode after this one when cycling through them in settings
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Dark => Self::Light,
+            Self::Light => Self::HighContrast,
+            Self::HighContrast => Self::Dark,
+        }
+    }
+
+    /// Short label shown in settings
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Dark => "Dark",
+            Self::Light => "Light",
+            Self::HighContrast => "Contrast",
+        }
+    }
+}
+
 /// Temperature display unit
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TemperatureUnit {
@@ -40,6 +193,14 @@ impl TemperatureUnit {
         }
     }
 
+    /// Convert a Celsius difference (e.g. a spread) to this unit
+    pub fn convert_delta(self, celsius: f32) -> f32 {
+        match self {
+            Self::Celsius => celsius,
+            Self::Fahrenheit => celsius * 9.0 / 5.0,
+        }
+    }
+
     /// Display suffix
     pub const fn suffix(self) -> &'static str {
         match self {
@@ -57,9 +218,375 @@ impl TemperatureUnit {
     }
 }
 
+/// Default interval between sensor reads in seconds
+pub const DEFAULT_SAMPLE_INTERVAL_SECS: u32 = 10;
+
+/// Increment applied by the settings page stepper in seconds
+pub const SAMPLE_INTERVAL_STEP_SECS: u32 = 5;
+
+/// Shortest selectable sensor read interval in seconds (debugging)
+///
+/// The first stepper value longer than the slowest sensor's measurement, so
+/// a read always finishes before the next one is due.
+pub const MIN_SAMPLE_INTERVAL_SECS: u32 =
+    (SLOWEST_MEASUREMENT_SECS / SAMPLE_INTERVAL_STEP_SECS + 1) * SAMPLE_INTERVAL_STEP_SECS;
+
+/// Longest selectable sensor read interval in seconds (SD wear reduction)
+pub const MAX_SAMPLE_INTERVAL_SECS: u32 = 300;
+
+/// Clamp a requested sample interval into the supported range
+pub const fn clamp_sample_interval_secs(secs: u32) -> u32 {
+    if secs < MIN_SAMPLE_INTERVAL_SECS {
+        MIN_SAMPLE_INTERVAL_SECS
+    } else if secs > MAX_SAMPLE_INTERVAL_SECS {
+        MAX_SAMPLE_INTERVAL_SECS
+    } else {
+        secs
+    }
+}
+
+/// Default length of a first-tier rollup window in seconds
+pub const DEFAULT_ROLLUP_INTERVAL_SECS: u32 = 300;
+
+/// Shortest selectable first-tier rollup window in seconds
+pub const MIN_ROLLUP_INTERVAL_SECS: u32 = 60;
+
+/// Longest selectable first-tier rollup window in seconds (one hourly bucket)
+pub const MAX_ROLLUP_INTERVAL_SECS: u32 = 3600;
+
+/// Clamp a requested rollup window into the supported range
+pub const fn clamp_rollup_interval_secs(secs: u32) -> u32 {
+    if secs < MIN_ROLLUP_INTERVAL_SECS {
+        MIN_ROLLUP_INTERVAL_SECS
+    } else if secs > MAX_ROLLUP_INTERVAL_SECS {
+        MAX_ROLLUP_INTERVAL_SECS
+    } else {
+        secs
+    }
+}
+
+/// Default display backlight brightness in percent
+pub const DEFAULT_BRIGHTNESS_PERCENT: u8 = 80;
+
+/// Maximum display backlight brightness in percent
+pub const MAX_BRIGHTNESS_PERCENT: u8 = 100;
+
+/// Default FT6336U touch detection threshold (lower is more sensitive)
+pub const DEFAULT_TOUCH_THRESHOLD: u8 = 22;
+
+/// Most sensitive selectable touch threshold
+pub const MIN_TOUCH_THRESHOLD: u8 = 10;
+
+/// Least sensitive selectable touch threshold (thick cover glass)
+pub const MAX_TOUCH_THRESHOLD: u8 = 80;
+
+/// Increment applied by the settings page stepper
+pub const TOUCH_THRESHOLD_STEP: u8 = 2;
+
+/// Clamp a requested touch threshold into the supported range
+pub const fn clamp_touch_threshold(value: u8) -> u8 {
+    if value < MIN_TOUCH_THRESHOLD {
+        MIN_TOUCH_THRESHOLD
+    } else if value > MAX_TOUCH_THRESHOLD {
+        MAX_TOUCH_THRESHOLD
+    } else {
+        value
+    }
+}
+
+/// Outdoor CO₂ level the SCD41 is recalibrated to from settings, in ppm
+pub const CO2_FRESH_AIR_PPM: u16 = 420;
+
+/// SCD41 temperature offset for self-heating, in m°C (the sensor's factory value)
+pub const DEFAULT_CO2_TEMPERATURE_OFFSET_MILLI_C: u32 = 4_000;
+
+/// Largest SCD41 temperature offset accepted from the config, in m°C
+pub const MAX_CO2_TEMPERATURE_OFFSET_MILLI_C: u32 = 20_000;
+
+/// Windows a trend page may open on by default
+///
+/// One minute holds too few samples to show a trend, and a week needs daily
+/// rollups a young device does not have yet.
+pub const TREND_WINDOW_CHOICES: [TimeWindow; 5] = [
+    TimeWindow::FiveMinutes,
+    TimeWindow::ThirtyMinutes,
+    TimeWindow::OneHour,
+    TimeWindow::TwelveHours,
+    TimeWindow::OneDay,
+];
+
+/// Default trend windows for temperature, humidity and CO₂, in that order
+pub const DEFAULT_TREND_WINDOWS: [TimeWindow; 3] = [
+    TimeWindow::FiveMinutes,
+    TimeWindow::OneHour,
+    TimeWindow::ThirtyMinutes,
+];
+
+/// Slot in [`DeviceConfig::trend_windows`] holding `sensor`'s default window
+///
+/// `None` for sensors without a stored preference.
+pub const fn trend_window_slot(sensor: SensorType) -> Option<usize> {
+    match sensor {
+        SensorType::Temperature => Some(0),
+        SensorType::Humidity => Some(1),
+        SensorType::Co2 => Some(2),
+        SensorType::Lux | SensorType::Pressure => None,
+    }
+}
+
+/// Keep `window` if it is one of [`TREND_WINDOW_CHOICES`], otherwise use `fallback`
+pub fn validate_trend_window(window: TimeWindow, fallback: TimeWindow) -> TimeWindow {
+    if TREND_WINDOW_CHOICES.contains(&window) {
+        window
+    } else {
+        fallback
+    }
+}
+
 /// Device-level configuration that persists to SD card
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DeviceConfig {
     pub home_page_mode: HomePageMode,
     pub temperature_unit: TemperatureUnit,
+    /// Interval between sensor reads in seconds
+    pub sample_interval_secs: u32,
+    /// Display backlight brightness in percent (0-100)
+    pub brightness_percent: u8,
+    /// Display color scheme
+    pub theme_mode: ThemeMode,
+    /// FT6336U touch detection threshold (lower is more sensitive)
+    pub touch_threshold: u8,
+    /// Length of a first-tier ("5-minute") rollup window in seconds
+    pub rollup_interval_secs: u32,
+    /// Window each trend page opens on (temperature, humidity, CO₂)
+    pub trend_windows: [TimeWindow; 3],
+    /// Whether the SCD41 runs automatic self-calibration (ASC)
+    pub co2_auto_calibration: bool,
+    /// How much warmer the SCD41 runs than the room in m°C, sent to it at start-up
+    pub co2_temperature_offset_milli_c: u32,
+}
+
+impl Default for DeviceConfig {
+    fn default() -> Self {
+        Self {
+            home_page_mode: HomePageMode::default(),
+            temperature_unit: TemperatureUnit::default(),
+            sample_interval_secs: DEFAULT_SAMPLE_INTERVAL_SECS,
+            brightness_percent: DEFAULT_BRIGHTNESS_PERCENT,
+            theme_mode: ThemeMode::default(),
+            touch_threshold: DEFAULT_TOUCH_THRESHOLD,
+            rollup_interval_secs: DEFAULT_ROLLUP_INTERVAL_SECS,
+            trend_windows: DEFAULT_TREND_WINDOWS,
+            co2_auto_calibration: true,
+            co2_temperature_offset_milli_c: DEFAULT_CO2_TEMPERATURE_OFFSET_MILLI_C,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+
+    /// A config file held in memory
+    impl ConfigVolume for RefCell<Vec<u8>> {
+        fn read_config_file(&self, buffer: &mut [u8]) -> Result<usize, StorageError> {
+            let file = self.borrow();
+            let len = file.len().min(buffer.len());
+            buffer[..len].copy_from_slice(&file[..len]);
+            Ok(len)
+        }
+
+        fn write_config_file(&self, bytes: &[u8]) -> Result<(), StorageError> {
+            *self.borrow_mut() = bytes.to_vec();
+            Ok(())
+        }
+    }
+
+    /// Encode `config` into a buffer the size of the on-card config file
+    fn encode(config: &Config<'_>) -> ([u8; CONFIG_BUFFER_SIZE], usize) {
+        let mut buffer = [0u8; CONFIG_BUFFER_SIZE];
+        let len = config.to_slice(&mut buffer).unwrap().len();
+        (buffer, len)
+    }
+
+    #[test]
+    fn sample_interval_clamps_to_supported_range() {
+        assert_eq!(clamp_sample_interval_secs(0), MIN_SAMPLE_INTERVAL_SECS);
+        assert_eq!(
+            clamp_sample_interval_secs(u32::MAX),
+            MAX_SAMPLE_INTERVAL_SECS
+        );
+        assert_eq!(
+            clamp_sample_interval_secs(DEFAULT_SAMPLE_INTERVAL_SECS),
+            DEFAULT_SAMPLE_INTERVAL_SECS
+        );
+    }
+
+    #[test]
+    fn minimum_sample_interval_outlasts_slowest_measurement() {
+        const {
+            assert!(MIN_SAMPLE_INTERVAL_SECS > SLOWEST_MEASUREMENT_SECS);
+            assert!(MIN_SAMPLE_INTERVAL_SECS.is_multiple_of(SAMPLE_INTERVAL_STEP_SECS));
+            assert!(DEFAULT_SAMPLE_INTERVAL_SECS >= MIN_SAMPLE_INTERVAL_SECS);
+        }
+    }
+
+    #[test]
+    fn device_config_round_trips_behind_version_byte() {
+        let config = Config {
+            internet: InternetConfig::default(),
+            device: DeviceConfig {
+                sample_interval_secs: 60,
+                theme_mode: ThemeMode::Light,
+                co2_auto_calibration: false,
+                ..DeviceConfig::default()
+            },
+        };
+        let (buffer, len) = encode(&config);
+
+        assert_eq!(buffer[0], CONFIG_FORMAT_VERSION);
+        assert_eq!(Config::from_bytes(&buffer[..len]).device, config.device);
+    }
+
+    #[test]
+    fn empty_file_decodes_to_defaults() {
+        assert_eq!(Config::from_bytes(&[]).device, DeviceConfig::default());
+    }
+
+    #[test]
+    fn other_format_version_decodes_to_defaults() {
+        let config = Config {
+            device: DeviceConfig {
+                sample_interval_secs: 60,
+                ..DeviceConfig::default()
+            },
+            ..Config::default()
+        };
+        let (mut buffer, len) = encode(&config);
+        buffer[0] = CONFIG_FORMAT_VERSION.wrapping_add(1);
+
+        assert_eq!(
+            Config::from_bytes(&buffer[..len]).device,
+            DeviceConfig::default()
+        );
+    }
+
+    #[test]
+    fn truncated_file_decodes_to_defaults() {
+        let config = Config {
+            device: DeviceConfig {
+                sample_interval_secs: 60,
+                ..DeviceConfig::default()
+            },
+            ..Config::default()
+        };
+        let (buffer, len) = encode(&config);
+
+        assert_eq!(
+            Config::from_bytes(&buffer[..len - 1]).device,
+            DeviceConfig::default()
+        );
+    }
+
+    #[test]
+    fn config_round_trips_through_volume() {
+        let volume = RefCell::new(Vec::new());
+        let config = Config {
+            internet: InternetConfig {
+                ssid: "baro-net",
+                password: "hunter22",
+            },
+            device: DeviceConfig {
+                brightness_percent: 40,
+                temperature_unit: TemperatureUnit::Fahrenheit,
+                trend_windows: [TimeWindow::OneWeek; 3],
+                ..DeviceConfig::default()
+            },
+        };
+        config.save(&volume).unwrap();
+
+        let mut buffer = [0u8; CONFIG_BUFFER_SIZE];
+        let loaded = Config::load(&volume, &mut buffer).unwrap();
+        assert_eq!(loaded.internet.ssid, "baro-net");
+        assert_eq!(loaded.internet.password, "hunter22");
+        assert_eq!(loaded.device, config.device);
+    }
+
+    #[test]
+    fn missing_file_loads_defaults() {
+        let volume = RefCell::new(Vec::new());
+        let mut buffer = [0u8; CONFIG_BUFFER_SIZE];
+        let loaded = Config::load(&volume, &mut buffer).unwrap();
+
+        assert_eq!(loaded.internet.ssid, "");
+        assert_eq!(loaded.device, DeviceConfig::default());
+    }
+
+    #[test]
+    fn corrupt_file_loads_defaults_and_is_overwritten() {
+        let volume = RefCell::new(alloc::vec![CONFIG_FORMAT_VERSION, 0xFF, 0xFF, 0xFF]);
+        let mut buffer = [0u8; CONFIG_BUFFER_SIZE];
+        let mut loaded = Config::load(&volume, &mut buffer).unwrap();
+        assert_eq!(loaded.device, DeviceConfig::default());
+
+        loaded.device.sample_interval_secs = 60;
+        loaded.save(&volume).unwrap();
+
+        let mut buffer = [0u8; CONFIG_BUFFER_SIZE];
+        let reloaded = Config::load(&volume, &mut buffer).unwrap();
+        assert_eq!(reloaded.device.sample_interval_secs, 60);
+    }
+
+    #[test]
+    fn encoding_into_empty_buffer_fails() {
+        assert!(Config::default().to_slice(&mut []).is_err());
+    }
+
+    #[test]
+    fn default_trend_windows_are_valid_choices() {
+        for window in DEFAULT_TREND_WINDOWS {
+            assert_eq!(validate_trend_window(window, TimeWindow::OneDay), window);
+        }
+    }
+
+    #[test]
+    fn windows_outside_the_choices_fall_back() {
+        let fallback = TimeWindow::OneHour;
+        assert_eq!(
+            validate_trend_window(TimeWindow::OneMinute, fallback),
+            fallback
+        );
+        assert_eq!(
+            validate_trend_window(TimeWindow::TwelveHours, fallback),
+            TimeWindow::TwelveHours
+        );
+    }
+
+    #[test]
+    fn trend_windows_round_trip_through_the_config_file() {
+        let trend_windows = [
+            TimeWindow::OneDay,
+            TimeWindow::FiveMinutes,
+            TimeWindow::TwelveHours,
+        ];
+        let config = Config {
+            internet: InternetConfig::default(),
+            device: DeviceConfig {
+                trend_windows,
+                ..DeviceConfig::default()
+            },
+        };
+        let (buffer, len) = encode(&config);
+
+        assert_eq!(
+            Config::from_bytes(&buffer[..len]).device.trend_windows,
+            trend_windows
+        );
+        // Each stored sensor has its own slot
+        assert_eq!(trend_window_slot(SensorType::Temperature), Some(0));
+        assert_eq!(trend_window_slot(SensorType::Co2), Some(2));
+        assert_eq!(trend_window_slot(SensorType::Lux), None);
+    }
 }
@@ -9,32 +9,39 @@
 use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
 use embassy_sync::channel::{Channel, Receiver, Sender};
 use embassy_sync::mutex::Mutex as AsyncMutex;
+use embassy_sync::signal::Signal;
+use embassy_time::{Duration, Instant, with_timeout};
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::Rectangle;
 use log::{debug, error, info};
 
-use crate::app_state::AppState;
-use crate::config::{HomePageMode, TemperatureUnit};
+use crate::app_state::{AppError, AppRunState, AppState, TimeStatus};
+use crate::config::{
+    DEFAULT_BRIGHTNESS_PERCENT, DEFAULT_SAMPLE_INTERVAL_SECS, DEFAULT_TOUCH_THRESHOLD,
+    DEFAULT_TREND_WINDOWS, DeviceConfig, HomePageMode, TemperatureUnit, ThemeMode,
+};
 use crate::framebuffer::FrameBuffer;
+use crate::log_ring::{LOG_RING, LogLevel};
 use crate::metrics::QualityLevel;
-use crate::pages::home::grid::HomeGridPage;
-use crate::pages::home::outdoor::HomePage;
-use crate::pages::monitor::MonitorPage;
+use crate::metrics::fixed::to_float;
+use crate::pages::error::ErrorPage;
+use crate::pages::monitor::LogSink;
 use crate::pages::page::{Page, PageWrapper};
-use crate::pages::settings::DisplaySettingsPage;
-use crate::pages::settings::SettingsPage;
-use crate::pages::wifi_status::{WifiState, WifiStatusPage};
+use crate::pages::page_manager::PageContext;
+use crate::pages::splash::SplashPage;
 use crate::sensor_store::SensorDataStore;
-use crate::sensors::SensorType;
 use crate::sensors::{
     CO2 as SENSOR_CO2_INDEX, HUMIDITY as SENSOR_HUMIDITY_INDEX, LUX as SENSOR_LUX_INDEX,
     TEMPERATURE as SENSOR_TEMPERATURE_INDEX,
 };
+use crate::sensors::{SensorCalibration, SensorHealth, SensorHealthReport, SensorType};
 use crate::storage::accumulator::RollupEvent;
-use crate::storage::{RollupTier, TimeWindow};
+use crate::storage::{HistoryData, MISSING_SENSOR_VALUE, RollupTier, TimeWindow, Timestamp};
 use crate::ui::{
-    Action, DISPLAY_HEIGHT_PX, DISPLAY_WIDTH_PX, PageEvent, PageId, SensorData, TouchEvent,
+    Action, DISPLAY_ROTATION, Drawable, LOGICAL_DISPLAY_SIZE, PageEvent, PageId, RotatedTarget,
+    SensorData, StorageBanner, StorageEvent, SystemEvent, Theme, TimeSyncBanner, ToastQueue,
+    TouchEvent,
 };
 
 extern crate alloc;
@@ -55,7 +62,7 @@ const AUTO_CYCLE_PAGES: [PageId; 4] = [
 ];
 
 /// Request to change the current page or update the display
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub enum DisplayRequest {
     /// Navigate to a specific page
     NavigateToPage(PageId),
@@ -65,12 +72,78 @@ pub enum DisplayRequest {
     HandleTouch(TouchEvent),
     /// Update the display with new rollup data
     UpdateData(Box<RollupEvent>),
+    /// Update the per-sensor health shown on pages
+    UpdateHealth(SensorHealthReport),
+    /// WiFi link went up (`true`) or down (`false`)
+    UpdateWifi(bool),
+    /// Latest WiFi signal strength in dBm
+    UpdateWifiRssi(i8),
+    /// Current Unix time in seconds (drives header clocks)
+    UpdateTime(u32),
+    /// Show a transient message on top of the current page
+    Notify(&'static str),
+    /// How far the clock can be trusted (drives the "time not synced" banner)
+    UpdateTimeStatus(TimeStatus),
+    /// Boot sequence advanced (drives the splash page)
+    UpdateRunState(AppRunState),
+    /// Whether stored data survives a reboot (drives the "no SD card" banner)
+    UpdateStoragePersistence(bool),
+    /// Status line from a system task, appended to the monitor page's log
+    LogLine(LogLevel, heapless::String<64>),
+    /// Sensor sampling stalled (`true`) or resumed (`false`), from the watchdog
+    UpdateSensorStalled(bool),
+    /// Stored history answering a page's `Action::RequestHistory`
+    LoadHistory {
+        sensor: SensorType,
+        window: TimeWindow,
+        tier: RollupTier,
+        data: Box<HistoryData>,
+    },
+    /// Replace the current page with an explanation of `AppError`
+    ShowError(Box<AppError>),
 }
 
 /// Global channel for display requests
 pub static DISPLAY_CHANNEL: Channel<CriticalSectionRawMutex, DisplayRequest, PAGE_CHANGE_CAPACITY> =
     Channel::new();
 
+/// Latest requested backlight brightness (percent), consumed by the firmware
+/// backlight task which drives the power management chip
+pub static BRIGHTNESS_SIGNAL: Signal<CriticalSectionRawMutex, u8> = Signal::new();
+
+/// Latest requested touch threshold, consumed by the firmware touch task
+/// which writes it to the touch controller
+pub static TOUCH_THRESHOLD_SIGNAL: Signal<CriticalSectionRawMutex, u8> = Signal::new();
+
+/// Pending CO₂ forced recalibration target (ppm), consumed by the firmware
+/// sensor task between reads
+pub static CO2_RECALIBRATION_SIGNAL: Signal<CriticalSectionRawMutex, u16> = Signal::new();
+
+/// Schedules `update` calls for a page that asks for ticks via `Page::wants_tick`
+#[derive(Debug, Default, Clone, Copy)]
+struct TickSchedule {
+    /// When the page was last ticked (ms since boot)
+    last_tick_ms: u64,
+}
+
+impl TickSchedule {
+    /// When the next tick is due for a page asking for `interval`, if it asks
+    fn deadline_ms(&self, interval: Option<Duration>) -> Option<u64> {
+        interval.map(|interval| self.last_tick_ms + interval.as_millis())
+    }
+
+    /// Whether a tick is due at `now_ms`, recording it as taken if so
+    fn update(&mut self, interval: Option<Duration>, now_ms: u64) -> bool {
+        match self.deadline_ms(interval) {
+            Some(deadline_ms) if now_ms >= deadline_ms => {
+                self.last_tick_ms = now_ms;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
 /// Display manager that owns the display and manages page rendering
 pub struct DisplayManager<D>
 where
@@ -85,6 +158,20 @@ where
     home_page_mode: HomePageMode,
     /// Current temperature display unit (loaded from device config)
     temperature_unit: TemperatureUnit,
+    /// Current sensor sampling interval in seconds (loaded from device config)
+    sample_interval_secs: u32,
+    /// Current backlight brightness in percent (loaded from device config)
+    brightness_percent: u8,
+    /// Current touch detection threshold (loaded from device config)
+    touch_threshold: u8,
+    /// Preferred trend windows (loaded from device config)
+    trend_windows: [TimeWindow; 3],
+    /// Whether CO₂ automatic self-calibration is on (loaded from device config)
+    co2_auto_calibration: bool,
+    /// Current sensor calibration (mirrors `AppState::calibration`)
+    calibration: SensorCalibration,
+    /// Active color theme (loaded from device config)
+    theme: Theme,
     /// Whether auto-cycling is currently active (Home grid mode)
     auto_cycle_enabled: bool,
     /// Timestamp of the last auto-cycle page switch
@@ -95,8 +182,26 @@ where
     all_sensors_healthy: bool,
     /// Last known timestamp from sensor data
     last_sensor_timestamp: u64,
+    /// Last known per-sensor health — replayed to pages on navigation
+    sensor_health: SensorHealthReport,
+    /// Last WiFi signal strength in dBm — replayed to pages on navigation
+    wifi_rssi: Option<i8>,
+    /// Last Unix time received — replayed to pages on navigation
+    current_time: Option<u32>,
+    /// Last boot stage received — replayed to pages on navigation
+    run_state: AppRunState,
+    /// When the current page is next due an `update` tick
+    ticks: TickSchedule,
     /// Centralized sensor data store — survives page navigation
     sensor_store: SensorDataStore,
+    /// Where the monitor page mirrors its log feed, if anywhere
+    log_sink: Option<&'static dyn LogSink>,
+    /// Transient messages drawn over the current page
+    toasts: ToastQueue,
+    /// Warning strip shown while the clock is not NTP-synchronized
+    time_banner: TimeSyncBanner,
+    /// Warning strip shown while data is only kept in RAM
+    storage_banner: StorageBanner,
     /// Touch debounce: skip the next Press event when true.
     ///
     /// Set after a touch that caused a page state change (dirty transition)
@@ -111,29 +216,44 @@ where
 {
     /// Create a new display manager with the given display
     pub fn new(display: D) -> Self {
-        let bounds = Rectangle::new(
-            Point::zero(),
-            Size::new(DISPLAY_WIDTH_PX as u32, DISPLAY_HEIGHT_PX as u32),
-        );
+        let bounds = Rectangle::new(Point::zero(), LOGICAL_DISPLAY_SIZE);
 
-        // Start on the WiFi connecting page — the firmware will navigate
-        // to Home once WiFi is up, or to WifiStatus(Error) on failure.
-        let wifi_page = WifiStatusPage::new(WifiState::Connecting);
+        // Start on the splash page — it moves to Home once the firmware
+        // reports the sensors running, or the firmware navigates to
+        // WifiStatus(Error) if WiFi fails.
+        let theme = Theme::dark();
+        let splash_page = SplashPage::new(bounds, &theme);
 
         Self {
             display,
             framebuffer: FrameBuffer::new(),
-            current_page: PageWrapper::WifiStatus(Box::new(wifi_page)),
+            current_page: PageWrapper::Splash(Box::new(splash_page)),
             bounds,
             needs_redraw: true,
             home_page_mode: HomePageMode::default(),
             temperature_unit: TemperatureUnit::default(),
+            sample_interval_secs: DEFAULT_SAMPLE_INTERVAL_SECS,
+            brightness_percent: DEFAULT_BRIGHTNESS_PERCENT,
+            touch_threshold: DEFAULT_TOUCH_THRESHOLD,
+            trend_windows: DEFAULT_TREND_WINDOWS,
+            co2_auto_calibration: true,
+            calibration: SensorCalibration::identity(),
+            theme,
             auto_cycle_enabled: false,
             auto_cycle_last_switch: 0,
             auto_cycle_index: 0,
             all_sensors_healthy: true,
             last_sensor_timestamp: 0,
+            sensor_health: SensorHealthReport::default(),
+            wifi_rssi: None,
+            current_time: None,
+            run_state: AppRunState::Uninitialized,
+            ticks: TickSchedule::default(),
             sensor_store: SensorDataStore::new(),
+            log_sink: None,
+            toasts: ToastQueue::new(bounds),
+            time_banner: TimeSyncBanner::new(bounds),
+            storage_banner: StorageBanner::new(bounds),
             skip_next_press: false,
         }
     }
@@ -149,194 +269,134 @@ where
         TD: embedded_sdmmc::TimeSource,
     {
         debug!(" Navigating to page: {:?}", page_id);
-        match page_id {
-            PageId::Home => {
-                // Navigate to the correct home page based on current mode
-                match self.home_page_mode {
-                    HomePageMode::Outdoor => {
-                        let mut page = HomePage::new(self.bounds);
-                        page.init();
-                        page.load_from_store(&self.sensor_store);
-                        self.current_page = PageWrapper::Home(Box::new(page));
-                        self.auto_cycle_enabled = false;
-                    }
-                    HomePageMode::Home => {
-                        let mut page = HomeGridPage::new(self.bounds);
-                        page.load_from_store(&self.sensor_store);
-                        self.current_page = PageWrapper::HomeGrid(Box::new(page));
-                        self.auto_cycle_enabled = true;
-                        self.auto_cycle_last_switch = self.last_sensor_timestamp;
-                        self.auto_cycle_index = 0;
-                    }
-                }
-            }
-            PageId::HomeGrid => {
-                let mut page = HomeGridPage::new(self.bounds);
-                page.load_from_store(&self.sensor_store);
-                self.current_page = PageWrapper::HomeGrid(Box::new(page));
+        let context = PageContext {
+            theme: &self.theme,
+            home_page_mode: self.home_page_mode,
+            temperature_unit: self.temperature_unit,
+            sample_interval_secs: self.sample_interval_secs,
+            brightness_percent: self.brightness_percent,
+            touch_threshold: self.touch_threshold,
+            trend_windows: self.trend_windows,
+            co2_auto_calibration: self.co2_auto_calibration,
+            calibration: &self.calibration,
+            sensor_store: &self.sensor_store,
+            log_sink: self.log_sink,
+        };
+        let Some(page) = crate::pages::build(page_id, self.bounds, &context) else {
+            debug!(" No page to build for {:?}", page_id);
+            return;
+        };
+        self.current_page = page;
+
+        // Page-specific setup that needs storage or display manager state
+        match &mut self.current_page {
+            PageWrapper::HomeGrid(_) => {
                 self.auto_cycle_enabled = true;
                 self.auto_cycle_last_switch = self.last_sensor_timestamp;
                 self.auto_cycle_index = 0;
             }
-            PageId::Settings => {
-                let mut page = SettingsPage::new(self.bounds);
-                page.init();
-                self.current_page = PageWrapper::Settings(Box::new(page));
-                self.auto_cycle_enabled = false;
-            }
-            PageId::DisplaySettings => {
-                let page = DisplaySettingsPage::new(
-                    self.bounds,
-                    self.home_page_mode,
-                    self.temperature_unit,
-                );
-                self.current_page = PageWrapper::DisplaySettings(Box::new(page));
+            PageWrapper::Settings(page) => {
+                if let Some(storage) = app_state.lock().await.storage_manager() {
+                    page.set_storage_capacity(storage.capacity());
+                    let now = self.last_sensor_timestamp as u32;
+                    page.set_lifetime_stats(storage.get_lifetime_stats(), now);
+
+                    // Both come from memory, so the lock is held only briefly
+                    page.set_window_stats(storage.today_stats(now), storage.all_time_stats());
+                }
                 self.auto_cycle_enabled = false;
             }
-            PageId::Monitor => {
-                let mut page = MonitorPage::new(self.bounds);
-                page.init();
-                page.load_from_store(&self.sensor_store);
-                self.current_page = PageWrapper::Monitor(Box::new(page));
+            PageWrapper::TrendPage(_)
+            | PageWrapper::WifiStatus(_)
+            | PageWrapper::Splash(_)
+            | PageWrapper::Error(_) => {}
+            PageWrapper::Home(_) | PageWrapper::DisplaySettings(_) | PageWrapper::Monitor(_) => {
                 self.auto_cycle_enabled = false;
             }
-            PageId::Graphs => {
-                debug!(" Graphs page not yet implemented");
-            }
-            PageId::TrendPage => {
-                debug!(" TrendPage requires sensor/window parameters");
-            }
-            PageId::TrendTemperature => {
-                debug!(" Creating TrendTemperature page with historical data");
-                let mut page = crate::pages::TrendPage::new(
-                    self.bounds,
-                    SensorType::Temperature,
-                    TimeWindow::FiveMinutes,
-                );
-
-                // Load historical data directly from storage
-                Self::load_trend_data(app_state, &mut page, TimeWindow::FiveMinutes).await;
-
-                self.current_page = PageWrapper::TrendPage(Box::new(page));
-            }
-            PageId::TrendHumidity => {
-                debug!(" Creating TrendHumidity page with historical data");
-                let mut page = crate::pages::TrendPage::new(
-                    self.bounds,
-                    SensorType::Humidity,
-                    TimeWindow::OneHour,
-                );
-
-                // Load historical data directly from storage
-                Self::load_trend_data(app_state, &mut page, TimeWindow::OneHour).await;
-
-                self.current_page = PageWrapper::TrendPage(Box::new(page));
-            }
-            PageId::TrendCo2 => {
-                debug!(" Creating TrendCo2 page with historical data");
-                let mut page = crate::pages::TrendPage::new(
-                    self.bounds,
-                    SensorType::Co2,
-                    TimeWindow::ThirtyMinutes,
-                );
-
-                // Load historical data directly from storage
-                Self::load_trend_data(app_state, &mut page, TimeWindow::ThirtyMinutes).await;
+        }
 
-                self.current_page = PageWrapper::TrendPage(Box::new(page));
+        // Activate the page and answer anything it asks for
+        Page::on_activate(&mut self.current_page);
+        while let Some(action) = Page::take_action(&mut self.current_page) {
+            match action {
+                Action::RequestHistory { sensor, window } => {
+                    if let Some((tier, data)) = Self::query_history(app_state, window).await {
+                        self.load_history(sensor, window, tier, &data);
+                    }
+                }
+                other => debug!(" Ignoring activation action: {:?}", other),
             }
-            PageId::TrendLux => {
-                debug!(" Creating TrendLux page with historical data");
-                let mut page = crate::pages::TrendPage::new(
-                    self.bounds,
-                    SensorType::Lux,
-                    TimeWindow::ThirtyMinutes,
-                );
-
-                Self::load_trend_data(app_state, &mut page, TimeWindow::ThirtyMinutes).await;
+        }
 
-                self.current_page = PageWrapper::TrendPage(Box::new(page));
-            }
-            PageId::WifiStatus => {
-                let page = WifiStatusPage::new(WifiState::Error);
-                self.current_page = PageWrapper::WifiStatus(Box::new(page));
-            }
+        // New pages start out assuming healthy sensors; bring them up to date
+        let health_event = PageEvent::SensorHealth(self.sensor_health);
+        Page::on_event(&mut self.current_page, &health_event);
+        if let Some(rssi_dbm) = self.wifi_rssi {
+            let rssi_event = PageEvent::SystemEvent(SystemEvent::WifiRssi(rssi_dbm));
+            Page::on_event(&mut self.current_page, &rssi_event);
         }
+        if let Some(unix_secs) = self.current_time {
+            let time_event = PageEvent::SystemEvent(SystemEvent::Time(unix_secs));
+            Page::on_event(&mut self.current_page, &time_event);
+        }
+        let run_state_event = PageEvent::SystemEvent(SystemEvent::RunState(self.run_state));
+        Page::on_event(&mut self.current_page, &run_state_event);
+
         self.needs_redraw = true;
     }
 
-    /// Load historical data for a trend page from storage
-    /// This gets the appropriate rollups based on the time window and loads them into the page
-    async fn load_trend_data<SD, DD, TD>(
+    /// Read the stored history for `window` from its preferred rollup tier
+    ///
+    /// Returns `None` when storage is not available.
+    async fn query_history<SD, DD, TD>(
         app_state: &'static AsyncMutex<CriticalSectionRawMutex, AppState<'static, SD, DD, TD>>,
-        page: &mut crate::pages::TrendPage,
         window: TimeWindow,
-    ) where
+    ) -> Option<(RollupTier, HistoryData)>
+    where
         SD: embedded_hal::spi::SpiDevice<u8>,
         DD: embedded_hal::delay::DelayNs,
         TD: embedded_sdmmc::TimeSource,
     {
-        // Lock app state and get storage manager
         let state = app_state.lock().await;
-        if let Some(storage) = state.storage_manager() {
-            let tier = window.preferred_rollup_tier();
-
-            // Get the current time from the latest rollup/sample
-            let current_time = match tier {
-                RollupTier::RawSample => {
-                    let samples: alloc::vec::Vec<_> =
-                        storage.get_raw_samples().iter().copied().collect();
-                    let time = samples.last().map(|s| s.timestamp).unwrap_or(0);
-                    page.load_historical_raw_samples(&samples, time);
-                    debug!(
-                        "Loaded {} raw samples, latest timestamp: {}",
-                        samples.len(),
-                        time
-                    );
-                    time
-                }
-                RollupTier::FiveMinute => {
-                    let rollups: alloc::vec::Vec<_> =
-                        storage.get_5m_rollups().iter().copied().collect();
-                    let time = rollups.last().map(|r| r.start_ts + 300).unwrap_or(0);
-                    page.load_historical_data(&rollups, time);
-                    debug!(
-                        "Loaded {} 5-minute rollups, latest timestamp: {}",
-                        rollups.len(),
-                        time
-                    );
-                    time
-                }
-                RollupTier::Hourly => {
-                    let rollups: alloc::vec::Vec<_> =
-                        storage.get_1h_rollups().iter().copied().collect();
-                    let time = rollups.last().map(|r| r.start_ts + 3600).unwrap_or(0);
-                    page.load_historical_data(&rollups, time);
-                    debug!(
-                        "Loaded {} hourly rollups, latest timestamp: {}",
-                        rollups.len(),
-                        time
-                    );
-                    time
-                }
-                RollupTier::Daily => {
-                    let rollups: alloc::vec::Vec<_> =
-                        storage.get_daily_rollups().iter().copied().collect();
-                    let time = rollups.last().map(|r| r.start_ts + 86400).unwrap_or(0);
-                    page.load_historical_data(&rollups, time);
-                    debug!(
-                        "Loaded {} daily rollups, latest timestamp: {}",
-                        rollups.len(),
-                        time
-                    );
-                    time
-                }
-            };
+        let storage = state.storage_manager()?;
+        let tier = window.preferred_rollup_tier();
+
+        let data = match tier {
+            RollupTier::RawSample => {
+                HistoryData::RawSamples(storage.get_raw_samples().iter().copied().collect())
+            }
+            RollupTier::FiveMinute => {
+                HistoryData::Rollups(storage.get_5m_rollups().iter().copied().collect())
+            }
+            RollupTier::Hourly => {
+                HistoryData::Rollups(storage.get_1h_rollups().iter().copied().collect())
+            }
+            RollupTier::Daily => {
+                HistoryData::Rollups(storage.get_daily_rollups().iter().copied().collect())
+            }
+        };
+
+        debug!(" Read {} {:?} entries for {:?}", data.len(), tier, window);
+        Some((tier, data))
+    }
 
-            debug!(
-                "TrendPage stats after load - Current time: {}",
-                current_time
-            );
+    /// Hand stored history to the current page if it is the trend page that asked for it
+    fn load_history(
+        &mut self,
+        sensor: SensorType,
+        window: TimeWindow,
+        tier: RollupTier,
+        data: &HistoryData,
+    ) {
+        match &mut self.current_page {
+            PageWrapper::TrendPage(page) if page.sensor() == sensor && page.window() == window => {
+                page.load_history(tier, data);
+                self.needs_redraw = true;
+            }
+            _ => debug!(
+                " Dropping history for {:?}/{:?}: page changed",
+                sensor, window
+            ),
         }
     }
 
@@ -350,6 +410,7 @@ where
         DD: embedded_hal::delay::DelayNs,
         TD: embedded_sdmmc::TimeSource,
     {
+        let event = DISPLAY_ROTATION.touch_to_logical(event, self.framebuffer.size());
         debug!(" Received touch event: {:?}", event);
 
         // Touch debounce: skip this Press if the previous touch caused a
@@ -388,6 +449,7 @@ where
                         | PageId::TrendHumidity
                         | PageId::TrendCo2
                         | PageId::TrendLux
+                        | PageId::TrendPressure
                         | PageId::TrendPage => {
                             self.navigate_to(PageId::Home, app_state).await;
                         }
@@ -420,6 +482,82 @@ where
                         state.device_config.temperature_unit = unit;
                     }
                 }
+                Action::SetSampleInterval(secs) => {
+                    info!(" Updating sample interval to {}s", secs);
+                    self.sample_interval_secs = secs;
+
+                    // Update device config in app state (read by the sensor
+                    // task every cycle) and persist it so it survives reboot
+                    persist_device_config(&mut *app_state.lock().await, |config| {
+                        config.sample_interval_secs = secs;
+                    });
+                }
+                Action::SetCalibrationOffset { sensor, offset } => {
+                    info!(
+                        " Updating {} calibration offset to {}",
+                        sensor.name(),
+                        offset
+                    );
+                    self.calibration.offset[sensor.index()] = offset;
+
+                    // The sensor task copies the calibration on every read cycle
+                    app_state.lock().await.calibration = self.calibration;
+                }
+                Action::SetCo2AutoCalibration(enabled) => {
+                    info!(" Updating CO2 auto-calibration to {}", enabled);
+                    self.co2_auto_calibration = enabled;
+
+                    // The sensor task applies it the next time it sets up the SCD41
+                    persist_device_config(&mut *app_state.lock().await, |config| {
+                        config.co2_auto_calibration = enabled;
+                    });
+                }
+                Action::ForceCo2Recalibration(target_ppm) => {
+                    info!(" Requesting CO2 recalibration to {} ppm", target_ppm);
+
+                    // Hand off to the sensor task, which owns the SCD41
+                    CO2_RECALIBRATION_SIGNAL.signal(target_ppm);
+                }
+                Action::SetThemeMode(mode) => {
+                    info!(" Updating theme to {:?}", mode);
+                    self.theme = Theme::for_mode(mode);
+                    self.toasts.set_palette(self.theme.palette);
+
+                    persist_device_config(&mut *app_state.lock().await, |config| {
+                        config.theme_mode = mode;
+                    });
+
+                    // Pages copy the palette at construction, so rebuild the current one
+                    let current_id = Page::id(&self.current_page);
+                    self.navigate_to(current_id, app_state).await;
+                }
+                Action::PreviewBrightness(percent) => {
+                    // Applied live while the slider moves; persisted on release
+                    self.brightness_percent = percent;
+                    BRIGHTNESS_SIGNAL.signal(percent);
+                }
+                Action::SetBrightness(percent) => {
+                    info!(" Updating brightness to {}%", percent);
+                    self.brightness_percent = percent;
+
+                    // Hand off to the backlight task; only the latest level matters
+                    BRIGHTNESS_SIGNAL.signal(percent);
+
+                    persist_device_config(&mut *app_state.lock().await, |config| {
+                        config.brightness_percent = percent;
+                    });
+                }
+                Action::SetTouchThreshold(threshold) => {
+                    info!(" Updating touch threshold to {}", threshold);
+                    self.touch_threshold = threshold;
+
+                    // Hand off to the touch task, which owns the controller
+                    TOUCH_THRESHOLD_SIGNAL.signal(threshold);
+
+                    persist_device_config(&mut *app_state.lock().await, |config| {
+                        config.touch_threshold = threshold;
+                    });
+                }
                 _ => {
                     debug!(" Unhandled action: {:?}", action);
                 }
@@ -441,16 +579,33 @@ where
     }
 
     /// Check if all sensor values indicate Good or Excellent quality.
-    fn check_all_healthy(temp: f32, humidity: f32, co2: f32, lux: f32) -> bool {
-        let qualities = [
-            QualityLevel::assess(SensorType::Temperature, temp),
-            QualityLevel::assess(SensorType::Humidity, humidity),
-            QualityLevel::assess(SensorType::Co2, co2),
-            QualityLevel::assess(SensorType::Lux, lux),
+    ///
+    /// A missing reading counts as unhealthy.
+    fn check_all_healthy(data: &SensorData) -> bool {
+        let readings = [
+            (SensorType::Temperature, data.temperature),
+            (SensorType::Humidity, data.humidity),
+            (SensorType::Co2, data.co2),
+            (SensorType::Lux, data.lux),
         ];
-        qualities
-            .iter()
-            .all(|q| matches!(q, QualityLevel::Good | QualityLevel::Excellent))
+        readings.iter().all(|(sensor, value)| {
+            value.is_some_and(|v| {
+                matches!(
+                    QualityLevel::assess(*sensor, v),
+                    QualityLevel::Good | QualityLevel::Excellent
+                )
+            })
+        })
+    }
+
+    /// Convert a stored milli-unit value to a float, or `None` if the sensor
+    /// could not be read.
+    fn milli_to_float(value: i32) -> Option<f32> {
+        if value == MISSING_SENSOR_VALUE {
+            None
+        } else {
+            Some(to_float(value))
+        }
     }
 
     /// Set the home page mode (called during boot after loading config)
@@ -463,6 +618,47 @@ where
         self.temperature_unit = unit;
     }
 
+    /// Set the sensor sampling interval (called during boot after loading config)
+    pub fn set_sample_interval(&mut self, secs: u32) {
+        self.sample_interval_secs = secs;
+    }
+
+    /// Set the sensor calibration (called during boot after setting up app state)
+    pub fn set_calibration(&mut self, calibration: SensorCalibration) {
+        self.calibration = calibration;
+    }
+
+    /// Set the backlight brightness (called during boot after loading config)
+    pub fn set_brightness(&mut self, percent: u8) {
+        self.brightness_percent = percent;
+    }
+
+    /// Set the touch threshold (called during boot after loading config)
+    pub fn set_touch_threshold(&mut self, threshold: u8) {
+        self.touch_threshold = threshold;
+    }
+
+    /// Set the preferred trend windows (called during boot after loading config)
+    pub fn set_trend_windows(&mut self, windows: [TimeWindow; 3]) {
+        self.trend_windows = windows;
+    }
+
+    /// Set whether CO₂ automatic self-calibration is on (called during boot after loading config)
+    pub fn set_co2_auto_calibration(&mut self, enabled: bool) {
+        self.co2_auto_calibration = enabled;
+    }
+
+    /// Set the color theme (called during boot after loading config)
+    pub fn set_theme_mode(&mut self, mode: ThemeMode) {
+        self.theme = Theme::for_mode(mode);
+        self.toasts.set_palette(self.theme.palette);
+    }
+
+    /// Mirror the monitor page's log feed to `sink` (applies from the next navigation)
+    pub fn set_log_sink(&mut self, sink: &'static dyn LogSink) {
+        self.log_sink = Some(sink);
+    }
+
     /// Update the current page with new data
     fn update_data(&mut self, event: Box<RollupEvent>) {
         debug!(" Received data update: {:?}", event);
@@ -474,33 +670,21 @@ where
         // Convert RollupEvent to PageEvent and dispatch to current page
         match *event {
             RollupEvent::RawSample(sample) => {
-                // Extract sensor values from the raw sample (in milli-units)
-                let temperature_mc = sample.values[SENSOR_TEMPERATURE_INDEX];
-                let humidity_mp = sample.values[SENSOR_HUMIDITY_INDEX];
-                let co2_mp = sample.values[SENSOR_CO2_INDEX];
-                let lux_ml = sample.values[SENSOR_LUX_INDEX];
-
-                // Convert to float values (divide by 1000)
-                let temp_c = temperature_mc as f32 / 1000.0;
-                let humidity_pct = humidity_mp as f32 / 1000.0;
-                let co2_ppm = co2_mp as f32 / 1000.0;
-                let lux_val = lux_ml as f32 / 1000.0;
-
                 debug!("{}", sample);
 
-                // Track health for auto-cycle
-                self.all_sensors_healthy =
-                    Self::check_all_healthy(temp_c, humidity_pct, co2_ppm, lux_val);
-                self.last_sensor_timestamp = sample.timestamp as u64;
-
+                // Convert milli-unit values to floats (missing sensors become None)
                 let sensor_data = SensorData {
-                    temperature: Some(temp_c),
-                    humidity: Some(humidity_pct),
-                    co2: Some(co2_ppm),
-                    lux: Some(lux_val),
+                    temperature: Self::milli_to_float(sample.values[SENSOR_TEMPERATURE_INDEX]),
+                    humidity: Self::milli_to_float(sample.values[SENSOR_HUMIDITY_INDEX]),
+                    co2: Self::milli_to_float(sample.values[SENSOR_CO2_INDEX]),
+                    lux: Self::milli_to_float(sample.values[SENSOR_LUX_INDEX]),
                     timestamp: sample.timestamp as u64,
                 };
 
+                // Track health for auto-cycle
+                self.all_sensors_healthy = Self::check_all_healthy(&sensor_data);
+                self.last_sensor_timestamp = sample.timestamp as u64;
+
                 // Persist into the centralized store so future page
                 // navigations start with current data.
                 self.sensor_store.push(&sensor_data);
@@ -516,24 +700,14 @@ where
             RollupEvent::Rollup5m(rollup)
             | RollupEvent::Rollup1h(rollup)
             | RollupEvent::RollupDaily(rollup) => {
-                // For rollups, use the average values
-                let temperature_mc = rollup.avg[SENSOR_TEMPERATURE_INDEX];
-                let humidity_mp = rollup.avg[SENSOR_HUMIDITY_INDEX];
-                let co2_mp = rollup.avg[SENSOR_CO2_INDEX];
-                let lux_ml = rollup.avg[SENSOR_LUX_INDEX];
-
-                let temp_c = temperature_mc as f32 / 1000.0;
-                let humidity_pct = humidity_mp as f32 / 1000.0;
-                let co2_ppm = co2_mp as f32 / 1000.0;
-                let lux_val = lux_ml as f32 / 1000.0;
-
                 debug!("{}", rollup);
 
+                // For rollups, use the average values
                 let sensor_data = SensorData {
-                    temperature: Some(temp_c),
-                    humidity: Some(humidity_pct),
-                    co2: Some(co2_ppm),
-                    lux: Some(lux_val),
+                    temperature: Self::milli_to_float(rollup.avg[SENSOR_TEMPERATURE_INDEX]),
+                    humidity: Self::milli_to_float(rollup.avg[SENSOR_HUMIDITY_INDEX]),
+                    co2: Self::milli_to_float(rollup.avg[SENSOR_CO2_INDEX]),
+                    lux: Self::milli_to_float(rollup.avg[SENSOR_LUX_INDEX]),
                     timestamp: rollup.start_ts as u64,
                 };
 
@@ -543,7 +717,22 @@ where
                 let page_event = PageEvent::SensorUpdate(sensor_data);
                 let needs_redraw = Page::on_event(&mut self.current_page, &page_event);
 
-                if needs_redraw || needs_redraw_rollup {
+                // Announce the rollup boundary (the end of its window)
+                let (interval, tier) = match *event {
+                    RollupEvent::Rollup5m(_) => ("5m", RollupTier::FiveMinute),
+                    RollupEvent::Rollup1h(_) => ("1h", RollupTier::Hourly),
+                    _ => ("1d", RollupTier::Daily),
+                };
+                let storage_event = PageEvent::StorageEvent(StorageEvent::Rollup {
+                    interval,
+                    count: rollup.sample_count as usize,
+                    timestamp: Timestamp::new(rollup.start_ts)
+                        .advance(tier.bucket_secs())
+                        .secs() as u64,
+                });
+                let needs_redraw_storage = Page::on_event(&mut self.current_page, &storage_event);
+
+                if needs_redraw || needs_redraw_rollup || needs_redraw_storage {
                     debug!(" Page marked for redraw after rollup update");
                     self.needs_redraw = true;
                 }
@@ -551,6 +740,161 @@ where
         }
     }
 
+    /// Record new per-sensor health and forward it to the current page
+    fn update_health(&mut self, report: SensorHealthReport) {
+        debug!(" Received sensor health: {:?}", report);
+        self.sensor_health = report;
+
+        let page_event = PageEvent::SensorHealth(report);
+        if Page::on_event(&mut self.current_page, &page_event) {
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Forward a WiFi link change to the current page
+    fn update_wifi(&mut self, connected: bool) {
+        let event = if connected {
+            SystemEvent::NetworkConnected
+        } else {
+            SystemEvent::NetworkDisconnected
+        };
+
+        if Page::on_event(&mut self.current_page, &PageEvent::SystemEvent(event)) {
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Forward a WiFi signal-strength reading to the current page
+    fn update_wifi_rssi(&mut self, rssi_dbm: i8) {
+        self.wifi_rssi = Some(rssi_dbm);
+        let event = PageEvent::SystemEvent(SystemEvent::WifiRssi(rssi_dbm));
+        if Page::on_event(&mut self.current_page, &event) {
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Forward the current time to the current page
+    fn update_time(&mut self, unix_secs: u32) {
+        self.current_time = Some(unix_secs);
+        let event = PageEvent::SystemEvent(SystemEvent::Time(unix_secs));
+        if Page::on_event(&mut self.current_page, &event) {
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Forward a boot stage change to the current page
+    fn update_run_state(&mut self, run_state: AppRunState) {
+        info!(" Run state: {:?}", run_state);
+        self.run_state = run_state;
+        let event = PageEvent::SystemEvent(SystemEvent::RunState(run_state));
+        if Page::on_event(&mut self.current_page, &event) {
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Record a system task's status line in `LOG_RING` and tell the current page
+    fn log_line(&mut self, level: LogLevel, line: heapless::String<64>) {
+        info!(" Log ({}): {}", level.label(), line);
+        LOG_RING.push(self.current_time.unwrap_or(0) as u64, level, &line);
+        let event = PageEvent::SystemEvent(SystemEvent::LogLine(level, line));
+        if Page::on_event(&mut self.current_page, &event) {
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Warn that readings stopped updating (or say they are back) and tell the current page
+    fn update_sensor_stalled(&mut self, stalled: bool) {
+        let event = if stalled {
+            SystemEvent::SensorStalled
+        } else {
+            SystemEvent::SensorResumed
+        };
+        if Page::on_event(&mut self.current_page, &PageEvent::SystemEvent(event)) {
+            self.needs_redraw = true;
+        }
+        self.notify(if stalled {
+            "Sensor readings stopped"
+        } else {
+            "Sensor readings resumed"
+        });
+    }
+
+    /// Queue a toast and forward the notification to the current page
+    fn notify(&mut self, message: &'static str) {
+        info!(" Notify: {}", message);
+        let event = PageEvent::SystemEvent(SystemEvent::Notify(message));
+        let toast_changed = self.toasts.on_event(&event);
+        if Page::on_event(&mut self.current_page, &event) || toast_changed {
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Show or hide the "time not synced" banner
+    fn update_time_status(&mut self, status: TimeStatus) {
+        info!(" Time status: {:?}", status);
+        if self.time_banner.set_time_status(status) {
+            self.needs_redraw = true;
+        }
+        self.stack_banners();
+    }
+
+    /// Show or hide the "no SD card" banner
+    fn update_storage_persistence(&mut self, persistent: bool) {
+        info!(" Storage persistent: {}", persistent);
+        if self.storage_banner.set_persistent(persistent) {
+            self.needs_redraw = true;
+        }
+        self.stack_banners();
+    }
+
+    /// Keep the storage banner above the time banner when both are shown
+    fn stack_banners(&mut self) {
+        let inset_px = if self.time_banner.is_visible() {
+            Drawable::bounds(&self.time_banner).size.height
+        } else {
+            0
+        };
+        if self.storage_banner.set_bottom_inset_px(inset_px) && self.storage_banner.is_visible() {
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Replace the current page with the error page for `app_error`
+    fn show_error(&mut self, app_error: &AppError) {
+        error!(" Showing error page: {}", app_error);
+        self.current_page = PageWrapper::Error(Box::new(ErrorPage::new(
+            self.bounds,
+            app_error,
+            &self.theme,
+        )));
+        Page::on_activate(&mut self.current_page);
+        self.auto_cycle_enabled = false;
+        self.needs_redraw = true;
+    }
+
+    /// When the current page's next tick is due, if it asked for one
+    fn tick_deadline_ms(&self) -> Option<u64> {
+        self.ticks.deadline_ms(Page::wants_tick(&self.current_page))
+    }
+
+    /// Update the current page once its requested tick interval has passed
+    fn update_tick(&mut self) {
+        let interval = Page::wants_tick(&self.current_page);
+        if self.ticks.update(interval, Instant::now().as_millis()) {
+            Page::update(&mut self.current_page);
+            if Page::is_dirty(&self.current_page) {
+                self.needs_redraw = true;
+            }
+        }
+    }
+
+    /// Advance the toast expiry clock, redrawing when the visible toast changes
+    fn update_toasts(&mut self) {
+        if self.toasts.update(Instant::now().as_millis()) {
+            self.needs_redraw = true;
+        }
+    }
+
     /// Render the current page if needed.
     ///
     /// Drawing targets the PSRAM framebuffer first. After the page finishes,
@@ -562,13 +906,25 @@ where
             debug!(" Rendering page to framebuffer");
 
             // Clear the framebuffer (only pixels that differ will be marked dirty)
-            let _ = self.framebuffer.clear(Rgb565::BLACK);
+            let _ = self.framebuffer.clear(self.theme.palette.background);
 
-            // Draw the current page into the RAM framebuffer (infallible)
-            let _ = self.current_page.draw_page(&mut self.framebuffer);
+            // Draw the current page into the RAM framebuffer (infallible),
+            // turned to match how the panel is mounted
+            let mut target = RotatedTarget::new(&mut self.framebuffer, DISPLAY_ROTATION);
+            let _ = self.current_page.draw_page(&mut target);
+
+            // Overlays sit on top of whatever page is showing, toasts topmost
+            let _ = self.time_banner.draw(&mut target);
+            self.time_banner.mark_clean();
+            let _ = self.storage_banner.draw(&mut target);
+            self.storage_banner.mark_clean();
+            let _ = self.toasts.draw(&mut target);
+            self.toasts.mark_clean();
 
             // Flush only the changed region to the hardware display
-            self.framebuffer.flush(&mut self.display)?;
+            if let Some(rows) = self.framebuffer.flush(&mut self.display)? {
+                debug!(" Flushed rows {}..{}", rows.start, rows.end);
+            }
 
             self.needs_redraw = false;
         }
@@ -604,6 +960,65 @@ where
                 debug!(" -> UpdateData: {:?}", event);
                 self.update_data(event);
             }
+            DisplayRequest::UpdateHealth(report) => {
+                debug!(" -> UpdateHealth: {:?}", report);
+                self.update_health(report);
+            }
+            DisplayRequest::UpdateWifi(connected) => {
+                debug!(" -> UpdateWifi: {}", connected);
+                self.update_wifi(connected);
+            }
+            DisplayRequest::UpdateWifiRssi(rssi_dbm) => {
+                debug!(" -> UpdateWifiRssi: {} dBm", rssi_dbm);
+                self.update_wifi_rssi(rssi_dbm);
+            }
+            DisplayRequest::UpdateTime(unix_secs) => {
+                debug!(" -> UpdateTime: {}", unix_secs);
+                self.update_time(unix_secs);
+            }
+            DisplayRequest::Notify(message) => {
+                debug!(" -> Notify: {}", message);
+                self.notify(message);
+            }
+            DisplayRequest::LogLine(level, line) => {
+                debug!(" -> LogLine");
+                self.log_line(level, line);
+            }
+            DisplayRequest::UpdateSensorStalled(stalled) => {
+                debug!(" -> UpdateSensorStalled");
+                self.update_sensor_stalled(stalled);
+            }
+            DisplayRequest::UpdateTimeStatus(status) => {
+                debug!(" -> UpdateTimeStatus: {:?}", status);
+                self.update_time_status(status);
+            }
+            DisplayRequest::UpdateStoragePersistence(persistent) => {
+                debug!(" -> UpdateStoragePersistence: {}", persistent);
+                self.update_storage_persistence(persistent);
+            }
+            DisplayRequest::UpdateRunState(run_state) => {
+                debug!(" -> UpdateRunState: {:?}", run_state);
+                self.update_run_state(run_state);
+                // The splash page asks to leave once the sensors are running
+                if let Some(Action::NavigateToPage(page_id)) =
+                    Page::take_action(&mut self.current_page)
+                {
+                    self.navigate_to(page_id, app_state).await;
+                }
+            }
+            DisplayRequest::LoadHistory {
+                sensor,
+                window,
+                tier,
+                data,
+            } => {
+                debug!(" -> LoadHistory: {:?}/{:?}", sensor, window);
+                self.load_history(sensor, window, tier, &data);
+            }
+            DisplayRequest::ShowError(app_error) => {
+                debug!(" -> ShowError: {:?}", app_error);
+                self.show_error(&app_error);
+            }
         }
 
         // Auto-cycle logic (Home grid mode only)
@@ -614,7 +1029,7 @@ where
                 .saturating_sub(self.auto_cycle_last_switch)
                 >= AUTO_CYCLE_INTERVAL_SECS
         {
-            if self.all_sensors_healthy {
+            if self.all_sensors_healthy && self.sensor_health.overall() == SensorHealth::Ok {
                 // Cycle to next trend page
                 let target = AUTO_CYCLE_PAGES[self.auto_cycle_index % AUTO_CYCLE_PAGES.len()];
                 self.auto_cycle_index = (self.auto_cycle_index + 1) % AUTO_CYCLE_PAGES.len();
@@ -631,6 +1046,9 @@ where
             }
         }
 
+        self.update_toasts();
+        self.update_tick();
+
         // Render if needed
         if self.needs_redraw {
             debug!(" Rendering page");
@@ -660,9 +1078,31 @@ where
         }
 
         loop {
-            // Wait for a display request
+            // Wait for a display request, waking early to dismiss a toast or
+            // tick a page that asked for one
             debug!(" Display manager: Waiting for request...");
-            let request = receiver.receive().await;
+            let deadline_ms = match (self.toasts.deadline_ms(), self.tick_deadline_ms()) {
+                (Some(toast_ms), Some(tick_ms)) => Some(toast_ms.min(tick_ms)),
+                (toast_ms, tick_ms) => toast_ms.or(tick_ms),
+            };
+            let request = match deadline_ms {
+                Some(deadline_ms) => {
+                    let wait_ms = deadline_ms.saturating_sub(Instant::now().as_millis());
+                    with_timeout(Duration::from_millis(wait_ms), receiver.receive())
+                        .await
+                        .ok()
+                }
+                None => Some(receiver.receive().await),
+            };
+
+            let Some(request) = request else {
+                self.update_toasts();
+                self.update_tick();
+                if let Err(e) = self.render() {
+                    error!(" Display render error: {:?}", e);
+                }
+                continue;
+            };
             debug!(" Display manager: Received request: {:?}", request);
 
             // Process the request
@@ -673,6 +1113,27 @@ where
     }
 }
 
+/// Apply `update` to the device config in app state and save it so it
+/// survives a reboot
+///
+/// A failed save is only logged; the new setting stays in effect until then.
+fn persist_device_config<SD, DD, TD>(
+    state: &mut AppState<'static, SD, DD, TD>,
+    update: impl FnOnce(&mut DeviceConfig),
+) where
+    SD: embedded_hal::spi::SpiDevice<u8>,
+    DD: embedded_hal::delay::DelayNs,
+    TD: embedded_sdmmc::TimeSource,
+{
+    update(&mut state.device_config);
+    let config = state.device_config;
+    if let Some(storage) = state.storage_manager()
+        && let Err(e) = storage.save_device_config(&config)
+    {
+        error!(" Failed to persist device config: {:?}", e);
+    }
+}
+
 /// Helper to get a display request sender
 pub fn get_display_sender()
 -> Sender<'static, CriticalSectionRawMutex, DisplayRequest, PAGE_CHANGE_CAPACITY> {
@@ -684,3 +1145,122 @@ pub fn get_display_receiver()
 -> Receiver<'static, CriticalSectionRawMutex, DisplayRequest, PAGE_CHANGE_CAPACITY> {
     DISPLAY_CHANNEL.receiver()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::test_harness::screen_bounds;
+    use alloc::vec::Vec;
+
+    /// Page asking for a tick every `interval`, recording when it was updated
+    struct TickingPage {
+        interval: Option<Duration>,
+        updated_at_ms: Vec<u64>,
+        now_ms: u64,
+    }
+
+    impl TickingPage {
+        fn new(interval: Option<Duration>) -> Self {
+            Self {
+                interval,
+                updated_at_ms: Vec::new(),
+                now_ms: 0,
+            }
+        }
+    }
+
+    impl Page for TickingPage {
+        fn id(&self) -> PageId {
+            PageId::Splash
+        }
+
+        fn title(&self) -> &str {
+            "Ticking"
+        }
+
+        fn handle_touch(&mut self, _event: TouchEvent) -> Option<Action> {
+            None
+        }
+
+        fn update(&mut self) {
+            self.updated_at_ms.push(self.now_ms);
+        }
+
+        fn wants_tick(&self) -> Option<Duration> {
+            self.interval
+        }
+
+        fn draw_page<DT: DrawTarget<Color = Rgb565>>(
+            &mut self,
+            _display: &mut DT,
+        ) -> Result<(), DT::Error> {
+            Ok(())
+        }
+
+        fn bounds(&self) -> Rectangle {
+            screen_bounds()
+        }
+
+        fn is_dirty(&self) -> bool {
+            false
+        }
+
+        fn mark_clean(&mut self) {}
+
+        fn mark_dirty(&mut self) {}
+    }
+
+    /// Poll `page` every `step_ms` for `duration_ms`, ticking it when due
+    fn run_polling(page: &mut TickingPage, step_ms: u64, duration_ms: u64) {
+        let mut ticks = TickSchedule::default();
+        for now_ms in (0..=duration_ms).step_by(step_ms as usize) {
+            page.now_ms = now_ms;
+            if ticks.update(page.wants_tick(), now_ms) {
+                page.update();
+            }
+        }
+    }
+
+    #[test]
+    fn page_requesting_100ms_is_ticked_every_100ms() {
+        let mut page = TickingPage::new(Some(Duration::from_millis(100)));
+        run_polling(&mut page, 10, 1_000);
+
+        let expected: Vec<u64> = (1..=10).map(|i| i * 100).collect();
+        assert_eq!(page.updated_at_ms, expected);
+    }
+
+    #[test]
+    fn sleeping_until_each_deadline_keeps_the_cadence() {
+        // The display loop sleeps until the deadline rather than polling
+        let mut page = TickingPage::new(Some(Duration::from_millis(100)));
+        let mut ticks = TickSchedule::default();
+        for _ in 0..5 {
+            let now_ms = ticks.deadline_ms(page.wants_tick()).unwrap();
+            page.now_ms = now_ms;
+            assert!(ticks.update(page.wants_tick(), now_ms));
+            page.update();
+            // Not due again until a full interval later
+            assert!(!ticks.update(page.wants_tick(), now_ms + 99));
+        }
+        assert_eq!(page.updated_at_ms, [100, 200, 300, 400, 500]);
+    }
+
+    #[test]
+    fn late_ticks_restart_the_interval_without_bunching() {
+        let mut page = TickingPage::new(Some(Duration::from_millis(100)));
+        run_polling(&mut page, 70, 700);
+
+        // Due at 100, seen at 140; then due at 240, seen at 280; ...
+        assert_eq!(page.updated_at_ms, [140, 280, 420, 560, 700]);
+    }
+
+    #[test]
+    fn pages_without_a_tick_interval_are_never_ticked() {
+        let mut page = TickingPage::new(None);
+        run_polling(&mut page, 10, 1_000);
+
+        assert!(page.updated_at_ms.is_empty());
+        assert_eq!(TickSchedule::default().deadline_ms(None), None);
+    }
+}
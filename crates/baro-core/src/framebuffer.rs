@@ -1,14 +1,16 @@
 //! PSRAM-backed framebuffer with per-pixel change detection.
 //!
-//! All page drawing targets this RAM buffer instead of the SPI display.
-//! After drawing completes, only the rectangular region containing changed
-//! pixels is flushed to the hardware display in a single SPI transaction.
+//! All page drawing targets this RAM buffer instead of the SPI display, so a
+//! half-drawn frame is never visible. After drawing completes, only the
+//! rectangular region containing changed pixels is flushed to the hardware
+//! display in a single SPI transaction, decoupling drawing from SPI timing.
 
 extern crate alloc;
 
 use alloc::vec;
 use alloc::vec::Vec;
 use core::convert::Infallible;
+use core::ops::Range;
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::Rectangle;
@@ -16,123 +18,181 @@ use log::debug;
 
 use crate::ui::{DISPLAY_HEIGHT_PX, DISPLAY_WIDTH_PX};
 
-/// Total number of pixels in the framebuffer (320 x 240 = 76,800).
-const PIXEL_COUNT: usize = DISPLAY_WIDTH_PX as usize * DISPLAY_HEIGHT_PX as usize;
-
-/// Bounding box of pixels that have changed since the last flush.
-#[derive(Debug, Clone, Copy)]
-struct DirtyRect {
+/// Horizontal extent of changed pixels within one row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RowSpan {
     min_x: usize,
-    min_y: usize,
     max_x: usize,
-    max_y: usize,
 }
 
-impl DirtyRect {
-    /// Expand the dirty region to include the given pixel coordinate.
-    fn expand(&mut self, x: usize, y: usize) {
+impl RowSpan {
+    /// Expand the span to include the given column.
+    fn expand(&mut self, x: usize) {
         self.min_x = self.min_x.min(x);
-        self.min_y = self.min_y.min(y);
         self.max_x = self.max_x.max(x);
-        self.max_y = self.max_y.max(y);
-    }
-
-    /// Create a new dirty rect covering a single pixel.
-    fn from_point(x: usize, y: usize) -> Self {
-        Self {
-            min_x: x,
-            min_y: y,
-            max_x: x,
-            max_y: y,
-        }
     }
 }
 
 /// PSRAM-backed framebuffer implementing `DrawTarget<Color = Rgb565>`.
 ///
-/// Heap-allocates a 320x240x2 = 153,600-byte pixel buffer (1.8% of 8MB PSRAM).
-/// Tracks a dirty bounding box so that only changed pixels are flushed to the
-/// hardware display.
-pub struct FrameBuffer {
+/// Sized by `WIDTH` x `HEIGHT` (defaulting to the display). At 320x240 it
+/// heap-allocates a 153,600-byte pixel buffer (1.8% of 8MB PSRAM).
+/// Tracks the changed column span of every row so that only changed pixels
+/// are flushed to the hardware display, either all at once (`flush`) or
+/// limited to a region (`flush_region`).
+pub struct FrameBuffer<
+    const WIDTH: usize = { DISPLAY_WIDTH_PX as usize },
+    const HEIGHT: usize = { DISPLAY_HEIGHT_PX as usize },
+> {
     pixels: Vec<Rgb565>,
-    dirty: Option<DirtyRect>,
+    dirty_rows: Vec<Option<RowSpan>>,
 }
 
-impl Default for FrameBuffer {
+impl<const WIDTH: usize, const HEIGHT: usize> Default for FrameBuffer<WIDTH, HEIGHT> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl FrameBuffer {
+impl<const WIDTH: usize, const HEIGHT: usize> FrameBuffer<WIDTH, HEIGHT> {
     /// Allocate a new framebuffer filled with black pixels.
     ///
     /// The allocation lands in PSRAM via the global allocator.
     pub fn new() -> Self {
         Self {
-            pixels: vec![Rgb565::BLACK; PIXEL_COUNT],
-            dirty: None,
+            pixels: vec![Rgb565::BLACK; WIDTH * HEIGHT],
+            dirty_rows: vec![None; HEIGHT],
         }
     }
 
-    /// Write a single pixel, expanding the dirty rect only if the color changed.
+    /// Color of the pixel at `(x, y)`, or `None` if out of bounds.
+    pub fn pixel(&self, x: usize, y: usize) -> Option<Rgb565> {
+        (x < WIDTH && y < HEIGHT).then(|| self.pixels[y * WIDTH + x])
+    }
+
+    /// Rows touched since the last flush, or `None` if nothing changed.
+    pub fn dirty_rows(&self) -> Option<Range<usize>> {
+        let first = self.dirty_rows.iter().position(Option::is_some)?;
+        let last = self.dirty_rows.iter().rposition(Option::is_some)?;
+        Some(first..last + 1)
+    }
+
+    /// Write a single pixel, expanding its row's dirty span only if the color changed.
     #[inline]
     fn set_pixel(&mut self, x: usize, y: usize, color: Rgb565) {
-        let idx = y * DISPLAY_WIDTH_PX as usize + x;
+        let idx = y * WIDTH + x;
         if self.pixels[idx] != color {
             self.pixels[idx] = color;
-            match &mut self.dirty {
-                Some(rect) => rect.expand(x, y),
-                None => self.dirty = Some(DirtyRect::from_point(x, y)),
+            match &mut self.dirty_rows[y] {
+                Some(span) => span.expand(x),
+                row => *row = Some(RowSpan { min_x: x, max_x: x }),
             }
         }
     }
 
-    /// Flush the dirty region to a hardware display, then reset the dirty state.
+    /// Flush every changed pixel to a hardware display, then reset the dirty state.
     ///
     /// Only the bounding rectangle of changed pixels is sent over SPI via
-    /// `fill_contiguous`. If nothing changed, this is a no-op.
-    pub fn flush<D>(&mut self, display: &mut D) -> Result<(), D::Error>
+    /// `fill_contiguous`. Returns the span of rows that was transferred, or
+    /// `None` (and sends nothing) if no pixel changed.
+    pub fn flush<D>(&mut self, display: &mut D) -> Result<Option<Range<usize>>, D::Error>
+    where
+        D: DrawTarget<Color = Rgb565>,
+    {
+        let full = Rectangle::new(Point::zero(), Size::new(WIDTH as u32, HEIGHT as u32));
+        let area = self.flush_region(display, full)?;
+        Ok(area.map(|area| {
+            let top = area.top_left.y as usize;
+            top..top + area.size.height as usize
+        }))
+    }
+
+    /// Flush the changed pixels inside `region` to a hardware display.
+    ///
+    /// Sends the bounding rectangle of changed pixels within `region` in a
+    /// single `fill_contiguous` call and returns it, or `None` if nothing in
+    /// the region changed. Rows whose changes lie entirely inside the region
+    /// are marked clean; rows with changes extending past it stay dirty for a
+    /// later flush.
+    pub fn flush_region<D>(
+        &mut self,
+        display: &mut D,
+        region: Rectangle,
+    ) -> Result<Option<Rectangle>, D::Error>
     where
         D: DrawTarget<Color = Rgb565>,
     {
-        let Some(rect) = self.dirty.take() else {
-            return Ok(());
+        let x_start = (region.top_left.x.max(0) as usize).min(WIDTH);
+        let y_start = (region.top_left.y.max(0) as usize).min(HEIGHT);
+        let x_end = (region.top_left.x.max(0) as usize)
+            .saturating_add(region.size.width as usize)
+            .min(WIDTH);
+        let y_end = (region.top_left.y.max(0) as usize)
+            .saturating_add(region.size.height as usize)
+            .min(HEIGHT);
+        if x_start >= x_end || y_start >= y_end {
+            return Ok(None);
+        }
+
+        // Bounding box of the dirty spans clipped to the region
+        let mut bounds: Option<(usize, usize, usize, usize)> = None;
+        for (y, span) in self.dirty_rows[y_start..y_end].iter().enumerate() {
+            let Some(span) = span else { continue };
+            let min_x = span.min_x.max(x_start);
+            let max_x = span.max_x.min(x_end - 1);
+            if min_x > max_x {
+                continue;
+            }
+            let y = y_start + y;
+            bounds = Some(match bounds {
+                Some((x0, y0, x1, _)) => (x0.min(min_x), y0, x1.max(max_x), y),
+                None => (min_x, y, max_x, y),
+            });
+        }
+        let Some((min_x, min_y, max_x, max_y)) = bounds else {
+            return Ok(None);
         };
 
-        let width = rect.max_x - rect.min_x + 1;
-        let height = rect.max_y - rect.min_y + 1;
+        let width = max_x - min_x + 1;
+        let height = max_y - min_y + 1;
 
         debug!(
             "Flushing {}x{} dirty region at ({}, {})",
-            width, height, rect.min_x, rect.min_y
+            width, height, min_x, min_y
         );
 
         let area = Rectangle::new(
-            Point::new(rect.min_x as i32, rect.min_y as i32),
+            Point::new(min_x as i32, min_y as i32),
             Size::new(width as u32, height as u32),
         );
 
         // Borrow the pixel slice so the closure captures a shared reference,
         // avoiding the `FnMut` escaping-reference issue with `&mut self`.
         let pixels = &self.pixels;
-        let stride = DISPLAY_WIDTH_PX as usize;
-        let pixel_iter = (rect.min_y..=rect.max_y).flat_map(move |y| {
-            let row_start = y * stride + rect.min_x;
+        let pixel_iter = (min_y..=max_y).flat_map(move |y| {
+            let row_start = y * WIDTH + min_x;
             pixels[row_start..row_start + width].iter().copied()
         });
 
-        display.fill_contiguous(&area, pixel_iter)
+        display.fill_contiguous(&area, pixel_iter)?;
+
+        for row in &mut self.dirty_rows[min_y..=max_y] {
+            if row.is_some_and(|span| span.min_x >= x_start && span.max_x < x_end) {
+                *row = None;
+            }
+        }
+
+        Ok(Some(area))
     }
 }
 
-impl OriginDimensions for FrameBuffer {
+impl<const WIDTH: usize, const HEIGHT: usize> OriginDimensions for FrameBuffer<WIDTH, HEIGHT> {
     fn size(&self) -> Size {
-        Size::new(DISPLAY_WIDTH_PX as u32, DISPLAY_HEIGHT_PX as u32)
+        Size::new(WIDTH as u32, HEIGHT as u32)
     }
 }
 
-impl DrawTarget for FrameBuffer {
+impl<const WIDTH: usize, const HEIGHT: usize> DrawTarget for FrameBuffer<WIDTH, HEIGHT> {
     type Color = Rgb565;
     type Error = Infallible;
 
@@ -140,8 +200,8 @@ impl DrawTarget for FrameBuffer {
     where
         I: IntoIterator<Item = Pixel<Self::Color>>,
     {
-        let w = DISPLAY_WIDTH_PX as usize;
-        let h = DISPLAY_HEIGHT_PX as usize;
+        let w = WIDTH;
+        let h = HEIGHT;
 
         for Pixel(coord, color) in pixels {
             let x = coord.x;
@@ -157,8 +217,8 @@ impl DrawTarget for FrameBuffer {
     where
         I: IntoIterator<Item = Self::Color>,
     {
-        let w = DISPLAY_WIDTH_PX as usize;
-        let h = DISPLAY_HEIGHT_PX as usize;
+        let w = WIDTH;
+        let h = HEIGHT;
 
         // Clamp the area to display bounds
         let area_x = area.top_left.x.max(0) as usize;
@@ -183,8 +243,8 @@ impl DrawTarget for FrameBuffer {
     }
 
     fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
-        let w = DISPLAY_WIDTH_PX as usize;
-        let h = DISPLAY_HEIGHT_PX as usize;
+        let w = WIDTH;
+        let h = HEIGHT;
 
         let x_start = (area.top_left.x.max(0) as usize).min(w);
         let y_start = (area.top_left.y.max(0) as usize).min(h);
@@ -200,8 +260,8 @@ impl DrawTarget for FrameBuffer {
     }
 
     fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
-        let w = DISPLAY_WIDTH_PX as usize;
-        let h = DISPLAY_HEIGHT_PX as usize;
+        let w = WIDTH;
+        let h = HEIGHT;
 
         for y in 0..h {
             for x in 0..w {
@@ -211,3 +271,134 @@ impl DrawTarget for FrameBuffer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::primitives::PrimitiveStyle;
+
+    type SmallBuffer = FrameBuffer<16, 12>;
+
+    fn fill(fb: &mut SmallBuffer, area: Rectangle, color: Rgb565) {
+        area.into_styled(PrimitiveStyle::with_fill(color))
+            .draw(fb)
+            .unwrap();
+    }
+
+    #[test]
+    fn drawing_a_rectangle_sets_exactly_its_pixels() {
+        let mut fb = SmallBuffer::new();
+        let area = Rectangle::new(Point::new(3, 2), Size::new(4, 5));
+        fill(&mut fb, area, Rgb565::RED);
+
+        for y in 0..12 {
+            for x in 0..16 {
+                let expected = if area.contains(Point::new(x as i32, y as i32)) {
+                    Rgb565::RED
+                } else {
+                    Rgb565::BLACK
+                };
+                assert_eq!(fb.pixel(x, y), Some(expected), "pixel ({x}, {y})");
+            }
+        }
+        assert_eq!(fb.pixel(16, 0), None);
+    }
+
+    #[test]
+    fn flush_reports_dirty_rows_and_sends_changed_pixels() {
+        let mut fb = SmallBuffer::new();
+        fill(
+            &mut fb,
+            Rectangle::new(Point::new(3, 2), Size::new(4, 5)),
+            Rgb565::RED,
+        );
+        assert_eq!(fb.dirty_rows(), Some(2..7));
+
+        let mut display = SmallBuffer::new();
+        assert_eq!(fb.flush(&mut display).unwrap(), Some(2..7));
+        assert_eq!(display.pixel(3, 2), Some(Rgb565::RED));
+        assert_eq!(display.pixel(6, 6), Some(Rgb565::RED));
+        assert_eq!(display.pixel(7, 6), Some(Rgb565::BLACK));
+
+        // Everything was sent, so a second flush has nothing to do
+        assert_eq!(fb.dirty_rows(), None);
+        assert_eq!(fb.flush(&mut display).unwrap(), None);
+    }
+
+    #[test]
+    fn redrawing_the_same_color_leaves_buffer_clean() {
+        let mut fb = SmallBuffer::new();
+        fb.clear(Rgb565::BLACK).unwrap();
+        assert_eq!(fb.dirty_rows(), None);
+    }
+
+    #[test]
+    fn band_change_marks_only_its_rows() {
+        let mut fb: FrameBuffer = FrameBuffer::new();
+        let band = Rectangle::new(Point::new(0, 100), Size::new(320, 40));
+        band.into_styled(PrimitiveStyle::with_fill(Rgb565::BLUE))
+            .draw(&mut fb)
+            .unwrap();
+        assert_eq!(fb.dirty_rows(), Some(100..140));
+
+        let mut display: FrameBuffer = FrameBuffer::new();
+        let full = Rectangle::new(Point::zero(), Size::new(320, 240));
+        assert_eq!(fb.flush_region(&mut display, full).unwrap(), Some(band));
+    }
+
+    #[test]
+    fn flush_region_sends_only_changes_inside_region() {
+        let mut fb = SmallBuffer::new();
+        fill(
+            &mut fb,
+            Rectangle::new(Point::new(2, 1), Size::new(2, 2)),
+            Rgb565::RED,
+        );
+        fill(
+            &mut fb,
+            Rectangle::new(Point::new(10, 8), Size::new(3, 2)),
+            Rgb565::GREEN,
+        );
+
+        let mut display = SmallBuffer::new();
+        let top = Rectangle::new(Point::zero(), Size::new(16, 6));
+        assert_eq!(
+            fb.flush_region(&mut display, top).unwrap(),
+            Some(Rectangle::new(Point::new(2, 1), Size::new(2, 2)))
+        );
+        assert_eq!(display.pixel(10, 8), Some(Rgb565::BLACK));
+
+        // The lower change is still pending
+        assert_eq!(fb.dirty_rows(), Some(8..10));
+        assert_eq!(fb.flush(&mut display).unwrap(), Some(8..10));
+        assert_eq!(display.pixel(12, 9), Some(Rgb565::GREEN));
+    }
+
+    #[test]
+    fn rows_extending_past_region_stay_dirty() {
+        let mut fb = SmallBuffer::new();
+        fill(
+            &mut fb,
+            Rectangle::new(Point::new(0, 4), Size::new(16, 1)),
+            Rgb565::RED,
+        );
+
+        let mut display = SmallBuffer::new();
+        let left = Rectangle::new(Point::zero(), Size::new(8, 12));
+        assert_eq!(
+            fb.flush_region(&mut display, left).unwrap(),
+            Some(Rectangle::new(Point::new(0, 4), Size::new(8, 1)))
+        );
+        assert_eq!(fb.dirty_rows(), Some(4..5));
+    }
+
+    #[test]
+    fn region_outside_buffer_flushes_nothing() {
+        let mut fb = SmallBuffer::new();
+        fb.clear(Rgb565::RED).unwrap();
+
+        let mut display = SmallBuffer::new();
+        let outside = Rectangle::new(Point::new(20, 20), Size::new(4, 4));
+        assert_eq!(fb.flush_region(&mut display, outside).unwrap(), None);
+    }
+}
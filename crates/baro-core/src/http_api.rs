@@ -0,0 +1,626 @@
+//! Minimal HTTP API for reading the device over the network
+//!
+//! The firmware owns the TCP listener; this module parses request lines and
+//! renders response bodies so the protocol logic stays platform-independent.
+//! JSON is written by hand to avoid pulling serde into the request path.
+//!
+//! Routes:
+//! - `GET /api/current` — latest raw sample as a JSON object
+//! - `GET /api/history?window=1h` — rollups covering the window as a JSON array
+//! - `GET /metrics` — current readings and lifetime counters in Prometheus text format
+//! - `GET /logs` — recent lines from the device log as a JSON array
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::log_ring::{LogRing, write_log_entry_json};
+use crate::metrics::fixed::to_float;
+use crate::sensors::{CO2, HUMIDITY, LUX, TEMPERATURE};
+use crate::storage::backend::StorageBackend;
+use crate::storage::manager::{OutOfOrderCounts, StorageManager};
+use crate::storage::{LifetimeStats, MISSING_SENSOR_VALUE, RawSample, Rollup, TimeWindow};
+use crate::ui::core::SensorData;
+
+/// Windows accepted by `/api/history`, matched against `TimeWindow::label`
+const HISTORY_WINDOWS: [TimeWindow; 7] = [
+    TimeWindow::OneMinute,
+    TimeWindow::FiveMinutes,
+    TimeWindow::ThirtyMinutes,
+    TimeWindow::OneHour,
+    TimeWindow::TwelveHours,
+    TimeWindow::OneDay,
+    TimeWindow::OneWeek,
+];
+
+/// Content type of JSON responses
+const CONTENT_TYPE_JSON: &str = "application/json";
+
+/// Content type of the Prometheus text exposition format
+const CONTENT_TYPE_PROMETHEUS: &str = "text/plain; version=0.0.4";
+
+/// Window used by `/api/history` when none is given
+const DEFAULT_HISTORY_WINDOW: TimeWindow = TimeWindow::OneHour;
+
+/// A parsed API request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiRoute {
+    /// `GET /api/current`
+    Current,
+    /// `GET /api/history?window=<label>`
+    History(TimeWindow),
+    /// `GET /metrics`
+    Metrics,
+    /// `GET /logs`
+    Logs,
+    /// Unknown path or unsupported method
+    NotFound,
+    /// `/api/history` with an unrecognized window
+    BadRequest,
+    /// Request line that is not ASCII, incomplete, or missing its target
+    MalformedRequest,
+}
+
+impl ApiRoute {
+    /// Content type of this route's response body
+    pub const fn content_type(self) -> &'static str {
+        match self {
+            Self::Metrics => CONTENT_TYPE_PROMETHEUS,
+            _ => CONTENT_TYPE_JSON,
+        }
+    }
+}
+
+/// HTTP status codes the API responds with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpStatus {
+    Ok,
+    BadRequest,
+    NotFound,
+    ServiceUnavailable,
+}
+
+impl HttpStatus {
+    /// Status line text (code and reason phrase)
+    pub const fn status_line(self) -> &'static str {
+        match self {
+            Self::Ok => "200 OK",
+            Self::BadRequest => "400 Bad Request",
+            Self::NotFound => "404 Not Found",
+            Self::ServiceUnavailable => "503 Service Unavailable",
+        }
+    }
+}
+
+/// Route a raw HTTP request from the bytes received so far
+///
+/// Only the request line is inspected; headers and any body after the first
+/// `\n` are ignored, so they may hold arbitrary bytes. A request line that is
+/// not ASCII or has no terminating newline within `request` is malformed.
+pub fn parse_request(request: &[u8]) -> ApiRoute {
+    let Some(end) = request.iter().position(|&b| b == b'\n') else {
+        return ApiRoute::MalformedRequest;
+    };
+    let line = &request[..end];
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    if !line.is_ascii() {
+        return ApiRoute::MalformedRequest;
+    }
+
+    match core::str::from_utf8(line) {
+        Ok(line) => parse_request_line(line),
+        Err(_) => ApiRoute::MalformedRequest,
+    }
+}
+
+/// Route the first line of an HTTP request (e.g. `GET /api/current HTTP/1.1`)
+pub fn parse_request_line(line: &str) -> ApiRoute {
+    let mut parts = line.split_ascii_whitespace();
+    let (Some(method), Some(target), Some(_version)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return ApiRoute::MalformedRequest;
+    };
+    if method != "GET" {
+        return ApiRoute::NotFound;
+    }
+
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    match path {
+        "/api/current" => ApiRoute::Current,
+        "/metrics" => ApiRoute::Metrics,
+        "/logs" => ApiRoute::Logs,
+        "/api/history" => {
+            let window = query
+                .split('&')
+                .find_map(|pair| pair.strip_prefix("window="));
+            match window {
+                None => ApiRoute::History(DEFAULT_HISTORY_WINDOW),
+                Some(label) => HISTORY_WINDOWS
+                    .into_iter()
+                    .find(|w| w.label() == label)
+                    .map_or(ApiRoute::BadRequest, ApiRoute::History),
+            }
+        }
+        _ => ApiRoute::NotFound,
+    }
+}
+
+/// Build the status and body for a routed request
+///
+/// `storage` is `None` until the storage manager is available, in which case
+/// JSON data routes answer 503 and `/metrics` reports no samples. `/logs` is
+/// served from `log` whether or not storage is up.
+pub fn respond<B: StorageBackend, const N: usize>(
+    route: ApiRoute,
+    storage: Option<&StorageManager<B>>,
+    log: &LogRing<N>,
+) -> (HttpStatus, String) {
+    let mut body = String::new();
+    let status = match (route, storage) {
+        (ApiRoute::NotFound, _) => {
+            body.push_str("{\"error\":\"not found\"}");
+            HttpStatus::NotFound
+        }
+        (ApiRoute::BadRequest, _) => {
+            body.push_str("{\"error\":\"unknown window\"}");
+            HttpStatus::BadRequest
+        }
+        (ApiRoute::MalformedRequest, _) => {
+            body.push_str("{\"error\":\"malformed request\"}");
+            HttpStatus::BadRequest
+        }
+        (ApiRoute::Current, Some(storage)) => match storage.latest_sample() {
+            Some(sample) => {
+                write_sensor_data_json(&mut body, &sensor_data_from_sample(sample));
+                HttpStatus::Ok
+            }
+            None => {
+                body.push_str("{\"error\":\"no readings yet\"}");
+                HttpStatus::ServiceUnavailable
+            }
+        },
+        (ApiRoute::Metrics, storage) => {
+            let current = storage
+                .and_then(|s| s.latest_sample())
+                .map(sensor_data_from_sample);
+            let lifetime = storage.map(|s| s.get_lifetime_stats());
+            let out_of_order = storage.map(|s| s.out_of_order_counts());
+            write_metrics(&mut body, current.as_ref(), lifetime, out_of_order);
+            HttpStatus::Ok
+        }
+        (ApiRoute::Logs, _) => {
+            write_log_json(&mut body, log);
+            HttpStatus::Ok
+        }
+        (ApiRoute::History(window), Some(storage)) => {
+            write_rollups_json(&mut body, &storage.rollups_in_window(window));
+            HttpStatus::Ok
+        }
+        (_, None) => {
+            body.push_str("{\"error\":\"storage unavailable\"}");
+            HttpStatus::ServiceUnavailable
+        }
+    };
+    (status, body)
+}
+
+/// Write every line held by `log` as a JSON array, oldest first
+///
+/// Each line is an object like
+/// `{"ts":1700000000,"level":"info","msg":"[WiFi] Reconnected"}`.
+pub fn write_log_json<const N: usize>(out: &mut String, log: &LogRing<N>) {
+    // Copy under the lock, format outside it
+    let lines = log.lines_since(0);
+
+    out.push('[');
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let _ = write_log_entry_json(out, line.timestamp, line.level, &line.message);
+    }
+    out.push(']');
+}
+
+/// Render a complete HTTP/1.1 response
+///
+/// The connection is closed after each response, so no keep-alive handling is needed.
+pub fn write_response(out: &mut String, status: HttpStatus, content_type: &str, body: &str) {
+    let _ = write!(
+        out,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status.status_line(),
+        content_type,
+        body.len(),
+        body
+    );
+}
+
+/// Convert a raw sample into display units (missing sensors become `None`)
+pub fn sensor_data_from_sample(sample: &RawSample) -> SensorData {
+    SensorData {
+        temperature: milli_to_float(sample.values[TEMPERATURE]),
+        humidity: milli_to_float(sample.values[HUMIDITY]),
+        co2: milli_to_float(sample.values[CO2]),
+        lux: milli_to_float(sample.values[LUX]),
+        timestamp: sample.timestamp as u64,
+    }
+}
+
+/// Write a `SensorData` as a JSON object
+///
+/// Missing readings are written as `null`, e.g.
+/// `{"timestamp":1700000000,"temperature":22.50,"humidity":45.20,"co2":415.00,"lux":null}`.
+pub fn write_sensor_data_json(out: &mut String, data: &SensorData) {
+    let _ = write!(out, "{{\"timestamp\":{}", data.timestamp);
+    write_float_field(out, "temperature", data.temperature);
+    write_float_field(out, "humidity", data.humidity);
+    write_float_field(out, "co2", data.co2);
+    write_float_field(out, "lux", data.lux);
+    out.push('}');
+}
+
+/// Write rollups as a JSON array of per-window averages, minima, and maxima
+///
+/// Each element looks like
+/// `{"start_ts":1700000000,"samples":30,"temperature":{"avg":22.500,"min":22.000,"max":23.000},...}`.
+pub fn write_rollups_json(out: &mut String, rollups: &[Rollup]) {
+    out.push('[');
+    for (i, rollup) in rollups.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let _ = write!(
+            out,
+            "{{\"start_ts\":{},\"samples\":{}",
+            rollup.start_ts, rollup.sample_count
+        );
+        for (name, index) in [
+            ("temperature", TEMPERATURE),
+            ("humidity", HUMIDITY),
+            ("co2", CO2),
+            ("lux", LUX),
+        ] {
+            let _ = write!(out, ",\"{}\":", name);
+            if rollup.avg[index] == MISSING_SENSOR_VALUE {
+                out.push_str("null");
+                continue;
+            }
+            out.push_str("{\"avg\":");
+            write_milli(out, rollup.avg[index]);
+            out.push_str(",\"min\":");
+            write_milli(out, rollup.min[index]);
+            out.push_str(",\"max\":");
+            write_milli(out, rollup.max[index]);
+            out.push('}');
+        }
+        out.push('}');
+    }
+    out.push(']');
+}
+
+/// Write current readings and lifetime counters in Prometheus text format
+///
+/// A metric family is omitted entirely when its value is unavailable (missing
+/// sensor, no sample yet, or no storage), rather than reported as 0.
+pub fn write_metrics(
+    out: &mut String,
+    current: Option<&SensorData>,
+    lifetime: Option<&LifetimeStats>,
+    out_of_order: Option<OutOfOrderCounts>,
+) {
+    if let Some(data) = current {
+        for (name, help, value) in [
+            (
+                "baro_temperature_celsius",
+                "Current air temperature in degrees Celsius.",
+                data.temperature,
+            ),
+            (
+                "baro_humidity_percent",
+                "Current relative humidity in percent.",
+                data.humidity,
+            ),
+            (
+                "baro_co2_ppm",
+                "Current CO2 concentration in ppm.",
+                data.co2,
+            ),
+            (
+                "baro_illuminance_lux",
+                "Current ambient light level in lux.",
+                data.lux,
+            ),
+        ] {
+            if let Some(value) = value.filter(|v| v.is_finite()) {
+                write_metric(out, name, help, "gauge", value);
+            }
+        }
+        write_metric(
+            out,
+            "baro_sample_timestamp_seconds",
+            "Unix time of the latest sample.",
+            "gauge",
+            data.timestamp,
+        );
+    }
+
+    if let Some(stats) = lifetime {
+        write_metric(
+            out,
+            "baro_total_samples",
+            "Samples recorded since the lifetime counters were reset.",
+            "counter",
+            stats.total_samples,
+        );
+    }
+
+    if let Some(counts) = out_of_order {
+        write_metric(
+            out,
+            "baro_out_of_order_records_total",
+            "Duplicate or out-of-order samples and rollups dropped since boot.",
+            "counter",
+            counts.total(),
+        );
+    }
+}
+
+fn write_metric(
+    out: &mut String,
+    name: &str,
+    help: &str,
+    kind: &str,
+    value: impl core::fmt::Display,
+) {
+    let _ = write!(
+        out,
+        "# HELP {name} {help}\n# TYPE {name} {kind}\n{name} {value}\n"
+    );
+}
+
+/// Write a milli-unit integer as a fixed three-decimal number (no float rounding)
+fn write_milli(out: &mut String, milli: i32) {
+    let sign = if milli < 0 { "-" } else { "" };
+    let abs = milli.unsigned_abs();
+    let _ = write!(out, "{}{}.{:03}", sign, abs / 1000, abs % 1000);
+}
+
+fn write_float_field(out: &mut String, name: &str, value: Option<f32>) {
+    let _ = write!(out, ",\"{}\":", name);
+    match value {
+        Some(v) if v.is_finite() => {
+            let _ = write!(out, "{:.2}", v);
+        }
+        _ => out.push_str("null"),
+    }
+}
+
+fn milli_to_float(value: i32) -> Option<f32> {
+    (value != MISSING_SENSOR_VALUE).then(|| to_float(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log_ring::LogLevel;
+    use crate::storage::MAX_SENSORS;
+    use crate::storage::backend::RamBackend;
+
+    fn rollup_with_temperature(start_ts: u32) -> Rollup {
+        let mut avg = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+        let mut min = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+        let mut max = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+        avg[TEMPERATURE] = 22_500;
+        min[TEMPERATURE] = -1_250;
+        max[TEMPERATURE] = 23_000;
+        Rollup::new(start_ts, &avg, &min, &max, 30)
+    }
+
+    #[test]
+    fn request_line_routes() {
+        assert_eq!(
+            parse_request_line("GET /api/current HTTP/1.1"),
+            ApiRoute::Current
+        );
+        assert_eq!(
+            parse_request_line("GET /api/history?window=1d HTTP/1.1"),
+            ApiRoute::History(TimeWindow::OneDay)
+        );
+        assert_eq!(
+            parse_request_line("GET /api/history HTTP/1.1"),
+            ApiRoute::History(DEFAULT_HISTORY_WINDOW)
+        );
+        assert_eq!(
+            parse_request_line("GET /api/history?window=2y HTTP/1.1"),
+            ApiRoute::BadRequest
+        );
+        assert_eq!(
+            parse_request_line("POST /api/current HTTP/1.1"),
+            ApiRoute::NotFound
+        );
+        assert_eq!(parse_request_line("GET /nope HTTP/1.1"), ApiRoute::NotFound);
+        assert_eq!(parse_request_line("GET"), ApiRoute::MalformedRequest);
+    }
+
+    #[test]
+    fn only_request_line_is_parsed_from_raw_bytes() {
+        // Header bytes after the request line need not be valid UTF-8
+        let request = b"GET /metrics HTTP/1.1\r\nX-Junk: \xFF\xFE\r\n\r\n";
+        assert_eq!(parse_request(request), ApiRoute::Metrics);
+    }
+
+    #[test]
+    fn bad_raw_requests_are_malformed() {
+        assert_eq!(parse_request(b""), ApiRoute::MalformedRequest);
+        assert_eq!(
+            parse_request(b"GET /api/current HTTP/1.1"),
+            ApiRoute::MalformedRequest
+        );
+        assert_eq!(
+            parse_request(b"GET /api/\xC3\xA9 HTTP/1.1\r\n"),
+            ApiRoute::MalformedRequest
+        );
+    }
+
+    #[test]
+    fn malformed_request_answers_400() {
+        let log: LogRing<4> = LogRing::new();
+        let (status, body) = respond::<RamBackend, 4>(ApiRoute::MalformedRequest, None, &log);
+
+        assert_eq!(status, HttpStatus::BadRequest);
+        assert_eq!(body, "{\"error\":\"malformed request\"}");
+    }
+
+    #[test]
+    fn current_without_samples_is_unavailable() {
+        let storage = StorageManager::with_backend(RamBackend::new());
+        let log: LogRing<4> = LogRing::new();
+        let (status, _) = respond(ApiRoute::Current, Some(&storage), &log);
+        assert_eq!(status, HttpStatus::ServiceUnavailable);
+    }
+
+    #[test]
+    fn sensor_data_serializes_with_nulls_for_missing_readings() {
+        let data = SensorData {
+            temperature: Some(22.5),
+            humidity: Some(45.2),
+            co2: Some(415.0),
+            lux: None,
+            timestamp: 1_700_000_000,
+        };
+        let mut out = String::new();
+        write_sensor_data_json(&mut out, &data);
+
+        assert_eq!(
+            out,
+            "{\"timestamp\":1700000000,\"temperature\":22.50,\"humidity\":45.20,\
+             \"co2\":415.00,\"lux\":null}"
+        );
+    }
+
+    #[test]
+    fn rollups_serialize_as_array() {
+        let mut out = String::new();
+        write_rollups_json(
+            &mut out,
+            &[
+                rollup_with_temperature(1_700_000_000),
+                rollup_with_temperature(1_700_000_300),
+            ],
+        );
+
+        let element = |ts: u32| {
+            alloc::format!(
+                "{{\"start_ts\":{ts},\"samples\":30,\
+                 \"temperature\":{{\"avg\":22.500,\"min\":-1.250,\"max\":23.000}},\
+                 \"humidity\":null,\"co2\":null,\"lux\":null}}"
+            )
+        };
+        assert_eq!(
+            out,
+            alloc::format!("[{},{}]", element(1_700_000_000), element(1_700_000_300))
+        );
+    }
+
+    #[test]
+    fn empty_rollup_slice_is_empty_array() {
+        let mut out = String::new();
+        write_rollups_json(&mut out, &[]);
+        assert_eq!(out, "[]");
+    }
+
+    #[test]
+    fn response_carries_route_content_type_and_length() {
+        let mut out = String::new();
+        write_response(
+            &mut out,
+            HttpStatus::Ok,
+            ApiRoute::Metrics.content_type(),
+            "up 1\n",
+        );
+
+        assert_eq!(
+            out,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\n\
+             Content-Length: 5\r\nConnection: close\r\n\r\nup 1\n"
+        );
+        assert_eq!(ApiRoute::Current.content_type(), "application/json");
+    }
+
+    #[test]
+    fn metrics_text_for_known_state() {
+        let data = SensorData {
+            temperature: Some(22.5),
+            humidity: Some(45.25),
+            co2: Some(415.0),
+            lux: None,
+            timestamp: 1_700_000_000,
+        };
+        let mut lifetime = LifetimeStats::default();
+        lifetime.total_samples = 1234;
+        let out_of_order = OutOfOrderCounts {
+            raw_samples: 2,
+            rollups_5m: 1,
+            ..OutOfOrderCounts::default()
+        };
+        let mut out = String::new();
+        write_metrics(&mut out, Some(&data), Some(&lifetime), Some(out_of_order));
+
+        assert_eq!(
+            out,
+            "# HELP baro_temperature_celsius Current air temperature in degrees Celsius.\n\
+             # TYPE baro_temperature_celsius gauge\n\
+             baro_temperature_celsius 22.5\n\
+             # HELP baro_humidity_percent Current relative humidity in percent.\n\
+             # TYPE baro_humidity_percent gauge\n\
+             baro_humidity_percent 45.25\n\
+             # HELP baro_co2_ppm Current CO2 concentration in ppm.\n\
+             # TYPE baro_co2_ppm gauge\n\
+             baro_co2_ppm 415\n\
+             # HELP baro_sample_timestamp_seconds Unix time of the latest sample.\n\
+             # TYPE baro_sample_timestamp_seconds gauge\n\
+             baro_sample_timestamp_seconds 1700000000\n\
+             # HELP baro_total_samples Samples recorded since the lifetime counters were reset.\n\
+             # TYPE baro_total_samples counter\n\
+             baro_total_samples 1234\n\
+             # HELP baro_out_of_order_records_total Duplicate or out-of-order samples and rollups dropped since boot.\n\
+             # TYPE baro_out_of_order_records_total counter\n\
+             baro_out_of_order_records_total 3\n"
+        );
+    }
+
+    #[test]
+    fn metrics_without_state_are_empty() {
+        let mut out = String::new();
+        write_metrics(&mut out, None, None, None);
+        assert_eq!(out, "");
+    }
+
+    #[test]
+    fn metrics_route_answers_without_storage() {
+        let log: LogRing<4> = LogRing::new();
+        let (status, body) = respond::<RamBackend, 4>(ApiRoute::Metrics, None, &log);
+
+        assert_eq!(status, HttpStatus::Ok);
+        assert_eq!(body, "");
+    }
+
+    #[test]
+    fn logs_serialize_as_array_oldest_first() {
+        let log: LogRing<2> = LogRing::new();
+        let mut out = String::new();
+        write_log_json(&mut out, &log);
+        assert_eq!(out, "[]");
+
+        log.push(1, LogLevel::Info, "dropped");
+        log.push(2, LogLevel::Warn, "[WiFi] Lost");
+        log.push(3, LogLevel::Info, "[WiFi] Reconnected");
+        out.clear();
+        write_log_json(&mut out, &log);
+        assert_eq!(
+            out,
+            "[{\"ts\":2,\"level\":\"warn\",\"msg\":\"[WiFi] Lost\"},\
+             {\"ts\":3,\"level\":\"info\",\"msg\":\"[WiFi] Reconnected\"}]"
+        );
+    }
+}
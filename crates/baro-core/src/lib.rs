@@ -16,10 +16,21 @@ pub mod async_i2c_bus;
 pub mod config;
 pub mod display_manager;
 pub mod framebuffer;
+#[cfg(feature = "http-api")]
+pub mod http_api;
+pub mod log_ring;
 pub mod metrics;
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
+pub mod ntp;
 pub mod pages;
+pub mod reconnect;
 pub mod sensor_store;
+pub mod sensor_watchdog;
 pub mod sensors;
 pub mod storage;
+#[cfg(test)]
+mod test_util;
+pub mod touch_scan;
 pub mod ui;
 pub mod widgets;
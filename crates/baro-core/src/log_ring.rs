@@ -0,0 +1,302 @@
+//! Device-wide ring buffer of recent log lines
+//!
+//! Any subsystem can append short status lines to [`LOG_RING`]; the monitor
+//! page and the HTTP `/logs` route read them back. The ring outlives every
+//! page, so lines logged while another page is showing are not lost. Once
+//! full, the oldest line is dropped for each new one.
+//!
+//! [`write_log_entry_json`] renders a line in the structured form shared by
+//! the `/logs` route and the firmware's RTT mirror.
+
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt::Write;
+
+use embassy_sync::blocking_mutex::Mutex;
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use heapless::{Deque, String};
+
+/// Maximum length of a log line in bytes; longer messages are truncated
+pub const LOG_LINE_MAX_BYTES: usize = 64;
+
+/// Number of lines kept by [`LOG_RING`]
+pub const LOG_RING_CAPACITY: usize = 64;
+
+/// The device-wide log
+pub static LOG_RING: LogRing<LOG_RING_CAPACITY> = LogRing::new();
+
+/// Severity of a log line
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    /// Routine activity (sensor readings, rollups, reconnects)
+    #[default]
+    Info,
+    /// Something degraded that the device works around
+    Warn,
+    /// Something failed
+    Error,
+}
+
+impl LogLevel {
+    /// Lowercase name used in structured output
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// One line held by a [`LogRing`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    /// Position in the ring's history, starting at 1 and never reused
+    pub seq: u32,
+    /// When the line was logged (Unix seconds once the clock is known)
+    pub timestamp: u64,
+    /// Severity
+    pub level: LogLevel,
+    /// Line text
+    pub message: String<LOG_LINE_MAX_BYTES>,
+}
+
+struct LogRingInner<const N: usize> {
+    lines: Deque<LogLine, N>,
+    last_seq: u32,
+}
+
+/// Fixed-capacity log keeping the newest `N` lines
+///
+/// Guarded by a critical-section mutex so it can live in a `static` and be
+/// written from any task. Each line gets an increasing sequence number;
+/// readers remember the last one they saw and fetch only newer lines with
+/// [`LogRing::for_each_since`].
+///
+/// # Examples
+/// ```ignore
+/// static LOG: LogRing<8> = LogRing::new();
+/// LOG.push(1_700_000_000, LogLevel::Info, "[WiFi] Reconnected");
+/// LOG.for_each_since(0, |line| info!("{}", line.message));
+/// ```
+pub struct LogRing<const N: usize> {
+    inner: Mutex<CriticalSectionRawMutex, RefCell<LogRingInner<N>>>,
+}
+
+impl<const N: usize> Default for LogRing<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> LogRing<N> {
+    /// Create an empty ring
+    pub const fn new() -> Self {
+        Self {
+            inner: Mutex::new(RefCell::new(LogRingInner {
+                lines: Deque::new(),
+                last_seq: 0,
+            })),
+        }
+    }
+
+    /// Append a line, dropping the oldest if full, and return its sequence number
+    ///
+    /// Messages longer than [`LOG_LINE_MAX_BYTES`] are cut at the last
+    /// character boundary that fits.
+    pub fn push(&self, timestamp: u64, level: LogLevel, message: &str) -> u32 {
+        let message = truncate_message(message);
+        self.inner.lock(|inner| {
+            let mut inner = inner.borrow_mut();
+            inner.last_seq = inner.last_seq.saturating_add(1);
+            let seq = inner.last_seq;
+            if inner.lines.is_full() {
+                inner.lines.pop_front();
+            }
+            let _ = inner.lines.push_back(LogLine {
+                seq,
+                timestamp,
+                level,
+                message,
+            });
+            seq
+        })
+    }
+
+    /// Number of lines currently held
+    pub fn len(&self) -> usize {
+        self.inner.lock(|inner| inner.borrow().lines.len())
+    }
+
+    /// Whether nothing has been logged yet
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sequence number of the newest line, or 0 if nothing was logged
+    pub fn last_seq(&self) -> u32 {
+        self.inner.lock(|inner| inner.borrow().last_seq)
+    }
+
+    /// Call `f` on each held line newer than `seq`, oldest first
+    ///
+    /// Pass 0 to visit every held line. `f` runs inside the critical section,
+    /// so keep it short; use [`LogRing::lines_since`] to do slow work such as
+    /// formatting outside it.
+    pub fn for_each_since(&self, seq: u32, mut f: impl FnMut(&LogLine)) {
+        self.inner.lock(|inner| {
+            inner
+                .borrow()
+                .lines
+                .iter()
+                .filter(|line| line.seq > seq)
+                .for_each(&mut f);
+        });
+    }
+
+    /// Copy of each held line newer than `seq`, oldest first
+    ///
+    /// Room for a full ring is allocated before taking the lock, so the
+    /// critical section only copies lines.
+    pub fn lines_since(&self, seq: u32) -> Vec<LogLine> {
+        let mut lines = Vec::with_capacity(N);
+        self.for_each_since(seq, |line| lines.push(line.clone()));
+        lines
+    }
+}
+
+/// Copy `message` into a line, cut to fit [`LOG_LINE_MAX_BYTES`]
+fn truncate_message(message: &str) -> String<LOG_LINE_MAX_BYTES> {
+    let mut end = message.len().min(LOG_LINE_MAX_BYTES);
+    while !message.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    let mut line = String::new();
+    let _ = line.push_str(&message[..end]);
+    line
+}
+
+/// Write a log entry as a single-line JSON object
+///
+/// Produces `{"ts":1700000000,"level":"info","msg":"[Raw] temperature: 22.50"}`,
+/// escaping quotes, backslashes, and control characters in the message.
+pub fn write_log_entry_json<W: Write>(
+    out: &mut W,
+    timestamp: u64,
+    level: LogLevel,
+    message: &str,
+) -> core::fmt::Result {
+    write!(
+        out,
+        "{{\"ts\":{},\"level\":\"{}\",\"msg\":\"",
+        timestamp,
+        level.label()
+    )?;
+    for c in message.chars() {
+        match c {
+            '"' => out.write_str("\\\"")?,
+            '\\' => out.write_str("\\\\")?,
+            '\n' => out.write_str("\\n")?,
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.write_char(c)?,
+        }
+    }
+    out.write_str("\"}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn json(timestamp: u64, level: LogLevel, message: &str) -> alloc::string::String {
+        let mut out = alloc::string::String::new();
+        write_log_entry_json(&mut out, timestamp, level, message).unwrap();
+        out
+    }
+
+    #[test]
+    fn log_entry_json_layout() {
+        assert_eq!(
+            json(1_700_000_000, LogLevel::Warn, "[WiFi] Reconnected"),
+            "{\"ts\":1700000000,\"level\":\"warn\",\"msg\":\"[WiFi] Reconnected\"}"
+        );
+    }
+
+    #[test]
+    fn log_entry_json_escapes_message() {
+        assert_eq!(
+            json(0, LogLevel::Error, "say \"hi\"\\\n\t"),
+            "{\"ts\":0,\"level\":\"error\",\"msg\":\"say \\\"hi\\\"\\\\\\n\\u0009\"}"
+        );
+    }
+
+    /// Sequence numbers and messages of the lines newer than `seq`
+    fn held(ring: &LogRing<4>, seq: u32) -> Vec<(u32, alloc::string::String)> {
+        ring.lines_since(seq)
+            .into_iter()
+            .map(|line| (line.seq, line.message.as_str().into()))
+            .collect()
+    }
+
+    #[test]
+    fn ring_wraps_keeping_the_newest_lines_in_order() {
+        let ring: LogRing<4> = LogRing::new();
+        assert!(ring.is_empty());
+        for i in 1..=6 {
+            let mut message = String::<LOG_LINE_MAX_BYTES>::new();
+            let _ = write!(message, "line {i}");
+            assert_eq!(ring.push(i, LogLevel::Info, &message), i as u32);
+        }
+
+        assert_eq!(ring.len(), 4);
+        assert_eq!(ring.last_seq(), 6);
+        let lines = held(&ring, 0);
+        assert_eq!(
+            lines.iter().map(|(seq, _)| *seq).collect::<Vec<_>>(),
+            [3, 4, 5, 6]
+        );
+        assert_eq!(lines[0].1, "line 3");
+        assert_eq!(lines[3].1, "line 6");
+    }
+
+    #[test]
+    fn interleaved_reads_see_each_line_once_oldest_first() {
+        let ring: LogRing<4> = LogRing::new();
+        let mut seen = Vec::new();
+        let mut last_seq = 0;
+        let mut read = |ring: &LogRing<4>| {
+            ring.for_each_since(last_seq, |line| {
+                seen.push(line.seq);
+                last_seq = line.seq;
+            });
+        };
+
+        ring.push(0, LogLevel::Info, "a");
+        ring.push(0, LogLevel::Warn, "b");
+        read(&ring);
+        read(&ring);
+        ring.push(0, LogLevel::Error, "c");
+        read(&ring);
+        // A reader that falls more than a ring behind only sees what is left
+        for _ in 0..6 {
+            ring.push(0, LogLevel::Info, "d");
+        }
+        read(&ring);
+
+        assert_eq!(seen, [1, 2, 3, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn long_messages_are_cut_on_a_char_boundary() {
+        let ring: LogRing<4> = LogRing::new();
+        // 63 ASCII bytes then a 2-byte character that would end past the limit
+        let mut message = alloc::string::String::from("x").repeat(LOG_LINE_MAX_BYTES - 1);
+        message.push('é');
+        ring.push(0, LogLevel::Info, &message);
+
+        let line = &ring.lines_since(0)[0];
+        assert_eq!(line.message.len(), LOG_LINE_MAX_BYTES - 1);
+        assert!(line.message.chars().all(|c| c == 'x'));
+    }
+}
@@ -0,0 +1,112 @@
+//! Fixed-point helpers for milli-unit sensor values
+//!
+//! Sensor readings are stored as `i32` in thousandths of their display unit
+//! (m°C, m%, mppm, mlux, mhPa). These helpers are the one place that
+//! converts between that representation and `f32`, so rounding is the same
+//! everywhere.
+
+/// Milli-units per display unit
+pub const MILLI_PER_UNIT: f32 = 1000.0;
+
+/// Convert a display-unit value to milli-units
+///
+/// Rounds to the nearest milli-unit, with halves rounded away from zero
+/// (`0.0005` -> `1`, `-0.0005` -> `-1`). Values beyond the `i32` range
+/// saturate to `i32::MIN`/`i32::MAX`, and NaN maps to `0`.
+pub fn to_milli(value: f32) -> i32 {
+    let scaled = value * MILLI_PER_UNIT;
+    // `as` truncates toward zero and saturates, so bias by half a unit first
+    let biased = if scaled < 0.0 {
+        scaled - 0.5
+    } else {
+        scaled + 0.5
+    };
+    biased as i32
+}
+
+/// Convert a milli-unit value to display units
+pub fn to_float(milli: i32) -> f32 {
+    milli as f32 / MILLI_PER_UNIT
+}
+
+/// Average of milli-unit values from their `i64` sum and a positive count
+///
+/// Rounds to the nearest milli-unit with halves away from zero (the average
+/// of `[1, 2]` is `2`), then saturates into the `i32` range instead of
+/// wrapping. Callers must skip `count == 0`.
+pub fn average_milli(sum: i64, count: i64) -> i32 {
+    let quotient = sum / count;
+    let remainder = sum % count;
+
+    // |remainder| >= count / 2, done in u64 so doubling cannot overflow
+    let rounded = if remainder.unsigned_abs() * 2 >= count.unsigned_abs() {
+        if (sum < 0) == (count < 0) {
+            quotient + 1
+        } else {
+            quotient - 1
+        }
+    } else {
+        quotient
+    };
+
+    rounded.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_milli_rounds_halves_away_from_zero() {
+        assert_eq!(to_milli(0.0005), 1);
+        assert_eq!(to_milli(-0.0005), -1);
+        assert_eq!(to_milli(0.0004), 0);
+        assert_eq!(to_milli(-0.0004), 0);
+        assert_eq!(to_milli(21.5), 21_500);
+        assert_eq!(to_milli(-40.0), -40_000);
+    }
+
+    #[test]
+    fn to_milli_saturates_at_the_i32_extremes() {
+        assert_eq!(to_milli(1.0e10), i32::MAX);
+        assert_eq!(to_milli(-1.0e10), i32::MIN);
+        assert_eq!(to_milli(f32::INFINITY), i32::MAX);
+        assert_eq!(to_milli(f32::NEG_INFINITY), i32::MIN);
+        assert_eq!(to_milli(f32::NAN), 0);
+    }
+
+    #[test]
+    fn to_float_round_trips_whole_milli_units() {
+        for milli in [0, 1, -1, 21_500, 1_013_250, -40_000] {
+            assert_eq!(to_milli(to_float(milli)), milli);
+        }
+    }
+
+    #[test]
+    fn average_rounds_halves_away_from_zero() {
+        assert_eq!(average_milli(1 + 2, 2), 2);
+        assert_eq!(average_milli(-1 - 2, 2), -2);
+        assert_eq!(average_milli(5, 2), 3);
+        assert_eq!(average_milli(-5, 2), -3);
+        // Below the half rounds toward the quotient
+        assert_eq!(average_milli(1, 3), 0);
+        assert_eq!(average_milli(-1, 3), 0);
+        assert_eq!(average_milli(2, 3), 1);
+        assert_eq!(average_milli(-2, 3), -1);
+    }
+
+    #[test]
+    fn average_saturates_instead_of_wrapping() {
+        let max = i64::from(i32::MAX);
+        let min = i64::from(i32::MIN);
+
+        // Averages of extreme values stay exact where they fit
+        assert_eq!(average_milli(max * 4, 4), i32::MAX);
+        assert_eq!(average_milli(min * 4, 4), i32::MIN);
+        // ...and clamp where they don't
+        assert_eq!(average_milli(max * 4, 2), i32::MAX);
+        assert_eq!(average_milli(min * 4, 2), i32::MIN);
+        assert_eq!(average_milli(i64::MAX, 1), i32::MAX);
+        assert_eq!(average_milli(i64::MIN, 1), i32::MIN);
+    }
+}
@@ -3,11 +3,14 @@
 //! This module provides quality level assessment and thresholds for
 //! determining environmental quality based on sensor readings.
 
+pub mod fixed;
+pub mod stats;
+
 use crate::sensors::SensorType;
 use crate::ui::styling::{
     COLOR_BAD_BACKGROUND, COLOR_BAD_FOREGROUND, COLOR_EXCELLENT_BACKGROUND,
     COLOR_EXCELLENT_FOREGROUND, COLOR_GOOD_BACKGROUND, COLOR_GOOD_FOREGROUND,
-    COLOR_POOR_BACKGROUND, COLOR_POOR_FOREGROUND,
+    COLOR_POOR_BACKGROUND, COLOR_POOR_FOREGROUND, ColorPalette,
 };
 use embedded_graphics::pixelcolor::Rgb565;
 
@@ -102,6 +105,21 @@ impl QualityLevel {
                     Self::Bad
                 }
             }
+            SensorType::Pressure => {
+                // Pressure quality thresholds (hPa)
+                // Pressure says more about the weather than air quality, so the
+                // bands only flag readings far from typical sea-level values.
+                // Good: 980-1040 hPa (normal weather range)
+                // Poor: 950-1060 hPa (deep low or strong high)
+                // Bad: anything else (likely a sensor or altitude issue)
+                if (980.0..=1040.0).contains(&value) {
+                    Self::Good
+                } else if (950.0..=1060.0).contains(&value) {
+                    Self::Poor
+                } else {
+                    Self::Bad
+                }
+            }
         }
     }
 
@@ -115,6 +133,52 @@ impl QualityLevel {
         }
     }
 
+    /// Foreground color for `value`, blended between adjacent quality bands
+    ///
+    /// `thresholds` lists `(value, level)` stops in ascending order of value.
+    /// A value at a stop takes that level's foreground color, a value between
+    /// two stops is blended linearly, and a value outside the stops takes the
+    /// nearest stop's color. An empty slice yields the `Good` color.
+    ///
+    /// ```ignore
+    /// // CO2 fading from green at 800 ppm to red at 2000 ppm
+    /// let stops = [
+    ///     (800.0, QualityLevel::Excellent),
+    ///     (1000.0, QualityLevel::Good),
+    ///     (1500.0, QualityLevel::Poor),
+    ///     (2000.0, QualityLevel::Bad),
+    /// ];
+    /// let color = QualityLevel::color_for(co2_ppm, &stops);
+    /// ```
+    pub fn color_for(value: f32, thresholds: &[(f32, QualityLevel)]) -> Rgb565 {
+        let Some(&(first_value, first_level)) = thresholds.first() else {
+            return Self::Good.foreground_color();
+        };
+        if value <= first_value {
+            return first_level.foreground_color();
+        }
+
+        for pair in thresholds.windows(2) {
+            let (low, low_level) = pair[0];
+            let (high, high_level) = pair[1];
+            if value <= high {
+                let span = high - low;
+                let t = if span > 0.0 {
+                    (value - low) / span
+                } else {
+                    1.0
+                };
+                return ColorPalette::lerp(
+                    low_level.foreground_color(),
+                    high_level.foreground_color(),
+                    t,
+                );
+            }
+        }
+
+        thresholds[thresholds.len() - 1].1.foreground_color()
+    }
+
     /// Get the background color for this quality level
     pub const fn background_color(self) -> Rgb565 {
         match self {
@@ -175,10 +239,51 @@ impl QualityLevel {
     }
 
     /// Banner icon prefix character
+    ///
+    /// Kept to characters the ISO-8859-1 mono fonts can draw; anything
+    /// outside Latin-1 renders as `?`.
     pub const fn status_icon(self) -> &'static str {
         match self {
-            Self::Excellent | Self::Good => "●",
-            Self::Poor | Self::Bad => "▲",
+            Self::Excellent | Self::Good => "*",
+            Self::Poor | Self::Bad => "!",
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STOPS: [(f32, QualityLevel); 3] = [
+        (800.0, QualityLevel::Excellent),
+        (1000.0, QualityLevel::Good),
+        (2000.0, QualityLevel::Bad),
+    ];
+
+    #[test]
+    fn color_for_uses_the_stop_colors_at_and_beyond_the_stops() {
+        let excellent = QualityLevel::Excellent.foreground_color();
+        let good = QualityLevel::Good.foreground_color();
+        let bad = QualityLevel::Bad.foreground_color();
+
+        assert_eq!(QualityLevel::color_for(400.0, &STOPS), excellent);
+        assert_eq!(QualityLevel::color_for(800.0, &STOPS), excellent);
+        assert_eq!(QualityLevel::color_for(1000.0, &STOPS), good);
+        assert_eq!(QualityLevel::color_for(2000.0, &STOPS), bad);
+        assert_eq!(QualityLevel::color_for(5000.0, &STOPS), bad);
+        assert_eq!(QualityLevel::color_for(1000.0, &[]), good);
+    }
+
+    #[test]
+    fn color_for_blends_between_adjacent_stops() {
+        let good = QualityLevel::Good.foreground_color();
+        let bad = QualityLevel::Bad.foreground_color();
+
+        assert_eq!(
+            QualityLevel::color_for(1500.0, &STOPS),
+            ColorPalette::lerp(good, bad, 0.5)
+        );
+        assert_ne!(QualityLevel::color_for(1500.0, &STOPS), good);
+        assert_ne!(QualityLevel::color_for(1500.0, &STOPS), bad);
+    }
+}
@@ -0,0 +1,187 @@
+//! Summary statistics over a stream of milli-unit readings
+//!
+//! [`StatsAccumulator`] folds values in one at a time, so statistics over
+//! data too large to hold in RAM (months of rollups on the SD card) cost
+//! the same few bytes as a trend page's visible window.
+
+use super::fixed::{average_milli, to_float};
+
+/// Statistics for a time window
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrendStats {
+    /// Average value in milli-units
+    pub avg: i32,
+    /// Minimum value in milli-units
+    pub min: i32,
+    /// Maximum value in milli-units
+    pub max: i32,
+    /// Population standard deviation in milli-units
+    pub std_dev: i32,
+    /// Most recent value in the window in milli-units
+    pub latest: i32,
+    /// Number of samples
+    pub count: usize,
+}
+
+impl TrendStats {
+    /// Get average as float
+    pub fn avg_f32(&self) -> f32 {
+        to_float(self.avg)
+    }
+}
+
+/// Running min, max, mean and deviation of values pushed oldest first
+///
+/// The deviation uses Welford's method, which stays accurate without
+/// keeping the values around.
+///
+/// # Examples
+/// ```ignore
+/// let mut acc = StatsAccumulator::new();
+/// for (_, value) in window {
+///     acc.push(value);
+/// }
+/// let stats = acc.finish();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct StatsAccumulator {
+    sum: i64,
+    min: i32,
+    max: i32,
+    /// Running mean (milli-units)
+    mean: f64,
+    /// Sum of squared deviations from the mean (milli-units²)
+    m2: f64,
+    latest: i32,
+    count: usize,
+}
+
+impl Default for StatsAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StatsAccumulator {
+    /// An accumulator that has seen no values
+    pub const fn new() -> Self {
+        Self {
+            sum: 0,
+            min: i32::MAX,
+            max: i32::MIN,
+            mean: 0.0,
+            m2: 0.0,
+            latest: 0,
+            count: 0,
+        }
+    }
+
+    /// Fold in one reading
+    pub fn push(&mut self, value: i32) {
+        self.push_summary(value, value, value);
+    }
+
+    /// Fold in a pre-aggregated record (a rollup) as one sample
+    ///
+    /// `avg` feeds the mean and deviation, while `min` and `max` widen the
+    /// extremes, so the result keeps peaks a rollup averaged away.
+    pub fn push_summary(&mut self, avg: i32, min: i32, max: i32) {
+        self.sum = self.sum.saturating_add(avg as i64);
+        self.min = self.min.min(min);
+        self.max = self.max.max(max);
+        self.latest = avg;
+        self.count += 1;
+
+        let x = avg as f64;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    /// Number of values folded in so far
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Statistics of everything pushed, or all zeros if nothing was
+    pub fn finish(&self) -> TrendStats {
+        if self.count == 0 {
+            return TrendStats::default();
+        }
+
+        let avg = average_milli(self.sum, self.count as i64);
+
+        // Round the variance to whole milli-units², then take the integer root
+        let variance = (self.m2 / self.count as f64 + 0.5) as u64;
+        let std_dev = variance.isqrt().min(i32::MAX as u64) as i32;
+
+        TrendStats {
+            avg,
+            min: self.min,
+            max: self.max,
+            std_dev,
+            latest: self.latest,
+            count: self.count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats_of(values: &[i32]) -> TrendStats {
+        let mut acc = StatsAccumulator::new();
+        for &value in values {
+            acc.push(value);
+        }
+        acc.finish()
+    }
+
+    #[test]
+    fn std_dev_matches_the_textbook_example() {
+        // Population standard deviation of 2, 4, 4, 4, 5, 5, 7, 9 is exactly 2
+        let stats = stats_of(&[2_000, 4_000, 4_000, 4_000, 5_000, 5_000, 7_000, 9_000]);
+        assert_eq!(stats.std_dev, 2_000);
+        assert_eq!(stats.avg, 5_000);
+    }
+
+    #[test]
+    fn std_dev_matches_a_reference_within_a_milli_unit() {
+        // Reference: Python's statistics.pstdev gives 553.7147...
+        let stats = stats_of(&[21_000, 21_500, 22_250, 20_750, 21_900]);
+        assert!((stats.std_dev - 554).abs() <= 1, "{}", stats.std_dev);
+        assert_eq!(stats.avg, 21_480);
+    }
+
+    #[test]
+    fn constant_series_has_no_deviation() {
+        let stats = stats_of(&[415_000; 20]);
+        assert_eq!(stats.std_dev, 0);
+        assert_eq!((stats.min, stats.max), (415_000, 415_000));
+    }
+
+    #[test]
+    fn latest_is_the_last_value_pushed() {
+        let stats = stats_of(&[3, 9, 1, 5]);
+        assert_eq!(stats.latest, 5);
+        assert_eq!((stats.min, stats.max, stats.count), (1, 9, 4));
+    }
+
+    #[test]
+    fn nothing_pushed_gives_zeroed_stats() {
+        assert_eq!(StatsAccumulator::new().finish(), TrendStats::default());
+    }
+
+    #[test]
+    fn summaries_widen_the_extremes_beyond_their_averages() {
+        let mut acc = StatsAccumulator::new();
+        acc.push_summary(20_000, 18_000, 23_000);
+        acc.push_summary(21_000, 20_500, 21_500);
+
+        let stats = acc.finish();
+        assert_eq!((stats.min, stats.max), (18_000, 23_000));
+        assert_eq!(stats.avg, 20_500);
+        assert_eq!(stats.std_dev, 500);
+    }
+}
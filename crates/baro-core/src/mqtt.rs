@@ -0,0 +1,318 @@
+//! Minimal MQTT 3.1.1 publishing for rollup events
+//!
+//! Only what a QoS 0 publisher needs is implemented: CONNECT, PUBLISH, and
+//! DISCONNECT packet encoding, plus the topic/payload formatting for each
+//! `RollupEvent`. The firmware owns the TCP connection, so everything here is
+//! independent of the network layer.
+//!
+//! Topics:
+//! - raw samples: `<prefix>/<sensor>` with the reading as a plain number (`22.500`)
+//! - rollups: `<prefix>/<tier>/<sensor>` with `{"start_ts":..,"avg":..,"min":..,"max":..}`
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::config::MAX_SAMPLE_INTERVAL_SECS;
+#[cfg(feature = "sensor-bme280")]
+use crate::sensors::PRESSURE;
+use crate::sensors::{CO2, HUMIDITY, LUX, TEMPERATURE};
+use crate::storage::{MISSING_SENSOR_VALUE, Rollup, accumulator::RollupEvent};
+
+/// Keep-alive interval requested in CONNECT (seconds)
+///
+/// Twice the longest sample interval, so a raw-sample PUBLISH always arrives
+/// within the window and no PINGREQ is needed.
+pub const MQTT_KEEP_ALIVE_SECS: u16 = 2 * MAX_SAMPLE_INTERVAL_SECS as u16;
+
+/// Sensor slots published, with their topic suffix
+#[cfg(feature = "sensor-bme280")]
+const MQTT_SENSOR_TOPICS: &[(&str, usize)] = &[
+    ("temperature", TEMPERATURE),
+    ("humidity", HUMIDITY),
+    ("co2", CO2),
+    ("lux", LUX),
+    ("pressure", PRESSURE),
+];
+
+/// Sensor slots published, with their topic suffix
+#[cfg(not(feature = "sensor-bme280"))]
+const MQTT_SENSOR_TOPICS: &[(&str, usize)] = &[
+    ("temperature", TEMPERATURE),
+    ("humidity", HUMIDITY),
+    ("co2", CO2),
+    ("lux", LUX),
+];
+
+// Fixed header first bytes (packet type << 4 | flags)
+const PACKET_CONNECT: u8 = 0x10;
+const PACKET_PUBLISH_QOS0: u8 = 0x30;
+const PACKET_DISCONNECT: u8 = 0xE0;
+
+// CONNECT flags
+const CONNECT_FLAG_USERNAME: u8 = 0x80;
+const CONNECT_FLAG_PASSWORD: u8 = 0x40;
+const CONNECT_FLAG_CLEAN_SESSION: u8 = 0x02;
+
+/// Protocol level for MQTT 3.1.1
+const PROTOCOL_LEVEL_3_1_1: u8 = 4;
+
+/// A topic and payload ready to be encoded as a PUBLISH packet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttMessage {
+    pub topic: String,
+    pub payload: String,
+}
+
+/// Format the messages published for one rollup event
+///
+/// One message is produced per sensor with a reading; missing sensors are skipped.
+pub fn event_messages(prefix: &str, event: &RollupEvent) -> Vec<MqttMessage> {
+    let mut messages = Vec::new();
+
+    match event {
+        RollupEvent::RawSample(sample) => {
+            for &(name, index) in MQTT_SENSOR_TOPICS {
+                let value = sample.values[index];
+                if value == MISSING_SENSOR_VALUE {
+                    continue;
+                }
+                let mut payload = String::new();
+                write_milli(&mut payload, value);
+                messages.push(MqttMessage {
+                    topic: topic(prefix, None, name),
+                    payload,
+                });
+            }
+        }
+        RollupEvent::Rollup5m(rollup) => push_rollup(&mut messages, prefix, "5m", rollup),
+        RollupEvent::Rollup1h(rollup) => push_rollup(&mut messages, prefix, "1h", rollup),
+        RollupEvent::RollupDaily(rollup) => push_rollup(&mut messages, prefix, "daily", rollup),
+    }
+
+    messages
+}
+
+fn push_rollup(messages: &mut Vec<MqttMessage>, prefix: &str, tier: &str, rollup: &Rollup) {
+    for &(name, index) in MQTT_SENSOR_TOPICS {
+        if rollup.avg[index] == MISSING_SENSOR_VALUE {
+            continue;
+        }
+        let mut payload = String::new();
+        let _ = write!(payload, "{{\"start_ts\":{},\"avg\":", rollup.start_ts);
+        write_milli(&mut payload, rollup.avg[index]);
+        payload.push_str(",\"min\":");
+        write_milli(&mut payload, rollup.min[index]);
+        payload.push_str(",\"max\":");
+        write_milli(&mut payload, rollup.max[index]);
+        payload.push('}');
+        messages.push(MqttMessage {
+            topic: topic(prefix, Some(tier), name),
+            payload,
+        });
+    }
+}
+
+/// Join the prefix, optional tier, and sensor name, ignoring a trailing `/` on the prefix
+fn topic(prefix: &str, tier: Option<&str>, sensor: &str) -> String {
+    let mut topic = String::from(prefix.trim_end_matches('/'));
+    for part in tier.into_iter().chain(core::iter::once(sensor)) {
+        if !topic.is_empty() {
+            topic.push('/');
+        }
+        topic.push_str(part);
+    }
+    topic
+}
+
+/// Write a milli-unit integer as a fixed three-decimal number (no float rounding)
+fn write_milli(out: &mut String, milli: i32) {
+    let sign = if milli < 0 { "-" } else { "" };
+    let abs = milli.unsigned_abs();
+    let _ = write!(out, "{}{}.{:03}", sign, abs / 1000, abs % 1000);
+}
+
+/// Encode a CONNECT packet with a clean session
+///
+/// Empty `username`/`password` are omitted, as required for anonymous brokers.
+pub fn encode_connect(
+    out: &mut Vec<u8>,
+    client_id: &str,
+    username: &str,
+    password: &str,
+    keep_alive_secs: u16,
+) {
+    let mut flags = CONNECT_FLAG_CLEAN_SESSION;
+    let mut body = Vec::new();
+    push_str(&mut body, "MQTT");
+    body.push(PROTOCOL_LEVEL_3_1_1);
+    if !username.is_empty() {
+        flags |= CONNECT_FLAG_USERNAME;
+        // A password without a username is not allowed in 3.1.1
+        if !password.is_empty() {
+            flags |= CONNECT_FLAG_PASSWORD;
+        }
+    }
+    body.push(flags);
+    body.extend_from_slice(&keep_alive_secs.to_be_bytes());
+    push_str(&mut body, client_id);
+    if flags & CONNECT_FLAG_USERNAME != 0 {
+        push_str(&mut body, username);
+    }
+    if flags & CONNECT_FLAG_PASSWORD != 0 {
+        push_str(&mut body, password);
+    }
+
+    out.push(PACKET_CONNECT);
+    push_remaining_length(out, body.len());
+    out.extend_from_slice(&body);
+}
+
+/// Encode a QoS 0 PUBLISH packet (no packet identifier, not retained)
+pub fn encode_publish(out: &mut Vec<u8>, topic: &str, payload: &[u8]) {
+    out.push(PACKET_PUBLISH_QOS0);
+    push_remaining_length(out, 2 + topic.len() + payload.len());
+    push_str(out, topic);
+    out.extend_from_slice(payload);
+}
+
+/// Encode a DISCONNECT packet
+pub fn encode_disconnect(out: &mut Vec<u8>) {
+    out.extend_from_slice(&[PACKET_DISCONNECT, 0]);
+}
+
+/// Whether `packet` is a CONNACK accepting the connection
+pub fn is_connack_accepted(packet: &[u8]) -> bool {
+    matches!(packet, [0x20, 0x02, _, 0x00, ..])
+}
+
+/// Length-prefixed UTF-8 string
+fn push_str(out: &mut Vec<u8>, s: &str) {
+    out.extend_from_slice(&(s.len() as u16).to_be_bytes());
+    out.extend_from_slice(s.as_bytes());
+}
+
+/// Variable-length "remaining length" field (7 bits per byte, MSB = continuation)
+fn push_remaining_length(out: &mut Vec<u8>, mut len: usize) {
+    loop {
+        let mut byte = (len % 128) as u8;
+        len /= 128;
+        if len > 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if len == 0 {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{MAX_SENSORS, RawSample};
+
+    fn message(topic: &str, payload: &str) -> MqttMessage {
+        MqttMessage {
+            topic: topic.into(),
+            payload: payload.into(),
+        }
+    }
+
+    fn values() -> [i32; MAX_SENSORS] {
+        let mut values = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+        values[TEMPERATURE] = 22_500;
+        values[CO2] = 415_000;
+        values
+    }
+
+    #[test]
+    fn raw_sample_publishes_one_plain_number_per_sensor() {
+        let event = RollupEvent::RawSample(RawSample::new(1_700_000_000, &values()));
+
+        assert_eq!(
+            event_messages("baro", &event),
+            [
+                message("baro/temperature", "22.500"),
+                message("baro/co2", "415.000"),
+            ]
+        );
+    }
+
+    #[test]
+    fn rollup_publishes_json_under_its_tier() {
+        let mut min = values();
+        min[TEMPERATURE] = -1_250;
+        let rollup = Rollup::new(1_700_000_000, &values(), &min, &values(), 30);
+
+        assert_eq!(
+            event_messages("baro", &RollupEvent::Rollup1h(rollup))[0],
+            message(
+                "baro/1h/temperature",
+                "{\"start_ts\":1700000000,\"avg\":22.500,\"min\":-1.250,\"max\":22.500}"
+            )
+        );
+        assert_eq!(
+            event_messages("baro", &RollupEvent::RollupDaily(rollup))[1].topic,
+            "baro/daily/co2"
+        );
+    }
+
+    #[test]
+    fn topic_prefix_trailing_slash_and_empty_prefix() {
+        assert_eq!(topic("home/baro/", Some("5m"), "lux"), "home/baro/5m/lux");
+        assert_eq!(topic("", None, "lux"), "lux");
+    }
+
+    #[test]
+    fn publish_packet_layout() {
+        let mut packet = Vec::new();
+        encode_publish(&mut packet, "a/b", b"1.5");
+
+        assert_eq!(packet, [0x30, 8, 0, 3, b'a', b'/', b'b', b'1', b'.', b'5']);
+    }
+
+    #[test]
+    fn long_publish_uses_two_byte_remaining_length() {
+        let mut packet = Vec::new();
+        encode_publish(&mut packet, "t", &[0; 200]);
+
+        // 2 + 1 + 200 = 203 = 0x4B + 1 * 128
+        assert_eq!(&packet[..3], &[0x30, 0xCB, 0x01]);
+        assert_eq!(packet.len(), 3 + 203);
+    }
+
+    #[test]
+    fn anonymous_connect_omits_credentials() {
+        let mut packet = Vec::new();
+        encode_connect(&mut packet, "baro", "", "", 60);
+
+        assert_eq!(
+            packet,
+            [
+                0x10, 16, 0, 4, b'M', b'Q', b'T', b'T', 4, 0x02, 0, 60, 0, 4, b'b', b'a', b'r',
+                b'o',
+            ]
+        );
+    }
+
+    #[test]
+    fn connect_with_credentials_sets_flags() {
+        let mut packet = Vec::new();
+        encode_connect(&mut packet, "c", "u", "p", 60);
+        assert_eq!(packet[9], 0xC2);
+        assert!(packet.ends_with(&[0, 1, b'u', 0, 1, b'p']));
+
+        // A password alone is not sent
+        packet.clear();
+        encode_connect(&mut packet, "c", "", "p", 60);
+        assert_eq!(packet[9], 0x02);
+    }
+
+    #[test]
+    fn connack_acceptance() {
+        assert!(is_connack_accepted(&[0x20, 0x02, 0x00, 0x00]));
+        assert!(!is_connack_accepted(&[0x20, 0x02, 0x00, 0x05]));
+        assert!(!is_connack_accepted(&[0x20, 0x02]));
+    }
+}
@@ -0,0 +1,246 @@
+//! SNTP request building and response validation
+//!
+//! The firmware owns the UDP socket; this module only deals with the 48-byte
+//! packets so the parsing rules stay platform-independent. A response is only
+//! accepted if it comes from a synchronized server and carries a transmit
+//! timestamp that is plausibly in the present.
+
+use crate::app_state::TimeSyncError;
+
+/// Size of an SNTP packet without extension fields or authenticator
+pub const NTP_PACKET_LEN: usize = 48;
+
+/// Seconds between the NTP epoch (1900-01-01) and the Unix epoch (1970-01-01)
+pub const NTP_UNIX_OFFSET_SECS: u32 = 2_208_988_800;
+
+/// Earliest Unix time accepted from a server (2020-01-01T00:00:00Z)
+pub const MIN_PLAUSIBLE_UNIX_SECS: u32 = 1_577_836_800;
+
+/// Base time to wait for a response from a single server
+pub const NTP_TIMEOUT_MS: u64 = 3_000;
+
+/// Upper bound of the random extra added to each attempt's timeout
+pub const NTP_MAX_JITTER_MS: u64 = 500;
+
+/// Number of passes over the server list before giving up
+pub const NTP_ROUNDS: usize = 2;
+
+/// UDP port SNTP servers listen on
+pub const NTP_PORT: u16 = 123;
+
+/// Default server addresses, tried in order
+///
+/// IPv4 literals avoid a DNS dependency during boot.
+pub const DEFAULT_NTP_SERVERS: [[u8; 4]; 6] = [
+    [162, 159, 200, 1],   // pool.ntp.org (Cloudflare)
+    [216, 239, 35, 0],    // time.google.com
+    [216, 239, 35, 4],    // time.google.com
+    [162, 159, 200, 123], // time.cloudflare.com
+    [17, 253, 4, 253],    // time.apple.com
+    [129, 6, 15, 28],     // time-a-g.nist.gov
+];
+
+/// Most servers a configured list may name
+pub const MAX_NTP_SERVERS: usize = 8;
+
+// LI=0 (no warning), VN=3, Mode=3 (client)
+const CLIENT_REQUEST_HEADER: u8 = 0x1B;
+
+const MODE_MASK: u8 = 0x07;
+const MODE_SERVER: u8 = 4;
+const MODE_BROADCAST: u8 = 5;
+const LEAP_UNSYNCHRONIZED: u8 = 3;
+const STRATUM_KISS_OF_DEATH: u8 = 0;
+
+const STRATUM_OFFSET: usize = 1;
+const TRANSMIT_TIMESTAMP_OFFSET: usize = 40;
+
+/// Build a client request packet
+pub const fn request_packet() -> [u8; NTP_PACKET_LEN] {
+    let mut packet = [0u8; NTP_PACKET_LEN];
+    packet[0] = CLIENT_REQUEST_HEADER;
+    packet
+}
+
+/// Timeout for one attempt, stretched by a jitter derived from `random`
+///
+/// Spreading the timeouts keeps a fleet of devices that lost power together
+/// from retrying in lockstep.
+pub const fn attempt_timeout_ms(random: u32) -> u64 {
+    NTP_TIMEOUT_MS + (random as u64) % (NTP_MAX_JITTER_MS + 1)
+}
+
+/// Parse a comma-separated list of IPv4 server addresses
+///
+/// Returns `None` if the list is empty, names more than [`MAX_NTP_SERVERS`],
+/// or has an entry that is not a dotted-quad address, so the caller can fall
+/// back to [`DEFAULT_NTP_SERVERS`] instead of syncing against a partial list.
+pub fn parse_server_list(list: &str) -> Option<heapless::Vec<[u8; 4], MAX_NTP_SERVERS>> {
+    let mut servers = heapless::Vec::new();
+    for entry in list.split(',') {
+        let address: core::net::Ipv4Addr = entry.trim().parse().ok()?;
+        servers.push(address.octets()).ok()?;
+    }
+    (!servers.is_empty()).then_some(servers)
+}
+
+/// Validate an SNTP response and extract its transmit time as Unix seconds
+pub fn parse_response(packet: &[u8]) -> Result<u32, TimeSyncError> {
+    if packet.len() < NTP_PACKET_LEN {
+        return Err(TimeSyncError::ResponseTooShort { len: packet.len() });
+    }
+
+    let header = packet[0];
+    let mode = header & MODE_MASK;
+    if mode != MODE_SERVER && mode != MODE_BROADCAST {
+        return Err(TimeSyncError::UnexpectedMode { mode });
+    }
+    if header >> 6 == LEAP_UNSYNCHRONIZED {
+        return Err(TimeSyncError::ServerUnsynchronized);
+    }
+    if packet[STRATUM_OFFSET] == STRATUM_KISS_OF_DEATH {
+        return Err(TimeSyncError::KissOfDeath);
+    }
+
+    let ts = &packet[TRANSMIT_TIMESTAMP_OFFSET..TRANSMIT_TIMESTAMP_OFFSET + 8];
+    if ts.iter().all(|&b| b == 0) {
+        return Err(TimeSyncError::ZeroTransmitTimestamp);
+    }
+
+    let ntp_secs = u32::from_be_bytes([ts[0], ts[1], ts[2], ts[3]]);
+    // Era 0 ends in 2036; wrapping keeps the conversion valid into era 1
+    let unix_time = ntp_secs.wrapping_sub(NTP_UNIX_OFFSET_SECS);
+    if unix_time < MIN_PLAUSIBLE_UNIX_SECS {
+        return Err(TimeSyncError::ImplausibleTime { unix_time });
+    }
+
+    Ok(unix_time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2024-01-01T00:00:00Z
+    const UNIX_2024: u32 = 1_704_067_200;
+
+    /// A well-formed stratum 2 server response carrying `unix_time`
+    fn response(unix_time: u32) -> [u8; NTP_PACKET_LEN] {
+        let mut packet = [0u8; NTP_PACKET_LEN];
+        // LI=0, VN=4, Mode=4 (server)
+        packet[0] = 0x24;
+        packet[STRATUM_OFFSET] = 2;
+        let ntp_secs = unix_time.wrapping_add(NTP_UNIX_OFFSET_SECS);
+        packet[TRANSMIT_TIMESTAMP_OFFSET..TRANSMIT_TIMESTAMP_OFFSET + 4]
+            .copy_from_slice(&ntp_secs.to_be_bytes());
+        packet
+    }
+
+    #[test]
+    fn request_is_a_version_3_client_packet() {
+        let packet = request_packet();
+        assert_eq!(packet[0], 0x1B);
+        assert!(packet[1..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn valid_response_yields_unix_time() {
+        assert_eq!(parse_response(&response(UNIX_2024)).unwrap(), UNIX_2024);
+    }
+
+    #[test]
+    fn broadcast_response_is_accepted() {
+        let mut packet = response(UNIX_2024);
+        packet[0] = 0x25;
+        assert_eq!(parse_response(&packet).unwrap(), UNIX_2024);
+    }
+
+    #[test]
+    fn time_past_ntp_era_rollover_is_accepted() {
+        // 2040 is past the 2036 end of NTP era 0
+        let unix_2040 = 2_208_988_800;
+        assert_eq!(parse_response(&response(unix_2040)).unwrap(), unix_2040);
+    }
+
+    #[test]
+    fn short_packet_is_rejected() {
+        assert!(matches!(
+            parse_response(&response(UNIX_2024)[..47]),
+            Err(TimeSyncError::ResponseTooShort { len: 47 })
+        ));
+    }
+
+    #[test]
+    fn client_mode_packet_is_rejected() {
+        let mut packet = response(UNIX_2024);
+        packet[0] = 0x23;
+        assert!(matches!(
+            parse_response(&packet),
+            Err(TimeSyncError::UnexpectedMode { mode: 3 })
+        ));
+    }
+
+    #[test]
+    fn unsynchronized_server_is_rejected() {
+        let mut packet = response(UNIX_2024);
+        packet[0] |= LEAP_UNSYNCHRONIZED << 6;
+        assert!(matches!(
+            parse_response(&packet),
+            Err(TimeSyncError::ServerUnsynchronized)
+        ));
+    }
+
+    #[test]
+    fn kiss_of_death_is_rejected() {
+        let mut packet = response(UNIX_2024);
+        packet[STRATUM_OFFSET] = STRATUM_KISS_OF_DEATH;
+        assert!(matches!(
+            parse_response(&packet),
+            Err(TimeSyncError::KissOfDeath)
+        ));
+    }
+
+    #[test]
+    fn zero_transmit_timestamp_is_rejected() {
+        let mut packet = response(UNIX_2024);
+        packet[TRANSMIT_TIMESTAMP_OFFSET..].fill(0);
+        assert!(matches!(
+            parse_response(&packet),
+            Err(TimeSyncError::ZeroTransmitTimestamp)
+        ));
+    }
+
+    #[test]
+    fn time_before_2020_is_rejected() {
+        let unix_2019 = MIN_PLAUSIBLE_UNIX_SECS - 1;
+        assert!(matches!(
+            parse_response(&response(unix_2019)),
+            Err(TimeSyncError::ImplausibleTime { unix_time }) if unix_time == unix_2019
+        ));
+    }
+
+    #[test]
+    fn attempt_timeout_jitter_is_bounded() {
+        assert_eq!(attempt_timeout_ms(0), NTP_TIMEOUT_MS);
+        assert_eq!(
+            attempt_timeout_ms(NTP_MAX_JITTER_MS as u32),
+            NTP_TIMEOUT_MS + NTP_MAX_JITTER_MS
+        );
+        assert!(attempt_timeout_ms(u32::MAX) <= NTP_TIMEOUT_MS + NTP_MAX_JITTER_MS);
+    }
+
+    #[test]
+    fn server_list_keeps_the_configured_order() {
+        let servers = parse_server_list("192.168.1.1, 162.159.200.1").unwrap();
+        assert_eq!(servers.as_slice(), &[[192, 168, 1, 1], [162, 159, 200, 1]]);
+    }
+
+    #[test]
+    fn invalid_server_list_is_rejected() {
+        assert!(parse_server_list("").is_none());
+        assert!(parse_server_list("192.168.1.1,").is_none());
+        assert!(parse_server_list("pool.ntp.org").is_none());
+        assert!(parse_server_list("192.168.1.256").is_none());
+        assert!(parse_server_list(&["10.0.0.1"; MAX_NTP_SERVERS + 1].join(",")).is_none());
+    }
+}
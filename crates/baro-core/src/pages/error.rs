@@ -0,0 +1,297 @@
+//! Error page
+//!
+//! Full-screen explanation of an [`AppError`]: what went wrong (the error's
+//! `Display` text) and what the user can try next. Any tap dismisses the
+//! page back to the home screen.
+//!
+//! ```text
+//! ┌──────────────────────────────────────┐
+//! │  Something went wrong                │  ← title (error color)
+//! │                                      │
+//! │  Storage error: SD card error: ...   │  ← wrapped error message
+//! │                                      │
+//! │  Reseat the SD card and restart the  │  ← suggested action
+//! │  device.                             │
+//! │                                      │
+//! │            Tap to dismiss            │  ← hint
+//! └──────────────────────────────────────┘
+//! ```
+
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::mono_font::iso_8859_1::{FONT_6X10, FONT_10X20};
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+
+extern crate alloc;
+use alloc::string::ToString;
+
+use crate::app_state::AppError;
+use crate::pages::page::Page;
+use crate::ui::core::{Action, Drawable, PageId, TouchEvent};
+use crate::ui::{ColorPalette, MultiLineText, Style, TextSize, Theme};
+
+// ---------------------------------------------------------------------------
+// Layout constants
+// ---------------------------------------------------------------------------
+
+/// Outer margin around the page content in pixels.
+const MARGIN_PX: u32 = 16;
+
+/// Height reserved for the title line in pixels.
+const TITLE_HEIGHT_PX: u32 = 28;
+
+/// Height reserved for the error message in pixels.
+const MESSAGE_HEIGHT_PX: u32 = 76;
+
+/// Gap between the message and the suggested action in pixels.
+const SECTION_GAP_PX: u32 = 12;
+
+/// Height reserved for the suggested action in pixels.
+const SUGGESTION_HEIGHT_PX: u32 = 52;
+
+/// Distance of the dismiss hint from the bottom edge in pixels.
+const HINT_BOTTOM_OFFSET_PX: i32 = 14;
+
+/// Title shown above every error.
+const TITLE_TEXT: &str = "Something went wrong";
+
+/// Hint shown at the bottom of the page.
+const HINT_TEXT: &str = "Tap to dismiss";
+
+// ---------------------------------------------------------------------------
+// ErrorPage
+// ---------------------------------------------------------------------------
+
+/// Full-screen page describing an [`AppError`] and how to recover from it.
+///
+/// The message and suggestion are rendered when the page is built, so the
+/// error itself is not kept around.
+pub struct ErrorPage {
+    bounds: Rectangle,
+    message: MultiLineText,
+    suggestion: MultiLineText,
+    palette: ColorPalette,
+    dirty: bool,
+}
+
+impl ErrorPage {
+    /// Create the page for `error`.
+    pub fn new(bounds: Rectangle, error: &AppError, theme: &Theme) -> Self {
+        let palette = theme.palette;
+        let width = bounds.size.width.saturating_sub(MARGIN_PX * 2);
+        let left = bounds.top_left.x + MARGIN_PX as i32;
+        let message_top = bounds.top_left.y + (MARGIN_PX + TITLE_HEIGHT_PX) as i32;
+        let suggestion_top = message_top + (MESSAGE_HEIGHT_PX + SECTION_GAP_PX) as i32;
+
+        let message = MultiLineText::new(
+            Rectangle::new(
+                Point::new(left, message_top),
+                Size::new(width, MESSAGE_HEIGHT_PX),
+            ),
+            &error.to_string(),
+            theme.text_size(TextSize::Medium),
+        )
+        .with_style(Style::new().with_foreground(palette.text_primary));
+
+        let suggestion = MultiLineText::new(
+            Rectangle::new(
+                Point::new(left, suggestion_top),
+                Size::new(width, SUGGESTION_HEIGHT_PX),
+            ),
+            error.suggested_action(),
+            theme.text_size(TextSize::Medium),
+        )
+        .with_style(Style::new().with_foreground(palette.text_secondary));
+
+        Self {
+            bounds,
+            message,
+            suggestion,
+            palette,
+            dirty: true,
+        }
+    }
+
+    /// Wrapped error message lines.
+    pub fn message_lines(&self) -> impl Iterator<Item = &str> {
+        self.message.lines()
+    }
+
+    /// Wrapped suggested-action lines.
+    pub fn suggestion_lines(&self) -> impl Iterator<Item = &str> {
+        self.suggestion.lines()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Page trait
+// ---------------------------------------------------------------------------
+
+impl Page for ErrorPage {
+    fn id(&self) -> PageId {
+        PageId::Error
+    }
+
+    fn title(&self) -> &str {
+        "Error"
+    }
+
+    fn on_activate(&mut self) {
+        self.dirty = true;
+    }
+
+    fn handle_touch(&mut self, event: TouchEvent) -> Option<Action> {
+        match event {
+            TouchEvent::Press(_) => Some(Action::NavigateToPage(PageId::Home)),
+            _ => None,
+        }
+    }
+
+    fn update(&mut self) {
+        // Static page
+    }
+
+    fn draw_page<D: DrawTarget<Color = Rgb565>>(
+        &mut self,
+        display: &mut D,
+    ) -> Result<(), D::Error> {
+        Drawable::draw(self, display)
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Drawable trait
+// ---------------------------------------------------------------------------
+
+impl Drawable for ErrorPage {
+    fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        display.clear(self.palette.background)?;
+
+        Text::with_baseline(
+            TITLE_TEXT,
+            self.bounds.top_left + Point::new(MARGIN_PX as i32, MARGIN_PX as i32),
+            MonoTextStyle::new(&FONT_10X20, self.palette.error),
+            Baseline::Top,
+        )
+        .draw(display)?;
+
+        self.message.draw(display)?;
+        self.suggestion.draw(display)?;
+
+        let bottom_center = Point::new(
+            self.bounds.center().x,
+            self.bounds.top_left.y + self.bounds.size.height as i32 - HINT_BOTTOM_OFFSET_PX,
+        );
+        Text::with_text_style(
+            HINT_TEXT,
+            bottom_center,
+            MonoTextStyle::new(&FONT_6X10, self.palette.text_secondary),
+            TextStyleBuilder::new()
+                .alignment(Alignment::Center)
+                .baseline(Baseline::Middle)
+                .build(),
+        )
+        .draw(display)?;
+
+        Ok(())
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app_state::{TimeSyncError, WifiError};
+    use crate::pages::test_harness::{assert_contains, render, screen_bounds};
+    use crate::sensors::SensorError;
+    use crate::storage::{RollupTier, StorageError};
+    use crate::ui::core::TouchPoint;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    fn one_of_each() -> [AppError; 4] {
+        [
+            AppError::Wifi(WifiError::ConnectionFailed),
+            AppError::TimeSync(TimeSyncError::AllServersFailed),
+            AppError::Storage(StorageError::UnsupportedTier(RollupTier::Daily)),
+            AppError::Sensor(SensorError::InitializationFailed {
+                sensor: "SCD41",
+                details: "no response",
+            }),
+        ]
+    }
+
+    #[test]
+    fn every_error_renders_a_message_and_a_suggestion() {
+        let theme = Theme::dark();
+        for error in one_of_each() {
+            let page = ErrorPage::new(screen_bounds(), &error, &theme);
+
+            let message: Vec<&str> = page.message_lines().collect();
+            let suggestion: Vec<&str> = page.suggestion_lines().collect();
+            assert!(message.iter().any(|line| !line.is_empty()), "{error:?}");
+            assert!(suggestion.iter().any(|line| !line.is_empty()), "{error:?}");
+
+            // Wrapping only moves breaks; the words are the error's Display text
+            let joined: String = message.join(" ");
+            assert_eq!(joined, error.to_string(), "{error:?}");
+        }
+    }
+
+    #[test]
+    fn page_draws_the_title_and_any_tap_goes_home() {
+        let theme = Theme::dark();
+        let error = AppError::Wifi(WifiError::InitFailed);
+        let mut page = ErrorPage::new(screen_bounds(), &error, &theme);
+
+        let fb = render(&mut page);
+        let title_band = Rectangle::new(
+            Point::new(0, MARGIN_PX as i32),
+            Size::new(screen_bounds().size.width, TITLE_HEIGHT_PX),
+        );
+        assert_contains(&fb, title_band, theme.palette.error);
+
+        assert_eq!(
+            page.handle_touch(TouchEvent::Press(TouchPoint::new(5, 5))),
+            Some(Action::NavigateToPage(PageId::Home))
+        );
+        assert_eq!(
+            page.handle_touch(TouchEvent::Release(TouchPoint::new(5, 5))),
+            None
+        );
+    }
+}
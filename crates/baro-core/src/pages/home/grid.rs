@@ -5,30 +5,29 @@
 //! current value, quality level, and a small trend sparkline. Tapping
 //! a card navigates to its full TrendPage.
 
-use core::fmt::Write;
-
 use embedded_graphics::Drawable as EgDrawable;
 use embedded_graphics::mono_font::MonoTextStyle;
-use embedded_graphics::mono_font::ascii::FONT_6X10;
+use embedded_graphics::mono_font::iso_8859_1::FONT_6X10;
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::{PrimitiveStyle, Rectangle, RoundedRectangle};
 use embedded_graphics::text::{Alignment, Text};
 
+use crate::config::TemperatureUnit;
 use crate::metrics::QualityLevel;
 use crate::pages::page::Page;
 use crate::sensor_store::SensorDataStore;
-use crate::sensors::SensorType;
-use crate::ui::Drawable;
-use crate::ui::core::{Action, PageEvent, PageId, TouchEvent};
-use crate::ui::styling::{COLOR_BACKGROUND, COLOR_FOREGROUND, WHITE};
+use crate::sensors::{SensorHealth, SensorHealthReport, SensorType};
+use crate::ui::core::{Action, PageEvent, PageId, SystemEvent, TouchEvent};
+use crate::ui::styling::{ColorPalette, Theme};
+use crate::ui::{Clock, Drawable};
 
 // ---------------------------------------------------------------------------
 // Layout constants
 // ---------------------------------------------------------------------------
 
 /// Height of the top header bar
-const HEADER_HEIGHT_PX: u32 = 36;
+pub(super) const HEADER_HEIGHT_PX: u32 = 36;
 
 /// Corner radius for cards
 const CORNER_RADIUS: u32 = 12;
@@ -51,6 +50,12 @@ const CARD_CORNER_RADIUS: u32 = 8;
 /// Settings gear icon touch target width
 const SETTINGS_TOUCH_WIDTH: u32 = 44;
 
+/// Width of the header clock (five 6px characters)
+const CLOCK_WIDTH_PX: u32 = 30;
+
+/// Height of the header clock
+const CLOCK_HEIGHT_PX: u32 = 10;
+
 /// Maximum number of sparkline points per card
 const SPARKLINE_MAX_POINTS: usize = 30;
 
@@ -66,12 +71,6 @@ const SPARKLINE_BOTTOM_MARGIN: u32 = 4;
 /// Number of gradient bands below the sparkline
 const SPARKLINE_GRADIENT_BANDS: u32 = 4;
 
-/// Header text color (muted)
-const COLOR_HEADER_TEXT: Rgb565 = Rgb565::new(20, 40, 20);
-
-/// Muted text for labels
-const COLOR_MUTED_TEXT: Rgb565 = Rgb565::new(18, 36, 18);
-
 /// Number of sensors displayed in the grid
 const GRID_SENSOR_COUNT: usize = 4;
 
@@ -95,6 +94,8 @@ struct SensorCard {
     sensor: SensorType,
     quality: QualityLevel,
     latest_value: Option<f32>,
+    /// Reported sensor health; a failed sensor hides its stale value
+    health: SensorHealth,
     /// Ring buffer of recent values for sparkline rendering
     sparkline: [Option<f32>; SPARKLINE_MAX_POINTS],
     sparkline_count: usize,
@@ -108,6 +109,7 @@ impl SensorCard {
             sensor,
             quality: QualityLevel::Good,
             latest_value: None,
+            health: SensorHealth::Ok,
             sparkline: [None; SPARKLINE_MAX_POINTS],
             sparkline_count: 0,
             sparkline_head: 0,
@@ -131,6 +133,16 @@ impl SensorCard {
         }
     }
 
+    /// Update the sensor health, returning whether it changed.
+    fn update_health(&mut self, health: SensorHealth) -> bool {
+        if self.health == health {
+            return false;
+        }
+        self.health = health;
+        self.dirty = true;
+        true
+    }
+
     /// Map this sensor to its TrendPage PageId
     fn trend_page_id(&self) -> PageId {
         match self.sensor {
@@ -138,6 +150,7 @@ impl SensorCard {
             SensorType::Humidity => PageId::TrendHumidity,
             SensorType::Co2 => PageId::TrendCo2,
             SensorType::Lux => PageId::TrendLux,
+            SensorType::Pressure => PageId::TrendPressure,
         }
     }
 
@@ -146,13 +159,15 @@ impl SensorCard {
         &self,
         display: &mut D,
         bounds: Rectangle,
+        palette: &ColorPalette,
+        temperature_unit: TemperatureUnit,
     ) -> Result<(), D::Error> {
         // Card background with quality-tinted color
         RoundedRectangle::with_equal_corners(
             bounds,
             Size::new(CARD_CORNER_RADIUS, CARD_CORNER_RADIUS),
         )
-        .into_styled(PrimitiveStyle::with_fill(COLOR_FOREGROUND))
+        .into_styled(PrimitiveStyle::with_fill(palette.surface))
         .draw(display)?;
 
         // Sensor name (top-left)
@@ -160,11 +175,32 @@ impl SensorCard {
         Text::with_alignment(
             self.sensor.short_name(),
             Point::new(bounds.top_left.x + 8, name_y),
-            MonoTextStyle::new(&FONT_6X10, COLOR_MUTED_TEXT),
+            MonoTextStyle::new(&FONT_6X10, palette.text_secondary),
             Alignment::Left,
         )
         .draw(display)?;
 
+        // Failed sensor: show a fault state instead of the stale value
+        if self.health == SensorHealth::Failed {
+            Text::with_alignment(
+                SensorHealth::Failed.short_label(),
+                Point::new(bounds.top_left.x + bounds.size.width as i32 - 8, name_y),
+                MonoTextStyle::new(&FONT_6X10, palette.error),
+                Alignment::Right,
+            )
+            .draw(display)?;
+
+            Text::with_alignment(
+                "--",
+                Point::new(bounds.top_left.x + 8, name_y + 16),
+                MonoTextStyle::new(&FONT_6X10, palette.text_secondary),
+                Alignment::Left,
+            )
+            .draw(display)?;
+
+            return Ok(());
+        }
+
         // Quality label (top-right)
         Text::with_alignment(
             self.quality.short_label(),
@@ -176,37 +212,29 @@ impl SensorCard {
 
         // Current value (large, centered below name)
         if let Some(val) = self.latest_value {
-            let mut buf = heapless::String::<16>::new();
-            let _ = match self.sensor {
-                SensorType::Temperature | SensorType::Humidity => {
-                    write!(buf, "{:.1}", val)
-                }
-                SensorType::Co2 | SensorType::Lux => {
-                    write!(buf, "{:.0}", val)
-                }
-            };
+            let buf = self.sensor.format_number(val, temperature_unit);
 
             let val_y = name_y + 16;
             Text::with_alignment(
                 &buf,
                 Point::new(bounds.top_left.x + 8, val_y),
-                MonoTextStyle::new(&FONT_6X10, WHITE),
+                MonoTextStyle::new(&FONT_6X10, palette.text_primary),
                 Alignment::Left,
             )
             .draw(display)?;
 
             // Unit
             Text::with_alignment(
-                self.sensor.unit(),
+                self.sensor.display_unit(temperature_unit),
                 Point::new(bounds.top_left.x + bounds.size.width as i32 - 8, val_y),
-                MonoTextStyle::new(&FONT_6X10, COLOR_MUTED_TEXT),
+                MonoTextStyle::new(&FONT_6X10, palette.text_secondary),
                 Alignment::Right,
             )
             .draw(display)?;
         }
 
         // Sparkline
-        self.draw_sparkline(display, bounds)?;
+        self.draw_sparkline(display, bounds, palette)?;
 
         Ok(())
     }
@@ -216,6 +244,7 @@ impl SensorCard {
         &self,
         display: &mut D,
         card_bounds: Rectangle,
+        palette: &ColorPalette,
     ) -> Result<(), D::Error> {
         if self.sparkline_count < 2 {
             return Ok(());
@@ -262,7 +291,7 @@ impl SensorCard {
         let range = if range < 0.001 { 1.0 } else { range };
 
         let line_color = self.quality.foreground_color();
-        let bg_color = COLOR_FOREGROUND; // card background
+        let bg_color = palette.surface; // card background
 
         // Pre-compute gradient band colors (line_color → bg_color)
         let line_r = ((line_color.into_storage() >> 11) & 0x1F) as u32;
@@ -390,11 +419,14 @@ pub struct HomeGridPage {
     bounds: Rectangle,
     cards: [SensorCard; GRID_SENSOR_COUNT],
     settings_touch_bounds: Rectangle,
+    clock: Clock,
+    temperature_unit: TemperatureUnit,
+    palette: ColorPalette,
     dirty: bool,
 }
 
 impl HomeGridPage {
-    pub fn new(bounds: Rectangle) -> Self {
+    pub fn new(bounds: Rectangle, theme: &Theme) -> Self {
         let cards = [
             SensorCard::new(GRID_SENSORS[0]),
             SensorCard::new(GRID_SENSORS[1]),
@@ -410,14 +442,33 @@ impl HomeGridPage {
             Size::new(SETTINGS_TOUCH_WIDTH, HEADER_HEIGHT_PX),
         );
 
+        // Clock sits just left of the settings gear
+        let clock = Clock::new(Rectangle::new(
+            Point::new(
+                settings_touch_bounds.top_left.x - CLOCK_WIDTH_PX as i32,
+                bounds.top_left.y + ((HEADER_HEIGHT_PX - CLOCK_HEIGHT_PX) / 2) as i32,
+            ),
+            Size::new(CLOCK_WIDTH_PX, CLOCK_HEIGHT_PX),
+        ))
+        .with_palette(theme.palette);
+
         Self {
             bounds,
             cards,
             settings_touch_bounds,
+            clock,
+            temperature_unit: TemperatureUnit::default(),
+            palette: theme.palette,
             dirty: true,
         }
     }
 
+    /// Set the unit temperatures are displayed in.
+    pub fn with_temperature_unit(mut self, unit: TemperatureUnit) -> Self {
+        self.temperature_unit = unit;
+        self
+    }
+
     /// Initialize the page from the centralized sensor data store.
     ///
     /// Restores latest sensor values and sparkline ring buffers so the page
@@ -476,11 +527,34 @@ impl HomeGridPage {
         Rectangle::new(Point::new(x, y), Size::new(card_width, card_height))
     }
 
+    /// Apply a sensor health report to every card, returning whether any changed.
+    fn apply_health(&mut self, report: &SensorHealthReport) -> bool {
+        let mut changed = false;
+        for card in self.cards.iter_mut() {
+            changed |= card.update_health(report.get(card.sensor));
+        }
+        if changed {
+            self.dirty = true;
+        }
+        changed
+    }
+
     /// Map a flat card index (0–3) to (row, col)
     fn card_grid_position(index: usize) -> (usize, usize) {
         (index / 2, index % 2)
     }
 
+    /// Area below the header
+    fn content_bounds(&self) -> Rectangle {
+        Rectangle::new(
+            self.bounds.top_left + Point::new(0, HEADER_HEIGHT_PX as i32),
+            Size::new(
+                self.bounds.size.width,
+                self.bounds.size.height.saturating_sub(HEADER_HEIGHT_PX),
+            ),
+        )
+    }
+
     fn draw_header<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
         let header_rect = Rectangle::new(
             self.bounds.top_left,
@@ -488,7 +562,7 @@ impl HomeGridPage {
         );
 
         RoundedRectangle::with_equal_corners(header_rect, Size::new(CORNER_RADIUS, CORNER_RADIUS))
-            .into_styled(PrimitiveStyle::with_fill(COLOR_FOREGROUND))
+            .into_styled(PrimitiveStyle::with_fill(self.palette.surface))
             .draw(display)?;
 
         // Grid icon (4 small squares)
@@ -496,7 +570,7 @@ impl HomeGridPage {
         let grid_y = self.bounds.top_left.y + 10;
         let sq = 6u32;
         let gap: i32 = 2;
-        let sq_style = PrimitiveStyle::with_fill(COLOR_HEADER_TEXT);
+        let sq_style = PrimitiveStyle::with_fill(self.palette.text_secondary);
 
         for row in 0..2 {
             for col in 0..2 {
@@ -519,7 +593,7 @@ impl HomeGridPage {
                 self.bounds.top_left.x + 36,
                 self.bounds.top_left.y + (HEADER_HEIGHT_PX / 2 + 4) as i32,
             ),
-            MonoTextStyle::new(&FONT_6X10, COLOR_HEADER_TEXT),
+            MonoTextStyle::new(&FONT_6X10, self.palette.text_secondary),
             Alignment::Left,
         )
         .draw(display)?;
@@ -531,13 +605,15 @@ impl HomeGridPage {
             "*",
             Point::new(gear_x, gear_y),
             MonoTextStyle::new(
-                &embedded_graphics::mono_font::ascii::FONT_10X20,
-                COLOR_HEADER_TEXT,
+                &embedded_graphics::mono_font::iso_8859_1::FONT_10X20,
+                self.palette.text_secondary,
             ),
             Alignment::Center,
         )
         .draw(display)?;
 
+        self.clock.draw(display)?;
+
         Ok(())
     }
 }
@@ -600,6 +676,14 @@ impl Page for HomeGridPage {
                 self.dirty = true;
                 true
             }
+            PageEvent::SensorHealth(report) => self.apply_health(report),
+            PageEvent::SystemEvent(SystemEvent::Time(unix_secs)) => {
+                if !self.clock.set_time(*unix_secs) {
+                    return false;
+                }
+                self.dirty = true;
+                true
+            }
             _ => false,
         }
     }
@@ -638,15 +722,19 @@ impl Drawable for HomeGridPage {
             return Ok(());
         }
 
-        display.clear(COLOR_BACKGROUND)?;
+        display.clear(self.palette.background)?;
 
         self.draw_header(display)?;
 
+        if !crate::sensors::any_sensor_configured() {
+            return super::draw_no_sensors(display, self.content_bounds(), &self.palette);
+        }
+
         // Draw 2×2 grid of sensor cards
         for i in 0..GRID_SENSOR_COUNT {
             let (row, col) = Self::card_grid_position(i);
             let card_rect = self.card_bounds(row, col);
-            self.cards[i].draw(display, card_rect)?;
+            self.cards[i].draw(display, card_rect, &self.palette, self.temperature_unit)?;
         }
 
         Ok(())
@@ -15,3 +15,121 @@ pub mod outdoor;
 
 pub use grid::HomeGridPage;
 pub use outdoor::HomePage;
+
+use crate::ui::styling::ColorPalette;
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::mono_font::iso_8859_1::{FONT_6X10, FONT_10X20};
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+
+/// Headline shown in place of sensor tiles when no sensor driver is compiled in
+const NO_SENSORS_MESSAGE: &str = "No sensors configured";
+
+/// Hint shown under the headline
+const NO_SENSORS_HINT: &str = "Enable a sensor-* feature and reflash";
+
+/// Gap between the headline and hint in pixels
+const NO_SENSORS_LINE_GAP_PX: i32 = 16;
+
+/// Draw the "no sensors configured" message centered in `area`
+fn draw_no_sensors<D: DrawTarget<Color = Rgb565>>(
+    display: &mut D,
+    area: Rectangle,
+    palette: &ColorPalette,
+) -> Result<(), D::Error> {
+    let centered = TextStyleBuilder::new()
+        .alignment(Alignment::Center)
+        .baseline(Baseline::Middle)
+        .build();
+    let center = area.center();
+
+    Text::with_text_style(
+        NO_SENSORS_MESSAGE,
+        center - Point::new(0, NO_SENSORS_LINE_GAP_PX / 2),
+        MonoTextStyle::new(&FONT_10X20, palette.text_primary),
+        centered,
+    )
+    .draw(display)?;
+    Text::with_text_style(
+        NO_SENSORS_HINT,
+        center + Point::new(0, NO_SENSORS_LINE_GAP_PX),
+        MonoTextStyle::new(&FONT_6X10, palette.text_secondary),
+        centered,
+    )
+    .draw(display)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framebuffer::FrameBuffer;
+    use crate::pages::Page;
+    use crate::pages::test_harness::{render, screen_bounds};
+    use crate::sensor_store::SensorDataStore;
+    use crate::ui::Theme;
+    use crate::ui::core::SensorData;
+
+    /// Store whose latest sample carries no readings at all
+    fn all_missing_store() -> SensorDataStore {
+        let mut store = SensorDataStore::new();
+        store.push(&SensorData {
+            temperature: None,
+            humidity: None,
+            co2: None,
+            lux: None,
+            timestamp: 1_000,
+        });
+        store
+    }
+
+    /// Whether `content` in `fb` shows exactly the "no sensors configured" state
+    fn shows_no_sensors(fb: &FrameBuffer, content: Rectangle, palette: &ColorPalette) -> bool {
+        let mut expected: FrameBuffer = FrameBuffer::new();
+        expected.clear(palette.background).unwrap();
+        draw_no_sensors(&mut expected, content, palette).unwrap();
+
+        content.points().all(|p| {
+            let (x, y) = (p.x as usize, p.y as usize);
+            fb.pixel(x, y) == expected.pixel(x, y)
+        })
+    }
+
+    /// The header is the same on every home page; anything below it is content
+    fn below(page: &impl Page, header_height: u32) -> Rectangle {
+        let bounds = page.bounds();
+        Rectangle::new(
+            bounds.top_left + Point::new(0, header_height as i32),
+            Size::new(bounds.size.width, bounds.size.height - header_height),
+        )
+    }
+
+    #[test]
+    fn home_pages_show_the_no_sensors_state_only_without_drivers() {
+        let theme = Theme::dark();
+        let store = all_missing_store();
+
+        let mut outdoor = HomePage::new(screen_bounds(), &theme);
+        outdoor.init();
+        outdoor.load_from_store(&store);
+        let content = below(&outdoor, outdoor::HEADER_HEIGHT_PX);
+        let fb = render(&mut outdoor);
+        assert_eq!(
+            shows_no_sensors(&fb, content, &theme.palette),
+            !crate::sensors::any_sensor_configured()
+        );
+
+        let mut grid = HomeGridPage::new(screen_bounds(), &theme);
+        grid.load_from_store(&store);
+        let content = below(&grid, grid::HEADER_HEIGHT_PX);
+        let fb = render(&mut grid);
+        assert_eq!(
+            shows_no_sensors(&fb, content, &theme.palette),
+            !crate::sensors::any_sensor_configured()
+        );
+    }
+}
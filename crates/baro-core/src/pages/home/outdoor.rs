@@ -12,7 +12,7 @@ use core::fmt::Write;
 
 use embedded_graphics::Drawable as EgDrawable;
 use embedded_graphics::mono_font::MonoTextStyle;
-use embedded_graphics::mono_font::ascii::{FONT_6X10, FONT_10X20};
+use embedded_graphics::mono_font::iso_8859_1::{FONT_6X10, FONT_10X20};
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::{
@@ -20,20 +20,21 @@ use embedded_graphics::primitives::{
 };
 use embedded_graphics::text::{Alignment, Text};
 
+use crate::config::TemperatureUnit;
 use crate::metrics::QualityLevel;
 use crate::pages::page::Page;
 use crate::sensor_store::SensorDataStore;
-use crate::sensors::SensorType;
+use crate::sensors::{SensorHealth, SensorHealthReport, SensorType};
 use crate::ui::core::{Action, Drawable, PageEvent, PageId, TouchEvent, Touchable};
 use crate::ui::layouts::scrollable::{ScrollDirection, ScrollableContainer};
-use crate::ui::styling::{COLOR_BACKGROUND, COLOR_FOREGROUND, WHITE};
+use crate::ui::styling::{COLOR_FOREGROUND, ColorPalette, LOGICAL_DISPLAY_SIZE, Theme, WHITE};
 
 // ---------------------------------------------------------------------------
 // Layout constants
 // ---------------------------------------------------------------------------
 
 /// Height of the top header bar
-const HEADER_HEIGHT_PX: u32 = 36;
+pub(super) const HEADER_HEIGHT_PX: u32 = 36;
 
 /// Height of the status banner
 const BANNER_HEIGHT_PX: u32 = 44;
@@ -88,11 +89,11 @@ const QUALITY_BAR_GAP: u32 = 2;
 // Alert overlay constants
 // ---------------------------------------------------------------------------
 
-/// Width of the alert dialog box
-const ALERT_BOX_WIDTH: u32 = 240;
+/// Width of the alert dialog box (three quarters of the screen width)
+const ALERT_BOX_WIDTH: u32 = LOGICAL_DISPLAY_SIZE.width * 3 / 4;
 
-/// Height of the alert dialog box
-const ALERT_BOX_HEIGHT: u32 = 120;
+/// Height of the alert dialog box (half the screen height)
+const ALERT_BOX_HEIGHT: u32 = LOGICAL_DISPLAY_SIZE.height / 2;
 
 /// Width of the dismiss button
 const ALERT_BTN_WIDTH: u32 = 80;
@@ -107,11 +108,8 @@ const ALERT_BTN_BOTTOM_MARGIN: u32 = 12;
 // Colors
 // ---------------------------------------------------------------------------
 
-/// Header text color (muted)
-const COLOR_HEADER_TEXT: Rgb565 = Rgb565::new(20, 40, 20);
-
-/// Muted text for labels
-const COLOR_MUTED_TEXT: Rgb565 = Rgb565::new(18, 36, 18);
+/// Muted text on status-colored backgrounds (banner, alert), which stay dark in both themes
+const COLOR_STATUS_MUTED_TEXT: Rgb565 = Rgb565::new(18, 36, 18);
 
 /// Semi-transparent overlay (dark)
 const COLOR_OVERLAY: Rgb565 = Rgb565::new(5, 10, 5);
@@ -136,6 +134,8 @@ struct SensorRow {
     sensor: SensorType,
     quality: QualityLevel,
     latest_value: Option<f32>,
+    /// Reported sensor health; a failed sensor hides its stale value
+    health: SensorHealth,
     dirty: bool,
 }
 
@@ -145,10 +145,30 @@ impl SensorRow {
             sensor,
             quality: QualityLevel::Good,
             latest_value: None,
+            health: SensorHealth::Ok,
             dirty: true,
         }
     }
 
+    /// Latest value, or `None` while the sensor is reported as failed
+    fn live_value(&self) -> Option<f32> {
+        if self.health == SensorHealth::Failed {
+            None
+        } else {
+            self.latest_value
+        }
+    }
+
+    /// Update the sensor health, returning whether it changed.
+    fn update_health(&mut self, health: SensorHealth) -> bool {
+        if self.health == health {
+            return false;
+        }
+        self.health = health;
+        self.dirty = true;
+        true
+    }
+
     fn update_value(&mut self, value: f32) {
         let new_quality = QualityLevel::assess(self.sensor, value);
         if new_quality != self.quality || self.latest_value != Some(value) {
@@ -165,6 +185,7 @@ impl SensorRow {
             SensorType::Humidity => PageId::TrendHumidity,
             SensorType::Co2 => PageId::TrendCo2,
             SensorType::Lux => PageId::TrendLux,
+            SensorType::Pressure => PageId::TrendPressure,
         }
     }
 
@@ -174,6 +195,7 @@ impl SensorRow {
         display: &mut D,
         x: i32,
         y: i32,
+        palette: &ColorPalette,
     ) -> Result<(), D::Error> {
         let filled_count = match self.quality {
             QualityLevel::Bad => 1,
@@ -187,7 +209,7 @@ impl SensorRow {
             let color = if i < filled_count {
                 self.quality.foreground_color()
             } else {
-                COLOR_MUTED_TEXT
+                palette.text_secondary
             };
 
             Rectangle::new(
@@ -206,22 +228,24 @@ impl SensorRow {
         &self,
         display: &mut D,
         bounds: Rectangle,
+        palette: &ColorPalette,
+        temperature_unit: TemperatureUnit,
     ) -> Result<(), D::Error> {
         // Row background
         RoundedRectangle::with_equal_corners(
             bounds,
             Size::new(PILL_CORNER_RADIUS, PILL_CORNER_RADIUS),
         )
-        .into_styled(PrimitiveStyle::with_fill(COLOR_FOREGROUND))
+        .into_styled(PrimitiveStyle::with_fill(palette.surface))
         .draw(display)?;
 
         let row_center_y = bounds.top_left.y + (ROW_HEIGHT_PX / 2) as i32 + 4;
-        let text_style = MonoTextStyle::new(&FONT_6X10, WHITE);
+        let text_style = MonoTextStyle::new(&FONT_6X10, palette.text_primary);
 
         // Alert indicator for Poor/Bad
         let label_x = bounds.top_left.x + 10;
         if self.quality.sort_key() <= 1 {
-            // Poor or Bad — show alert triangle
+            // Poor or Bad — show alert marker
             Text::with_alignment(
                 self.quality.status_icon(),
                 Point::new(label_x, row_center_y),
@@ -236,22 +260,45 @@ impl SensorRow {
         Text::with_alignment(
             self.sensor.short_name(),
             Point::new(name_x, row_center_y),
-            MonoTextStyle::new(&FONT_6X10, COLOR_MUTED_TEXT),
+            MonoTextStyle::new(&FONT_6X10, palette.text_secondary),
             Alignment::Left,
         )
         .draw(display)?;
 
+        // Failed sensor: show a fault state instead of the stale value
+        if self.health == SensorHealth::Failed {
+            let val_x = bounds.top_left.x + (bounds.size.width / 2) as i32 + 10;
+            Text::with_alignment(
+                "--",
+                Point::new(val_x, row_center_y),
+                MonoTextStyle::new(&FONT_6X10, palette.text_secondary),
+                Alignment::Center,
+            )
+            .draw(display)?;
+
+            Text::with_alignment(
+                SensorHealth::Failed.short_label(),
+                Point::new(
+                    bounds.top_left.x + bounds.size.width as i32 - 10,
+                    row_center_y,
+                ),
+                MonoTextStyle::new(&FONT_6X10, palette.error),
+                Alignment::Right,
+            )
+            .draw(display)?;
+
+            return Ok(());
+        }
+
         // Value (large, centered)
         if let Some(val) = self.latest_value {
-            let mut buf = heapless::String::<16>::new();
-            let _ = match self.sensor {
-                SensorType::Temperature | SensorType::Humidity => {
-                    write!(buf, "{:.1} {}", val, self.sensor.unit())
-                }
-                SensorType::Co2 | SensorType::Lux => {
-                    write!(buf, "{:.0} {}", val, self.sensor.unit())
-                }
-            };
+            let mut buf = heapless::String::<24>::new();
+            let _ = write!(
+                buf,
+                "{} {}",
+                self.sensor.format_number(val, temperature_unit),
+                self.sensor.display_unit(temperature_unit)
+            );
 
             let val_x = bounds.top_left.x + (bounds.size.width / 2) as i32 + 10;
             Text::with_alignment(
@@ -271,7 +318,7 @@ impl SensorRow {
         let bar_y =
             bounds.top_left.y + (ROW_HEIGHT_PX / 2) as i32 - (QUALITY_BAR_SEG_HEIGHT / 2) as i32;
 
-        self.draw_quality_bar(display, bar_x, bar_y)?;
+        self.draw_quality_bar(display, bar_x, bar_y, palette)?;
 
         // Quality text label
         Text::with_alignment(
@@ -311,7 +358,7 @@ impl StatusBanner {
     fn update(&mut self, rows: &[SensorRow], row_count: usize) {
         let qualities: heapless::Vec<QualityLevel, MAX_HOME_SENSORS> = rows[..row_count]
             .iter()
-            .filter(|r| r.latest_value.is_some())
+            .filter(|r| r.live_value().is_some())
             .map(|r| r.quality)
             .collect();
 
@@ -321,7 +368,7 @@ impl StatusBanner {
         // Find worst sensor name
         let worst_name = rows[..row_count]
             .iter()
-            .filter(|r| r.latest_value.is_some())
+            .filter(|r| r.live_value().is_some())
             .min_by_key(|r| r.quality.sort_key())
             .map(|r| r.sensor.short_name())
             .unwrap_or("");
@@ -349,7 +396,7 @@ impl StatusBanner {
             ))
             .draw(display)?;
 
-        // Main status line: "● ALL GOOD" or "▲ POOR — CO2"
+        // Main status line: "* ALL GOOD" or "! NEEDS ATTENTION - CO2"
         let mut status_buf = heapless::String::<32>::new();
         let _ = write!(
             status_buf,
@@ -386,7 +433,7 @@ impl StatusBanner {
             Text::with_alignment(
                 &sub_buf,
                 Point::new(center_x, line2_y),
-                MonoTextStyle::new(&FONT_6X10, COLOR_MUTED_TEXT),
+                MonoTextStyle::new(&FONT_6X10, COLOR_STATUS_MUTED_TEXT),
                 Alignment::Center,
             )
             .draw(display)?;
@@ -394,7 +441,7 @@ impl StatusBanner {
             Text::with_alignment(
                 "All sensors nominal",
                 Point::new(center_x, line2_y),
-                MonoTextStyle::new(&FONT_6X10, COLOR_MUTED_TEXT),
+                MonoTextStyle::new(&FONT_6X10, COLOR_STATUS_MUTED_TEXT),
                 Alignment::Center,
             )
             .draw(display)?;
@@ -428,13 +475,24 @@ impl AlertOverlay {
         }
     }
 
-    /// Compute the dismiss button bounds dynamically from the page bounds
-    fn dismiss_bounds(page_bounds: Rectangle) -> Rectangle {
+    /// Alert dialog bounds, centered in the page bounds
+    fn box_bounds(page_bounds: Rectangle) -> Rectangle {
         let box_x = page_bounds.top_left.x
             + (page_bounds.size.width.saturating_sub(ALERT_BOX_WIDTH) / 2) as i32;
         let box_y = page_bounds.top_left.y
             + (page_bounds.size.height.saturating_sub(ALERT_BOX_HEIGHT) / 2) as i32;
 
+        Rectangle::new(
+            Point::new(box_x, box_y),
+            Size::new(ALERT_BOX_WIDTH, ALERT_BOX_HEIGHT),
+        )
+    }
+
+    /// Compute the dismiss button bounds dynamically from the page bounds
+    fn dismiss_bounds(page_bounds: Rectangle) -> Rectangle {
+        let alert_rect = Self::box_bounds(page_bounds);
+        let (box_x, box_y) = (alert_rect.top_left.x, alert_rect.top_left.y);
+
         let btn_x = box_x + (ALERT_BOX_WIDTH / 2) as i32 - (ALERT_BTN_WIDTH / 2) as i32;
         let btn_y = box_y + ALERT_BOX_HEIGHT as i32
             - ALERT_BTN_HEIGHT as i32
@@ -454,7 +512,7 @@ impl AlertOverlay {
 
         for row in &rows[..row_count] {
             if row.quality == QualityLevel::Bad
-                && let Some(val) = row.latest_value
+                && let Some(val) = row.live_value()
             {
                 let sensor_idx = row.sensor.index();
                 if sensor_idx < MAX_HOME_SENSORS
@@ -485,6 +543,7 @@ impl AlertOverlay {
         &self,
         display: &mut D,
         page_bounds: Rectangle,
+        temperature_unit: TemperatureUnit,
     ) -> Result<(), D::Error> {
         if !self.active {
             return Ok(());
@@ -496,15 +555,8 @@ impl AlertOverlay {
             .draw(display)?;
 
         // Alert box centered on screen
-        let box_x = page_bounds.top_left.x
-            + (page_bounds.size.width.saturating_sub(ALERT_BOX_WIDTH) / 2) as i32;
-        let box_y = page_bounds.top_left.y
-            + (page_bounds.size.height.saturating_sub(ALERT_BOX_HEIGHT) / 2) as i32;
-
-        let alert_rect = Rectangle::new(
-            Point::new(box_x, box_y),
-            Size::new(ALERT_BOX_WIDTH, ALERT_BOX_HEIGHT),
-        );
+        let alert_rect = Self::box_bounds(page_bounds);
+        let (box_x, box_y) = (alert_rect.top_left.x, alert_rect.top_left.y);
 
         // Alert box background
         RoundedRectangle::with_equal_corners(alert_rect, Size::new(CORNER_RADIUS, CORNER_RADIUS))
@@ -531,15 +583,13 @@ impl AlertOverlay {
         .draw(display)?;
 
         // Value
-        let mut val_buf = heapless::String::<16>::new();
-        let _ = match self.sensor {
-            SensorType::Temperature | SensorType::Humidity => {
-                write!(val_buf, "{:.1} {}", self.value, self.sensor.unit())
-            }
-            SensorType::Co2 | SensorType::Lux => {
-                write!(val_buf, "{:.0} {}", self.value, self.sensor.unit())
-            }
-        };
+        let mut val_buf = heapless::String::<24>::new();
+        let _ = write!(
+            val_buf,
+            "{} {}",
+            self.sensor.format_number(self.value, temperature_unit),
+            self.sensor.display_unit(temperature_unit)
+        );
         Text::with_alignment(
             &val_buf,
             Point::new(center_x, box_y + 58),
@@ -594,11 +644,13 @@ pub struct HomePage {
     alert: AlertOverlay,
     settings_touch_bounds: Rectangle,
     last_timestamp: u64,
+    temperature_unit: TemperatureUnit,
+    palette: ColorPalette,
     dirty: bool,
 }
 
 impl HomePage {
-    pub fn new(bounds: Rectangle) -> Self {
+    pub fn new(bounds: Rectangle, theme: &Theme) -> Self {
         let rows = [
             SensorRow::new(DEFAULT_SENSORS[0]),
             SensorRow::new(DEFAULT_SENSORS[1]),
@@ -637,10 +689,18 @@ impl HomePage {
             alert: AlertOverlay::new(),
             settings_touch_bounds,
             last_timestamp: 0,
+            temperature_unit: TemperatureUnit::default(),
+            palette: theme.palette,
             dirty: true,
         }
     }
 
+    /// Set the unit temperatures are displayed in.
+    pub fn with_temperature_unit(mut self, unit: TemperatureUnit) -> Self {
+        self.temperature_unit = unit;
+        self
+    }
+
     /// Kept for API compatibility.
     pub fn init(&mut self) {
         self.dirty = true;
@@ -689,6 +749,20 @@ impl HomePage {
         (row_count as u32 * ROW_HEIGHT_PX) + ((row_count - 1) as u32 * ROW_GAP_PX)
     }
 
+    /// Apply a sensor health report to every row, returning whether any changed.
+    fn apply_health(&mut self, report: &SensorHealthReport) -> bool {
+        let mut changed = false;
+        for row in self.rows[..self.row_count].iter_mut() {
+            changed |= row.update_health(report.get(row.sensor));
+        }
+
+        if changed {
+            self.banner.update(&self.rows, self.row_count);
+            self.dirty = true;
+        }
+        changed
+    }
+
     /// Recompute the sort order (worst quality first)
     fn recompute_sort_order(&mut self) {
         for i in 0..self.row_count {
@@ -736,6 +810,17 @@ impl HomePage {
         row_bottom > vp_top && row_top < vp_bottom
     }
 
+    /// Area below the header
+    fn content_bounds(&self) -> Rectangle {
+        Rectangle::new(
+            self.bounds.top_left + Point::new(0, HEADER_HEIGHT_PX as i32),
+            Size::new(
+                self.bounds.size.width,
+                self.bounds.size.height.saturating_sub(HEADER_HEIGHT_PX),
+            ),
+        )
+    }
+
     fn draw_header<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
         let header_rect = Rectangle::new(
             self.bounds.top_left,
@@ -743,7 +828,7 @@ impl HomePage {
         );
 
         RoundedRectangle::with_equal_corners(header_rect, Size::new(CORNER_RADIUS, CORNER_RADIUS))
-            .into_styled(PrimitiveStyle::with_fill(COLOR_FOREGROUND))
+            .into_styled(PrimitiveStyle::with_fill(self.palette.surface))
             .draw(display)?;
 
         // Grid icon (4 small squares)
@@ -751,7 +836,7 @@ impl HomePage {
         let grid_y = self.bounds.top_left.y + 10;
         let sq = 6u32;
         let gap: i32 = 2;
-        let sq_style = PrimitiveStyle::with_fill(COLOR_HEADER_TEXT);
+        let sq_style = PrimitiveStyle::with_fill(self.palette.text_secondary);
 
         for row in 0..2 {
             for col in 0..2 {
@@ -774,7 +859,7 @@ impl HomePage {
                 self.bounds.top_left.x + 36,
                 self.bounds.top_left.y + (HEADER_HEIGHT_PX / 2 + 4) as i32,
             ),
-            MonoTextStyle::new(&FONT_6X10, COLOR_HEADER_TEXT),
+            MonoTextStyle::new(&FONT_6X10, self.palette.text_secondary),
             Alignment::Left,
         )
         .draw(display)?;
@@ -785,7 +870,7 @@ impl HomePage {
         Text::with_alignment(
             "*",
             Point::new(gear_x, gear_y),
-            MonoTextStyle::new(&FONT_10X20, COLOR_HEADER_TEXT),
+            MonoTextStyle::new(&FONT_10X20, self.palette.text_secondary),
             Alignment::Center,
         )
         .draw(display)?;
@@ -875,6 +960,10 @@ impl Page for HomePage {
                 }
                 None
             }
+            TouchEvent::Release(_) => {
+                self.scroll.handle_touch(event);
+                None
+            }
         }
     }
 
@@ -912,6 +1001,7 @@ impl Page for HomePage {
                 self.dirty = true;
                 true
             }
+            PageEvent::SensorHealth(report) => self.apply_health(report),
             _ => false,
         }
     }
@@ -950,11 +1040,15 @@ impl Drawable for HomePage {
             return Ok(());
         }
 
-        display.clear(COLOR_BACKGROUND)?;
+        display.clear(self.palette.background)?;
 
         // Header
         self.draw_header(display)?;
 
+        if !crate::sensors::any_sensor_configured() {
+            return super::draw_no_sensors(display, self.content_bounds(), &self.palette);
+        }
+
         // Status banner
         let banner_rect = Rectangle::new(
             Point::new(
@@ -975,14 +1069,15 @@ impl Drawable for HomePage {
             }
             let data_idx = self.sort_order[visual_idx];
             let row_rect = self.row_screen_bounds(visual_idx);
-            self.rows[data_idx].draw(display, row_rect)?;
+            self.rows[data_idx].draw(display, row_rect, &self.palette, self.temperature_unit)?;
         }
 
         // Scrollbar indicator
         self.draw_scrollbar(display)?;
 
         // Alert overlay (drawn last, on top)
-        self.alert.draw(display, self.bounds)?;
+        self.alert
+            .draw(display, self.bounds, self.temperature_unit)?;
 
         Ok(())
     }
@@ -1016,3 +1111,22 @@ impl Drawable for HomePage {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alert_box_is_centred_within_the_logical_screen() {
+        let screen = Rectangle::new(Point::zero(), LOGICAL_DISPLAY_SIZE);
+        let alert = AlertOverlay::box_bounds(screen);
+
+        assert_eq!(alert.center(), screen.center());
+        assert!(screen.contains(alert.top_left));
+        assert!(screen.contains(alert.bottom_right().unwrap()));
+
+        let dismiss = AlertOverlay::dismiss_bounds(screen);
+        assert!(alert.contains(dismiss.top_left));
+        assert!(alert.contains(dismiss.bottom_right().unwrap()));
+    }
+}
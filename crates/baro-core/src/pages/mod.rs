@@ -1,17 +1,23 @@
 pub mod constants;
+pub mod error;
 pub mod home;
 pub mod monitor;
 pub mod page;
 pub mod page_manager;
 pub mod settings;
+pub mod splash;
+#[cfg(test)]
+pub(crate) mod test_harness;
 pub mod trend;
 pub mod wifi_status;
 
+pub use error::ErrorPage;
 pub use home::grid::HomeGridPage;
 pub use home::outdoor::HomePage;
-pub use monitor::MonitorPage;
+pub use monitor::{LogSink, MonitorPage};
 pub use page::{Page, PageWrapper};
-pub use page_manager::PageManager;
+pub use page_manager::{PageContext, PageManager, build, trend_page_params};
 pub use settings::{DisplaySettingsPage, SettingsPage};
+pub use splash::SplashPage;
 pub use trend::TrendPage;
 pub use wifi_status::{WifiState, WifiStatusPage};
@@ -2,13 +2,17 @@
 //! Monitor page with live sensor data and log feed.
 //!
 //! Displays a header with back navigation, current sensor values,
-//! and a scrolling log of raw samples and rollup events.
+//! and a scrolling log of raw samples, rollup events, and system log lines.
+//! The feed is a view of the device-wide [`LOG_RING`], so lines logged while
+//! the page was closed show up when it opens. Lines are colored by
+//! [`LogLevel`]. New entries can be mirrored off-device through a
+//! [`LogSink`], e.g. to a serial console.
 
 use core::fmt::Write;
 
 use embedded_graphics::Drawable as EgDrawable;
 use embedded_graphics::mono_font::MonoTextStyle;
-use embedded_graphics::mono_font::ascii::FONT_6X10;
+use embedded_graphics::mono_font::iso_8859_1::FONT_6X10;
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::{
@@ -17,11 +21,14 @@ use embedded_graphics::primitives::{
 use embedded_graphics::text::{Alignment, Text};
 use heapless::{String as HeaplessString, Vec};
 
+use crate::config::TemperatureUnit;
+use crate::log_ring::{LOG_RING, LogLevel};
 use crate::pages::page::Page;
 use crate::sensor_store::SensorDataStore;
+use crate::sensors::SensorType;
 use crate::ui::Drawable;
-use crate::ui::core::{Action, PageEvent, PageId, StorageEvent, TouchEvent};
-use crate::ui::styling::{COLOR_BACKGROUND, COLOR_FOREGROUND, WHITE};
+use crate::ui::core::{Action, PageEvent, PageId, StorageEvent, SystemEvent, TouchEvent};
+use crate::ui::styling::{COLOR_POOR_FOREGROUND, ColorPalette, Theme};
 
 // ---------------------------------------------------------------------------
 // Layout constants
@@ -45,9 +52,6 @@ const SENSOR_SECTION_HEIGHT: u32 = 48;
 /// Y offset for the log feed area
 const LOG_Y_OFFSET: u32 = SENSOR_SECTION_Y + SENSOR_SECTION_HEIGHT + 4;
 
-/// Log feed border stroke width
-const LOG_BORDER_WIDTH: u32 = 1;
-
 /// Log text left padding
 const LOG_TEXT_PADDING_LEFT: i32 = 4;
 
@@ -57,14 +61,33 @@ const LOG_LINE_HEIGHT: i32 = 12;
 /// Horizontal padding
 const PADDING_X: u32 = 6;
 
-/// Maximum log entries
+/// Maximum log entries shown in the feed
 const MAX_LOG_ENTRIES: usize = 20;
 
-/// Header text color (muted)
-const COLOR_HEADER_TEXT: Rgb565 = Rgb565::new(20, 40, 20);
+// ---------------------------------------------------------------------------
+// LogSink
+// ---------------------------------------------------------------------------
+
+/// Receives a copy of every entry added to the monitor log feed
+///
+/// Lets the platform forward the feed somewhere other than the display
+/// (the firmware mirrors it to RTT) without the page knowing about it.
+pub trait LogSink {
+    /// Called once per log entry with the entry's Unix timestamp (seconds)
+    fn log_entry(&self, timestamp: u64, level: LogLevel, message: &str);
+}
 
-/// Muted text color
-const COLOR_MUTED_TEXT: Rgb565 = Rgb565::new(18, 36, 18);
+/// Text color for a feed line at `level`
+///
+/// Errors use the palette's error red, warnings the amber "poor" status color,
+/// and everything else the primary text color.
+pub fn log_level_color(level: LogLevel, palette: &ColorPalette) -> Rgb565 {
+    match level {
+        LogLevel::Info => palette.text_primary,
+        LogLevel::Warn => COLOR_POOR_FOREGROUND,
+        LogLevel::Error => palette.error,
+    }
+}
 
 // ---------------------------------------------------------------------------
 // LogEntry
@@ -72,6 +95,7 @@ const COLOR_MUTED_TEXT: Rgb565 = Rgb565::new(18, 36, 18);
 
 #[derive(Clone)]
 struct LogEntry {
+    level: LogLevel,
     message: HeaplessString<64>,
 }
 
@@ -86,11 +110,18 @@ pub struct MonitorPage {
     last_humidity: Option<f32>,
     last_co2: Option<f32>,
     last_lux: Option<f32>,
+    log_sink: Option<&'static dyn LogSink>,
+    /// Sequence number of the newest `LOG_RING` line copied into the feed
+    last_log_seq: u32,
+    temperature_unit: TemperatureUnit,
+    palette: ColorPalette,
+    /// Log feed border stroke width, from the theme
+    border_width_px: u32,
     dirty: bool,
 }
 
 impl MonitorPage {
-    pub fn new(bounds: Rectangle) -> Self {
+    pub fn new(bounds: Rectangle, theme: &Theme) -> Self {
         Self {
             bounds,
             log_entries: Vec::new(),
@@ -98,12 +129,33 @@ impl MonitorPage {
             last_humidity: None,
             last_co2: None,
             last_lux: None,
+            log_sink: None,
+            last_log_seq: 0,
+            temperature_unit: TemperatureUnit::default(),
+            palette: theme.palette,
+            border_width_px: theme.border_width_px,
             dirty: true,
         }
     }
 
-    /// Kept for API compatibility.
+    /// Mirror every new log entry to `sink`.
+    pub fn with_log_sink(mut self, sink: Option<&'static dyn LogSink>) -> Self {
+        self.log_sink = sink;
+        self
+    }
+
+    /// Set the unit temperatures are displayed in.
+    pub fn with_temperature_unit(mut self, unit: TemperatureUnit) -> Self {
+        self.temperature_unit = unit;
+        self
+    }
+
+    /// Fill the feed with the most recent lines already in `LOG_RING`.
+    ///
+    /// These are not mirrored to the log sink, which saw them when they were
+    /// first shown.
     pub fn init(&mut self) {
+        self.sync_log(false);
         self.dirty = true;
     }
 
@@ -126,7 +178,27 @@ impl MonitorPage {
         )
     }
 
-    fn add_log_entry(&mut self, message: &str) {
+    /// Log `message` to `LOG_RING` and show it in the feed
+    fn add_log_entry(&mut self, timestamp: u64, level: LogLevel, message: &str) {
+        LOG_RING.push(timestamp, level, message);
+        self.sync_log(true);
+    }
+
+    /// Copy lines logged since the last sync into the feed, optionally
+    /// mirroring each one to the log sink
+    fn sync_log(&mut self, mirror: bool) {
+        LOG_RING.for_each_since(self.last_log_seq, |line| {
+            if mirror && let Some(sink) = self.log_sink {
+                sink.log_entry(line.timestamp, line.level, &line.message);
+            }
+            self.push_feed_entry(line.level, &line.message);
+            self.last_log_seq = line.seq;
+        });
+    }
+
+    /// Append to the feed, dropping the oldest entry once it holds
+    /// `MAX_LOG_ENTRIES`
+    fn push_feed_entry(&mut self, level: LogLevel, message: &str) {
         let mut entry_text = HeaplessString::<64>::new();
         entry_text.push_str(message).ok();
 
@@ -138,11 +210,13 @@ impl MonitorPage {
                 }
             }
             if let Some(last) = self.log_entries.get_mut(MAX_LOG_ENTRIES - 1) {
+                last.level = level;
                 last.message = entry_text;
             }
         } else {
             self.log_entries
                 .push(LogEntry {
+                    level,
                     message: entry_text,
                 })
                 .ok();
@@ -164,7 +238,7 @@ impl MonitorPage {
         );
 
         RoundedRectangle::with_equal_corners(header_rect, Size::new(CORNER_RADIUS, CORNER_RADIUS))
-            .into_styled(PrimitiveStyle::with_fill(COLOR_FOREGROUND))
+            .into_styled(PrimitiveStyle::with_fill(self.palette.surface))
             .draw(display)?;
 
         let text_y = self.bounds.top_left.y + (HEADER_HEIGHT_PX / 2 + 4) as i32;
@@ -173,7 +247,7 @@ impl MonitorPage {
         Text::with_alignment(
             "<",
             Point::new(self.bounds.top_left.x + 12, text_y),
-            MonoTextStyle::new(&FONT_6X10, COLOR_HEADER_TEXT),
+            MonoTextStyle::new(&FONT_6X10, self.palette.text_secondary),
             Alignment::Left,
         )
         .draw(display)?;
@@ -182,7 +256,7 @@ impl MonitorPage {
         Text::with_alignment(
             "MONITOR",
             Point::new(self.bounds.top_left.x + 28, text_y),
-            MonoTextStyle::new(&FONT_6X10, COLOR_HEADER_TEXT),
+            MonoTextStyle::new(&FONT_6X10, self.palette.text_secondary),
             Alignment::Left,
         )
         .draw(display)?;
@@ -196,13 +270,17 @@ impl MonitorPage {
     ) -> Result<(), D::Error> {
         let x = self.bounds.top_left.x + PADDING_X as i32;
         let y_base = self.bounds.top_left.y + SENSOR_SECTION_Y as i32;
-        let text_style = MonoTextStyle::new(&FONT_6X10, WHITE);
-        let _label_style = MonoTextStyle::new(&FONT_6X10, COLOR_MUTED_TEXT);
+        let text_style = MonoTextStyle::new(&FONT_6X10, self.palette.text_primary);
 
         // Row 1: Temperature + Humidity
         let mut buf = HeaplessString::<32>::new();
         if let Some(t) = self.last_temperature {
-            let _ = write!(buf, "T: {:.1}C", t);
+            let _ = write!(
+                buf,
+                "T: {}",
+                SensorType::Temperature.format_number(t, self.temperature_unit)
+            );
+            let _ = buf.push_str(self.temperature_unit.suffix());
         } else {
             let _ = write!(buf, "T: --");
         }
@@ -239,7 +317,7 @@ impl MonitorPage {
             Point::new(x, sep_y),
             Size::new(self.bounds.size.width.saturating_sub(PADDING_X * 2), 1),
         )
-        .into_styled(PrimitiveStyle::with_fill(COLOR_MUTED_TEXT))
+        .into_styled(PrimitiveStyle::with_fill(self.palette.text_secondary))
         .draw(display)?;
 
         Ok(())
@@ -253,13 +331,12 @@ impl MonitorPage {
 
         // Log area background with border
         let style = PrimitiveStyleBuilder::new()
-            .fill_color(COLOR_BACKGROUND)
-            .stroke_color(COLOR_MUTED_TEXT)
-            .stroke_width(LOG_BORDER_WIDTH)
+            .fill_color(self.palette.background)
+            .stroke_color(self.palette.text_secondary)
+            .stroke_width(self.border_width_px)
             .build();
         log_area.into_styled(style).draw(display)?;
 
-        let text_style = MonoTextStyle::new(&FONT_6X10, WHITE);
         let mut y = log_area.top_left.y + LOG_LINE_HEIGHT;
         let max_y = log_area.top_left.y + log_area.size.height as i32 - 2;
 
@@ -267,6 +344,8 @@ impl MonitorPage {
             if y > max_y {
                 break;
             }
+            let text_style =
+                MonoTextStyle::new(&FONT_6X10, log_level_color(entry.level, &self.palette));
             Text::new(
                 entry.message.as_str(),
                 Point::new(log_area.top_left.x + LOG_TEXT_PADDING_LEFT, y),
@@ -335,29 +414,41 @@ impl Page for MonitorPage {
                         data.lux.unwrap_or(0.0),
                     );
                 }
-                self.add_log_entry(&log_msg);
+                self.add_log_entry(data.timestamp, LogLevel::Info, &log_msg);
 
                 self.dirty = true;
                 true
             }
             PageEvent::StorageEvent(storage_event) => {
                 match storage_event {
-                    StorageEvent::RawSample { sensor, value, .. } => {
+                    StorageEvent::RawSample {
+                        sensor,
+                        value,
+                        timestamp,
+                    } => {
                         let mut log_msg = HeaplessString::<64>::new();
                         let _ = write!(log_msg, "[Raw] {}: {:.2}", sensor, value);
-                        self.add_log_entry(&log_msg);
+                        self.add_log_entry(*timestamp, LogLevel::Info, &log_msg);
                     }
                     StorageEvent::Rollup {
-                        interval, count, ..
+                        interval,
+                        count,
+                        timestamp,
                     } => {
                         let mut log_msg = HeaplessString::<64>::new();
                         let _ = write!(log_msg, "[Rollup] {}: {}", interval, count);
-                        self.add_log_entry(&log_msg);
+                        self.add_log_entry(*timestamp, LogLevel::Info, &log_msg);
                     }
                 }
                 self.dirty = true;
                 true
             }
+            PageEvent::SystemEvent(SystemEvent::LogLine(..)) => {
+                // The display manager already logged the line to LOG_RING
+                self.sync_log(true);
+                self.dirty = true;
+                true
+            }
             _ => false,
         }
     }
@@ -396,7 +487,7 @@ impl Drawable for MonitorPage {
             return Ok(());
         }
 
-        display.clear(COLOR_BACKGROUND)?;
+        display.clear(self.palette.background)?;
         self.draw_header(display)?;
         self.draw_sensor_values(display)?;
         self.draw_log_feed(display)?;
@@ -420,3 +511,191 @@ impl Drawable for MonitorPage {
         self.dirty = true;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::test_harness::screen_bounds;
+    use alloc::string::String;
+    use alloc::vec::Vec as AllocVec;
+    use core::cell::RefCell;
+    use embassy_sync::blocking_mutex::Mutex;
+    use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+
+    /// Timestamp, level and message of one forwarded entry
+    type Captured = (u64, LogLevel, String);
+
+    /// Sink recording every forwarded entry
+    struct CapturingSink {
+        entries: Mutex<CriticalSectionRawMutex, RefCell<AllocVec<Captured>>>,
+    }
+
+    impl LogSink for CapturingSink {
+        fn log_entry(&self, timestamp: u64, level: LogLevel, message: &str) {
+            self.entries.lock(|entries| {
+                entries
+                    .borrow_mut()
+                    .push((timestamp, level, message.into()))
+            });
+        }
+    }
+
+    static SINK: CapturingSink = CapturingSink {
+        entries: Mutex::new(RefCell::new(AllocVec::new())),
+    };
+
+    /// Entries the sink received with `timestamp`
+    ///
+    /// `LOG_RING` is shared by every test, so entries are told apart by timestamp.
+    fn captured(timestamp: u64) -> AllocVec<(LogLevel, String)> {
+        SINK.entries.lock(|entries| {
+            entries
+                .borrow()
+                .iter()
+                .filter(|(ts, ..)| *ts == timestamp)
+                .map(|(_, level, message)| (*level, message.clone()))
+                .collect()
+        })
+    }
+
+    #[test]
+    fn new_entries_are_forwarded_to_sink() {
+        const TS: u64 = 1_700_068_001;
+        let mut page =
+            MonitorPage::new(screen_bounds(), &Theme::default()).with_log_sink(Some(&SINK));
+        page.init();
+
+        page.on_event(&PageEvent::StorageEvent(StorageEvent::RawSample {
+            sensor: "temperature",
+            value: 22.5,
+            timestamp: TS,
+        }));
+        page.on_event(&PageEvent::StorageEvent(StorageEvent::Rollup {
+            interval: "5m",
+            count: 30,
+            timestamp: TS,
+        }));
+
+        assert_eq!(
+            captured(TS),
+            [
+                (LogLevel::Info, String::from("[Raw] temperature: 22.50")),
+                (LogLevel::Info, String::from("[Rollup] 5m: 30")),
+            ]
+        );
+    }
+
+    #[test]
+    fn only_lines_logged_after_init_are_forwarded() {
+        const TS: u64 = 1_700_068_002;
+        LOG_RING.push(TS, LogLevel::Warn, "before the page opened");
+
+        let mut page =
+            MonitorPage::new(screen_bounds(), &Theme::default()).with_log_sink(Some(&SINK));
+        page.init();
+
+        // The display manager logs system lines to LOG_RING, then notifies the page
+        LOG_RING.push(TS, LogLevel::Error, "[SD] Write failed");
+        page.on_event(&PageEvent::SystemEvent(SystemEvent::LogLine(
+            LogLevel::Error,
+            HeaplessString::try_from("[SD] Write failed").unwrap(),
+        )));
+
+        assert_eq!(
+            captured(TS),
+            [(LogLevel::Error, String::from("[SD] Write failed"))]
+        );
+    }
+
+    /// Feed entries whose message is `message`
+    fn feed_count(page: &MonitorPage, message: &str) -> usize {
+        page.log_entries
+            .iter()
+            .filter(|entry| entry.message == message)
+            .count()
+    }
+
+    #[test]
+    fn log_line_event_appends_exactly_one_entry() {
+        const MESSAGE: &str = "[WiFi] Reconnected (attempt 3)";
+        let mut page = MonitorPage::new(screen_bounds(), &Theme::default());
+        page.init();
+        Page::mark_clean(&mut page);
+
+        LOG_RING.push(1_700_068_003, LogLevel::Info, MESSAGE);
+        let event = PageEvent::SystemEvent(SystemEvent::LogLine(
+            LogLevel::Info,
+            HeaplessString::try_from(MESSAGE).unwrap(),
+        ));
+        assert!(page.on_event(&event));
+        assert_eq!(feed_count(&page, MESSAGE), 1);
+        assert!(Page::is_dirty(&page));
+
+        // A repeated notification doesn't copy the same line again
+        page.on_event(&event);
+        assert_eq!(feed_count(&page, MESSAGE), 1);
+    }
+
+    #[test]
+    fn feed_keeps_the_newest_entries_up_to_the_cap() {
+        let mut page = MonitorPage::new(screen_bounds(), &Theme::default());
+        for i in 0..MAX_LOG_ENTRIES + 5 {
+            let mut message = HeaplessString::<64>::new();
+            let _ = write!(message, "line {i}");
+            page.push_feed_entry(LogLevel::Info, &message);
+        }
+
+        assert_eq!(page.log_entries.len(), MAX_LOG_ENTRIES);
+        assert_eq!(page.log_entries[0].message, "line 5");
+        assert_eq!(page.log_entries[MAX_LOG_ENTRIES - 1].message, "line 24");
+    }
+
+    #[test]
+    fn error_entries_are_stored_with_the_red_error_color() {
+        const MESSAGE: &str = "[NTP] Time sync failed";
+        for theme in [Theme::dark(), Theme::light(), Theme::high_contrast()] {
+            let mut page = MonitorPage::new(screen_bounds(), &theme);
+            page.init();
+
+            LOG_RING.push(1_700_068_004, LogLevel::Error, MESSAGE);
+            page.on_event(&PageEvent::SystemEvent(SystemEvent::LogLine(
+                LogLevel::Error,
+                HeaplessString::try_from(MESSAGE).unwrap(),
+            )));
+
+            let entry = page
+                .log_entries
+                .iter()
+                .find(|entry| entry.message == MESSAGE)
+                .unwrap();
+            assert_eq!(entry.level, LogLevel::Error);
+
+            // The error color is the palette's red, distinct from info text
+            let color = log_level_color(entry.level, &theme.palette);
+            assert_eq!(color, theme.palette.error);
+            assert_ne!(color, log_level_color(LogLevel::Info, &theme.palette));
+            let (r, g, b) = (color.r() << 3, color.g() << 2, color.b() << 3);
+            assert!(r > g && r > b, "{color:?} is not red");
+        }
+    }
+
+    #[test]
+    fn sensor_updates_log_at_info_level() {
+        let mut page = MonitorPage::new(screen_bounds(), &Theme::default());
+        page.init();
+        page.on_event(&PageEvent::SensorUpdate(crate::ui::core::SensorData {
+            temperature: Some(21.5),
+            humidity: None,
+            co2: None,
+            lux: None,
+            timestamp: 1_700_068_005,
+        }));
+
+        let entry = page
+            .log_entries
+            .iter()
+            .find(|entry| entry.message.starts_with("[Sensor] T:21.5"))
+            .unwrap();
+        assert_eq!(entry.level, LogLevel::Info);
+    }
+}
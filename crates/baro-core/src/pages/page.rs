@@ -19,6 +19,7 @@
 //! `heapless::Vec<PageWrapper, N>` and routes calls through this wrapper.
 
 use crate::ui::core::{Action, DirtyRegion, PageId, TouchEvent};
+use embassy_time::Duration;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::Rectangle;
 use heapless::Vec;
@@ -36,9 +37,11 @@ use alloc::boxed::Box;
 /// [`PageManager`](super::page_manager::PageManager) calls these methods in
 /// a well-defined order each frame:
 ///
-/// 1. **`on_activate`** — once, when the page becomes the active page.
+/// 1. **`on_activate`** — once, when the page becomes the active page,
+///    followed by **`take_action`** until it returns `None`.
 /// 2. **`on_event`** — zero or more times per frame for incoming events.
-/// 3. **`update`** — once per frame to advance internal state.
+/// 3. **`update`** — once per frame to advance internal state, and every
+///    `wants_tick` interval while the page asks for one.
 /// 4. **`handle_touch`** — when a touch event targets this page.
 /// 5. **`draw_page`** — when `is_dirty()` is true.
 /// 6. **`on_deactivate`** — once, when navigating away from the page.
@@ -58,9 +61,26 @@ pub trait Page {
     /// Process a touch event and optionally return a navigation [`Action`].
     fn handle_touch(&mut self, event: TouchEvent) -> Option<Action>;
 
+    /// Take the next [`Action`] the page raised outside of touch handling.
+    ///
+    /// Polled after `on_activate` until it returns `None`; used by pages that
+    /// need something from their owner, such as stored history.
+    fn take_action(&mut self) -> Option<Action> {
+        None
+    }
+
     /// Advance per-frame state (animations, timers, etc.).
     fn update(&mut self);
 
+    /// Interval at which the page wants `update` called and a redraw checked.
+    ///
+    /// The display manager otherwise only redraws in response to events, so
+    /// animating pages (spinners, marquees) return their frame interval here.
+    /// Re-read after every tick, so a page can stop ticking by returning `None`.
+    fn wants_tick(&self) -> Option<Duration> {
+        None
+    }
+
     /// Handle an incoming [`PageEvent`](crate::ui::core::PageEvent).
     ///
     /// Returns `true` if the event was consumed and the page needs a redraw.
@@ -127,10 +147,18 @@ impl<T: Page> Page for Box<T> {
         (**self).handle_touch(event)
     }
 
+    fn take_action(&mut self) -> Option<Action> {
+        (**self).take_action()
+    }
+
     fn update(&mut self) {
         (**self).update()
     }
 
+    fn wants_tick(&self) -> Option<Duration> {
+        (**self).wants_tick()
+    }
+
     fn on_event(&mut self, event: &crate::ui::core::PageEvent) -> bool {
         (**self).on_event(event)
     }
@@ -163,92 +191,308 @@ impl<T: Page> Page for Box<T> {
 // PageWrapper
 // ---------------------------------------------------------------------------
 
-/// Enum-based wrapper that stores one of the concrete page types.
+/// Declare [`PageWrapper`] and its `delegate_page!` dispatch macro from a
+/// single list of `Variant(PageType)` pairs.
 ///
-/// Using an enum instead of `dyn Page` avoids the overhead (and `alloc`
-/// requirements) of trait objects while still allowing the
-/// [`PageManager`](super::page_manager::PageManager) to hold a heterogeneous
-/// collection of pages.
-///
-/// Each variant wraps its page in a [`Box`] to keep the enum size uniform
-/// regardless of the underlying page's stack footprint.
-///
-/// When adding a new page to the application, add a variant here and
-/// implement the delegation in the [`Page`] impl below.
-pub enum PageWrapper {
-    Home(Box<crate::pages::home::outdoor::HomePage>),
-    HomeGrid(Box<crate::pages::home::grid::HomeGridPage>),
-    Settings(Box<crate::pages::settings::SettingsPage>),
-    DisplaySettings(Box<crate::pages::settings::DisplaySettingsPage>),
-    Monitor(Box<crate::pages::monitor::MonitorPage>),
-    TrendPage(Box<crate::pages::trend::TrendPage>),
-    WifiStatus(Box<crate::pages::wifi_status::WifiStatusPage>),
-}
+/// Both are generated from the same list, so registering a page here is
+/// enough for every `Page` method on the wrapper to forward to it.
+macro_rules! page_wrapper {
+    ($($variant:ident($page:ty)),* $(,)?) => {
+        /// Enum-based wrapper that stores one of the concrete page types.
+        ///
+        /// Using an enum instead of `dyn Page` avoids the overhead (and `alloc`
+        /// requirements) of trait objects while still allowing the
+        /// [`PageManager`](super::page_manager::PageManager) to hold a
+        /// heterogeneous collection of pages.
+        ///
+        /// Each variant wraps its page in a [`Box`] to keep the enum size
+        /// uniform regardless of the underlying page's stack footprint.
+        ///
+        /// When adding a new page to the application, register it in the
+        /// `page_wrapper!` list below; the [`Page`] delegation follows from it.
+        pub enum PageWrapper {
+            $($variant(Box<$page>),)*
+        }
 
-/// Helper macro to delegate a `Page` method call through every `PageWrapper` variant.
-macro_rules! delegate_page {
-    ($self:ident, $method:ident $(, $arg:expr)*) => {
-        match $self {
-            PageWrapper::Home(page) => page.$method($($arg),*),
-            PageWrapper::HomeGrid(page) => page.$method($($arg),*),
-            PageWrapper::Settings(page) => page.$method($($arg),*),
-            PageWrapper::DisplaySettings(page) => page.$method($($arg),*),
-            PageWrapper::Monitor(page) => page.$method($($arg),*),
-            PageWrapper::TrendPage(page) => page.$method($($arg),*),
-            PageWrapper::WifiStatus(page) => page.$method($($arg),*),
+        /// Evaluate `$body` with `$inner` bound to the page inside any variant.
+        macro_rules! delegate_page {
+            ($wrapper:expr, $inner:ident => $body:expr) => {
+                match $wrapper {
+                    $(PageWrapper::$variant($inner) => $body,)*
+                }
+            };
         }
     };
 }
 
+page_wrapper! {
+    Home(crate::pages::home::outdoor::HomePage),
+    HomeGrid(crate::pages::home::grid::HomeGridPage),
+    Settings(crate::pages::settings::SettingsPage),
+    DisplaySettings(crate::pages::settings::DisplaySettingsPage),
+    Monitor(crate::pages::monitor::MonitorPage),
+    TrendPage(crate::pages::trend::TrendPage),
+    WifiStatus(crate::pages::wifi_status::WifiStatusPage),
+    Splash(crate::pages::splash::SplashPage),
+    Error(crate::pages::error::ErrorPage),
+}
+
 impl Page for PageWrapper {
     fn id(&self) -> PageId {
-        delegate_page!(self, id)
+        delegate_page!(self, page => page.id())
     }
 
     fn title(&self) -> &str {
-        delegate_page!(self, title)
+        delegate_page!(self, page => page.title())
     }
 
     fn on_activate(&mut self) {
-        delegate_page!(self, on_activate)
+        delegate_page!(self, page => page.on_activate())
     }
 
     fn on_deactivate(&mut self) {
-        delegate_page!(self, on_deactivate)
+        delegate_page!(self, page => page.on_deactivate())
     }
 
     fn handle_touch(&mut self, event: TouchEvent) -> Option<Action> {
-        delegate_page!(self, handle_touch, event)
+        delegate_page!(self, page => page.handle_touch(event))
+    }
+
+    fn take_action(&mut self) -> Option<Action> {
+        delegate_page!(self, page => page.take_action())
     }
 
     fn update(&mut self) {
-        delegate_page!(self, update)
+        delegate_page!(self, page => page.update())
+    }
+
+    fn wants_tick(&self) -> Option<Duration> {
+        delegate_page!(self, page => page.wants_tick())
     }
 
     fn on_event(&mut self, event: &crate::ui::core::PageEvent) -> bool {
-        delegate_page!(self, on_event, event)
+        delegate_page!(self, page => page.on_event(event))
     }
 
     fn draw_page<D: DrawTarget<Color = embedded_graphics::pixelcolor::Rgb565>>(
         &mut self,
         display: &mut D,
     ) -> Result<(), D::Error> {
-        delegate_page!(self, draw_page, display)
+        delegate_page!(self, page => page.draw_page(display))
     }
 
     fn bounds(&self) -> Rectangle {
-        delegate_page!(self, bounds)
+        delegate_page!(self, page => page.bounds())
     }
 
     fn is_dirty(&self) -> bool {
-        delegate_page!(self, is_dirty)
+        delegate_page!(self, page => page.is_dirty())
     }
 
     fn mark_clean(&mut self) {
-        delegate_page!(self, mark_clean)
+        delegate_page!(self, page => page.mark_clean())
     }
 
     fn mark_dirty(&mut self) {
-        delegate_page!(self, mark_dirty)
+        delegate_page!(self, page => page.mark_dirty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app_state::{AppError, AppRunState, WifiError};
+    use crate::config::{HomePageMode, TemperatureUnit};
+    use crate::framebuffer::FrameBuffer;
+    use crate::pages::error::ErrorPage;
+    use crate::pages::home::grid::HomeGridPage;
+    use crate::pages::home::outdoor::HomePage;
+    use crate::pages::monitor::MonitorPage;
+    use crate::pages::settings::{DisplaySettingsPage, SettingsPage};
+    use crate::pages::splash::SplashPage;
+    use crate::pages::test_harness::{render, screen_bounds};
+    use crate::pages::trend::TrendPage;
+    use crate::pages::wifi_status::{WifiState, WifiStatusPage};
+    use crate::sensors::{SensorCalibration, SensorType};
+    use crate::storage::TimeWindow;
+    use crate::ui::Theme;
+    use crate::ui::core::{PageEvent, SensorData, SystemEvent, TouchPoint};
+    use alloc::vec;
+
+    /// Evaluate `$body` with `$inner` bound to the unwrapped page
+    ///
+    /// Spelled out by hand so the test doesn't rely on the dispatch it checks,
+    /// and stops compiling when a variant is added without updating it.
+    macro_rules! with_inner {
+        ($wrapper:expr, $inner:ident => $body:expr) => {
+            match $wrapper {
+                PageWrapper::Home(page) => {
+                    let $inner: &mut HomePage = page;
+                    $body
+                }
+                PageWrapper::HomeGrid(page) => {
+                    let $inner: &mut HomeGridPage = page;
+                    $body
+                }
+                PageWrapper::Settings(page) => {
+                    let $inner: &mut SettingsPage = page;
+                    $body
+                }
+                PageWrapper::DisplaySettings(page) => {
+                    let $inner: &mut DisplaySettingsPage = page;
+                    $body
+                }
+                PageWrapper::Monitor(page) => {
+                    let $inner: &mut MonitorPage = page;
+                    $body
+                }
+                PageWrapper::TrendPage(page) => {
+                    let $inner: &mut TrendPage = page;
+                    $body
+                }
+                PageWrapper::WifiStatus(page) => {
+                    let $inner: &mut WifiStatusPage = page;
+                    $body
+                }
+                PageWrapper::Splash(page) => {
+                    let $inner: &mut SplashPage = page;
+                    $body
+                }
+                PageWrapper::Error(page) => {
+                    let $inner: &mut ErrorPage = page;
+                    $body
+                }
+            }
+        };
+    }
+
+    /// One freshly built wrapper per variant
+    fn every_variant() -> alloc::vec::Vec<PageWrapper> {
+        let theme = Theme::dark();
+        let bounds = screen_bounds();
+        vec![
+            PageWrapper::Home(Box::new(HomePage::new(bounds, &theme))),
+            PageWrapper::HomeGrid(Box::new(HomeGridPage::new(bounds, &theme))),
+            PageWrapper::Settings(Box::new(SettingsPage::new(
+                bounds,
+                30,
+                80,
+                40,
+                &SensorCalibration::default(),
+                &theme,
+            ))),
+            PageWrapper::DisplaySettings(Box::new(DisplaySettingsPage::new(
+                bounds,
+                HomePageMode::Outdoor,
+                TemperatureUnit::Celsius,
+                &theme,
+            ))),
+            PageWrapper::Monitor(Box::new(MonitorPage::new(bounds, &theme))),
+            PageWrapper::TrendPage(Box::new(TrendPage::new(
+                bounds,
+                SensorType::Co2,
+                TimeWindow::OneHour,
+                &theme,
+            ))),
+            PageWrapper::WifiStatus(Box::new(WifiStatusPage::new(WifiState::Error, &theme))),
+            PageWrapper::Splash(Box::new(SplashPage::new(bounds, &theme))),
+            PageWrapper::Error(Box::new(ErrorPage::new(
+                bounds,
+                &AppError::Wifi(WifiError::InitFailed),
+                &theme,
+            ))),
+        ]
+    }
+
+    fn same_pixels(a: &FrameBuffer, b: &FrameBuffer) -> bool {
+        let size = screen_bounds().size;
+        (0..size.height as usize)
+            .all(|y| (0..size.width as usize).all(|x| a.pixel(x, y) == b.pixel(x, y)))
+    }
+
+    #[test]
+    fn every_variant_forwards_the_read_only_methods() {
+        for mut wrapper in every_variant() {
+            let id = wrapper.id();
+            let title = alloc::string::String::from(wrapper.title());
+            let bounds = Page::bounds(&wrapper);
+            let dirty = Page::is_dirty(&wrapper);
+            let tick = wrapper.wants_tick();
+
+            with_inner!(&mut wrapper, page => {
+                assert_eq!(id, Page::id(page));
+                assert_eq!(title, Page::title(page), "{id:?}");
+                assert_eq!(bounds, Page::bounds(page), "{id:?}");
+                assert_eq!(dirty, Page::is_dirty(page), "{id:?}");
+                assert_eq!(tick, Page::wants_tick(page), "{id:?}");
+            });
+        }
+    }
+
+    #[test]
+    fn every_variant_forwards_dirty_tracking() {
+        for mut wrapper in every_variant() {
+            let id = wrapper.id();
+
+            Page::mark_clean(&mut wrapper);
+            with_inner!(&mut wrapper, page => assert!(!Page::is_dirty(page), "{id:?}"));
+
+            Page::mark_dirty(&mut wrapper);
+            with_inner!(&mut wrapper, page => assert!(Page::is_dirty(page), "{id:?}"));
+        }
+    }
+
+    #[test]
+    fn every_variant_forwards_input_lifecycle_and_drawing() {
+        let touch = TouchEvent::Press(TouchPoint::new(10, 10));
+        let events = [
+            PageEvent::SensorUpdate(SensorData {
+                temperature: Some(21.5),
+                humidity: Some(40.0),
+                co2: Some(650.0),
+                lux: Some(120.0),
+                timestamp: 1_700_000_000,
+            }),
+            PageEvent::SystemEvent(SystemEvent::RunState(AppRunState::WifiConnecting)),
+        ];
+
+        // The same calls through the wrapper and on an identical bare page
+        // must give the same answers
+        for (mut wrapper, mut twin) in every_variant().into_iter().zip(every_variant()) {
+            let id = wrapper.id();
+
+            wrapper.on_activate();
+            let mut actions = vec![];
+            while let Some(action) = wrapper.take_action() {
+                actions.push(action);
+            }
+            let consumed: alloc::vec::Vec<bool> =
+                events.iter().map(|event| wrapper.on_event(event)).collect();
+            wrapper.update();
+            let touched = wrapper.handle_touch(touch);
+            wrapper.on_deactivate();
+            let drawn = render(&mut wrapper);
+
+            with_inner!(&mut twin, page => {
+                page.on_activate();
+                let mut twin_actions = vec![];
+                while let Some(action) = page.take_action() {
+                    twin_actions.push(action);
+                }
+                assert_eq!(actions, twin_actions, "{id:?}");
+                for (event, consumed) in events.iter().zip(&consumed) {
+                    assert_eq!(page.on_event(event), *consumed, "{id:?}");
+                }
+                page.update();
+                assert_eq!(page.handle_touch(touch), touched, "{id:?}");
+                page.on_deactivate();
+                // The monitor feed shows the shared LOG_RING, which the
+                // wrapped page has already written to
+                let twin_drawn = render(page);
+                if id != PageId::Monitor {
+                    assert!(same_pixels(&twin_drawn, &drawn), "{id:?} drew differently");
+                }
+            });
+        }
     }
 }
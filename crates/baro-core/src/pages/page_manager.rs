@@ -1,13 +1,30 @@
 // src/pages/page_manager.rs
 //! Page manager with navigation and event dispatching.
 
+use crate::config::{
+    DEFAULT_TREND_WINDOWS, HomePageMode, TemperatureUnit, trend_window_slot, validate_trend_window,
+};
+use crate::pages::home::grid::HomeGridPage;
+use crate::pages::home::outdoor::HomePage;
+use crate::pages::monitor::{LogSink, MonitorPage};
 use crate::pages::page::{Page, PageWrapper};
+use crate::pages::settings::{DisplaySettingsPage, SettingsPage};
+use crate::pages::splash::SplashPage;
+use crate::pages::trend::TrendPage;
+use crate::pages::wifi_status::{WifiState, WifiStatusPage};
+use crate::sensor_store::SensorDataStore;
+use crate::sensors::{SensorCalibration, SensorType};
+use crate::storage::TimeWindow;
+use crate::ui::Theme;
 use crate::ui::core::{Action, PageEvent, PageId, TouchEvent};
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::Rectangle;
 use heapless::Vec;
 use log::debug;
 
+extern crate alloc;
+use alloc::boxed::Box;
+
 /// Manages page navigation, rendering, and event dispatching.
 pub struct PageManager {
     pages: Vec<PageWrapper, 8>,
@@ -163,3 +180,260 @@ impl PageManager {
         self.display_bounds
     }
 }
+
+// ---------------------------------------------------------------------------
+// Page construction
+// ---------------------------------------------------------------------------
+
+/// Sensor and default time window shown by each dedicated trend page
+///
+/// Temperature, humidity and CO₂ can be overridden by the user's stored
+/// preference (`PageContext::trend_windows`).
+const TREND_PAGES: [(PageId, SensorType, TimeWindow); 5] = [
+    (
+        PageId::TrendTemperature,
+        SensorType::Temperature,
+        DEFAULT_TREND_WINDOWS[0],
+    ),
+    (
+        PageId::TrendHumidity,
+        SensorType::Humidity,
+        DEFAULT_TREND_WINDOWS[1],
+    ),
+    (PageId::TrendCo2, SensorType::Co2, DEFAULT_TREND_WINDOWS[2]),
+    (PageId::TrendLux, SensorType::Lux, TimeWindow::ThirtyMinutes),
+    (
+        PageId::TrendPressure,
+        SensorType::Pressure,
+        TimeWindow::OneHour,
+    ),
+];
+
+/// Sensor and time window for a dedicated trend page, or `None` for other pages
+pub fn trend_page_params(page_id: PageId) -> Option<(SensorType, TimeWindow)> {
+    TREND_PAGES
+        .iter()
+        .find(|(id, _, _)| *id == page_id)
+        .map(|&(_, sensor, window)| (sensor, window))
+}
+
+/// Settings and shared state needed to construct any page
+pub struct PageContext<'a> {
+    pub theme: &'a Theme,
+    pub home_page_mode: HomePageMode,
+    pub temperature_unit: TemperatureUnit,
+    pub sample_interval_secs: u32,
+    pub brightness_percent: u8,
+    pub touch_threshold: u8,
+    /// Preferred trend windows (temperature, humidity, CO₂)
+    pub trend_windows: [TimeWindow; 3],
+    pub co2_auto_calibration: bool,
+    pub calibration: &'a SensorCalibration,
+    /// Latest readings, used to populate pages that show current values
+    pub sensor_store: &'a SensorDataStore,
+    pub log_sink: Option<&'static dyn LogSink>,
+}
+
+/// Construct the page for `page_id`
+///
+/// `PageId::Home` resolves to the outdoor or grid home page according to
+/// `context.home_page_mode`. Trend pages are created empty; the caller loads
+/// their history from wherever it keeps it, and open on the window in
+/// `context.trend_windows` when that is a valid choice. Temperatures are shown in
+/// `context.temperature_unit`. Returns `None` for ids that have no page of
+/// their own (`Graphs`, and `TrendPage` without a sensor) and for `Error`,
+/// which needs the error it describes.
+pub fn build(page_id: PageId, bounds: Rectangle, context: &PageContext<'_>) -> Option<PageWrapper> {
+    let theme = context.theme;
+
+    if let Some((sensor, default_window)) = trend_page_params(page_id) {
+        let window = trend_window_slot(sensor)
+            .map(|slot| validate_trend_window(context.trend_windows[slot], default_window))
+            .unwrap_or(default_window);
+        let page = TrendPage::new(bounds, sensor, window, theme)
+            .with_temperature_unit(context.temperature_unit)
+            .with_rollup_marks(true);
+        return Some(PageWrapper::TrendPage(Box::new(page)));
+    }
+
+    let page = match page_id {
+        PageId::Home if context.home_page_mode == HomePageMode::Outdoor => {
+            let mut page =
+                HomePage::new(bounds, theme).with_temperature_unit(context.temperature_unit);
+            page.init();
+            page.load_from_store(context.sensor_store);
+            PageWrapper::Home(Box::new(page))
+        }
+        PageId::Home | PageId::HomeGrid => {
+            let mut page =
+                HomeGridPage::new(bounds, theme).with_temperature_unit(context.temperature_unit);
+            page.load_from_store(context.sensor_store);
+            PageWrapper::HomeGrid(Box::new(page))
+        }
+        PageId::Settings => {
+            let mut page = SettingsPage::new(
+                bounds,
+                context.sample_interval_secs,
+                context.brightness_percent,
+                context.touch_threshold,
+                context.calibration,
+                theme,
+            )
+            .with_co2_auto_calibration(context.co2_auto_calibration);
+            page.init();
+            PageWrapper::Settings(Box::new(page))
+        }
+        PageId::DisplaySettings => {
+            PageWrapper::DisplaySettings(Box::new(DisplaySettingsPage::new(
+                bounds,
+                context.home_page_mode,
+                context.temperature_unit,
+                theme,
+            )))
+        }
+        PageId::Monitor => {
+            let mut page = MonitorPage::new(bounds, theme)
+                .with_log_sink(context.log_sink)
+                .with_temperature_unit(context.temperature_unit);
+            page.init();
+            page.load_from_store(context.sensor_store);
+            PageWrapper::Monitor(Box::new(page))
+        }
+        PageId::WifiStatus => {
+            PageWrapper::WifiStatus(Box::new(WifiStatusPage::new(WifiState::Error, theme)))
+        }
+        PageId::Splash => PageWrapper::Splash(Box::new(SplashPage::new(bounds, theme))),
+        PageId::Graphs
+        | PageId::Error
+        | PageId::TrendPage
+        | PageId::TrendTemperature
+        | PageId::TrendHumidity
+        | PageId::TrendCo2
+        | PageId::TrendLux
+        | PageId::TrendPressure => return None,
+    };
+
+    Some(page)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::test_harness::{render, screen_bounds};
+
+    const ALL_PAGE_IDS: [PageId; 15] = [
+        PageId::Home,
+        PageId::HomeGrid,
+        PageId::Settings,
+        PageId::DisplaySettings,
+        PageId::Monitor,
+        PageId::Graphs,
+        PageId::TrendPage,
+        PageId::TrendTemperature,
+        PageId::TrendHumidity,
+        PageId::TrendCo2,
+        PageId::TrendLux,
+        PageId::TrendPressure,
+        PageId::WifiStatus,
+        PageId::Splash,
+        PageId::Error,
+    ];
+
+    /// Ids `build` deliberately has no standalone page for
+    const UNBUILT_PAGE_IDS: [PageId; 3] = [PageId::Graphs, PageId::TrendPage, PageId::Error];
+
+    fn with_context<R>(
+        home_page_mode: HomePageMode,
+        trend_windows: [TimeWindow; 3],
+        f: impl FnOnce(&PageContext<'_>) -> R,
+    ) -> R {
+        let theme = Theme::dark();
+        let calibration = SensorCalibration::default();
+        let sensor_store = SensorDataStore::new();
+        let context = PageContext {
+            theme: &theme,
+            home_page_mode,
+            temperature_unit: TemperatureUnit::Celsius,
+            sample_interval_secs: 30,
+            brightness_percent: 80,
+            touch_threshold: 40,
+            trend_windows,
+            co2_auto_calibration: true,
+            calibration: &calibration,
+            sensor_store: &sensor_store,
+            log_sink: None,
+        };
+        f(&context)
+    }
+
+    #[test]
+    fn every_page_id_builds_and_draws() {
+        for mode in [HomePageMode::Outdoor, HomePageMode::Home] {
+            with_context(mode, DEFAULT_TREND_WINDOWS, |context| {
+                for page_id in ALL_PAGE_IDS {
+                    let page = build(page_id, screen_bounds(), context);
+                    if UNBUILT_PAGE_IDS.contains(&page_id) {
+                        assert!(page.is_none(), "{page_id:?}");
+                        continue;
+                    }
+
+                    let mut page = page.unwrap_or_else(|| panic!("no page for {page_id:?}"));
+                    // Trend pages share one id; Home may resolve to the grid page
+                    if trend_page_params(page_id).is_some() {
+                        assert_eq!(page.id(), PageId::TrendPage);
+                    } else if page_id != PageId::Home {
+                        assert_eq!(page.id(), page_id);
+                    }
+                    render(&mut page);
+                }
+            });
+        }
+    }
+
+    #[test]
+    fn home_follows_the_home_page_mode() {
+        with_context(HomePageMode::Outdoor, DEFAULT_TREND_WINDOWS, |context| {
+            let page = build(PageId::Home, screen_bounds(), context);
+            assert!(matches!(page, Some(PageWrapper::Home(_))));
+        });
+        with_context(HomePageMode::Home, DEFAULT_TREND_WINDOWS, |context| {
+            let page = build(PageId::Home, screen_bounds(), context);
+            assert!(matches!(page, Some(PageWrapper::HomeGrid(_))));
+        });
+    }
+
+    #[test]
+    fn trend_pages_use_the_table_and_the_stored_windows() {
+        let stored = [
+            TimeWindow::ThirtyMinutes,
+            // Not a selectable trend window, so the default is used
+            TimeWindow::OneMinute,
+            TimeWindow::FiveMinutes,
+        ];
+        with_context(HomePageMode::Home, stored, |context| {
+            let expected = [
+                (PageId::TrendTemperature, SensorType::Temperature, stored[0]),
+                (
+                    PageId::TrendHumidity,
+                    SensorType::Humidity,
+                    DEFAULT_TREND_WINDOWS[1],
+                ),
+                (PageId::TrendCo2, SensorType::Co2, stored[2]),
+                (PageId::TrendLux, SensorType::Lux, TimeWindow::ThirtyMinutes),
+                (
+                    PageId::TrendPressure,
+                    SensorType::Pressure,
+                    TimeWindow::OneHour,
+                ),
+            ];
+            for (page_id, sensor, window) in expected {
+                let Some(PageWrapper::TrendPage(page)) = build(page_id, screen_bounds(), context)
+                else {
+                    panic!("{page_id:?} did not build a trend page");
+                };
+                assert_eq!(page.sensor(), sensor);
+                assert_eq!(page.window(), window, "{page_id:?}");
+            }
+        });
+    }
+}
@@ -0,0 +1,302 @@
+// src/pages/settings/calibration.rs
+//! Sensor calibration controls of the settings list.
+//!
+//! - **Calibration offsets** — one `-`/`+` stepper per sensor emitting
+//!   `Action::SetCalibrationOffset`
+//! - **CO2 self-calibration** — on/off toggle emitting `Action::SetCo2AutoCalibration`
+//! - **CO2 recalibration** — tap twice to emit `Action::ForceCo2Recalibration`
+//!   at the outdoor fresh-air level
+
+use core::fmt::Write;
+
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use heapless::String as HeaplessString;
+
+use crate::config::CO2_FRESH_AIR_PPM;
+use crate::sensors::{SensorCalibration, SensorType};
+use crate::storage::MAX_SENSORS;
+use crate::ui::core::{Action, TouchResult};
+use crate::ui::styling::ColorPalette;
+
+use super::rows::{
+    RowLayout, button_bounds, decrement_bounds, draw_button_row, draw_stepper_row, increment_bounds,
+};
+
+/// Largest calibration offset magnitude, in multiples of a row's step
+const MAX_CALIBRATION_STEPS: i32 = 50;
+
+/// A sensor whose calibration offset can be adjusted inline.
+struct OffsetRow {
+    label: &'static str,
+    sensor: SensorType,
+    /// Offset change per stepper press (stored milli-unit)
+    step: i32,
+}
+
+const OFFSET_ROWS: &[OffsetRow] = &[
+    OffsetRow {
+        label: "Temp offset",
+        sensor: SensorType::Temperature,
+        step: 100,
+    },
+    OffsetRow {
+        label: "Humidity offset",
+        sensor: SensorType::Humidity,
+        step: 500,
+    },
+    OffsetRow {
+        label: "CO2 offset",
+        sensor: SensorType::Co2,
+        step: 10_000,
+    },
+    OffsetRow {
+        label: "Lux offset",
+        sensor: SensorType::Lux,
+        step: 1_000,
+    },
+];
+
+/// Row of the CO2 self-calibration toggle (after the offset rows)
+const AUTO_CALIBRATION_ROW: usize = OFFSET_ROWS.len();
+
+/// Row of the CO2 recalibration button (after the self-calibration row)
+const RECALIBRATION_ROW: usize = AUTO_CALIBRATION_ROW + 1;
+
+/// Calibration settings adjusted inline in the settings list.
+pub(super) struct CalibrationControls {
+    /// Currently applied calibration offsets (stored milli-units, by sensor index)
+    offsets: [i32; MAX_SENSORS],
+    /// Whether CO2 automatic self-calibration is on
+    co2_auto_calibration: bool,
+    /// Recalibration was tapped once and waits for a confirming tap
+    recalibration_armed: bool,
+}
+
+impl CalibrationControls {
+    /// Number of rows the group occupies
+    pub(super) const ROW_COUNT: usize = RECALIBRATION_ROW + 1;
+
+    pub(super) fn new(calibration: &SensorCalibration) -> Self {
+        Self {
+            offsets: calibration.offset,
+            co2_auto_calibration: true,
+            recalibration_armed: false,
+        }
+    }
+
+    /// Show the current CO2 self-calibration setting.
+    pub(super) fn set_co2_auto_calibration(&mut self, enabled: bool) {
+        self.co2_auto_calibration = enabled;
+    }
+
+    /// Cancel a pending recalibration, returning whether one was pending.
+    ///
+    /// Any press disarms it; only a press on the button itself confirms it.
+    pub(super) fn disarm_recalibration(&mut self) -> bool {
+        core::mem::take(&mut self.recalibration_armed)
+    }
+
+    /// Handle a press on the offset steppers and CO2 buttons.
+    ///
+    /// `recalibration_was_armed` is the result of the
+    /// [`disarm_recalibration`](Self::disarm_recalibration) call that started
+    /// this press.
+    pub(super) fn handle_press(
+        &mut self,
+        point: Point,
+        rows: RowLayout,
+        recalibration_was_armed: bool,
+    ) -> TouchResult {
+        for (i, row) in OFFSET_ROWS.iter().enumerate() {
+            let bounds = rows.bounds(i);
+            if decrement_bounds(bounds).contains(point) {
+                return self.step_offset(row, false);
+            }
+            if increment_bounds(bounds).contains(point) {
+                return self.step_offset(row, true);
+            }
+        }
+
+        if button_bounds(rows.bounds(AUTO_CALIBRATION_ROW)).contains(point) {
+            self.co2_auto_calibration = !self.co2_auto_calibration;
+            return TouchResult::Action(Action::SetCo2AutoCalibration(self.co2_auto_calibration));
+        }
+
+        // The first tap arms, the second runs it
+        if button_bounds(rows.bounds(RECALIBRATION_ROW)).contains(point) {
+            if recalibration_was_armed {
+                return TouchResult::Action(Action::ForceCo2Recalibration(CO2_FRESH_AIR_PPM));
+            }
+            self.recalibration_armed = true;
+            return TouchResult::Handled;
+        }
+
+        TouchResult::NotHandled
+    }
+
+    /// Apply an offset stepper press, returning the action if the offset changed.
+    fn step_offset(&mut self, row: &OffsetRow, increase: bool) -> TouchResult {
+        let index = row.sensor.index();
+        let limit = row.step * MAX_CALIBRATION_STEPS;
+        let current = self.offsets[index];
+        let requested = if increase {
+            current.saturating_add(row.step)
+        } else {
+            current.saturating_sub(row.step)
+        };
+        let next = requested.clamp(-limit, limit);
+
+        if next == current {
+            return TouchResult::Handled;
+        }
+
+        self.offsets[index] = next;
+        TouchResult::Action(Action::SetCalibrationOffset {
+            sensor: row.sensor,
+            offset: next,
+        })
+    }
+
+    pub(super) fn draw<D: DrawTarget<Color = Rgb565>>(
+        &self,
+        display: &mut D,
+        rows: RowLayout,
+        palette: &ColorPalette,
+    ) -> Result<(), D::Error> {
+        for (i, row) in OFFSET_ROWS.iter().enumerate() {
+            if !rows.is_visible(i) {
+                continue;
+            }
+
+            // Offsets are stored in milli-units; show one decimal place
+            let offset = self.offsets[row.sensor.index()];
+            let sign = if offset < 0 { '-' } else { '+' };
+            let magnitude = offset.unsigned_abs();
+
+            let mut value: HeaplessString<24> = HeaplessString::new();
+            let _ = write!(
+                value,
+                "{}{}.{} {}",
+                sign,
+                magnitude / 1000,
+                magnitude % 1000 / 100,
+                row.sensor.unit()
+            );
+            draw_stepper_row(display, rows.bounds(i), row.label, &value, palette)?;
+        }
+
+        if rows.is_visible(AUTO_CALIBRATION_ROW) {
+            let (state, toggle) = if self.co2_auto_calibration {
+                ("On", "Off")
+            } else {
+                ("Off", "On")
+            };
+            draw_button_row(
+                display,
+                rows.bounds(AUTO_CALIBRATION_ROW),
+                "CO2 self-calibration",
+                state,
+                toggle,
+                palette,
+            )?;
+        }
+
+        if rows.is_visible(RECALIBRATION_ROW) {
+            let mut value: HeaplessString<24> = HeaplessString::new();
+            let button = if self.recalibration_armed {
+                let _ = write!(value, "Tap again to run");
+                "Confirm"
+            } else {
+                let _ = write!(value, "Outdoors: {} ppm", CO2_FRESH_AIR_PPM);
+                "Run"
+            };
+            draw_button_row(
+                display,
+                rows.bounds(RECALIBRATION_ROW),
+                "CO2 recalibrate",
+                &value,
+                button,
+                palette,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::test_harness::screen_bounds;
+
+    fn rows() -> RowLayout {
+        RowLayout::new(screen_bounds(), 0)
+    }
+
+    fn press(controls: &mut CalibrationControls, point: Point) -> TouchResult {
+        let armed = controls.disarm_recalibration();
+        controls.handle_press(point, rows(), armed)
+    }
+
+    #[test]
+    fn offset_stepper_emits_stepped_offset() {
+        let mut controls = CalibrationControls::new(&SensorCalibration::identity());
+        let plus = increment_bounds(rows().bounds(0)).center();
+
+        assert_eq!(
+            press(&mut controls, plus),
+            TouchResult::Action(Action::SetCalibrationOffset {
+                sensor: SensorType::Temperature,
+                offset: 100,
+            })
+        );
+    }
+
+    #[test]
+    fn offset_stepper_stops_at_limit() {
+        let mut calibration = SensorCalibration::identity();
+        calibration.offset[SensorType::Temperature.index()] = -100 * MAX_CALIBRATION_STEPS;
+        let mut controls = CalibrationControls::new(&calibration);
+        let minus = decrement_bounds(rows().bounds(0)).center();
+
+        assert_eq!(press(&mut controls, minus), TouchResult::Handled);
+    }
+
+    #[test]
+    fn recalibration_needs_two_consecutive_taps() {
+        let mut controls = CalibrationControls::new(&SensorCalibration::identity());
+        let run = button_bounds(rows().bounds(RECALIBRATION_ROW)).center();
+
+        assert_eq!(press(&mut controls, run), TouchResult::Handled);
+        assert_eq!(
+            press(&mut controls, run),
+            TouchResult::Action(Action::ForceCo2Recalibration(CO2_FRESH_AIR_PPM))
+        );
+    }
+
+    #[test]
+    fn press_elsewhere_disarms_recalibration() {
+        let mut controls = CalibrationControls::new(&SensorCalibration::identity());
+        let run = button_bounds(rows().bounds(RECALIBRATION_ROW)).center();
+
+        assert_eq!(press(&mut controls, run), TouchResult::Handled);
+        assert_eq!(press(&mut controls, Point::zero()), TouchResult::NotHandled);
+        assert_eq!(press(&mut controls, run), TouchResult::Handled);
+    }
+
+    #[test]
+    fn auto_calibration_toggles() {
+        let mut controls = CalibrationControls::new(&SensorCalibration::identity());
+        let toggle = button_bounds(rows().bounds(AUTO_CALIBRATION_ROW)).center();
+
+        assert_eq!(
+            press(&mut controls, toggle),
+            TouchResult::Action(Action::SetCo2AutoCalibration(false))
+        );
+        assert_eq!(
+            press(&mut controls, toggle),
+            TouchResult::Action(Action::SetCo2AutoCalibration(true))
+        );
+    }
+}
@@ -0,0 +1,328 @@
+// src/pages/settings/device.rs
+//! Device controls of the settings list: sampling, backlight, touch and theme.
+//!
+//! - **Sample interval** — `-`/`+` stepper emitting `Action::SetSampleInterval`
+//! - **Brightness** — slider emitting `Action::PreviewBrightness` while
+//!   dragged and `Action::SetBrightness` on release
+//! - **Touch threshold** — `-`/`+` stepper emitting `Action::SetTouchThreshold`
+//! - **Theme** — button cycling the color scheme, emitting `Action::SetThemeMode`
+
+use core::fmt::Write;
+
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+use heapless::String as HeaplessString;
+
+use crate::config::{
+    SAMPLE_INTERVAL_STEP_SECS, TOUCH_THRESHOLD_STEP, ThemeMode, clamp_sample_interval_secs,
+    clamp_touch_threshold,
+};
+use crate::ui::Drawable;
+use crate::ui::components::Slider;
+use crate::ui::core::{Action, TouchEvent, TouchResult, Touchable};
+use crate::ui::styling::ColorPalette;
+
+use super::rows::{
+    RowLayout, button_bounds, decrement_bounds, draw_button_row, draw_info_row, draw_stepper_row,
+    increment_bounds, widget_bounds,
+};
+
+/// Row of each control, counted from the first row of the group
+const SAMPLE_INTERVAL_ROW: usize = 0;
+const BRIGHTNESS_ROW: usize = 1;
+const TOUCH_THRESHOLD_ROW: usize = 2;
+const THEME_ROW: usize = 3;
+
+/// Device settings adjusted inline in the settings list.
+pub(super) struct DeviceControls {
+    /// Currently applied sensor sampling interval (seconds)
+    sample_interval_secs: u32,
+    /// Backlight brightness slider (percent)
+    brightness: Slider,
+    /// Currently applied touch detection threshold
+    touch_threshold: u8,
+    /// Active color scheme, flipped by the theme toggle
+    theme_mode: ThemeMode,
+}
+
+impl DeviceControls {
+    /// Number of rows the group occupies
+    pub(super) const ROW_COUNT: usize = 4;
+
+    pub(super) fn new(
+        sample_interval_secs: u32,
+        brightness_percent: u8,
+        touch_threshold: u8,
+        theme_mode: ThemeMode,
+        palette: ColorPalette,
+    ) -> Self {
+        Self {
+            sample_interval_secs: clamp_sample_interval_secs(sample_interval_secs),
+            brightness: Slider::new(
+                Rectangle::zero(),
+                brightness_percent,
+                Action::PreviewBrightness,
+            )
+            .with_on_release(Action::SetBrightness)
+            .with_palette(palette),
+            touch_threshold: clamp_touch_threshold(touch_threshold),
+            theme_mode,
+        }
+    }
+
+    /// Keep the slider positioned over its row after scrolling.
+    pub(super) fn sync_slider_bounds(&mut self, rows: RowLayout) {
+        self.brightness
+            .set_bounds(widget_bounds(rows.bounds(BRIGHTNESS_ROW)));
+    }
+
+    /// Forward a touch to the brightness slider.
+    ///
+    /// The slider claims presses on itself and the drag and release that
+    /// follow; a press elsewhere also ends a drag in progress.
+    pub(super) fn handle_slider_touch(&mut self, event: TouchEvent) -> TouchResult {
+        self.brightness.handle_touch(event)
+    }
+
+    /// Handle a press on the stepper and theme buttons.
+    pub(super) fn handle_press(&mut self, point: Point, rows: RowLayout) -> TouchResult {
+        let interval_row = rows.bounds(SAMPLE_INTERVAL_ROW);
+        if decrement_bounds(interval_row).contains(point) {
+            return self.step_sample_interval(false);
+        }
+        if increment_bounds(interval_row).contains(point) {
+            return self.step_sample_interval(true);
+        }
+
+        let touch_row = rows.bounds(TOUCH_THRESHOLD_ROW);
+        if decrement_bounds(touch_row).contains(point) {
+            return self.step_touch_threshold(false);
+        }
+        if increment_bounds(touch_row).contains(point) {
+            return self.step_touch_threshold(true);
+        }
+
+        // The display manager rebuilds the page in the new theme
+        if button_bounds(rows.bounds(THEME_ROW)).contains(point) {
+            self.theme_mode = self.theme_mode.next();
+            return TouchResult::Action(Action::SetThemeMode(self.theme_mode));
+        }
+
+        TouchResult::NotHandled
+    }
+
+    /// Apply a stepper press, returning the action if the value changed.
+    ///
+    /// The new interval is clamped to the supported range, so pressing `-` at
+    /// the minimum (or `+` at the maximum) produces no action.
+    fn step_sample_interval(&mut self, increase: bool) -> TouchResult {
+        let requested = if increase {
+            self.sample_interval_secs
+                .saturating_add(SAMPLE_INTERVAL_STEP_SECS)
+        } else {
+            self.sample_interval_secs
+                .saturating_sub(SAMPLE_INTERVAL_STEP_SECS)
+        };
+        let next = clamp_sample_interval_secs(requested);
+
+        if next == self.sample_interval_secs {
+            return TouchResult::Handled;
+        }
+
+        self.sample_interval_secs = next;
+        TouchResult::Action(Action::SetSampleInterval(next))
+    }
+
+    /// Apply a touch threshold stepper press, returning the action if the value changed.
+    fn step_touch_threshold(&mut self, increase: bool) -> TouchResult {
+        let requested = if increase {
+            self.touch_threshold.saturating_add(TOUCH_THRESHOLD_STEP)
+        } else {
+            self.touch_threshold.saturating_sub(TOUCH_THRESHOLD_STEP)
+        };
+        let next = clamp_touch_threshold(requested);
+
+        if next == self.touch_threshold {
+            return TouchResult::Handled;
+        }
+
+        self.touch_threshold = next;
+        TouchResult::Action(Action::SetTouchThreshold(next))
+    }
+
+    pub(super) fn draw<D: DrawTarget<Color = Rgb565>>(
+        &self,
+        display: &mut D,
+        rows: RowLayout,
+        palette: &ColorPalette,
+    ) -> Result<(), D::Error> {
+        if rows.is_visible(SAMPLE_INTERVAL_ROW) {
+            let mut value: HeaplessString<16> = HeaplessString::new();
+            let _ = write!(value, "Every {}s", self.sample_interval_secs);
+            draw_stepper_row(
+                display,
+                rows.bounds(SAMPLE_INTERVAL_ROW),
+                "Sample interval",
+                &value,
+                palette,
+            )?;
+        }
+
+        if rows.is_visible(BRIGHTNESS_ROW) {
+            let mut value: HeaplessString<8> = HeaplessString::new();
+            let _ = write!(value, "{}%", self.brightness.value());
+            draw_info_row(
+                display,
+                rows.bounds(BRIGHTNESS_ROW),
+                "Brightness",
+                &value,
+                palette,
+            )?;
+            self.brightness.draw(display)?;
+        }
+
+        if rows.is_visible(TOUCH_THRESHOLD_ROW) {
+            let mut value: HeaplessString<32> = HeaplessString::new();
+            let _ = write!(value, "{} (lower = more sensitive)", self.touch_threshold);
+            draw_stepper_row(
+                display,
+                rows.bounds(TOUCH_THRESHOLD_ROW),
+                "Touch threshold",
+                &value,
+                palette,
+            )?;
+        }
+
+        if rows.is_visible(THEME_ROW) {
+            draw_button_row(
+                display,
+                rows.bounds(THEME_ROW),
+                "Theme",
+                self.theme_mode.label(),
+                self.theme_mode.next().label(),
+                palette,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        DEFAULT_SAMPLE_INTERVAL_SECS, MAX_SAMPLE_INTERVAL_SECS, MIN_SAMPLE_INTERVAL_SECS,
+    };
+    use crate::pages::test_harness::screen_bounds;
+    use crate::ui::core::TouchPoint;
+
+    fn rows() -> RowLayout {
+        RowLayout::new(screen_bounds(), 0)
+    }
+
+    fn controls(sample_interval_secs: u32) -> DeviceControls {
+        let mut controls = DeviceControls::new(
+            sample_interval_secs,
+            80,
+            22,
+            ThemeMode::Dark,
+            ColorPalette::default(),
+        );
+        controls.sync_slider_bounds(rows());
+        controls
+    }
+
+    fn tap_interval_stepper(controls: &mut DeviceControls, increase: bool) -> TouchResult {
+        let row = rows().bounds(SAMPLE_INTERVAL_ROW);
+        let button = if increase {
+            increment_bounds(row)
+        } else {
+            decrement_bounds(row)
+        };
+        controls.handle_press(button.center(), rows())
+    }
+
+    #[test]
+    fn interval_stepper_emits_stepped_interval() {
+        let mut controls = controls(DEFAULT_SAMPLE_INTERVAL_SECS);
+
+        assert_eq!(
+            tap_interval_stepper(&mut controls, true),
+            TouchResult::Action(Action::SetSampleInterval(
+                DEFAULT_SAMPLE_INTERVAL_SECS + SAMPLE_INTERVAL_STEP_SECS
+            ))
+        );
+        assert_eq!(
+            tap_interval_stepper(&mut controls, false),
+            TouchResult::Action(Action::SetSampleInterval(DEFAULT_SAMPLE_INTERVAL_SECS))
+        );
+    }
+
+    #[test]
+    fn interval_stepper_stops_at_minimum() {
+        let mut controls = controls(MIN_SAMPLE_INTERVAL_SECS);
+        assert_eq!(
+            tap_interval_stepper(&mut controls, false),
+            TouchResult::Handled
+        );
+    }
+
+    #[test]
+    fn interval_stepper_stops_at_maximum() {
+        let mut controls = controls(MAX_SAMPLE_INTERVAL_SECS);
+        assert_eq!(
+            tap_interval_stepper(&mut controls, true),
+            TouchResult::Handled
+        );
+    }
+
+    #[test]
+    fn out_of_range_interval_is_clamped_on_construction() {
+        let mut controls = controls(1);
+        assert_eq!(
+            tap_interval_stepper(&mut controls, true),
+            TouchResult::Action(Action::SetSampleInterval(
+                MIN_SAMPLE_INTERVAL_SECS + SAMPLE_INTERVAL_STEP_SECS
+            ))
+        );
+    }
+
+    #[test]
+    fn brightness_drag_previews_and_persists_on_release() {
+        let mut controls = controls(DEFAULT_SAMPLE_INTERVAL_SECS);
+        let slider = widget_bounds(rows().bounds(BRIGHTNESS_ROW));
+        let y = slider.center().y as u16;
+        let left = TouchPoint::new(slider.top_left.x as u16, y);
+        let right = TouchPoint::new((slider.top_left.x + slider.size.width as i32) as u16, y);
+
+        assert_eq!(
+            controls.handle_slider_touch(TouchEvent::Press(left)),
+            TouchResult::Action(Action::PreviewBrightness(0))
+        );
+        assert_eq!(
+            controls.handle_slider_touch(TouchEvent::Drag(right)),
+            TouchResult::Action(Action::PreviewBrightness(100))
+        );
+        assert_eq!(
+            controls.handle_slider_touch(TouchEvent::Release(right)),
+            TouchResult::Action(Action::SetBrightness(100))
+        );
+    }
+
+    #[test]
+    fn theme_button_cycles_modes() {
+        let mut controls = controls(DEFAULT_SAMPLE_INTERVAL_SECS);
+        let button = button_bounds(rows().bounds(THEME_ROW)).center();
+
+        assert_eq!(
+            controls.handle_press(button, rows()),
+            TouchResult::Action(Action::SetThemeMode(ThemeMode::Light))
+        );
+        assert_eq!(
+            controls.handle_press(button, rows()),
+            TouchResult::Action(Action::SetThemeMode(ThemeMode::HighContrast))
+        );
+    }
+}
@@ -6,7 +6,7 @@
 
 use embedded_graphics::Drawable as EgDrawable;
 use embedded_graphics::mono_font::MonoTextStyle;
-use embedded_graphics::mono_font::ascii::FONT_6X10;
+use embedded_graphics::mono_font::iso_8859_1::FONT_6X10;
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::{
@@ -19,7 +19,7 @@ use crate::pages::page::Page;
 use crate::ui::Drawable;
 use crate::ui::core::{Action, PageEvent, PageId, TouchEvent, Touchable};
 use crate::ui::layouts::{ScrollDirection, ScrollableContainer};
-use crate::ui::styling::{COLOR_BACKGROUND, COLOR_FOREGROUND, WHITE};
+use crate::ui::styling::{ColorPalette, LIGHT_GRAY, Theme, WHITE};
 
 // ---------------------------------------------------------------------------
 // Layout constants
@@ -58,14 +58,14 @@ const RADIO_OUTER_DIAMETER: u32 = 12;
 /// Radio button inner diameter (filled when selected)
 const RADIO_INNER_DIAMETER: u32 = 6;
 
-/// Header text color (muted)
-const COLOR_HEADER_TEXT: Rgb565 = Rgb565::new(20, 40, 20);
+/// Accent color for selected option (the same in both themes)
+const COLOR_ACCENT: Rgb565 = Rgb565::new(8, 40, 12);
 
-/// Muted text for secondary labels
-const COLOR_MUTED_TEXT: Rgb565 = Rgb565::new(18, 36, 18);
+/// Label and radio color on the selected (accent) card
+const COLOR_ACCENT_TEXT: Rgb565 = WHITE;
 
-/// Accent color for selected option
-const COLOR_ACCENT: Rgb565 = Rgb565::new(8, 40, 12);
+/// Subtitle color on the selected (accent) card
+const COLOR_ACCENT_SUBTITLE: Rgb565 = LIGHT_GRAY;
 
 /// Back button touch target width
 const BACK_TOUCH_WIDTH: u32 = 44;
@@ -108,6 +108,9 @@ pub struct DisplaySettingsPage {
     scroll: ScrollableContainer,
     selected_mode: HomePageMode,
     selected_temp_unit: TemperatureUnit,
+    palette: ColorPalette,
+    /// Radio outline stroke width, from the theme
+    border_width_px: u32,
     dirty: bool,
 }
 
@@ -116,6 +119,7 @@ impl DisplaySettingsPage {
         bounds: Rectangle,
         current_mode: HomePageMode,
         current_temp_unit: TemperatureUnit,
+        theme: &Theme,
     ) -> Self {
         let scroll_viewport = Self::scroll_viewport(bounds);
         let scroll = ScrollableContainer::new(
@@ -129,6 +133,8 @@ impl DisplaySettingsPage {
             scroll,
             selected_mode: current_mode,
             selected_temp_unit: current_temp_unit,
+            palette: theme.palette,
+            border_width_px: theme.border_width_px,
             dirty: true,
         }
     }
@@ -191,7 +197,7 @@ impl DisplaySettingsPage {
         );
 
         RoundedRectangle::with_equal_corners(header_rect, Size::new(CORNER_RADIUS, CORNER_RADIUS))
-            .into_styled(PrimitiveStyle::with_fill(COLOR_FOREGROUND))
+            .into_styled(PrimitiveStyle::with_fill(self.palette.surface))
             .draw(display)?;
 
         // Back arrow
@@ -199,7 +205,7 @@ impl DisplaySettingsPage {
         Text::with_alignment(
             "<",
             Point::new(self.bounds.top_left.x + 12, text_y),
-            MonoTextStyle::new(&FONT_6X10, COLOR_HEADER_TEXT),
+            MonoTextStyle::new(&FONT_6X10, self.palette.text_secondary),
             Alignment::Left,
         )
         .draw(display)?;
@@ -208,7 +214,7 @@ impl DisplaySettingsPage {
         Text::with_alignment(
             "DISPLAY",
             Point::new(self.bounds.top_left.x + 28, text_y),
-            MonoTextStyle::new(&FONT_6X10, COLOR_HEADER_TEXT),
+            MonoTextStyle::new(&FONT_6X10, self.palette.text_secondary),
             Alignment::Left,
         )
         .draw(display)?;
@@ -234,10 +240,14 @@ impl DisplaySettingsPage {
         }
 
         // Card background — accent tint when selected
-        let bg_color = if is_selected {
-            COLOR_ACCENT
+        let (bg_color, text_color, subtitle_color) = if is_selected {
+            (COLOR_ACCENT, COLOR_ACCENT_TEXT, COLOR_ACCENT_SUBTITLE)
         } else {
-            COLOR_FOREGROUND
+            (
+                self.palette.surface,
+                self.palette.text_primary,
+                self.palette.text_secondary,
+            )
         };
 
         RoundedRectangle::with_equal_corners(
@@ -261,8 +271,8 @@ impl DisplaySettingsPage {
         )
         .into_styled(
             PrimitiveStyleBuilder::new()
-                .stroke_color(WHITE)
-                .stroke_width(1)
+                .stroke_color(text_color)
+                .stroke_width(self.border_width_px)
                 .build(),
         )
         .draw(display)?;
@@ -276,7 +286,7 @@ impl DisplaySettingsPage {
                 ),
                 RADIO_INNER_DIAMETER,
             )
-            .into_styled(PrimitiveStyle::with_fill(WHITE))
+            .into_styled(PrimitiveStyle::with_fill(text_color))
             .draw(display)?;
         }
 
@@ -286,17 +296,12 @@ impl DisplaySettingsPage {
         Text::with_alignment(
             label,
             Point::new(label_x, label_y),
-            MonoTextStyle::new(&FONT_6X10, WHITE),
+            MonoTextStyle::new(&FONT_6X10, text_color),
             Alignment::Left,
         )
         .draw(display)?;
 
-        // Subtitle — use lighter color on selected (accent) background for contrast
-        let subtitle_color = if is_selected {
-            COLOR_HEADER_TEXT
-        } else {
-            COLOR_MUTED_TEXT
-        };
+        // Subtitle — lighter color on the selected (accent) background for contrast
         let subtitle_y = label_y + 12;
         Text::with_alignment(
             subtitle,
@@ -380,6 +385,9 @@ impl Page for DisplaySettingsPage {
                 self.scroll.handle_touch(event);
                 self.dirty = true;
             }
+            TouchEvent::Release(_) => {
+                self.scroll.handle_touch(event);
+            }
         }
         None
     }
@@ -424,7 +432,7 @@ impl Drawable for DisplaySettingsPage {
             return Ok(());
         }
 
-        display.clear(COLOR_BACKGROUND)?;
+        display.clear(self.palette.background)?;
 
         self.draw_header(display)?;
 
@@ -433,7 +441,7 @@ impl Drawable for DisplaySettingsPage {
         Text::with_alignment(
             "Home Page Style",
             Point::new(label_x, self.section_label_screen_y(mode_section_label_y())),
-            MonoTextStyle::new(&FONT_6X10, WHITE),
+            MonoTextStyle::new(&FONT_6X10, self.palette.text_primary),
             Alignment::Left,
         )
         .draw(display)?;
@@ -458,7 +466,7 @@ impl Drawable for DisplaySettingsPage {
         Text::with_alignment(
             "Temperature Unit",
             Point::new(label_x, self.section_label_screen_y(temp_section_label_y())),
-            MonoTextStyle::new(&FONT_6X10, WHITE),
+            MonoTextStyle::new(&FONT_6X10, self.palette.text_primary),
             Alignment::Left,
         )
         .draw(display)?;
@@ -4,20 +4,37 @@
 //! Each row navigates to a sub-settings page. Currently implemented:
 //! - **Display** → `DisplaySettingsPage` (home page mode selector)
 //! - **Monitor** → `MonitorPage` (live sensor feed + storage log)
+//!
+//! Below the categories, groups of inline rows follow, each in its own module:
+//! - [`device`](super::device) — sample interval, brightness, touch threshold, theme
+//! - [`calibration`](super::calibration) — sensor offsets and CO2 calibration
+//! - [`storage`](super::storage) — read-only SD card usage and lifetime stats
+//!
+//! This module owns the header, the category rows and scrolling, and routes
+//! touches and drawing to the groups.
 
 use embedded_graphics::Drawable as EgDrawable;
 use embedded_graphics::mono_font::MonoTextStyle;
-use embedded_graphics::mono_font::ascii::FONT_6X10;
+use embedded_graphics::mono_font::iso_8859_1::FONT_6X10;
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::{PrimitiveStyle, Rectangle, RoundedRectangle};
 use embedded_graphics::text::{Alignment, Text};
 
+use crate::metrics::stats::TrendStats;
 use crate::pages::page::Page;
+use crate::sensors::SensorCalibration;
+use crate::storage::manager::StorageCapacity;
+use crate::storage::{LifetimeStats, MAX_SENSORS};
 use crate::ui::Drawable;
-use crate::ui::core::{Action, PageEvent, PageId, TouchEvent, Touchable};
+use crate::ui::core::{Action, PageEvent, PageId, TouchEvent, TouchResult, Touchable};
 use crate::ui::layouts::{ScrollDirection, ScrollableContainer};
-use crate::ui::styling::{COLOR_BACKGROUND, COLOR_FOREGROUND, WHITE};
+use crate::ui::styling::{ColorPalette, FONT_6X10_CENTERED_BASELINE_PX, LIST_ROW_HEIGHT_PX, Theme};
+
+use super::calibration::CalibrationControls;
+use super::device::DeviceControls;
+use super::rows::{RowLayout, draw_info_row};
+use super::storage::StorageRows;
 
 // ---------------------------------------------------------------------------
 // Layout constants
@@ -29,29 +46,33 @@ const HEADER_HEIGHT_PX: u32 = 36;
 /// Corner radius for rounded elements
 const CORNER_RADIUS: u32 = 12;
 
-/// Height of each category row
-const ROW_HEIGHT_PX: u32 = 40;
-
-/// Vertical gap between rows
-const ROW_GAP_PX: u32 = 2;
-
 /// Touch target width for the back button
 const BACK_TOUCH_WIDTH: u32 = 44;
 
-/// Horizontal padding for the list area
-const LIST_PADDING_X: u32 = 8;
+/// Left inset of the header's back arrow
+const BACK_ARROW_INSET_PX: u32 = 12;
 
-/// Vertical padding at top of scroll content
-const LIST_PADDING_TOP: u32 = 4;
+/// Left inset of the header title (clear of the back arrow)
+const TITLE_INSET_PX: u32 = 30;
 
-/// Pill corner radius for rows
-const PILL_CORNER_RADIUS: u32 = 6;
+/// Right inset of a category row's chevron
+const CHEVRON_INSET_PX: u32 = 14;
 
-/// Header text color (muted)
-const COLOR_HEADER_TEXT: Rgb565 = Rgb565::new(20, 40, 20);
+// ---------------------------------------------------------------------------
+// Row sections
+// ---------------------------------------------------------------------------
+
+/// First row of the device controls (after all categories)
+const DEVICE_FIRST_ROW: usize = CATEGORIES.len();
+
+/// First row of the calibration controls (after the device controls)
+const CALIBRATION_FIRST_ROW: usize = DEVICE_FIRST_ROW + DeviceControls::ROW_COUNT;
 
-/// Muted text for secondary labels
-const COLOR_MUTED_TEXT: Rgb565 = Rgb565::new(18, 36, 18);
+/// First row of the storage rows (after the calibration controls)
+const STORAGE_FIRST_ROW: usize = CALIBRATION_FIRST_ROW + CalibrationControls::ROW_COUNT;
+
+/// Total number of rows in the list
+const ROW_COUNT: usize = STORAGE_FIRST_ROW + StorageRows::ROW_COUNT;
 
 // ---------------------------------------------------------------------------
 // Category definition
@@ -85,24 +106,55 @@ const CATEGORIES: &[SettingsCategory] = &[
 pub struct SettingsPage {
     bounds: Rectangle,
     scroll: ScrollableContainer,
+    device: DeviceControls,
+    calibration: CalibrationControls,
+    storage: StorageRows,
+    palette: ColorPalette,
     dirty: bool,
 }
 
 impl SettingsPage {
-    pub fn new(bounds: Rectangle) -> Self {
+    pub fn new(
+        bounds: Rectangle,
+        sample_interval_secs: u32,
+        brightness_percent: u8,
+        touch_threshold: u8,
+        calibration: &SensorCalibration,
+        theme: &Theme,
+    ) -> Self {
         let scroll_viewport = Self::scroll_viewport(bounds);
-        let content_height = Self::content_height(CATEGORIES.len());
         let scroll = ScrollableContainer::new(
             scroll_viewport,
-            Size::new(scroll_viewport.size.width, content_height),
+            Size::new(
+                scroll_viewport.size.width,
+                RowLayout::content_height(ROW_COUNT),
+            ),
             ScrollDirection::Vertical,
         );
 
-        Self {
+        let mut page = Self {
             bounds,
             scroll,
+            device: DeviceControls::new(
+                sample_interval_secs,
+                brightness_percent,
+                touch_threshold,
+                theme.mode,
+                theme.palette,
+            ),
+            calibration: CalibrationControls::new(calibration),
+            storage: StorageRows::default(),
+            palette: theme.palette,
             dirty: true,
-        }
+        };
+        page.sync_slider_bounds();
+        page
+    }
+
+    /// Show the current CO2 self-calibration setting (on by default).
+    pub fn with_co2_auto_calibration(mut self, enabled: bool) -> Self {
+        self.calibration.set_co2_auto_calibration(enabled);
+        self
     }
 
     /// Kept for API compatibility with existing callers.
@@ -110,6 +162,35 @@ impl SettingsPage {
         self.dirty = true;
     }
 
+    /// Update the storage fill levels shown in the SD card row.
+    pub fn set_storage_capacity(&mut self, capacity: StorageCapacity) {
+        if self.storage.set_capacity(capacity) {
+            self.dirty = true;
+        }
+    }
+
+    /// Update the lifetime stats shown at the bottom of the list.
+    ///
+    /// `now` is the current sample timestamp, used to compute uptime.
+    pub fn set_lifetime_stats(&mut self, stats: &LifetimeStats, now: u32) {
+        self.storage.set_lifetime_stats(stats, now);
+        self.dirty = true;
+    }
+
+    /// Update the today and all-time summaries kept by storage.
+    ///
+    /// Both are indexed by sensor slot, as returned by
+    /// [`StorageManager::today_stats`](crate::storage::manager::StorageManager::today_stats)
+    /// and [`StorageManager::all_time_stats`](crate::storage::manager::StorageManager::all_time_stats).
+    pub fn set_window_stats(
+        &mut self,
+        today: [TrendStats; MAX_SENSORS],
+        all_time: [TrendStats; MAX_SENSORS],
+    ) {
+        self.storage.set_window_stats(today, all_time);
+        self.dirty = true;
+    }
+
     /// The scrollable viewport below the header.
     fn scroll_viewport(bounds: Rectangle) -> Rectangle {
         Rectangle::new(
@@ -124,35 +205,81 @@ impl SettingsPage {
         )
     }
 
-    /// Total content height for the given number of rows.
-    fn content_height(count: usize) -> u32 {
-        LIST_PADDING_TOP + count as u32 * (ROW_HEIGHT_PX + ROW_GAP_PX)
+    /// Row layout at the current scroll position.
+    fn rows(&self) -> RowLayout {
+        RowLayout::new(self.scroll.viewport(), self.scroll.scroll_offset().y)
     }
 
-    /// Calculate the bounding rectangle of a category row by index (in content space).
-    fn row_content_y(&self, index: usize) -> i32 {
-        LIST_PADDING_TOP as i32 + (index as u32 * (ROW_HEIGHT_PX + ROW_GAP_PX)) as i32
+    /// Keep the brightness slider positioned over its row after scrolling.
+    fn sync_slider_bounds(&mut self) {
+        let rows = self.rows().section(DEVICE_FIRST_ROW);
+        self.device.sync_slider_bounds(rows);
     }
 
-    /// Row bounds on screen, adjusted for scroll offset.
-    fn row_screen_bounds(&self, index: usize) -> Rectangle {
-        let viewport = self.scroll.viewport();
-        let scroll_y = self.scroll.scroll_offset().y;
-        let x = viewport.top_left.x + LIST_PADDING_X as i32;
-        let y = viewport.top_left.y + self.row_content_y(index) - scroll_y;
-        let width = viewport.size.width.saturating_sub(LIST_PADDING_X * 2);
-        Rectangle::new(Point::new(x, y), Size::new(width, ROW_HEIGHT_PX))
+    /// Mark the page dirty if a control group reacted to a touch.
+    fn absorb(&mut self, result: TouchResult) -> Option<Option<Action>> {
+        match result {
+            TouchResult::Action(action) => {
+                self.dirty = true;
+                Some(Some(action))
+            }
+            TouchResult::Handled => {
+                self.dirty = true;
+                Some(None)
+            }
+            TouchResult::NotHandled => None,
+        }
     }
 
-    /// Check if a row is at least partially visible in the viewport.
-    fn is_row_visible(&self, index: usize) -> bool {
-        let bounds = self.row_screen_bounds(index);
-        let viewport = self.scroll.viewport();
-        let row_top = bounds.top_left.y;
-        let row_bottom = row_top + ROW_HEIGHT_PX as i32;
-        let vp_top = viewport.top_left.y;
-        let vp_bottom = vp_top + viewport.size.height as i32;
-        row_bottom > vp_top && row_top < vp_bottom
+    /// Route a press to the header, the categories and each control group.
+    fn handle_press(&mut self, event: TouchEvent, point: Point) -> Option<Action> {
+        // Any press disarms a pending recalibration; only the button confirms it
+        let recalibration_armed = self.calibration.disarm_recalibration();
+        if recalibration_armed {
+            self.dirty = true;
+        }
+
+        // Brightness slider (also releases a previous drag when pressed elsewhere)
+        let slider = self.device.handle_slider_touch(event);
+        if let Some(action) = self.absorb(slider) {
+            return action;
+        }
+
+        // Back button (top-left of header)
+        let back_rect = Rectangle::new(
+            self.bounds.top_left,
+            Size::new(BACK_TOUCH_WIDTH, HEADER_HEIGHT_PX),
+        );
+        if back_rect.contains(point) {
+            return Some(Action::GoBack);
+        }
+
+        let rows = self.rows();
+        for (i, category) in CATEGORIES.iter().enumerate() {
+            if rows.bounds(i).contains(point) {
+                return Some(Action::NavigateToPage(category.target));
+            }
+        }
+
+        let device = self
+            .device
+            .handle_press(point, rows.section(DEVICE_FIRST_ROW));
+        if let Some(action) = self.absorb(device) {
+            return action;
+        }
+
+        let calibration = self.calibration.handle_press(
+            point,
+            rows.section(CALIBRATION_FIRST_ROW),
+            recalibration_armed,
+        );
+        if let Some(action) = self.absorb(calibration) {
+            return action;
+        }
+
+        // Start tracking for potential drag
+        self.scroll.handle_touch(event);
+        None
     }
 
     fn draw_header<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
@@ -162,16 +289,16 @@ impl SettingsPage {
         );
 
         RoundedRectangle::with_equal_corners(header_rect, Size::new(CORNER_RADIUS, CORNER_RADIUS))
-            .into_styled(PrimitiveStyle::with_fill(COLOR_FOREGROUND))
+            .into_styled(PrimitiveStyle::with_fill(self.palette.surface))
             .draw(display)?;
 
-        let text_y = self.bounds.top_left.y + (HEADER_HEIGHT_PX / 2 + 4) as i32;
+        let text_y = header_rect.center().y + FONT_6X10_CENTERED_BASELINE_PX as i32;
 
         // Back arrow (top-left)
         Text::with_alignment(
             "<",
-            Point::new(self.bounds.top_left.x + 12, text_y),
-            MonoTextStyle::new(&FONT_6X10, WHITE),
+            Point::new(self.bounds.top_left.x + BACK_ARROW_INSET_PX as i32, text_y),
+            MonoTextStyle::new(&FONT_6X10, self.palette.text_primary),
             Alignment::Left,
         )
         .draw(display)?;
@@ -179,8 +306,8 @@ impl SettingsPage {
         // Title
         Text::with_alignment(
             "SETTINGS",
-            Point::new(self.bounds.top_left.x + 30, text_y),
-            MonoTextStyle::new(&FONT_6X10, COLOR_HEADER_TEXT),
+            Point::new(self.bounds.top_left.x + TITLE_INSET_PX as i32, text_y),
+            MonoTextStyle::new(&FONT_6X10, self.palette.text_secondary),
             Alignment::Left,
         )
         .draw(display)?;
@@ -188,52 +315,27 @@ impl SettingsPage {
         Ok(())
     }
 
-    fn draw_row<D: DrawTarget<Color = Rgb565>>(
+    fn draw_category_row<D: DrawTarget<Color = Rgb565>>(
         &self,
         display: &mut D,
-        index: usize,
+        bounds: Rectangle,
         category: &SettingsCategory,
     ) -> Result<(), D::Error> {
-        if !self.is_row_visible(index) {
-            return Ok(());
-        }
-
-        let bounds = self.row_screen_bounds(index);
-
-        // Row background
-        RoundedRectangle::with_equal_corners(
+        draw_info_row(
+            display,
             bounds,
-            Size::new(PILL_CORNER_RADIUS, PILL_CORNER_RADIUS),
-        )
-        .into_styled(PrimitiveStyle::with_fill(COLOR_FOREGROUND))
-        .draw(display)?;
-
-        // Label (left)
-        let label_y = bounds.top_left.y + 16;
-        Text::with_alignment(
             category.label,
-            Point::new(bounds.top_left.x + 12, label_y),
-            MonoTextStyle::new(&FONT_6X10, WHITE),
-            Alignment::Left,
-        )
-        .draw(display)?;
-
-        // Subtitle (below label)
-        let subtitle_y = label_y + 14;
-        Text::with_alignment(
             category.subtitle,
-            Point::new(bounds.top_left.x + 12, subtitle_y),
-            MonoTextStyle::new(&FONT_6X10, COLOR_MUTED_TEXT),
-            Alignment::Left,
-        )
-        .draw(display)?;
+            &self.palette,
+        )?;
 
         // Chevron ">" on right
-        let right_x = bounds.top_left.x + bounds.size.width as i32 - 14;
+        let right_x = bounds.top_left.x + bounds.size.width as i32 - CHEVRON_INSET_PX as i32;
+        let center_y = bounds.top_left.y + (LIST_ROW_HEIGHT_PX / 2) as i32;
         Text::with_alignment(
             ">",
-            Point::new(right_x, bounds.top_left.y + (ROW_HEIGHT_PX / 2 + 4) as i32),
-            MonoTextStyle::new(&FONT_6X10, COLOR_MUTED_TEXT),
+            Point::new(right_x, center_y + FONT_6X10_CENTERED_BASELINE_PX as i32),
+            MonoTextStyle::new(&FONT_6X10, self.palette.text_secondary),
             Alignment::Right,
         )
         .draw(display)?;
@@ -256,36 +358,30 @@ impl Page for SettingsPage {
     }
 
     fn on_activate(&mut self) {
+        self.calibration.disarm_recalibration();
         self.dirty = true;
     }
 
     fn handle_touch(&mut self, event: TouchEvent) -> Option<Action> {
         match event {
-            TouchEvent::Press(point) => {
-                let pt = point.to_point();
-
-                // Back button (top-left of header)
-                let back_rect = Rectangle::new(
-                    self.bounds.top_left,
-                    Size::new(BACK_TOUCH_WIDTH, HEADER_HEIGHT_PX),
-                );
-                if back_rect.contains(pt) {
-                    return Some(Action::GoBack);
-                }
-
-                // Check each category row (using screen bounds)
-                for (i, category) in CATEGORIES.iter().enumerate() {
-                    if self.row_screen_bounds(i).contains(pt) {
-                        return Some(Action::NavigateToPage(category.target));
-                    }
+            TouchEvent::Press(point) => return self.handle_press(event, point.to_point()),
+            TouchEvent::Drag(_) => {
+                // An active slider drag takes priority over scrolling
+                let slider = self.device.handle_slider_touch(event);
+                if let Some(action) = self.absorb(slider) {
+                    return action;
                 }
 
-                // Start tracking for potential drag
                 self.scroll.handle_touch(event);
+                self.sync_slider_bounds();
+                self.dirty = true;
             }
-            TouchEvent::Drag(_) => {
+            TouchEvent::Release(_) => {
+                // End any scroll tracking, then any slider drag (which commits it)
                 self.scroll.handle_touch(event);
-                self.dirty = true;
+                if let TouchResult::Action(action) = self.device.handle_slider_touch(event) {
+                    return Some(action);
+                }
             }
         }
         None
@@ -331,14 +427,24 @@ impl Drawable for SettingsPage {
             return Ok(());
         }
 
-        display.clear(COLOR_BACKGROUND)?;
+        display.clear(self.palette.background)?;
 
         self.draw_header(display)?;
 
+        let rows = self.rows();
         for (i, category) in CATEGORIES.iter().enumerate() {
-            self.draw_row(display, i, category)?;
+            if rows.is_visible(i) {
+                self.draw_category_row(display, rows.bounds(i), category)?;
+            }
         }
 
+        self.device
+            .draw(display, rows.section(DEVICE_FIRST_ROW), &self.palette)?;
+        self.calibration
+            .draw(display, rows.section(CALIBRATION_FIRST_ROW), &self.palette)?;
+        self.storage
+            .draw(display, rows.section(STORAGE_FIRST_ROW), &self.palette)?;
+
         // Draw scrollbar indicators
         self.scroll.draw(display)?;
 
@@ -361,3 +467,60 @@ impl Drawable for SettingsPage {
         self.dirty = true;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{DEFAULT_SAMPLE_INTERVAL_SECS, SAMPLE_INTERVAL_STEP_SECS};
+    use crate::pages::settings::rows::increment_bounds;
+    use crate::pages::test_harness::screen_bounds;
+    use crate::ui::core::TouchPoint;
+
+    fn page() -> SettingsPage {
+        SettingsPage::new(
+            screen_bounds(),
+            DEFAULT_SAMPLE_INTERVAL_SECS,
+            80,
+            22,
+            &SensorCalibration::identity(),
+            &Theme::dark(),
+        )
+    }
+
+    fn press(page: &mut SettingsPage, target: Rectangle) -> Option<Action> {
+        let center = target.center();
+        let point = TouchPoint::new(center.x as u16, center.y as u16);
+        let action = page.handle_touch(TouchEvent::Press(point));
+        page.handle_touch(TouchEvent::Release(point));
+        action
+    }
+
+    #[test]
+    fn back_button_goes_back() {
+        let mut page = page();
+        let back = Rectangle::new(Point::zero(), Size::new(BACK_TOUCH_WIDTH, HEADER_HEIGHT_PX));
+        assert_eq!(press(&mut page, back), Some(Action::GoBack));
+    }
+
+    #[test]
+    fn category_row_navigates_to_its_page() {
+        let mut page = page();
+        let row = page.rows().bounds(1);
+        assert_eq!(
+            press(&mut page, row),
+            Some(Action::NavigateToPage(PageId::Monitor))
+        );
+    }
+
+    #[test]
+    fn presses_reach_the_device_group() {
+        let mut page = page();
+        let interval_row = page.rows().section(DEVICE_FIRST_ROW).bounds(0);
+        assert_eq!(
+            press(&mut page, increment_bounds(interval_row)),
+            Some(Action::SetSampleInterval(
+                DEFAULT_SAMPLE_INTERVAL_SECS + SAMPLE_INTERVAL_STEP_SECS
+            ))
+        );
+    }
+}
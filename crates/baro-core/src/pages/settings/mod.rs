@@ -1,5 +1,9 @@
+mod calibration;
+mod device;
 pub mod display;
 pub mod list;
+mod rows;
+mod storage;
 
 pub use display::DisplaySettingsPage;
 pub use list::SettingsPage;
@@ -0,0 +1,247 @@
+// src/pages/settings/rows.rs
+//! Row geometry and drawing shared by the settings list's control groups.
+//!
+//! Every row of the settings list is a pill with a label line over a value
+//! line. Control groups add `-`/`+` stepper buttons, a labelled button, or a
+//! wide widget area (slider, usage bar) at the right of their rows, and use
+//! [`RowLayout`] to find where their rows currently sit on screen.
+
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::mono_font::iso_8859_1::FONT_6X10;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle, RoundedRectangle};
+use embedded_graphics::text::{Alignment, Text};
+
+use crate::ui::styling::{
+    ColorPalette, FONT_6X10_CENTERED_BASELINE_PX, LIST_ROW_CORNER_RADIUS_PX, LIST_ROW_GAP_PX,
+    LIST_ROW_HEIGHT_PX, LIST_ROW_LABEL_BASELINE_PX, LIST_ROW_TEXT_INSET_PX,
+    LIST_ROW_VALUE_SPACING_PX,
+};
+
+// ---------------------------------------------------------------------------
+// Layout constants
+// ---------------------------------------------------------------------------
+
+/// Horizontal padding for the list area
+const LIST_PADDING_X: u32 = 8;
+
+/// Vertical padding at top of scroll content
+const LIST_PADDING_TOP: u32 = 4;
+
+/// Side length of the square `-`/`+` stepper buttons
+const STEPPER_BUTTON_SIZE_PX: u32 = 28;
+
+/// Horizontal gap between the stepper buttons
+const STEPPER_BUTTON_GAP_PX: u32 = 6;
+
+/// Right inset of the row buttons and widgets from the row edge
+const CONTROL_RIGHT_INSET_PX: u32 = 8;
+
+/// Width of the labelled buttons (theme, CO2 calibration) at the right of a row
+const ROW_BUTTON_WIDTH_PX: u32 = 64;
+
+/// Left offset of a row's widget area from the row edge (leaves room for the label)
+const WIDGET_LEFT_OFFSET_PX: u32 = 96;
+
+/// Height of a row's widget area
+const WIDGET_HEIGHT_PX: u32 = 24;
+
+// ---------------------------------------------------------------------------
+// RowLayout
+// ---------------------------------------------------------------------------
+
+/// Maps row indices to screen bounds at the list's current scroll position.
+///
+/// Indices count from the first row of a section, so a control group can
+/// number its own rows from zero once given [`RowLayout::section`].
+#[derive(Debug, Clone, Copy)]
+pub(super) struct RowLayout {
+    viewport: Rectangle,
+    scroll_y: i32,
+    first: usize,
+}
+
+impl RowLayout {
+    /// Layout of the whole list inside `viewport`, scrolled down by `scroll_y`.
+    pub(super) fn new(viewport: Rectangle, scroll_y: i32) -> Self {
+        Self {
+            viewport,
+            scroll_y,
+            first: 0,
+        }
+    }
+
+    /// Layout of the section whose first row is row `first` of this one.
+    pub(super) fn section(self, first: usize) -> Self {
+        Self {
+            first: self.first + first,
+            ..self
+        }
+    }
+
+    /// Total content height of a list with `count` rows.
+    pub(super) fn content_height(count: usize) -> u32 {
+        LIST_PADDING_TOP + count as u32 * (LIST_ROW_HEIGHT_PX + LIST_ROW_GAP_PX)
+    }
+
+    /// Row bounds on screen, adjusted for the scroll offset.
+    pub(super) fn bounds(&self, index: usize) -> Rectangle {
+        let content_y = LIST_PADDING_TOP as i32
+            + ((self.first + index) as u32 * (LIST_ROW_HEIGHT_PX + LIST_ROW_GAP_PX)) as i32;
+        let x = self.viewport.top_left.x + LIST_PADDING_X as i32;
+        let y = self.viewport.top_left.y + content_y - self.scroll_y;
+        let width = self.viewport.size.width.saturating_sub(LIST_PADDING_X * 2);
+        Rectangle::new(Point::new(x, y), Size::new(width, LIST_ROW_HEIGHT_PX))
+    }
+
+    /// Check if a row is at least partially visible in the viewport.
+    pub(super) fn is_visible(&self, index: usize) -> bool {
+        let row_top = self.bounds(index).top_left.y;
+        let row_bottom = row_top + LIST_ROW_HEIGHT_PX as i32;
+        let vp_top = self.viewport.top_left.y;
+        let vp_bottom = vp_top + self.viewport.size.height as i32;
+        row_bottom > vp_top && row_top < vp_bottom
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Control geometry
+// ---------------------------------------------------------------------------
+
+/// Screen bounds of the `+` stepper button inside a row.
+pub(super) fn increment_bounds(row: Rectangle) -> Rectangle {
+    let x = row.top_left.x + row.size.width as i32
+        - (CONTROL_RIGHT_INSET_PX + STEPPER_BUTTON_SIZE_PX) as i32;
+    let y = row.top_left.y + (LIST_ROW_HEIGHT_PX - STEPPER_BUTTON_SIZE_PX) as i32 / 2;
+    Rectangle::new(
+        Point::new(x, y),
+        Size::new(STEPPER_BUTTON_SIZE_PX, STEPPER_BUTTON_SIZE_PX),
+    )
+}
+
+/// Screen bounds of the `-` stepper button inside a row.
+pub(super) fn decrement_bounds(row: Rectangle) -> Rectangle {
+    let plus = increment_bounds(row);
+    Rectangle::new(
+        Point::new(
+            plus.top_left.x - (STEPPER_BUTTON_GAP_PX + STEPPER_BUTTON_SIZE_PX) as i32,
+            plus.top_left.y,
+        ),
+        plus.size,
+    )
+}
+
+/// Screen bounds of the labelled button inside a row.
+pub(super) fn button_bounds(row: Rectangle) -> Rectangle {
+    let x = row.top_left.x + row.size.width as i32
+        - (CONTROL_RIGHT_INSET_PX + ROW_BUTTON_WIDTH_PX) as i32;
+    let y = row.top_left.y + (LIST_ROW_HEIGHT_PX - STEPPER_BUTTON_SIZE_PX) as i32 / 2;
+    Rectangle::new(
+        Point::new(x, y),
+        Size::new(ROW_BUTTON_WIDTH_PX, STEPPER_BUTTON_SIZE_PX),
+    )
+}
+
+/// Screen bounds of the wide widget area (slider, usage bar) inside a row.
+pub(super) fn widget_bounds(row: Rectangle) -> Rectangle {
+    let x = row.top_left.x + WIDGET_LEFT_OFFSET_PX as i32;
+    let y = row.top_left.y + (LIST_ROW_HEIGHT_PX - WIDGET_HEIGHT_PX) as i32 / 2;
+    let width = row
+        .size
+        .width
+        .saturating_sub(WIDGET_LEFT_OFFSET_PX + CONTROL_RIGHT_INSET_PX);
+    Rectangle::new(Point::new(x, y), Size::new(width, WIDGET_HEIGHT_PX))
+}
+
+// ---------------------------------------------------------------------------
+// Drawing
+// ---------------------------------------------------------------------------
+
+/// Draw a row's pill background with a label line over a value line.
+pub(super) fn draw_info_row<D: DrawTarget<Color = Rgb565>>(
+    display: &mut D,
+    bounds: Rectangle,
+    label: &str,
+    value: &str,
+    palette: &ColorPalette,
+) -> Result<(), D::Error> {
+    RoundedRectangle::with_equal_corners(
+        bounds,
+        Size::new(LIST_ROW_CORNER_RADIUS_PX, LIST_ROW_CORNER_RADIUS_PX),
+    )
+    .into_styled(PrimitiveStyle::with_fill(palette.surface))
+    .draw(display)?;
+
+    let text_x = bounds.top_left.x + LIST_ROW_TEXT_INSET_PX as i32;
+    let label_y = bounds.top_left.y + LIST_ROW_LABEL_BASELINE_PX as i32;
+    Text::with_alignment(
+        label,
+        Point::new(text_x, label_y),
+        MonoTextStyle::new(&FONT_6X10, palette.text_primary),
+        Alignment::Left,
+    )
+    .draw(display)?;
+
+    Text::with_alignment(
+        value,
+        Point::new(text_x, label_y + LIST_ROW_VALUE_SPACING_PX as i32),
+        MonoTextStyle::new(&FONT_6X10, palette.text_secondary),
+        Alignment::Left,
+    )
+    .draw(display)?;
+
+    Ok(())
+}
+
+/// Draw a filled button with a centered glyph or short label.
+pub(super) fn draw_button<D: DrawTarget<Color = Rgb565>>(
+    display: &mut D,
+    bounds: Rectangle,
+    glyph: &str,
+    palette: &ColorPalette,
+) -> Result<(), D::Error> {
+    RoundedRectangle::with_equal_corners(
+        bounds,
+        Size::new(LIST_ROW_CORNER_RADIUS_PX, LIST_ROW_CORNER_RADIUS_PX),
+    )
+    .into_styled(PrimitiveStyle::with_fill(palette.control))
+    .draw(display)?;
+
+    Text::with_alignment(
+        glyph,
+        bounds.center() + Point::new(0, FONT_6X10_CENTERED_BASELINE_PX as i32),
+        MonoTextStyle::new(&FONT_6X10, palette.text_primary),
+        Alignment::Center,
+    )
+    .draw(display)?;
+
+    Ok(())
+}
+
+/// Draw an info row with `-`/`+` stepper buttons at its right.
+pub(super) fn draw_stepper_row<D: DrawTarget<Color = Rgb565>>(
+    display: &mut D,
+    bounds: Rectangle,
+    label: &str,
+    value: &str,
+    palette: &ColorPalette,
+) -> Result<(), D::Error> {
+    draw_info_row(display, bounds, label, value, palette)?;
+    draw_button(display, decrement_bounds(bounds), "-", palette)?;
+    draw_button(display, increment_bounds(bounds), "+", palette)
+}
+
+/// Draw an info row with a labelled button at its right.
+pub(super) fn draw_button_row<D: DrawTarget<Color = Rgb565>>(
+    display: &mut D,
+    bounds: Rectangle,
+    label: &str,
+    value: &str,
+    button: &str,
+    palette: &ColorPalette,
+) -> Result<(), D::Error> {
+    draw_info_row(display, bounds, label, value, palette)?;
+    draw_button(display, button_bounds(bounds), button, palette)
+}
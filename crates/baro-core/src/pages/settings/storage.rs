@@ -0,0 +1,378 @@
+// src/pages/settings/storage.rs
+//! Read-only storage rows of the settings list.
+//!
+//! - **SD card** — bar showing how full the card is
+//! - **Lifetime** — sample count and uptime, then all-time min/avg/max for
+//!   each sensor
+//! - **Today** — min/avg/max for each sensor since midnight UTC
+
+use core::fmt::Write;
+
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle, RoundedRectangle};
+use heapless::String as HeaplessString;
+
+use crate::metrics::stats::TrendStats;
+use crate::sensors::SensorType;
+use crate::storage::manager::StorageCapacity;
+use crate::storage::{LifetimeStats, MAX_SENSORS};
+use crate::ui::styling::ColorPalette;
+
+use super::rows::{RowLayout, draw_info_row, widget_bounds};
+
+/// Height of the SD card usage bar
+const CAPACITY_BAR_HEIGHT_PX: u32 = 8;
+
+/// Sensors listed in the lifetime section, in display order.
+const LIFETIME_SENSORS: &[SensorType] = &[
+    SensorType::Temperature,
+    SensorType::Humidity,
+    SensorType::Co2,
+    SensorType::Lux,
+    SensorType::Pressure,
+];
+
+/// Row of the SD card usage bar
+const STORAGE_ROW: usize = 0;
+
+/// Row of the lifetime summary, followed by one row per lifetime sensor
+const LIFETIME_ROW: usize = 1;
+
+/// Row of the today heading, followed by one row per sensor
+const TODAY_ROW: usize = LIFETIME_ROW + 1 + LIFETIME_SENSORS.len();
+
+/// Min/avg/max per sensor slot, streamed from stored rollups
+type SensorSummaries = [TrendStats; MAX_SENSORS];
+
+/// Write a stored milli-unit value in display units.
+///
+/// CO2 and lux are shown as whole numbers, everything else with one decimal.
+fn write_milli_value<const N: usize>(out: &mut HeaplessString<N>, sensor: SensorType, value: i32) {
+    let sign = if value < 0 { "-" } else { "" };
+    let magnitude = value.unsigned_abs();
+    let _ = match sensor {
+        SensorType::Co2 | SensorType::Lux => write!(out, "{}{}", sign, magnitude / 1000),
+        _ => write!(
+            out,
+            "{}{}.{}",
+            sign,
+            magnitude / 1000,
+            magnitude % 1000 / 100
+        ),
+    };
+}
+
+/// Write `min … / avg … / max … unit`, or `--` when there is nothing to show.
+fn write_min_avg_max<const N: usize>(
+    out: &mut HeaplessString<N>,
+    sensor: SensorType,
+    summary: Option<(i32, i32, i32)>,
+) {
+    match summary {
+        Some((min, avg, max)) => {
+            let _ = write!(out, "min ");
+            write_milli_value(out, sensor, min);
+            let _ = write!(out, " / avg ");
+            write_milli_value(out, sensor, avg);
+            let _ = write!(out, " / max ");
+            write_milli_value(out, sensor, max);
+            let _ = write!(out, " {}", sensor.unit());
+        }
+        None => {
+            let _ = write!(out, "--");
+        }
+    }
+}
+
+/// Min, average and max of `sensor` in `summaries`, if it has any samples.
+fn summary_of(summaries: &SensorSummaries, sensor: SensorType) -> Option<(i32, i32, i32)> {
+    let stats = &summaries[sensor.index()];
+    (stats.count > 0).then_some((stats.min, stats.avg, stats.max))
+}
+
+/// Storage usage and lifetime statistics shown in the settings list.
+#[derive(Default)]
+pub(super) struct StorageRows {
+    /// Storage fill levels (`None` when no storage manager is available)
+    capacity: Option<StorageCapacity>,
+    /// All-time stats and the uptime they cover (`None` when unavailable)
+    lifetime: Option<(LifetimeStats, u32)>,
+    /// Stats streamed from today's rollups
+    today: Option<SensorSummaries>,
+    /// Stats streamed from every stored daily rollup
+    all_time: Option<SensorSummaries>,
+}
+
+impl StorageRows {
+    /// Number of rows the group occupies
+    pub(super) const ROW_COUNT: usize = TODAY_ROW + 1 + LIFETIME_SENSORS.len();
+
+    /// Update the storage fill levels, returning whether they changed.
+    pub(super) fn set_capacity(&mut self, capacity: StorageCapacity) -> bool {
+        let changed = self.capacity != Some(capacity);
+        self.capacity = Some(capacity);
+        changed
+    }
+
+    /// Update the lifetime stats; `now` is the current sample timestamp.
+    pub(super) fn set_lifetime_stats(&mut self, stats: &LifetimeStats, now: u32) {
+        self.lifetime = Some((*stats, stats.uptime(now)));
+    }
+
+    /// Update the today and all-time summaries streamed from storage.
+    pub(super) fn set_window_stats(&mut self, today: SensorSummaries, all_time: SensorSummaries) {
+        self.today = Some(today);
+        self.all_time = Some(all_time);
+    }
+
+    /// All-time min/avg/max of `sensor`
+    ///
+    /// Daily rollups only exist for finished days, so until the first one
+    /// is stored the in-memory lifetime stats are shown instead.
+    fn all_time_summary(&self, sensor: SensorType) -> Option<(i32, i32, i32)> {
+        self.all_time
+            .as_ref()
+            .and_then(|summaries| summary_of(summaries, sensor))
+            .or_else(|| {
+                let (stats, _) = self.lifetime.as_ref()?;
+                let index = sensor.index();
+                Some((stats.min(index)?, stats.average(index)?, stats.max(index)?))
+            })
+    }
+
+    /// Today's min/avg/max of `sensor`
+    fn today_summary(&self, sensor: SensorType) -> Option<(i32, i32, i32)> {
+        summary_of(self.today.as_ref()?, sensor)
+    }
+
+    pub(super) fn draw<D: DrawTarget<Color = Rgb565>>(
+        &self,
+        display: &mut D,
+        rows: RowLayout,
+        palette: &ColorPalette,
+    ) -> Result<(), D::Error> {
+        if rows.is_visible(STORAGE_ROW) {
+            self.draw_storage_row(display, rows.bounds(STORAGE_ROW), palette)?;
+        }
+        self.draw_lifetime_rows(display, rows.section(LIFETIME_ROW), palette)?;
+        self.draw_today_rows(display, rows.section(TODAY_ROW), palette)
+    }
+
+    fn draw_storage_row<D: DrawTarget<Color = Rgb565>>(
+        &self,
+        display: &mut D,
+        bounds: Rectangle,
+        palette: &ColorPalette,
+    ) -> Result<(), D::Error> {
+        let capacity = self.capacity.filter(|c| c.card_bytes > 0);
+        let mut value: HeaplessString<16> = HeaplessString::new();
+        match capacity {
+            Some(c) => {
+                let _ = write!(value, "{}% used", c.card_percent_used());
+            }
+            None => {
+                let _ = write!(value, "Unavailable");
+            }
+        }
+        draw_info_row(display, bounds, "SD card", &value, palette)?;
+
+        // Usage bar in the row's widget area
+        let area = widget_bounds(bounds);
+        let track = Rectangle::new(
+            Point::new(
+                area.top_left.x,
+                area.center().y - (CAPACITY_BAR_HEIGHT_PX / 2) as i32,
+            ),
+            Size::new(area.size.width, CAPACITY_BAR_HEIGHT_PX),
+        );
+        let corner = Size::new(CAPACITY_BAR_HEIGHT_PX / 2, CAPACITY_BAR_HEIGHT_PX / 2);
+
+        RoundedRectangle::with_equal_corners(track, corner)
+            .into_styled(PrimitiveStyle::with_fill(palette.control))
+            .draw(display)?;
+
+        let percent = capacity.map_or(0, |c| c.card_percent_used()) as u32;
+        let filled_width = track.size.width * percent / 100;
+        if filled_width > 0 {
+            RoundedRectangle::with_equal_corners(
+                Rectangle::new(
+                    track.top_left,
+                    Size::new(filled_width, CAPACITY_BAR_HEIGHT_PX),
+                ),
+                corner,
+            )
+            .into_styled(PrimitiveStyle::with_fill(palette.secondary))
+            .draw(display)?;
+        }
+
+        Ok(())
+    }
+
+    /// Draw the lifetime summary row followed by one row per sensor.
+    fn draw_lifetime_rows<D: DrawTarget<Color = Rgb565>>(
+        &self,
+        display: &mut D,
+        rows: RowLayout,
+        palette: &ColorPalette,
+    ) -> Result<(), D::Error> {
+        if rows.is_visible(0) {
+            let mut summary: HeaplessString<40> = HeaplessString::new();
+            match &self.lifetime {
+                Some((stats, uptime_secs)) if stats.total_samples > 0 => {
+                    let hours = uptime_secs / 3600;
+                    let _ = write!(
+                        summary,
+                        "{} samples, up {}d {}h",
+                        stats.total_samples,
+                        hours / 24,
+                        hours % 24
+                    );
+                }
+                _ => {
+                    let _ = write!(summary, "No samples yet");
+                }
+            }
+            draw_info_row(display, rows.bounds(0), "Lifetime", &summary, palette)?;
+        }
+
+        for (i, &sensor) in LIFETIME_SENSORS.iter().enumerate() {
+            let row = 1 + i;
+            if !rows.is_visible(row) {
+                continue;
+            }
+
+            let mut value: HeaplessString<48> = HeaplessString::new();
+            write_min_avg_max(&mut value, sensor, self.all_time_summary(sensor));
+            draw_info_row(display, rows.bounds(row), sensor.name(), &value, palette)?;
+        }
+
+        Ok(())
+    }
+
+    /// Draw the today heading followed by one row per sensor.
+    fn draw_today_rows<D: DrawTarget<Color = Rgb565>>(
+        &self,
+        display: &mut D,
+        rows: RowLayout,
+        palette: &ColorPalette,
+    ) -> Result<(), D::Error> {
+        if rows.is_visible(0) {
+            draw_info_row(display, rows.bounds(0), "Today", "Since 00:00 UTC", palette)?;
+        }
+
+        for (i, &sensor) in LIFETIME_SENSORS.iter().enumerate() {
+            let row = 1 + i;
+            if !rows.is_visible(row) {
+                continue;
+            }
+
+            let mut value: HeaplessString<48> = HeaplessString::new();
+            write_min_avg_max(&mut value, sensor, self.today_summary(sensor));
+            draw_info_row(display, rows.bounds(row), sensor.name(), &value, palette)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{MISSING_SENSOR_VALUE, RawSample};
+
+    const START: u32 = 1_700_000_000;
+
+    fn summaries(sensor: SensorType, min: i32, avg: i32, max: i32) -> SensorSummaries {
+        let mut summaries = [TrendStats::default(); MAX_SENSORS];
+        summaries[sensor.index()] = TrendStats {
+            avg,
+            min,
+            max,
+            std_dev: 0,
+            latest: avg,
+            count: 12,
+        };
+        summaries
+    }
+
+    fn row_text(summary: Option<(i32, i32, i32)>, sensor: SensorType) -> HeaplessString<48> {
+        let mut value = HeaplessString::new();
+        write_min_avg_max(&mut value, sensor, summary);
+        value
+    }
+
+    /// Lifetime stats that saw a single CO₂ reading of `value`
+    fn lifetime_with_co2(value: i32) -> LifetimeStats {
+        let mut values = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+        values[SensorType::Co2.index()] = value;
+        let mut stats = LifetimeStats::new(START);
+        stats.update(&RawSample::new(START, &values));
+        stats
+    }
+
+    #[test]
+    fn today_rows_show_the_streamed_summary() {
+        let mut rows = StorageRows::default();
+        rows.set_window_stats(
+            summaries(SensorType::Temperature, 18_250, 21_400, 24_960),
+            [TrendStats::default(); MAX_SENSORS],
+        );
+
+        assert_eq!(
+            row_text(
+                rows.today_summary(SensorType::Temperature),
+                SensorType::Temperature
+            ),
+            "min 18.2 / avg 21.4 / max 24.9 °C"
+        );
+        // No rollups for this sensor today
+        assert_eq!(
+            row_text(
+                rows.today_summary(SensorType::Humidity),
+                SensorType::Humidity
+            ),
+            "--"
+        );
+    }
+
+    #[test]
+    fn rows_without_storage_show_placeholders() {
+        let rows = StorageRows::default();
+        for sensor in LIFETIME_SENSORS.iter().copied() {
+            assert_eq!(row_text(rows.today_summary(sensor), sensor), "--");
+            assert_eq!(row_text(rows.all_time_summary(sensor), sensor), "--");
+        }
+    }
+
+    #[test]
+    fn all_time_prefers_the_daily_rollups() {
+        let mut rows = StorageRows::default();
+        rows.set_lifetime_stats(&lifetime_with_co2(700_000), START + 60);
+        rows.set_window_stats(
+            [TrendStats::default(); MAX_SENSORS],
+            summaries(SensorType::Co2, 410_000, 655_000, 1_840_000),
+        );
+
+        assert_eq!(
+            row_text(rows.all_time_summary(SensorType::Co2), SensorType::Co2),
+            "min 410 / avg 655 / max 1840 ppm"
+        );
+    }
+
+    #[test]
+    fn all_time_falls_back_to_lifetime_stats_before_the_first_day() {
+        let mut rows = StorageRows::default();
+        rows.set_lifetime_stats(&lifetime_with_co2(700_000), START + 60);
+        rows.set_window_stats(
+            [TrendStats::default(); MAX_SENSORS],
+            [TrendStats::default(); MAX_SENSORS],
+        );
+
+        assert_eq!(
+            rows.all_time_summary(SensorType::Co2),
+            Some((700_000, 700_000, 700_000))
+        );
+    }
+}
@@ -0,0 +1,461 @@
+//! Boot splash page
+//!
+//! Shown from power-on until the sensors start, so the user sees what the
+//! device is waiting on instead of a blank screen. Each
+//! `SystemEvent::RunState` moves the page along the boot sequence
+//! (start-up → Wi-Fi → time sync → sensors); once the sensors are running the
+//! page asks to navigate to the home screen.
+//!
+//! ```text
+//! ┌──────────────────────────────────────┐
+//! │                                      │
+//! │             AIR AROUND YOU           │  ← title
+//! │                                      │
+//! │                 ∘ ∘ ●                │  ← spinner
+//! │                ∘     ∘               │
+//! │                 ∘ ∘ ∘                │
+//! │                                      │
+//! │           Connecting to Wi-Fi        │  ← current step
+//! │             ▬▬▬ ▬▬▬ ─── ───          │  ← progress segments
+//! │               Step 2 of 4            │
+//! └──────────────────────────────────────┘
+//! ```
+
+use embassy_time::Duration;
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::mono_font::iso_8859_1::{FONT_6X10, FONT_10X20};
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Circle, PrimitiveStyle, Rectangle};
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+use heapless::String;
+
+use core::fmt::Write;
+
+use crate::app_state::AppRunState;
+use crate::pages::page::Page;
+use crate::ui::core::{Action, Drawable, PageEvent, PageId, SystemEvent, TouchEvent};
+use crate::ui::{ColorPalette, Theme};
+
+// ---------------------------------------------------------------------------
+// Boot sequence
+// ---------------------------------------------------------------------------
+
+/// Number of steps in the boot sequence shown by the progress segments
+pub const BOOT_STEP_COUNT: u8 = 4;
+
+/// Label describing what the device is doing in `state`
+pub fn progress_label(state: AppRunState) -> &'static str {
+    match state {
+        AppRunState::Uninitialized => "Starting up",
+        AppRunState::WifiConnecting => "Connecting to Wi-Fi",
+        AppRunState::WifiConnected => "Wi-Fi connected",
+        AppRunState::TimeSyncing => "Syncing time",
+        AppRunState::TimeKnown => "Time synced",
+        AppRunState::TimeUnsynced => "Time not synced",
+        AppRunState::SensorsRunning => "Starting sensors",
+        AppRunState::Error => "Startup failed",
+    }
+}
+
+/// 1-based boot step `state` belongs to, or 0 if startup failed
+pub fn progress_step(state: AppRunState) -> u8 {
+    match state {
+        AppRunState::Uninitialized => 1,
+        AppRunState::WifiConnecting | AppRunState::WifiConnected => 2,
+        AppRunState::TimeSyncing | AppRunState::TimeKnown | AppRunState::TimeUnsynced => 3,
+        AppRunState::SensorsRunning => 4,
+        AppRunState::Error => 0,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Layout constants
+// ---------------------------------------------------------------------------
+
+/// Interval between spinner frames in milliseconds
+pub const SPINNER_FRAME_MS: u64 = 125;
+
+/// Title shown above the spinner.
+const TITLE_TEXT: &str = "AIR AROUND YOU";
+
+/// Vertical offset of the title from the page centre in pixels.
+const TITLE_OFFSET_Y_PX: i32 = -70;
+
+/// Vertical offset of the spinner centre from the page centre in pixels.
+const SPINNER_OFFSET_Y_PX: i32 = -18;
+
+/// Diameter of one spinner dot in pixels.
+const SPINNER_DOT_DIAMETER_PX: u32 = 6;
+
+/// Spinner dot centres relative to the spinner centre, clockwise from the top
+/// (a ring of radius 16, diagonals rounded to the nearest pixel).
+const SPINNER_DOT_OFFSETS: [(i32, i32); 8] = [
+    (0, -16),
+    (11, -11),
+    (16, 0),
+    (11, 11),
+    (0, 16),
+    (-11, 11),
+    (-16, 0),
+    (-11, -11),
+];
+
+/// Vertical offset of the step label from the page centre in pixels.
+const LABEL_OFFSET_Y_PX: i32 = 26;
+
+/// Vertical offset of the progress segments from the page centre in pixels.
+const SEGMENTS_OFFSET_Y_PX: i32 = 46;
+
+/// Width of one progress segment in pixels.
+const SEGMENT_WIDTH_PX: u32 = 28;
+
+/// Height of one progress segment in pixels.
+const SEGMENT_HEIGHT_PX: u32 = 4;
+
+/// Gap between progress segments in pixels.
+const SEGMENT_GAP_PX: u32 = 6;
+
+/// Vertical offset of the "Step n of 4" caption from the page centre in pixels.
+const CAPTION_OFFSET_Y_PX: i32 = 64;
+
+// ---------------------------------------------------------------------------
+// SplashPage
+// ---------------------------------------------------------------------------
+
+/// Full-screen boot progress page driven by [`AppRunState`].
+///
+/// The spinner advances one dot per [`Page::update`]; the page asks to be
+/// ticked every [`SPINNER_FRAME_MS`] until startup fails.
+pub struct SplashPage {
+    bounds: Rectangle,
+    run_state: AppRunState,
+    spinner_frame: usize,
+    pending_action: Option<Action>,
+    palette: ColorPalette,
+    dirty: bool,
+}
+
+impl SplashPage {
+    /// Create the page at the start of the boot sequence.
+    pub fn new(bounds: Rectangle, theme: &Theme) -> Self {
+        Self {
+            bounds,
+            run_state: AppRunState::Uninitialized,
+            spinner_frame: 0,
+            pending_action: None,
+            palette: theme.palette,
+            dirty: true,
+        }
+    }
+
+    /// Boot state currently shown.
+    pub fn run_state(&self) -> AppRunState {
+        self.run_state
+    }
+
+    /// Show `run_state`, returning whether it changed
+    ///
+    /// Reaching `SensorsRunning` queues navigation to the home page.
+    pub fn set_run_state(&mut self, run_state: AppRunState) -> bool {
+        if self.run_state == run_state {
+            return false;
+        }
+
+        self.run_state = run_state;
+        if run_state == AppRunState::SensorsRunning {
+            self.pending_action = Some(Action::NavigateToPage(PageId::Home));
+        }
+        self.dirty = true;
+        true
+    }
+
+    /// Whether the spinner is still turning (it stops once startup fails)
+    fn is_spinning(&self) -> bool {
+        self.run_state != AppRunState::Error
+    }
+
+    // -- drawing -------------------------------------------------------------
+
+    /// Draw `text` centred horizontally, `offset_y` pixels from the page centre.
+    fn draw_centered_text<D: DrawTarget<Color = Rgb565>>(
+        &self,
+        display: &mut D,
+        text: &str,
+        offset_y: i32,
+        style: MonoTextStyle<'_, Rgb565>,
+    ) -> Result<(), D::Error> {
+        Text::with_text_style(
+            text,
+            self.bounds.center() + Point::new(0, offset_y),
+            style,
+            TextStyleBuilder::new()
+                .alignment(Alignment::Center)
+                .baseline(Baseline::Middle)
+                .build(),
+        )
+        .draw(display)?;
+        Ok(())
+    }
+
+    /// Draw the ring of spinner dots, the current one highlighted with a
+    /// dimmer trailing dot behind it.
+    fn draw_spinner<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        let center = self.bounds.center() + Point::new(0, SPINNER_OFFSET_Y_PX);
+        let dot_count = SPINNER_DOT_OFFSETS.len();
+        let head = self.spinner_frame % dot_count;
+        let tail = (head + dot_count - 1) % dot_count;
+
+        for (index, &(dx, dy)) in SPINNER_DOT_OFFSETS.iter().enumerate() {
+            let color = if !self.is_spinning() {
+                self.palette.error
+            } else if index == head {
+                self.palette.primary
+            } else if index == tail {
+                self.palette.text_secondary
+            } else {
+                self.palette.border
+            };
+            Circle::with_center(center + Point::new(dx, dy), SPINNER_DOT_DIAMETER_PX)
+                .into_styled(PrimitiveStyle::with_fill(color))
+                .draw(display)?;
+        }
+
+        Ok(())
+    }
+
+    /// Draw one segment per boot step, filled up to the current step.
+    fn draw_segments<D: DrawTarget<Color = Rgb565>>(
+        &self,
+        display: &mut D,
+    ) -> Result<(), D::Error> {
+        let step = progress_step(self.run_state);
+        let total_width = BOOT_STEP_COUNT as u32 * SEGMENT_WIDTH_PX
+            + (BOOT_STEP_COUNT as u32 - 1) * SEGMENT_GAP_PX;
+        let left = self.bounds.center().x - (total_width / 2) as i32;
+        let top = self.bounds.center().y + SEGMENTS_OFFSET_Y_PX;
+
+        for index in 0..BOOT_STEP_COUNT {
+            let color = if index < step {
+                self.palette.primary
+            } else {
+                self.palette.border
+            };
+            let x = left + (index as u32 * (SEGMENT_WIDTH_PX + SEGMENT_GAP_PX)) as i32;
+            Rectangle::new(
+                Point::new(x, top),
+                Size::new(SEGMENT_WIDTH_PX, SEGMENT_HEIGHT_PX),
+            )
+            .into_styled(PrimitiveStyle::with_fill(color))
+            .draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Page trait
+// ---------------------------------------------------------------------------
+
+impl Page for SplashPage {
+    fn id(&self) -> PageId {
+        PageId::Splash
+    }
+
+    fn title(&self) -> &str {
+        "Starting"
+    }
+
+    fn on_activate(&mut self) {
+        self.dirty = true;
+    }
+
+    fn handle_touch(&mut self, _event: TouchEvent) -> Option<Action> {
+        // Nothing to interact with while booting
+        None
+    }
+
+    fn take_action(&mut self) -> Option<Action> {
+        self.pending_action.take()
+    }
+
+    fn update(&mut self) {
+        if self.is_spinning() {
+            self.spinner_frame = (self.spinner_frame + 1) % SPINNER_DOT_OFFSETS.len();
+            self.dirty = true;
+        }
+    }
+
+    fn wants_tick(&self) -> Option<Duration> {
+        self.is_spinning()
+            .then(|| Duration::from_millis(SPINNER_FRAME_MS))
+    }
+
+    fn on_event(&mut self, event: &PageEvent) -> bool {
+        match event {
+            PageEvent::SystemEvent(SystemEvent::RunState(run_state)) => {
+                self.set_run_state(*run_state)
+            }
+            _ => false,
+        }
+    }
+
+    fn draw_page<D: DrawTarget<Color = Rgb565>>(
+        &mut self,
+        display: &mut D,
+    ) -> Result<(), D::Error> {
+        Drawable::draw(self, display)
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Drawable trait
+// ---------------------------------------------------------------------------
+
+impl Drawable for SplashPage {
+    fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        display.clear(self.palette.background)?;
+
+        self.draw_centered_text(
+            display,
+            TITLE_TEXT,
+            TITLE_OFFSET_Y_PX,
+            MonoTextStyle::new(&FONT_10X20, self.palette.text_secondary),
+        )?;
+
+        self.draw_spinner(display)?;
+
+        let label_color = if self.is_spinning() {
+            self.palette.text_primary
+        } else {
+            self.palette.error
+        };
+        self.draw_centered_text(
+            display,
+            progress_label(self.run_state),
+            LABEL_OFFSET_Y_PX,
+            MonoTextStyle::new(&FONT_10X20, label_color),
+        )?;
+
+        self.draw_segments(display)?;
+
+        let step = progress_step(self.run_state);
+        if step > 0 {
+            let mut caption: String<16> = String::new();
+            let _ = write!(caption, "Step {} of {}", step, BOOT_STEP_COUNT);
+            self.draw_centered_text(
+                display,
+                &caption,
+                CAPTION_OFFSET_Y_PX,
+                MonoTextStyle::new(&FONT_6X10, self.palette.text_secondary),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::test_harness::screen_bounds;
+
+    const ALL_STATES: [AppRunState; 8] = [
+        AppRunState::Uninitialized,
+        AppRunState::WifiConnecting,
+        AppRunState::WifiConnected,
+        AppRunState::TimeSyncing,
+        AppRunState::TimeKnown,
+        AppRunState::TimeUnsynced,
+        AppRunState::SensorsRunning,
+        AppRunState::Error,
+    ];
+
+    #[test]
+    fn each_run_state_maps_to_its_progress_label() {
+        let expected = [
+            "Starting up",
+            "Connecting to Wi-Fi",
+            "Wi-Fi connected",
+            "Syncing time",
+            "Time synced",
+            "Time not synced",
+            "Starting sensors",
+            "Startup failed",
+        ];
+        for (state, label) in ALL_STATES.into_iter().zip(expected) {
+            assert_eq!(progress_label(state), label, "{state:?}");
+        }
+    }
+
+    #[test]
+    fn progress_steps_follow_the_boot_sequence() {
+        let steps: alloc::vec::Vec<u8> = ALL_STATES.into_iter().map(progress_step).collect();
+        assert_eq!(steps, [1, 2, 2, 3, 3, 3, 4, 0]);
+        assert!(steps.iter().all(|&step| step <= BOOT_STEP_COUNT));
+    }
+
+    #[test]
+    fn run_state_events_advance_and_sensors_running_navigates_home() {
+        let mut page = SplashPage::new(screen_bounds(), &Theme::dark());
+        Page::mark_clean(&mut page);
+
+        let event = |state| PageEvent::SystemEvent(SystemEvent::RunState(state));
+        assert!(page.on_event(&event(AppRunState::WifiConnecting)));
+        assert!(Page::is_dirty(&page));
+        assert!(!page.on_event(&event(AppRunState::WifiConnecting)));
+        assert_eq!(page.take_action(), None);
+
+        assert!(page.on_event(&event(AppRunState::SensorsRunning)));
+        assert_eq!(
+            page.take_action(),
+            Some(Action::NavigateToPage(PageId::Home))
+        );
+    }
+
+    #[test]
+    fn spinner_stops_ticking_after_a_failed_start() {
+        let mut page = SplashPage::new(screen_bounds(), &Theme::dark());
+        assert_eq!(
+            page.wants_tick(),
+            Some(Duration::from_millis(SPINNER_FRAME_MS))
+        );
+
+        page.set_run_state(AppRunState::Error);
+        assert_eq!(page.wants_tick(), None);
+    }
+}
@@ -0,0 +1,67 @@
+//! Pixel test harness for pages.
+//!
+//! Renders a page into a display-sized [`FrameBuffer`] (`MockDisplay` is
+//! limited to 64x64) so tests can assert what landed in key regions of the
+//! screen, such as a filled header or a non-black graph area.
+
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+
+use crate::framebuffer::FrameBuffer;
+use crate::pages::Page;
+use crate::ui::{DISPLAY_HEIGHT_PX, DISPLAY_WIDTH_PX};
+
+/// Full-screen bounds pages are constructed with under test.
+pub(crate) fn screen_bounds() -> Rectangle {
+    Rectangle::new(
+        Point::zero(),
+        Size::new(u32::from(DISPLAY_WIDTH_PX), u32::from(DISPLAY_HEIGHT_PX)),
+    )
+}
+
+/// Draw `page` into a fresh display-sized framebuffer.
+pub(crate) fn render<P: Page>(page: &mut P) -> FrameBuffer {
+    let mut fb = FrameBuffer::new();
+    page.draw_page(&mut fb).unwrap();
+    fb
+}
+
+/// Iterate the colors of every pixel inside `region`.
+fn region_pixels(fb: &FrameBuffer, region: Rectangle) -> impl Iterator<Item = Rgb565> + '_ {
+    region
+        .points()
+        .filter_map(|p| fb.pixel(p.x as usize, p.y as usize))
+}
+
+/// Number of pixels inside `region` that have exactly `color`.
+pub(crate) fn count_color(fb: &FrameBuffer, region: Rectangle, color: Rgb565) -> usize {
+    region_pixels(fb, region).filter(|&c| c == color).count()
+}
+
+/// Assert every pixel inside `region` has `color`.
+pub(crate) fn assert_filled(fb: &FrameBuffer, region: Rectangle, color: Rgb565) {
+    let area = region.size.width as usize * region.size.height as usize;
+    assert_eq!(
+        count_color(fb, region, color),
+        area,
+        "region {region:?} is not filled with {color:?}"
+    );
+}
+
+/// Assert no pixel inside `region` is black.
+pub(crate) fn assert_not_black(fb: &FrameBuffer, region: Rectangle) {
+    assert_eq!(
+        count_color(fb, region, Rgb565::BLACK),
+        0,
+        "region {region:?} has black pixels"
+    );
+}
+
+/// Assert at least one pixel inside `region` has `color`.
+pub(crate) fn assert_contains(fb: &FrameBuffer, region: Rectangle, color: Rgb565) {
+    assert!(
+        count_color(fb, region, color) > 0,
+        "region {region:?} has no {color:?} pixels"
+    );
+}
@@ -1,23 +1,19 @@
 //! Constants for the trend page module
 
-use embedded_graphics::pixelcolor::Rgb565;
-
-// Color constants from styling
-// RGB565 format: R(5 bits), G(6 bits), B(5 bits)
-// Convert from 8-bit RGB: R>>3, G>>2, B>>3
-pub(super) const COLOR_FOREGROUND: Rgb565 = Rgb565::new(26 >> 3, 32 >> 2, 33 >> 3);
-pub(super) const _COLOR_STROKE: Rgb565 = Rgb565::new(43 >> 3, 55 >> 2, 57 >> 3);
-pub(super) const LIGHT_GRAY: Rgb565 = Rgb565::new(21, 42, 21);
-
-/// Very faint gray for grid lines (less visible than LIGHT_GRAY)
-pub(super) const FAINT_GRAY: Rgb565 = Rgb565::new(10, 20, 10);
-
 /// Maximum data points for the largest time window (1 hour at 10s interval)
 pub(super) const MAX_DATA_POINTS: usize = 360;
 
 /// Window growth chunk size for auto-zoom (seconds)
 pub(super) const WINDOW_GROWTH_CHUNK_SECS: u32 = 300;
 
+/// Rollup boundaries remembered for graph annotation (oldest dropped first)
+pub(super) const MAX_ROLLUP_MARKS: usize = 16;
+
+/// Default EMA weight of each new reading in the headline current value
+///
+/// Lower is smoother; 1.0 shows every reading unfiltered.
+pub(super) const DEFAULT_CURRENT_VALUE_ALPHA: f32 = 0.3;
+
 /// Gradient fill opacity (80% transparent)
 pub(super) const GRADIENT_FILL_OPACITY: u8 = 51;
 
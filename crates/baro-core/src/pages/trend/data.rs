@@ -2,11 +2,11 @@
 
 use heapless::{Deque, Vec};
 
+use crate::metrics::stats::{StatsAccumulator, TrendStats};
 use crate::sensors::SensorType;
-use crate::storage::{RawSample, Rollup};
+use crate::storage::{MISSING_SENSOR_VALUE, RawSample, Rollup, Timestamp};
 
 use super::constants::{DataPoint, MAX_DATA_POINTS};
-use super::stats::TrendStats;
 
 /// Ring buffer for storing time-series data points
 pub(super) struct TrendDataBuffer {
@@ -26,23 +26,35 @@ impl TrendDataBuffer {
     }
 
     /// Add a data point from a raw sample
-    pub(super) fn push_from_raw_sample(&mut self, sample: &RawSample) {
+    ///
+    /// Returns the value added, or `None` if the sensor had no reading.
+    pub(super) fn push_from_raw_sample(&mut self, sample: &RawSample) -> Option<i32> {
         let value = sample.values[self.sensor_index];
+        if value == MISSING_SENSOR_VALUE {
+            return None;
+        }
         // If buffer is full, remove oldest
         if self.points.is_full() {
             self.points.pop_front();
         }
         let _ = self.points.push_back((sample.timestamp, value));
+        Some(value)
     }
 
     /// Add a data point from a rollup (using average)
-    pub(super) fn push_from_rollup(&mut self, rollup: &Rollup) {
+    ///
+    /// Returns the value added, or `None` if the sensor had no reading.
+    pub(super) fn push_from_rollup(&mut self, rollup: &Rollup) -> Option<i32> {
         let value = rollup.avg[self.sensor_index];
+        if value == MISSING_SENSOR_VALUE {
+            return None;
+        }
         // If buffer is full, remove oldest
         if self.points.is_full() {
             self.points.pop_front();
         }
         let _ = self.points.push_back((rollup.start_ts, value));
+        Some(value)
     }
 
     /// Bulk load multiple rollups into the buffer (for initialization)
@@ -72,46 +84,233 @@ impl TrendDataBuffer {
         window_secs: u32,
         now: u32,
     ) -> Vec<DataPoint, MAX_DATA_POINTS> {
-        let window_start = now.saturating_sub(window_secs);
+        let window_start = Timestamp::new(now).window_start(window_secs);
 
         self.points
             .iter()
-            .filter(|(ts, _)| *ts >= window_start)
+            .filter(|(ts, _)| !Timestamp::new(*ts).before(window_start))
             .copied()
             .collect()
     }
 
+    /// Get data points within the window, decimated to at most `max_points`
+    ///
+    /// Used for drawing: once there are more points than pixel columns the
+    /// extra points only overdraw, so the window is reduced with
+    /// [`decimate_lttb`]. Statistics use the full `get_window_data` instead.
+    pub(super) fn get_display_data(
+        &self,
+        window_secs: u32,
+        now: u32,
+        max_points: usize,
+    ) -> Vec<DataPoint, MAX_DATA_POINTS> {
+        let data = self.get_window_data(window_secs, now);
+        if data.len() <= max_points {
+            return data;
+        }
+        decimate_lttb(&data, max_points)
+    }
+
     /// Calculate statistics for the current time window (seconds)
     pub(super) fn calculate_stats(&self, window_secs: u32, now: u32) -> TrendStats {
-        let data = self.get_window_data(window_secs, now);
+        let mut stats = StatsAccumulator::new();
+        for (_, value) in self.get_window_data(window_secs, now) {
+            stats.push(value);
+        }
+        stats.finish()
+    }
+
+    /// Check if there's any data in the buffer
+    pub(super) fn is_empty(&self) -> bool {
+        self.points.len() == 0
+    }
+}
+
+/// Reduce `data` to `target` points with Largest-Triangle-Three-Buckets
+///
+/// Keeps the first and last points, splits the rest into `target - 2`
+/// buckets and picks from each the point forming the largest triangle with
+/// the previously kept point and the average of the next bucket. Peaks and
+/// troughs survive because they span the largest triangles. Returns the data
+/// unchanged when it already fits or `target` is below 3.
+pub(super) fn decimate_lttb(data: &[DataPoint], target: usize) -> Vec<DataPoint, MAX_DATA_POINTS> {
+    let len = data.len();
+    if target >= len || target < 3 {
+        return data.iter().copied().collect();
+    }
+
+    // Timestamps are made relative to the first point so they fit an f32
+    let origin = Timestamp::new(data[0].0);
+    let x = |point: &DataPoint| Timestamp::new(point.0).since(origin) as f32;
+    let y = |point: &DataPoint| point.1 as f32;
+
+    let mut sampled = Vec::new();
+    let _ = sampled.push(data[0]);
+
+    // More than one point per bucket since `target < len`, so no bucket is empty
+    let bucket_size = (len - 2) as f32 / (target - 2) as f32;
+    let mut previous = data[0];
+
+    for bucket in 0..target - 2 {
+        // Average of the following bucket (the last point for the final bucket)
+        let next_start = ((bucket + 1) as f32 * bucket_size) as usize + 1;
+        let next_end = (((bucket + 2) as f32 * bucket_size) as usize + 1).min(len);
+        let next = &data[next_start..next_end];
+        let avg_x = next.iter().map(x).sum::<f32>() / next.len() as f32;
+        let avg_y = next.iter().map(y).sum::<f32>() / next.len() as f32;
+
+        // Point in this bucket with the largest triangle area
+        let start = (bucket as f32 * bucket_size) as usize + 1;
+        let (prev_x, prev_y) = (x(&previous), y(&previous));
+        let mut chosen = data[start];
+        let mut max_area = -1.0f32;
+        for point in &data[start..next_start] {
+            let area = ((prev_x - avg_x) * (y(point) - prev_y)
+                - (prev_x - x(point)) * (avg_y - prev_y))
+                .abs();
+            if area > max_area {
+                max_area = area;
+                chosen = *point;
+            }
+        }
+
+        let _ = sampled.push(chosen);
+        previous = chosen;
+    }
 
-        if data.is_empty() {
-            return TrendStats::default();
+    let _ = sampled.push(data[len - 1]);
+    sampled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 360 points 10 s apart: a gentle wave with one spike and one dip
+    fn dense_series() -> Vec<DataPoint, MAX_DATA_POINTS> {
+        (0..MAX_DATA_POINTS as u32)
+            .map(|i| {
+                let value = match i {
+                    123 => 40_000,
+                    250 => -5_000,
+                    _ => 20_000 + ((i % 30) as i32 - 15) * 100,
+                };
+                (1_000 + i * 10, value)
+            })
+            .collect()
+    }
+
+    fn extreme(data: &[DataPoint], pick_max: bool) -> DataPoint {
+        let by_value = |a: &&DataPoint, b: &&DataPoint| a.1.cmp(&b.1);
+        let found = if pick_max {
+            data.iter().max_by(by_value)
+        } else {
+            data.iter().min_by(by_value)
+        };
+        *found.unwrap()
+    }
+
+    #[test]
+    fn lttb_reduces_to_the_target_count() {
+        let data = dense_series();
+        for target in [280, 100, 3] {
+            let sampled = decimate_lttb(&data, target);
+            assert_eq!(sampled.len(), target);
+            assert_eq!(sampled[0], data[0]);
+            assert_eq!(sampled[target - 1], data[data.len() - 1]);
+            assert!(sampled.windows(2).all(|w| w[0].0 < w[1].0));
         }
+    }
 
-        let mut sum = 0i64;
-        let mut min = i32::MAX;
-        let mut max = i32::MIN;
+    #[test]
+    fn lttb_preserves_the_global_min_and_max() {
+        let data = dense_series();
+        let max = extreme(&data, true);
+        let min = extreme(&data, false);
+        assert_eq!(max, (1_000 + 123 * 10, 40_000));
+        assert_eq!(min, (1_000 + 250 * 10, -5_000));
 
-        for (_, value) in data.iter() {
-            sum += *value as i64;
-            min = min.min(*value);
-            max = max.max(*value);
+        for target in [280, 60, 12] {
+            let sampled = decimate_lttb(&data, target);
+            assert!(sampled.contains(&max), "max lost at {target}");
+            assert!(sampled.contains(&min), "min lost at {target}");
         }
+    }
 
-        let count = data.len();
-        let avg = (sum / count as i64) as i32;
+    #[test]
+    fn lttb_leaves_small_inputs_alone() {
+        let data = dense_series();
+        assert_eq!(decimate_lttb(&data[..10], 20).as_slice(), &data[..10]);
+        assert_eq!(decimate_lttb(&data[..10], 2).as_slice(), &data[..10]);
+    }
 
-        TrendStats {
-            avg,
-            min,
-            max,
-            count,
+    #[test]
+    fn display_data_is_decimated_only_past_the_pixel_width() {
+        let mut buffer = TrendDataBuffer::new(SensorType::Temperature);
+        for point in dense_series() {
+            let _ = buffer.points.push_back(point);
         }
+        let now = 1_000 + 359 * 10;
+
+        assert_eq!(buffer.get_display_data(3_600, now, 280).len(), 280);
+        assert_eq!(buffer.get_display_data(3_600, now, 400).len(), 360);
+        // Statistics still see every point
+        assert_eq!(buffer.get_window_data(3_600, now).len(), 360);
     }
 
-    /// Check if there's any data in the buffer
-    pub(super) fn is_empty(&self) -> bool {
-        self.points.len() == 0
+    fn buffer_of(values: &[i32]) -> TrendDataBuffer {
+        let mut buffer = TrendDataBuffer::new(SensorType::Temperature);
+        for (i, &value) in values.iter().enumerate() {
+            let mut sample = RawSample::default();
+            sample.timestamp = 1_000 + i as u32 * 10;
+            sample.values[SensorType::Temperature.index()] = value;
+            buffer.push_from_raw_sample(&sample);
+        }
+        buffer
+    }
+
+    #[test]
+    fn window_average_rounds_to_nearest() {
+        let stats = buffer_of(&[1, 2]).calculate_stats(3_600, 2_000);
+        assert_eq!(stats.avg, 2);
+
+        let stats = buffer_of(&[-1, -2]).calculate_stats(3_600, 2_000);
+        assert_eq!(stats.avg, -2);
+    }
+
+    #[test]
+    fn window_average_of_extreme_values_does_not_wrap() {
+        let stats = buffer_of(&[i32::MAX; 50]).calculate_stats(3_600, 2_000);
+        assert_eq!(
+            (stats.avg, stats.min, stats.max),
+            (i32::MAX, i32::MAX, i32::MAX)
+        );
+
+        // Most negative value that isn't the missing-value sentinel
+        let stats = buffer_of(&[i32::MIN + 1; 50]).calculate_stats(3_600, 2_000);
+        assert_eq!(stats.avg, i32::MIN + 1);
+
+        let stats = buffer_of(&[i32::MAX, i32::MIN + 1]).calculate_stats(3_600, 2_000);
+        assert_eq!(stats.avg, 0);
+    }
+
+    #[test]
+    fn window_near_the_u32_limit_keeps_its_points() {
+        let mut buffer = TrendDataBuffer::new(SensorType::Temperature);
+        for ts in [u32::MAX - 600, u32::MAX - 300, u32::MAX] {
+            let mut sample = RawSample::default();
+            sample.timestamp = ts;
+            sample.values[SensorType::Temperature.index()] = 21_000;
+            buffer.push_from_raw_sample(&sample);
+        }
+
+        assert_eq!(buffer.get_window_data(400, u32::MAX).len(), 2);
+        assert_eq!(buffer.get_window_data(3_600, u32::MAX).len(), 3);
+    }
+
+    #[test]
+    fn window_longer_than_uptime_starts_at_zero() {
+        let buffer = buffer_of(&[1, 2, 3]);
+        assert_eq!(buffer.get_window_data(86_400, 1_020).len(), 3);
     }
 }
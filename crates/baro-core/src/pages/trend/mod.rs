@@ -6,6 +6,5 @@
 mod constants;
 mod data;
 mod page;
-mod stats;
 
 pub use page::TrendPage;
@@ -2,25 +2,30 @@
 
 use alloc::vec::Vec;
 use embedded_graphics::Drawable as EgDrawable;
-use embedded_graphics::mono_font::ascii::FONT_10X20;
-use embedded_graphics::mono_font::{MonoTextStyle, ascii::FONT_6X10};
+use embedded_graphics::mono_font::iso_8859_1::FONT_10X20;
+use embedded_graphics::mono_font::{MonoTextStyle, iso_8859_1::FONT_6X10};
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
 use embedded_graphics::text::{Alignment, Text};
 use heapless::Vec as HeaplessVec;
 
+use crate::config::TemperatureUnit;
 use crate::metrics::QualityLevel;
+use crate::metrics::fixed::to_float;
+use crate::metrics::stats::TrendStats;
 use crate::pages::Page;
 use crate::sensors::SensorType;
 use crate::storage::accumulator::RollupEvent;
-use crate::storage::{RawSample, Rollup, RollupTier, TimeWindow};
+use crate::storage::{HistoryData, RawSample, Rollup, RollupTier, TimeWindow, Timestamp};
 use crate::ui::components::graph::{
     CurrentValueDisplay, CurrentValuePosition, DataPoint, DataSeries, GradientFill, Graph,
-    GridConfig, HorizontalGridLines, LabelFormatter, LineStyle, SeriesStyle, XAxisConfig,
+    GridConfig, GridSpacing, HorizontalGridLines, LabelFormatter, LineStyle, SeriesStyle,
+    XAxisConfig,
 };
-use crate::ui::core::{Action, DirtyRegion, PageEvent, PageId, TouchEvent};
-use crate::ui::{Container, Direction, Drawable, Padding, Style, WHITE};
+use crate::ui::core::{Action, DirtyRegion, PageEvent, PageId, StorageEvent, TouchEvent};
+use crate::ui::styling::{ColorPalette, DARK_GRAY, LIGHT_GRAY};
+use crate::ui::{Container, Direction, Drawable, Padding, Style, Theme, WHITE};
 
 use core::fmt::Write;
 
@@ -30,16 +35,16 @@ use alloc::{boxed::Box, string::String};
 use crate::ui::{FONT_6X10_CHAR_HEIGHT_PX, FONT_6X10_CHAR_WIDTH_PX};
 
 use super::constants::{
-    BACK_TOUCH_WIDTH_PX, COLOR_FOREGROUND, CURRENT_VALUE_OFFSET_X_PX, CURRENT_VALUE_OFFSET_Y_PX,
-    FAINT_GRAY, GRADIENT_FILL_HEIGHT_PX, GRADIENT_FILL_OPACITY, HEADER_HEIGHT_PX,
-    HEADER_TITLE_PADDING_LEFT_PX, LIGHT_GRAY, MAX_DATA_POINTS, QUALITY_INDICATOR_BORDER_WIDTH_PX,
-    QUALITY_INDICATOR_CORNER_RADIUS_PX, QUALITY_INDICATOR_HEIGHT_PX,
-    QUALITY_INDICATOR_MARGIN_RIGHT_PX, QUALITY_INDICATOR_PADDING_HORIZONTAL_PX,
-    QUALITY_INDICATOR_PADDING_VERTICAL_PX, QUALITY_INDICATOR_TEXT_PADDING_PX, SERIES_LINE_WIDTH_PX,
-    STATS_HEIGHT_PX, WINDOW_GROWTH_CHUNK_SECS,
+    BACK_TOUCH_WIDTH_PX, CURRENT_VALUE_OFFSET_X_PX, CURRENT_VALUE_OFFSET_Y_PX,
+    DEFAULT_CURRENT_VALUE_ALPHA, GRADIENT_FILL_HEIGHT_PX, GRADIENT_FILL_OPACITY, HEADER_HEIGHT_PX,
+    HEADER_TITLE_PADDING_LEFT_PX, MAX_DATA_POINTS, MAX_ROLLUP_MARKS,
+    QUALITY_INDICATOR_BORDER_WIDTH_PX, QUALITY_INDICATOR_CORNER_RADIUS_PX,
+    QUALITY_INDICATOR_HEIGHT_PX, QUALITY_INDICATOR_MARGIN_RIGHT_PX,
+    QUALITY_INDICATOR_PADDING_HORIZONTAL_PX, QUALITY_INDICATOR_PADDING_VERTICAL_PX,
+    QUALITY_INDICATOR_TEXT_PADDING_PX, SERIES_LINE_WIDTH_PX, STATS_HEIGHT_PX,
+    WINDOW_GROWTH_CHUNK_SECS,
 };
 use super::data::TrendDataBuffer;
-use super::stats::TrendStats;
 
 /// Trend page displaying time-series graph and statistics
 pub struct TrendPage {
@@ -47,7 +52,11 @@ pub struct TrendPage {
     sensor: SensorType,
     window: TimeWindow,
     data_buffer: TrendDataBuffer,
-    dirty: bool,
+
+    // Per-section dirty flags, reported separately by `dirty_regions`
+    header_dirty: bool,
+    graph_dirty: bool,
+    stats_dirty: bool,
 
     // Layout sections
     header_bounds: Rectangle,
@@ -62,13 +71,31 @@ pub struct TrendPage {
     current_quality: QualityLevel,
     current_timestamp: u32,
 
-    // Flag to track if initial data has been requested
+    // Flag to track if initial data has been loaded
     initial_data_loaded: bool,
+
+    /// History request raised on activation, until taken by the owner
+    pending_request: Option<Action>,
+
+    /// Unit temperatures are displayed in (data stays in Celsius)
+    temperature_unit: TemperatureUnit,
+
+    /// EMA of the readings, in sensor units, shown as the headline value
+    smoothed_current: Option<f32>,
+    /// Weight of each new reading in `smoothed_current` (0, 1]
+    current_value_alpha: f32,
+
+    /// Whether rollup boundaries are ticked on the X axis
+    show_rollup_marks: bool,
+    /// Timestamps of recent rollup boundaries, oldest first
+    rollup_marks: HeaplessVec<u32, MAX_ROLLUP_MARKS>,
+
+    palette: ColorPalette,
 }
 
 impl TrendPage {
     /// Create a new trend page for a specific sensor and time window
-    pub fn new(bounds: Rectangle, sensor: SensorType, window: TimeWindow) -> Self {
+    pub fn new(bounds: Rectangle, sensor: SensorType, window: TimeWindow, theme: &Theme) -> Self {
         let graph_height = bounds
             .size
             .height
@@ -101,8 +128,8 @@ impl TrendPage {
             .with_grid(GridConfig {
                 vertical_lines: None,
                 horizontal_lines: Some(HorizontalGridLines {
-                    count: 3,
-                    color: FAINT_GRAY,
+                    spacing: GridSpacing::Count(3),
+                    color: DARK_GRAY,
                     width: 1,
                     style: LineStyle::Solid,
                 }),
@@ -114,14 +141,16 @@ impl TrendPage {
                 show_axis_line: false,
             });
 
-        let _ = graph.add_series(DataSeries::new());
+        let _ = graph.add_series(DataSeries::new().with_latest_marker(true));
 
         Self {
             bounds,
             sensor,
             window,
             data_buffer: TrendDataBuffer::new(sensor),
-            dirty: true,
+            header_dirty: true,
+            graph_dirty: true,
+            stats_dirty: true,
             header_bounds,
             graph_bounds,
             stats_bounds,
@@ -130,13 +159,74 @@ impl TrendPage {
             current_quality: QualityLevel::Good,
             current_timestamp: 0,
             initial_data_loaded: false,
+            pending_request: None,
+            temperature_unit: TemperatureUnit::default(),
+            smoothed_current: None,
+            current_value_alpha: DEFAULT_CURRENT_VALUE_ALPHA,
+            show_rollup_marks: false,
+            rollup_marks: HeaplessVec::new(),
+            palette: theme.palette,
         }
     }
 
+    /// Set the unit temperatures are displayed in.
+    pub fn with_temperature_unit(mut self, unit: TemperatureUnit) -> Self {
+        self.temperature_unit = unit;
+        self
+    }
+
+    /// Pin the chart's Y axis to `min..max` (in display units) instead of
+    /// auto-scaling, e.g. 0-100 for humidity.
+    ///
+    /// Readings outside the range are clipped at the chart edge. An empty
+    /// or inverted range is ignored.
+    pub fn with_y_range(mut self, min: f32, max: f32) -> Self {
+        let _ = self.graph.set_y_bounds(min, max);
+        self
+    }
+
+    /// Smooth the headline current value with an EMA of weight `alpha`
+    ///
+    /// Only the displayed number is filtered; the graph and statistics keep
+    /// the raw readings. `alpha` is clamped to `0.01..=1.0`, where 1.0
+    /// disables smoothing.
+    pub fn with_current_value_smoothing(mut self, alpha: f32) -> Self {
+        self.current_value_alpha = if alpha.is_finite() {
+            alpha.clamp(0.01, 1.0)
+        } else {
+            DEFAULT_CURRENT_VALUE_ALPHA
+        };
+        self
+    }
+
+    /// Headline current value (smoothed, in sensor units), if any
+    pub fn smoothed_current(&self) -> Option<f32> {
+        self.smoothed_current
+    }
+
+    /// Fold a new reading (milli-units) into the smoothed current value
+    fn smooth_current(&mut self, value: i32) {
+        let value = to_float(value);
+        self.smoothed_current = Some(match self.smoothed_current {
+            Some(previous) => previous + self.current_value_alpha * (value - previous),
+            None => value,
+        });
+    }
+
+    /// Restart smoothing from the newest buffered reading
+    fn reset_smoothed_current(&mut self) {
+        self.smoothed_current = self
+            .data_buffer
+            .points
+            .back()
+            .map(|(_, value)| to_float(*value));
+    }
+
     /// Load historical data into the trend page buffer
     /// This should be called once when the page is created or activated
     pub fn load_historical_data(&mut self, rollups: &[Rollup], current_time: u32) {
         self.data_buffer.load_rollups(rollups);
+        self.reset_smoothed_current();
         self.current_timestamp = current_time;
         self.update_stats();
         self.initial_data_loaded = true;
@@ -147,14 +237,83 @@ impl TrendPage {
     /// This should be called for short time windows (1m, 5m)
     pub fn load_historical_raw_samples(&mut self, samples: &[RawSample], current_time: u32) {
         self.data_buffer.load_raw_samples(samples);
+        self.reset_smoothed_current();
         self.current_timestamp = current_time;
         self.update_stats();
         self.initial_data_loaded = true;
         self.mark_dirty();
     }
 
+    /// Load history for this page's window from the given tier
+    ///
+    /// "Now" is taken from the newest entry: a raw sample's timestamp, or the
+    /// end of the newest rollup's bucket. Empty data still marks the initial
+    /// load as done so the page does not ask again.
+    pub fn load_history(&mut self, tier: RollupTier, data: &HistoryData) {
+        match data {
+            HistoryData::RawSamples(samples) => {
+                let current_time = samples.last().map(|s| s.timestamp).unwrap_or(0);
+                self.load_historical_raw_samples(samples, current_time);
+            }
+            HistoryData::Rollups(rollups) => {
+                let current_time = rollups
+                    .last()
+                    .map(|r| {
+                        Timestamp::new(r.start_ts)
+                            .advance(tier.bucket_secs())
+                            .secs()
+                    })
+                    .unwrap_or(0);
+                self.load_historical_data(rollups, current_time);
+            }
+        }
+    }
+
+    /// Tick the X axis wherever a rollup was written
+    pub fn with_rollup_marks(mut self, show: bool) -> Self {
+        self.show_rollup_marks = show;
+        self
+    }
+
+    /// Timestamps of the rollup boundaries seen so far, oldest first
+    pub fn rollup_marks(&self) -> &[u32] {
+        &self.rollup_marks
+    }
+
+    /// Remember a rollup boundary, dropping the oldest when full
+    ///
+    /// Returns false for a boundary at or before the latest one recorded.
+    fn record_rollup_mark(&mut self, timestamp: u32) -> bool {
+        if self
+            .rollup_marks
+            .last()
+            .is_some_and(|&last| timestamp <= last)
+        {
+            return false;
+        }
+        if self.rollup_marks.is_full() {
+            self.rollup_marks.remove(0);
+        }
+        let _ = self.rollup_marks.push(timestamp);
+        true
+    }
+
+    /// Sensor shown by this page
+    pub fn sensor(&self) -> SensorType {
+        self.sensor
+    }
+
+    /// Time window shown by this page
+    pub fn window(&self) -> TimeWindow {
+        self.window
+    }
+
     /// Update cached statistics and quality level
+    ///
+    /// Marks the stats bar dirty, plus the header and graph when the quality
+    /// level (shown in the header pill and as the graph background) changes.
     fn update_stats(&mut self) {
+        let previous_quality = self.current_quality;
         let effective_window_secs = self.effective_window_secs();
         self.stats = self
             .data_buffer
@@ -164,6 +323,12 @@ impl TrendPage {
         if self.stats.count > 0 {
             self.current_quality = QualityLevel::assess(self.sensor, self.stats.avg_f32());
         }
+
+        self.stats_dirty = true;
+        if self.current_quality != previous_quality {
+            self.header_dirty = true;
+            self.graph_dirty = true;
+        }
     }
 
     fn effective_window_secs(&self) -> u32 {
@@ -174,7 +339,7 @@ impl TrendPage {
             return window_secs;
         };
 
-        let span_secs = self.current_timestamp.saturating_sub(oldest_ts);
+        let span_secs = Timestamp::new(self.current_timestamp).since(Timestamp::new(oldest_ts));
         if span_secs == 0 {
             return chunk_secs.min(window_secs);
         }
@@ -196,12 +361,12 @@ impl TrendPage {
     where
         D: DrawTarget<Color = Rgb565>,
     {
-        // Clear header area with foreground color
+        // Clear header area with the surface color
         self.header_bounds
-            .into_styled(PrimitiveStyle::with_fill(COLOR_FOREGROUND))
+            .into_styled(PrimitiveStyle::with_fill(self.palette.surface))
             .draw(display)?;
 
-        let text_style = MonoTextStyle::new(&FONT_6X10, WHITE);
+        let text_style = MonoTextStyle::new(&FONT_6X10, self.palette.text_primary);
 
         // Center text vertically in header
         let title_y = self.header_bounds.top_left.y
@@ -306,9 +471,15 @@ impl TrendPage {
                 ))
                 .draw(display)?;
 
+            // A sensor without a compiled-in driver will never report
+            let message = if self.sensor.is_configured() {
+                "No data available"
+            } else {
+                "Sensor not configured"
+            };
             let text_style = MonoTextStyle::new(&FONT_6X10, LIGHT_GRAY);
             Text::with_alignment(
-                "No data available",
+                message,
                 self.graph_bounds.center(),
                 text_style,
                 Alignment::Center,
@@ -319,9 +490,11 @@ impl TrendPage {
 
         // Get data for current window
         let effective_window_secs = self.effective_window_secs();
-        let data = self
-            .data_buffer
-            .get_window_data(effective_window_secs, self.current_timestamp);
+        let data = self.data_buffer.get_display_data(
+            effective_window_secs,
+            self.current_timestamp,
+            self.graph_bounds.size.width as usize,
+        );
 
         if data.is_empty() {
             // Draw empty graph background
@@ -346,10 +519,14 @@ impl TrendPage {
             .set_background(self.current_quality.background_color());
 
         if self.graph.series_count() == 0 {
-            let _ = self.graph.add_series(DataSeries::new());
+            let _ = self
+                .graph
+                .add_series(DataSeries::new().with_latest_marker(true));
         }
 
-        let window_start = self.current_timestamp.saturating_sub(effective_window_secs);
+        let window_start = Timestamp::new(self.current_timestamp)
+            .window_start(effective_window_secs)
+            .secs();
 
         let series_style = SeriesStyle {
             color: self.current_quality.foreground_color(),
@@ -369,8 +546,10 @@ impl TrendPage {
 
         let mut series_points = Vec::with_capacity(data.len());
         for (ts, value) in data.iter() {
-            let relative_ts = ts.saturating_sub(window_start) as f32;
-            let value_f32 = TrendStats::to_float(*value);
+            let relative_ts = Timestamp::new(*ts).since(Timestamp::new(window_start)) as f32;
+            let value_f32 = self
+                .sensor
+                .display_value(to_float(*value), self.temperature_unit);
             let point = DataPoint::new(relative_ts, value_f32);
             series_points.push(point);
         }
@@ -378,11 +557,27 @@ impl TrendPage {
         let _ = self.graph.set_series_points(0, &series_points);
         let _ = self.graph.set_x_bounds(0.0, effective_window_secs as f32);
 
-        // Set current value display if we have data
-        if let Some((_, current_value)) = self.data_buffer.points.back() {
-            let value_f32 = TrendStats::to_float(*current_value);
+        if self.show_rollup_marks {
+            let marks: Vec<f32> = self
+                .rollup_marks
+                .iter()
+                .filter(|&&ts| ts >= window_start)
+                .map(|&ts| (ts - window_start) as f32)
+                .collect();
+            self.graph.set_x_markers(&marks, LIGHT_GRAY);
+        }
+
+        // Set current value display if we have data (smoothed; the series keeps raw detail)
+        if let Some(current_value) = self.smoothed_current {
+            let value_f32 = self
+                .sensor
+                .display_value(current_value, self.temperature_unit);
             let mut label = String::new();
-            let _ = write!(&mut label, "{}", self.sensor.unit());
+            let _ = write!(
+                &mut label,
+                "{}",
+                self.sensor.display_unit(self.temperature_unit)
+            );
 
             self.graph.set_current_value(CurrentValueDisplay {
                 value: value_f32,
@@ -407,64 +602,58 @@ impl TrendPage {
     where
         D: DrawTarget<Color = Rgb565>,
     {
-        // Clear stats area with foreground color
+        // Clear stats area with the surface color
         self.stats_bounds
-            .into_styled(PrimitiveStyle::with_fill(COLOR_FOREGROUND))
+            .into_styled(PrimitiveStyle::with_fill(self.palette.surface))
             .draw(display)?;
 
         if self.stats.count == 0 {
             return Ok(());
         }
 
-        let text_style = MonoTextStyle::new(&FONT_6X10, WHITE);
-        let section_width = self.stats_bounds.size.width / 3;
-        let stats_text_y = self.stats_bounds.top_left.y + STATS_HEIGHT_PX as i32 / 2;
+        let text_style = MonoTextStyle::new(&FONT_6X10, self.palette.text_primary);
+        let left = self.stats_bounds.top_left.x;
+        let width = self.stats_bounds.size.width as i32;
+        let top = self.stats_bounds.top_left.y;
 
-        // Format stats with sensor unit
-        let unit = self.sensor.unit();
+        // Two rows: Avg / Min / Max in thirds, then Now / spread in halves
+        let first_row_y = top + STATS_HEIGHT_PX as i32 / 3 + FONT_6X10_CHAR_HEIGHT_PX as i32 / 2;
+        let second_row_y =
+            top + 2 * STATS_HEIGHT_PX as i32 / 3 + FONT_6X10_CHAR_HEIGHT_PX as i32 / 2;
+
+        // Format stats in the display unit
+        let unit = self.temperature_unit;
         let mut avg_str = String::new();
         let mut min_str = String::new();
         let mut max_str = String::new();
+        let mut latest_str = String::new();
+        let mut std_dev_str = String::new();
+
+        let _ = write!(avg_str, "Avg: {}", self.sensor.format(self.stats.avg, unit));
+        let _ = write!(min_str, "Min: {}", self.sensor.format(self.stats.min, unit));
+        let _ = write!(max_str, "Max: {}", self.sensor.format(self.stats.max, unit));
+        let _ = write!(
+            latest_str,
+            "Now: {}",
+            self.sensor.format(self.stats.latest, unit)
+        );
+        let _ = write!(
+            std_dev_str,
+            "SD: ±{}",
+            self.sensor.format_delta(self.stats.std_dev, unit)
+        );
 
-        let _ = write!(avg_str, "Avg: {:.1}{}", self.stats.avg_f32(), unit);
-        let _ = write!(min_str, "Min: {:.1}{}", self.stats.min_f32(), unit);
-        let _ = write!(max_str, "Max: {:.1}{}", self.stats.max_f32(), unit);
-
-        // Draw AVG
-        Text::with_alignment(
-            &avg_str,
-            Point::new(
-                self.stats_bounds.top_left.x + section_width as i32 / 2,
-                stats_text_y,
-            ),
-            text_style,
-            Alignment::Center,
-        )
-        .draw(display)?;
-
-        // Draw MIN
-        Text::with_alignment(
-            &min_str,
-            Point::new(
-                self.stats_bounds.top_left.x + section_width as i32 + section_width as i32 / 2,
-                stats_text_y,
-            ),
-            text_style,
-            Alignment::Center,
-        )
-        .draw(display)?;
-
-        // Draw MAX
-        Text::with_alignment(
-            &max_str,
-            Point::new(
-                self.stats_bounds.top_left.x + 2 * section_width as i32 + section_width as i32 / 2,
-                stats_text_y,
-            ),
-            text_style,
-            Alignment::Center,
-        )
-        .draw(display)?;
+        let cells = [
+            (&avg_str, left + width / 6, first_row_y),
+            (&min_str, left + width / 2, first_row_y),
+            (&max_str, left + 5 * width / 6, first_row_y),
+            (&latest_str, left + width / 4, second_row_y),
+            (&std_dev_str, left + 3 * width / 4, second_row_y),
+        ];
+        for (text, x, y) in cells {
+            Text::with_alignment(text, Point::new(x, y), text_style, Alignment::Center)
+                .draw(display)?;
+        }
 
         Ok(())
     }
@@ -482,12 +671,17 @@ impl Page for TrendPage {
     fn on_activate(&mut self) {
         self.mark_dirty();
 
-        // TODO: Request initial data load from storage manager
-        // This would require a new PageEvent type or DisplayRequest to fetch
-        // historical data from the storage manager based on this page's
-        // sensor type and time window preferences.
-        // For now, this is handled by the display manager sending the data
-        // via DisplayRequest::LoadHistoricalData when the page is created.
+        // Ask the owner for stored history the first time the page is shown
+        if !self.initial_data_loaded {
+            self.pending_request = Some(Action::RequestHistory {
+                sensor: self.sensor,
+                window: self.window,
+            });
+        }
+    }
+
+    fn take_action(&mut self) -> Option<Action> {
+        self.pending_request.take()
     }
 
     fn on_event(&mut self, event: &PageEvent) -> bool {
@@ -512,26 +706,27 @@ impl Page for TrendPage {
                 // This ensures get_window_data() uses the correct time reference
                 let new_timestamp = match rollup_event.as_ref() {
                     RollupEvent::RawSample(sample) => {
-                        self.data_buffer.push_from_raw_sample(sample);
+                        if let Some(value) = self.data_buffer.push_from_raw_sample(sample) {
+                            self.smooth_current(value);
+                        }
                         sample.timestamp
                     }
                     RollupEvent::Rollup5m(rollup)
                     | RollupEvent::Rollup1h(rollup)
                     | RollupEvent::RollupDaily(rollup) => {
-                        self.data_buffer.push_from_rollup(rollup);
-                        // Use rollup end time for better accuracy so "now" advances as expected.
-                        match rollup_event.as_ref() {
-                            RollupEvent::Rollup5m(_) => {
-                                rollup.start_ts + TimeWindow::FiveMinutes.duration_secs()
-                            }
-                            RollupEvent::Rollup1h(_) => {
-                                rollup.start_ts + TimeWindow::OneHour.duration_secs()
-                            }
-                            RollupEvent::RollupDaily(_) => {
-                                rollup.start_ts + TimeWindow::OneDay.duration_secs()
-                            }
-                            RollupEvent::RawSample(_) => rollup.start_ts,
+                        if let Some(value) = self.data_buffer.push_from_rollup(rollup) {
+                            self.smooth_current(value);
                         }
+                        // Use rollup end time for better accuracy so "now" advances as expected.
+                        let duration_secs = match rollup_event.as_ref() {
+                            RollupEvent::Rollup5m(_) => TimeWindow::FiveMinutes.duration_secs(),
+                            RollupEvent::Rollup1h(_) => TimeWindow::OneHour.duration_secs(),
+                            RollupEvent::RollupDaily(_) => TimeWindow::OneDay.duration_secs(),
+                            RollupEvent::RawSample(_) => 0,
+                        };
+                        Timestamp::new(rollup.start_ts)
+                            .advance(duration_secs)
+                            .secs()
                     }
                 };
 
@@ -540,9 +735,17 @@ impl Page for TrendPage {
                     self.current_timestamp = new_timestamp;
                 }
 
-                // Recalculate statistics with updated timestamp
+                // New data moves the graph and stats; the header only
+                // changes with the quality level
+                self.graph_dirty = true;
                 self.update_stats();
-                self.mark_dirty();
+                true
+            }
+            PageEvent::StorageEvent(StorageEvent::Rollup { timestamp, .. }) => {
+                if !self.show_rollup_marks || !self.record_rollup_mark(*timestamp as u32) {
+                    return false;
+                }
+                self.graph_dirty = true;
                 true
             }
             _ => false,
@@ -586,24 +789,379 @@ impl Page for TrendPage {
     }
 
     fn is_dirty(&self) -> bool {
-        self.dirty
+        self.header_dirty || self.graph_dirty || self.stats_dirty
     }
 
     fn mark_clean(&mut self) {
-        self.dirty = false;
+        self.header_dirty = false;
+        self.graph_dirty = false;
+        self.stats_dirty = false;
     }
 
     fn mark_dirty(&mut self) {
-        self.dirty = true;
+        self.header_dirty = true;
+        self.graph_dirty = true;
+        self.stats_dirty = true;
     }
 
+    /// Only the sections that changed, or the whole page when all of them did
     fn dirty_regions(&self) -> HeaplessVec<DirtyRegion, 8> {
-        if self.is_dirty() {
-            let mut regions = HeaplessVec::new();
+        let mut regions = HeaplessVec::new();
+        if self.header_dirty && self.graph_dirty && self.stats_dirty {
             let _ = regions.push(DirtyRegion::new(self.bounds));
-            regions
-        } else {
-            HeaplessVec::new()
+            return regions;
+        }
+
+        let sections = [
+            (self.header_dirty, self.header_bounds),
+            (self.graph_dirty, self.graph_bounds),
+            (self.stats_dirty, self.stats_bounds),
+        ];
+        for (dirty, bounds) in sections {
+            if dirty {
+                let _ = regions.push(DirtyRegion::new(bounds));
+            }
+        }
+        regions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::test_harness::{
+        assert_contains, assert_filled, assert_not_black, count_color, render, screen_bounds,
+    };
+
+    #[test]
+    fn no_data_state_renders_header_empty_graph_and_stats() {
+        let theme = Theme::dark();
+        let mut page = TrendPage::new(
+            screen_bounds(),
+            SensorType::Temperature,
+            TimeWindow::OneHour,
+            &theme,
+        );
+        let fb = render(&mut page);
+
+        // Header: surface fill above the title text and quality pill
+        let header_strip = Rectangle::new(
+            page.header_bounds.top_left,
+            Size::new(page.header_bounds.size.width, 2),
+        );
+        assert_filled(&fb, header_strip, theme.palette.surface);
+
+        // Graph: quality background with the centered placeholder message
+        assert_not_black(&fb, page.graph_bounds);
+        let background = QualityLevel::Good.background_color();
+        let graph_area = page.graph_bounds.size.width * page.graph_bounds.size.height;
+        let message_px = count_color(&fb, page.graph_bounds, LIGHT_GRAY);
+        assert!(message_px > 0);
+        assert_eq!(
+            count_color(&fb, page.graph_bounds, background) + message_px,
+            graph_area as usize
+        );
+        let message_band = Rectangle::with_center(
+            page.graph_bounds.center(),
+            Size::new(page.graph_bounds.size.width, FONT_6X10_CHAR_HEIGHT_PX * 2),
+        );
+        assert_contains(&fb, message_band, LIGHT_GRAY);
+
+        // Stats: no samples, so the section is a plain surface fill
+        assert_filled(&fb, page.stats_bounds, theme.palette.surface);
+    }
+
+    #[test]
+    fn renders_against_the_shared_styling_colors() {
+        use crate::sensors::TEMPERATURE;
+        use crate::ui::styling::{
+            COLOR_BACKGROUND, COLOR_BAD_BACKGROUND, COLOR_EXCELLENT_BACKGROUND,
+            COLOR_GOOD_BACKGROUND, COLOR_POOR_BACKGROUND,
+        };
+
+        for theme in [Theme::dark(), Theme::light()] {
+            let mut page = TrendPage::new(
+                screen_bounds(),
+                SensorType::Temperature,
+                TimeWindow::FiveMinutes,
+                &theme,
+            );
+            let samples: Vec<RawSample> = (0..30)
+                .map(|i| {
+                    let mut sample = RawSample::default();
+                    sample.timestamp = 1_000 + i * 10;
+                    sample.values[TEMPERATURE] = 21_000 + (i as i32 % 5) * 200;
+                    sample
+                })
+                .collect();
+            page.load_historical_raw_samples(&samples, 1_290);
+            let fb = render(&mut page);
+
+            // Header and stats chrome use the theme's surface
+            let header_strip = Rectangle::new(
+                page.header_bounds.top_left,
+                Size::new(page.header_bounds.size.width, 2),
+            );
+            assert_filled(&fb, header_strip, theme.palette.surface);
+
+            // The graph sits on the shared quality background with shared grid lines
+            let background = page.current_quality.background_color();
+            assert!(
+                [
+                    COLOR_EXCELLENT_BACKGROUND,
+                    COLOR_GOOD_BACKGROUND,
+                    COLOR_POOR_BACKGROUND,
+                    COLOR_BAD_BACKGROUND,
+                ]
+                .contains(&background)
+            );
+            assert_contains(&fb, page.graph_bounds, background);
+            assert_contains(&fb, page.graph_bounds, DARK_GRAY);
+        }
+
+        assert_eq!(Theme::dark().palette.background, COLOR_BACKGROUND);
+    }
+
+    #[test]
+    fn activation_requests_history_for_the_page_sensor_and_window() {
+        let theme = Theme::dark();
+        let mut page = TrendPage::new(
+            screen_bounds(),
+            SensorType::Humidity,
+            TimeWindow::ThirtyMinutes,
+            &theme,
+        );
+        assert_eq!(page.take_action(), None);
+
+        page.on_activate();
+        assert_eq!(
+            page.take_action(),
+            Some(Action::RequestHistory {
+                sensor: SensorType::Humidity,
+                window: TimeWindow::ThirtyMinutes,
+            })
+        );
+        // The request is handed out once
+        assert_eq!(page.take_action(), None);
+    }
+
+    #[test]
+    fn loaded_history_stops_further_requests() {
+        let theme = Theme::dark();
+        let mut page = TrendPage::new(
+            screen_bounds(),
+            SensorType::Co2,
+            TimeWindow::OneHour,
+            &theme,
+        );
+
+        let mut rollup = Rollup::default();
+        rollup.start_ts = 10_000;
+        page.load_history(
+            RollupTier::FiveMinute,
+            &HistoryData::Rollups(alloc::vec![rollup]),
+        );
+        // "Now" is the end of the newest bucket
+        assert_eq!(page.current_timestamp, 10_000 + 300);
+
+        page.on_activate();
+        assert_eq!(page.take_action(), None);
+
+        // Empty history also counts as loaded
+        let mut empty = TrendPage::new(
+            screen_bounds(),
+            SensorType::Co2,
+            TimeWindow::FiveMinutes,
+            &theme,
+        );
+        empty.load_history(RollupTier::RawSample, &HistoryData::RawSamples(Vec::new()));
+        empty.on_activate();
+        assert_eq!(empty.take_action(), None);
+    }
+
+    fn rollup_written(timestamp: u64) -> PageEvent {
+        PageEvent::StorageEvent(StorageEvent::Rollup {
+            interval: "5m",
+            count: 1,
+            timestamp,
+        })
+    }
+
+    fn clean_trend_page(show_rollup_marks: bool) -> TrendPage {
+        let mut page = TrendPage::new(
+            screen_bounds(),
+            SensorType::Temperature,
+            TimeWindow::OneHour,
+            &Theme::dark(),
+        )
+        .with_rollup_marks(show_rollup_marks);
+        page.mark_clean();
+        page
+    }
+
+    #[test]
+    fn storage_rollup_event_records_the_boundary_and_marks_dirty() {
+        let mut page = clean_trend_page(true);
+        assert!(!page.is_dirty());
+
+        assert!(page.on_event(&rollup_written(1_700_000_300)));
+
+        assert!(page.is_dirty());
+        assert_eq!(page.rollup_marks(), [1_700_000_300]);
+    }
+
+    #[test]
+    fn stale_or_repeated_boundaries_are_ignored() {
+        let mut page = clean_trend_page(true);
+        assert!(page.on_event(&rollup_written(1_700_000_300)));
+        page.mark_clean();
+
+        assert!(!page.on_event(&rollup_written(1_700_000_300)));
+        assert!(!page.on_event(&rollup_written(1_700_000_000)));
+        assert!(!page.is_dirty());
+        assert_eq!(page.rollup_marks(), [1_700_000_300]);
+    }
+
+    #[test]
+    fn rollup_marks_are_off_unless_enabled() {
+        let mut page = clean_trend_page(false);
+
+        assert!(!page.on_event(&rollup_written(1_700_000_300)));
+        assert!(!page.is_dirty());
+        assert!(page.rollup_marks().is_empty());
+    }
+
+    #[test]
+    fn oldest_rollup_mark_is_dropped_when_full() {
+        let mut page = clean_trend_page(true);
+        for i in 0..=MAX_ROLLUP_MARKS as u64 {
+            page.on_event(&rollup_written(1_700_000_000 + i * 300));
+        }
+
+        assert_eq!(page.rollup_marks().len(), MAX_ROLLUP_MARKS);
+        assert_eq!(page.rollup_marks()[0], 1_700_000_300);
+    }
+
+    fn temperature_reading(timestamp: u32, milli_celsius: i32) -> PageEvent {
+        use crate::sensors::TEMPERATURE;
+
+        let mut sample = RawSample::default();
+        sample.timestamp = timestamp;
+        sample.values[TEMPERATURE] = milli_celsius;
+        PageEvent::RollupEvent(Box::new(RollupEvent::RawSample(sample)))
+    }
+
+    fn smoothed_page(alpha: f32) -> TrendPage {
+        TrendPage::new(
+            screen_bounds(),
+            SensorType::Temperature,
+            TimeWindow::FiveMinutes,
+            &Theme::dark(),
+        )
+        .with_current_value_smoothing(alpha)
+    }
+
+    #[test]
+    fn smoothed_current_converges_on_a_step_at_the_alpha_rate() {
+        let alpha = 0.3;
+        let mut page = smoothed_page(alpha);
+
+        // The first reading seeds the average
+        page.on_event(&temperature_reading(1_000, 20_000));
+        assert_eq!(page.smoothed_current(), Some(20.0));
+
+        // After a 10 degree step the remaining gap shrinks by (1 - alpha) per reading
+        let mut gap = 10.0;
+        for n in 1..=10u32 {
+            page.on_event(&temperature_reading(1_000 + n * 10, 30_000));
+            gap *= 1.0 - alpha;
+            let expected = 30.0 - gap;
+            let actual = page.smoothed_current().unwrap();
+            assert!(
+                (actual - expected).abs() < 1e-3,
+                "step {n}: {actual} != {expected}"
+            );
         }
+        assert!(page.smoothed_current().unwrap() < 30.0);
+    }
+
+    #[test]
+    fn alpha_of_one_follows_the_input_exactly() {
+        let mut page = smoothed_page(1.0);
+        for (i, value) in [20_000, 30_000, 15_500].into_iter().enumerate() {
+            page.on_event(&temperature_reading(1_000 + i as u32 * 10, value));
+            assert_eq!(page.smoothed_current(), Some(value as f32 / 1000.0));
+        }
+    }
+
+    #[test]
+    fn missing_readings_leave_the_smoothed_value_alone() {
+        let mut page = smoothed_page(0.5);
+        page.on_event(&temperature_reading(1_000, 20_000));
+        page.on_event(&temperature_reading(
+            1_010,
+            crate::storage::MISSING_SENSOR_VALUE,
+        ));
+        assert_eq!(page.smoothed_current(), Some(20.0));
+    }
+
+    /// Bounds of each region `page` reports dirty
+    fn dirty_bounds(page: &TrendPage) -> Vec<Rectangle> {
+        page.dirty_regions()
+            .iter()
+            .map(|region| region.bounds)
+            .collect()
+    }
+
+    /// Temperature page holding a few readings, with nothing left to draw
+    fn drawn_page_with_readings() -> TrendPage {
+        let mut page = smoothed_page(1.0).with_rollup_marks(true);
+        for i in 0..5 {
+            page.on_event(&temperature_reading(1_000 + i * 10, 21_000));
+        }
+        Page::mark_clean(&mut page);
+        page
+    }
+
+    #[test]
+    fn stats_only_change_dirties_just_the_stats_rectangle() {
+        let mut page = drawn_page_with_readings();
+        assert!(dirty_bounds(&page).is_empty());
+
+        page.update_stats();
+
+        assert_eq!(dirty_bounds(&page), [page.stats_bounds]);
+    }
+
+    #[test]
+    fn new_reading_dirties_graph_and_stats_but_not_the_header() {
+        let mut page = drawn_page_with_readings();
+
+        assert!(page.on_event(&temperature_reading(1_050, 21_200)));
+
+        assert_eq!(dirty_bounds(&page), [page.graph_bounds, page.stats_bounds]);
+    }
+
+    #[test]
+    fn quality_change_redraws_the_whole_page() {
+        let mut page = drawn_page_with_readings();
+        let quality = page.current_quality;
+
+        // A heatwave pulls the average into another quality band
+        for i in 0..20 {
+            page.on_event(&temperature_reading(1_050 + i * 10, 40_000));
+        }
+
+        assert_ne!(page.current_quality, quality);
+        assert_eq!(dirty_bounds(&page), [page.bounds]);
+    }
+
+    #[test]
+    fn rollup_mark_dirties_just_the_graph() {
+        let mut page = drawn_page_with_readings();
+
+        assert!(page.on_event(&rollup_written(1_700_000_300)));
+
+        assert_eq!(dirty_bounds(&page), [page.graph_bounds]);
     }
 }
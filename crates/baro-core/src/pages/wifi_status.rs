@@ -5,18 +5,19 @@
 //! a non-functional "Connect" button placeholder).
 //!
 //! Layout is built using the [`Container`] system for automatic centering
-//! and sizing. Icons (grid, wifi) are drawn as overlays since there is no
-//! icon Element variant.
+//! and sizing. Icons (grid, signal bars) are drawn as overlays since there is
+//! no icon Element variant.
 //!
 //! ```text
 //! ┌──────────────────────────────────────┐
-//! │  ▫  AIR AROUND YOU         ≈ (icon)  │  ← header (Container)
+//! │  ▫  AIR AROUND YOU            ▁▃▅▇   │  ← header (Container + signal bars)
 //! ├──────────────────────────────────────┤
 //! │                                      │
 //! │           ( n o n )   or  ...        │  ← status text
 //! │                                      │
 //! │       No Wi-Fi Connection            │  ← title
 //! │       Data cannot be updated.        │  ← subtitle
+//! │       Last signal: -72 dBm           │  ← last RSSI (error state, if known)
 //! │                                      │
 //! │       [ <-> CONNECT TO WI-FI ]       │  ← button (noop)
 //! │                                      │
@@ -31,16 +32,15 @@ use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
 
 extern crate alloc;
 use alloc::boxed::Box;
+use alloc::format;
 
 use crate::pages::page::Page;
-use crate::ui::core::{Action, Drawable, PageId, TouchEvent};
-use crate::ui::styling::{
-    COLOR_BACKGROUND, COLOR_FOREGROUND, DISPLAY_HEIGHT_PX, DISPLAY_WIDTH_PX, WHITE,
-};
+use crate::ui::core::{Action, Drawable, PageEvent, PageId, SystemEvent, TouchEvent};
+use crate::ui::styling::{LOGICAL_DISPLAY_SIZE, Theme};
 use crate::ui::{
     Alignment as UiAlignment, Button, ButtonVariant, ColorPalette, Container, Direction, Element,
-    MAX_CONTAINER_CHILDREN, MainAxisAlignment, Padding, SizeConstraint, Style, TextComponent,
-    TextSize,
+    MAX_CONTAINER_CHILDREN, MainAxisAlignment, Padding, SignalBars, SizeConstraint, Style,
+    TextComponent, TextSize,
 };
 
 // ---------------------------------------------------------------------------
@@ -71,19 +71,19 @@ const GRID_ICON_GAP_PX: i32 = 2;
 /// Grid icon left offset from header left edge.
 const GRID_ICON_LEFT_PX: i32 = 12;
 
+/// Signal bars icon width in the header.
+const SIGNAL_ICON_WIDTH_PX: u32 = 18;
+
+/// Signal bars icon height in the header.
+const SIGNAL_ICON_HEIGHT_PX: u32 = 14;
+
 // ---------------------------------------------------------------------------
 // Colors
 // ---------------------------------------------------------------------------
 
-/// Cyan accent used for the connecting state text.
+/// Cyan accent used for the connecting state text (the same in both themes).
 const COLOR_ACCENT_CYAN: Rgb565 = Rgb565::new(0, 50, 31);
 
-/// Muted gray for subtitle / secondary text.
-const COLOR_TEXT_MUTED: Rgb565 = Rgb565::new(14, 28, 14);
-
-/// Light grayish text for the header title.
-const COLOR_HEADER_TEXT: Rgb565 = Rgb565::new(20, 40, 20);
-
 // ---------------------------------------------------------------------------
 // WiFi connection state
 // ---------------------------------------------------------------------------
@@ -123,10 +123,10 @@ impl WifiState {
     }
 
     /// Accent color used for the status text.
-    fn accent_color(self) -> Rgb565 {
+    fn accent_color(self, palette: &ColorPalette) -> Rgb565 {
         match self {
             Self::Connecting => COLOR_ACCENT_CYAN,
-            Self::Error => COLOR_TEXT_MUTED,
+            Self::Error => palette.text_secondary,
         }
     }
 }
@@ -137,28 +137,48 @@ impl WifiState {
 
 /// Full-screen bounds for the page.
 fn page_bounds() -> Rectangle {
+    Rectangle::new(Point::zero(), LOGICAL_DISPLAY_SIZE)
+}
+
+/// Bounds of the signal bars icon, right-aligned and centred in the header.
+fn signal_icon_bounds() -> Rectangle {
     Rectangle::new(
-        Point::zero(),
-        Size::new(DISPLAY_WIDTH_PX as u32, DISPLAY_HEIGHT_PX as u32),
+        Point::new(
+            LOGICAL_DISPLAY_SIZE.width as i32
+                - (HEADER_RIGHT_PADDING_PX + SIGNAL_ICON_WIDTH_PX) as i32,
+            (HEADER_HEIGHT_PX.saturating_sub(SIGNAL_ICON_HEIGHT_PX) / 2) as i32,
+        ),
+        Size::new(SIGNAL_ICON_WIDTH_PX, SIGNAL_ICON_HEIGHT_PX),
     )
 }
 
 /// A combined WiFi connecting / error page.
 ///
 /// Uses the [`Container`] layout system for automatic positioning and
-/// centering. Icons (grid, wifi) are drawn as overlays.
+/// centering. Icons (grid, signal bars) are drawn as overlays.
 pub struct WifiStatusPage {
     state: WifiState,
     root: Container<2>,
+    signal: SignalBars,
+    palette: ColorPalette,
+    /// Theme the page was built in, for its text sizes
+    theme: Theme,
     dirty: bool,
 }
 
 impl WifiStatusPage {
     /// Create the page in the given initial state.
-    pub fn new(state: WifiState) -> Self {
+    pub fn new(state: WifiState, theme: &Theme) -> Self {
         let mut page = Self {
             state,
             root: Container::new(page_bounds(), Direction::Vertical),
+            signal: SignalBars::new(signal_icon_bounds()).with_palette(ColorPalette {
+                primary: COLOR_ACCENT_CYAN,
+                border: theme.palette.text_secondary,
+                ..theme.palette
+            }),
+            palette: theme.palette,
+            theme: *theme,
             dirty: true,
         };
         page.rebuild_layout();
@@ -179,6 +199,19 @@ impl WifiStatusPage {
         self.state
     }
 
+    /// Record the latest WiFi signal strength, marking the page dirty if the
+    /// bars change or the error-state text needs updating.
+    pub fn set_rssi(&mut self, rssi_dbm: i8) {
+        let previous = self.signal.rssi();
+        self.signal.set_rssi(Some(rssi_dbm));
+        if self.state == WifiState::Error && previous != Some(rssi_dbm) {
+            self.rebuild_layout();
+            self.dirty = true;
+        } else if Drawable::is_dirty(&self.signal) {
+            self.dirty = true;
+        }
+    }
+
     // -- layout construction -----------------------------------------------
 
     /// Rebuild the root container tree for the current state.
@@ -189,8 +222,9 @@ impl WifiStatusPage {
             Container::<2>::new(bounds, Direction::Vertical).with_alignment(UiAlignment::Stretch);
 
         // ── Header row ──────────────────────────────────────────────────
-        let header_text = TextComponent::auto("AIR AROUND YOU", TextSize::Medium)
-            .with_style(Style::new().with_foreground(COLOR_HEADER_TEXT));
+        let header_text =
+            TextComponent::auto("AIR AROUND YOU", self.theme.text_size(TextSize::Medium))
+                .with_style(Style::new().with_foreground(self.palette.text_secondary));
 
         let header = Container::<MAX_CONTAINER_CHILDREN>::new(
             Rectangle::new(
@@ -201,7 +235,7 @@ impl WifiStatusPage {
         )
         .with_alignment(UiAlignment::Center)
         .with_main_axis_alignment(MainAxisAlignment::Start)
-        .with_style(Style::new().with_background(COLOR_FOREGROUND))
+        .with_style(Style::new().with_background(self.palette.surface))
         .with_padding(Padding::new(
             0,
             HEADER_RIGHT_PADDING_PX,
@@ -221,37 +255,52 @@ impl WifiStatusPage {
         // will override these bounds with the actual remaining space, but
         // preferred_size() reads current bounds, so starting at zero would
         // corrupt child widths to 0 and break centering.
-        let mut body =
-            Container::<MAX_CONTAINER_CHILDREN>::new(bounds, Direction::Vertical)
-                .with_alignment(UiAlignment::Center)
-                .with_main_axis_alignment(MainAxisAlignment::Center)
-                .with_gap(BODY_CONTENT_GAP_PX);
+        let mut body = Container::<MAX_CONTAINER_CHILDREN>::new(bounds, Direction::Vertical)
+            .with_alignment(UiAlignment::Center)
+            .with_main_axis_alignment(MainAxisAlignment::Center)
+            .with_gap(BODY_CONTENT_GAP_PX);
 
         // Status text
-        let status = TextComponent::auto(self.state.status_text(), TextSize::Large)
-            .with_style(Style::new().with_foreground(self.state.accent_color()));
+        let status = TextComponent::auto(
+            self.state.status_text(),
+            self.theme.text_size(TextSize::Large),
+        )
+        .with_style(Style::new().with_foreground(self.state.accent_color(&self.palette)));
         let _ = body.add_child(Element::Text(Box::new(status)), SizeConstraint::Fit);
 
         // Title
-        let title = TextComponent::auto(self.state.title_text(), TextSize::Large)
-            .with_style(Style::new().with_foreground(WHITE));
+        let title = TextComponent::auto(
+            self.state.title_text(),
+            self.theme.text_size(TextSize::Large),
+        )
+        .with_style(Style::new().with_foreground(self.palette.text_primary));
         let _ = body.add_child(Element::Text(Box::new(title)), SizeConstraint::Fit);
 
         // Subtitle
-        let subtitle = TextComponent::auto(self.state.subtitle(), TextSize::Small)
-            .with_style(Style::new().with_foreground(COLOR_TEXT_MUTED));
+        let subtitle =
+            TextComponent::auto(self.state.subtitle(), self.theme.text_size(TextSize::Small))
+                .with_style(Style::new().with_foreground(self.palette.text_secondary));
         let _ = body.add_child(Element::Text(Box::new(subtitle)), SizeConstraint::Fit);
 
+        // Last known signal strength helps tell "out of range" from "wrong password"
+        if self.state == WifiState::Error
+            && let Some(rssi_dbm) = self.signal.rssi()
+        {
+            let text = format!("Last signal: {} dBm", rssi_dbm);
+            let rssi = TextComponent::auto(&text, self.theme.text_size(TextSize::Small))
+                .with_style(Style::new().with_foreground(self.palette.text_secondary));
+            let _ = body.add_child(Element::Text(Box::new(rssi)), SizeConstraint::Fit);
+        }
+
         // Button (only in error state)
         if self.state == WifiState::Error {
             // Small spacer before button
             let _ = body.add_child(Element::spacer(Rectangle::zero()), SizeConstraint::Fixed(8));
 
             let palette = ColorPalette {
-                surface: COLOR_FOREGROUND,
                 text_primary: COLOR_ACCENT_CYAN,
-                border: COLOR_TEXT_MUTED,
-                ..ColorPalette::default()
+                border: self.palette.text_secondary,
+                ..self.palette
             };
 
             let btn = Button::auto("CONNECT TO WI-FI", Action::Custom(0))
@@ -275,7 +324,7 @@ impl WifiStatusPage {
         &self,
         display: &mut D,
     ) -> Result<(), D::Error> {
-        let sq_style = PrimitiveStyle::with_fill(COLOR_HEADER_TEXT);
+        let sq_style = PrimitiveStyle::with_fill(self.palette.text_secondary);
 
         // Vertically centre the icon block within the header.
         let icon_block_height = GRID_ICON_SQUARE_PX * 2 + GRID_ICON_GAP_PX as u32;
@@ -328,6 +377,23 @@ impl Page for WifiStatusPage {
         // No periodic updates needed
     }
 
+    fn on_event(&mut self, event: &PageEvent) -> bool {
+        if let PageEvent::SystemEvent(SystemEvent::WifiRssi(rssi_dbm)) = event {
+            self.set_rssi(*rssi_dbm);
+            return self.dirty;
+        }
+
+        let state = match event {
+            PageEvent::SystemEvent(SystemEvent::NetworkConnected) => WifiState::Connecting,
+            PageEvent::SystemEvent(SystemEvent::NetworkDisconnected) => WifiState::Error,
+            _ => return false,
+        };
+
+        let changed = self.state != state;
+        self.set_state(state);
+        changed
+    }
+
     fn draw_page<D: DrawTarget<Color = Rgb565>>(
         &mut self,
         display: &mut D,
@@ -362,15 +428,16 @@ impl Drawable for WifiStatusPage {
             return Ok(());
         }
 
-        // Full-screen dark background
-        display.clear(COLOR_BACKGROUND)?;
+        // Full-screen background
+        display.clear(self.palette.background)?;
 
         // Container draws the header background, "AIR AROUND YOU" text (vertically
         // centred), body content (centrally positioned), and button.
         self.root.draw(display)?;
 
-        // Overlay: grid icon in header (not representable as an Element).
+        // Overlays: grid and signal icons in header (not representable as Elements).
         self.draw_grid_icon(display)?;
+        self.signal.draw(display)?;
 
         Ok(())
     }
@@ -385,6 +452,7 @@ impl Drawable for WifiStatusPage {
 
     fn mark_clean(&mut self) {
         self.dirty = false;
+        self.signal.mark_clean();
     }
 
     fn mark_dirty(&mut self) {
@@ -0,0 +1,210 @@
+//! Network connection helpers: candidate selection and reconnect backoff
+//!
+//! [`connect_first`] walks an ordered list of candidate networks until one
+//! connects. The firmware's WiFi supervisor owns one [`ReconnectBackoff`] and
+//! asks it how long to wait before each reconnect attempt. Neither touches
+//! hardware or timers directly, so both behave identically on device and host.
+
+use core::fmt::Debug;
+use core::future::Future;
+
+use log::{info, warn};
+
+use crate::config::InternetConfig;
+
+/// Delay before the first reconnect attempt in seconds
+pub const WIFI_RECONNECT_BASE_DELAY_SECS: u32 = 2;
+
+/// Upper bound on the delay between reconnect attempts in seconds
+pub const WIFI_RECONNECT_MAX_DELAY_SECS: u32 = 300;
+
+/// Doubling backoff schedule, capped at a maximum delay
+///
+/// Each call to [`next_delay_secs`](Self::next_delay_secs) returns the wait
+/// before the next attempt (`base`, `2 × base`, `4 × base`, ... up to `max`).
+/// Call [`reset`](Self::reset) once the link is back up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectBackoff {
+    base_secs: u32,
+    max_secs: u32,
+    attempts: u32,
+}
+
+impl Default for ReconnectBackoff {
+    fn default() -> Self {
+        Self::new(
+            WIFI_RECONNECT_BASE_DELAY_SECS,
+            WIFI_RECONNECT_MAX_DELAY_SECS,
+        )
+    }
+}
+
+impl ReconnectBackoff {
+    /// Create a schedule starting at `base_secs` and capped at `max_secs`.
+    pub const fn new(base_secs: u32, max_secs: u32) -> Self {
+        Self {
+            base_secs,
+            max_secs,
+            attempts: 0,
+        }
+    }
+
+    /// Delay before the next attempt, advancing the schedule.
+    pub fn next_delay_secs(&mut self) -> u32 {
+        let factor = 1u32.checked_shl(self.attempts).unwrap_or(u32::MAX);
+        self.attempts = self.attempts.saturating_add(1);
+        self.base_secs.saturating_mul(factor).min(self.max_secs)
+    }
+
+    /// Number of delays handed out since the last reset
+    pub const fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Start over from the base delay (call after a successful connect).
+    pub fn reset(&mut self) {
+        self.attempts = 0;
+    }
+}
+
+/// Something that can attempt to join a network with a set of credentials
+///
+/// Implemented by the firmware over the WiFi controller.
+pub trait NetworkConnector {
+    /// Error reported by a failed connection attempt
+    type Error: Debug;
+
+    /// Attempt to connect to `network`.
+    fn connect(
+        &mut self,
+        network: &InternetConfig<'_>,
+    ) -> impl Future<Output = Result<(), Self::Error>>;
+}
+
+/// Try each candidate network in order until one connects
+///
+/// Returns the index of the network that connected, or `None` if every
+/// candidate failed (or the list is empty).
+pub async fn connect_first<C: NetworkConnector>(
+    connector: &mut C,
+    candidates: &[InternetConfig<'_>],
+) -> Option<usize> {
+    for (index, network) in candidates.iter().enumerate() {
+        info!(
+            "Connecting to WiFi SSID: {} ({}/{})",
+            network.ssid,
+            index + 1,
+            candidates.len()
+        );
+        match connector.connect(network).await {
+            Ok(()) => {
+                info!("Connected to WiFi SSID: {}", network.ssid);
+                return Some(index);
+            }
+            Err(e) => warn!("WiFi SSID {} failed: {:?}", network.ssid, e),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::block_on;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    /// Connector failing its first `failures` attempts, recording each SSID tried
+    struct FlakyConnector {
+        failures: usize,
+        tried: Vec<String>,
+    }
+
+    impl NetworkConnector for FlakyConnector {
+        type Error = ();
+
+        async fn connect(&mut self, network: &InternetConfig<'_>) -> Result<(), ()> {
+            self.tried.push(network.ssid.into());
+            if self.tried.len() <= self.failures {
+                return Err(());
+            }
+            Ok(())
+        }
+    }
+
+    fn networks() -> [InternetConfig<'static>; 3] {
+        ["home", "office", "phone"].map(|ssid| InternetConfig {
+            ssid,
+            password: "secret",
+        })
+    }
+
+    #[test]
+    fn connect_first_skips_failing_candidates() {
+        let mut connector = FlakyConnector {
+            failures: 2,
+            tried: Vec::new(),
+        };
+
+        assert_eq!(
+            block_on(connect_first(&mut connector, &networks())),
+            Some(2)
+        );
+        assert_eq!(connector.tried, ["home", "office", "phone"]);
+    }
+
+    #[test]
+    fn connect_first_stops_at_first_success() {
+        let mut connector = FlakyConnector {
+            failures: 0,
+            tried: Vec::new(),
+        };
+
+        assert_eq!(
+            block_on(connect_first(&mut connector, &networks())),
+            Some(0)
+        );
+        assert_eq!(connector.tried, ["home"]);
+    }
+
+    #[test]
+    fn connect_first_reports_when_all_fail() {
+        let mut connector = FlakyConnector {
+            failures: usize::MAX,
+            tried: Vec::new(),
+        };
+
+        assert_eq!(block_on(connect_first(&mut connector, &networks())), None);
+        assert_eq!(connector.tried.len(), 3);
+        assert_eq!(block_on(connect_first(&mut connector, &[])), None);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let mut backoff = ReconnectBackoff::new(2, 30);
+        let delays: [u32; 6] = core::array::from_fn(|_| backoff.next_delay_secs());
+
+        assert_eq!(delays, [2, 4, 8, 16, 30, 30]);
+        assert_eq!(backoff.attempts(), 6);
+    }
+
+    #[test]
+    fn backoff_saturates_after_many_attempts() {
+        let mut backoff = ReconnectBackoff::default();
+        for _ in 0..100 {
+            backoff.next_delay_secs();
+        }
+        assert_eq!(backoff.next_delay_secs(), WIFI_RECONNECT_MAX_DELAY_SECS);
+    }
+
+    #[test]
+    fn reset_restarts_from_base_delay() {
+        let mut backoff = ReconnectBackoff::default();
+        backoff.next_delay_secs();
+        backoff.next_delay_secs();
+
+        backoff.reset();
+        assert_eq!(backoff.attempts(), 0);
+        assert_eq!(backoff.next_delay_secs(), WIFI_RECONNECT_BASE_DELAY_SECS);
+    }
+}
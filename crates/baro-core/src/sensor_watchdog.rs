@@ -0,0 +1,198 @@
+//! Sensor task liveness check
+//!
+//! A panic is not what this guards against: the firmware's panic handler
+//! halts the whole chip. The failure that goes unnoticed is the sensor task
+//! hanging on an await that never completes, such as an I2C transaction
+//! outside a read's timeout or `RollupAccumulator::add_sample` blocked on a
+//! full rollup channel because a subscriber stopped draining it (the stalled
+//! publisher then also holds the app state lock). The display, storage and
+//! network tasks keep running, so pages keep showing the last readings as if
+//! they were current.
+//!
+//! The sensor task records when it last stored a sample in
+//! `AppState::last_sample_at`, and the firmware's supervisor hands that to a
+//! [`SensorWatchdog`] every [`SENSOR_WATCHDOG_POLL_SECS`], which reports when
+//! sampling stalls and when it resumes. Like `reconnect`, nothing here reads
+//! a clock itself, so the logic behaves the same on device and host.
+
+use embassy_time::{Duration, Instant};
+
+/// Sample intervals without a new sample after which sampling counts as stalled
+pub const SENSOR_STALL_INTERVALS: u32 = 3;
+
+/// Shortest stall timeout in seconds
+///
+/// A cycle also spans the sensor reads themselves, which with retries can
+/// outlast a short sample interval several times over.
+pub const MIN_SENSOR_STALL_SECS: u32 = 60;
+
+/// How often the supervisor checks on the sensor task in seconds
+pub const SENSOR_WATCHDOG_POLL_SECS: u64 = 10;
+
+/// Time without a sample after which sampling at `sample_interval_secs` is stalled
+pub const fn sensor_stall_timeout_secs(sample_interval_secs: u32) -> u32 {
+    let secs = sample_interval_secs.saturating_mul(SENSOR_STALL_INTERVALS);
+    if secs < MIN_SENSOR_STALL_SECS {
+        MIN_SENSOR_STALL_SECS
+    } else {
+        secs
+    }
+}
+
+/// Change in sampling reported by [`SensorWatchdog::check`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SensorWatchdogEvent {
+    /// No sample was stored for `silent_secs`, past the stall timeout
+    Stalled { silent_secs: u64 },
+    /// A new sample arrived after a stall
+    Resumed,
+}
+
+/// Tracks whether the sensor task is still producing samples
+///
+/// Reports each change once, so the caller can raise and clear an alert
+/// without repeating it on every check.
+///
+/// # Examples
+/// ```ignore
+/// let mut watchdog = SensorWatchdog::new(Instant::now());
+/// loop {
+///     Timer::after_secs(SENSOR_WATCHDOG_POLL_SECS).await;
+///     let last_sample_at = app_state.lock().await.last_sample_at;
+///     if let Some(event) = watchdog.check(last_sample_at, Instant::now(), interval_secs) {
+///         // raise or clear the alert
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SensorWatchdog {
+    started_at: Instant,
+    stalled: bool,
+}
+
+impl SensorWatchdog {
+    /// Start watching at `started_at`, when the sensor task was spawned
+    pub const fn new(started_at: Instant) -> Self {
+        Self {
+            started_at,
+            stalled: false,
+        }
+    }
+
+    /// Whether the last check found sampling stalled
+    pub const fn is_stalled(&self) -> bool {
+        self.stalled
+    }
+
+    /// Compare the last sample time against `now`, returning any change
+    ///
+    /// Until the first sample arrives (`last_sample_at` is `None`), the time
+    /// since the watchdog started counts instead, so a task that dies before
+    /// its first sample is caught too.
+    pub fn check(
+        &mut self,
+        last_sample_at: Option<Instant>,
+        now: Instant,
+        sample_interval_secs: u32,
+    ) -> Option<SensorWatchdogEvent> {
+        let since = last_sample_at.unwrap_or(self.started_at);
+        let silent = now.saturating_duration_since(since);
+        let timeout = Duration::from_secs(sensor_stall_timeout_secs(sample_interval_secs) as u64);
+        let stalled = silent > timeout;
+
+        if stalled == self.stalled {
+            return None;
+        }
+        self.stalled = stalled;
+
+        Some(if stalled {
+            SensorWatchdogEvent::Stalled {
+                silent_secs: silent.as_secs(),
+            }
+        } else {
+            SensorWatchdogEvent::Resumed
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INTERVAL_SECS: u32 = 30;
+
+    fn at(secs: u64) -> Instant {
+        Instant::from_secs(secs)
+    }
+
+    #[test]
+    fn timeout_is_three_intervals_with_a_floor() {
+        assert_eq!(sensor_stall_timeout_secs(30), 90);
+        assert_eq!(sensor_stall_timeout_secs(300), 900);
+        assert_eq!(sensor_stall_timeout_secs(10), MIN_SENSOR_STALL_SECS);
+        assert_eq!(sensor_stall_timeout_secs(0), MIN_SENSOR_STALL_SECS);
+        assert_eq!(sensor_stall_timeout_secs(u32::MAX), u32::MAX);
+    }
+
+    #[test]
+    fn recent_sample_is_not_stale() {
+        let mut watchdog = SensorWatchdog::new(at(0));
+        assert_eq!(watchdog.check(Some(at(100)), at(150), INTERVAL_SECS), None);
+        // Exactly at the timeout still counts as live
+        assert_eq!(watchdog.check(Some(at(100)), at(190), INTERVAL_SECS), None);
+        assert!(!watchdog.is_stalled());
+    }
+
+    #[test]
+    fn stale_sample_is_reported_once() {
+        let mut watchdog = SensorWatchdog::new(at(0));
+
+        assert_eq!(
+            watchdog.check(Some(at(100)), at(191), INTERVAL_SECS),
+            Some(SensorWatchdogEvent::Stalled { silent_secs: 91 })
+        );
+        assert!(watchdog.is_stalled());
+        assert_eq!(watchdog.check(Some(at(100)), at(300), INTERVAL_SECS), None);
+    }
+
+    #[test]
+    fn new_sample_after_a_stall_resumes() {
+        let mut watchdog = SensorWatchdog::new(at(0));
+        watchdog.check(Some(at(100)), at(400), INTERVAL_SECS);
+
+        assert_eq!(
+            watchdog.check(Some(at(405)), at(410), INTERVAL_SECS),
+            Some(SensorWatchdogEvent::Resumed)
+        );
+        assert!(!watchdog.is_stalled());
+        assert_eq!(watchdog.check(Some(at(405)), at(420), INTERVAL_SECS), None);
+    }
+
+    #[test]
+    fn task_that_never_samples_is_caught_from_start() {
+        let mut watchdog = SensorWatchdog::new(at(50));
+
+        assert_eq!(watchdog.check(None, at(140), INTERVAL_SECS), None);
+        assert_eq!(
+            watchdog.check(None, at(141), INTERVAL_SECS),
+            Some(SensorWatchdogEvent::Stalled { silent_secs: 91 })
+        );
+    }
+
+    #[test]
+    fn longer_interval_extends_the_timeout() {
+        let mut watchdog = SensorWatchdog::new(at(0));
+        // 200 s of silence is a stall at 30 s but not at 300 s
+        assert_eq!(watchdog.check(Some(at(100)), at(300), 300), None);
+        assert!(matches!(
+            watchdog.check(Some(at(100)), at(300), INTERVAL_SECS),
+            Some(SensorWatchdogEvent::Stalled { .. })
+        ));
+    }
+
+    #[test]
+    fn sample_stamped_after_now_is_not_stale() {
+        let mut watchdog = SensorWatchdog::new(at(0));
+        assert_eq!(watchdog.check(Some(at(500)), at(400), INTERVAL_SECS), None);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::metrics::fixed::to_milli;
 use crate::sensors::{SensorError, SensorReadings};
 
 use super::Sensor;
@@ -34,14 +35,16 @@ impl<I: I2c> BH1750Sensor<I> {
 impl<I: I2c> Sensor<1> for BH1750Sensor<I> {
     type Readings = BH1750Readings;
 
+    const NAME: &'static str = "BH1750";
+
     async fn read(&mut self) -> Result<BH1750Readings, SensorError> {
         self.sensor
             .one_time_measurement(Resolution::High)
             .await
             .map(|lux| {
                 // The BH1750 gives us the lux value as f32, but we want to store it as i32 in our values array.
-                // We can multiply by 1000 to preserve three decimal places of precision, and then convert to i32.
-                let lux_i32 = (lux * 1000.0) as i32;
+                // Storing milli-lux preserves three decimal places of precision.
+                let lux_i32 = to_milli(lux);
                 info!("BH1750: Measured lux = {} (stored as {})", lux, lux_i32);
 
                 BH1750Readings { milli_lux: lux_i32 }
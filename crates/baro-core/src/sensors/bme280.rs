@@ -0,0 +1,344 @@
+use crate::sensors::{SensorError, SensorReadings};
+
+use super::Sensor;
+use embassy_time::Timer;
+use embedded_hal_async::i2c::I2c;
+use log::{debug, error};
+
+/// Default I2C address (SDO pulled low)
+pub const BME280_I2C_ADDRESS: u8 = 0x76;
+
+/// Expected value of the chip ID register
+const BME280_CHIP_ID: u8 = 0x60;
+
+/// Chip ID register
+const REG_CHIP_ID: u8 = 0xD0;
+
+/// Start of the temperature/pressure calibration block (0x88..=0x9F)
+const REG_CALIB_START: u8 = 0x88;
+
+/// Length of the temperature/pressure calibration block in bytes
+const CALIB_LEN: usize = 24;
+
+/// Measurement status register (bit 3 set while converting)
+const REG_STATUS: u8 = 0xF3;
+
+/// Status bit indicating a conversion is in progress
+const STATUS_MEASURING: u8 = 0x08;
+
+/// Measurement control register (oversampling + mode)
+const REG_CTRL_MEAS: u8 = 0xF4;
+
+/// Temperature x1, pressure x1 oversampling, forced mode
+const CTRL_MEAS_FORCED_X1: u8 = (0b001 << 5) | (0b001 << 2) | 0b01;
+
+/// Start of the pressure + temperature data burst (0xF7..=0xFC)
+const REG_DATA_START: u8 = 0xF7;
+
+/// Length of the pressure + temperature data burst in bytes
+const DATA_LEN: usize = 6;
+
+/// Delay between status polls while a forced conversion runs
+const CONVERSION_POLL_INTERVAL_MS: u64 = 2;
+
+/// Maximum number of status polls before giving up (x1 oversampling takes ~7ms)
+const CONVERSION_MAX_POLLS: u8 = 10;
+
+/// Typed readings from the BME280 sensor.
+pub struct BME280Readings {
+    /// Pressure in milli-hPa (hPa × 1000)
+    pub pressure_milli_hpa: i32,
+    pub temperature_milli_celsius: i32,
+}
+
+impl SensorReadings<2> for BME280Readings {
+    fn to_array(self) -> [i32; 2] {
+        [self.pressure_milli_hpa, self.temperature_milli_celsius]
+    }
+}
+
+/// Factory trimming values used by the datasheet compensation formulas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bme280Calibration {
+    dig_t1: u16,
+    dig_t2: i16,
+    dig_t3: i16,
+    dig_p1: u16,
+    dig_p2: i16,
+    dig_p3: i16,
+    dig_p4: i16,
+    dig_p5: i16,
+    dig_p6: i16,
+    dig_p7: i16,
+    dig_p8: i16,
+    dig_p9: i16,
+}
+
+impl Bme280Calibration {
+    /// Parse the 24-byte calibration block read from `0x88`.
+    pub fn from_bytes(raw: &[u8; CALIB_LEN]) -> Self {
+        let u = |i: usize| u16::from_le_bytes([raw[i], raw[i + 1]]);
+        let s = |i: usize| i16::from_le_bytes([raw[i], raw[i + 1]]);
+        Self {
+            dig_t1: u(0),
+            dig_t2: s(2),
+            dig_t3: s(4),
+            dig_p1: u(6),
+            dig_p2: s(8),
+            dig_p3: s(10),
+            dig_p4: s(12),
+            dig_p5: s(14),
+            dig_p6: s(16),
+            dig_p7: s(18),
+            dig_p8: s(20),
+            dig_p9: s(22),
+        }
+    }
+
+    /// Compute `t_fine` (shared by both compensation formulas) from raw temperature.
+    fn t_fine(&self, adc_t: i32) -> i32 {
+        let t1 = self.dig_t1 as i32;
+        let var1 = (((adc_t >> 3) - (t1 << 1)) * self.dig_t2 as i32) >> 11;
+        let delta = (adc_t >> 4) - t1;
+        let var2 = (((delta * delta) >> 12) * self.dig_t3 as i32) >> 14;
+        var1 + var2
+    }
+
+    /// Compensated temperature in milli-°C.
+    pub fn temperature_milli_celsius(&self, adc_t: i32) -> i32 {
+        // Datasheet result is in 0.01 °C
+        let centi = (self.t_fine(adc_t) * 5 + 128) >> 8;
+        centi * 10
+    }
+
+    /// Compensated pressure in milli-hPa, or `None` if the calibration is invalid.
+    pub fn pressure_milli_hpa(&self, adc_t: i32, adc_p: i32) -> Option<i32> {
+        let t_fine = self.t_fine(adc_t) as i64;
+
+        let mut var1 = t_fine - 128_000;
+        let mut var2 = var1 * var1 * self.dig_p6 as i64;
+        var2 += (var1 * self.dig_p5 as i64) << 17;
+        var2 += (self.dig_p4 as i64) << 35;
+        var1 = ((var1 * var1 * self.dig_p3 as i64) >> 8) + ((var1 * self.dig_p2 as i64) << 12);
+        var1 = (((1_i64 << 47) + var1) * self.dig_p1 as i64) >> 33;
+        if var1 == 0 {
+            return None;
+        }
+
+        let mut p = 1_048_576 - adc_p as i64;
+        p = (((p << 31) - var2) * 3125) / var1;
+        let var1 = (self.dig_p9 as i64 * (p >> 13) * (p >> 13)) >> 25;
+        let var2 = (self.dig_p8 as i64 * p) >> 19;
+        p = ((p + var1 + var2) >> 8) + ((self.dig_p7 as i64) << 4);
+
+        // `p` is Pa in Q24.8; 1 Pa = 10 milli-hPa
+        Some((p * 10 / 256) as i32)
+    }
+}
+
+/// Combine the 20-bit msb/lsb/xlsb register layout into a raw ADC value.
+const fn adc_20bit(msb: u8, lsb: u8, xlsb: u8) -> i32 {
+    ((msb as i32) << 12) | ((lsb as i32) << 4) | ((xlsb as i32) >> 4)
+}
+
+/// Register-level BME280 driver reading pressure and temperature in forced mode.
+pub struct BME280Sensor<I> {
+    i2c: I,
+    address: u8,
+    calibration: Option<Bme280Calibration>,
+}
+
+impl<I: I2c> BME280Sensor<I> {
+    pub fn new(i2c: I) -> Self {
+        Self::with_address(i2c, BME280_I2C_ADDRESS)
+    }
+
+    /// Create a driver for a BME280 at a non-default address (e.g. `0x77`).
+    pub fn with_address(i2c: I, address: u8) -> Self {
+        Self {
+            i2c,
+            address,
+            calibration: None,
+        }
+    }
+
+    async fn read_registers(
+        &mut self,
+        register: u8,
+        buf: &mut [u8],
+        operation: &'static str,
+    ) -> Result<(), SensorError> {
+        self.i2c
+            .write_read(self.address, &[register], buf)
+            .await
+            .map_err(|e| {
+                error!("BME280 {} failed: {:?}", operation, e);
+                SensorError::ReadFailed {
+                    sensor: "BME280",
+                    operation,
+                    details: "I2C communication error or sensor not responding",
+                }
+            })
+    }
+
+    /// Verify the chip ID and load the factory calibration (once per instance).
+    async fn calibration(&mut self) -> Result<Bme280Calibration, SensorError> {
+        if let Some(calibration) = self.calibration {
+            return Ok(calibration);
+        }
+
+        let mut chip_id = [0u8; 1];
+        self.read_registers(REG_CHIP_ID, &mut chip_id, "read chip id")
+            .await?;
+        if chip_id[0] != BME280_CHIP_ID {
+            error!("BME280: unexpected chip id 0x{:02X}", chip_id[0]);
+            return Err(SensorError::InitializationFailed {
+                sensor: "BME280",
+                details: "Unexpected chip id (not a BME280)",
+            });
+        }
+
+        let mut raw = [0u8; CALIB_LEN];
+        self.read_registers(REG_CALIB_START, &mut raw, "read calibration")
+            .await?;
+        let calibration = Bme280Calibration::from_bytes(&raw);
+        debug!("BME280: loaded calibration {:?}", calibration);
+
+        self.calibration = Some(calibration);
+        Ok(calibration)
+    }
+
+    /// Trigger a forced conversion and wait for it to finish.
+    async fn measure(&mut self) -> Result<(), SensorError> {
+        self.i2c
+            .write(self.address, &[REG_CTRL_MEAS, CTRL_MEAS_FORCED_X1])
+            .await
+            .map_err(|e| {
+                error!("BME280 start conversion failed: {:?}", e);
+                SensorError::ReadFailed {
+                    sensor: "BME280",
+                    operation: "start forced conversion",
+                    details: "I2C communication error or sensor not responding",
+                }
+            })?;
+
+        for _ in 0..CONVERSION_MAX_POLLS {
+            Timer::after_millis(CONVERSION_POLL_INTERVAL_MS).await;
+            let mut status = [0u8; 1];
+            self.read_registers(REG_STATUS, &mut status, "read status")
+                .await?;
+            if status[0] & STATUS_MEASURING == 0 {
+                return Ok(());
+            }
+        }
+
+        Err(SensorError::Timeout {
+            sensor: "BME280",
+            operation: "forced conversion",
+        })
+    }
+}
+
+impl<I: I2c> Sensor<2> for BME280Sensor<I> {
+    type Readings = BME280Readings;
+
+    const NAME: &'static str = "BME280";
+
+    async fn read(&mut self) -> Result<BME280Readings, SensorError> {
+        let calibration = self.calibration().await?;
+        self.measure().await?;
+
+        let mut data = [0u8; DATA_LEN];
+        self.read_registers(REG_DATA_START, &mut data, "read pressure/temperature")
+            .await?;
+
+        let adc_p = adc_20bit(data[0], data[1], data[2]);
+        let adc_t = adc_20bit(data[3], data[4], data[5]);
+
+        let pressure_milli_hpa =
+            calibration
+                .pressure_milli_hpa(adc_t, adc_p)
+                .ok_or(SensorError::ReadFailed {
+                    sensor: "BME280",
+                    operation: "compensate pressure",
+                    details: "Invalid calibration data (dig_P1 is zero)",
+                })?;
+        let temperature_milli_celsius = calibration.temperature_milli_celsius(adc_t);
+
+        Ok(BME280Readings {
+            pressure_milli_hpa,
+            temperature_milli_celsius,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{I2cExpect, MockI2c, block_on};
+
+    /// Calibration block of the datasheet's worked example (section 8.2)
+    const DATASHEET_CALIBRATION: [u8; CALIB_LEN] = [
+        112, 107, 67, 103, 24, 252, 125, 142, 67, 214, 208, 11, 39, 11, 140, 0, 249, 255, 140, 60,
+        248, 198, 112, 23,
+    ];
+
+    /// Raw burst for adc_P = 415148 and adc_T = 519888
+    const DATASHEET_DATA: [u8; DATA_LEN] = [0x65, 0x5A, 0xC0, 0x7E, 0xED, 0x00];
+
+    fn address() -> u8 {
+        BME280_I2C_ADDRESS
+    }
+
+    fn forced_read() -> [I2cExpect; 4] {
+        [
+            I2cExpect::write(address(), &[REG_CTRL_MEAS, CTRL_MEAS_FORCED_X1]),
+            I2cExpect::write_read(address(), &[REG_STATUS], &[STATUS_MEASURING]),
+            I2cExpect::write_read(address(), &[REG_STATUS], &[0x00]),
+            I2cExpect::write_read(address(), &[REG_DATA_START], &DATASHEET_DATA),
+        ]
+    }
+
+    #[test]
+    fn reads_datasheet_example_from_transcript() {
+        let mut transcript = alloc::vec![
+            I2cExpect::write_read(address(), &[REG_CHIP_ID], &[BME280_CHIP_ID]),
+            I2cExpect::write_read(address(), &[REG_CALIB_START], &DATASHEET_CALIBRATION),
+        ];
+        transcript.extend(forced_read());
+        // Calibration is only loaded once per driver
+        transcript.extend(forced_read());
+        let mut sensor = BME280Sensor::new(MockI2c::new(transcript));
+
+        for _ in 0..2 {
+            let readings = block_on(sensor.read()).unwrap();
+            assert_eq!(readings.temperature_milli_celsius, 25_080);
+            assert_eq!(readings.pressure_milli_hpa, 1_006_532);
+        }
+        sensor.i2c.done();
+    }
+
+    #[test]
+    fn wrong_chip_id_fails_initialization() {
+        let mut sensor = BME280Sensor::new(MockI2c::new([I2cExpect::write_read(
+            address(),
+            &[REG_CHIP_ID],
+            &[0x58],
+        )]));
+
+        assert!(matches!(
+            block_on(sensor.read()),
+            Err(SensorError::InitializationFailed { .. })
+        ));
+        sensor.i2c.done();
+    }
+
+    #[test]
+    fn zero_dig_p1_is_rejected() {
+        let mut calibration = DATASHEET_CALIBRATION;
+        calibration[6] = 0;
+        calibration[7] = 0;
+        let calibration = Bme280Calibration::from_bytes(&calibration);
+        assert_eq!(calibration.pressure_milli_hpa(519_888, 415_148), None);
+    }
+}
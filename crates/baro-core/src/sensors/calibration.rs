@@ -0,0 +1,115 @@
+//! Per-sensor calibration applied to raw readings at read time
+//!
+//! Calibration adjusts values before they enter the sensor values array, so
+//! stored history always reflects corrected readings and changing the
+//! calibration never rewrites past data.
+
+use crate::storage::MAX_SENSORS;
+
+/// Scale factor representing 1.0 (scale values are milli-factors)
+pub const CALIBRATION_SCALE_UNITY: i32 = 1000;
+
+/// Per-sensor calibration offsets and scale factors
+///
+/// Indexed the same way as the sensor values array (see `sensors::indices`).
+/// A corrected value is computed as `raw * scale / 1000 + offset`, with the
+/// offset expressed in the sensor's stored unit (e.g. milli-°C).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SensorCalibration {
+    /// Additive offset per sensor, in the sensor's stored unit
+    pub offset: [i32; MAX_SENSORS],
+    /// Multiplicative milli-factor per sensor (1000 = 1.0)
+    pub scale: [i32; MAX_SENSORS],
+}
+
+impl Default for SensorCalibration {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl SensorCalibration {
+    /// Calibration that leaves every reading unchanged
+    pub const fn identity() -> Self {
+        Self {
+            offset: [0; MAX_SENSORS],
+            scale: [CALIBRATION_SCALE_UNITY; MAX_SENSORS],
+        }
+    }
+
+    /// Apply the calibration for `index` to a single raw value.
+    ///
+    /// Scale is applied first, then the offset. The result saturates at the
+    /// `i32` range instead of wrapping.
+    pub fn apply(&self, index: usize, raw: i32) -> i32 {
+        let scaled = raw as i64 * self.scale[index] as i64 / CALIBRATION_SCALE_UNITY as i64;
+        (scaled + self.offset[index] as i64).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+
+    /// Apply the calibration in place to `count` values starting at `start`.
+    pub fn apply_range(&self, values: &mut [i32; MAX_SENSORS], start: usize, count: usize) {
+        for (index, value) in values.iter_mut().enumerate().skip(start).take(count) {
+            *value = self.apply(index, *value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MISSING_SENSOR_VALUE;
+
+    const TEMPERATURE: usize = 0;
+
+    #[test]
+    fn identity_leaves_readings_unchanged() {
+        assert_eq!(
+            SensorCalibration::identity().apply(TEMPERATURE, 21_340),
+            21_340
+        );
+    }
+
+    #[test]
+    fn offset_shifts_reading() {
+        let mut calibration = SensorCalibration::identity();
+        calibration.offset[TEMPERATURE] = 500;
+        assert_eq!(calibration.apply(TEMPERATURE, 21_000), 21_500);
+    }
+
+    #[test]
+    fn scale_applies_before_offset() {
+        let mut calibration = SensorCalibration::identity();
+        calibration.scale[TEMPERATURE] = 1_100;
+        calibration.offset[TEMPERATURE] = 500;
+        assert_eq!(calibration.apply(TEMPERATURE, 20_000), 22_500);
+    }
+
+    #[test]
+    fn scale_compounds_when_applied_twice() {
+        let mut calibration = SensorCalibration::identity();
+        calibration.scale[TEMPERATURE] = 1_100;
+        let once = calibration.apply(TEMPERATURE, 20_000);
+        assert_eq!(calibration.apply(TEMPERATURE, once), 24_200);
+    }
+
+    #[test]
+    fn result_saturates_instead_of_wrapping() {
+        let mut calibration = SensorCalibration::identity();
+        calibration.scale[TEMPERATURE] = 2 * CALIBRATION_SCALE_UNITY;
+        assert_eq!(calibration.apply(TEMPERATURE, i32::MAX), i32::MAX);
+    }
+
+    #[test]
+    fn apply_range_touches_only_the_range() {
+        let mut calibration = SensorCalibration::identity();
+        calibration.offset = [500; MAX_SENSORS];
+        let mut values = [1_000; MAX_SENSORS];
+        values[MAX_SENSORS - 1] = MISSING_SENSOR_VALUE;
+
+        calibration.apply_range(&mut values, 1, 2);
+
+        assert_eq!(values[0], 1_000);
+        assert_eq!(values[1..3], [1_500, 1_500]);
+        assert_eq!(values[MAX_SENSORS - 1], MISSING_SENSOR_VALUE);
+    }
+}
@@ -0,0 +1,226 @@
+//! Sensor health tracking and aggregation
+//!
+//! Each physical sensor reports a [`SensorHealth`] after every read attempt.
+//! [`SensorHealthTracker`] turns those outcomes into a per-reading
+//! [`SensorHealthReport`] that the UI uses to flag faulty sensors instead of
+//! showing stale values.
+
+use super::SensorType;
+
+/// Number of sensor types tracked in a health report
+pub const SENSOR_TYPE_COUNT: usize = 5;
+
+/// Consecutive read failures after which a sensor is reported as failed
+pub const FAILED_AFTER_CONSECUTIVE_FAILURES: u8 = 3;
+
+/// Health of a single sensor (or the aggregate of several)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SensorHealth {
+    /// Sensor is responding normally
+    #[default]
+    Ok,
+    /// Sensor is responding intermittently or reporting a problem
+    Degraded,
+    /// Sensor has stopped responding
+    Failed,
+}
+
+impl SensorHealth {
+    /// Sort key for severity (higher = more severe)
+    pub const fn severity(self) -> u8 {
+        match self {
+            Self::Ok => 0,
+            Self::Degraded => 1,
+            Self::Failed => 2,
+        }
+    }
+
+    /// Return the worst (most severe) health from a slice
+    pub fn worst(levels: &[SensorHealth]) -> SensorHealth {
+        levels
+            .iter()
+            .copied()
+            .max_by_key(|h| h.severity())
+            .unwrap_or(SensorHealth::Ok)
+    }
+
+    /// Map a run of consecutive read failures to a health level
+    pub const fn from_consecutive_failures(failures: u8) -> SensorHealth {
+        if failures == 0 {
+            SensorHealth::Ok
+        } else if failures < FAILED_AFTER_CONSECUTIVE_FAILURES {
+            SensorHealth::Degraded
+        } else {
+            SensorHealth::Failed
+        }
+    }
+
+    /// Short label for compact display
+    pub const fn short_label(self) -> &'static str {
+        match self {
+            Self::Ok => "OK",
+            Self::Degraded => "DEGRADED",
+            Self::Failed => "FAULT",
+        }
+    }
+}
+
+/// Snapshot of every sensor type's health
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SensorHealthReport {
+    sensors: [SensorHealth; SENSOR_TYPE_COUNT],
+}
+
+impl SensorHealthReport {
+    /// Array slot for a sensor type
+    const fn slot(sensor: SensorType) -> usize {
+        match sensor {
+            SensorType::Temperature => 0,
+            SensorType::Humidity => 1,
+            SensorType::Co2 => 2,
+            SensorType::Lux => 3,
+            SensorType::Pressure => 4,
+        }
+    }
+
+    /// Health of a single sensor type
+    pub const fn get(&self, sensor: SensorType) -> SensorHealth {
+        self.sensors[Self::slot(sensor)]
+    }
+
+    /// Set the health of a single sensor type
+    pub fn set(&mut self, sensor: SensorType, health: SensorHealth) {
+        self.sensors[Self::slot(sensor)] = health;
+    }
+
+    /// Overall health: the worst of all sensor types
+    pub fn overall(&self) -> SensorHealth {
+        SensorHealth::worst(&self.sensors)
+    }
+}
+
+/// Tracks consecutive read failures per sensor type
+///
+/// A physical sensor may feed several sensor types (the SHT40 produces both
+/// temperature and humidity), so outcomes are recorded against a slice of
+/// types.
+#[derive(Debug, Clone, Default)]
+pub struct SensorHealthTracker {
+    failures: [u8; SENSOR_TYPE_COUNT],
+    reported: SensorHealthReport,
+}
+
+impl SensorHealthTracker {
+    pub const fn new() -> Self {
+        Self {
+            failures: [0; SENSOR_TYPE_COUNT],
+            reported: SensorHealthReport {
+                sensors: [SensorHealth::Ok; SENSOR_TYPE_COUNT],
+            },
+        }
+    }
+
+    /// Record a successful read along with the health the sensor reported.
+    pub fn record_success(&mut self, sensors: &[SensorType], reported: SensorHealth) {
+        for &sensor in sensors {
+            let slot = SensorHealthReport::slot(sensor);
+            self.failures[slot] = 0;
+            self.reported.set(sensor, reported);
+        }
+    }
+
+    /// Record a failed read; repeated failures escalate to `Failed`.
+    pub fn record_failure(&mut self, sensors: &[SensorType]) {
+        for &sensor in sensors {
+            let slot = SensorHealthReport::slot(sensor);
+            self.failures[slot] = self.failures[slot].saturating_add(1);
+            self.reported.set(
+                sensor,
+                SensorHealth::from_consecutive_failures(self.failures[slot]),
+            );
+        }
+    }
+
+    /// Current health of every sensor type
+    pub fn report(&self) -> SensorHealthReport {
+        self.reported
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHT40: &[SensorType] = &[SensorType::Temperature, SensorType::Humidity];
+    const SCD41: &[SensorType] = &[SensorType::Co2];
+
+    #[test]
+    fn worst_picks_most_severe() {
+        use SensorHealth::*;
+        assert_eq!(SensorHealth::worst(&[]), Ok);
+        assert_eq!(SensorHealth::worst(&[Ok, Ok]), Ok);
+        assert_eq!(SensorHealth::worst(&[Ok, Degraded, Ok]), Degraded);
+        assert_eq!(SensorHealth::worst(&[Degraded, Failed, Ok]), Failed);
+    }
+
+    #[test]
+    fn consecutive_failures_escalate() {
+        assert_eq!(SensorHealth::from_consecutive_failures(0), SensorHealth::Ok);
+        assert_eq!(
+            SensorHealth::from_consecutive_failures(1),
+            SensorHealth::Degraded
+        );
+        assert_eq!(
+            SensorHealth::from_consecutive_failures(FAILED_AFTER_CONSECUTIVE_FAILURES),
+            SensorHealth::Failed
+        );
+    }
+
+    #[test]
+    fn failure_marks_every_type_of_the_sensor() {
+        let mut tracker = SensorHealthTracker::new();
+        tracker.record_failure(SHT40);
+
+        let report = tracker.report();
+        assert_eq!(report.get(SensorType::Temperature), SensorHealth::Degraded);
+        assert_eq!(report.get(SensorType::Humidity), SensorHealth::Degraded);
+        assert_eq!(report.get(SensorType::Co2), SensorHealth::Ok);
+        assert_eq!(report.overall(), SensorHealth::Degraded);
+    }
+
+    #[test]
+    fn one_failed_sensor_fails_the_overall_state() {
+        let mut tracker = SensorHealthTracker::new();
+        for _ in 0..FAILED_AFTER_CONSECUTIVE_FAILURES {
+            tracker.record_failure(SCD41);
+            tracker.record_success(SHT40, SensorHealth::Ok);
+        }
+
+        let report = tracker.report();
+        assert_eq!(report.get(SensorType::Co2), SensorHealth::Failed);
+        assert_eq!(report.get(SensorType::Temperature), SensorHealth::Ok);
+        assert_eq!(report.overall(), SensorHealth::Failed);
+    }
+
+    #[test]
+    fn success_resets_failures_to_reported_health() {
+        let mut tracker = SensorHealthTracker::new();
+        tracker.record_failure(SCD41);
+        tracker.record_failure(SCD41);
+        tracker.record_success(SCD41, SensorHealth::Degraded);
+        assert_eq!(
+            tracker.report().get(SensorType::Co2),
+            SensorHealth::Degraded
+        );
+
+        // The failure run restarted, so one more failure is not yet fatal
+        tracker.record_failure(SCD41);
+        assert_eq!(
+            tracker.report().get(SensorType::Co2),
+            SensorHealth::Degraded
+        );
+
+        tracker.record_success(SCD41, SensorHealth::Ok);
+        assert_eq!(tracker.report().overall(), SensorHealth::Ok);
+    }
+}
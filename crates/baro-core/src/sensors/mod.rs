@@ -1,19 +1,44 @@
 #[cfg(feature = "sensor-bh1750")]
 mod bh1750;
+#[cfg(feature = "sensor-bme280")]
+mod bme280;
+mod calibration;
+mod health;
+mod registry;
 #[cfg(feature = "sensor-scd41")]
 mod scd41;
 #[cfg(feature = "sensor-sht40")]
 mod sht40;
+mod spike;
+mod warmup;
 
 #[cfg(feature = "sensor-bh1750")]
 pub use bh1750::*;
+#[cfg(feature = "sensor-bme280")]
+pub use bme280::*;
+pub use calibration::{CALIBRATION_SCALE_UNITY, SensorCalibration};
+pub use health::{
+    FAILED_AFTER_CONSECUTIVE_FAILURES, SENSOR_TYPE_COUNT, SensorHealth, SensorHealthReport,
+    SensorHealthTracker,
+};
+pub use registry::{SensorList, SensorRegistry, SensorSlot};
 #[cfg(feature = "sensor-scd41")]
 pub use scd41::*;
 #[cfg(feature = "sensor-sht40")]
 pub use sht40::*;
+pub use spike::{
+    DEFAULT_SPIKE_THRESHOLD_DEVIATIONS, SPIKE_EMA_ALPHA, SPIKE_MAX_CONSECUTIVE,
+    SPIKE_WARMUP_SAMPLES, SpikeFilter,
+};
+pub use warmup::SensorWarmup;
 
-use super::storage::MAX_SENSORS;
+use super::storage::{MAX_SENSORS, MISSING_SENSOR_VALUE};
+use crate::config::TemperatureUnit;
+use crate::metrics::fixed::to_float;
+use core::fmt::Write;
 use core::{fmt, future::Future, marker::PhantomData};
+use embassy_time::{Duration, Instant, Timer, with_timeout};
+use log::warn;
 use thiserror_no_std::Error;
 
 /// Detailed sensor error with context for debugging
@@ -46,8 +71,39 @@ pub enum SensorError {
         sensor: &'static str,
         operation: &'static str,
     },
+    /// The I2C mux could not be switched to the sensor's channel, so the
+    /// sensor itself was never reached (points at wiring on that channel)
+    #[error("Sensor '{sensor}' unreachable: I2C mux channel {channel} selection failed")]
+    MuxChannel { sensor: &'static str, channel: u8 },
 }
 
+/// Attempts per sensor read before the sensor is reported as missing
+pub const SENSOR_READ_ATTEMPTS: u8 = 3;
+
+/// Delay before the first read retry in milliseconds (doubles after each retry)
+pub const SENSOR_RETRY_BASE_DELAY_MS: u64 = 50;
+
+/// Default deadline for a single `Sensor::read` in milliseconds
+pub const DEFAULT_SENSOR_READ_TIMEOUT_MS: u64 = 1000;
+
+/// Duration of one SCD41 single-shot measurement in seconds
+pub const SCD41_MEASUREMENT_SECS: u32 = 5;
+
+/// Measurement time of the slowest compiled-in sensor in seconds
+///
+/// Sampling faster than this would start a new read before the previous one
+/// finished. Only the SCD41 takes seconds; the others convert in milliseconds.
+pub const SLOWEST_MEASUREMENT_SECS: u32 = if cfg!(feature = "sensor-scd41") {
+    SCD41_MEASUREMENT_SECS
+} else {
+    1
+};
+
+/// Lowest CO₂ reading accepted as real, in milli-ppm (1 ppm)
+///
+/// Excludes the 0 ppm the SCD41 returns from a glitched read.
+pub const MIN_PLAUSIBLE_CO2_MILLI_PPM: i32 = 1_000;
+
 /// Helper to format I2C errors from esp-hal
 pub fn format_i2c_error(_err: &dyn fmt::Debug) -> &'static str {
     // For now, we'll return a generic message.
@@ -55,6 +111,18 @@ pub fn format_i2c_error(_err: &dyn fmt::Debug) -> &'static str {
     "I2C communication failed (see logs for details)"
 }
 
+/// Tag a failed mux channel switch for `sensor` as [`SensorError::MuxChannel`]
+///
+/// A wiring fault on one channel then reads differently from the sensor
+/// itself failing to respond.
+pub fn select_mux_channel<T, E>(
+    selection: Result<T, E>,
+    sensor: &'static str,
+    channel: u8,
+) -> Result<T, SensorError> {
+    selection.map_err(|_| SensorError::MuxChannel { sensor, channel })
+}
+
 /// Trait for sensor reading data structures.
 /// Provides compile-time guarantees about the number of values and their conversion to arrays.
 pub trait SensorReadings<const COUNT: usize> {
@@ -67,8 +135,24 @@ pub trait Sensor<const COUNT: usize> {
     /// The type of readings this sensor produces.
     type Readings: SensorReadings<COUNT>;
 
+    /// Sensor name used in errors and logs.
+    const NAME: &'static str;
+
+    /// Deadline for one `read` before it is abandoned as hung.
+    ///
+    /// Sensors whose measurement legitimately takes longer override this.
+    const READ_TIMEOUT_MS: u64 = DEFAULT_SENSOR_READ_TIMEOUT_MS;
+
     /// Read the sensor and return typed readings.
     fn read(&mut self) -> impl Future<Output = Result<Self::Readings, SensorError>>;
+
+    /// Report the sensor's self-assessed health after a successful read.
+    ///
+    /// Sensors without a self-test keep the default, which reports `Ok`.
+    /// Read failures are tracked separately by `SensorHealthTracker`.
+    fn health(&mut self) -> impl Future<Output = SensorHealth> {
+        core::future::ready(SensorHealth::Ok)
+    }
 }
 
 // Type-level index markers
@@ -113,13 +197,98 @@ where
 
     /// Read and write to the values array at the correct indices.
     /// Type safety ensures the readings are stored at the declared START position.
+    ///
+    /// A read that takes longer than `S::READ_TIMEOUT_MS` (a wedged I2C
+    /// device) is abandoned: this sensor's slots are set to
+    /// `MISSING_SENSOR_VALUE` and `SensorError::Timeout` is returned.
     pub async fn read_into(&mut self, values: &mut [i32; MAX_SENSORS]) -> Result<(), SensorError> {
-        let readings = self.sensor.read().await?;
+        let deadline = Duration::from_millis(S::READ_TIMEOUT_MS);
+        let Ok(result) = with_timeout(deadline, self.sensor.read()).await else {
+            values[START..START + COUNT].fill(MISSING_SENSOR_VALUE);
+            return Err(SensorError::Timeout {
+                sensor: S::NAME,
+                operation: "read",
+            });
+        };
+        let readings = result?;
         let data = readings.to_array();
         values[START..START + COUNT].copy_from_slice(&data);
         Ok(())
     }
 
+    /// Read, calibrate, and write to the values array at the correct indices.
+    ///
+    /// Only this sensor's slots are adjusted; other values in the array are untouched.
+    pub async fn read_into_calibrated(
+        &mut self,
+        values: &mut [i32; MAX_SENSORS],
+        calibration: &SensorCalibration,
+    ) -> Result<(), SensorError> {
+        self.read_into(values).await?;
+        calibration.apply_range(values, START, COUNT);
+        Ok(())
+    }
+
+    /// Read with calibration, retrying transient failures with exponential backoff.
+    ///
+    /// Makes up to `SENSOR_READ_ATTEMPTS` attempts, waiting
+    /// `SENSOR_RETRY_BASE_DELAY_MS` before the first retry and doubling the
+    /// wait each time. If every attempt fails, this sensor's slots are set to
+    /// `MISSING_SENSOR_VALUE` and the last error is returned.
+    pub async fn read_into_with_retry(
+        &mut self,
+        values: &mut [i32; MAX_SENSORS],
+        calibration: &SensorCalibration,
+    ) -> Result<(), SensorError> {
+        let mut delay_ms = SENSOR_RETRY_BASE_DELAY_MS;
+        let mut attempt = 1;
+
+        loop {
+            match self.read_into_calibrated(values, calibration).await {
+                Ok(()) => return Ok(()),
+                Err(e) if attempt >= SENSOR_READ_ATTEMPTS => {
+                    values[START..START + COUNT].fill(MISSING_SENSOR_VALUE);
+                    return Err(e);
+                }
+                Err(e) => {
+                    warn!(
+                        "Sensor read attempt {}/{} failed on mux channel {}: {}; retrying in {}ms",
+                        attempt, SENSOR_READ_ATTEMPTS, MUX_CHANNEL, e, delay_ms
+                    );
+                    Timer::after_millis(delay_ms).await;
+                    delay_ms *= 2;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// Read with retry, suppressing values until the sensor has warmed up.
+    ///
+    /// Behaves like [`Self::read_into_with_retry`], but while `warmup` has not
+    /// elapsed this sensor's slots are set to `MISSING_SENSOR_VALUE` so
+    /// unstable readings never reach storage. The warm-up clock starts on the
+    /// first call.
+    pub async fn read_into_with_warmup(
+        &mut self,
+        values: &mut [i32; MAX_SENSORS],
+        calibration: &SensorCalibration,
+        warmup: &mut SensorWarmup,
+    ) -> Result<(), SensorError> {
+        let warmed_up = warmup.poll(Instant::now());
+        self.read_into_with_retry(values, calibration).await?;
+
+        if !warmed_up {
+            values[START..START + COUNT].fill(MISSING_SENSOR_VALUE);
+        }
+        Ok(())
+    }
+
+    /// Query the wrapped sensor's self-reported health.
+    pub async fn health(&mut self) -> SensorHealth {
+        self.sensor.health().await
+    }
+
     /// Get the starting index where this sensor's data is stored.
     pub const fn start_index() -> usize {
         START
@@ -144,10 +313,17 @@ where
 }
 
 pub mod indices {
-    #[cfg(any(feature = "sensor-sht40", feature = "sensor-scd41"))]
+    #[cfg(any(
+        feature = "sensor-sht40",
+        feature = "sensor-scd41",
+        feature = "sensor-bh1750",
+        feature = "sensor-bme280"
+    ))]
     use crate::sensors::IndexedSensor;
     #[cfg(feature = "sensor-bh1750")]
     use crate::sensors::bh1750::BH1750Sensor;
+    #[cfg(feature = "sensor-bme280")]
+    use crate::sensors::bme280::BME280Sensor;
     #[cfg(feature = "sensor-scd41")]
     use crate::sensors::scd41::SCD41Sensor;
     #[cfg(feature = "sensor-sht40")]
@@ -167,26 +343,90 @@ pub mod indices {
     /// - Produces 2 values (temperature, humidity)
     /// - Connected to I2C mux channel 0
     #[cfg(feature = "sensor-sht40")]
-    pub type SHT40Indexed<I> = IndexedSensor<SHT40Sensor<I>, 0, 2, 0>;
+    pub type SHT40Indexed<I> = IndexedSensor<SHT40Sensor<I>, SHT40_START, SHT40_COUNT, 0>;
 
     /// SCD41 sensor configuration:
     /// - Starts at index 2 (CO2)
     /// - Produces 1 value (CO2 ppm)
     /// - Connected to I2C mux channel 1
     #[cfg(feature = "sensor-scd41")]
-    pub type SCD41Indexed<I> = IndexedSensor<SCD41Sensor<I>, 2, 1, 1>;
+    pub type SCD41Indexed<I> = IndexedSensor<SCD41Sensor<I>, SCD41_START, SCD41_COUNT, 1>;
 
     /// BH1750 sensor configuration:
     /// - Starts at index 3 (lux)
     /// - Produces 1 value (lux)
     /// - Connected to I2C mux channel 2
     #[cfg(feature = "sensor-bh1750")]
-    pub type BH1750Indexed<I> = IndexedSensor<BH1750Sensor<I>, 3, 1, 2>;
+    pub type BH1750Indexed<I> = IndexedSensor<BH1750Sensor<I>, BH1750_START, BH1750_COUNT, 2>;
+
+    /// BME280 sensor configuration:
+    /// - Starts at index 4 (pressure)
+    /// - Produces 2 values (pressure, temperature)
+    /// - Connected to I2C mux channel 3
+    #[cfg(feature = "sensor-bme280")]
+    pub type BME280Indexed<I> = IndexedSensor<BME280Sensor<I>, BME280_START, BME280_COUNT, 3>;
 
-    pub const TEMPERATURE: usize = 0;
-    pub const HUMIDITY: usize = 1;
-    pub const CO2: usize = 2;
-    pub const LUX: usize = 3;
+    pub const SHT40_START: usize = 0;
+    pub const SHT40_COUNT: usize = 2;
+    pub const SCD41_START: usize = 2;
+    pub const SCD41_COUNT: usize = 1;
+    pub const BH1750_START: usize = 3;
+    pub const BH1750_COUNT: usize = 1;
+    pub const BME280_START: usize = 4;
+    pub const BME280_COUNT: usize = 2;
+
+    /// `(START, COUNT)` of every sensor above, whether or not its feature is
+    /// enabled, so a clash is caught in every build configuration
+    pub const REGISTERED_RANGES: [(usize, usize); 4] = [
+        (SHT40_START, SHT40_COUNT),
+        (SCD41_START, SCD41_COUNT),
+        (BH1750_START, BH1750_COUNT),
+        (BME280_START, BME280_COUNT),
+    ];
+
+    /// Fail const evaluation if any two `(START, COUNT)` ranges overlap or
+    /// a range runs past `MAX_SENSORS`
+    ///
+    /// Used in a `const _` item, so a bad layout is a compile error rather
+    /// than silently corrupted data:
+    ///
+    /// ```compile_fail
+    /// use baro_core::sensors::indices::assert_no_overlap;
+    ///
+    /// // 0..2 and 1..3 both claim index 1
+    /// const _: () = assert_no_overlap(&[(0, 2), (1, 2)]);
+    /// ```
+    pub const fn assert_no_overlap(ranges: &[(usize, usize)]) {
+        let mut i = 0;
+        while i < ranges.len() {
+            let (start, count) = ranges[i];
+            assert!(
+                start + count <= crate::storage::MAX_SENSORS,
+                "sensor index range runs past MAX_SENSORS"
+            );
+
+            let mut j = i + 1;
+            while j < ranges.len() {
+                let (other_start, other_count) = ranges[j];
+                assert!(
+                    start + count <= other_start || other_start + other_count <= start,
+                    "sensor index ranges overlap"
+                );
+                j += 1;
+            }
+            i += 1;
+        }
+    }
+
+    const _: () = assert_no_overlap(&REGISTERED_RANGES);
+
+    pub const TEMPERATURE: usize = SHT40_START;
+    pub const HUMIDITY: usize = SHT40_START + 1;
+    pub const CO2: usize = SCD41_START;
+    pub const LUX: usize = BH1750_START;
+    pub const PRESSURE: usize = BME280_START;
+    /// Secondary temperature reported by the BME280 (not shown as its own sensor)
+    pub const BME280_TEMPERATURE: usize = BME280_START + 1;
 }
 
 /// Sensor type identifier for selecting which sensor data to display
@@ -200,9 +440,20 @@ pub enum SensorType {
     Co2,
     /// Lux sensor (BH1750 index 3)
     Lux,
+    /// Barometric pressure sensor (BME280 index 4)
+    Pressure,
 }
 
 impl SensorType {
+    /// Every sensor type, in index order
+    pub const ALL: [SensorType; SENSOR_TYPE_COUNT] = [
+        Self::Temperature,
+        Self::Humidity,
+        Self::Co2,
+        Self::Lux,
+        Self::Pressure,
+    ];
+
     /// Get the sensor array index for this sensor type
     pub const fn index(self) -> usize {
         match self {
@@ -210,19 +461,132 @@ impl SensorType {
             Self::Humidity => indices::HUMIDITY,
             Self::Co2 => indices::CO2,
             Self::Lux => indices::LUX,
+            Self::Pressure => indices::PRESSURE,
+        }
+    }
+
+    /// Whether the driver producing this sensor's readings is compiled in
+    pub const fn is_configured(self) -> bool {
+        match self {
+            Self::Temperature | Self::Humidity => cfg!(feature = "sensor-sht40"),
+            Self::Co2 => cfg!(feature = "sensor-scd41"),
+            Self::Lux => cfg!(feature = "sensor-bh1750"),
+            Self::Pressure => cfg!(feature = "sensor-bme280"),
         }
     }
 
     /// Get the unit string for display
+    ///
+    /// `°` is U+00B0, which the ISO-8859-1 mono fonts used by the UI include.
     pub const fn unit(self) -> &'static str {
         match self {
             Self::Temperature => "°C",
             Self::Humidity => "%",
             Self::Co2 => "ppm",
             Self::Lux => "lux",
+            Self::Pressure => "hPa",
+        }
+    }
+
+    /// Unit label shown next to a value in the user's temperature unit
+    pub const fn display_unit(self, temperature_unit: TemperatureUnit) -> &'static str {
+        match self {
+            Self::Temperature => temperature_unit.unit_label(),
+            _ => self.unit(),
+        }
+    }
+
+    /// Convert a stored (metric) reading to the unit it is displayed in
+    ///
+    /// Only temperature follows the unit setting; other sensors are shown
+    /// as stored.
+    pub fn display_value(self, value: f32, temperature_unit: TemperatureUnit) -> f32 {
+        match self {
+            Self::Temperature => temperature_unit.convert(value),
+            _ => value,
+        }
+    }
+
+    /// Number of decimal places shown for this sensor's readings
+    pub const fn decimal_places(self) -> usize {
+        match self {
+            Self::Temperature | Self::Humidity | Self::Pressure => 1,
+            Self::Co2 | Self::Lux => 0,
+        }
+    }
+
+    /// Format a stored reading for display without its unit, e.g. `"77.0"`
+    pub fn format_number(
+        self,
+        value: f32,
+        temperature_unit: TemperatureUnit,
+    ) -> heapless::String<16> {
+        let mut buf = heapless::String::new();
+        let _ = write!(
+            buf,
+            "{:.*}",
+            self.decimal_places(),
+            self.display_value(value, temperature_unit)
+        );
+        buf
+    }
+
+    /// Format a milli-unit reading with its display unit, e.g. `"77.0°F"`
+    ///
+    /// Stored values are always metric; only the displayed text converts.
+    pub fn format(
+        self,
+        value_milli: i32,
+        temperature_unit: TemperatureUnit,
+    ) -> heapless::String<16> {
+        let mut buf = self.format_number(to_float(value_milli), temperature_unit);
+        let _ = buf.push_str(self.display_unit(temperature_unit));
+        buf
+    }
+
+    /// Format a milli-unit difference (e.g. a standard deviation) with its
+    /// display unit
+    ///
+    /// Unlike [`Self::format`], temperatures are only scaled, not offset,
+    /// when converting to Fahrenheit.
+    pub fn format_delta(
+        self,
+        delta_milli: i32,
+        temperature_unit: TemperatureUnit,
+    ) -> heapless::String<16> {
+        let delta = to_float(delta_milli);
+        let value = match self {
+            Self::Temperature => temperature_unit.convert_delta(delta),
+            _ => delta,
+        };
+
+        let mut buf = heapless::String::new();
+        let _ = write!(buf, "{:.*}", self.decimal_places(), value);
+        let _ = buf.push_str(self.display_unit(temperature_unit));
+        buf
+    }
+
+    /// Lowest and highest physically possible readings in milli-units, inclusive
+    ///
+    /// These are the sensors' rated ranges, except that CO₂ starts at
+    /// [`MIN_PLAUSIBLE_CO2_MILLI_PPM`] (the SCD41 reports 0 ppm when a read
+    /// goes wrong, and real air never gets there).
+    pub const fn plausible_range(self) -> (i32, i32) {
+        match self {
+            Self::Temperature => (-40_000, 85_000),
+            Self::Humidity => (0, 100_000),
+            Self::Co2 => (MIN_PLAUSIBLE_CO2_MILLI_PPM, 40_000_000),
+            Self::Lux => (0, 100_000_000),
+            Self::Pressure => (300_000, 1_100_000),
         }
     }
 
+    /// Whether a milli-unit reading could have come from a working sensor
+    pub const fn plausible(self, value_milli: i32) -> bool {
+        let (min, max) = self.plausible_range();
+        value_milli >= min && value_milli <= max
+    }
+
     /// Get the display name for this sensor
     pub const fn name(self) -> &'static str {
         match self {
@@ -230,6 +594,7 @@ impl SensorType {
             Self::Humidity => "Humidity",
             Self::Co2 => "CO2",
             Self::Lux => "Lux",
+            Self::Pressure => "Pressure",
         }
     }
 
@@ -240,15 +605,54 @@ impl SensorType {
             Self::Humidity => "Humid",
             Self::Co2 => "CO2",
             Self::Lux => "Lux",
+            Self::Pressure => "Press",
         }
     }
 }
 
 pub use indices::*;
 
+/// Replace implausible readings with `MISSING_SENSOR_VALUE`
+///
+/// Only configured sensors are checked; the slots of sensors that are not
+/// compiled in are left alone. Each rejected reading is logged, and the
+/// number rejected is returned.
+pub fn reject_implausible(values: &mut [i32; MAX_SENSORS]) -> usize {
+    let mut rejected = 0;
+    for sensor in SensorType::ALL {
+        let slot = &mut values[sensor.index()];
+        if !sensor.is_configured() || *slot == MISSING_SENSOR_VALUE || sensor.plausible(*slot) {
+            continue;
+        }
+        warn!(
+            "Rejected implausible {} reading: {}",
+            sensor.name(),
+            to_float(*slot)
+        );
+        *slot = MISSING_SENSOR_VALUE;
+        rejected += 1;
+    }
+    rejected
+}
+
+/// Whether any sensor driver is compiled in
+///
+/// Without one the firmware never produces readings, so pages show a
+/// "no sensors configured" state instead of placeholders.
+pub const fn any_sensor_configured() -> bool {
+    cfg!(any(
+        feature = "sensor-sht40",
+        feature = "sensor-scd41",
+        feature = "sensor-bh1750",
+        feature = "sensor-bme280"
+    ))
+}
+
 // Re-export for convenience
 #[cfg(feature = "sensor-bh1750")]
 pub use indices::BH1750Indexed;
+#[cfg(feature = "sensor-bme280")]
+pub use indices::BME280Indexed;
 #[cfg(feature = "sensor-scd41")]
 pub use indices::SCD41Indexed;
 #[cfg(feature = "sensor-sht40")]
@@ -256,8 +660,326 @@ pub use indices::SHT40Indexed;
 
 #[cfg(feature = "sensor-bh1750")]
 pub use bh1750::BH1750Sensor;
+#[cfg(feature = "sensor-bme280")]
+pub use bme280::BME280Sensor;
 
 #[cfg(feature = "sensor-scd41")]
 pub use scd41::SCD41Sensor;
 #[cfg(feature = "sensor-sht40")]
 pub use sht40::SHT40Sensor;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{I2cExpect, MockI2c, block_on};
+    use embedded_hal_async::i2c::I2c;
+
+    const ADDRESS: u8 = 0x40;
+    const REGISTER: u8 = 0x00;
+
+    struct RawReading(i32);
+
+    impl SensorReadings<1> for RawReading {
+        fn to_array(self) -> [i32; 1] {
+            [self.0]
+        }
+    }
+
+    /// Minimal driver reading one big-endian 16-bit register.
+    struct RegisterSensor<I>(I);
+
+    impl<I: I2c> Sensor<1> for RegisterSensor<I> {
+        type Readings = RawReading;
+
+        const NAME: &'static str = "register";
+
+        async fn read(&mut self) -> Result<RawReading, SensorError> {
+            let mut buf = [0u8; 2];
+            self.0
+                .write_read(ADDRESS, &[REGISTER], &mut buf)
+                .await
+                .map_err(|_| SensorError::ReadFailed {
+                    sensor: Self::NAME,
+                    operation: "read register",
+                    details: "I2C error",
+                })?;
+            Ok(RawReading(u16::from_be_bytes(buf) as i32))
+        }
+    }
+
+    type Indexed = IndexedSensor<RegisterSensor<MockI2c>, 2, 1, 0>;
+
+    fn read_register() -> I2cExpect {
+        I2cExpect::write_read(ADDRESS, &[REGISTER], &[0x01, 0x2C])
+    }
+
+    #[test]
+    fn retry_recovers_after_two_failures() {
+        let i2c = MockI2c::new([I2cExpect::Fail, I2cExpect::Fail, read_register()]);
+        let mut sensor = Indexed::new(RegisterSensor(i2c));
+        let mut values = [0; MAX_SENSORS];
+
+        let result =
+            block_on(sensor.read_into_with_retry(&mut values, &SensorCalibration::identity()));
+
+        assert!(result.is_ok());
+        assert_eq!(values[2], 300);
+        sensor.sensor.0.done();
+    }
+
+    #[test]
+    fn persistent_failure_marks_only_this_sensor_missing() {
+        const { assert!(SENSOR_READ_ATTEMPTS == 3) };
+        let i2c = MockI2c::new([I2cExpect::Fail, I2cExpect::Fail, I2cExpect::Fail]);
+        let mut sensor = Indexed::new(RegisterSensor(i2c));
+        let mut values = [7; MAX_SENSORS];
+
+        let result =
+            block_on(sensor.read_into_with_retry(&mut values, &SensorCalibration::identity()));
+
+        assert!(matches!(result, Err(SensorError::ReadFailed { .. })));
+        assert_eq!(values[2], MISSING_SENSOR_VALUE);
+        assert_eq!(values[1], 7);
+        assert_eq!(values[3], 7);
+        sensor.sensor.0.done();
+    }
+
+    #[test]
+    fn warmup_suppresses_values_but_still_reads() {
+        let i2c = MockI2c::new([read_register()]);
+        let mut sensor = Indexed::new(RegisterSensor(i2c));
+        let mut warmup = SensorWarmup::new(3600);
+        let mut values = [0; MAX_SENSORS];
+
+        let result = block_on(sensor.read_into_with_warmup(
+            &mut values,
+            &SensorCalibration::identity(),
+            &mut warmup,
+        ));
+
+        assert!(result.is_ok());
+        assert_eq!(values[2], MISSING_SENSOR_VALUE);
+        sensor.sensor.0.done();
+    }
+
+    #[test]
+    fn temperature_formats_in_the_display_unit() {
+        use crate::config::TemperatureUnit;
+
+        let temp = SensorType::Temperature;
+        assert_eq!(temp.format(25_000, TemperatureUnit::Fahrenheit), "77.0°F");
+        assert_eq!(temp.format(25_000, TemperatureUnit::Celsius), "25.0°C");
+        assert_eq!(temp.format(-40_000, TemperatureUnit::Fahrenheit), "-40.0°F");
+        // A difference only scales: 5 °C apart is 9 °F apart
+        assert_eq!(
+            temp.format_delta(5_000, TemperatureUnit::Fahrenheit),
+            "9.0°F"
+        );
+    }
+
+    #[test]
+    fn other_sensors_ignore_the_temperature_unit() {
+        use crate::config::TemperatureUnit;
+
+        for unit in [TemperatureUnit::Celsius, TemperatureUnit::Fahrenheit] {
+            assert_eq!(SensorType::Humidity.format(45_200, unit), "45.2%");
+            assert_eq!(SensorType::Co2.format(415_000, unit), "415ppm");
+        }
+    }
+
+    /// Whether `text` is free of UTF-8 mojibake and drawable by the ISO-8859-1 fonts
+    fn is_clean_latin1(text: &str) -> bool {
+        let bytes = text.as_bytes();
+        let stray_lead = bytes
+            .iter()
+            .enumerate()
+            .any(|(i, &b)| b == 0xC2 && !matches!(bytes.get(i + 1), Some(0x80..=0xBF)));
+        // "Â" is what a double-encoded "°" starts with
+        !stray_lead && !text.contains('Â') && text.chars().all(|c| (c as u32) <= 0xFF)
+    }
+
+    #[test]
+    fn unit_and_name_strings_have_no_encoding_damage() {
+        use crate::config::TemperatureUnit;
+        use crate::metrics::QualityLevel;
+
+        for sensor in SensorType::ALL {
+            for text in [sensor.unit(), sensor.name(), sensor.short_name()] {
+                assert!(is_clean_latin1(text), "{sensor:?}: {text:?}");
+            }
+        }
+        for unit in [TemperatureUnit::Celsius, TemperatureUnit::Fahrenheit] {
+            assert!(is_clean_latin1(unit.unit_label()), "{unit:?}");
+        }
+        for level in [
+            QualityLevel::Excellent,
+            QualityLevel::Good,
+            QualityLevel::Poor,
+            QualityLevel::Bad,
+        ] {
+            for text in [
+                level.label(),
+                level.short_label(),
+                level.status_text(),
+                level.status_icon(),
+            ] {
+                assert!(is_clean_latin1(text), "{level:?}: {text:?}");
+            }
+        }
+        assert_eq!(SensorType::Temperature.unit().as_bytes(), b"\xC2\xB0C");
+    }
+
+    #[test]
+    fn mojibake_is_detected() {
+        assert!(!is_clean_latin1("\u{C2}\u{B0}C"));
+        assert!(!is_clean_latin1("CO\u{2082}"));
+        assert!(is_clean_latin1("°F"));
+    }
+
+    #[test]
+    fn mux_select_failure_is_tagged_with_its_channel() {
+        let selection: Result<(), &str> = Err("NACK");
+
+        use alloc::string::ToString;
+
+        let error = select_mux_channel(selection, "SCD41", 1).unwrap_err();
+
+        assert!(
+            matches!(
+                error,
+                SensorError::MuxChannel {
+                    sensor: "SCD41",
+                    channel: 1
+                }
+            ),
+            "{error:?}"
+        );
+        assert_eq!(
+            error.to_string(),
+            "Sensor 'SCD41' unreachable: I2C mux channel 1 selection failed"
+        );
+    }
+
+    #[test]
+    fn mux_select_success_passes_the_channel_through() {
+        let selection: Result<u8, &str> = Ok(1);
+        assert_eq!(select_mux_channel(selection, "SCD41", 1).unwrap(), 1);
+    }
+
+    #[test]
+    fn registered_index_ranges_do_not_overlap() {
+        indices::assert_no_overlap(&indices::REGISTERED_RANGES);
+        // Touching ranges are fine
+        indices::assert_no_overlap(&[(0, 2), (2, 1), (3, 1)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "sensor index ranges overlap")]
+    fn overlapping_index_ranges_are_rejected() {
+        // 0..2 and 1..3 both claim index 1
+        indices::assert_no_overlap(&[(0, 2), (1, 2)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "sensor index range runs past MAX_SENSORS")]
+    fn index_range_past_the_sample_width_is_rejected() {
+        indices::assert_no_overlap(&[(crate::storage::MAX_SENSORS - 1, 2)]);
+    }
+
+    /// Sensor whose read never completes, like a bus stuck mid-transfer
+    struct HangingSensor;
+
+    impl Sensor<1> for HangingSensor {
+        type Readings = RawReading;
+
+        const NAME: &'static str = "hanging";
+        const READ_TIMEOUT_MS: u64 = 200;
+
+        async fn read(&mut self) -> Result<RawReading, SensorError> {
+            core::future::pending().await
+        }
+    }
+
+    #[test]
+    fn hung_read_times_out_and_marks_the_slot_missing() {
+        let mut sensor = IndexedSensor::<HangingSensor, 2, 1, 0>::new(HangingSensor);
+        let mut values = [7; MAX_SENSORS];
+        let started = Instant::now();
+
+        let result = block_on(sensor.read_into(&mut values));
+
+        assert!(
+            matches!(
+                result,
+                Err(SensorError::Timeout {
+                    sensor: "hanging",
+                    operation: "read"
+                })
+            ),
+            "{result:?}"
+        );
+        assert!(started.elapsed() >= Duration::from_millis(HangingSensor::READ_TIMEOUT_MS));
+        assert_eq!(values[2], MISSING_SENSOR_VALUE);
+        assert_eq!(values[1], 7);
+        assert_eq!(values[3], 7);
+    }
+
+    #[test]
+    fn plausible_ranges_include_both_boundaries() {
+        let cases = [
+            (SensorType::Temperature, -40_000, 85_000),
+            (SensorType::Humidity, 0, 100_000),
+            (SensorType::Co2, 1_000, 40_000_000),
+        ];
+        for (sensor, min, max) in cases {
+            assert_eq!(sensor.plausible_range(), (min, max), "{}", sensor.name());
+            assert!(sensor.plausible(min), "{} at {min}", sensor.name());
+            assert!(sensor.plausible(max), "{} at {max}", sensor.name());
+            assert!(!sensor.plausible(min - 1), "{} below {min}", sensor.name());
+            assert!(!sensor.plausible(max + 1), "{} above {max}", sensor.name());
+        }
+    }
+
+    #[test]
+    fn glitch_readings_are_implausible() {
+        // The SHT40 reports -45 °C when a read goes wrong
+        assert!(!SensorType::Temperature.plausible(-45_000));
+        assert!(!SensorType::Humidity.plausible(-1_000));
+        assert!(!SensorType::Humidity.plausible(125_000));
+        assert!(!SensorType::Co2.plausible(-1));
+        assert!(!SensorType::Co2.plausible(0));
+        assert!(!SensorType::Co2.plausible(65_535_000));
+        assert!(SensorType::Temperature.plausible(21_500));
+        assert!(SensorType::Co2.plausible(612_000));
+    }
+
+    #[test]
+    fn reject_implausible_marks_only_configured_sensors_missing() {
+        let mut values = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+        values[SensorType::Temperature.index()] = -45_000;
+        values[SensorType::Humidity.index()] = 101_000;
+        values[SensorType::Co2.index()] = 612_000;
+        values[SensorType::Pressure.index()] = 1_013_250;
+        let before = values;
+
+        let rejected = reject_implausible(&mut values);
+
+        let expected_rejected = [SensorType::Temperature, SensorType::Humidity]
+            .into_iter()
+            .filter(|sensor| sensor.is_configured())
+            .count();
+        assert_eq!(rejected, expected_rejected);
+        for sensor in [SensorType::Temperature, SensorType::Humidity] {
+            let expected = if sensor.is_configured() {
+                MISSING_SENSOR_VALUE
+            } else {
+                before[sensor.index()]
+            };
+            assert_eq!(values[sensor.index()], expected, "{}", sensor.name());
+        }
+        // Plausible and missing readings pass through untouched
+        assert_eq!(values[SensorType::Co2.index()], 612_000);
+        assert_eq!(values[SensorType::Pressure.index()], 1_013_250);
+        assert_eq!(values[SensorType::Lux.index()], MISSING_SENSOR_VALUE);
+    }
+}
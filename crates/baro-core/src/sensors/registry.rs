@@ -0,0 +1,268 @@
+//! Registry of sensors read into the shared values array
+//!
+//! Sensors are registered as a type-level list rather than trait objects,
+//! so reading them needs no allocation or dynamic dispatch and each sensor's
+//! `impl Future` stays concrete. Adding a sensor is one `register` call:
+//!
+//! ```ignore
+//! let registry = SensorRegistry::new()
+//!     .register(Sht40Slot)
+//!     .register(Bme280Slot);
+//! registry
+//!     .read_each(&mut mux, &mut values, &calibration, &mut |sensors, result| {
+//!         // record health for `sensors`
+//!     })
+//!     .await;
+//! ```
+
+use core::future::Future;
+
+use super::{SensorCalibration, SensorError, SensorHealth, SensorType};
+use crate::storage::MAX_SENSORS;
+
+/// A sensor that can be read into its slice of the values array
+///
+/// `Src` is whatever the sensor is reached through (for the firmware, the
+/// I2C mux), handed to each slot in turn so slots never hold it at the same
+/// time. Implementations normally build an `IndexedSensor` and call one of
+/// its `read_into_*` methods, which only write the sensor's own indices.
+pub trait SensorSlot<Src> {
+    /// Sensors whose values this slot fills, for health tracking
+    const SENSOR_TYPES: &'static [SensorType];
+
+    /// Read the sensor into `values`, returning its self-reported health
+    fn read_into(
+        &mut self,
+        source: &mut Src,
+        values: &mut [i32; MAX_SENSORS],
+        calibration: &SensorCalibration,
+    ) -> impl Future<Output = Result<SensorHealth, SensorError>>;
+}
+
+/// A type-level list of `SensorSlot`s: `()` or `(newest, rest)`
+pub trait SensorList<Src> {
+    /// Read every slot in registration order, reporting each outcome
+    fn read_each<F>(
+        &mut self,
+        source: &mut Src,
+        values: &mut [i32; MAX_SENSORS],
+        calibration: &SensorCalibration,
+        on_result: &mut F,
+    ) -> impl Future<Output = ()>
+    where
+        F: FnMut(&'static [SensorType], Result<SensorHealth, SensorError>);
+}
+
+impl<Src> SensorList<Src> for () {
+    async fn read_each<F>(
+        &mut self,
+        _source: &mut Src,
+        _values: &mut [i32; MAX_SENSORS],
+        _calibration: &SensorCalibration,
+        _on_result: &mut F,
+    ) where
+        F: FnMut(&'static [SensorType], Result<SensorHealth, SensorError>),
+    {
+    }
+}
+
+impl<Src, Head, Tail> SensorList<Src> for (Head, Tail)
+where
+    Head: SensorSlot<Src>,
+    Tail: SensorList<Src>,
+{
+    async fn read_each<F>(
+        &mut self,
+        source: &mut Src,
+        values: &mut [i32; MAX_SENSORS],
+        calibration: &SensorCalibration,
+        on_result: &mut F,
+    ) where
+        F: FnMut(&'static [SensorType], Result<SensorHealth, SensorError>),
+    {
+        // The tail holds the earlier registrations, so it goes first
+        self.1
+            .read_each(source, values, calibration, on_result)
+            .await;
+        let result = self.0.read_into(source, values, calibration).await;
+        on_result(Head::SENSOR_TYPES, result);
+    }
+}
+
+/// Ordered collection of sensors to read each sample
+pub struct SensorRegistry<L> {
+    sensors: L,
+}
+
+impl SensorRegistry<()> {
+    /// An empty registry
+    pub const fn new() -> Self {
+        Self { sensors: () }
+    }
+}
+
+impl Default for SensorRegistry<()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L> SensorRegistry<L> {
+    /// Add a sensor, read after all previously registered ones
+    pub fn register<S>(self, slot: S) -> SensorRegistry<(S, L)> {
+        SensorRegistry {
+            sensors: (slot, self.sensors),
+        }
+    }
+
+    /// Read every registered sensor into `values`, in registration order
+    ///
+    /// `on_result` is called after each sensor with the sensor types it
+    /// covers and the read outcome. A failing sensor does not stop the
+    /// remaining ones from being read. The registry is consumed, so slots
+    /// may borrow per-read state (like a warm-up clock).
+    pub async fn read_each<Src, F>(
+        mut self,
+        source: &mut Src,
+        values: &mut [i32; MAX_SENSORS],
+        calibration: &SensorCalibration,
+        on_result: &mut F,
+    ) where
+        L: SensorList<Src>,
+        F: FnMut(&'static [SensorType], Result<SensorHealth, SensorError>),
+    {
+        self.sensors
+            .read_each(source, values, calibration, on_result)
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensors::{IndexedSensor, Sensor, SensorReadings};
+    use crate::test_util::block_on;
+    use alloc::vec::Vec;
+
+    /// Readings a `FixedSensor` hands back unchanged
+    struct Fixed<const COUNT: usize>([i32; COUNT]);
+
+    impl<const COUNT: usize> SensorReadings<COUNT> for Fixed<COUNT> {
+        fn to_array(self) -> [i32; COUNT] {
+            self.0
+        }
+    }
+
+    /// Sensor that always reads the same values, or always fails
+    struct FixedSensor<const COUNT: usize>(Option<[i32; COUNT]>);
+
+    impl<const COUNT: usize> Sensor<COUNT> for FixedSensor<COUNT> {
+        type Readings = Fixed<COUNT>;
+
+        const NAME: &'static str = "fixed";
+
+        async fn read(&mut self) -> Result<Fixed<COUNT>, SensorError> {
+            self.0.map(Fixed).ok_or(SensorError::ReadFailed {
+                sensor: Self::NAME,
+                operation: "read",
+                details: "scripted failure",
+            })
+        }
+    }
+
+    /// Two values at indices 0..2
+    struct PairSlot(IndexedSensor<FixedSensor<2>, 0, 2, 0>);
+
+    /// One value at index 2
+    struct SingleSlot(IndexedSensor<FixedSensor<1>, 2, 1, 1>);
+
+    impl SensorSlot<()> for PairSlot {
+        const SENSOR_TYPES: &'static [SensorType] =
+            &[SensorType::Temperature, SensorType::Humidity];
+
+        async fn read_into(
+            &mut self,
+            _source: &mut (),
+            values: &mut [i32; MAX_SENSORS],
+            calibration: &SensorCalibration,
+        ) -> Result<SensorHealth, SensorError> {
+            self.0.read_into_calibrated(values, calibration).await?;
+            Ok(SensorHealth::Ok)
+        }
+    }
+
+    impl SensorSlot<()> for SingleSlot {
+        const SENSOR_TYPES: &'static [SensorType] = &[SensorType::Co2];
+
+        async fn read_into(
+            &mut self,
+            _source: &mut (),
+            values: &mut [i32; MAX_SENSORS],
+            calibration: &SensorCalibration,
+        ) -> Result<SensorHealth, SensorError> {
+            self.0.read_into_calibrated(values, calibration).await?;
+            Ok(SensorHealth::Ok)
+        }
+    }
+
+    /// Read `registry` into values pre-filled with 7, collecting each
+    /// slot's sensor types and whether its read succeeded
+    fn read_all<L: SensorList<()>>(
+        registry: SensorRegistry<L>,
+    ) -> ([i32; MAX_SENSORS], Vec<(&'static [SensorType], bool)>) {
+        let mut values = [7; MAX_SENSORS];
+        let mut results = Vec::new();
+        block_on(registry.read_each(
+            &mut (),
+            &mut values,
+            &SensorCalibration::identity(),
+            &mut |sensors, result| results.push((sensors, result.is_ok())),
+        ));
+        (values, results)
+    }
+
+    #[test]
+    fn each_sensor_writes_only_its_own_slice() {
+        let registry = SensorRegistry::new()
+            .register(PairSlot(IndexedSensor::new(FixedSensor(Some([210, 450])))))
+            .register(SingleSlot(IndexedSensor::new(FixedSensor(Some([800])))));
+
+        let (values, results) = read_all(registry);
+
+        assert_eq!(values[..3], [210, 450, 800]);
+        assert!(values[3..].iter().all(|&value| value == 7));
+        // Reported in registration order
+        assert_eq!(
+            results,
+            [
+                (PairSlot::SENSOR_TYPES, true),
+                (SingleSlot::SENSOR_TYPES, true)
+            ]
+        );
+    }
+
+    #[test]
+    fn a_failing_sensor_does_not_stop_or_clobber_the_other() {
+        let registry = SensorRegistry::new()
+            .register(PairSlot(IndexedSensor::new(FixedSensor(None))))
+            .register(SingleSlot(IndexedSensor::new(FixedSensor(Some([800])))));
+
+        let (values, results) = read_all(registry);
+
+        assert_eq!(values[..3], [7, 7, 800]);
+        assert_eq!(
+            results,
+            [
+                (PairSlot::SENSOR_TYPES, false),
+                (SingleSlot::SENSOR_TYPES, true)
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_registry_leaves_values_untouched() {
+        let (values, results) = read_all(SensorRegistry::new());
+        assert!(values.iter().all(|&value| value == 7));
+        assert!(results.is_empty());
+    }
+}
@@ -1,57 +1,151 @@
 use crate::sensors::{SensorError, SensorReadings};
 
-use super::Sensor;
+use super::{SCD41_MEASUREMENT_SECS, Sensor};
+use embassy_time::Timer;
 use embedded_hal_async::i2c::I2c;
 use log::{error, info};
 use scd41_embedded::r#async::Scd41Async;
 
-const CO2_MEASUREMENT_INTERVAL_MS: u32 = 5000;
+const CO2_MEASUREMENT_INTERVAL_MS: u32 = SCD41_MEASUREMENT_SECS * 1000;
+
+/// Fixed I2C address of the SCD41
+const SCD41_I2C_ADDRESS: u8 = 0x62;
+
+/// `set_automatic_self_calibration_enabled` command
+const CMD_SET_AUTOMATIC_SELF_CALIBRATION: u16 = 0x2416;
+
+/// `perform_forced_recalibration` command
+const CMD_PERFORM_FORCED_RECALIBRATION: u16 = 0x362F;
+
+/// Time the sensor takes to execute a forced recalibration
+const FORCED_RECALIBRATION_EXECUTION_MS: u64 = 400;
+
+/// Correction word returned when a forced recalibration failed
+const FORCED_RECALIBRATION_FAILED: u16 = 0xFFFF;
+
+/// `set_temperature_offset` command
+const CMD_SET_TEMPERATURE_OFFSET: u16 = 0x241D;
+
+/// `get_temperature_offset` command
+const CMD_GET_TEMPERATURE_OFFSET: u16 = 0x2318;
+
+/// Time the sensor takes to execute a settings command
+const COMMAND_EXECUTION_MS: u64 = 1;
+
+/// Temperature span of the offset word's fixed-point scale
+const TEMPERATURE_OFFSET_SPAN_MILLI_C: u32 = 175_000;
+
+/// Steps per [`TEMPERATURE_OFFSET_SPAN_MILLI_C`] in the offset word (2¹⁶)
+const TEMPERATURE_OFFSET_SCALE: u64 = 1 << 16;
+
+/// Time after first poll during which SCD41 CO₂ readings are unreliable
+pub const SCD41_WARMUP_SECS: u64 = 60;
 
 /// Typed readings from the SCD41 sensor.
 /// This provides named access to sensor values and ensures type safety.
 pub struct SCD41Readings {
-    pub co2_ppm: i32,
+    /// CO₂ concentration in milli-ppm, like every other stored reading
+    pub co2_milli_ppm: i32,
 }
 
 impl SensorReadings<1> for SCD41Readings {
     fn to_array(self) -> [i32; 1] {
-        [self.co2_ppm]
+        [self.co2_milli_ppm]
     }
 }
 
+/// Single-shot CO₂ reads from an SCD41
+///
+/// Reads only measure: the sensor's settings are sent separately through
+/// [`Scd41Settings`], so a sensor recreated for every read costs no extra
+/// I2C traffic.
 pub struct SCD41Sensor<I> {
     sensor: Scd41Async<I, embassy_time::Delay>,
-    calibrated: bool,
 }
 
 impl<I: I2c> SCD41Sensor<I> {
     pub fn new(i2c: I) -> Self {
         Self {
             sensor: Scd41Async::<I, embassy_time::Delay>::new(i2c, embassy_time::Delay),
-            calibrated: false,
+        }
+    }
+}
+
+/// SCD41 settings that live in sensor RAM, sent only when they change
+///
+/// The sensor forgets its self-calibration flag and temperature offset on
+/// power-off, so both start out pending and are sent by the first
+/// [`Self::apply`]; after that only a changed setting is sent again. Keep
+/// one for the lifetime of the sensor task and apply it between reads,
+/// while the sensor is idle.
+///
+/// # Examples
+/// ```ignore
+/// let mut settings = Scd41Settings::new(config.co2_auto_calibration, config.co2_temperature_offset_milli_c);
+/// loop {
+///     settings.set_auto_calibration(config.co2_auto_calibration);
+///     settings.apply(&mut i2c).await?; // no I2C traffic unless something changed
+///     scd41.read_into_with_warmup(&mut values, &calibration, &mut warmup).await?;
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scd41Settings {
+    auto_calibration: bool,
+    temperature_offset_milli_c: u32,
+    auto_calibration_pending: bool,
+    temperature_offset_pending: bool,
+}
+
+impl Scd41Settings {
+    /// Settings to send on the first [`Self::apply`]
+    pub const fn new(auto_calibration: bool, temperature_offset_milli_c: u32) -> Self {
+        Self {
+            auto_calibration,
+            temperature_offset_milli_c,
+            auto_calibration_pending: true,
+            temperature_offset_pending: true,
         }
     }
 
-    /// Perform calibration and start periodic measurement.
-    /// This should be called once during initialization.
-    async fn initialize(&mut self) -> Result<(), SensorError> {
-        // Enable automatic self-calibration (ASC)
-        // ASC continuously calibrates the sensor over time (requires 7 days of operation)
-        self.sensor
-            .set_automatic_self_calibration(true)
-            .await
-            .map_err(|e| {
-                error!("SCD41 set_automatic_self_calibration failed: {:?}", e);
-                SensorError::InitializationFailed {
-                    sensor: "SCD41",
-                    details: "Failed to enable automatic self-calibration",
-                }
-            })?;
+    /// Choose whether automatic self-calibration (ASC) runs
+    ///
+    /// ASC assumes the sensor sees fresh air (~420 ppm) at least once a
+    /// week; in rooms that never get there it drags readings low, so it can
+    /// be turned off. Setting the value already in use sends nothing.
+    pub fn set_auto_calibration(&mut self, enabled: bool) {
+        if enabled != self.auto_calibration {
+            self.auto_calibration = enabled;
+            self.auto_calibration_pending = true;
+        }
+    }
 
-        info!("SCD41: Automatic self-calibration enabled");
+    /// Set the temperature offset in m°C (see [`scd41_set_temperature_offset`])
+    ///
+    /// Setting the value already in use sends nothing.
+    pub fn set_temperature_offset(&mut self, milli_c: u32) {
+        if milli_c != self.temperature_offset_milli_c {
+            self.temperature_offset_milli_c = milli_c;
+            self.temperature_offset_pending = true;
+        }
+    }
 
-        self.calibrated = true;
+    /// Whether the next [`Self::apply`] has anything to send
+    pub const fn is_pending(&self) -> bool {
+        self.auto_calibration_pending || self.temperature_offset_pending
+    }
 
+    /// Send every setting that changed since it was last sent
+    ///
+    /// A setting that fails to send stays pending, so the next call retries it.
+    pub async fn apply<I: I2c>(&mut self, i2c: &mut I) -> Result<(), SensorError> {
+        if self.auto_calibration_pending {
+            scd41_set_automatic_self_calibration(i2c, self.auto_calibration).await?;
+            self.auto_calibration_pending = false;
+        }
+        if self.temperature_offset_pending {
+            scd41_set_temperature_offset(i2c, self.temperature_offset_milli_c).await?;
+            self.temperature_offset_pending = false;
+        }
         Ok(())
     }
 }
@@ -60,19 +154,12 @@ impl<I: I2c> SCD41Sensor<I> {
 impl<I: I2c> Sensor<1> for SCD41Sensor<I> {
     type Readings = SCD41Readings;
 
-    async fn read(&mut self) -> Result<SCD41Readings, super::SensorError> {
-        // Initialize sensor on first read
-        if !self.calibrated {
-            // Need to initialize before reading
-            self.initialize().await.map_err(|e| {
-                error!("SCD41 initialization failed: {:?}", e);
-                SensorError::InitializationFailed {
-                    sensor: "SCD41",
-                    details: "Failed to initialize sensor before reading",
-                }
-            })?;
-        }
+    const NAME: &'static str = "SCD41";
+
+    /// Covers the 5 s measurement and up to 5 s of data-ready polling
+    const READ_TIMEOUT_MS: u64 = 15_000;
 
+    async fn read(&mut self) -> Result<SCD41Readings, super::SensorError> {
         self.sensor.measure_single_shot().await.map_err(|e| {
             error!("SCD41 single shot measurement failed: {:?}", e);
             SensorError::ReadFailed {
@@ -119,8 +206,342 @@ impl<I: I2c> Sensor<1> for SCD41Sensor<I> {
             }
         })?;
 
-        let co2_ppm = measurement.co2_ppm as i32;
+        let co2_milli_ppm = i32::from(measurement.co2_ppm) * 1000;
+
+        Ok(SCD41Readings { co2_milli_ppm })
+    }
+}
+
+/// Sensirion CRC-8 (polynomial 0x31, init 0xFF) of one data word
+const fn sensirion_crc(word: [u8; 2]) -> u8 {
+    let mut crc: u8 = 0xFF;
+    let mut i = 0;
+    while i < word.len() {
+        crc ^= word[i];
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x31
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        i += 1;
+    }
+    crc
+}
+
+/// Bytes of a command carrying one argument word, as the SCD41 expects them
+const fn command_with_argument(command: u16, argument: u16) -> [u8; 5] {
+    let [cmd_hi, cmd_lo] = command.to_be_bytes();
+    let word = argument.to_be_bytes();
+    [cmd_hi, cmd_lo, word[0], word[1], sensirion_crc(word)]
+}
+
+/// Temperature offset word for `milli_c`, rounded to the nearest step
+///
+/// The datasheet's scaling is `word = offset * 2¹⁶ / 175 °C`; offsets too
+/// large for the word saturate at `u16::MAX`.
+const fn encode_temperature_offset(milli_c: u32) -> u16 {
+    let span = TEMPERATURE_OFFSET_SPAN_MILLI_C as u64;
+    let word = (milli_c as u64 * TEMPERATURE_OFFSET_SCALE + span / 2) / span;
+    if word > u16::MAX as u64 {
+        u16::MAX
+    } else {
+        word as u16
+    }
+}
+
+/// Temperature offset in m°C for a word read back from the sensor
+const fn decode_temperature_offset(word: u16) -> u32 {
+    let span = TEMPERATURE_OFFSET_SPAN_MILLI_C as u64;
+    ((word as u64 * span + TEMPERATURE_OFFSET_SCALE / 2) / TEMPERATURE_OFFSET_SCALE) as u32
+}
+
+/// Map an I2C error from `operation` to a `SensorError`, logging it
+fn i2c_error<E: core::fmt::Debug>(operation: &'static str) -> impl FnOnce(E) -> SensorError {
+    move |e| {
+        error!("SCD41 {} failed: {:?}", operation, e);
+        SensorError::ReadFailed {
+            sensor: "SCD41",
+            operation,
+            details: "I2C communication error",
+        }
+    }
+}
+
+/// Read the one-word response to the previous command, checking its CRC
+async fn read_word<I: I2c>(i2c: &mut I, operation: &'static str) -> Result<u16, SensorError> {
+    let mut response = [0u8; 3];
+    i2c.read(SCD41_I2C_ADDRESS, &mut response)
+        .await
+        .map_err(i2c_error(operation))?;
+
+    let word = [response[0], response[1]];
+    if sensirion_crc(word) != response[2] {
+        return Err(SensorError::ReadFailed {
+            sensor: "SCD41",
+            operation,
+            details: "CRC mismatch",
+        });
+    }
+    Ok(u16::from_be_bytes(word))
+}
+
+/// Enable or disable SCD41 automatic self-calibration (ASC)
+///
+/// ASC continuously corrects the baseline over time (it needs 7 days of
+/// operation to settle). The sensor must be idle, which it is between
+/// single-shot reads. Sends `set_automatic_self_calibration_enabled`
+/// (0x2416); like the temperature offset, the flag is kept in RAM.
+pub async fn scd41_set_automatic_self_calibration<I: I2c>(
+    i2c: &mut I,
+    enabled: bool,
+) -> Result<(), SensorError> {
+    i2c.write(
+        SCD41_I2C_ADDRESS,
+        &command_with_argument(CMD_SET_AUTOMATIC_SELF_CALIBRATION, u16::from(enabled)),
+    )
+    .await
+    .map_err(i2c_error("set automatic self-calibration"))?;
+
+    Timer::after_millis(COMMAND_EXECUTION_MS).await;
+    info!(
+        "SCD41: Automatic self-calibration {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+/// Recalibrate an SCD41 against a known CO₂ level (forced recalibration, FRC)
+///
+/// The sensor must have run for at least 3 minutes in air at `target_ppm`
+/// (e.g. outdoors, ~420 ppm) and be idle, which it is between single-shot
+/// reads. Sends `perform_forced_recalibration` (0x362F) and returns the
+/// correction the sensor applied, in ppm.
+pub async fn scd41_set_forced_recalibration<I: I2c>(
+    i2c: &mut I,
+    target_ppm: u16,
+) -> Result<i16, SensorError> {
+    i2c.write(
+        SCD41_I2C_ADDRESS,
+        &command_with_argument(CMD_PERFORM_FORCED_RECALIBRATION, target_ppm),
+    )
+    .await
+    .map_err(i2c_error("forced recalibration"))?;
+
+    Timer::after_millis(FORCED_RECALIBRATION_EXECUTION_MS).await;
+
+    let correction = read_word(i2c, "read forced recalibration result").await?;
+    if correction == FORCED_RECALIBRATION_FAILED {
+        return Err(SensorError::ReadFailed {
+            sensor: "SCD41",
+            operation: "forced recalibration",
+            details: "Sensor rejected the recalibration (not idle or not warmed up)",
+        });
+    }
+
+    let correction_ppm = (correction as i32 - 0x8000) as i16;
+    info!(
+        "SCD41: Forced recalibration to {} ppm (correction {} ppm)",
+        target_ppm, correction_ppm
+    );
+    Ok(correction_ppm)
+}
+
+/// Set the SCD41 temperature offset, correcting for heat from nearby parts
+///
+/// The offset (in m°C) is subtracted from the sensor's internal temperature,
+/// which feeds its CO₂ compensation, so an enclosure that runs warm stops
+/// skewing CO₂ readings. The sensor must be idle, which it is between
+/// single-shot reads. Sends `set_temperature_offset` (0x241D); the value is
+/// kept in RAM, so it is lost on power-off and has to be set again at boot.
+pub async fn scd41_set_temperature_offset<I: I2c>(
+    i2c: &mut I,
+    milli_c: u32,
+) -> Result<(), SensorError> {
+    i2c.write(
+        SCD41_I2C_ADDRESS,
+        &command_with_argument(
+            CMD_SET_TEMPERATURE_OFFSET,
+            encode_temperature_offset(milli_c),
+        ),
+    )
+    .await
+    .map_err(i2c_error("set temperature offset"))?;
+
+    Timer::after_millis(COMMAND_EXECUTION_MS).await;
+    info!("SCD41: Temperature offset set to {} m°C", milli_c);
+    Ok(())
+}
+
+/// Read the SCD41 temperature offset in m°C
+///
+/// Sends `get_temperature_offset` (0x2318). The sensor stores the offset in
+/// steps of about 2.7 m°C, so the value read back can differ slightly from
+/// the one set.
+pub async fn scd41_get_temperature_offset<I: I2c>(i2c: &mut I) -> Result<u32, SensorError> {
+    i2c.write(SCD41_I2C_ADDRESS, &CMD_GET_TEMPERATURE_OFFSET.to_be_bytes())
+        .await
+        .map_err(i2c_error("get temperature offset"))?;
+
+    Timer::after_millis(COMMAND_EXECUTION_MS).await;
+
+    let word = read_word(i2c, "read temperature offset").await?;
+    Ok(decode_temperature_offset(word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{I2cExpect, MockI2c, block_on};
+
+    const ASC_ON: [u8; 5] = [0x24, 0x16, 0x00, 0x01, 0xB0];
+    const ASC_OFF: [u8; 5] = [0x24, 0x16, 0x00, 0x00, 0x81];
+    /// 5.4 °C, the datasheet's `set_temperature_offset` example
+    const OFFSET_5_4_C: [u8; 5] = [0x24, 0x1D, 0x07, 0xE6, 0x48];
+
+    #[test]
+    fn crc_matches_the_datasheet_example() {
+        assert_eq!(sensirion_crc([0xBE, 0xEF]), 0x92);
+    }
+
+    #[test]
+    fn auto_calibration_commands_carry_the_flag() {
+        let mut i2c = MockI2c::new([
+            I2cExpect::write(SCD41_I2C_ADDRESS, &ASC_ON),
+            I2cExpect::write(SCD41_I2C_ADDRESS, &ASC_OFF),
+        ]);
+
+        block_on(scd41_set_automatic_self_calibration(&mut i2c, true)).unwrap();
+        block_on(scd41_set_automatic_self_calibration(&mut i2c, false)).unwrap();
+        i2c.done();
+    }
+
+    #[test]
+    fn forced_recalibration_sends_the_target_and_decodes_the_correction() {
+        let mut i2c = MockI2c::new([
+            I2cExpect::write(SCD41_I2C_ADDRESS, &[0x36, 0x2F, 0x01, 0x90, 0x4C]),
+            I2cExpect::read(SCD41_I2C_ADDRESS, &[0x7F, 0xCE, 0x7B]),
+        ]);
+
+        let correction = block_on(scd41_set_forced_recalibration(&mut i2c, 400)).unwrap();
+
+        assert_eq!(correction, -50);
+        i2c.done();
+    }
+
+    #[test]
+    fn rejected_forced_recalibration_is_an_error() {
+        let mut i2c = MockI2c::new([
+            I2cExpect::write(SCD41_I2C_ADDRESS, &[0x36, 0x2F, 0x01, 0x90, 0x4C]),
+            I2cExpect::read(SCD41_I2C_ADDRESS, &[0xFF, 0xFF, 0xAC]),
+        ]);
+
+        assert!(block_on(scd41_set_forced_recalibration(&mut i2c, 400)).is_err());
+        i2c.done();
+    }
+
+    #[test]
+    fn temperature_offset_uses_the_sensor_fixed_point_format() {
+        assert_eq!(encode_temperature_offset(0), 0);
+        assert_eq!(encode_temperature_offset(5_400), 0x07E6);
+        assert_eq!(encode_temperature_offset(174_997), u16::MAX);
+        // Saturates once the word overflows
+        assert_eq!(encode_temperature_offset(175_000), u16::MAX);
+        assert_eq!(encode_temperature_offset(u32::MAX), u16::MAX);
+
+        assert_eq!(decode_temperature_offset(0), 0);
+        assert_eq!(decode_temperature_offset(0x0912), 6_200);
+        assert_eq!(decode_temperature_offset(u16::MAX), 174_997);
+        for milli_c in [0, 1_000, 2_500, 5_400, 10_000] {
+            let round_trip = decode_temperature_offset(encode_temperature_offset(milli_c));
+            assert!(
+                round_trip.abs_diff(milli_c) <= 2,
+                "{milli_c} -> {round_trip}"
+            );
+        }
+    }
+
+    #[test]
+    fn temperature_offset_is_written_then_read_from_the_sensor() {
+        let mut i2c = MockI2c::new([
+            I2cExpect::write(SCD41_I2C_ADDRESS, &OFFSET_5_4_C),
+            I2cExpect::write(SCD41_I2C_ADDRESS, &[0x23, 0x18]),
+            I2cExpect::read(SCD41_I2C_ADDRESS, &[0x09, 0x12, 0x63]),
+        ]);
+
+        block_on(scd41_set_temperature_offset(&mut i2c, 5_400)).unwrap();
+        let offset = block_on(scd41_get_temperature_offset(&mut i2c)).unwrap();
+
+        // The datasheet's 6.2 °C example
+        assert_eq!(offset, 6_200);
+        i2c.done();
+    }
+
+    #[test]
+    fn corrupted_offset_response_is_rejected() {
+        let mut i2c = MockI2c::new([
+            I2cExpect::write(SCD41_I2C_ADDRESS, &[0x23, 0x18]),
+            I2cExpect::read(SCD41_I2C_ADDRESS, &[0x09, 0x12, 0x00]),
+        ]);
+
+        assert!(block_on(scd41_get_temperature_offset(&mut i2c)).is_err());
+        i2c.done();
+    }
+
+    #[test]
+    fn settings_are_sent_once_at_start_up() {
+        let mut settings = Scd41Settings::new(true, 5_400);
+        let mut i2c = MockI2c::new([
+            I2cExpect::write(SCD41_I2C_ADDRESS, &ASC_ON),
+            I2cExpect::write(SCD41_I2C_ADDRESS, &OFFSET_5_4_C),
+        ]);
+
+        block_on(settings.apply(&mut i2c)).unwrap();
+        assert!(!settings.is_pending());
+        // Later cycles have nothing to send; the mock panics on any traffic
+        for _ in 0..3 {
+            settings.set_auto_calibration(true);
+            settings.set_temperature_offset(5_400);
+            block_on(settings.apply(&mut i2c)).unwrap();
+        }
+        i2c.done();
+    }
+
+    #[test]
+    fn only_the_changed_setting_is_sent_again() {
+        let mut settings = Scd41Settings::new(true, 5_400);
+        let mut i2c = MockI2c::new([
+            I2cExpect::write(SCD41_I2C_ADDRESS, &ASC_ON),
+            I2cExpect::write(SCD41_I2C_ADDRESS, &OFFSET_5_4_C),
+            I2cExpect::write(SCD41_I2C_ADDRESS, &ASC_OFF),
+        ]);
+        block_on(settings.apply(&mut i2c)).unwrap();
+
+        settings.set_auto_calibration(false);
+        assert!(settings.is_pending());
+        block_on(settings.apply(&mut i2c)).unwrap();
+
+        assert!(!settings.is_pending());
+        i2c.done();
+    }
+
+    #[test]
+    fn failed_setting_is_retried_on_the_next_apply() {
+        let mut settings = Scd41Settings::new(false, 5_400);
+        let mut i2c = MockI2c::new([
+            I2cExpect::write(SCD41_I2C_ADDRESS, &ASC_OFF),
+            I2cExpect::Fail,
+            I2cExpect::write(SCD41_I2C_ADDRESS, &OFFSET_5_4_C),
+        ]);
+
+        assert!(block_on(settings.apply(&mut i2c)).is_err());
+        assert!(settings.is_pending());
+        block_on(settings.apply(&mut i2c)).unwrap();
 
-        Ok(SCD41Readings { co2_ppm })
+        assert!(!settings.is_pending());
+        i2c.done();
     }
 }
@@ -1,3 +1,4 @@
+use crate::metrics::fixed::to_milli;
 use crate::sensors::{SensorError, SensorReadings};
 
 use super::Sensor;
@@ -33,6 +34,8 @@ impl<I: I2c> SHT40Sensor<I> {
 impl<I: I2c> Sensor<2> for SHT40Sensor<I> {
     type Readings = SHT40Readings;
 
+    const NAME: &'static str = "SHT40";
+
     async fn read(&mut self) -> Result<SHT40Readings, super::SensorError> {
         let measurement = self
             .sensor
@@ -47,10 +50,8 @@ impl<I: I2c> Sensor<2> for SHT40Sensor<I> {
                 }
             })?;
 
-        let temperature_milli_celsius =
-            (measurement.temperature_celsius().to_num::<f32>() * 1000.0) as i32;
-        let humidity_milli_percent =
-            (measurement.humidity_percent().to_num::<f32>() * 1000.0) as i32;
+        let temperature_milli_celsius = to_milli(measurement.temperature_celsius().to_num::<f32>());
+        let humidity_milli_percent = to_milli(measurement.humidity_percent().to_num::<f32>());
 
         Ok(SHT40Readings {
             temperature_milli_celsius,
@@ -0,0 +1,266 @@
+//! Single-sample spike rejection
+//!
+//! A glitched read can be within a sensor's rated range yet far from its
+//! neighbours, e.g. one CO₂ reading of 3000 ppm between two of 600.
+//! [`SpikeFilter`] keeps an exponential moving average (EMA) and mean
+//! absolute deviation per sensor, and drops readings that stray too many
+//! deviations from the average so one bad read does not spike the trend
+//! graph.
+//!
+//! A genuine step change (a window opened) is flagged too at first, so after
+//! [`SPIKE_MAX_CONSECUTIVE`] flagged readings in a row the filter accepts the
+//! new level and restarts its average from there.
+
+use super::{SENSOR_TYPE_COUNT, SensorType};
+use crate::metrics::fixed::to_float;
+use crate::storage::{MAX_SENSORS, MISSING_SENSOR_VALUE};
+use log::warn;
+
+/// Default distance from the average, in deviations, beyond which a reading is a spike
+pub const DEFAULT_SPIKE_THRESHOLD_DEVIATIONS: f32 = 4.0;
+
+/// Weight of each new reading in the running average and deviation
+pub const SPIKE_EMA_ALPHA: f32 = 0.2;
+
+/// Readings taken to seed the average before anything can be flagged
+pub const SPIKE_WARMUP_SAMPLES: u8 = 5;
+
+/// Flagged readings in a row after which the new level is accepted
+pub const SPIKE_MAX_CONSECUTIVE: u8 = 3;
+
+/// Smallest deviation assumed for `sensor`, in milli-units
+///
+/// A steady signal drives the measured deviation towards zero, where normal
+/// sensor noise would otherwise count as a spike. `None` for sensors that
+/// are not filtered: light levels legitimately jump when a lamp is switched.
+const fn deviation_floor_milli(sensor: SensorType) -> Option<f32> {
+    match sensor {
+        SensorType::Temperature => Some(200.0),
+        SensorType::Humidity => Some(1_000.0),
+        SensorType::Co2 => Some(20_000.0),
+        SensorType::Pressure => Some(100.0),
+        SensorType::Lux => None,
+    }
+}
+
+/// Running average of one sensor
+#[derive(Debug, Clone, Copy)]
+struct Baseline {
+    mean: f32,
+    deviation: f32,
+    samples: u8,
+    flagged_run: u8,
+}
+
+impl Baseline {
+    /// A baseline that has seen no readings
+    const EMPTY: Self = Self {
+        mean: 0.0,
+        deviation: 0.0,
+        samples: 0,
+        flagged_run: 0,
+    };
+
+    /// Fold `value` into the average
+    fn accept(&mut self, value: f32) {
+        if self.samples == 0 {
+            self.mean = value;
+            self.deviation = 0.0;
+        } else {
+            let error = value - self.mean;
+            self.mean += SPIKE_EMA_ALPHA * error;
+            self.deviation += SPIKE_EMA_ALPHA * (error.abs() - self.deviation);
+        }
+        self.samples = self.samples.saturating_add(1);
+        self.flagged_run = 0;
+    }
+}
+
+/// Flags readings that jump away from each sensor's recent average
+///
+/// Keep one filter for the lifetime of the sensor task and pass every
+/// reading through it, in order.
+///
+/// # Examples
+/// ```ignore
+/// let mut spikes = SpikeFilter::default();
+/// loop {
+///     let mut values = sensors.read_all(&calibration).await?;
+///     spikes.filter(&mut values);
+///     accumulator.add_sample(timestamp, &values).await;
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpikeFilter {
+    threshold_deviations: f32,
+    baselines: [Baseline; SENSOR_TYPE_COUNT],
+}
+
+impl Default for SpikeFilter {
+    fn default() -> Self {
+        Self::new(DEFAULT_SPIKE_THRESHOLD_DEVIATIONS)
+    }
+}
+
+impl SpikeFilter {
+    /// Create a filter flagging readings more than `threshold_deviations` from the average
+    pub const fn new(threshold_deviations: f32) -> Self {
+        Self {
+            threshold_deviations,
+            baselines: [Baseline::EMPTY; SENSOR_TYPE_COUNT],
+        }
+    }
+
+    /// Check one reading, returning `true` if it is a spike
+    ///
+    /// Accepted readings update the sensor's average; spikes do not, unless
+    /// they are the [`SPIKE_MAX_CONSECUTIVE`]th in a row, which restarts the
+    /// average at the new level.
+    pub fn is_spike(&mut self, sensor: SensorType, value_milli: i32) -> bool {
+        let Some(floor) = deviation_floor_milli(sensor) else {
+            return false;
+        };
+        let Some(slot) = SensorType::ALL.iter().position(|&s| s == sensor) else {
+            return false;
+        };
+        let baseline = &mut self.baselines[slot];
+        let value = value_milli as f32;
+
+        if baseline.samples >= SPIKE_WARMUP_SAMPLES {
+            let limit = self.threshold_deviations * baseline.deviation.max(floor);
+            if (value - baseline.mean).abs() > limit {
+                baseline.flagged_run += 1;
+                if baseline.flagged_run < SPIKE_MAX_CONSECUTIVE {
+                    return true;
+                }
+                // Still off after several reads: the level really changed
+                *baseline = Baseline::EMPTY;
+            }
+        }
+
+        baseline.accept(value);
+        false
+    }
+
+    /// Replace spikes in a full reading set with `MISSING_SENSOR_VALUE`
+    ///
+    /// Missing readings are skipped and leave the average untouched. Each
+    /// dropped reading is logged, and the number dropped is returned.
+    pub fn filter(&mut self, values: &mut [i32; MAX_SENSORS]) -> usize {
+        let mut dropped = 0;
+        for sensor in SensorType::ALL {
+            let slot = &mut values[sensor.index()];
+            if *slot == MISSING_SENSOR_VALUE || !self.is_spike(sensor, *slot) {
+                continue;
+            }
+            warn!("Dropped {} spike: {}", sensor.name(), to_float(*slot));
+            *slot = MISSING_SENSOR_VALUE;
+            dropped += 1;
+        }
+        dropped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    /// CO₂ wobbling ±15 ppm around 600 ppm, in milli-ppm
+    fn clean_co2(i: usize) -> i32 {
+        const WOBBLE: [i32; 6] = [0, 8_000, 15_000, 4_000, -9_000, -15_000];
+        600_000 + WOBBLE[i % WOBBLE.len()]
+    }
+
+    #[test]
+    fn clean_signal_is_never_flagged() {
+        let mut filter = SpikeFilter::default();
+        for i in 0..200 {
+            assert!(
+                !filter.is_spike(SensorType::Co2, clean_co2(i)),
+                "reading {i}"
+            );
+        }
+    }
+
+    #[test]
+    fn injected_spike_is_flagged() {
+        let mut filter = SpikeFilter::default();
+        let flagged: Vec<usize> = (0..60)
+            .filter(|&i| {
+                let value = if i == 30 { 3_000_000 } else { clean_co2(i) };
+                filter.is_spike(SensorType::Co2, value)
+            })
+            .collect();
+
+        assert_eq!(flagged, [30]);
+    }
+
+    #[test]
+    fn filter_drops_the_spike_and_keeps_its_neighbours() {
+        let mut filter = SpikeFilter::default();
+        let mut values = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+        for i in 0..20 {
+            values[SensorType::Co2.index()] = clean_co2(i);
+            assert_eq!(filter.filter(&mut values), 0);
+        }
+
+        values[SensorType::Co2.index()] = 3_000_000;
+        values[SensorType::Temperature.index()] = 21_000;
+        assert_eq!(filter.filter(&mut values), 1);
+        assert_eq!(values[SensorType::Co2.index()], MISSING_SENSOR_VALUE);
+        assert_eq!(values[SensorType::Temperature.index()], 21_000);
+
+        // The spike did not drag the average: the next clean read passes
+        values[SensorType::Co2.index()] = clean_co2(20);
+        assert_eq!(filter.filter(&mut values), 0);
+        assert_eq!(values[SensorType::Co2.index()], clean_co2(20));
+    }
+
+    #[test]
+    fn nothing_is_flagged_during_warmup() {
+        let mut filter = SpikeFilter::default();
+        for value in [600_000, 3_000_000, 600_000, 3_000_000, 600_000] {
+            assert!(!filter.is_spike(SensorType::Co2, value));
+        }
+    }
+
+    #[test]
+    fn sustained_step_is_accepted_after_a_few_reads() {
+        let mut filter = SpikeFilter::default();
+        for i in 0..30 {
+            filter.is_spike(SensorType::Co2, clean_co2(i));
+        }
+
+        let flags: Vec<bool> = (0..10)
+            .map(|_| filter.is_spike(SensorType::Co2, 1_500_000))
+            .collect();
+
+        let flagged = usize::from(SPIKE_MAX_CONSECUTIVE) - 1;
+        assert!(flags[..flagged].iter().all(|&f| f), "{flags:?}");
+        assert!(flags[flagged..].iter().all(|&f| !f), "{flags:?}");
+    }
+
+    #[test]
+    fn lower_threshold_flags_smaller_jumps() {
+        let mut strict = SpikeFilter::new(2.0);
+        let mut lenient = SpikeFilter::default();
+        for i in 0..30 {
+            strict.is_spike(SensorType::Co2, clean_co2(i));
+            lenient.is_spike(SensorType::Co2, clean_co2(i));
+        }
+
+        // 60 ppm above the average: 3 deviation floors
+        assert!(strict.is_spike(SensorType::Co2, 660_000));
+        assert!(!lenient.is_spike(SensorType::Co2, 660_000));
+    }
+
+    #[test]
+    fn light_levels_are_not_filtered() {
+        let mut filter = SpikeFilter::default();
+        for _ in 0..20 {
+            filter.is_spike(SensorType::Lux, 50_000);
+        }
+        assert!(!filter.is_spike(SensorType::Lux, 20_000_000));
+    }
+}
@@ -0,0 +1,76 @@
+//! Warm-up suppression for sensors that need time to stabilize
+//!
+//! Some sensors (notably the SCD41) report inaccurate values for a while
+//! after power-on. [`SensorWarmup`] tracks how long a sensor has been polled
+//! so readings taken before the warm-up period has elapsed can be replaced
+//! with `MISSING_SENSOR_VALUE` instead of being stored.
+
+use embassy_time::{Duration, Instant};
+
+/// Tracks the warm-up period of a single sensor
+///
+/// The clock starts the first time the sensor is polled, not when this value
+/// is created, so a sensor that is enabled late still gets its full warm-up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SensorWarmup {
+    duration: Duration,
+    started_at: Option<Instant>,
+}
+
+impl SensorWarmup {
+    /// Create a warm-up tracker that suppresses readings for `warmup_secs`.
+    ///
+    /// A duration of 0 disables suppression.
+    pub const fn new(warmup_secs: u64) -> Self {
+        Self {
+            duration: Duration::from_secs(warmup_secs),
+            started_at: None,
+        }
+    }
+
+    /// Record a poll at `now` and report whether the sensor has warmed up.
+    ///
+    /// The first call starts the warm-up clock.
+    pub fn poll(&mut self, now: Instant) -> bool {
+        let started_at = *self.started_at.get_or_insert(now);
+        now.saturating_duration_since(started_at) >= self.duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at_secs(secs: u64) -> Instant {
+        Instant::from_secs(secs)
+    }
+
+    #[test]
+    fn reads_before_deadline_are_suppressed() {
+        let mut warmup = SensorWarmup::new(60);
+        assert!(!warmup.poll(at_secs(100)));
+        assert!(!warmup.poll(at_secs(159)));
+    }
+
+    #[test]
+    fn reads_after_deadline_pass_through() {
+        let mut warmup = SensorWarmup::new(60);
+        warmup.poll(at_secs(100));
+        assert!(warmup.poll(at_secs(160)));
+        assert!(warmup.poll(at_secs(500)));
+    }
+
+    #[test]
+    fn clock_starts_at_first_poll() {
+        // Created long before the sensor is first read
+        let mut warmup = SensorWarmup::new(60);
+        assert!(!warmup.poll(at_secs(10_000)));
+        assert!(!warmup.poll(at_secs(10_030)));
+        assert!(warmup.poll(at_secs(10_060)));
+    }
+
+    #[test]
+    fn zero_duration_never_suppresses() {
+        assert!(SensorWarmup::new(0).poll(at_secs(0)));
+    }
+}
@@ -3,7 +3,12 @@ use embassy_sync::{blocking_mutex::raw::CriticalSectionRawMutex, pubsub::Publish
 extern crate alloc;
 use alloc::vec::Vec;
 
-use super::{MAX_SENSORS, RawSample, Rollup};
+use super::{MAX_SENSORS, MISSING_SENSOR_VALUE, RawSample, Rollup, RollupTier, Timestamp};
+use crate::config::{
+    DEFAULT_SAMPLE_INTERVAL_SECS, MAX_SAMPLE_INTERVAL_SECS, clamp_rollup_interval_secs,
+};
+use crate::metrics::fixed::average_milli;
+use log::info;
 
 /// Channel capacity for pub-sub events
 /// Set to 8 to handle bursts without blocking the sensor task
@@ -12,17 +17,31 @@ pub const EVENT_CHANNEL_CAPACITY: usize = 8;
 /// Number of subscribers that can listen to rollup events
 /// - Subscriber 0: StorageManager (SD card writer + RAM buffers)
 /// - Subscriber 1: UI rendering task
-pub const EVENT_SUBSCRIBERS: usize = 2;
+/// - Subscriber 2: MQTT publisher (`mqtt` feature)
+/// - Subscriber 3: raw-sample UDP stream (`sample-stream` feature)
+pub const EVENT_SUBSCRIBERS: usize = 4;
 
 /// Number of publishers (just the sensor task)
 pub const EVENT_PUBLISHERS: usize = 1;
 
+/// Largest step between consecutive samples treated as continuous
+///
+/// Several times the maximum sampling interval, so a slow or briefly stalled
+/// sensor task doesn't split windows, and a small backwards correction from
+/// NTP stays in the open window. Anything larger in either direction is
+/// treated as a clock discontinuity, such as the jump from boot-relative time
+/// to Unix time after NTP sync.
+pub const CLOCK_JUMP_THRESHOLD_SECS: u32 = 4 * MAX_SAMPLE_INTERVAL_SECS;
+
 /// Events published by the accumulator to notify subscribers of new data
 #[derive(Debug, Clone, Copy)]
 pub enum RollupEvent {
     /// A new raw sample was recorded
     RawSample(RawSample),
-    /// A 5-minute rollup was completed
+    /// A first-tier rollup was completed
+    ///
+    /// Covers the accumulator's configured rollup interval, which is five
+    /// minutes by default; the name refers to the storage tier.
     Rollup5m(Rollup),
     /// An hourly rollup was completed
     Rollup1h(Rollup),
@@ -38,9 +57,13 @@ pub enum RollupEvent {
 ///
 /// ## Accumulation Windows
 ///
-/// - **5-minute rollups**: 30 raw samples (10s × 30 = 5 minutes)
-/// - **Hourly rollups**: 12 five-minute rollups (5m × 12 = 1 hour)
-/// - **Daily rollups**: 24 hourly rollups (1h × 24 = 24 hours)
+/// Windows are measured in sample time, not sample counts, so they keep
+/// their length whatever the sampling interval:
+///
+/// - **First-tier (`Rollup5m`) rollups**: raw samples spanning the configured
+///   rollup interval (5 minutes by default, 1 minute at the finest)
+/// - **Hourly rollups**: first-tier rollups spanning one hour
+/// - **Daily rollups**: hourly rollups spanning 24 hours
 ///
 /// ## Usage
 ///
@@ -49,17 +72,22 @@ pub enum RollupEvent {
 /// static ROLLUP_CHANNEL: PubSubChannel<...> = PubSubChannel::new();
 ///
 /// let publisher = ROLLUP_CHANNEL.publisher().unwrap();
-/// let mut accumulator = RollupAccumulator::new(publisher);
+/// let mut accumulator = RollupAccumulator::new(publisher, DEFAULT_ROLLUP_INTERVAL_SECS);
 ///
 /// // Add samples every 10 seconds
 /// accumulator.add_sample(timestamp, &sensor_values).await;
 /// ```
 pub struct RollupAccumulator<'a> {
-    /// Buffer for raw samples (up to 30 for 5-minute rollup)
+    /// Length of a first-tier rollup window in seconds
+    rollup_interval_secs: u32,
+    /// Buffer for raw samples in the open first-tier window
     raw_buffer: Vec<RawSample>,
-    /// Buffer for 5-minute rollups (up to 12 for hourly rollup)
+    /// Timestamp of the most recent sample, kept across `flush` so a clock
+    /// jump is still detected when the raw buffer is empty
+    last_sample_ts: Option<u32>,
+    /// Buffer for first-tier rollups in the open hourly window
     rollup_5m_buffer: Vec<Rollup>,
-    /// Buffer for hourly rollups (up to 24 for daily rollup)
+    /// Buffer for hourly rollups in the open daily window
     rollup_1h_buffer: Vec<Rollup>,
     /// Publisher for sending rollup events
     publisher: Publisher<
@@ -74,6 +102,9 @@ pub struct RollupAccumulator<'a> {
 
 impl<'a> RollupAccumulator<'a> {
     /// Create a new rollup accumulator with a publisher
+    ///
+    /// `rollup_interval_secs` sets the first-tier window and is clamped to
+    /// `MIN_ROLLUP_INTERVAL_SECS..=MAX_ROLLUP_INTERVAL_SECS`.
     pub fn new(
         publisher: Publisher<
             'a,
@@ -83,23 +114,73 @@ impl<'a> RollupAccumulator<'a> {
             EVENT_SUBSCRIBERS,
             EVENT_PUBLISHERS,
         >,
+        rollup_interval_secs: u32,
     ) -> Self {
+        let rollup_interval_secs = clamp_rollup_interval_secs(rollup_interval_secs);
+        let hour_secs = RollupTier::Hourly.bucket_secs();
+        let day_secs = RollupTier::Daily.bucket_secs();
+
         Self {
-            raw_buffer: Vec::with_capacity(30),
-            rollup_5m_buffer: Vec::with_capacity(12),
-            rollup_1h_buffer: Vec::with_capacity(24),
+            rollup_interval_secs,
+            raw_buffer: Vec::with_capacity(
+                (rollup_interval_secs / DEFAULT_SAMPLE_INTERVAL_SECS) as usize,
+            ),
+            last_sample_ts: None,
+            rollup_5m_buffer: Vec::with_capacity((hour_secs / rollup_interval_secs) as usize),
+            rollup_1h_buffer: Vec::with_capacity((day_secs / hour_secs) as usize),
             publisher,
         }
     }
 
+    /// Length of a first-tier rollup window in seconds
+    pub fn rollup_interval_secs(&self) -> u32 {
+        self.rollup_interval_secs
+    }
+
+    /// Whether `next_ts` falls outside a window of `window_secs` opened at `start_ts`
+    ///
+    /// A `next_ts` before `start_ts` (a small backwards step within
+    /// `CLOCK_JUMP_THRESHOLD_SECS`) stays in the window.
+    fn window_elapsed(start_ts: Option<u32>, next_ts: u32, window_secs: u32) -> bool {
+        start_ts.is_some_and(|start| {
+            Timestamp::new(next_ts).since(Timestamp::new(start)) >= window_secs
+        })
+    }
+
+    /// Divide per-sensor sums by their counts, marking sensors with no
+    /// readings (and their min/max) as missing.
+    fn finish_averages(
+        sum: &[i64; MAX_SENSORS],
+        count: &[i64; MAX_SENSORS],
+        min: &mut [i32; MAX_SENSORS],
+        max: &mut [i32; MAX_SENSORS],
+    ) -> [i32; MAX_SENSORS] {
+        let mut avg = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+        for i in 0..MAX_SENSORS {
+            if count[i] > 0 {
+                avg[i] = average_milli(sum[i], count[i]);
+            } else {
+                min[i] = MISSING_SENSOR_VALUE;
+                max[i] = MISSING_SENSOR_VALUE;
+            }
+        }
+        avg
+    }
+
     fn compute_rollup(rollup: &[RawSample]) -> Rollup {
-        let mut avg = [0i32; MAX_SENSORS];
+        let mut sum = [0i64; MAX_SENSORS];
+        let mut count = [0i64; MAX_SENSORS];
         let mut min = [i32::MAX; MAX_SENSORS];
         let mut max = [i32::MIN; MAX_SENSORS];
 
         for r in rollup.iter() {
             for i in 0..MAX_SENSORS {
-                avg[i] += r.values[i];
+                // Sensors that failed to read for this sample don't contribute
+                if r.values[i] == MISSING_SENSOR_VALUE {
+                    continue;
+                }
+                sum[i] = sum[i].saturating_add(r.values[i] as i64);
+                count[i] += 1;
                 if r.values[i] < min[i] {
                     min[i] = r.values[i];
                 }
@@ -109,20 +190,28 @@ impl<'a> RollupAccumulator<'a> {
             }
         }
 
-        let count = rollup.len() as i32;
-        avg.iter_mut().for_each(|a| *a /= count);
+        let avg = Self::finish_averages(&sum, &count, &mut min, &mut max);
 
-        Rollup::new(rollup[0].timestamp, &avg, &min, &max)
+        Rollup::new(rollup[0].timestamp, &avg, &min, &max, rollup.len() as u32)
     }
 
+    /// Combine lower-tier rollups, weighting each average by its sample count
     fn compute_rollup_from_rollups(rollup: &[Rollup]) -> Rollup {
-        let mut avg = [0i32; MAX_SENSORS];
+        let mut sum = [0i64; MAX_SENSORS];
+        let mut count = [0i64; MAX_SENSORS];
         let mut min = [i32::MAX; MAX_SENSORS];
         let mut max = [i32::MIN; MAX_SENSORS];
 
         for r in rollup.iter() {
             for i in 0..MAX_SENSORS {
-                avg[i] += r.avg[i];
+                // Skip rollups in which this sensor never produced a reading
+                if r.avg[i] == MISSING_SENSOR_VALUE {
+                    continue;
+                }
+                // Weight each rollup by the samples behind it
+                let weight = r.weight() as i64;
+                sum[i] = sum[i].saturating_add(r.avg[i] as i64 * weight);
+                count[i] += weight;
                 if r.min[i] < min[i] {
                     min[i] = r.min[i];
                 }
@@ -132,36 +221,93 @@ impl<'a> RollupAccumulator<'a> {
             }
         }
 
-        let count = rollup.len() as i32;
-        avg.iter_mut().for_each(|a| *a /= count);
+        let avg = Self::finish_averages(&sum, &count, &mut min, &mut max);
 
-        Rollup::new(rollup[0].start_ts, &avg, &min, &max)
+        let sample_count = rollup
+            .iter()
+            .fold(0u32, |total, r| total.saturating_add(r.sample_count));
+
+        Rollup::new(rollup[0].start_ts, &avg, &min, &max, sample_count)
     }
 
     /// Add a new raw sample to the accumulator
     ///
-    /// This should be called every 10 seconds with fresh sensor readings.
-    /// When 30 samples accumulate, a 5-minute rollup is automatically generated.
-    /// All events are published to subscribers (storage manager, UI tasks, etc.)
+    /// This should be called once per sampling interval with fresh sensor
+    /// readings. When a sample lands a full rollup interval after the first
+    /// one buffered, the buffered window is closed into a first-tier rollup
+    /// and the sample opens the next one. All events are published to
+    /// subscribers (storage manager, UI tasks, etc.)
+    ///
+    /// If `timestamp` is more than `CLOCK_JUMP_THRESHOLD_SECS` away from the
+    /// previous sample in either direction, every open window is flushed
+    /// first so no rollup spans the discontinuity.
     pub async fn add_sample(&mut self, timestamp: u32, values: &[i32; MAX_SENSORS]) {
+        if let Some(last) = self.last_sample_ts.replace(timestamp)
+            && Self::is_clock_jump(last, timestamp)
+        {
+            info!(
+                "Clock jump detected ({} -> {}), flushing open rollup windows",
+                last, timestamp
+            );
+            self.flush_all().await;
+        }
+
         let sample = RawSample::new(timestamp, values);
 
         // Publish raw sample event
         self.publisher.publish(RollupEvent::RawSample(sample)).await;
 
-        // Try to add to buffer; if full, generate rollup
-        if self.raw_buffer.len() < 30 {
-            self.raw_buffer.push(sample);
+        // Close the window once this sample falls past its end
+        let window_start = self.raw_buffer.first().map(|s| s.timestamp);
+        if Self::window_elapsed(window_start, timestamp, self.rollup_interval_secs) {
+            self.generate_5m_rollup().await;
+            self.raw_buffer.clear();
+        }
+        self.raw_buffer.push(sample);
+    }
+
+    /// Emit a partial first-tier rollup from buffered raw samples and reset the window
+    ///
+    /// Call this before shutdown (or periodically) so a power loss mid-window
+    /// doesn't drop up to a rollup interval of data. Does nothing if no raw samples
+    /// are buffered. If `now` is a clock jump away from the last sample, every
+    /// open window is closed, as in `add_sample`.
+    pub async fn flush(&mut self, now: u32) {
+        if self.raw_buffer.is_empty() {
+            return;
+        }
+
+        if self
+            .last_sample_ts
+            .is_some_and(|last| Self::is_clock_jump(last, now))
+        {
+            self.flush_all().await;
         } else {
-            // Buffer is full (30 samples), generate 5-minute rollup
             self.generate_5m_rollup().await;
-            // Clear buffer and add current sample
             self.raw_buffer.clear();
-            self.raw_buffer.push(sample);
         }
     }
 
-    /// Generate a 5-minute rollup from accumulated raw samples
+    /// Whether moving from `last` to `next` crosses a clock discontinuity.
+    fn is_clock_jump(last: u32, next: u32) -> bool {
+        let (last, next) = (Timestamp::new(last), Timestamp::new(next));
+        next.since(last) > CLOCK_JUMP_THRESHOLD_SECS || last.since(next) > CLOCK_JUMP_THRESHOLD_SECS
+    }
+
+    /// Close every open window, publishing partial rollups from whatever has
+    /// accumulated, and start fresh at all tiers.
+    async fn flush_all(&mut self) {
+        self.generate_5m_rollup().await;
+        self.raw_buffer.clear();
+
+        self.generate_1h_rollup().await;
+        self.rollup_5m_buffer.clear();
+
+        self.generate_daily_rollup().await;
+        self.rollup_1h_buffer.clear();
+    }
+
+    /// Generate a first-tier rollup from accumulated raw samples
     async fn generate_5m_rollup(&mut self) {
         if self.raw_buffer.is_empty() {
             return;
@@ -169,21 +315,23 @@ impl<'a> RollupAccumulator<'a> {
 
         let rollup = Self::compute_rollup(&self.raw_buffer);
 
-        // Publish 5-minute rollup event
+        // Publish first-tier rollup event
         self.publisher.publish(RollupEvent::Rollup5m(rollup)).await;
 
-        // Add to hourly buffer
-        if self.rollup_5m_buffer.len() < 12 {
-            self.rollup_5m_buffer.push(rollup);
-        } else {
-            // Buffer is full (12 rollups), generate hourly rollup
+        // Add to hourly buffer, closing the hour this rollup falls past
+        let hour_start = self.rollup_5m_buffer.first().map(|r| r.start_ts);
+        if Self::window_elapsed(
+            hour_start,
+            rollup.start_ts,
+            RollupTier::Hourly.bucket_secs(),
+        ) {
             self.generate_1h_rollup().await;
             self.rollup_5m_buffer.clear();
-            self.rollup_5m_buffer.push(rollup);
         }
+        self.rollup_5m_buffer.push(rollup);
     }
 
-    /// Generate an hourly rollup from accumulated 5-minute rollups
+    /// Generate an hourly rollup from accumulated first-tier rollups
     async fn generate_1h_rollup(&mut self) {
         if self.rollup_5m_buffer.is_empty() {
             return;
@@ -194,15 +342,13 @@ impl<'a> RollupAccumulator<'a> {
         // Publish hourly rollup event
         self.publisher.publish(RollupEvent::Rollup1h(rollup)).await;
 
-        // Add to daily buffer
-        if self.rollup_1h_buffer.len() < 24 {
-            self.rollup_1h_buffer.push(rollup);
-        } else {
-            // Buffer is full (24 rollups), generate daily rollup
+        // Add to daily buffer, closing the day this rollup falls past
+        let day_start = self.rollup_1h_buffer.first().map(|r| r.start_ts);
+        if Self::window_elapsed(day_start, rollup.start_ts, RollupTier::Daily.bucket_secs()) {
             self.generate_daily_rollup().await;
             self.rollup_1h_buffer.clear();
-            self.rollup_1h_buffer.push(rollup);
         }
+        self.rollup_1h_buffer.push(rollup);
     }
 
     /// Generate a daily rollup from accumulated hourly rollups
@@ -219,3 +365,374 @@ impl<'a> RollupAccumulator<'a> {
             .await;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DEFAULT_ROLLUP_INTERVAL_SECS;
+    use crate::test_util::block_on;
+    use embassy_sync::pubsub::{PubSubChannel, Subscriber};
+
+    type Channel = PubSubChannel<
+        CriticalSectionRawMutex,
+        RollupEvent,
+        EVENT_CHANNEL_CAPACITY,
+        EVENT_SUBSCRIBERS,
+        EVENT_PUBLISHERS,
+    >;
+    type EventSubscriber<'a> = Subscriber<
+        'a,
+        CriticalSectionRawMutex,
+        RollupEvent,
+        EVENT_CHANNEL_CAPACITY,
+        EVENT_SUBSCRIBERS,
+        EVENT_PUBLISHERS,
+    >;
+
+    const FIFTY_YEARS_SECS: u32 = 50 * 365 * 24 * 60 * 60;
+    const SAMPLE_SECS: u32 = 10;
+
+    /// Events published so far, split by kind
+    #[derive(Default)]
+    struct Published {
+        raw: usize,
+        rollups: Vec<(RollupTier, Rollup)>,
+    }
+
+    impl Published {
+        fn drain(&mut self, subscriber: &mut EventSubscriber<'_>) {
+            while let Some(event) = subscriber.try_next_message_pure() {
+                match event {
+                    RollupEvent::RawSample(_) => self.raw += 1,
+                    RollupEvent::Rollup5m(r) => self.rollups.push((RollupTier::FiveMinute, r)),
+                    RollupEvent::Rollup1h(r) => self.rollups.push((RollupTier::Hourly, r)),
+                    RollupEvent::RollupDaily(r) => self.rollups.push((RollupTier::Daily, r)),
+                }
+            }
+        }
+
+        fn tier(&self, tier: RollupTier) -> Vec<Rollup> {
+            self.rollups
+                .iter()
+                .filter(|(t, _)| *t == tier)
+                .map(|(_, r)| *r)
+                .collect()
+        }
+    }
+
+    fn values(temperature: i32) -> [i32; MAX_SENSORS] {
+        let mut values = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+        values[0] = temperature;
+        values
+    }
+
+    /// Feed one sample per `SAMPLE_SECS` at `timestamps`, draining events as they come
+    fn feed(
+        accumulator: &mut RollupAccumulator<'_>,
+        subscriber: &mut EventSubscriber<'_>,
+        published: &mut Published,
+        timestamps: impl Iterator<Item = u32>,
+        temperature: i32,
+    ) {
+        for ts in timestamps {
+            block_on(accumulator.add_sample(ts, &values(temperature)));
+            published.drain(subscriber);
+        }
+    }
+
+    /// No rollup at any tier may mix samples from both sides of the jump
+    fn assert_no_rollup_straddles(published: &Published) {
+        for (tier, rollup) in &published.rollups {
+            assert_eq!(
+                rollup.min[0], rollup.max[0],
+                "{tier:?} rollup at {} spans the jump",
+                rollup.start_ts
+            );
+        }
+    }
+
+    #[test]
+    fn forward_jump_of_fifty_years_splits_every_tier() {
+        let channel = Channel::new();
+        let mut subscriber = channel.subscriber().unwrap();
+        let mut accumulator =
+            RollupAccumulator::new(channel.publisher().unwrap(), DEFAULT_ROLLUP_INTERVAL_SECS);
+        let mut published = Published::default();
+
+        // Boot-relative time for 7.5 minutes, then NTP lands 50 years later
+        let boot = (100..550).step_by(SAMPLE_SECS as usize);
+        let synced = 100 + FIFTY_YEARS_SECS;
+        let unix = (synced..synced + 600).step_by(SAMPLE_SECS as usize);
+        feed(
+            &mut accumulator,
+            &mut subscriber,
+            &mut published,
+            boot,
+            1_000,
+        );
+        feed(
+            &mut accumulator,
+            &mut subscriber,
+            &mut published,
+            unix,
+            2_000,
+        );
+
+        assert_eq!(published.raw, 45 + 60);
+        assert_no_rollup_straddles(&published);
+
+        // Both boot-relative windows were closed at the jump
+        let first_tier = published.tier(RollupTier::FiveMinute);
+        assert_eq!(first_tier[0].start_ts, 100);
+        assert_eq!(first_tier[0].sample_count, 30);
+        assert_eq!(first_tier[1].start_ts, 400);
+        assert_eq!(first_tier[1].sample_count, 15);
+        assert_eq!(first_tier[2].start_ts, synced);
+        assert_eq!(published.tier(RollupTier::Hourly)[0].sample_count, 45);
+    }
+
+    #[test]
+    fn backward_jump_after_flush_still_splits() {
+        let channel = Channel::new();
+        let mut subscriber = channel.subscriber().unwrap();
+        let mut accumulator =
+            RollupAccumulator::new(channel.publisher().unwrap(), DEFAULT_ROLLUP_INTERVAL_SECS);
+        let mut published = Published::default();
+
+        let start = 100 + FIFTY_YEARS_SECS;
+        let before = (start..start + 120).step_by(SAMPLE_SECS as usize);
+        feed(
+            &mut accumulator,
+            &mut subscriber,
+            &mut published,
+            before,
+            1_000,
+        );
+
+        // The flush empties the raw buffer; the jump back must still be seen
+        block_on(accumulator.flush(start + 120));
+        published.drain(&mut subscriber);
+
+        let after = (100..1_000).step_by(SAMPLE_SECS as usize);
+        feed(
+            &mut accumulator,
+            &mut subscriber,
+            &mut published,
+            after,
+            2_000,
+        );
+        block_on(accumulator.flush(1_000));
+        published.drain(&mut subscriber);
+
+        assert_no_rollup_straddles(&published);
+        let hourly = published.tier(RollupTier::Hourly);
+        assert_eq!(hourly.len(), 1);
+        assert_eq!(hourly[0].start_ts, start);
+    }
+
+    #[test]
+    fn small_backward_step_stays_in_window() {
+        let channel = Channel::new();
+        let mut subscriber = channel.subscriber().unwrap();
+        let mut accumulator =
+            RollupAccumulator::new(channel.publisher().unwrap(), DEFAULT_ROLLUP_INTERVAL_SECS);
+        let mut published = Published::default();
+
+        let samples = [1_000, 1_010, 1_005, 1_020].into_iter();
+        feed(
+            &mut accumulator,
+            &mut subscriber,
+            &mut published,
+            samples,
+            1_000,
+        );
+
+        assert!(published.rollups.is_empty());
+        block_on(accumulator.flush(1_030));
+        published.drain(&mut subscriber);
+        assert_eq!(published.tier(RollupTier::FiveMinute)[0].sample_count, 4);
+    }
+
+    #[test]
+    fn flush_emits_partial_window_once() {
+        let channel = Channel::new();
+        let mut subscriber = channel.subscriber().unwrap();
+        let mut accumulator =
+            RollupAccumulator::new(channel.publisher().unwrap(), DEFAULT_ROLLUP_INTERVAL_SECS);
+        let mut published = Published::default();
+
+        // Half of a five-minute window: 15 samples averaging 1_700
+        for (i, ts) in (1_000..1_150).step_by(SAMPLE_SECS as usize).enumerate() {
+            block_on(accumulator.add_sample(ts, &values(1_000 + i as i32 * 100)));
+            published.drain(&mut subscriber);
+        }
+        assert!(published.rollups.is_empty());
+
+        block_on(accumulator.flush(1_150));
+        published.drain(&mut subscriber);
+        let partial = published.tier(RollupTier::FiveMinute);
+        assert_eq!(partial.len(), 1);
+        assert_eq!(partial[0].start_ts, 1_000);
+        assert_eq!(partial[0].sample_count, 15);
+        assert_eq!(partial[0].avg[0], 1_700);
+        assert_eq!(partial[0].min[0], 1_000);
+        assert_eq!(partial[0].max[0], 2_400);
+        assert_eq!(partial[0].avg[1], MISSING_SENSOR_VALUE);
+
+        // Nothing is left to flush
+        block_on(accumulator.flush(1_160));
+        published.drain(&mut subscriber);
+        assert_eq!(published.rollups.len(), 1);
+    }
+
+    fn rollup(start_ts: u32, avg: i32, sample_count: u32) -> Rollup {
+        Rollup::new(
+            start_ts,
+            &values(avg),
+            &values(avg),
+            &values(avg),
+            sample_count,
+        )
+    }
+
+    #[test]
+    fn rollup_record_stays_256_bytes() {
+        assert_eq!(core::mem::size_of::<Rollup>(), 256);
+    }
+
+    #[test]
+    fn lopsided_rollups_are_weighted_by_sample_count() {
+        // 30 samples at 10.000 and 3 samples at 21.000
+        let rollups = [rollup(0, 10_000, 30), rollup(300, 21_000, 3)];
+
+        let combined = RollupAccumulator::compute_rollup_from_rollups(&rollups);
+
+        // An unweighted mean of the two averages would be 15.500
+        assert_eq!(combined.avg[0], 11_000);
+        assert_eq!(combined.sample_count, 33);
+        assert_eq!(combined.min[0], 10_000);
+        assert_eq!(combined.max[0], 21_000);
+        assert_eq!(combined.start_ts, 0);
+    }
+
+    #[test]
+    fn legacy_rollups_without_count_weigh_as_one_sample() {
+        let rollups = [rollup(0, 10_000, 0), rollup(300, 20_000, 0)];
+
+        let combined = RollupAccumulator::compute_rollup_from_rollups(&rollups);
+
+        assert_eq!(combined.avg[0], 15_000);
+        assert_eq!(combined.sample_count, 0);
+    }
+
+    #[test]
+    fn sensor_missing_from_a_rollup_ignores_its_weight() {
+        let mut missing = rollup(300, 0, 100);
+        missing.avg[0] = MISSING_SENSOR_VALUE;
+        let rollups = [rollup(0, 10_000, 5), missing];
+
+        let combined = RollupAccumulator::compute_rollup_from_rollups(&rollups);
+
+        assert_eq!(combined.avg[0], 10_000);
+    }
+
+    fn raw(timestamp: u32, temperature: i32) -> RawSample {
+        RawSample::new(timestamp, &values(temperature))
+    }
+
+    #[test]
+    fn rollup_average_rounds_to_nearest() {
+        let rollup = RollupAccumulator::compute_rollup(&[raw(0, 1), raw(10, 2)]);
+        assert_eq!(rollup.avg[0], 2);
+
+        let rollup = RollupAccumulator::compute_rollup(&[raw(0, -1), raw(10, -2)]);
+        assert_eq!(rollup.avg[0], -2);
+    }
+
+    #[test]
+    fn rollup_average_of_extreme_values_does_not_wrap() {
+        let samples: Vec<RawSample> = (0..30).map(|i| raw(i * 10, i32::MAX)).collect();
+        let five_minute = RollupAccumulator::compute_rollup(&samples);
+        assert_eq!(five_minute.avg[0], i32::MAX);
+
+        // A year of 10 s samples behind each rollup still averages exactly
+        let rollups = [
+            rollup(0, i32::MAX, 3_153_600),
+            rollup(300, i32::MAX, 3_153_600),
+        ];
+        let combined = RollupAccumulator::compute_rollup_from_rollups(&rollups);
+        assert_eq!(combined.avg[0], i32::MAX);
+
+        // Absurd weights saturate instead of overflowing
+        let rollups = [
+            rollup(0, i32::MAX, u32::MAX),
+            rollup(300, i32::MAX, u32::MAX),
+        ];
+        let combined = RollupAccumulator::compute_rollup_from_rollups(&rollups);
+        assert_eq!(combined.sample_count, u32::MAX);
+        assert!(combined.avg[0] > 0);
+    }
+
+    /// Rollups published for one sample every `SAMPLE_SECS` over `0..=end_ts`
+    fn rollups_over(rollup_interval_secs: u32, end_ts: u32) -> Published {
+        let channel = Channel::new();
+        let mut subscriber = channel.subscriber().unwrap();
+        let mut accumulator =
+            RollupAccumulator::new(channel.publisher().unwrap(), rollup_interval_secs);
+        let mut published = Published::default();
+
+        feed(
+            &mut accumulator,
+            &mut subscriber,
+            &mut published,
+            (0..=end_ts).step_by(SAMPLE_SECS as usize),
+            21_000,
+        );
+        published
+    }
+
+    #[test]
+    fn sixty_second_window_emits_a_rollup_every_minute() {
+        // Minute windows [0, 60) .. [3600, 3660), the last closed by the 3660 sample
+        let published = rollups_over(60, 3_660);
+
+        let minutes = published.tier(RollupTier::FiveMinute);
+        assert_eq!(minutes.len(), 61);
+        for (i, rollup) in minutes.iter().enumerate() {
+            assert_eq!(rollup.start_ts, i as u32 * 60);
+            assert_eq!(rollup.sample_count, 60 / SAMPLE_SECS);
+        }
+
+        // The hour still closes once, over all 60 minute rollups
+        let hours = published.tier(RollupTier::Hourly);
+        assert_eq!(hours.len(), 1);
+        assert_eq!(hours[0].start_ts, 0);
+        assert_eq!(hours[0].sample_count, 360);
+    }
+
+    #[test]
+    fn default_window_emits_every_five_minutes_over_the_same_feed() {
+        let published = rollups_over(DEFAULT_ROLLUP_INTERVAL_SECS, 3_660);
+
+        let first_tier = published.tier(RollupTier::FiveMinute);
+        assert_eq!(first_tier.len(), 12);
+        assert!(
+            first_tier
+                .iter()
+                .enumerate()
+                .all(|(i, rollup)| rollup.start_ts == i as u32 * 300)
+        );
+        // [3600, 3900) is still open, so nothing has closed the hour yet
+        assert!(published.tier(RollupTier::Hourly).is_empty());
+    }
+
+    #[test]
+    fn rollup_window_is_clamped_to_the_supported_range() {
+        let channel = Channel::new();
+        let short = RollupAccumulator::new(channel.publisher().unwrap(), 10);
+        assert_eq!(short.rollup_interval_secs(), 60);
+        drop(short);
+        let long = RollupAccumulator::new(channel.publisher().unwrap(), 86_400);
+        assert_eq!(long.rollup_interval_secs(), 3_600);
+    }
+}
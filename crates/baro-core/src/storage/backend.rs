@@ -0,0 +1,601 @@
+//! Storage backends behind `StorageManager`
+//!
+//! The manager keeps its RAM ring buffers the same way whichever backend it
+//! uses; the backend decides what outlives a reboot. [`SdCardManager`] writes
+//! everything to the SD card, while [`RamBackend`] keeps a bounded copy in
+//! memory so the device stays usable without a card. `RamBackend` has no
+//! hardware dependencies, so `StorageManager<RamBackend>` also runs the
+//! accumulator → storage pipeline on the host.
+
+use core::cell::{Cell, RefCell};
+
+use crate::config::DeviceConfig;
+use crate::storage::sd_card::{ROLLUP_FILE_1H, ROLLUP_FILE_5M, ROLLUP_FILE_DAILY, SdCardManager};
+
+use super::{Rollup, RollupTier, StorageError, Timestamp};
+
+extern crate alloc;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+// Capacity of each `RamBackend` rollup ring
+const RAM_ROLLUPS_5M_CAPACITY: usize = 288; // 24 hours
+const RAM_ROLLUPS_1H_CAPACITY: usize = 168; // 7 days
+const RAM_ROLLUPS_DAILY_CAPACITY: usize = 30; // 30 days
+
+/// Where `StorageManager` persists rollups, lifetime stats and settings
+///
+/// Rollup tiers are `FiveMinute`, `Hourly` and `Daily`; raw samples only
+/// ever live in the manager's RAM ring buffer.
+pub trait StorageBackend {
+    /// Whether written data survives a reboot
+    fn is_persistent(&self) -> bool;
+
+    /// Size of the storage medium in bytes (0 if unknown or not a card)
+    fn capacity_bytes(&self) -> u64;
+
+    /// Bytes of rollup records currently stored
+    fn rollup_bytes(&self) -> Result<u64, StorageError>;
+
+    /// Append one rollup to `tier`
+    fn append_rollup(&self, tier: RollupTier, rollup: &Rollup) -> Result<(), StorageError>;
+
+    /// Read stored rollups of `tier` whose start timestamp falls within
+    /// `within_window`, returning how many were written to `buffer`
+    fn read_rollups(
+        &self,
+        tier: RollupTier,
+        buffer: &mut [Rollup],
+        within_window: (u32, u32),
+    ) -> Result<usize, StorageError> {
+        self.read_rollups_from(tier, buffer, within_window, &mut 0)
+    }
+
+    /// Like [`Self::read_rollups`], but start at record `*next_record` of
+    /// `tier` and leave it just past the last record examined
+    ///
+    /// Passing the same cursor again resumes where the previous read
+    /// stopped, so paging through a tier with a small buffer reads each
+    /// record once. A cursor is only meaningful until the tier is next
+    /// appended to or pruned.
+    fn read_rollups_from(
+        &self,
+        tier: RollupTier,
+        buffer: &mut [Rollup],
+        within_window: (u32, u32),
+        next_record: &mut u32,
+    ) -> Result<usize, StorageError>;
+
+    /// Drop rollups of `tier` that start before `cutoff`, returning how many
+    /// were removed
+    fn prune(&self, tier: RollupTier, cutoff: u32) -> Result<usize, StorageError>;
+
+    /// Read the serialized lifetime stats, returning the number of bytes read
+    fn read_lifetime_data(&self, buffer: &mut [u8]) -> Result<usize, StorageError>;
+
+    /// Replace the serialized lifetime stats
+    fn overwrite_lifetime_data(&self, data: &[u8]) -> Result<(), StorageError>;
+
+    /// Read the device settings (defaults if none were saved)
+    fn read_device_config(&self) -> Result<DeviceConfig, StorageError>;
+
+    /// Replace the device settings
+    fn write_device_config(&self, device: &DeviceConfig) -> Result<(), StorageError>;
+
+    /// Last known good Unix time, or `None` if it was never saved
+    fn read_last_known_time(&self) -> Result<Option<u32>, StorageError>;
+
+    /// Replace the last known good Unix time
+    fn write_last_known_time(&self, unix_time: u32) -> Result<(), StorageError>;
+}
+
+// ---------------------------------------------------------------------------
+// SD card
+// ---------------------------------------------------------------------------
+
+/// Rollup file holding `tier` on the SD card
+fn rollup_file_for(tier: RollupTier) -> Result<&'static str, StorageError> {
+    match tier {
+        RollupTier::FiveMinute => Ok(ROLLUP_FILE_5M),
+        RollupTier::Hourly => Ok(ROLLUP_FILE_1H),
+        RollupTier::Daily => Ok(ROLLUP_FILE_DAILY),
+        RollupTier::RawSample => Err(StorageError::UnsupportedTier(tier)),
+    }
+}
+
+impl<S, D, T> StorageBackend for SdCardManager<S, D, T>
+where
+    S: embedded_hal::spi::SpiDevice<u8>,
+    D: embedded_hal::delay::DelayNs,
+    T: embedded_sdmmc::TimeSource,
+{
+    fn is_persistent(&self) -> bool {
+        true
+    }
+
+    fn capacity_bytes(&self) -> u64 {
+        self.card_bytes()
+    }
+
+    fn rollup_bytes(&self) -> Result<u64, StorageError> {
+        Ok(SdCardManager::rollup_bytes(self)?)
+    }
+
+    fn append_rollup(&self, tier: RollupTier, rollup: &Rollup) -> Result<(), StorageError> {
+        Ok(self.append_rollup_data(rollup_file_for(tier)?, rollup)?)
+    }
+
+    fn read_rollups_from(
+        &self,
+        tier: RollupTier,
+        buffer: &mut [Rollup],
+        within_window: (u32, u32),
+        next_record: &mut u32,
+    ) -> Result<usize, StorageError> {
+        Ok(self.read_rollup_data(rollup_file_for(tier)?, buffer, within_window, next_record)?)
+    }
+
+    fn prune(&self, tier: RollupTier, _cutoff: u32) -> Result<usize, StorageError> {
+        // Rollup files are append-only and kept forever (see STORAGE.md)
+        rollup_file_for(tier)?;
+        Ok(0)
+    }
+
+    fn read_lifetime_data(&self, buffer: &mut [u8]) -> Result<usize, StorageError> {
+        Ok(SdCardManager::read_lifetime_data(self, buffer)?)
+    }
+
+    fn overwrite_lifetime_data(&self, data: &[u8]) -> Result<(), StorageError> {
+        Ok(SdCardManager::overwrite_lifetime_data(self, data)?)
+    }
+
+    fn read_device_config(&self) -> Result<DeviceConfig, StorageError> {
+        SdCardManager::read_device_config(self)
+    }
+
+    fn write_device_config(&self, device: &DeviceConfig) -> Result<(), StorageError> {
+        SdCardManager::write_device_config(self, device)
+    }
+
+    fn read_last_known_time(&self) -> Result<Option<u32>, StorageError> {
+        Ok(SdCardManager::read_last_known_time(self)?)
+    }
+
+    fn write_last_known_time(&self, unix_time: u32) -> Result<(), StorageError> {
+        Ok(SdCardManager::write_last_known_time(self, unix_time)?)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// RAM
+// ---------------------------------------------------------------------------
+
+/// In-memory backend for running without an SD card
+///
+/// Keeps a bounded ring per rollup tier (24 hours of 5-minute, 7 days of
+/// hourly and 30 days of daily rollups) plus settings, the last known time
+/// and lifetime stats. Nothing survives a reboot.
+#[derive(Debug, Default)]
+pub struct RamBackend {
+    rollups_5m: RefCell<VecDeque<Rollup>>,
+    rollups_1h: RefCell<VecDeque<Rollup>>,
+    rollups_daily: RefCell<VecDeque<Rollup>>,
+    device_config: Cell<DeviceConfig>,
+    last_known_time: Cell<Option<u32>>,
+    lifetime_data: RefCell<Vec<u8>>,
+}
+
+impl RamBackend {
+    /// Create an empty backend with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ring holding `tier` and its capacity
+    fn ring(&self, tier: RollupTier) -> Result<(&RefCell<VecDeque<Rollup>>, usize), StorageError> {
+        match tier {
+            RollupTier::FiveMinute => Ok((&self.rollups_5m, RAM_ROLLUPS_5M_CAPACITY)),
+            RollupTier::Hourly => Ok((&self.rollups_1h, RAM_ROLLUPS_1H_CAPACITY)),
+            RollupTier::Daily => Ok((&self.rollups_daily, RAM_ROLLUPS_DAILY_CAPACITY)),
+            RollupTier::RawSample => Err(StorageError::UnsupportedTier(tier)),
+        }
+    }
+}
+
+impl StorageBackend for RamBackend {
+    fn is_persistent(&self) -> bool {
+        false
+    }
+
+    fn capacity_bytes(&self) -> u64 {
+        0
+    }
+
+    fn rollup_bytes(&self) -> Result<u64, StorageError> {
+        let records = self.rollups_5m.borrow().len()
+            + self.rollups_1h.borrow().len()
+            + self.rollups_daily.borrow().len();
+        Ok((records * core::mem::size_of::<Rollup>()) as u64)
+    }
+
+    fn append_rollup(&self, tier: RollupTier, rollup: &Rollup) -> Result<(), StorageError> {
+        let (ring, capacity) = self.ring(tier)?;
+        let mut ring = ring.borrow_mut();
+        if ring.len() >= capacity {
+            ring.pop_front();
+        }
+        ring.push_back(*rollup);
+        Ok(())
+    }
+
+    fn read_rollups_from(
+        &self,
+        tier: RollupTier,
+        buffer: &mut [Rollup],
+        within_window: (u32, u32),
+        next_record: &mut u32,
+    ) -> Result<usize, StorageError> {
+        let (ring, _) = self.ring(tier)?;
+        let ring = ring.borrow();
+
+        let mut count = 0;
+        for rollup in ring.iter().skip(*next_record as usize) {
+            if count == buffer.len() {
+                break;
+            }
+            *next_record += 1;
+            if rollup.start_ts >= within_window.0 && rollup.start_ts <= within_window.1 {
+                buffer[count] = *rollup;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    fn prune(&self, tier: RollupTier, cutoff: u32) -> Result<usize, StorageError> {
+        let (ring, _) = self.ring(tier)?;
+        let mut ring = ring.borrow_mut();
+        let cutoff = Timestamp::new(cutoff);
+        let mut removed = 0;
+        while ring
+            .front()
+            .is_some_and(|r| Timestamp::new(r.start_ts).before(cutoff))
+        {
+            ring.pop_front();
+            removed += 1;
+        }
+        Ok(removed)
+    }
+
+    fn read_lifetime_data(&self, buffer: &mut [u8]) -> Result<usize, StorageError> {
+        let data = self.lifetime_data.borrow();
+        let len = data.len().min(buffer.len());
+        buffer[..len].copy_from_slice(&data[..len]);
+        Ok(len)
+    }
+
+    fn overwrite_lifetime_data(&self, data: &[u8]) -> Result<(), StorageError> {
+        let mut stored = self.lifetime_data.borrow_mut();
+        stored.clear();
+        stored.extend_from_slice(data);
+        Ok(())
+    }
+
+    fn read_device_config(&self) -> Result<DeviceConfig, StorageError> {
+        Ok(self.device_config.get())
+    }
+
+    fn write_device_config(&self, device: &DeviceConfig) -> Result<(), StorageError> {
+        self.device_config.set(*device);
+        Ok(())
+    }
+
+    fn read_last_known_time(&self) -> Result<Option<u32>, StorageError> {
+        Ok(self.last_known_time.get())
+    }
+
+    fn write_last_known_time(&self, unix_time: u32) -> Result<(), StorageError> {
+        self.last_known_time.set(Some(unix_time));
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Backend selection
+// ---------------------------------------------------------------------------
+
+/// The backend a `StorageManager` was built with
+///
+/// Chosen at boot depending on whether an SD card was found.
+pub enum Backend<S, D, T>
+where
+    S: embedded_hal::spi::SpiDevice<u8>,
+    D: embedded_hal::delay::DelayNs,
+    T: embedded_sdmmc::TimeSource,
+{
+    SdCard(SdCardManager<S, D, T>),
+    Ram(RamBackend),
+}
+
+/// Helper macro to delegate a `StorageBackend` call through every `Backend` variant.
+macro_rules! delegate_backend {
+    ($self:ident, $method:ident $(, $arg:expr)*) => {
+        match $self {
+            Backend::SdCard(backend) => StorageBackend::$method(backend $(, $arg)*),
+            Backend::Ram(backend) => StorageBackend::$method(backend $(, $arg)*),
+        }
+    };
+}
+
+impl<S, D, T> StorageBackend for Backend<S, D, T>
+where
+    S: embedded_hal::spi::SpiDevice<u8>,
+    D: embedded_hal::delay::DelayNs,
+    T: embedded_sdmmc::TimeSource,
+{
+    fn is_persistent(&self) -> bool {
+        delegate_backend!(self, is_persistent)
+    }
+
+    fn capacity_bytes(&self) -> u64 {
+        delegate_backend!(self, capacity_bytes)
+    }
+
+    fn rollup_bytes(&self) -> Result<u64, StorageError> {
+        delegate_backend!(self, rollup_bytes)
+    }
+
+    fn append_rollup(&self, tier: RollupTier, rollup: &Rollup) -> Result<(), StorageError> {
+        delegate_backend!(self, append_rollup, tier, rollup)
+    }
+
+    fn read_rollups_from(
+        &self,
+        tier: RollupTier,
+        buffer: &mut [Rollup],
+        within_window: (u32, u32),
+        next_record: &mut u32,
+    ) -> Result<usize, StorageError> {
+        delegate_backend!(
+            self,
+            read_rollups_from,
+            tier,
+            buffer,
+            within_window,
+            next_record
+        )
+    }
+
+    fn prune(&self, tier: RollupTier, cutoff: u32) -> Result<usize, StorageError> {
+        delegate_backend!(self, prune, tier, cutoff)
+    }
+
+    fn read_lifetime_data(&self, buffer: &mut [u8]) -> Result<usize, StorageError> {
+        delegate_backend!(self, read_lifetime_data, buffer)
+    }
+
+    fn overwrite_lifetime_data(&self, data: &[u8]) -> Result<(), StorageError> {
+        delegate_backend!(self, overwrite_lifetime_data, data)
+    }
+
+    fn read_device_config(&self) -> Result<DeviceConfig, StorageError> {
+        delegate_backend!(self, read_device_config)
+    }
+
+    fn write_device_config(&self, device: &DeviceConfig) -> Result<(), StorageError> {
+        delegate_backend!(self, write_device_config, device)
+    }
+
+    fn read_last_known_time(&self) -> Result<Option<u32>, StorageError> {
+        delegate_backend!(self, read_last_known_time)
+    }
+
+    fn write_last_known_time(&self, unix_time: u32) -> Result<(), StorageError> {
+        delegate_backend!(self, write_last_known_time, unix_time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MAX_SENSORS;
+
+    const START: u32 = 1_700_000_000;
+
+    fn rollup(start_ts: u32) -> Rollup {
+        let values = [1_000; MAX_SENSORS];
+        Rollup::new(start_ts, &values, &values, &values, 1)
+    }
+
+    /// Start timestamps of `tier` rollups within `window`, read through the trait
+    fn stored_starts(
+        backend: &impl StorageBackend,
+        tier: RollupTier,
+        window: (u32, u32),
+    ) -> Vec<u32> {
+        let mut buffer = alloc::vec![Rollup::default(); 16];
+        let count = backend.read_rollups(tier, &mut buffer, window).unwrap();
+        buffer[..count].iter().map(|r| r.start_ts).collect()
+    }
+
+    #[test]
+    fn ram_backend_reads_back_appended_rollups_within_window() {
+        let backend = RamBackend::new();
+        for i in 0..5 {
+            backend
+                .append_rollup(RollupTier::FiveMinute, &rollup(START + i * 300))
+                .unwrap();
+        }
+        backend
+            .append_rollup(RollupTier::Hourly, &rollup(START))
+            .unwrap();
+
+        // Both ends of the window are inclusive, and tiers are kept apart
+        assert_eq!(
+            stored_starts(&backend, RollupTier::FiveMinute, (START + 300, START + 900)),
+            [START + 300, START + 600, START + 900]
+        );
+        assert_eq!(
+            stored_starts(&backend, RollupTier::Hourly, (0, u32::MAX)),
+            [START]
+        );
+        assert!(stored_starts(&backend, RollupTier::Daily, (0, u32::MAX)).is_empty());
+        assert_eq!(
+            backend.rollup_bytes().unwrap(),
+            6 * core::mem::size_of::<Rollup>() as u64
+        );
+    }
+
+    #[test]
+    fn ram_backend_read_stops_at_buffer_length() {
+        let backend = RamBackend::new();
+        for i in 0..5 {
+            backend
+                .append_rollup(RollupTier::Daily, &rollup(START + i * 86_400))
+                .unwrap();
+        }
+
+        let mut buffer = [Rollup::default(); 2];
+        let count = backend
+            .read_rollups(RollupTier::Daily, &mut buffer, (0, u32::MAX))
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(buffer[1].start_ts, START + 86_400);
+    }
+
+    #[test]
+    fn ram_backend_ring_drops_oldest_at_capacity() {
+        let backend = RamBackend::new();
+        for i in 0..RAM_ROLLUPS_DAILY_CAPACITY as u32 + 3 {
+            backend
+                .append_rollup(RollupTier::Daily, &rollup(START + i * 86_400))
+                .unwrap();
+        }
+
+        let mut buffer = alloc::vec![Rollup::default(); RAM_ROLLUPS_DAILY_CAPACITY + 3];
+        let count = backend
+            .read_rollups(RollupTier::Daily, &mut buffer, (0, u32::MAX))
+            .unwrap();
+        assert_eq!(count, RAM_ROLLUPS_DAILY_CAPACITY);
+        assert_eq!(buffer[0].start_ts, START + 3 * 86_400);
+    }
+
+    #[test]
+    fn ram_backend_prunes_before_cutoff() {
+        let backend = RamBackend::new();
+        for i in 0..4 {
+            backend
+                .append_rollup(RollupTier::Hourly, &rollup(START + i * 3600))
+                .unwrap();
+        }
+
+        assert_eq!(
+            backend.prune(RollupTier::Hourly, START + 2 * 3600).unwrap(),
+            2
+        );
+        assert_eq!(
+            stored_starts(&backend, RollupTier::Hourly, (0, u32::MAX)),
+            [START + 2 * 3600, START + 3 * 3600]
+        );
+    }
+
+    #[test]
+    fn raw_sample_tier_is_rejected_like_the_sd_backend() {
+        let backend = RamBackend::new();
+        let tier = RollupTier::RawSample;
+
+        assert!(matches!(
+            backend.append_rollup(tier, &rollup(START)),
+            Err(StorageError::UnsupportedTier(RollupTier::RawSample))
+        ));
+        assert!(matches!(
+            backend.read_rollups(tier, &mut [Rollup::default(); 1], (0, u32::MAX)),
+            Err(StorageError::UnsupportedTier(RollupTier::RawSample))
+        ));
+        assert!(matches!(
+            backend.prune(tier, START),
+            Err(StorageError::UnsupportedTier(RollupTier::RawSample))
+        ));
+        // The SD card maps tiers to files the same way
+        assert!(rollup_file_for(tier).is_err());
+        assert_eq!(
+            rollup_file_for(RollupTier::FiveMinute).unwrap(),
+            ROLLUP_FILE_5M
+        );
+    }
+
+    #[test]
+    fn ram_backend_round_trips_settings_time_and_lifetime_stats() {
+        let backend = RamBackend::new();
+        assert!(!backend.is_persistent());
+        assert_eq!(backend.capacity_bytes(), 0);
+        assert_eq!(
+            backend.read_device_config().unwrap(),
+            DeviceConfig::default()
+        );
+        assert_eq!(backend.read_last_known_time().unwrap(), None);
+
+        let device = DeviceConfig {
+            brightness_percent: 42,
+            ..DeviceConfig::default()
+        };
+        backend.write_device_config(&device).unwrap();
+        backend.write_last_known_time(START).unwrap();
+        backend.overwrite_lifetime_data(&[1, 2, 3]).unwrap();
+
+        assert_eq!(backend.read_device_config().unwrap(), device);
+        assert_eq!(backend.read_last_known_time().unwrap(), Some(START));
+        let mut buffer = [0u8; 8];
+        assert_eq!(backend.read_lifetime_data(&mut buffer).unwrap(), 3);
+        assert_eq!(buffer[..3], [1, 2, 3]);
+    }
+
+    #[test]
+    fn paged_reads_resume_where_the_last_one_stopped() {
+        let backend = RamBackend::new();
+        for i in 0..20 {
+            backend
+                .append_rollup(RollupTier::Hourly, &rollup(START + i * 3600))
+                .unwrap();
+        }
+        let window = (START + 2 * 3600, START + 16 * 3600);
+
+        let mut paged = Vec::new();
+        let mut next_record = 0;
+        let mut page = [Rollup::default(); 4];
+        loop {
+            let count = backend
+                .read_rollups_from(RollupTier::Hourly, &mut page, window, &mut next_record)
+                .unwrap();
+            paged.extend(page[..count].iter().map(|r| r.start_ts));
+            if count < page.len() {
+                break;
+            }
+        }
+
+        assert_eq!(paged, stored_starts(&backend, RollupTier::Hourly, window));
+        assert_eq!(paged.len(), 15);
+        // Every record was examined exactly once
+        assert_eq!(next_record, 20);
+    }
+
+    #[test]
+    fn read_past_the_end_returns_nothing() {
+        let backend = RamBackend::new();
+        backend
+            .append_rollup(RollupTier::Daily, &rollup(START))
+            .unwrap();
+
+        let mut next_record = 1;
+        let count = backend
+            .read_rollups_from(
+                RollupTier::Daily,
+                &mut [Rollup::default(); 4],
+                (0, u32::MAX),
+                &mut next_record,
+            )
+            .unwrap();
+
+        assert_eq!((count, next_record), (0, 1));
+    }
+}
@@ -0,0 +1,181 @@
+//! Crash-safe appends of fixed-size records
+//!
+//! A journaled file is a data file of fixed-size records plus a commit
+//! pointer holding how many of them are complete. A record is written and
+//! flushed past the last committed one before the pointer is advanced, so a
+//! write interrupted by an error or power loss leaves the committed records
+//! exactly as they were: readers stop at the pointer and the next append
+//! overwrites the torn record.
+//!
+//! The SD card implements [`RecordStore`] for its rollup files; the logic
+//! here only sees the store, so it is tested against an in-memory one.
+
+/// A data file of fixed-size records and its commit pointer
+pub trait RecordStore {
+    type Error;
+
+    /// Length of the data file in bytes
+    fn data_len(&mut self) -> Result<u32, Self::Error>;
+
+    /// Stored commit pointer, or `None` if missing or incomplete
+    fn read_commit(&mut self) -> Result<Option<u32>, Self::Error>;
+
+    /// Write `record` as record number `index` of the data file and flush it
+    fn write_record(&mut self, index: u32, record: &[u8]) -> Result<(), Self::Error>;
+
+    /// Replace the commit pointer and flush it
+    fn write_commit(&mut self, count: u32) -> Result<(), Self::Error>;
+}
+
+/// Number of committed records of `record_size` bytes in `store`
+///
+/// The commit pointer is clamped to the whole records actually present. A
+/// missing or incomplete pointer (first write, files predating journaling,
+/// or power loss while rewriting the pointer) falls back to the whole records
+/// in the file, dropping any trailing partial record.
+pub fn committed_records<S: RecordStore>(store: &mut S, record_size: u32) -> Result<u32, S::Error> {
+    let whole_records = store.data_len()? / record_size;
+    Ok(store
+        .read_commit()?
+        .map_or(whole_records, |count| count.min(whole_records)))
+}
+
+/// Append `record` just past the last committed record, then commit it
+///
+/// Returns the index the record was written at. On error the commit pointer
+/// is untouched, so the store still holds the records it held before.
+pub fn append_record<S: RecordStore>(store: &mut S, record: &[u8]) -> Result<u32, S::Error> {
+    let index = committed_records(store, record.len() as u32)?;
+    store.write_record(index, record)?;
+    // The record is durable; publish it
+    store.write_commit(index + 1)?;
+    Ok(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    const RECORD: usize = 8;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct WriteInterrupted;
+
+    /// In-memory store whose next data write can be cut off partway.
+    #[derive(Default)]
+    struct MemStore {
+        data: Vec<u8>,
+        commit: Vec<u8>,
+        /// Bytes of the next record write that land before it fails
+        tear_next_write_at: Option<usize>,
+    }
+
+    impl RecordStore for MemStore {
+        type Error = WriteInterrupted;
+
+        fn data_len(&mut self) -> Result<u32, WriteInterrupted> {
+            Ok(self.data.len() as u32)
+        }
+
+        fn read_commit(&mut self) -> Result<Option<u32>, WriteInterrupted> {
+            Ok(self
+                .commit
+                .as_slice()
+                .try_into()
+                .ok()
+                .map(u32::from_le_bytes))
+        }
+
+        fn write_record(&mut self, index: u32, record: &[u8]) -> Result<(), WriteInterrupted> {
+            let start = index as usize * record.len();
+            let written = self.tear_next_write_at.take().unwrap_or(record.len());
+            let end = start + written;
+            if self.data.len() < end {
+                self.data.resize(end, 0);
+            }
+            self.data[start..end].copy_from_slice(&record[..written]);
+
+            if written < record.len() {
+                return Err(WriteInterrupted);
+            }
+            Ok(())
+        }
+
+        fn write_commit(&mut self, count: u32) -> Result<(), WriteInterrupted> {
+            self.commit = count.to_le_bytes().to_vec();
+            Ok(())
+        }
+    }
+
+    fn record(fill: u8) -> [u8; RECORD] {
+        [fill; RECORD]
+    }
+
+    fn committed(store: &mut MemStore) -> u32 {
+        committed_records(store, RECORD as u32).unwrap()
+    }
+
+    #[test]
+    fn appends_commit_in_order() {
+        let mut store = MemStore::default();
+        for fill in 1..=3 {
+            append_record(&mut store, &record(fill)).unwrap();
+        }
+
+        assert_eq!(committed(&mut store), 3);
+        assert_eq!(&store.data[..RECORD], &record(1));
+        assert_eq!(&store.data[2 * RECORD..], &record(3));
+    }
+
+    #[test]
+    fn interrupted_write_leaves_committed_data_unchanged() {
+        let mut store = MemStore::default();
+        append_record(&mut store, &record(1)).unwrap();
+        append_record(&mut store, &record(2)).unwrap();
+        let before = store.data.clone();
+
+        store.tear_next_write_at = Some(RECORD / 2);
+        assert_eq!(append_record(&mut store, &record(3)), Err(WriteInterrupted));
+
+        // Half a record reached the file, but nothing was committed
+        assert_eq!(store.data.len(), 2 * RECORD + RECORD / 2);
+        assert_eq!(committed(&mut store), 2);
+        assert_eq!(&store.data[..2 * RECORD], before.as_slice());
+    }
+
+    #[test]
+    fn next_append_overwrites_torn_record() {
+        let mut store = MemStore::default();
+        append_record(&mut store, &record(1)).unwrap();
+        store.tear_next_write_at = Some(3);
+        let _ = append_record(&mut store, &record(2));
+
+        assert_eq!(append_record(&mut store, &record(3)), Ok(1));
+        assert_eq!(committed(&mut store), 2);
+        assert_eq!(&store.data[RECORD..], &record(3));
+    }
+
+    #[test]
+    fn missing_pointer_counts_whole_records() {
+        let mut store = MemStore {
+            data: alloc::vec![0xAA; 2 * RECORD + 5],
+            ..MemStore::default()
+        };
+        assert_eq!(committed(&mut store), 2);
+
+        // A pointer torn mid-rewrite reads as missing too
+        store.commit = alloc::vec![1, 0];
+        assert_eq!(committed(&mut store), 2);
+    }
+
+    #[test]
+    fn pointer_is_clamped_to_file_length() {
+        let mut store = MemStore {
+            data: alloc::vec![0xAA; RECORD],
+            ..MemStore::default()
+        };
+        store.write_commit(5).unwrap();
+        assert_eq!(committed(&mut store), 1);
+    }
+}
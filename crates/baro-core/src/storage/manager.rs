@@ -1,11 +1,25 @@
 // cSpell: disable
-use crate::storage::sd_card::{ROLLUP_FILE_1H, ROLLUP_FILE_5M, ROLLUP_FILE_DAILY, SdCardManager};
+use crate::config::{
+    DEFAULT_TREND_WINDOWS, DeviceConfig, MAX_BRIGHTNESS_PERCENT,
+    MAX_CO2_TEMPERATURE_OFFSET_MILLI_C, clamp_rollup_interval_secs, clamp_sample_interval_secs,
+    clamp_touch_threshold, validate_trend_window,
+};
+use crate::ntp::MIN_PLAUSIBLE_UNIX_SECS;
+use crate::storage::backend::{Backend, RamBackend, StorageBackend};
+use crate::storage::sd_card::SdCardManager;
 
-use super::{LifetimeStats, RawSample, Rollup, StorageError, accumulator::RollupEvent};
-use log::{debug, info};
+use crate::metrics::stats::{StatsAccumulator, TrendStats};
+use crate::sensors::SensorType;
+
+use super::{
+    LifetimeStats, MAX_SENSORS, MISSING_SENSOR_VALUE, RawSample, Rollup, RollupTier, StorageError,
+    TimeWindow, Timestamp, accumulator::RollupEvent,
+};
+use log::{debug, error, info, warn};
 
 extern crate alloc;
 use alloc::collections::VecDeque;
+use alloc::vec::Vec;
 
 // Capacity constants for ring buffers
 const RAW_SAMPLES_CAPACITY: usize = 360; // 1 hour (one sample every 10 seconds)
@@ -13,11 +27,152 @@ const ROLLUPS_5M_CAPACITY: usize = 2016; // 7 days (12 per hour * 24 * 7)
 const ROLLUPS_1H_CAPACITY: usize = 720; // 30 days (24 per day * 30)
 const ROLLUPS_DAILY_CAPACITY: usize = 365; // 1 year
 
+/// Rollups read from the backend at a time by `StorageManager::window_stats`
+const WINDOW_STATS_CHUNK_ROLLUPS: usize = 16;
+
+/// Size of one rollup record on the SD card
+const ROLLUP_RECORD_BYTES: u64 = core::mem::size_of::<Rollup>() as u64;
+
+// Retention windows used by `StorageManager::prune` (seconds)
+const RAW_SAMPLES_RETENTION_SECS: u32 = 24 * 60 * 60; // 24 hours
+const ROLLUPS_5M_RETENTION_SECS: u32 = 7 * 24 * 60 * 60; // 7 days
+const ROLLUPS_1H_RETENTION_SECS: u32 = 30 * 24 * 60 * 60; // 30 days
+const ROLLUPS_DAILY_RETENTION_SECS: u32 = 365 * 24 * 60 * 60; // 1 year
+
+/// How often `StorageManager::prune_if_due` runs a pruning pass (seconds)
+///
+/// The shortest retention is a day, so pruning hourly keeps every tier within
+/// an hour of its window without walking the buffers on every sample.
+pub const PRUNE_INTERVAL_SECS: u32 = 60 * 60; // 1 hour
+
+/// How often the current time is written to the SD card (seconds)
+///
+/// Also the offline estimate added on restore: every timestamp issued before
+/// power was lost is at most this far past the persisted value.
+pub const LAST_TIME_PERSIST_INTERVAL_SECS: u32 = 15 * 60; // 15 minutes
+
+/// Estimate the current time after a boot without NTP
+///
+/// Adding the persist interval guarantees the estimate is not earlier than
+/// any timestamp recorded before shutdown, so stored data stays monotonic.
+pub const fn estimate_time_from_last_known(last_known: u32) -> u32 {
+    Timestamp::new(last_known)
+        .advance(LAST_TIME_PERSIST_INTERVAL_SECS)
+        .secs()
+}
+
+/// Number of records removed from each ring buffer by `StorageManager::prune`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruneCounts {
+    pub raw_samples: usize,
+    pub rollups_5m: usize,
+    pub rollups_1h: usize,
+    pub rollups_daily: usize,
+}
+
+impl PruneCounts {
+    /// Total records removed across all tiers
+    pub fn total(&self) -> usize {
+        self.raw_samples + self.rollups_5m + self.rollups_1h + self.rollups_daily
+    }
+}
+
+/// Number of records `StorageManager::process_event` rejected per ring buffer
+/// because they were not newer than the newest record already stored
+///
+/// Non-zero counts usually mean the clock was adjusted backwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutOfOrderCounts {
+    pub raw_samples: usize,
+    pub rollups_5m: usize,
+    pub rollups_1h: usize,
+    pub rollups_daily: usize,
+}
+
+impl OutOfOrderCounts {
+    /// Total records rejected across all tiers
+    pub fn total(&self) -> usize {
+        self.raw_samples + self.rollups_5m + self.rollups_1h + self.rollups_daily
+    }
+}
+
+/// Snapshot of how full the RAM ring buffers and SD card are
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StorageCapacity {
+    /// Raw samples currently held in RAM
+    pub raw_used: usize,
+    /// Raw sample ring buffer capacity
+    pub raw_cap: usize,
+    /// Bytes of rollup records written to the SD card
+    pub rollup_bytes: u64,
+    /// Total SD card size in bytes (0 if unknown or running without a card)
+    pub card_bytes: u64,
+}
+
+impl StorageCapacity {
+    /// Percentage of the SD card used by rollup files (0 if the card size is unknown)
+    pub fn card_percent_used(&self) -> u8 {
+        if self.card_bytes == 0 {
+            return 0;
+        }
+        (self.rollup_bytes.saturating_mul(100) / self.card_bytes).min(100) as u8
+    }
+}
+
+/// Pop records from the front of `buffer` whose timestamp is older than `now - retention_secs`.
+///
+/// Buffers are appended in time order, so pruning stops at the first record
+/// still inside the retention window.
+fn prune_front<R>(
+    buffer: &mut VecDeque<R>,
+    now: u32,
+    retention_secs: u32,
+    timestamp: impl Fn(&R) -> u32,
+) -> usize {
+    let cutoff = Timestamp::new(now).window_start(retention_secs);
+    let mut removed = 0;
+    while buffer
+        .front()
+        .is_some_and(|r| Timestamp::new(timestamp(r)).before(cutoff))
+    {
+        buffer.pop_front();
+        removed += 1;
+    }
+    removed
+}
+
+/// Append `record` to `buffer` if it is strictly newer than the newest record
+///
+/// Drops the oldest record when `buffer` is at `capacity`. Returns `false`,
+/// leaving `buffer` untouched, for a duplicate or out-of-order record so every
+/// buffer stays strictly increasing in time.
+fn push_if_newer<R>(
+    buffer: &mut VecDeque<R>,
+    capacity: usize,
+    record: R,
+    timestamp: impl Fn(&R) -> u32,
+) -> bool {
+    if buffer
+        .back()
+        .is_some_and(|newest| timestamp(&record) <= timestamp(newest))
+    {
+        return false;
+    }
+
+    if buffer.len() >= capacity {
+        buffer.pop_front();
+    }
+    buffer.push_back(record);
+    true
+}
+
 /// Storage manager that maintains ring buffers in RAM and handles SD card persistence
 ///
 /// This task subscribes to rollup events and:
 /// 1. Stores data in RAM ring buffers for fast UI access
-/// 2. Writes data to SD card for long-term persistence
+/// 2. Writes data to its [`StorageBackend`] — the SD card for long-term
+///    persistence, or [`RamBackend`] when there is no card, in which case
+///    nothing survives a reboot but every query works the same
 ///
 /// ## Memory Usage
 ///
@@ -26,12 +181,10 @@ const ROLLUPS_DAILY_CAPACITY: usize = 365; // 1 year
 /// - Hourly rollups: 720 × 256 bytes = 180 KB (30 days)
 /// - Daily rollups: 365 × 256 bytes = 91 KB (1 year)
 /// - **Total: ~822 KB** (allocated from PSRAM heap, not static memory)
-pub struct StorageManager<S, D, T>
-where
-    S: embedded_hal::spi::SpiDevice<u8>,
-    D: embedded_hal::delay::DelayNs,
-    T: embedded_sdmmc::TimeSource,
-{
+///
+/// Generic over the backend so the manager can be exercised on the host with
+/// [`RamBackend`]; the firmware uses [`Backend`] to pick one at boot.
+pub struct StorageManager<B: StorageBackend> {
     /// Ring buffer for raw samples (last 1 hour for 5m/30m/1h graphs)
     raw_samples: VecDeque<RawSample>,
     /// Ring buffer for 5-minute rollups (last 7 days for 24h and 7d graphs)
@@ -42,70 +195,143 @@ where
     rollups_daily: VecDeque<Rollup>,
     /// Lifetime statistics
     lifetime_stats: LifetimeStats,
-    /// SD Card storage
-    sd_card_manager: SdCardManager<S, D, T>,
+    /// Bytes of rollup records on the SD card (tracked on append)
+    rollup_bytes: u64,
+    /// Last time written by `persist_time_if_due`
+    last_time_persisted: Option<u32>,
+    /// Time of the last pass run by `prune_if_due`
+    last_pruned: Option<u32>,
+    /// Duplicate or out-of-order records rejected since boot
+    out_of_order: OutOfOrderCounts,
+    /// Running statistics over every daily rollup stored, for the all-time
+    /// summary (scanned once in `init`, then updated as rollups arrive)
+    daily_totals: [StatsAccumulator; MAX_SENSORS],
+    /// Where rollups, lifetime stats and settings are persisted
+    backend: B,
 }
 
-impl<S, D, T> StorageManager<S, D, T>
+impl<S, D, T> StorageManager<Backend<S, D, T>>
 where
     S: embedded_hal::spi::SpiDevice<u8>,
     D: embedded_hal::delay::DelayNs,
     T: embedded_sdmmc::TimeSource,
 {
+    /// Create a manager persisting to the SD card
     pub fn new(sd_card_manager: SdCardManager<S, D, T>) -> Self {
+        Self::with_backend(Backend::SdCard(sd_card_manager))
+    }
+
+    /// Create a manager that keeps everything in RAM, for running without an SD card
+    pub fn in_memory() -> Self {
+        Self::with_backend(Backend::Ram(RamBackend::new()))
+    }
+}
+
+impl<B: StorageBackend> StorageManager<B> {
+    /// Create a manager persisting through `backend`
+    pub fn with_backend(backend: B) -> Self {
         Self {
             raw_samples: VecDeque::with_capacity(RAW_SAMPLES_CAPACITY),
             rollups_5m: VecDeque::with_capacity(ROLLUPS_5M_CAPACITY),
             rollups_1h: VecDeque::with_capacity(ROLLUPS_1H_CAPACITY),
             rollups_daily: VecDeque::with_capacity(ROLLUPS_DAILY_CAPACITY),
             lifetime_stats: LifetimeStats::default(),
-            sd_card_manager,
+            rollup_bytes: 0,
+            last_time_persisted: None,
+            last_pruned: None,
+            out_of_order: OutOfOrderCounts::default(),
+            daily_totals: [StatsAccumulator::new(); MAX_SENSORS],
+            backend,
         }
     }
 
+    /// Whether stored data survives a reboot (false without an SD card)
+    pub fn is_persistent(&self) -> bool {
+        self.backend.is_persistent()
+    }
+
+    /// Backend the manager persists through
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
     pub async fn init(&mut self, time: u32) -> Result<(), StorageError> {
-        info!(" Initializing storage manager, loading data from SD card...");
+        info!(
+            " Initializing storage manager, loading data from {}...",
+            if self.is_persistent() {
+                "SD card"
+            } else {
+                "RAM"
+            }
+        );
 
         let lifetime_data_buffer = &mut [0u8; core::mem::size_of::<LifetimeStats>()];
-        self.sd_card_manager
-            .read_lifetime_data(lifetime_data_buffer)?;
+        self.backend.read_lifetime_data(lifetime_data_buffer)?;
         self.lifetime_stats = LifetimeStats::from(lifetime_data_buffer);
 
         // Load 5-minute rollups (last 7 days)
-        let window_5m = (time.saturating_sub(7 * 24 * 60 * 60), time);
+        let window_5m = (
+            Timestamp::new(time).window_start(7 * 24 * 60 * 60).secs(),
+            time,
+        );
         let mut buffer_5m = alloc::vec![Rollup::default(); ROLLUPS_5M_CAPACITY];
         let count_5m =
-            self.sd_card_manager
-                .read_rollup_data(ROLLUP_FILE_5M, &mut buffer_5m, window_5m)?;
-        info!(" Loaded {} 5-minute rollups from SD card", count_5m);
+            self.backend
+                .read_rollups(RollupTier::FiveMinute, &mut buffer_5m, window_5m)?;
+        info!(" Loaded {} 5-minute rollups", count_5m);
         for rollup in &buffer_5m[..count_5m] {
-            self.rollups_5m.push_back(*rollup);
+            if !push_if_newer(&mut self.rollups_5m, ROLLUPS_5M_CAPACITY, *rollup, |r| {
+                r.start_ts
+            }) {
+                self.out_of_order.rollups_5m += 1;
+            }
         }
 
         // Load hourly rollups (last 30 days)
-        let window_1h = (time.saturating_sub(30 * 24 * 60 * 60), time);
+        let window_1h = (
+            Timestamp::new(time).window_start(30 * 24 * 60 * 60).secs(),
+            time,
+        );
         let mut buffer_1h = alloc::vec![Rollup::default(); ROLLUPS_1H_CAPACITY];
-        let count_1h =
-            self.sd_card_manager
-                .read_rollup_data(ROLLUP_FILE_1H, &mut buffer_1h, window_1h)?;
-        info!(" Loaded {} hourly rollups from SD card", count_1h);
+        let count_1h = self
+            .backend
+            .read_rollups(RollupTier::Hourly, &mut buffer_1h, window_1h)?;
+        info!(" Loaded {} hourly rollups", count_1h);
         for rollup in &buffer_1h[..count_1h] {
-            self.rollups_1h.push_back(*rollup);
+            if !push_if_newer(&mut self.rollups_1h, ROLLUPS_1H_CAPACITY, *rollup, |r| {
+                r.start_ts
+            }) {
+                self.out_of_order.rollups_1h += 1;
+            }
         }
 
         // Load daily rollups (last 365 days)
-        let window_daily = (time.saturating_sub(365 * 24 * 60 * 60), time);
+        let window_daily = (
+            Timestamp::new(time).window_start(365 * 24 * 60 * 60).secs(),
+            time,
+        );
         let mut buffer_daily = alloc::vec![Rollup::default(); ROLLUPS_DAILY_CAPACITY];
-        let count_daily = self.sd_card_manager.read_rollup_data(
-            ROLLUP_FILE_DAILY,
-            &mut buffer_daily,
-            window_daily,
-        )?;
-        info!(" Loaded {} daily rollups from SD card", count_daily);
+        let count_daily =
+            self.backend
+                .read_rollups(RollupTier::Daily, &mut buffer_daily, window_daily)?;
+        info!(" Loaded {} daily rollups", count_daily);
         for rollup in &buffer_daily[..count_daily] {
-            self.rollups_daily.push_back(*rollup);
+            if !push_if_newer(
+                &mut self.rollups_daily,
+                ROLLUPS_DAILY_CAPACITY,
+                *rollup,
+                |r| r.start_ts,
+            ) {
+                self.out_of_order.rollups_daily += 1;
+            }
         }
 
+        // The daily file is never pruned, so this covers every day recorded
+        self.daily_totals = self.accumulate_window(RollupTier::Daily, 0, u32::MAX)?;
+
+        self.rollup_bytes = self.backend.rollup_bytes()?;
+        info!(" {} bytes of rollups stored", self.rollup_bytes);
+
         info!(" Storage manager initialization complete");
         Ok(())
     }
@@ -114,14 +340,22 @@ where
     ///
     /// RAM storage always succeeds. Returns `Err` only if an SD card write fails.
     /// The caller should log the error and continue — RAM data remains valid.
+    ///
+    /// A sample or rollup whose timestamp is not after the newest one in its
+    /// tier (a duplicate, or one stamped before a backwards clock adjustment)
+    /// is dropped without being persisted and counted in
+    /// [`out_of_order_counts`](Self::out_of_order_counts).
     pub async fn process_event(&mut self, event: RollupEvent) -> Result<(), StorageError> {
         match event {
             RollupEvent::RawSample(sample) => {
                 // Add to ring buffer (oldest is automatically dropped when full)
-                if self.raw_samples.len() >= RAW_SAMPLES_CAPACITY {
-                    self.raw_samples.pop_front();
+                if !push_if_newer(&mut self.raw_samples, RAW_SAMPLES_CAPACITY, sample, |s| {
+                    s.timestamp
+                }) {
+                    self.out_of_order.raw_samples += 1;
+                    warn!(" Dropped out-of-order raw sample at {}", sample.timestamp);
+                    return Ok(());
                 }
-                self.raw_samples.push_back(sample);
 
                 // Update lifetime stats
                 self.lifetime_stats.update(&sample);
@@ -129,54 +363,241 @@ where
                 Ok(())
             }
             RollupEvent::Rollup5m(rollup) => {
-                if self.rollups_5m.len() >= ROLLUPS_5M_CAPACITY {
-                    self.rollups_5m.pop_front();
+                if !push_if_newer(&mut self.rollups_5m, ROLLUPS_5M_CAPACITY, rollup, |r| {
+                    r.start_ts
+                }) {
+                    self.out_of_order.rollups_5m += 1;
+                    warn!(" Dropped out-of-order 5m rollup at {}", rollup.start_ts);
+                    return Ok(());
                 }
-                self.rollups_5m.push_back(rollup);
 
                 // Append to rollup_5m.bin on SD card
-                self.sd_card_manager
-                    .append_rollup_data(ROLLUP_FILE_5M, &rollup)?;
+                self.backend
+                    .append_rollup(RollupTier::FiveMinute, &rollup)?;
+                self.rollup_bytes += ROLLUP_RECORD_BYTES;
                 info!(" Updating rollup file 5m.");
 
                 // Rewrite the lifetime stats as well
-                self.sd_card_manager
+                self.backend
                     .overwrite_lifetime_data(self.lifetime_stats.as_ref())?;
-                info!(" Updated lifetime stats on SD card.");
+                info!(" Updated lifetime stats.");
                 Ok(())
             }
             RollupEvent::Rollup1h(rollup) => {
-                if self.rollups_1h.len() >= ROLLUPS_1H_CAPACITY {
-                    self.rollups_1h.pop_front();
+                if !push_if_newer(&mut self.rollups_1h, ROLLUPS_1H_CAPACITY, rollup, |r| {
+                    r.start_ts
+                }) {
+                    self.out_of_order.rollups_1h += 1;
+                    warn!(" Dropped out-of-order 1h rollup at {}", rollup.start_ts);
+                    return Ok(());
                 }
-                self.rollups_1h.push_back(rollup);
 
                 // Append to rollup_1h.bin on SD card
-                self.sd_card_manager
-                    .append_rollup_data(ROLLUP_FILE_1H, &rollup)?;
+                self.backend.append_rollup(RollupTier::Hourly, &rollup)?;
+                self.rollup_bytes += ROLLUP_RECORD_BYTES;
                 info!(" Updating rollup file 1h.");
                 Ok(())
             }
             RollupEvent::RollupDaily(rollup) => {
-                if self.rollups_daily.len() >= ROLLUPS_DAILY_CAPACITY {
-                    self.rollups_daily.pop_front();
+                if !push_if_newer(
+                    &mut self.rollups_daily,
+                    ROLLUPS_DAILY_CAPACITY,
+                    rollup,
+                    |r| r.start_ts,
+                ) {
+                    self.out_of_order.rollups_daily += 1;
+                    warn!(" Dropped out-of-order daily rollup at {}", rollup.start_ts);
+                    return Ok(());
                 }
-                self.rollups_daily.push_back(rollup);
+                accumulate_rollup(&mut self.daily_totals, &rollup);
 
                 // Append to rollup_daily.bin on SD card
-                self.sd_card_manager
-                    .append_rollup_data(ROLLUP_FILE_DAILY, &rollup)?;
+                self.backend.append_rollup(RollupTier::Daily, &rollup)?;
+                self.rollup_bytes += ROLLUP_RECORD_BYTES;
                 info!(" Updating rollup file 24h.");
                 Ok(())
             }
         }
     }
 
+    /// Drop records that have aged out of their tier's retention window
+    ///
+    /// Raw samples are kept for 24 hours, 5-minute rollups for 7 days, hourly
+    /// rollups for 30 days, and daily rollups for 1 year (all relative to
+    /// `now`). The backend prunes its own copies with the same cutoffs (SD
+    /// card files are left untouched). The storage task calls this through
+    /// [`prune_if_due`](Self::prune_if_due).
+    pub fn prune(&mut self, now: u32) -> PruneCounts {
+        let counts = PruneCounts {
+            raw_samples: prune_front(
+                &mut self.raw_samples,
+                now,
+                RAW_SAMPLES_RETENTION_SECS,
+                |s| s.timestamp,
+            ),
+            rollups_5m: prune_front(&mut self.rollups_5m, now, ROLLUPS_5M_RETENTION_SECS, |r| {
+                r.start_ts
+            }),
+            rollups_1h: prune_front(&mut self.rollups_1h, now, ROLLUPS_1H_RETENTION_SECS, |r| {
+                r.start_ts
+            }),
+            rollups_daily: prune_front(
+                &mut self.rollups_daily,
+                now,
+                ROLLUPS_DAILY_RETENTION_SECS,
+                |r| r.start_ts,
+            ),
+        };
+
+        if counts.total() > 0 {
+            debug!(" Pruned expired records: {:?}", counts);
+        }
+
+        let mut backend_pruned = 0;
+        for (tier, retention_secs) in [
+            (RollupTier::FiveMinute, ROLLUPS_5M_RETENTION_SECS),
+            (RollupTier::Hourly, ROLLUPS_1H_RETENTION_SECS),
+            (RollupTier::Daily, ROLLUPS_DAILY_RETENTION_SECS),
+        ] {
+            let cutoff = Timestamp::new(now).window_start(retention_secs).secs();
+            match self.backend.prune(tier, cutoff) {
+                Ok(removed) => backend_pruned += removed,
+                Err(e) => error!(" Failed to prune {:?} rollups: {:?}", tier, e),
+            }
+        }
+        if backend_pruned > 0
+            && let Ok(bytes) = self.backend.rollup_bytes()
+        {
+            self.rollup_bytes = bytes;
+        }
+
+        counts
+    }
+
+    /// Run [`prune`](Self::prune) if `PRUNE_INTERVAL_SECS` have passed since the last pass
+    ///
+    /// The first call always prunes, so records restored at boot are aged out
+    /// with the first sample. Returns `None` when no pass was due.
+    pub fn prune_if_due(&mut self, now: u32) -> Option<PruneCounts> {
+        let due = self.last_pruned.is_none_or(|last| {
+            Timestamp::new(now).since(Timestamp::new(last)) >= PRUNE_INTERVAL_SECS
+        });
+        if !due {
+            return None;
+        }
+
+        self.last_pruned = Some(now);
+        Some(self.prune(now))
+    }
+
+    /// Current fill levels of the raw sample buffer and the SD card
+    pub fn capacity(&self) -> StorageCapacity {
+        StorageCapacity {
+            raw_used: self.raw_samples.len(),
+            raw_cap: RAW_SAMPLES_CAPACITY,
+            rollup_bytes: self.rollup_bytes,
+            card_bytes: self.backend.capacity_bytes(),
+        }
+    }
+
+    /// Load the persisted device configuration from the SD card
+    ///
+    /// Out-of-range values (e.g. from an older config file) are
+    /// clamped into their supported ranges.
+    pub fn load_device_config(&self) -> Result<DeviceConfig, StorageError> {
+        let mut config = self.backend.read_device_config()?;
+        config.sample_interval_secs = clamp_sample_interval_secs(config.sample_interval_secs);
+        config.brightness_percent = config.brightness_percent.min(MAX_BRIGHTNESS_PERCENT);
+        config.touch_threshold = clamp_touch_threshold(config.touch_threshold);
+        config.rollup_interval_secs = clamp_rollup_interval_secs(config.rollup_interval_secs);
+        for (window, fallback) in config.trend_windows.iter_mut().zip(DEFAULT_TREND_WINDOWS) {
+            *window = validate_trend_window(*window, fallback);
+        }
+        config.co2_temperature_offset_milli_c = config
+            .co2_temperature_offset_milli_c
+            .min(MAX_CO2_TEMPERATURE_OFFSET_MILLI_C);
+        info!(" Loaded device config: {:?}", config);
+        Ok(config)
+    }
+
+    /// Persist the device configuration to the SD card
+    pub fn save_device_config(&self, config: &DeviceConfig) -> Result<(), StorageError> {
+        self.backend.write_device_config(config)?;
+        info!(" Saved device config.");
+        Ok(())
+    }
+
+    /// Load the last known good Unix time from the SD card
+    ///
+    /// Values before `MIN_PLAUSIBLE_UNIX_SECS` are discarded as never having
+    /// come from a real clock.
+    pub fn load_last_known_time(&self) -> Result<Option<u32>, StorageError> {
+        let time = self
+            .backend
+            .read_last_known_time()?
+            .filter(|&t| t >= MIN_PLAUSIBLE_UNIX_SECS);
+        info!(" Loaded last known time: {:?}", time);
+        Ok(time)
+    }
+
+    /// Persist `now` as the last known good time if the persist interval has elapsed
+    ///
+    /// Does nothing while the clock is unknown (`now` before 2020), so an
+    /// unsynced boot never overwrites a good value with a small one.
+    pub fn persist_time_if_due(&mut self, now: u32) -> Result<(), StorageError> {
+        if now < MIN_PLAUSIBLE_UNIX_SECS {
+            return Ok(());
+        }
+        let due = self.last_time_persisted.is_none_or(|last| {
+            Timestamp::new(now).since(Timestamp::new(last)) >= LAST_TIME_PERSIST_INTERVAL_SECS
+        });
+        if !due {
+            return Ok(());
+        }
+
+        self.backend.write_last_known_time(now)?;
+        self.last_time_persisted = Some(now);
+        debug!(" Persisted last known time: {}", now);
+        Ok(())
+    }
+
     // Get raw samples for graph rendering (non-consuming, read-only access)
     pub fn get_raw_samples(&self) -> &VecDeque<RawSample> {
         &self.raw_samples
     }
 
+    /// Most recent raw sample, if any has been recorded since boot
+    pub fn latest_sample(&self) -> Option<&RawSample> {
+        self.raw_samples.back()
+    }
+
+    /// Rollups covering the last `window`, oldest first
+    ///
+    /// Uses the window's preferred tier, except that raw-sample windows fall
+    /// back to 5-minute rollups. The window is measured back from the end of
+    /// the newest rollup in that tier rather than from wall-clock time.
+    pub fn rollups_in_window(&self, window: TimeWindow) -> Vec<Rollup> {
+        let (rollups, rollup_secs) = match window.preferred_rollup_tier() {
+            RollupTier::RawSample | RollupTier::FiveMinute => (&self.rollups_5m, 5 * 60),
+            RollupTier::Hourly => (&self.rollups_1h, 60 * 60),
+            RollupTier::Daily => (&self.rollups_daily, 24 * 60 * 60),
+        };
+
+        let Some(newest) = rollups.back() else {
+            return Vec::new();
+        };
+        // Saturating, so a rollup near the u32 limit can't wrap the cutoff to 0
+        let cutoff = Timestamp::new(newest.start_ts)
+            .advance(rollup_secs)
+            .window_start(window.duration_secs())
+            .secs();
+        rollups
+            .iter()
+            .filter(|r| r.start_ts >= cutoff)
+            .copied()
+            .collect()
+    }
+
     /// Get 5-minute rollups for graph rendering
     pub fn get_5m_rollups(&self) -> &VecDeque<Rollup> {
         &self.rollups_5m
@@ -192,8 +613,702 @@ where
         &self.rollups_daily
     }
 
+    /// Records rejected as duplicate or out of order since boot
+    pub fn out_of_order_counts(&self) -> OutOfOrderCounts {
+        self.out_of_order
+    }
+
     /// Get lifetime statistics
     pub fn get_lifetime_stats(&self) -> &LifetimeStats {
         &self.lifetime_stats
     }
+
+    /// Statistics for `sensor` over records of `tier` starting in `start..=end`
+    ///
+    /// Rollups are streamed from the backend a few at a time in one pass over
+    /// the tier, so a summary over everything on the SD card (e.g. "all
+    /// time" from the daily tier) needs no more RAM than a short one. Each
+    /// rollup counts as one sample carrying its own min and max. Raw samples
+    /// come from the in-memory buffer, which only holds the last hour.
+    /// Missing readings are skipped.
+    pub fn window_stats(
+        &self,
+        tier: RollupTier,
+        sensor: SensorType,
+        start: u32,
+        end: u32,
+    ) -> Result<TrendStats, StorageError> {
+        Ok(self.window_stats_by_sensor(tier, start, end)?[sensor.index()])
+    }
+
+    /// Statistics for every sensor slot over records of `tier` starting in
+    /// `start..=end`, indexed like a reading
+    ///
+    /// Same as [`Self::window_stats`] for each sensor, but the tier is only
+    /// read once, which matters for summaries shown side by side.
+    pub fn window_stats_by_sensor(
+        &self,
+        tier: RollupTier,
+        start: u32,
+        end: u32,
+    ) -> Result<[TrendStats; MAX_SENSORS], StorageError> {
+        Ok(self
+            .accumulate_window(tier, start, end)?
+            .map(|acc| acc.finish()))
+    }
+
+    /// Statistics for every sensor slot over the hourly rollups since 00:00
+    /// UTC on the day containing `now`
+    ///
+    /// Read from the in-memory hourly buffer, which holds the last 30 days, so
+    /// no backend access is needed.
+    pub fn today_stats(&self, now: u32) -> [TrendStats; MAX_SENSORS] {
+        let start = Timestamp::new(now).day_start().secs();
+        let mut stats = [StatsAccumulator::new(); MAX_SENSORS];
+        for rollup in self
+            .rollups_1h
+            .iter()
+            .filter(|r| r.start_ts >= start && r.start_ts <= now)
+        {
+            accumulate_rollup(&mut stats, rollup);
+        }
+        stats.map(|acc| acc.finish())
+    }
+
+    /// Statistics for every sensor slot over every daily rollup stored
+    ///
+    /// Kept up to date as daily rollups arrive, so this never reads the
+    /// backend.
+    pub fn all_time_stats(&self) -> [TrendStats; MAX_SENSORS] {
+        self.daily_totals.map(|acc| acc.finish())
+    }
+
+    /// Fold every record of `tier` starting in `start..=end` into one
+    /// accumulator per sensor slot
+    fn accumulate_window(
+        &self,
+        tier: RollupTier,
+        start: u32,
+        end: u32,
+    ) -> Result<[StatsAccumulator; MAX_SENSORS], StorageError> {
+        let mut stats = [StatsAccumulator::new(); MAX_SENSORS];
+
+        if tier == RollupTier::RawSample {
+            for sample in self
+                .raw_samples
+                .iter()
+                .filter(|s| s.timestamp >= start && s.timestamp <= end)
+            {
+                for (acc, &value) in stats.iter_mut().zip(&sample.values) {
+                    if value != MISSING_SENSOR_VALUE {
+                        acc.push(value);
+                    }
+                }
+            }
+            return Ok(stats);
+        }
+
+        let mut chunk = alloc::vec![Rollup::default(); WINDOW_STATS_CHUNK_ROLLUPS];
+        let mut next_record = 0;
+        loop {
+            let read =
+                self.backend
+                    .read_rollups_from(tier, &mut chunk, (start, end), &mut next_record)?;
+            for rollup in &chunk[..read] {
+                accumulate_rollup(&mut stats, rollup);
+            }
+
+            // A short read means the whole tier has been scanned; otherwise
+            // the cursor resumes just past the last record examined
+            if read < chunk.len() {
+                break;
+            }
+        }
+
+        Ok(stats)
+    }
+}
+
+/// Fold one rollup into per-sensor accumulators as a single sample carrying
+/// its own min and max, skipping missing readings
+fn accumulate_rollup(stats: &mut [StatsAccumulator; MAX_SENSORS], rollup: &Rollup) {
+    for (index, acc) in stats.iter_mut().enumerate() {
+        if rollup.avg[index] != MISSING_SENSOR_VALUE {
+            acc.push_summary(rollup.avg[index], rollup.min[index], rollup.max[index]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::block_on;
+
+    const HOUR: u32 = 60 * 60;
+    const DAY: u32 = 24 * HOUR;
+    const START: u32 = 1_700_000_000;
+
+    fn manager() -> StorageManager<RamBackend> {
+        StorageManager::with_backend(RamBackend::new())
+    }
+
+    fn feed(manager: &mut StorageManager<RamBackend>, event: RollupEvent) {
+        block_on(manager.process_event(event)).unwrap();
+    }
+
+    fn rollup(start_ts: u32) -> Rollup {
+        let values = [1_000; MAX_SENSORS];
+        Rollup::new(start_ts, &values, &values, &values, 1)
+    }
+
+    #[test]
+    fn prune_keeps_last_day_of_raw_samples_spanning_two_days() {
+        let mut manager = manager();
+        // One sample every 10 minutes for 48 hours, both ends included
+        for ts in (START..=START + 2 * DAY).step_by(600) {
+            feed(
+                &mut manager,
+                RollupEvent::RawSample(RawSample::new(ts, &[0; MAX_SENSORS])),
+            );
+        }
+        assert_eq!(manager.get_raw_samples().len(), 289);
+
+        let counts = manager.prune(START + 2 * DAY);
+
+        assert_eq!(counts.raw_samples, 144);
+        let remaining = manager.get_raw_samples();
+        assert_eq!(remaining.len(), 145);
+        assert_eq!(remaining.front().unwrap().timestamp, START + DAY);
+        assert_eq!(remaining.back().unwrap().timestamp, START + 2 * DAY);
+    }
+
+    #[test]
+    fn prune_applies_each_tier_retention() {
+        let mut manager = manager();
+        let now = START + 40 * DAY;
+        // Hourly 5m rollups over the last 8 days, and hourly rollups over the
+        // last 31 days
+        for ts in (now - 8 * DAY..=now).step_by(HOUR as usize) {
+            feed(&mut manager, RollupEvent::Rollup5m(rollup(ts)));
+        }
+        for ts in (now - 31 * DAY..=now).step_by(DAY as usize) {
+            feed(&mut manager, RollupEvent::Rollup1h(rollup(ts)));
+            feed(&mut manager, RollupEvent::RollupDaily(rollup(ts)));
+        }
+
+        let counts = manager.prune(now);
+
+        assert_eq!(counts.rollups_5m, 24);
+        assert_eq!(manager.get_5m_rollups().len(), 7 * 24 + 1);
+        assert_eq!(counts.rollups_1h, 1);
+        assert_eq!(
+            manager.get_1h_rollups().front().unwrap().start_ts,
+            now - 30 * DAY
+        );
+        assert_eq!(counts.rollups_daily, 0);
+        assert_eq!(manager.get_daily_rollups().len(), 32);
+    }
+
+    #[test]
+    fn prune_if_due_runs_once_per_interval() {
+        let mut manager = manager();
+        feed(
+            &mut manager,
+            RollupEvent::RawSample(RawSample::new(START, &[0; MAX_SENSORS])),
+        );
+
+        assert!(manager.prune_if_due(START).is_some());
+        assert!(
+            manager
+                .prune_if_due(START + PRUNE_INTERVAL_SECS - 1)
+                .is_none()
+        );
+
+        // Overdue by a day: the pass that finally runs drops the old sample
+        let counts = manager.prune_if_due(START + DAY + PRUNE_INTERVAL_SECS);
+        assert_eq!(counts.map(|c| c.raw_samples), Some(1));
+        assert!(manager.get_raw_samples().is_empty());
+    }
+
+    #[test]
+    fn capacity_counts_appends() {
+        let mut manager = manager();
+        for i in 0..25 {
+            feed(
+                &mut manager,
+                RollupEvent::RawSample(RawSample::new(START + i * 10, &[0; MAX_SENSORS])),
+            );
+        }
+        for i in 0..4 {
+            feed(&mut manager, RollupEvent::Rollup5m(rollup(START + i * 300)));
+        }
+        feed(&mut manager, RollupEvent::Rollup1h(rollup(START)));
+
+        let capacity = manager.capacity();
+        assert_eq!(capacity.raw_used, 25);
+        assert_eq!(capacity.raw_cap, RAW_SAMPLES_CAPACITY);
+        assert_eq!(capacity.rollup_bytes, 5 * ROLLUP_RECORD_BYTES);
+        assert_eq!(capacity.card_bytes, 0);
+    }
+
+    #[test]
+    fn raw_used_stops_at_capacity() {
+        let mut manager = manager();
+        for i in 0..RAW_SAMPLES_CAPACITY as u32 + 10 {
+            feed(
+                &mut manager,
+                RollupEvent::RawSample(RawSample::new(START + i * 10, &[0; MAX_SENSORS])),
+            );
+        }
+        assert_eq!(manager.capacity().raw_used, RAW_SAMPLES_CAPACITY);
+    }
+
+    #[test]
+    fn duplicate_rollups_are_not_counted() {
+        let mut manager = manager();
+        feed(&mut manager, RollupEvent::Rollup5m(rollup(START)));
+        feed(&mut manager, RollupEvent::Rollup5m(rollup(START)));
+        assert_eq!(manager.capacity().rollup_bytes, ROLLUP_RECORD_BYTES);
+    }
+
+    #[test]
+    fn card_percent_used_rounds_down_and_caps() {
+        let capacity = |rollup_bytes, card_bytes| StorageCapacity {
+            rollup_bytes,
+            card_bytes,
+            ..StorageCapacity::default()
+        };
+        assert_eq!(capacity(0, 0).card_percent_used(), 0);
+        assert_eq!(capacity(500, 0).card_percent_used(), 0);
+        assert_eq!(capacity(499, 1000).card_percent_used(), 49);
+        assert_eq!(capacity(2000, 1000).card_percent_used(), 100);
+    }
+
+    #[test]
+    fn persisted_time_is_restored() {
+        let mut manager = manager();
+        assert_eq!(manager.load_last_known_time().unwrap(), None);
+
+        manager.persist_time_if_due(START).unwrap();
+        assert_eq!(manager.load_last_known_time().unwrap(), Some(START));
+    }
+
+    #[test]
+    fn time_is_persisted_once_per_interval() {
+        let mut manager = manager();
+        manager.persist_time_if_due(START).unwrap();
+
+        manager
+            .persist_time_if_due(START + LAST_TIME_PERSIST_INTERVAL_SECS - 1)
+            .unwrap();
+        assert_eq!(manager.load_last_known_time().unwrap(), Some(START));
+
+        let next = START + LAST_TIME_PERSIST_INTERVAL_SECS;
+        manager.persist_time_if_due(next).unwrap();
+        assert_eq!(manager.load_last_known_time().unwrap(), Some(next));
+    }
+
+    #[test]
+    fn implausible_times_are_neither_persisted_nor_restored() {
+        let mut manager = manager();
+        manager.persist_time_if_due(START).unwrap();
+
+        // An unsynced clock counting up from boot must not overwrite it
+        manager.persist_time_if_due(120).unwrap();
+        assert_eq!(manager.load_last_known_time().unwrap(), Some(START));
+
+        manager.backend().write_last_known_time(120).unwrap();
+        assert_eq!(manager.load_last_known_time().unwrap(), None);
+    }
+
+    #[test]
+    fn estimated_time_follows_every_timestamp_before_power_loss() {
+        let mut manager = manager();
+        let mut last_issued = START;
+        // Samples every 10 s for an hour, then power is lost
+        for ts in (START..START + HOUR).step_by(10) {
+            manager.persist_time_if_due(ts).unwrap();
+            last_issued = ts;
+        }
+
+        let persisted = manager.load_last_known_time().unwrap().unwrap();
+        assert!(persisted < last_issued);
+        assert!(estimate_time_from_last_known(persisted) > last_issued);
+    }
+
+    #[test]
+    fn estimate_saturates_at_end_of_time() {
+        assert_eq!(estimate_time_from_last_known(u32::MAX), u32::MAX);
+    }
+
+    #[test]
+    fn samples_flow_through_rollups_into_the_backend_and_back() {
+        use crate::config::DEFAULT_ROLLUP_INTERVAL_SECS;
+        use crate::storage::accumulator::{
+            EVENT_CHANNEL_CAPACITY, EVENT_PUBLISHERS, EVENT_SUBSCRIBERS, RollupAccumulator,
+        };
+        use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+        use embassy_sync::pubsub::PubSubChannel;
+
+        // Midnight UTC, so rollup windows line up with the feed
+        const DAY_START: u32 = 1_699_920_000;
+
+        let channel = PubSubChannel::<
+            CriticalSectionRawMutex,
+            RollupEvent,
+            EVENT_CHANNEL_CAPACITY,
+            EVENT_SUBSCRIBERS,
+            EVENT_PUBLISHERS,
+        >::new();
+        let mut subscriber = channel.subscriber().unwrap();
+        let mut accumulator =
+            RollupAccumulator::new(channel.publisher().unwrap(), DEFAULT_ROLLUP_INTERVAL_SECS);
+        let mut manager = manager();
+
+        // 65 minutes of samples every 10 s, 20.000 °C rising 1 m°C per sample
+        let end = DAY_START + 65 * 60;
+        for (i, ts) in (DAY_START..=end).step_by(10).enumerate() {
+            let mut values = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+            values[0] = 20_000 + i as i32;
+            block_on(accumulator.add_sample(ts, &values));
+            while let Some(event) = subscriber.try_next_message_pure() {
+                feed(&mut manager, event);
+            }
+        }
+
+        // The newest hour of samples is in the RAM ring, and the closed windows were rolled up
+        assert_eq!(manager.get_raw_samples().len(), RAW_SAMPLES_CAPACITY);
+        assert_eq!(manager.latest_sample().unwrap().timestamp, end);
+        let five_minute = manager.get_5m_rollups();
+        assert_eq!(five_minute.len(), 13);
+        assert_eq!(five_minute[0].start_ts, DAY_START);
+        assert_eq!(five_minute[0].sample_count, 30);
+        assert_eq!(five_minute[0].min[0], 20_000);
+        assert_eq!(five_minute[0].max[0], 20_029);
+        let hourly = manager.get_1h_rollups();
+        assert_eq!(hourly.len(), 1);
+        assert_eq!(hourly[0].sample_count, 360);
+
+        // The same rollups were persisted and come back from a query
+        fn summarize<'a>(rollups: impl IntoIterator<Item = &'a Rollup>) -> Vec<(u32, i32, u32)> {
+            rollups
+                .into_iter()
+                .map(|r| (r.start_ts, r.avg[0], r.sample_count))
+                .collect()
+        }
+        let persisted = |manager: &StorageManager<RamBackend>, tier| {
+            let mut buffer = alloc::vec![Rollup::default(); 32];
+            let count = manager
+                .backend()
+                .read_rollups(tier, &mut buffer, (DAY_START, end))
+                .unwrap();
+            summarize(&buffer[..count])
+        };
+        assert_eq!(
+            persisted(&manager, RollupTier::FiveMinute),
+            summarize(manager.get_5m_rollups())
+        );
+        assert_eq!(
+            persisted(&manager, RollupTier::Hourly),
+            summarize(manager.get_1h_rollups())
+        );
+        assert_eq!(manager.rollups_in_window(TimeWindow::OneHour).len(), 12);
+
+        // A manager restarted on the same backend reloads what was persisted
+        let mut restarted = StorageManager::with_backend(manager.backend);
+        block_on(restarted.init(end)).unwrap();
+        assert_eq!(restarted.get_5m_rollups().len(), 13);
+        assert_eq!(restarted.get_1h_rollups().len(), 1);
+        assert!(restarted.get_raw_samples().is_empty());
+    }
+
+    #[test]
+    fn out_of_order_and_duplicate_samples_are_rejected() {
+        let mut manager = manager();
+        let sample = |ts| RollupEvent::RawSample(RawSample::new(ts, &[0; MAX_SENSORS]));
+
+        feed(&mut manager, sample(START));
+        feed(&mut manager, sample(START + 20));
+        // Duplicate of the newest, then one from before a backwards clock step
+        feed(&mut manager, sample(START + 20));
+        feed(&mut manager, sample(START + 10));
+        feed(&mut manager, sample(START + 30));
+
+        let timestamps: Vec<u32> = manager
+            .get_raw_samples()
+            .iter()
+            .map(|s| s.timestamp)
+            .collect();
+        assert_eq!(timestamps, [START, START + 20, START + 30]);
+        assert_eq!(
+            manager.out_of_order_counts(),
+            OutOfOrderCounts {
+                raw_samples: 2,
+                ..OutOfOrderCounts::default()
+            }
+        );
+    }
+
+    #[test]
+    fn rejected_rollups_are_not_persisted() {
+        let mut manager = manager();
+        feed(&mut manager, RollupEvent::Rollup1h(rollup(START + HOUR)));
+        feed(&mut manager, RollupEvent::Rollup1h(rollup(START)));
+        feed(&mut manager, RollupEvent::Rollup1h(rollup(START + HOUR)));
+        feed(&mut manager, RollupEvent::RollupDaily(rollup(START)));
+        feed(&mut manager, RollupEvent::RollupDaily(rollup(START)));
+
+        assert_eq!(manager.get_1h_rollups().len(), 1);
+        let mut buffer = [Rollup::default(); 4];
+        let stored = manager
+            .backend()
+            .read_rollups(RollupTier::Hourly, &mut buffer, (0, u32::MAX))
+            .unwrap();
+        assert_eq!(stored, 1);
+        assert_eq!(buffer[0].start_ts, START + HOUR);
+
+        let counts = manager.out_of_order_counts();
+        assert_eq!((counts.rollups_1h, counts.rollups_daily), (2, 1));
+        assert_eq!(counts.total(), 3);
+    }
+
+    /// Hourly rollups whose CO₂ slot swings over a day, with every seventh missing
+    fn hourly_co2_history(manager: &StorageManager<RamBackend>, hours: u32) {
+        let index = SensorType::Co2.index();
+        for hour in 0..hours {
+            let mut avg = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+            let mut min = avg;
+            let mut max = avg;
+            if hour % 7 != 3 {
+                let swing = ((hour * 37) % 24) as i32 * 25_000;
+                avg[index] = 450_000 + swing;
+                min[index] = avg[index] - 30_000 - (hour as i32 % 5) * 1_000;
+                max[index] = avg[index] + 80_000 + (hour as i32 % 3) * 7_000;
+            }
+            manager
+                .backend
+                .append_rollup(
+                    RollupTier::Hourly,
+                    &Rollup::new(START + hour * HOUR, &avg, &min, &max, 360),
+                )
+                .unwrap();
+        }
+    }
+
+    /// Stats over `start..=end` computed from the whole tier read at once
+    fn naive_window_stats(
+        manager: &StorageManager<RamBackend>,
+        sensor: SensorType,
+        start: u32,
+        end: u32,
+    ) -> TrendStats {
+        let index = sensor.index();
+        let mut buffer = alloc::vec![Rollup::default(); 1024];
+        let count = manager
+            .backend
+            .read_rollups(RollupTier::Hourly, &mut buffer, (0, u32::MAX))
+            .unwrap();
+        let window: Vec<&Rollup> = buffer[..count]
+            .iter()
+            .filter(|r| r.start_ts >= start && r.start_ts <= end)
+            .filter(|r| r.avg[index] != MISSING_SENSOR_VALUE)
+            .collect();
+        if window.is_empty() {
+            return TrendStats::default();
+        }
+
+        let n = window.len() as f64;
+        let sum: i64 = window.iter().map(|r| r.avg[index] as i64).sum();
+        let mean = sum as f64 / n;
+        let variance = window
+            .iter()
+            .map(|r| (r.avg[index] as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        TrendStats {
+            avg: crate::metrics::fixed::average_milli(sum, window.len() as i64),
+            min: window.iter().map(|r| r.min[index]).min().unwrap(),
+            max: window.iter().map(|r| r.max[index]).max().unwrap(),
+            std_dev: variance.sqrt().round() as i32,
+            latest: window.last().unwrap().avg[index],
+            count: window.len(),
+        }
+    }
+
+    fn assert_stats_match(streamed: TrendStats, naive: TrendStats) {
+        // Welford's running deviation may round differently by a milli-unit
+        assert!(
+            streamed.std_dev.abs_diff(naive.std_dev) <= 1,
+            "{streamed:?} vs {naive:?}"
+        );
+        assert_eq!(
+            TrendStats {
+                std_dev: 0,
+                ..streamed
+            },
+            TrendStats {
+                std_dev: 0,
+                ..naive
+            }
+        );
+    }
+
+    #[test]
+    fn streamed_window_stats_match_a_full_buffer_computation() {
+        let manager = manager();
+        // Several chunks' worth, and not a whole number of them
+        hourly_co2_history(&manager, 150);
+
+        let windows = [
+            (0, u32::MAX),
+            (START + 5 * HOUR, START + 29 * HOUR),
+            (START + 17 * HOUR, START + 17 * HOUR),
+            (START + 140 * HOUR, u32::MAX),
+            (START - DAY, START - 1),
+        ];
+        for (start, end) in windows {
+            let streamed = manager
+                .window_stats(RollupTier::Hourly, SensorType::Co2, start, end)
+                .unwrap();
+            let naive = naive_window_stats(&manager, SensorType::Co2, start, end);
+            assert_stats_match(streamed, naive);
+        }
+    }
+
+    #[test]
+    fn window_stats_over_whole_chunks_see_every_rollup() {
+        let manager = manager();
+        let hours = 3 * WINDOW_STATS_CHUNK_ROLLUPS as u32;
+        hourly_co2_history(&manager, hours);
+
+        let streamed = manager
+            .window_stats(RollupTier::Hourly, SensorType::Co2, 0, u32::MAX)
+            .unwrap();
+
+        let missing = (0..hours).filter(|hour| hour % 7 == 3).count();
+        assert_eq!(streamed.count, hours as usize - missing);
+        assert_stats_match(
+            streamed,
+            naive_window_stats(&manager, SensorType::Co2, 0, u32::MAX),
+        );
+    }
+
+    #[test]
+    fn raw_window_stats_use_the_in_memory_samples() {
+        let mut manager = manager();
+        let index = SensorType::Temperature.index();
+        for i in 0..10 {
+            let mut values = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+            values[index] = 20_000 + i as i32 * 100;
+            feed(
+                &mut manager,
+                RollupEvent::RawSample(RawSample::new(START + i * 10, &values)),
+            );
+        }
+
+        let stats = manager
+            .window_stats(
+                RollupTier::RawSample,
+                SensorType::Temperature,
+                START + 20,
+                START + 50,
+            )
+            .unwrap();
+
+        assert_eq!(
+            (stats.count, stats.min, stats.max, stats.avg, stats.latest),
+            (4, 20_200, 20_500, 20_350, 20_500)
+        );
+    }
+
+    #[test]
+    fn one_pass_covers_every_sensor() {
+        let manager = manager();
+        let temperature = SensorType::Temperature.index();
+        let co2 = SensorType::Co2.index();
+        for day in 0..40 {
+            let mut avg = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+            avg[temperature] = 18_000 + (day as i32 % 9) * 500;
+            if day % 2 == 0 {
+                avg[co2] = 500_000 + day as i32 * 10_000;
+            }
+            manager
+                .backend
+                .append_rollup(
+                    RollupTier::Daily,
+                    &Rollup::new(START + day * DAY, &avg, &avg, &avg, 8640),
+                )
+                .unwrap();
+        }
+
+        let all = manager
+            .window_stats_by_sensor(RollupTier::Daily, 0, u32::MAX)
+            .unwrap();
+
+        assert_eq!((all[temperature].count, all[co2].count), (30, 15));
+        for sensor in [SensorType::Temperature, SensorType::Co2] {
+            assert_eq!(
+                all[sensor.index()],
+                manager
+                    .window_stats(RollupTier::Daily, sensor, 0, u32::MAX)
+                    .unwrap()
+            );
+        }
+        assert_eq!(all[SensorType::Lux.index()], TrendStats::default());
+    }
+
+    #[test]
+    fn today_stats_start_at_utc_midnight() {
+        let mut manager = manager();
+        let midnight = Timestamp::new(START).day_start().secs();
+        let co2 = SensorType::Co2.index();
+        // Hourly rollups from 03:00 yesterday to 05:00 today
+        for ts in (midnight - 21 * HOUR..=midnight + 5 * HOUR).step_by(HOUR as usize) {
+            let mut avg = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+            avg[co2] = if ts < midnight { 2_000_000 } else { 600_000 };
+            feed(
+                &mut manager,
+                RollupEvent::Rollup1h(Rollup::new(ts, &avg, &avg, &avg, 360)),
+            );
+        }
+
+        let today = manager.today_stats(midnight + 5 * HOUR + 30 * 60)[co2];
+
+        assert_eq!(today.count, 6);
+        assert_eq!((today.min, today.max), (600_000, 600_000));
+        // One second before midnight only sees yesterday
+        assert_eq!(manager.today_stats(midnight - 1)[co2].count, 21);
+    }
+
+    #[test]
+    fn all_time_stats_cover_loaded_and_new_daily_rollups() {
+        let mut manager = manager();
+        for day in 0..3 {
+            manager
+                .backend
+                .append_rollup(RollupTier::Daily, &rollup(START + day * DAY))
+                .unwrap();
+        }
+        block_on(manager.init(START + 3 * DAY)).unwrap();
+        assert_eq!(manager.all_time_stats()[0].count, 3);
+
+        feed(
+            &mut manager,
+            RollupEvent::RollupDaily(rollup(START + 3 * DAY)),
+        );
+        // A duplicate is not counted twice
+        feed(
+            &mut manager,
+            RollupEvent::RollupDaily(rollup(START + 3 * DAY)),
+        );
+
+        let all_time = manager.all_time_stats();
+        assert_eq!(all_time[0].count, 4);
+        assert_eq!(
+            all_time,
+            manager
+                .window_stats_by_sensor(RollupTier::Daily, 0, u32::MAX)
+                .unwrap()
+        );
+    }
 }
@@ -1,12 +1,17 @@
+pub mod backend;
 pub mod rollup_storage;
 pub mod sd_card;
 
 pub mod accumulator;
+pub mod journal;
 pub mod manager;
+pub mod timestamp;
 
 pub use rollup_storage::*;
+pub use timestamp::Timestamp;
 
 use sd_card::SdCardManagerError;
+use serde::{Deserialize, Serialize};
 use thiserror_no_std::Error;
 
 /// Storage subsystem error type
@@ -17,16 +22,27 @@ use thiserror_no_std::Error;
 pub enum StorageError {
     #[error("SD card error: {0}")]
     SdCard(#[from] SdCardManagerError),
+
+    #[error("Rollup tier {0:?} is not kept by the storage backend")]
+    UnsupportedTier(RollupTier),
+
+    #[error("Failed to encode config: {0}")]
+    EncodeConfig(postcard::Error),
 }
 
 /// Maximum number of sensor values stored per sample
 pub const MAX_SENSORS: usize = 20;
 
+/// Sentinel stored in a sensor slot when that sensor could not be read
+///
+/// Rollups skip missing values, and pages treat them as "no reading".
+pub const MISSING_SENSOR_VALUE: i32 = i32::MIN;
+
 /// Time window for data aggregation and display
 ///
 /// Defines the different time scales over which sensor data can be viewed.
 /// Each window corresponds to specific data tiers and sample counts.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TimeWindow {
     /// 1 minute window (6 raw samples at 10s interval)
     OneMinute,
@@ -107,3 +123,39 @@ pub enum RollupTier {
     /// Daily rollups
     Daily,
 }
+
+impl RollupTier {
+    /// Length of one entry in this tier in seconds (0 for raw samples)
+    pub const fn bucket_secs(self) -> u32 {
+        match self {
+            Self::RawSample => 0,
+            Self::FiveMinute => 300,
+            Self::Hourly => 3600,
+            Self::Daily => 86400,
+        }
+    }
+}
+
+/// Historical data from one tier, as requested by a trend page
+#[derive(Debug, Clone)]
+pub enum HistoryData {
+    /// Raw samples, oldest first
+    RawSamples(alloc::vec::Vec<RawSample>),
+    /// Rollups from a 5-minute, hourly or daily tier, oldest first
+    Rollups(alloc::vec::Vec<Rollup>),
+}
+
+impl HistoryData {
+    /// Number of samples or rollups
+    pub fn len(&self) -> usize {
+        match self {
+            Self::RawSamples(samples) => samples.len(),
+            Self::Rollups(rollups) => rollups.len(),
+        }
+    }
+
+    /// Whether there is no data
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -1,4 +1,5 @@
-use super::MAX_SENSORS;
+use super::{MAX_SENSORS, MISSING_SENSOR_VALUE};
+use crate::metrics::fixed::{average_milli, to_float};
 use crate::sensors::{CO2, HUMIDITY, TEMPERATURE};
 use core::fmt::Display;
 
@@ -24,11 +25,16 @@ pub struct RawSample {
     _padding: [u8; 12],
 }
 
+/// Size of a `RawSample` on disk and on the wire
+pub const RAW_SAMPLE_BYTES: usize = 96;
+
+const _: () = assert!(core::mem::size_of::<RawSample>() == RAW_SAMPLE_BYTES);
+
 impl Display for RawSample {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let temp_c = self.values[TEMPERATURE] as f32 / 1000.0;
-        let humidity_pct = self.values[HUMIDITY] as f32 / 1000.0;
-        let co2_ppm = self.values[CO2] as f32 / 1000.0;
+        let temp_c = to_float(self.values[TEMPERATURE]);
+        let humidity_pct = to_float(self.values[HUMIDITY]);
+        let co2_ppm = to_float(self.values[CO2]);
 
         write!(
             f,
@@ -85,16 +91,22 @@ pub struct Rollup {
     pub min: [i32; MAX_SENSORS],
     /// Maximum value for each sensor over the window
     pub max: [i32; MAX_SENSORS],
+    /// Number of raw samples summarized by this rollup
+    ///
+    /// Rollups written before this field existed read back as 0.
+    pub sample_count: u32,
     /// Padding to reach 256 bytes for efficient SD card I/O
-    _padding: [u8; 12],
+    _padding: [u8; 8],
 }
 
+const _: () = assert!(core::mem::size_of::<Rollup>() == 256);
+
 impl Display for Rollup {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         // Only displaying averages here as others aren't vital for debugging.
-        let temp_avg = self.avg[TEMPERATURE] as f32 / 1000.0;
-        let humidity_avg = self.avg[HUMIDITY] as f32 / 1000.0;
-        let co2_avg = self.avg[CO2] as f32 / 1000.0;
+        let temp_avg = to_float(self.avg[TEMPERATURE]);
+        let humidity_avg = to_float(self.avg[HUMIDITY]);
+        let co2_avg = to_float(self.avg[CO2]);
 
         write!(
             f,
@@ -143,12 +155,12 @@ pub struct LifetimeStats {
 
 impl Display for LifetimeStats {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let temp_max = self.sensor_max[TEMPERATURE] as f32 / 1000.0;
-        let temp_min = self.sensor_min[TEMPERATURE] as f32 / 1000.0;
-        let humidity_max = self.sensor_max[HUMIDITY] as f32 / 1000.0;
-        let humidity_min = self.sensor_min[HUMIDITY] as f32 / 1000.0;
-        let co2_max = self.sensor_max[CO2] as f32 / 1000.0;
-        let co2_min = self.sensor_min[CO2] as f32 / 1000.0;
+        let temp_max = to_float(self.sensor_max[TEMPERATURE]);
+        let temp_min = to_float(self.sensor_min[TEMPERATURE]);
+        let humidity_max = to_float(self.sensor_max[HUMIDITY]);
+        let humidity_min = to_float(self.sensor_min[HUMIDITY]);
+        let co2_max = to_float(self.sensor_max[CO2]);
+        let co2_min = to_float(self.sensor_min[CO2]);
 
         write!(
             f,
@@ -174,6 +186,35 @@ impl RawSample {
             _padding: [0; 12],
         }
     }
+
+    /// Encode as a little-endian, 96-byte record (the same layout as on disk)
+    ///
+    /// Unlike `as_ref`, the byte order is fixed, so the encoding can be sent
+    /// between machines without serde.
+    pub fn to_bytes(&self) -> [u8; RAW_SAMPLE_BYTES] {
+        let mut bytes = [0u8; RAW_SAMPLE_BYTES];
+        bytes[..4].copy_from_slice(&self.timestamp.to_le_bytes());
+        for (chunk, value) in bytes[4..].chunks_exact_mut(4).zip(self.values.iter()) {
+            chunk.copy_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decode a record produced by `to_bytes`
+    ///
+    /// Returns `None` if `bytes` is not exactly `RAW_SAMPLE_BYTES` long.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != RAW_SAMPLE_BYTES {
+            return None;
+        }
+        let word = |i: usize| [bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]];
+
+        let mut values = [0i32; MAX_SENSORS];
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = i32::from_le_bytes(word(4 + i * 4));
+        }
+        Some(Self::new(u32::from_le_bytes(word(0)), &values))
+    }
 }
 
 impl Rollup {
@@ -183,16 +224,25 @@ impl Rollup {
         avg: &[i32; MAX_SENSORS],
         min: &[i32; MAX_SENSORS],
         max: &[i32; MAX_SENSORS],
+        sample_count: u32,
     ) -> Self {
         Self {
             start_ts,
             avg: *avg,
             min: *min,
             max: *max,
-            _padding: [0; 12],
+            sample_count,
+            _padding: [0; 8],
         }
     }
 
+    /// Weight of this rollup when averaged with others
+    ///
+    /// Legacy rollups without a sample count weigh as a single sample.
+    pub fn weight(&self) -> u32 {
+        self.sample_count.max(1)
+    }
+
     pub fn as_slice(&self) -> &[u8] {
         // Safety: Rollup is #[repr(C)] and contains only plain data types
         unsafe {
@@ -224,18 +274,61 @@ impl LifetimeStats {
     }
 
     /// Update lifetime statistics with a new sample
+    ///
+    /// The first sample seeds `boot_time` and the extrema, so stats loaded
+    /// from an empty file don't report a spurious 0 minimum or maximum.
+    /// Missing sensor values are skipped.
     pub fn update(&mut self, sample: &RawSample) {
+        if self.total_samples == 0 {
+            self.boot_time = sample.timestamp;
+            self.sensor_max = [i32::MIN; MAX_SENSORS];
+            self.sensor_min = [i32::MAX; MAX_SENSORS];
+        }
         self.total_samples += 1;
 
         for i in 0..MAX_SENSORS {
+            let value = sample.values[i];
+            if value == MISSING_SENSOR_VALUE {
+                continue;
+            }
+
             // Update integrals (for exposure metrics)
-            self.sensor_integrals[i] =
-                self.sensor_integrals[i].saturating_add(sample.values[i] as i64);
+            self.sensor_integrals[i] = self.sensor_integrals[i].saturating_add(value as i64);
 
             // Update extrema
-            self.sensor_max[i] = self.sensor_max[i].max(sample.values[i]);
-            self.sensor_min[i] = self.sensor_min[i].min(sample.values[i]);
+            self.sensor_max[i] = self.sensor_max[i].max(value);
+            self.sensor_min[i] = self.sensor_min[i].min(value);
+        }
+    }
+
+    /// All-time average for a sensor index (`integral / total_samples`)
+    ///
+    /// Returns `None` before any sample has been recorded.
+    pub fn average(&self, index: usize) -> Option<i32> {
+        if self.total_samples == 0 {
+            return None;
         }
+        Some(average_milli(
+            self.sensor_integrals[index],
+            self.total_samples as i64,
+        ))
+    }
+
+    /// All-time minimum for a sensor index, or `None` if it was never read
+    pub fn min(&self, index: usize) -> Option<i32> {
+        let value = self.sensor_min[index];
+        (self.total_samples > 0 && value != i32::MAX).then_some(value)
+    }
+
+    /// All-time maximum for a sensor index, or `None` if it was never read
+    pub fn max(&self, index: usize) -> Option<i32> {
+        let value = self.sensor_max[index];
+        (self.total_samples > 0 && value != i32::MIN).then_some(value)
+    }
+
+    /// Seconds elapsed between `boot_time` and `now`
+    pub fn uptime(&self, now: u32) -> u32 {
+        now.saturating_sub(self.boot_time)
     }
 
     fn as_slice(&self) -> &[u8] {
@@ -289,3 +382,104 @@ impl<const N: usize> From<&mut [u8; N]> for LifetimeStats {
         stats
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: u32 = 1_700_000_000;
+
+    fn sample(timestamp: u32, temperature: i32) -> RawSample {
+        let mut values = [0; MAX_SENSORS];
+        values[TEMPERATURE] = temperature;
+        RawSample::new(timestamp, &values)
+    }
+
+    fn stats_over(temperatures: &[i32]) -> LifetimeStats {
+        let mut stats = LifetimeStats::default();
+        for (i, &t) in temperatures.iter().enumerate() {
+            stats.update(&sample(START + i as u32 * 10, t));
+        }
+        stats
+    }
+
+    #[test]
+    fn empty_stats_report_nothing() {
+        let stats = LifetimeStats::default();
+        assert_eq!(stats.average(TEMPERATURE), None);
+        assert_eq!(stats.min(TEMPERATURE), None);
+        assert_eq!(stats.max(TEMPERATURE), None);
+    }
+
+    #[test]
+    fn average_over_synthetic_sequence() {
+        let stats = stats_over(&[20_000, 22_000, 24_000, 26_000]);
+        assert_eq!(stats.total_samples, 4);
+        assert_eq!(stats.average(TEMPERATURE), Some(23_000));
+    }
+
+    #[test]
+    fn average_rounds_to_nearest() {
+        assert_eq!(stats_over(&[1, 2]).average(TEMPERATURE), Some(2));
+        assert_eq!(stats_over(&[-1, -2]).average(TEMPERATURE), Some(-2));
+    }
+
+    #[test]
+    fn extrema_track_the_whole_sequence() {
+        let stats = stats_over(&[21_000, -5_000, 30_500, 12_000]);
+        assert_eq!(stats.min(TEMPERATURE), Some(-5_000));
+        assert_eq!(stats.max(TEMPERATURE), Some(30_500));
+    }
+
+    #[test]
+    fn first_sample_seeds_extrema_of_zeroed_stats() {
+        // Stats loaded from an empty file are all zeros
+        let stats = stats_over(&[21_000]);
+        assert_eq!(stats.min(TEMPERATURE), Some(21_000));
+        assert_eq!(stats.max(TEMPERATURE), Some(21_000));
+        assert_eq!(stats.boot_time, START);
+    }
+
+    #[test]
+    fn missing_values_do_not_move_extrema() {
+        let stats = stats_over(&[21_000, MISSING_SENSOR_VALUE, 23_000]);
+        assert_eq!(stats.min(TEMPERATURE), Some(21_000));
+        assert_eq!(stats.max(TEMPERATURE), Some(23_000));
+    }
+
+    #[test]
+    fn uptime_counts_from_first_sample() {
+        let stats = stats_over(&[0; 3]);
+        assert_eq!(stats.uptime(START + 3_600), 3_600);
+        assert_eq!(stats.uptime(START - 1), 0);
+    }
+
+    #[test]
+    fn raw_sample_bytes_round_trip() {
+        let mut values = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+        values[TEMPERATURE] = -12_345;
+        values[MAX_SENSORS - 1] = i32::MAX;
+        let sample = RawSample::new(START, &values);
+
+        let decoded = RawSample::from_bytes(&sample.to_bytes()).unwrap();
+        assert_eq!(decoded.timestamp, START);
+        assert_eq!(decoded.values, values);
+    }
+
+    #[test]
+    fn raw_sample_bytes_are_little_endian() {
+        let bytes = sample(0x0102_0304, 0x0A0B_0C0D).to_bytes();
+        assert_eq!(&bytes[..4], &[0x04, 0x03, 0x02, 0x01]);
+        let offset = 4 + 4 * TEMPERATURE;
+        assert_eq!(&bytes[offset..offset + 4], &[0x0D, 0x0C, 0x0B, 0x0A]);
+        // Padding goes out as zeros
+        assert!(bytes[4 + 4 * MAX_SENSORS..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn raw_sample_bytes_of_wrong_length_are_rejected() {
+        let bytes = sample(START, 0).to_bytes();
+        assert!(RawSample::from_bytes(&bytes[..RAW_SAMPLE_BYTES - 1]).is_none());
+        assert!(RawSample::from_bytes(&[0; RAW_SAMPLE_BYTES + 1]).is_none());
+    }
+}
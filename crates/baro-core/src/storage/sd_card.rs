@@ -1,30 +1,160 @@
 // cSpell: disable
+use core::fmt;
+
 use embedded_sdmmc::{Mode, SdCard, TimeSource, VolumeIdx, VolumeManager};
 
-use crate::{config::Config, storage::Rollup};
+use crate::{
+    config::{CONFIG_BUFFER_SIZE, Config, ConfigVolume, DeviceConfig},
+    storage::journal::{self, RecordStore},
+    storage::{Rollup, StorageError},
+};
 use log::{debug, error};
 use thiserror_no_std::Error;
 
-/// Buffer size for serialized config data.
-/// Must be large enough to hold the postcard-serialized `Config` struct.
-/// We use a generous fixed size since `size_of::<Config>()` measures the
-/// in-memory representation (with references), not the serialized form.
-const CONFIG_BUFFER_SIZE: usize = 128;
-type ConfigBuffer = [u8; CONFIG_BUFFER_SIZE];
-
 pub const CONFIG_FILE: &str = "config.bin";
 pub const ROLLUP_FILE_1H: &str = "roll_1h.bin";
 pub const ROLLUP_FILE_5M: &str = "roll_5m.bin";
 pub const ROLLUP_FILE_DAILY: &str = "roll_day.bin";
 pub const ROLLUP_FILE_LIFETIME: &str = "lifetime.bin";
+pub const LAST_TIME_FILE: &str = "last_time.bin";
+
+// Commit pointer files holding the number of fully written records in each rollup file
+pub const ROLLUP_COMMIT_FILE_1H: &str = "roll_1h.cmt";
+pub const ROLLUP_COMMIT_FILE_5M: &str = "roll_5m.cmt";
+pub const ROLLUP_COMMIT_FILE_DAILY: &str = "roll_day.cmt";
+
+/// Size of one rollup record on the SD card in bytes
+const ROLLUP_RECORD_SIZE: u32 = core::mem::size_of::<Rollup>() as u32;
+
+/// Map a rollup data file to its commit pointer file.
+fn commit_file_for(file_name: &str) -> Result<&'static str, SdCardManagerError> {
+    match file_name {
+        ROLLUP_FILE_1H => Ok(ROLLUP_COMMIT_FILE_1H),
+        ROLLUP_FILE_5M => Ok(ROLLUP_COMMIT_FILE_5M),
+        ROLLUP_FILE_DAILY => Ok(ROLLUP_COMMIT_FILE_DAILY),
+        _ => Err(SdCardManagerError::UnknownRollupFile),
+    }
+}
+
+/// Error reported by `embedded_sdmmc` for our SD card driver
+type SdmmcError = embedded_sdmmc::Error<embedded_sdmmc::SdCardError>;
+
+/// Step of an SD card operation, carried by errors to say what failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SdCardOperation {
+    /// Opening the card's first volume
+    OpenVolume,
+    /// Opening the volume's root directory
+    OpenRootDir,
+    /// Opening (or creating) a file
+    OpenFile { file: &'static str },
+    /// Reading a file; `record` is set for rollup files
+    Read {
+        file: &'static str,
+        record: Option<u32>,
+    },
+    /// Moving to a record of a rollup file
+    Seek { file: &'static str, record: u32 },
+    /// Appending a record to a rollup file
+    Append { file: &'static str, record: u32 },
+    /// Overwriting a small file (config, commit pointer, time, lifetime stats)
+    Write { file: &'static str },
+    /// Flushing a file's writes to the card
+    Flush { file: &'static str },
+    /// Closing a file
+    CloseFile { file: &'static str },
+    /// Closing the root directory
+    CloseRootDir,
+    /// Closing the volume
+    CloseVolume,
+}
+
+impl fmt::Display for SdCardOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OpenVolume => write!(f, "open volume"),
+            Self::OpenRootDir => write!(f, "open root directory"),
+            Self::OpenFile { file } => write!(f, "open {}", file),
+            Self::Read { file, record: None } => write!(f, "read {}", file),
+            Self::Read {
+                file,
+                record: Some(record),
+            } => write!(f, "read {} record {}", file, record),
+            Self::Seek { file, record } => write!(f, "seek to {} record {}", file, record),
+            Self::Append { file, record } => write!(f, "append {} record {}", file, record),
+            Self::Write { file } => write!(f, "write {}", file),
+            Self::Flush { file } => write!(f, "flush {}", file),
+            Self::CloseFile { file } => write!(f, "close {}", file),
+            Self::CloseRootDir => write!(f, "close root directory"),
+            Self::CloseVolume => write!(f, "close volume"),
+        }
+    }
+}
 
 #[derive(Debug, Error)]
 pub enum SdCardManagerError {
-    #[error("SDMMC (SD Card Manager) error: {0:?}")]
-    SdmmcError(#[from] embedded_sdmmc::Error<embedded_sdmmc::SdCardError>),
+    #[error("Failed to {operation}: {error:?}")]
+    SdmmcError {
+        operation: SdCardOperation,
+        error: SdmmcError,
+    },
+
+    #[error("File is not a known rollup file (no commit pointer)")]
+    UnknownRollupFile,
+}
+
+impl SdCardManagerError {
+    /// The step that failed, if the error came from a card operation
+    pub const fn operation(&self) -> Option<SdCardOperation> {
+        match self {
+            Self::SdmmcError { operation, .. } => Some(*operation),
+            Self::UnknownRollupFile => None,
+        }
+    }
+}
+
+/// Wrap an `embedded_sdmmc` error from `operation`, for use with `map_err`
+fn sdmmc_error(operation: SdCardOperation) -> impl FnOnce(SdmmcError) -> SdCardManagerError {
+    move |error| SdCardManagerError::SdmmcError { operation, error }
+}
+
+/// Copy rollups starting in `within_window` into `buffer`, one record at a time
+///
+/// `read` fills the next record from a rollup file positioned at record
+/// `*next_record`, returning how many bytes it read; `*next_record` is
+/// advanced past each record examined. Stops when `buffer` is full, at the
+/// commit pointer (`committed`) or at a short read. A failed read is tagged
+/// with the file and the record being read.
+fn read_window_records(
+    file_name: &'static str,
+    committed: u32,
+    next_record: &mut u32,
+    buffer: &mut [Rollup],
+    within_window: (u32, u32),
+    mut read: impl FnMut(&mut [u8]) -> Result<usize, SdmmcError>,
+) -> Result<usize, SdCardManagerError> {
+    let mut count = 0;
+    let mut temp_rollup = Rollup::default();
+
+    while count < buffer.len() && *next_record < committed {
+        let bytes_read =
+            read(temp_rollup.as_mut()).map_err(sdmmc_error(SdCardOperation::Read {
+                file: file_name,
+                record: Some(*next_record),
+            }))?;
+        if bytes_read < ROLLUP_RECORD_SIZE as usize {
+            break; // EOF or partial record
+        }
+        *next_record += 1;
+
+        let timestamp = temp_rollup.start_ts;
+        if timestamp >= within_window.0 && timestamp <= within_window.1 {
+            buffer[count] = temp_rollup;
+            count += 1;
+        }
+    }
 
-    #[error("Error when parsing postcard data (configuration): {0}")]
-    PostcardParseError(#[from] postcard::Error),
+    Ok(count)
 }
 
 /// For NOW, these SD card operations are blocking (as are also the display operations on the same SPI bus),
@@ -39,6 +169,8 @@ where
     T: TimeSource,
 {
     volume_mgr: VolumeManager<SdCard<S, D>, T, 4, 4, 1>,
+    /// Total card size in bytes (0 if the card could not be queried)
+    card_bytes: u64,
 }
 
 impl<S, D, T> SdCardManager<S, D, T>
@@ -49,31 +181,39 @@ where
 {
     /// Create a new SD card storage manager
     pub fn new(sd_card: SdCard<S, D>, ts: T) -> Self {
+        let card_bytes = sd_card.num_bytes().unwrap_or_else(|e| {
+            error!("Failed to query SD card size: {:?}", e);
+            0
+        });
         let volume_mgr = VolumeManager::new(sd_card, ts);
 
-        Self { volume_mgr }
+        Self {
+            volume_mgr,
+            card_bytes,
+        }
     }
 
-    #[allow(dead_code)]
-    fn read_config(&self) -> Result<ConfigBuffer, SdCardManagerError> {
-        self.file_operation(CONFIG_FILE, Mode::ReadOnly, move |file| {
-            let mut buffer = [0u8; CONFIG_BUFFER_SIZE];
-            file.read(&mut buffer)
-                .map_err(SdCardManagerError::SdmmcError)?;
+    /// Total size of the SD card in bytes, or 0 if it could not be queried
+    pub fn card_bytes(&self) -> u64 {
+        self.card_bytes
+    }
 
-            Ok(buffer)
-        })
+    /// Bytes of committed rollup records across all rollup files
+    pub fn rollup_bytes(&self) -> Result<u64, SdCardManagerError> {
+        let mut total = 0;
+        for file_name in [ROLLUP_FILE_5M, ROLLUP_FILE_1H, ROLLUP_FILE_DAILY] {
+            total += self.read_committed_count(file_name)? as u64 * ROLLUP_RECORD_SIZE as u64;
+        }
+        Ok(total)
     }
 
     /// Allows you to read the config and perform an operation based on it.
-    #[allow(dead_code)]
     fn config_op_once<Outpt>(
         &self,
         operation: impl FnOnce(&Config<'_>) -> Outpt,
-    ) -> Result<Outpt, SdCardManagerError> {
-        let raw_bytes = self.read_config()?;
-        let config: Config =
-            postcard::from_bytes(&raw_bytes).map_err(SdCardManagerError::PostcardParseError)?;
+    ) -> Result<Outpt, StorageError> {
+        let mut buffer = [0u8; CONFIG_BUFFER_SIZE];
+        let config = Config::load(self, &mut buffer)?;
 
         Ok(operation(&config))
     }
@@ -81,41 +221,41 @@ where
     /// Allows you to read the config, mutate it, and save it back to the SD card.
     /// Will always read the latest config from the SD card before performing the operation, and always
     /// saves it back after the operation.
-    #[allow(dead_code)]
     fn config_op_once_mut(
         &self,
         operation: impl FnOnce(&mut Config<'_>),
-    ) -> Result<(), SdCardManagerError> {
-        let raw_bytes = self.read_config()?;
-        let mut config: Config =
-            postcard::from_bytes(&raw_bytes).map_err(SdCardManagerError::PostcardParseError)?;
+    ) -> Result<(), StorageError> {
+        let mut buffer = [0u8; CONFIG_BUFFER_SIZE];
+        let mut config = Config::load(self, &mut buffer)?;
 
         operation(&mut config);
 
         // We need to save this back to the SD card.
-        let mut buffer = [0u8; CONFIG_BUFFER_SIZE];
-        let serialized = postcard::to_slice(&config, &mut buffer)
-            .map_err(SdCardManagerError::PostcardParseError)?;
-
-        self.file_operation(CONFIG_FILE, Mode::ReadWriteCreateOrTruncate, move |file| {
-            debug!("Writing {} bytes of config data", serialized.len());
-
-            file.write(serialized)
-                .map_err(SdCardManagerError::SdmmcError)?;
-
-            // Explicitly flush to ensure data is written to the SD card
-            file.flush().map_err(SdCardManagerError::SdmmcError)?;
+        config.save(self)
+    }
 
-            debug!("Flushed config data");
+    /// Reads the device-level section of the persisted config.
+    ///
+    /// A missing, empty, outdated or corrupt config file yields
+    /// `DeviceConfig::default()`. Values are not range-checked here, so
+    /// callers should validate ranges before applying the result.
+    pub fn read_device_config(&self) -> Result<DeviceConfig, StorageError> {
+        self.config_op_once(|config| config.device)
+    }
 
-            Ok(())
-        })
+    /// Overwrites the device-level section of the persisted config, leaving the
+    /// remaining sections untouched.
+    pub fn write_device_config(&self, device: &DeviceConfig) -> Result<(), StorageError> {
+        self.config_op_once_mut(|config| config.device = *device)
     }
 
     /// Performs a generic file operation on the SD card, opening the file, passing the file handle to the operation, and then closing the file when the operation is completed.
+    ///
+    /// Errors from opening and closing carry the step that failed; the
+    /// operation wraps its own errors with the same context.
     fn file_operation<OpRes>(
         &self,
-        file_name: &str,
+        file_name: &'static str,
         mode: Mode,
         operation: impl FnOnce(
             &mut embedded_sdmmc::File<'_, SdCard<S, D>, T, 4, 4, 1>,
@@ -126,14 +266,15 @@ where
         let volume0 = self
             .volume_mgr
             .open_volume(VolumeIdx(0))
-            .map_err(SdCardManagerError::SdmmcError)?;
+            .map_err(sdmmc_error(SdCardOperation::OpenVolume))?;
 
         // Open root directory
         let root_dir = volume0
             .open_root_dir()
-            .map_err(SdCardManagerError::SdmmcError)?;
+            .map_err(sdmmc_error(SdCardOperation::OpenRootDir))?;
 
         // Open file
+        let open_file = SdCardOperation::OpenFile { file: file_name };
         let mut file = match mode {
             Mode::ReadOnly => {
                 // If we open in read only we want to ensure that this file is created if it doesn't exist.
@@ -145,14 +286,14 @@ where
                         // ! This includes rollup files, config, files, etc.
                         Ok(root_dir
                             .open_file_in_dir(file_name, Mode::ReadWriteCreateOrAppend)
-                            .map_err(SdCardManagerError::SdmmcError)?)
+                            .map_err(sdmmc_error(open_file))?)
                     }
-                    Err(e) => Err(SdCardManagerError::SdmmcError(e)),
+                    Err(e) => Err(sdmmc_error(open_file)(e)),
                 }
             }
             _ => root_dir
                 .open_file_in_dir(file_name, mode)
-                .map_err(SdCardManagerError::SdmmcError),
+                .map_err(sdmmc_error(open_file)),
         }?;
 
         // Perform operation
@@ -164,19 +305,19 @@ where
         debug!("Closing file: {}", file_name);
         file.close().map_err(|e| {
             error!("Failed to close file {}: {:?}", file_name, e);
-            SdCardManagerError::SdmmcError(e)
+            sdmmc_error(SdCardOperation::CloseFile { file: file_name })(e)
         })?;
 
         debug!("Closing root directory");
         root_dir.close().map_err(|e| {
             error!("Failed to close root directory: {:?}", e);
-            SdCardManagerError::SdmmcError(e)
+            sdmmc_error(SdCardOperation::CloseRootDir)(e)
         })?;
 
         debug!("Closing volume");
         volume0.close().map_err(|e| {
             error!("Failed to close volume: {:?}", e);
-            SdCardManagerError::SdmmcError(e)
+            sdmmc_error(SdCardOperation::CloseVolume)(e)
         })?;
 
         debug!(
@@ -187,73 +328,119 @@ where
         Ok(result)
     }
 
+    /// A rollup file and its commit pointer, for the journal to append through
+    fn rollup_journal(
+        &self,
+        file_name: &'static str,
+    ) -> Result<SdRollupJournal<'_, S, D, T>, SdCardManagerError> {
+        Ok(SdRollupJournal {
+            manager: self,
+            file_name,
+            commit_file: commit_file_for(file_name)?,
+        })
+    }
+
+    /// Number of committed records in a rollup file (see [`journal::committed_records`])
+    fn read_committed_count(&self, file_name: &'static str) -> Result<u32, SdCardManagerError> {
+        journal::committed_records(&mut self.rollup_journal(file_name)?, ROLLUP_RECORD_SIZE)
+    }
+
     /// Appends to a rollup file the data provided
+    ///
+    /// The record is written just past the last committed record and flushed,
+    /// and only then is the commit pointer advanced (see [`journal`]). If power
+    /// drops mid-write, the pointer still describes the previous consistent
+    /// state: readers ignore the partial record and the next append
+    /// overwrites it.
     pub fn append_rollup_data(
         &self,
-        file_name: &str,
+        file_name: &'static str,
         data: &Rollup,
     ) -> Result<(), SdCardManagerError> {
-        self.file_operation(file_name, Mode::ReadWriteCreateOrAppend, move |file| {
-            debug!(
-                "Writing {} bytes to {}",
-                core::mem::size_of::<Rollup>(),
-                file_name
-            );
-
-            // Write the data
-            file.write(data.as_ref())
-                .map_err(SdCardManagerError::SdmmcError)?;
-
-            debug!("Successfully wrote data to {}", file_name);
-
-            // Explicitly flush to ensure data is written to the SD card
-            file.flush().map_err(SdCardManagerError::SdmmcError)?;
-
-            debug!("Flushed data to {}", file_name);
-
-            Ok(())
-        })
+        let index = journal::append_record(&mut self.rollup_journal(file_name)?, data.as_ref())?;
+        debug!("Committed record {} in {}", index, file_name);
+        Ok(())
     }
 
+    /// Reads committed rollups whose start timestamp falls within `within_window`
+    ///
+    /// Reading starts at record `*next_record`, which is left just past the
+    /// last record examined, so a caller paging through the file with a
+    /// small buffer reads each record once. Records past the commit pointer
+    /// (from an interrupted append) are never returned.
     pub fn read_rollup_data(
         &self,
-        file_name: &str,
+        file_name: &'static str,
         buffer: &mut [Rollup],
         within_window: (u32, u32),
+        next_record: &mut u32,
     ) -> Result<usize, SdCardManagerError> {
+        let committed = self.read_committed_count(file_name)?;
+        if *next_record >= committed {
+            return Ok(0);
+        }
+
         self.file_operation(file_name, Mode::ReadOnly, move |file| {
-            let mut count = 0;
-            let mut temp_rollup = Rollup::default();
-
-            // Read rollups into buffer
-            while count < buffer.len() {
-                match file.read(temp_rollup.as_mut()) {
-                    Ok(bytes_read) => {
-                        if bytes_read == 0 {
-                            break; // EOF
-                        }
-
-                        // Check if within time window
-                        let timestamp = temp_rollup.start_ts;
-                        if timestamp >= within_window.0 && timestamp <= within_window.1 {
-                            buffer[count] = temp_rollup;
-                            count += 1;
-                        }
-                    }
-                    Err(e) => {
-                        // Handle read error
-                        return Err(e.into());
-                    }
-                }
-            }
+            file.seek_from_start(*next_record * ROLLUP_RECORD_SIZE)
+                .map_err(sdmmc_error(SdCardOperation::Seek {
+                    file: file_name,
+                    record: *next_record,
+                }))?;
+
+            read_window_records(
+                file_name,
+                committed,
+                next_record,
+                buffer,
+                within_window,
+                |bytes| file.read(bytes),
+            )
+        })
+    }
 
-            Ok(count)
+    /// Last known good Unix time, or `None` if it was never persisted
+    pub fn read_last_known_time(&self) -> Result<Option<u32>, SdCardManagerError> {
+        self.file_operation(LAST_TIME_FILE, Mode::ReadOnly, |file| {
+            let mut buffer = [0u8; 4];
+            let bytes_read =
+                file.read(&mut buffer)
+                    .map_err(sdmmc_error(SdCardOperation::Read {
+                        file: LAST_TIME_FILE,
+                        record: None,
+                    }))?;
+            Ok((bytes_read == buffer.len()).then(|| u32::from_le_bytes(buffer)))
         })
     }
 
+    /// Overwrite the last known good Unix time
+    pub fn write_last_known_time(&self, unix_time: u32) -> Result<(), SdCardManagerError> {
+        self.file_operation(
+            LAST_TIME_FILE,
+            Mode::ReadWriteCreateOrTruncate,
+            move |file| {
+                file.write(&unix_time.to_le_bytes()).map_err(sdmmc_error(
+                    SdCardOperation::Write {
+                        file: LAST_TIME_FILE,
+                    },
+                ))?;
+                file.flush().map_err(sdmmc_error(SdCardOperation::Flush {
+                    file: LAST_TIME_FILE,
+                }))?;
+
+                debug!("Persisted last known time {}", unix_time);
+                Ok(())
+            },
+        )
+    }
+
     pub fn read_lifetime_data(&self, buffer: &mut [u8]) -> Result<usize, SdCardManagerError> {
         self.file_operation(ROLLUP_FILE_LIFETIME, Mode::ReadOnly, move |file| {
-            let bytes_read = file.read(buffer).map_err(SdCardManagerError::SdmmcError)?;
+            let bytes_read = file
+                .read(buffer)
+                .map_err(sdmmc_error(SdCardOperation::Read {
+                    file: ROLLUP_FILE_LIFETIME,
+                    record: None,
+                }))?;
 
             Ok(bytes_read)
         })
@@ -267,12 +454,17 @@ where
                 debug!("Writing {} bytes to {}", data.len(), ROLLUP_FILE_LIFETIME);
 
                 // Write the data
-                file.write(data).map_err(SdCardManagerError::SdmmcError)?;
+                file.write(data)
+                    .map_err(sdmmc_error(SdCardOperation::Write {
+                        file: ROLLUP_FILE_LIFETIME,
+                    }))?;
 
                 debug!("Successfully wrote data to {}", ROLLUP_FILE_LIFETIME);
 
                 // Explicitly flush to ensure data is written to the SD card
-                file.flush().map_err(SdCardManagerError::SdmmcError)?;
+                file.flush().map_err(sdmmc_error(SdCardOperation::Flush {
+                    file: ROLLUP_FILE_LIFETIME,
+                }))?;
 
                 debug!("Flushed data to {}", ROLLUP_FILE_LIFETIME);
 
@@ -281,3 +473,285 @@ where
         )
     }
 }
+
+impl<S, D, T> ConfigVolume for SdCardManager<S, D, T>
+where
+    S: embedded_hal::spi::SpiDevice<u8>,
+    D: embedded_hal::delay::DelayNs,
+    T: TimeSource,
+{
+    fn read_config_file(&self, buffer: &mut [u8]) -> Result<usize, StorageError> {
+        // A missing config file is created empty and reads back as 0 bytes
+        Ok(self.file_operation(CONFIG_FILE, Mode::ReadOnly, |file| {
+            file.read(buffer)
+                .map_err(sdmmc_error(SdCardOperation::Read {
+                    file: CONFIG_FILE,
+                    record: None,
+                }))
+        })?)
+    }
+
+    fn write_config_file(&self, bytes: &[u8]) -> Result<(), StorageError> {
+        Ok(
+            self.file_operation(CONFIG_FILE, Mode::ReadWriteCreateOrTruncate, |file| {
+                debug!("Writing {} bytes of config data", bytes.len());
+
+                file.write(bytes)
+                    .map_err(sdmmc_error(SdCardOperation::Write { file: CONFIG_FILE }))?;
+
+                // Explicitly flush to ensure data is written to the SD card
+                file.flush()
+                    .map_err(sdmmc_error(SdCardOperation::Flush { file: CONFIG_FILE }))?;
+
+                debug!("Flushed config data");
+
+                Ok(())
+            })?,
+        )
+    }
+}
+
+/// A rollup file on the SD card with its commit pointer file
+struct SdRollupJournal<'a, S, D, T>
+where
+    S: embedded_hal::spi::SpiDevice<u8>,
+    D: embedded_hal::delay::DelayNs,
+    T: TimeSource,
+{
+    manager: &'a SdCardManager<S, D, T>,
+    file_name: &'static str,
+    commit_file: &'static str,
+}
+
+impl<S, D, T> RecordStore for SdRollupJournal<'_, S, D, T>
+where
+    S: embedded_hal::spi::SpiDevice<u8>,
+    D: embedded_hal::delay::DelayNs,
+    T: TimeSource,
+{
+    type Error = SdCardManagerError;
+
+    fn data_len(&mut self) -> Result<u32, SdCardManagerError> {
+        self.manager
+            .file_operation(self.file_name, Mode::ReadOnly, |file| Ok(file.length()))
+    }
+
+    fn read_commit(&mut self) -> Result<Option<u32>, SdCardManagerError> {
+        let commit_file = self.commit_file;
+        self.manager
+            .file_operation(commit_file, Mode::ReadOnly, |file| {
+                let mut buffer = [0u8; 4];
+                let bytes_read =
+                    file.read(&mut buffer)
+                        .map_err(sdmmc_error(SdCardOperation::Read {
+                            file: commit_file,
+                            record: None,
+                        }))?;
+                Ok((bytes_read == buffer.len()).then(|| u32::from_le_bytes(buffer)))
+            })
+    }
+
+    fn write_record(&mut self, index: u32, record: &[u8]) -> Result<(), SdCardManagerError> {
+        let file_name = self.file_name;
+        self.manager
+            .file_operation(file_name, Mode::ReadWriteCreateOrAppend, |file| {
+                debug!(
+                    "Writing {} bytes to {} at record {}",
+                    record.len(),
+                    file_name,
+                    index
+                );
+
+                // Overwrite anything past the committed region (e.g. a torn record)
+                file.seek_from_start(index * record.len() as u32)
+                    .map_err(sdmmc_error(SdCardOperation::Seek {
+                        file: file_name,
+                        record: index,
+                    }))?;
+
+                file.write(record)
+                    .map_err(sdmmc_error(SdCardOperation::Append {
+                        file: file_name,
+                        record: index,
+                    }))?;
+
+                // Explicitly flush to ensure data is written to the SD card
+                file.flush()
+                    .map_err(sdmmc_error(SdCardOperation::Flush { file: file_name }))
+            })
+    }
+
+    fn write_commit(&mut self, count: u32) -> Result<(), SdCardManagerError> {
+        let commit_file = self.commit_file;
+        self.manager
+            .file_operation(commit_file, Mode::ReadWriteCreateOrTruncate, |file| {
+                file.write(&count.to_le_bytes())
+                    .map_err(sdmmc_error(SdCardOperation::Write { file: commit_file }))?;
+                file.flush()
+                    .map_err(sdmmc_error(SdCardOperation::Flush { file: commit_file }))?;
+
+                debug!("Committed {} records in {}", count, self.file_name);
+                Ok(())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MAX_SENSORS;
+    use alloc::format;
+    use alloc::vec::Vec;
+    use embedded_sdmmc::SdCardError;
+
+    const START: u32 = 1_700_000_000;
+
+    /// Hourly rollups as they sit in a rollup file, one hour apart
+    fn records(count: u32) -> Vec<Rollup> {
+        let values = [1_000; MAX_SENSORS];
+        (0..count)
+            .map(|i| Rollup::new(START + i * 3600, &values, &values, &values, 360))
+            .collect()
+    }
+
+    /// A reader over `records` starting at `first`, failing at record `fail_at`
+    fn reader(
+        records: &[Rollup],
+        first: u32,
+        fail_at: Option<u32>,
+    ) -> impl FnMut(&mut [u8]) -> Result<usize, SdmmcError> + '_ {
+        let mut position = first;
+        move |bytes| {
+            if Some(position) == fail_at {
+                return Err(embedded_sdmmc::Error::DeviceError(SdCardError::ReadError));
+            }
+            let Some(record) = records.get(position as usize) else {
+                return Ok(0);
+            };
+            bytes.copy_from_slice(record.as_ref());
+            position += 1;
+            Ok(bytes.len())
+        }
+    }
+
+    #[test]
+    fn failed_read_reports_the_file_and_record() {
+        let records = records(6);
+        let mut buffer = [Rollup::default(); 8];
+        let mut next_record = 0;
+
+        let error = read_window_records(
+            ROLLUP_FILE_1H,
+            6,
+            &mut next_record,
+            &mut buffer,
+            (0, u32::MAX),
+            reader(&records, 0, Some(2)),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.operation(),
+            Some(SdCardOperation::Read {
+                file: ROLLUP_FILE_1H,
+                record: Some(2),
+            })
+        );
+        assert!(matches!(
+            error,
+            SdCardManagerError::SdmmcError {
+                error: embedded_sdmmc::Error::DeviceError(SdCardError::ReadError),
+                ..
+            }
+        ));
+        // The failed record was not consumed, so a retry starts there
+        assert_eq!(next_record, 2);
+    }
+
+    #[test]
+    fn read_context_survives_wrapping_in_a_storage_error() {
+        let records = records(6);
+        let mut next_record = 4;
+
+        let error: StorageError = read_window_records(
+            ROLLUP_FILE_DAILY,
+            6,
+            &mut next_record,
+            &mut [Rollup::default(); 8],
+            (0, u32::MAX),
+            reader(&records, 4, Some(5)),
+        )
+        .unwrap_err()
+        .into();
+
+        let message = format!("{error}");
+        assert!(
+            message.starts_with("SD card error: Failed to read roll_day.bin record 5: "),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn read_resumes_at_the_cursor_and_stops_at_the_commit_pointer() {
+        // Record 7 is a torn append past the commit pointer
+        let records = records(8);
+        let mut buffer = [Rollup::default(); 8];
+        let mut next_record = 3;
+
+        let count = read_window_records(
+            ROLLUP_FILE_5M,
+            7,
+            &mut next_record,
+            &mut buffer,
+            (0, u32::MAX),
+            reader(&records, 3, None),
+        )
+        .unwrap();
+
+        let starts: Vec<u32> = buffer[..count].iter().map(|r| r.start_ts).collect();
+        assert_eq!(starts, [3, 4, 5, 6].map(|i| START + i * 3600));
+        assert_eq!(next_record, 7);
+    }
+
+    #[test]
+    fn full_buffer_leaves_the_cursor_after_the_last_record_read() {
+        let records = records(10);
+        let mut buffer = [Rollup::default(); 3];
+        let mut next_record = 0;
+        let window = (START + 3600, u32::MAX);
+
+        let count = read_window_records(
+            ROLLUP_FILE_1H,
+            10,
+            &mut next_record,
+            &mut buffer,
+            window,
+            reader(&records, 0, None),
+        )
+        .unwrap();
+
+        // Record 0 is outside the window but was still examined
+        assert_eq!(count, 3);
+        assert_eq!(buffer[0].start_ts, START + 3600);
+        assert_eq!(next_record, 4);
+    }
+
+    #[test]
+    fn short_read_ends_the_scan() {
+        let records = records(2);
+        let mut next_record = 0;
+
+        // The commit pointer claims more records than the file holds
+        let count = read_window_records(
+            ROLLUP_FILE_1H,
+            5,
+            &mut next_record,
+            &mut [Rollup::default(); 8],
+            (0, u32::MAX),
+            reader(&records, 0, None),
+        )
+        .unwrap();
+
+        assert_eq!((count, next_record), (2, 2));
+    }
+}
@@ -0,0 +1,138 @@
+//! Overflow-safe timestamp arithmetic
+//!
+//! Stored timestamps are `u32` seconds (Unix time once synced, boot-relative
+//! before). Mixing wrapping and saturating arithmetic on them can reorder data
+//! near the `u32` limit: a wrapped timestamp sorts before everything recorded
+//! just ahead of it. [`Timestamp`] centralizes the arithmetic and always
+//! saturates, so time never runs backwards. Records stay plain `u32` on disk;
+//! convert at the point of calculation.
+
+/// Seconds in one UTC day
+pub const SECS_PER_DAY: u32 = 24 * 60 * 60;
+
+/// A point in time in whole seconds
+///
+/// Every operation saturates at `0` and `u32::MAX` instead of wrapping, so
+/// ordering between timestamps is always preserved.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(u32);
+
+impl Timestamp {
+    /// Latest representable time (early 2106 as Unix time)
+    pub const MAX: Self = Self(u32::MAX);
+
+    /// Wrap a raw seconds value
+    pub const fn new(secs: u32) -> Self {
+        Self(secs)
+    }
+
+    /// Raw seconds value, for storage
+    pub const fn secs(self) -> u32 {
+        self.0
+    }
+
+    /// The time `secs` later, pinned at [`Timestamp::MAX`]
+    pub const fn advance(self, secs: u32) -> Self {
+        Self(self.0.saturating_add(secs))
+    }
+
+    /// Start of the window of `window_secs` ending at this time, pinned at zero
+    pub const fn window_start(self, window_secs: u32) -> Self {
+        Self(self.0.saturating_sub(window_secs))
+    }
+
+    /// 00:00 UTC on the day containing this time
+    pub const fn day_start(self) -> Self {
+        Self(self.0 - self.0 % SECS_PER_DAY)
+    }
+
+    /// Whether this time is strictly earlier than `other`
+    pub const fn before(self, other: Self) -> bool {
+        self.0 < other.0
+    }
+
+    /// Seconds from `earlier` to this time, or zero if `earlier` is later
+    pub const fn since(self, earlier: Self) -> u32 {
+        self.0.saturating_sub(earlier.0)
+    }
+}
+
+impl From<u32> for Timestamp {
+    fn from(secs: u32) -> Self {
+        Self(secs)
+    }
+}
+
+impl From<Timestamp> for u32 {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0
+    }
+}
+
+impl core::fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: Timestamp = Timestamp::new(1_700_000_000);
+
+    #[test]
+    fn normal_arithmetic_is_exact() {
+        assert_eq!(NOW.advance(300).secs(), 1_700_000_300);
+        assert_eq!(NOW.window_start(3_600).secs(), 1_699_996_400);
+        assert_eq!(NOW.advance(300).since(NOW), 300);
+        assert!(NOW.before(NOW.advance(1)));
+        assert!(!NOW.before(NOW));
+    }
+
+    #[test]
+    fn advance_pins_at_the_u32_limit() {
+        let near_end = Timestamp::new(u32::MAX - 5);
+
+        assert_eq!(near_end.advance(5), Timestamp::MAX);
+        assert_eq!(near_end.advance(300), Timestamp::MAX);
+        assert_eq!(Timestamp::MAX.advance(u32::MAX), Timestamp::MAX);
+        // Still ordered after everything just before it
+        assert!(near_end.before(near_end.advance(300)));
+        assert!(!near_end.advance(300).before(near_end));
+    }
+
+    #[test]
+    fn window_start_pins_at_zero() {
+        assert_eq!(Timestamp::new(100).window_start(3_600), Timestamp::new(0));
+        assert_eq!(Timestamp::new(3_600).window_start(3_600), Timestamp::new(0));
+        assert_eq!(Timestamp::MAX.window_start(u32::MAX), Timestamp::new(0));
+    }
+
+    #[test]
+    fn day_start_is_utc_midnight() {
+        // 2023-11-14 22:13:20 UTC
+        assert_eq!(NOW.day_start().secs(), 1_699_920_000);
+        let midnight = Timestamp::new(1_699_920_000);
+        assert_eq!(midnight.day_start(), midnight);
+        assert_eq!(midnight.advance(SECS_PER_DAY - 1).day_start(), midnight);
+        assert_eq!(
+            midnight.window_start(1).day_start().secs(),
+            1_699_920_000 - SECS_PER_DAY
+        );
+        assert_eq!(Timestamp::MAX.day_start().secs() % SECS_PER_DAY, 0);
+    }
+
+    #[test]
+    fn since_a_later_time_is_zero() {
+        assert_eq!(NOW.since(NOW.advance(10)), 0);
+        assert_eq!(Timestamp::new(0).since(Timestamp::MAX), 0);
+        assert_eq!(Timestamp::MAX.since(Timestamp::new(0)), u32::MAX);
+    }
+
+    #[test]
+    fn converts_to_and_from_raw_seconds() {
+        assert_eq!(Timestamp::from(42_u32), Timestamp::new(42));
+        assert_eq!(u32::from(Timestamp::MAX), u32::MAX);
+    }
+}
@@ -0,0 +1,159 @@
+//! Host test helpers for async code and I2C drivers.
+//!
+//! - [`block_on`] drives a future to completion on the test thread, advancing
+//!   the embassy mock clock whenever the future waits on a timer
+//! - [`MockI2c`] replays a scripted transcript of I2C transactions and fails
+//!   the test on any transaction it did not expect
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+
+use embassy_time::{Duration, MockDriver};
+use embedded_hal_async::i2c::{ErrorKind, ErrorType, I2c, NoAcknowledgeSource, Operation};
+
+/// Mock clock step taken each time the future under test is pending
+const CLOCK_STEP: Duration = Duration::from_millis(1);
+
+/// Polls before [`block_on`] gives up on a future that never completes
+const MAX_POLLS: u32 = 1_000_000;
+
+/// Run `future` to completion, advancing the mock clock while it waits.
+///
+/// Every pending future in these tests is waiting on an embassy timer, so
+/// stepping the clock is enough to make progress.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+
+    for _ in 0..MAX_POLLS {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        MockDriver::get().advance(CLOCK_STEP);
+    }
+    panic!("future did not complete after {MAX_POLLS} polls");
+}
+
+/// One expected I2C transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum I2cExpect {
+    /// A write of exactly these bytes
+    #[cfg_attr(not(feature = "sensor-bme280"), allow(dead_code))]
+    Write { address: u8, bytes: Vec<u8> },
+    /// A write of these bytes followed by a read answered with `response`
+    WriteRead {
+        address: u8,
+        bytes: Vec<u8>,
+        response: Vec<u8>,
+    },
+    /// A read on its own, answered with `response`
+    #[cfg_attr(not(feature = "sensor-scd41"), allow(dead_code))]
+    Read { address: u8, response: Vec<u8> },
+    /// Any transaction, answered with a bus error (a NACK)
+    Fail,
+}
+
+impl I2cExpect {
+    #[cfg_attr(not(feature = "sensor-bme280"), allow(dead_code))]
+    pub(crate) fn write(address: u8, bytes: &[u8]) -> Self {
+        Self::Write {
+            address,
+            bytes: bytes.to_vec(),
+        }
+    }
+
+    pub(crate) fn write_read(address: u8, bytes: &[u8], response: &[u8]) -> Self {
+        Self::WriteRead {
+            address,
+            bytes: bytes.to_vec(),
+            response: response.to_vec(),
+        }
+    }
+
+    #[cfg_attr(not(feature = "sensor-scd41"), allow(dead_code))]
+    pub(crate) fn read(address: u8, response: &[u8]) -> Self {
+        Self::Read {
+            address,
+            response: response.to_vec(),
+        }
+    }
+}
+
+/// I2C bus replaying a recorded transcript.
+pub(crate) struct MockI2c {
+    expected: VecDeque<I2cExpect>,
+}
+
+impl MockI2c {
+    pub(crate) fn new(expected: impl IntoIterator<Item = I2cExpect>) -> Self {
+        Self {
+            expected: expected.into_iter().collect(),
+        }
+    }
+
+    /// Assert every expected transaction was performed.
+    pub(crate) fn done(&self) {
+        assert!(
+            self.expected.is_empty(),
+            "unperformed I2C transactions: {:?}",
+            self.expected
+        );
+    }
+}
+
+impl ErrorType for MockI2c {
+    type Error = ErrorKind;
+}
+
+impl I2c for MockI2c {
+    async fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        let expected = self
+            .expected
+            .pop_front()
+            .unwrap_or_else(|| panic!("unexpected I2C transaction at 0x{address:02X}"));
+
+        match (expected, operations) {
+            (I2cExpect::Fail, _) => Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+            (I2cExpect::Write { address: a, bytes }, [Operation::Write(written)]) => {
+                assert_eq!((address, *written), (a, bytes.as_slice()));
+                Ok(())
+            }
+            (
+                I2cExpect::Read {
+                    address: a,
+                    response,
+                },
+                [Operation::Read(buffer)],
+            ) => {
+                assert_eq!(address, a);
+                assert_eq!(buffer.len(), response.len(), "read length");
+                buffer.copy_from_slice(&response);
+                Ok(())
+            }
+            (
+                I2cExpect::WriteRead {
+                    address: a,
+                    bytes,
+                    response,
+                },
+                [Operation::Write(written), Operation::Read(buffer)],
+            ) => {
+                assert_eq!((address, *written), (a, bytes.as_slice()));
+                assert_eq!(buffer.len(), response.len(), "read length");
+                buffer.copy_from_slice(&response);
+                Ok(())
+            }
+            (expected, operations) => panic!(
+                "expected {expected:?}, got {} operations at 0x{address:02X}",
+                operations.len()
+            ),
+        }
+    }
+}
@@ -0,0 +1,524 @@
+//! Burst reads of the FT6336U touch registers
+//!
+//! The FT6336U driver's `scan()` fetches the touch status one register at a
+//! time, taking the shared bus lock for every round-trip. This module reads
+//! the whole touch block (`TD_STATUS` through `P2_MISC`) in a single
+//! write-read and decodes it, so a scan costs one bus acquisition and other
+//! devices cannot interleave halfway through a frame.
+
+use embedded_hal_async::i2c::I2c;
+
+/// 7-bit I2C address of the FT6336U
+pub const FT6336U_ADDRESS: u8 = 0x38;
+
+/// First register of the touch block (`TD_STATUS`)
+pub const TOUCH_REGISTERS_START: u8 = 0x02;
+
+/// Length of the touch block, `TD_STATUS` (0x02) through `P2_MISC` (0x0E)
+pub const TOUCH_REGISTERS_LEN: usize = 13;
+
+/// Touch detection threshold register (`ID_G_THGROUP`)
+pub const TOUCH_THRESHOLD_REGISTER: u8 = 0x80;
+
+/// Power control register (`ID_G_CTRL`)
+pub const CTRL_MODE_REGISTER: u8 = 0x86;
+
+/// Report rate while in monitor mode (`ID_G_PERIODMONITOR`, in Hz)
+pub const MONITOR_PERIOD_REGISTER: u8 = 0x89;
+
+/// Time without touches before the controller is put into monitor mode
+pub const TOUCH_MONITOR_AFTER_SECS: u64 = 60;
+
+/// Maximum number of simultaneous touches the controller reports
+pub const MAX_TOUCH_POINTS: usize = 2;
+
+/// Bytes per touch point in the register block (XH, XL, YH, YL, weight, misc)
+const TOUCH_POINT_STRIDE: usize = 6;
+
+/// Event flag reported in the top two bits of `Pn_XH`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TouchEventFlag {
+    /// Finger just went down
+    PressDown,
+    /// Finger just lifted
+    LiftUp,
+    /// Finger is held down
+    Contact,
+    /// No event
+    None,
+}
+
+impl TouchEventFlag {
+    fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0b00 => TouchEventFlag::PressDown,
+            0b01 => TouchEventFlag::LiftUp,
+            0b10 => TouchEventFlag::Contact,
+            _ => TouchEventFlag::None,
+        }
+    }
+}
+
+/// Power behaviour selected through `CTRL_MODE_REGISTER`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TouchCtrlMode {
+    /// Stay in active mode (full scan rate) even without touches
+    KeepActive = 0,
+    /// Drop to monitor mode (reduced scan rate) while nothing is touching
+    SwitchToMonitor = 1,
+}
+
+/// Decides when to move the touch controller between active and monitor mode
+///
+/// Fed with the time of every poll and whether it saw a touch; returns the
+/// mode to write only when it needs to change, so the control register is
+/// written once per transition rather than on every poll.
+///
+/// # Examples
+/// ```ignore
+/// let mut policy = TouchPowerPolicy::new(TOUCH_MONITOR_AFTER_SECS * 1000, now_ms);
+/// if let Some(mode) = policy.update(now_ms, frame.touch_count > 0) {
+///     scanner.write_ctrl_mode(mode).await?;
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TouchPowerPolicy {
+    idle_timeout_ms: u64,
+    last_activity_ms: u64,
+    mode: TouchCtrlMode,
+}
+
+impl TouchPowerPolicy {
+    /// Start in active mode with `now_ms` counted as the last activity
+    pub fn new(idle_timeout_ms: u64, now_ms: u64) -> Self {
+        Self {
+            idle_timeout_ms,
+            last_activity_ms: now_ms,
+            mode: TouchCtrlMode::KeepActive,
+        }
+    }
+
+    /// Mode the controller was last switched to
+    pub fn mode(&self) -> TouchCtrlMode {
+        self.mode
+    }
+
+    /// Record one poll, returning the mode to write if it should change
+    ///
+    /// A touch wakes the controller straight back to active mode; monitor
+    /// mode is entered once `idle_timeout_ms` has passed without one.
+    pub fn update(&mut self, now_ms: u64, touched: bool) -> Option<TouchCtrlMode> {
+        let next = if touched {
+            self.last_activity_ms = now_ms;
+            TouchCtrlMode::KeepActive
+        } else if now_ms.saturating_sub(self.last_activity_ms) >= self.idle_timeout_ms {
+            TouchCtrlMode::SwitchToMonitor
+        } else {
+            self.mode
+        };
+
+        if next == self.mode {
+            return None;
+        }
+        self.mode = next;
+        Some(next)
+    }
+}
+
+/// One decoded touch point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawTouchPoint {
+    /// Controller-assigned touch id (stable while the finger stays down)
+    pub id: u8,
+    pub event: TouchEventFlag,
+    pub x: u16,
+    pub y: u16,
+}
+
+/// Touch state decoded from one burst read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TouchFrame {
+    /// Number of valid entries in `points`
+    pub touch_count: u8,
+    pub points: [Option<RawTouchPoint>; MAX_TOUCH_POINTS],
+}
+
+impl TouchFrame {
+    /// Decode the touch block starting at `TD_STATUS`
+    ///
+    /// Counts above `MAX_TOUCH_POINTS` (the controller reports 0x0F while
+    /// idle after power-up) are treated as no touch.
+    pub fn decode(registers: &[u8; TOUCH_REGISTERS_LEN]) -> Self {
+        let count = registers[0] & 0x0F;
+        if count as usize > MAX_TOUCH_POINTS {
+            return Self::default();
+        }
+
+        let mut frame = Self {
+            touch_count: count,
+            ..Self::default()
+        };
+        for (index, slot) in frame.points.iter_mut().take(count as usize).enumerate() {
+            let base = 1 + index * TOUCH_POINT_STRIDE;
+            let xh = registers[base];
+            let xl = registers[base + 1];
+            let yh = registers[base + 2];
+            let yl = registers[base + 3];
+
+            *slot = Some(RawTouchPoint {
+                id: yh >> 4,
+                event: TouchEventFlag::from_bits(xh >> 6),
+                x: (u16::from(xh & 0x0F) << 8) | u16::from(xl),
+                y: (u16::from(yh & 0x0F) << 8) | u16::from(yl),
+            });
+        }
+        frame
+    }
+
+    /// Iterate over the valid touch points
+    pub fn iter(&self) -> impl Iterator<Item = &RawTouchPoint> {
+        self.points.iter().flatten()
+    }
+}
+
+/// Touch reader for the FT6336U using burst transfers
+///
+/// Setup (gesture mode, chip id) still goes through the `ft6336u_driver`
+/// crate; this type handles the polling path, the touch threshold and the
+/// power mode.
+///
+/// # Examples
+/// ```ignore
+/// let mut scanner = TouchScanner::new(AsyncI2cDevice::new(i2c_bus));
+/// let frame = scanner.read_touch_frame().await?;
+/// for point in frame.iter() {
+///     info!("touch {} at ({}, {})", point.id, point.x, point.y);
+/// }
+/// ```
+pub struct TouchScanner<I> {
+    i2c: I,
+}
+
+impl<I: I2c> TouchScanner<I> {
+    /// Create a scanner over an I2C device
+    pub fn new(i2c: I) -> Self {
+        Self { i2c }
+    }
+
+    /// Read and decode the touch block in a single write-read
+    ///
+    /// On a shared `AsyncI2cDevice` this takes the bus lock exactly once.
+    pub async fn read_touch_frame(&mut self) -> Result<TouchFrame, I::Error> {
+        let mut registers = [0u8; TOUCH_REGISTERS_LEN];
+        self.read_registers(TOUCH_REGISTERS_START, &mut registers)
+            .await?;
+        Ok(TouchFrame::decode(&registers))
+    }
+
+    /// Read a single register, for diagnostics
+    pub async fn read_register(&mut self, register: u8) -> Result<u8, I::Error> {
+        let mut value = [0u8; 1];
+        self.read_registers(register, &mut value).await?;
+        Ok(value[0])
+    }
+
+    /// Read the touch detection threshold (lower is more sensitive)
+    pub async fn read_touch_threshold(&mut self) -> Result<u8, I::Error> {
+        self.read_register(TOUCH_THRESHOLD_REGISTER).await
+    }
+
+    /// Write the touch detection threshold (lower is more sensitive)
+    pub async fn write_touch_threshold(&mut self, value: u8) -> Result<(), I::Error> {
+        self.i2c
+            .write(FT6336U_ADDRESS, &[TOUCH_THRESHOLD_REGISTER, value])
+            .await
+    }
+
+    /// Select whether the controller drops to monitor mode when untouched
+    pub async fn write_ctrl_mode(&mut self, mode: TouchCtrlMode) -> Result<(), I::Error> {
+        self.i2c
+            .write(FT6336U_ADDRESS, &[CTRL_MODE_REGISTER, mode as u8])
+            .await
+    }
+
+    /// Set the report rate used in monitor mode (Hz)
+    pub async fn write_monitor_period(&mut self, rate_hz: u8) -> Result<(), I::Error> {
+        self.i2c
+            .write(FT6336U_ADDRESS, &[MONITOR_PERIOD_REGISTER, rate_hz])
+            .await
+    }
+
+    /// Read consecutive registers starting at `start`
+    pub async fn read_registers(&mut self, start: u8, buffer: &mut [u8]) -> Result<(), I::Error> {
+        self.i2c.write_read(FT6336U_ADDRESS, &[start], buffer).await
+    }
+
+    /// Release the underlying I2C device
+    pub fn release(self) -> I {
+        self.i2c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::async_i2c_bus::{AsyncI2cDevice, BusOperation};
+    use crate::test_util::{I2cExpect, MockI2c, block_on};
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+    use embassy_sync::mutex::Mutex;
+    use embedded_hal_async::i2c::{ErrorType, Operation};
+
+    /// One touch, id 1, held down at (0x123, 0x0AB)
+    const ONE_TOUCH: [u8; TOUCH_REGISTERS_LEN] = [
+        0x01, 0x81, 0x23, 0x10, 0xAB, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00,
+    ];
+
+    /// Counts calls made through an `AsyncI2cDevice`, each of which takes
+    /// the shared bus lock once
+    struct CountingI2c<I> {
+        inner: I,
+        acquisitions: usize,
+    }
+
+    impl<I: ErrorType> ErrorType for CountingI2c<I> {
+        type Error = I::Error;
+    }
+
+    impl<I: I2c> I2c for CountingI2c<I> {
+        async fn transaction(
+            &mut self,
+            address: u8,
+            operations: &mut [Operation<'_>],
+        ) -> Result<(), Self::Error> {
+            self.acquisitions += 1;
+            self.inner.transaction(address, operations).await
+        }
+    }
+
+    /// The old register-at-a-time scan: `TD_STATUS`, then XH, XL, YH and
+    /// YL of each touch point as separate round-trips
+    async fn scan_register_by_register<I: I2c>(
+        scanner: &mut TouchScanner<I>,
+    ) -> Result<TouchFrame, I::Error> {
+        let mut registers = [0u8; TOUCH_REGISTERS_LEN];
+        registers[0] = scanner.read_register(TOUCH_REGISTERS_START).await?;
+        for point in 0..MAX_TOUCH_POINTS {
+            for offset in 0..4 {
+                let index = 1 + point * TOUCH_POINT_STRIDE + offset;
+                registers[index] = scanner
+                    .read_register(TOUCH_REGISTERS_START + index as u8)
+                    .await?;
+            }
+        }
+        Ok(TouchFrame::decode(&registers))
+    }
+
+    /// Expect one single-byte read per register the old scan touched
+    fn register_by_register_transcript() -> Vec<I2cExpect> {
+        let mut indices = vec![0];
+        for point in 0..MAX_TOUCH_POINTS {
+            indices.extend((0..4).map(|offset| 1 + point * TOUCH_POINT_STRIDE + offset));
+        }
+        indices
+            .into_iter()
+            .map(|index| {
+                I2cExpect::write_read(
+                    FT6336U_ADDRESS,
+                    &[TOUCH_REGISTERS_START + index as u8],
+                    &[ONE_TOUCH[index]],
+                )
+            })
+            .collect()
+    }
+
+    fn expected_frame() -> TouchFrame {
+        TouchFrame {
+            touch_count: 1,
+            points: [
+                Some(RawTouchPoint {
+                    id: 1,
+                    event: TouchEventFlag::Contact,
+                    x: 0x123,
+                    y: 0x0AB,
+                }),
+                None,
+            ],
+        }
+    }
+
+    #[test]
+    fn burst_scan_takes_the_bus_lock_once_instead_of_per_register() {
+        // Before: one lock per register
+        let bus = Mutex::<CriticalSectionRawMutex, _>::new(MockI2c::new(
+            register_by_register_transcript(),
+        ));
+        let mut scanner = TouchScanner::new(CountingI2c {
+            inner: AsyncI2cDevice::new(&bus),
+            acquisitions: 0,
+        });
+        let frame = block_on(scan_register_by_register(&mut scanner)).unwrap();
+        let before = scanner.release().acquisitions;
+        assert_eq!(frame, expected_frame());
+        assert_eq!(before, 1 + 4 * MAX_TOUCH_POINTS);
+        bus.into_inner().done();
+
+        // After: the whole block in one write-read
+        let bus = Mutex::<CriticalSectionRawMutex, _>::new(MockI2c::new([I2cExpect::write_read(
+            FT6336U_ADDRESS,
+            &[TOUCH_REGISTERS_START],
+            &ONE_TOUCH,
+        )]));
+        let mut scanner = TouchScanner::new(CountingI2c {
+            inner: AsyncI2cDevice::new(&bus),
+            acquisitions: 0,
+        });
+        let frame = block_on(scanner.read_touch_frame()).unwrap();
+        assert_eq!(frame, expected_frame());
+        assert_eq!(scanner.release().acquisitions, 1);
+        bus.into_inner().done();
+    }
+
+    #[test]
+    fn batch_runs_each_step_in_order() {
+        let bus = Mutex::<CriticalSectionRawMutex, _>::new(MockI2c::new([
+            I2cExpect::write(FT6336U_ADDRESS, &[TOUCH_THRESHOLD_REGISTER, 40]),
+            I2cExpect::write(FT6336U_ADDRESS, &[CTRL_MODE_REGISTER, 0]),
+        ]));
+        let mut device = AsyncI2cDevice::new(&bus);
+
+        block_on(device.batch(&mut [
+            BusOperation::new(
+                FT6336U_ADDRESS,
+                Operation::Write(&[TOUCH_THRESHOLD_REGISTER, 40]),
+            ),
+            BusOperation::new(FT6336U_ADDRESS, Operation::Write(&[CTRL_MODE_REGISTER, 0])),
+        ]))
+        .unwrap();
+        bus.into_inner().done();
+    }
+
+    #[test]
+    fn batch_stops_at_the_first_failing_step() {
+        // A third transaction would panic the mock as unexpected
+        let bus = Mutex::<CriticalSectionRawMutex, _>::new(MockI2c::new([
+            I2cExpect::write(FT6336U_ADDRESS, &[CTRL_MODE_REGISTER, 0]),
+            I2cExpect::Fail,
+        ]));
+        let mut device = AsyncI2cDevice::new(&bus);
+
+        let result = block_on(device.batch(&mut [
+            BusOperation::new(FT6336U_ADDRESS, Operation::Write(&[CTRL_MODE_REGISTER, 0])),
+            BusOperation::new(
+                FT6336U_ADDRESS,
+                Operation::Write(&[MONITOR_PERIOD_REGISTER, 25]),
+            ),
+            BusOperation::new(
+                FT6336U_ADDRESS,
+                Operation::Write(&[MONITOR_PERIOD_REGISTER, 50]),
+            ),
+        ]));
+        assert!(result.is_err());
+        bus.into_inner().done();
+    }
+
+    #[test]
+    fn counts_above_the_point_limit_decode_as_no_touch() {
+        let mut registers = ONE_TOUCH;
+        registers[0] = 0x0F;
+        assert_eq!(TouchFrame::decode(&registers), TouchFrame::default());
+    }
+
+    #[test]
+    fn decodes_a_recorded_two_finger_block() {
+        // Captured from the panel: finger 0 pressing at (250, 130), finger 1
+        // held at (12, 200)
+        let registers: [u8; TOUCH_REGISTERS_LEN] = [
+            0x02, // TD_STATUS: two touches
+            0x00, 0xFA, 0x00, 0x82, 0x20, 0x10, // P1: press down, id 0
+            0x80, 0x0C, 0x10, 0xC8, 0x18, 0x00, // P2: contact, id 1
+        ];
+
+        let frame = TouchFrame::decode(&registers);
+
+        assert_eq!(frame.touch_count, 2);
+        assert_eq!(
+            frame.points,
+            [
+                Some(RawTouchPoint {
+                    id: 0,
+                    event: TouchEventFlag::PressDown,
+                    x: 250,
+                    y: 130,
+                }),
+                Some(RawTouchPoint {
+                    id: 1,
+                    event: TouchEventFlag::Contact,
+                    x: 12,
+                    y: 200,
+                }),
+            ]
+        );
+        assert_eq!(frame.iter().count(), 2);
+    }
+
+    #[test]
+    fn threshold_write_hits_the_threshold_register_and_reads_back() {
+        let mut i2c = MockI2c::new([
+            I2cExpect::write(FT6336U_ADDRESS, &[TOUCH_THRESHOLD_REGISTER, 35]),
+            I2cExpect::write_read(FT6336U_ADDRESS, &[TOUCH_THRESHOLD_REGISTER], &[35]),
+        ]);
+        let mut scanner = TouchScanner::new(&mut i2c);
+
+        block_on(scanner.write_touch_threshold(35)).unwrap();
+        assert_eq!(block_on(scanner.read_touch_threshold()).unwrap(), 35);
+        i2c.done();
+    }
+
+    #[test]
+    fn power_policy_enters_monitor_mode_once_after_the_idle_timeout() {
+        let mut policy = TouchPowerPolicy::new(60_000, 1_000);
+
+        assert_eq!(policy.update(30_000, false), None);
+        assert_eq!(policy.update(60_999, false), None);
+        assert_eq!(
+            policy.update(61_000, false),
+            Some(TouchCtrlMode::SwitchToMonitor)
+        );
+        // Written once per transition, not on every idle poll
+        assert_eq!(policy.update(90_000, false), None);
+        assert_eq!(policy.mode(), TouchCtrlMode::SwitchToMonitor);
+    }
+
+    #[test]
+    fn power_policy_wakes_on_touch_and_restarts_the_idle_clock() {
+        let mut policy = TouchPowerPolicy::new(60_000, 0);
+        assert_eq!(
+            policy.update(60_000, false),
+            Some(TouchCtrlMode::SwitchToMonitor)
+        );
+
+        assert_eq!(policy.update(70_000, true), Some(TouchCtrlMode::KeepActive));
+        assert_eq!(policy.update(70_100, true), None);
+        // The timeout counts from the last touch, not from start-up
+        assert_eq!(policy.update(129_999, false), None);
+        assert_eq!(
+            policy.update(130_100, false),
+            Some(TouchCtrlMode::SwitchToMonitor)
+        );
+    }
+
+    #[test]
+    fn ctrl_mode_write_targets_the_ctrl_register() {
+        let mut i2c = MockI2c::new([
+            I2cExpect::write(FT6336U_ADDRESS, &[CTRL_MODE_REGISTER, 1]),
+            I2cExpect::write(FT6336U_ADDRESS, &[CTRL_MODE_REGISTER, 0]),
+        ]);
+        let mut scanner = TouchScanner::new(&mut i2c);
+
+        block_on(scanner.write_ctrl_mode(TouchCtrlMode::SwitchToMonitor)).unwrap();
+        block_on(scanner.write_ctrl_mode(TouchCtrlMode::KeepActive)).unwrap();
+        i2c.done();
+    }
+}
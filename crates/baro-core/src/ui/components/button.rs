@@ -6,12 +6,18 @@ use crate::ui::core::{
 };
 use crate::ui::styling::{ButtonVariant, ColorPalette, Style};
 use embedded_graphics::Drawable as EgDrawable;
-use embedded_graphics::mono_font::{MonoTextStyle, ascii::FONT_6X10};
+use embedded_graphics::mono_font::{MonoTextStyle, iso_8859_1::FONT_6X10};
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::{Rectangle, RoundedRectangle};
 use embedded_graphics::text::{Alignment as TextAlignment, Text};
 
+/// Percentage of the previous repeat interval used for the next one
+///
+/// 75% shortens the interval by a quarter on every repeat until it reaches
+/// the configured minimum.
+const REPEAT_ACCELERATION_PERCENT: u64 = 75;
+
 /// Button state
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum ButtonState {
@@ -20,6 +26,13 @@ enum ButtonState {
     Disabled,
 }
 
+/// Auto-repeat timing for a held button
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct RepeatConfig {
+    initial_ms: u64,
+    min_ms: u64,
+}
+
 /// Button component with label and action
 ///
 /// An interactive button that responds to touch events and can trigger actions.
@@ -29,13 +42,18 @@ enum ButtonState {
 /// # Visual Features
 /// - Rounded corners (configurable radius)
 /// - Color variants based on ColorPalette
-/// - Visual feedback on press (darkened background)
-/// - Disabled state with dimmed appearance
+/// - Visual feedback while pressed (darkened background, see
+///   `ButtonVariant::to_pressed_style`)
+/// - Disabled state with dimmed appearance that ignores touch (see
+///   `ButtonVariant::to_disabled_style`)
 ///
 /// # Touch Behavior
 /// - Triggers action immediately on press
 /// - Provides visual feedback during press
 /// - Updates state during drag (pressed if over button, normal if dragged away)
+/// - Returns to normal on release
+/// - With `with_repeat`, re-fires the action from `update()` while held,
+///   starting after `initial_ms` and speeding up towards `min_ms`
 ///
 /// # Examples
 /// ```ignore
@@ -55,6 +73,11 @@ pub struct Button {
     variant: ButtonVariant,
     palette: ColorPalette,
     border_radius: u32,
+    repeat: Option<RepeatConfig>,
+    /// Time of the next auto-repeat, once the hold has been timed
+    next_repeat_ms: Option<u64>,
+    /// Interval that produced `next_repeat_ms`
+    repeat_interval_ms: u64,
     dirty: bool,
 }
 
@@ -79,6 +102,9 @@ impl Button {
             variant: ButtonVariant::Primary,
             palette: ColorPalette::default(),
             border_radius: 8,
+            repeat: None,
+            next_repeat_ms: None,
+            repeat_interval_ms: 0,
             dirty: true,
         }
     }
@@ -116,6 +142,9 @@ impl Button {
             variant: ButtonVariant::Primary,
             palette: ColorPalette::default(),
             border_radius: 8,
+            repeat: None,
+            next_repeat_ms: None,
+            repeat_interval_ms: 0,
             dirty: true,
         }
     }
@@ -147,6 +176,20 @@ impl Button {
         self
     }
 
+    /// Re-fire the action while the button is held.
+    ///
+    /// The first repeat happens `initial_ms` after the press is first seen by
+    /// `update()`; each following repeat comes sooner, down to `min_ms`
+    /// (a minimum above `initial_ms` is treated as `initial_ms`).
+    pub fn with_repeat(mut self, initial_ms: u64, min_ms: u64) -> Self {
+        let initial_ms = initial_ms.max(1);
+        self.repeat = Some(RepeatConfig {
+            initial_ms,
+            min_ms: min_ms.clamp(1, initial_ms),
+        });
+        self
+    }
+
     /// Update the button's bounds (useful when managed by a layout container)
     pub fn with_bounds(mut self, bounds: Rectangle) -> Self {
         self.bounds = bounds;
@@ -162,18 +205,28 @@ impl Button {
         }
     }
 
+    /// Set whether the button starts enabled.
+    ///
+    /// Disabled buttons ignore touch and are rendered with dimmed colors.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.set_enabled(enabled);
+        self
+    }
+
     /// Enable or disable the button.
     ///
     /// Disabled buttons don't respond to touch and are rendered with dimmed colors.
     pub fn set_enabled(&mut self, enabled: bool) {
-        let new_state = if enabled {
-            ButtonState::Normal
-        } else {
-            ButtonState::Disabled
+        // Enabling an already enabled button keeps any press in progress
+        let new_state = match (enabled, self.state) {
+            (false, _) => ButtonState::Disabled,
+            (true, ButtonState::Disabled) => ButtonState::Normal,
+            (true, state) => state,
         };
 
         if self.state != new_state {
             self.state = new_state;
+            self.next_repeat_ms = None;
             self.dirty = true;
         }
     }
@@ -188,31 +241,63 @@ impl Button {
         self.action
     }
 
-    fn get_style(&self) -> Style {
-        let base_style = self.variant.to_style(&self.palette);
+    /// Advance the auto-repeat clock, returning the action when it re-fires.
+    ///
+    /// Call periodically with a monotonic millisecond timestamp. Returns
+    /// `None` unless the button is held and has auto-repeat enabled.
+    pub fn update(&mut self, now_ms: u64) -> Option<Action> {
+        let repeat = self.repeat?;
+        if self.state != ButtonState::Pressed {
+            self.next_repeat_ms = None;
+            return None;
+        }
+
+        match self.next_repeat_ms {
+            None => {
+                self.repeat_interval_ms = repeat.initial_ms;
+                self.next_repeat_ms = Some(now_ms + repeat.initial_ms);
+                None
+            }
+            Some(next_repeat_ms) if now_ms >= next_repeat_ms => {
+                self.repeat_interval_ms = (self.repeat_interval_ms * REPEAT_ACCELERATION_PERCENT
+                    / 100)
+                    .max(repeat.min_ms);
+                self.next_repeat_ms = Some(now_ms + self.repeat_interval_ms);
+                Some(self.action)
+            }
+            Some(_) => None,
+        }
+    }
+
+    /// Leave the pressed state and stop any auto-repeat
+    fn release(&mut self) {
+        self.next_repeat_ms = None;
+        if self.state == ButtonState::Pressed {
+            self.state = ButtonState::Normal;
+            self.dirty = true;
+        }
+    }
+
+    /// Check if the button is currently held down.
+    pub fn is_pressed(&self) -> bool {
+        matches!(self.state, ButtonState::Pressed)
+    }
 
+    /// Style the button is drawn with in its current state.
+    pub fn style(&self) -> Style {
         match self.state {
-            ButtonState::Normal => base_style,
-            ButtonState::Pressed => {
-                // Darken the background for pressed state
-                let bg = base_style.background_color.unwrap_or(self.palette.primary);
-                let darkened = Rgb565::new(
-                    bg.r().saturating_sub(4),
-                    bg.g().saturating_sub(8),
-                    bg.b().saturating_sub(4),
-                );
-                base_style.with_background(darkened)
-            }
-            ButtonState::Disabled => base_style
-                .with_background(self.palette.surface)
-                .with_foreground(self.palette.text_secondary),
+            ButtonState::Normal => self.variant.to_style(&self.palette),
+            ButtonState::Pressed => self.variant.to_pressed_style(&self.palette),
+            ButtonState::Disabled => self.variant.to_disabled_style(&self.palette),
         }
     }
 }
 
 impl Drawable for Button {
     fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
-        let style = self.get_style();
+        let style = self.style();
+
+        style.draw_shadow(self.bounds, self.border_radius, display)?;
 
         // Draw button background with rounded corners
         let corner_radius = Size::new(self.border_radius, self.border_radius);
@@ -270,6 +355,7 @@ impl Touchable for Button {
         match event {
             TouchEvent::Press(point) if self.contains_point(point) => {
                 self.state = ButtonState::Pressed;
+                self.next_repeat_ms = None;
                 self.dirty = true;
 
                 // Trigger action immediately on press
@@ -277,19 +363,145 @@ impl Touchable for Button {
             }
             TouchEvent::Drag(point) => {
                 // Update pressed state based on whether drag is still over button
-                let new_state = if self.contains_point(point) {
-                    ButtonState::Pressed
+                if self.contains_point(point) {
+                    if self.state != ButtonState::Pressed {
+                        self.state = ButtonState::Pressed;
+                        self.dirty = true;
+                    }
                 } else {
-                    ButtonState::Normal
-                };
-
-                if self.state != new_state {
-                    self.state = new_state;
-                    self.dirty = true;
+                    // Dragging away also cancels auto-repeat
+                    self.release();
                 }
                 TouchResult::Handled
             }
+            TouchEvent::Release(_) if self.state == ButtonState::Pressed => {
+                self.release();
+                TouchResult::Handled
+            }
             _ => TouchResult::NotHandled,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    const ACTION: Action = Action::Custom(7);
+
+    fn button() -> Button {
+        Button::new(
+            Rectangle::new(Point::new(10, 10), Size::new(60, 30)),
+            "+",
+            ACTION,
+        )
+    }
+
+    fn press() -> TouchEvent {
+        TouchEvent::Press(TouchPoint::new(20, 20))
+    }
+
+    fn release() -> TouchEvent {
+        TouchEvent::Release(TouchPoint::new(20, 20))
+    }
+
+    /// Tick `update()` every 10 ms over `from_ms..=to_ms`, returning when it fired
+    fn hold(button: &mut Button, from_ms: u64, to_ms: u64) -> Vec<u64> {
+        (from_ms..=to_ms)
+            .step_by(10)
+            .filter(|&now_ms| button.update(now_ms).is_some())
+            .collect()
+    }
+
+    #[test]
+    fn held_button_fires_at_a_fixed_rate_and_stops_on_release() {
+        let mut button = button().with_repeat(100, 100);
+        assert_eq!(button.handle_touch(press()), TouchResult::Action(ACTION));
+
+        // Clock starts at 0; one repeat every 100 ms after that
+        let fired = hold(&mut button, 0, 1_000);
+        assert_eq!(fired.len(), 10);
+        assert_eq!(fired[0], 100);
+        assert_eq!(fired[9], 1_000);
+
+        assert_eq!(button.handle_touch(release()), TouchResult::Handled);
+        assert!(!button.is_pressed());
+        assert!(hold(&mut button, 1_010, 3_000).is_empty());
+    }
+
+    #[test]
+    fn repeats_accelerate_down_to_the_minimum_interval() {
+        let mut button = button().with_repeat(400, 100);
+        button.handle_touch(press());
+
+        let fired = hold(&mut button, 0, 3_000);
+        let gaps: Vec<u64> = fired.windows(2).map(|w| w[1] - w[0]).collect();
+        assert_eq!(fired[0], 400);
+        assert!(gaps.windows(2).all(|w| w[1] <= w[0]), "{gaps:?}");
+        assert!(gaps[0] < 400);
+        assert_eq!(*gaps.last().unwrap(), 100);
+    }
+
+    #[test]
+    fn dragging_off_the_button_cancels_the_repeat() {
+        let mut button = button().with_repeat(100, 100);
+        button.handle_touch(press());
+        assert_eq!(hold(&mut button, 0, 250).len(), 2);
+
+        button.handle_touch(TouchEvent::Drag(TouchPoint::new(200, 200)));
+        assert!(hold(&mut button, 260, 1_000).is_empty());
+    }
+
+    #[test]
+    fn button_without_repeat_fires_only_on_press() {
+        let mut button = button();
+        button.handle_touch(press());
+        assert!(hold(&mut button, 0, 2_000).is_empty());
+    }
+
+    #[test]
+    fn disabled_button_ignores_touch() {
+        let mut button = button().with_enabled(false);
+        assert!(!button.is_enabled());
+        assert_eq!(button.handle_touch(press()), TouchResult::NotHandled);
+        assert_eq!(button.handle_touch(release()), TouchResult::NotHandled);
+        assert!(!button.is_pressed());
+
+        let palette = ColorPalette::default();
+        assert_eq!(
+            button.style(),
+            ButtonVariant::Primary.to_disabled_style(&palette)
+        );
+        assert_ne!(button.style(), ButtonVariant::Primary.to_style(&palette));
+    }
+
+    #[test]
+    fn pressed_button_reports_the_pressed_style_until_released() {
+        let palette = ColorPalette::default();
+        let mut button = button();
+        assert_eq!(button.style(), ButtonVariant::Primary.to_style(&palette));
+
+        button.mark_clean();
+        button.handle_touch(press());
+        assert!(button.is_pressed());
+        assert!(button.is_dirty());
+        assert_eq!(
+            button.style(),
+            ButtonVariant::Primary.to_pressed_style(&palette)
+        );
+        assert_ne!(button.style(), ButtonVariant::Primary.to_style(&palette));
+
+        button.handle_touch(release());
+        assert_eq!(button.style(), ButtonVariant::Primary.to_style(&palette));
+    }
+
+    #[test]
+    fn re_enabling_restores_the_normal_style() {
+        let palette = ColorPalette::default();
+        let mut button = button().with_enabled(false);
+        button.set_enabled(true);
+        assert_eq!(button.style(), ButtonVariant::Primary.to_style(&palette));
+        assert_eq!(button.handle_touch(press()), TouchResult::Action(ACTION));
+    }
+}
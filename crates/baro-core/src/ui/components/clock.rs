@@ -0,0 +1,196 @@
+// src/ui/components/clock.rs
+//! Wall-clock readout showing the synced time as `HH:MM`
+
+use core::fmt::Write;
+
+use crate::ui::core::{DirtyRegion, Drawable};
+use crate::ui::styling::ColorPalette;
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::mono_font::{MonoTextStyle, iso_8859_1::FONT_6X10};
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+use heapless::String;
+
+/// Seconds in a day
+const SECS_PER_DAY: i64 = 86_400;
+
+/// Text shown before the first time update arrives
+const UNKNOWN_TIME_TEXT: &str = "--:--";
+
+/// Format `unix_secs` shifted by `utc_offset_secs` as a 24-hour `HH:MM`
+pub fn clock_text(unix_secs: u32, utc_offset_secs: i32) -> String<5> {
+    let local_secs = unix_secs as i64 + utc_offset_secs as i64;
+    let secs_of_day = local_secs.rem_euclid(SECS_PER_DAY);
+
+    let mut text = String::new();
+    let _ = write!(
+        text,
+        "{:02}:{:02}",
+        secs_of_day / 3600,
+        (secs_of_day % 3600) / 60
+    );
+    text
+}
+
+/// Header clock
+///
+/// Shows the time from the latest `SystemEvent::Time` in the palette's
+/// secondary text color, right-aligned in its bounds, or `--:--` until the
+/// first update. Only marks itself dirty when the displayed minute changes.
+///
+/// # Examples
+/// ```ignore
+/// let mut clock = Clock::new(Rectangle::new(Point::new(240, 12), Size::new(30, 10)));
+/// clock.set_time(1_700_000_000); // "22:13"
+/// ```
+pub struct Clock {
+    bounds: Rectangle,
+    unix_secs: Option<u32>,
+    utc_offset_secs: i32,
+    palette: ColorPalette,
+    dirty: bool,
+}
+
+impl Clock {
+    /// Create a clock showing UTC with no time yet
+    pub fn new(bounds: Rectangle) -> Self {
+        Self {
+            bounds,
+            unix_secs: None,
+            utc_offset_secs: 0,
+            palette: ColorPalette::default(),
+            dirty: true,
+        }
+    }
+
+    /// Set the clock's color palette.
+    pub fn with_palette(mut self, palette: ColorPalette) -> Self {
+        self.palette = palette;
+        self.dirty = true;
+        self
+    }
+
+    /// Show local time `utc_offset_secs` ahead of UTC (negative for behind)
+    pub fn with_utc_offset_secs(mut self, utc_offset_secs: i32) -> Self {
+        self.utc_offset_secs = utc_offset_secs;
+        self.dirty = true;
+        self
+    }
+
+    /// Latest Unix time, if any
+    pub fn time(&self) -> Option<u32> {
+        self.unix_secs
+    }
+
+    /// Text currently displayed
+    pub fn text(&self) -> String<5> {
+        match self.unix_secs {
+            Some(unix_secs) => clock_text(unix_secs, self.utc_offset_secs),
+            None => String::try_from(UNKNOWN_TIME_TEXT).unwrap_or_default(),
+        }
+    }
+
+    /// Update the time, returning whether the displayed minute changed
+    pub fn set_time(&mut self, unix_secs: u32) -> bool {
+        let previous = self.text();
+        self.unix_secs = Some(unix_secs);
+        let changed = self.text() != previous;
+        if changed {
+            self.dirty = true;
+        }
+        changed
+    }
+}
+
+impl Drawable for Clock {
+    fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        // Clear first so a shorter redraw leaves no stale digits
+        self.bounds
+            .into_styled(PrimitiveStyle::with_fill(self.palette.surface))
+            .draw(display)?;
+
+        let anchor = Point::new(
+            self.bounds.top_left.x + self.bounds.size.width as i32,
+            self.bounds.center().y,
+        );
+        Text::with_text_style(
+            &self.text(),
+            anchor,
+            MonoTextStyle::new(&FONT_6X10, self.palette.text_secondary),
+            TextStyleBuilder::new()
+                .alignment(Alignment::Right)
+                .baseline(Baseline::Middle)
+                .build(),
+        )
+        .draw(display)?;
+
+        Ok(())
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    fn dirty_region(&self) -> Option<DirtyRegion> {
+        if self.dirty {
+            Some(DirtyRegion::new(self.bounds))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_unix_time_formats_as_utc_clock() {
+        // 2023-11-14 22:13:20 UTC
+        assert_eq!(clock_text(1_700_000_000, 0), "22:13");
+        assert_eq!(clock_text(0, 0), "00:00");
+        assert_eq!(clock_text(86_399, 0), "23:59");
+        assert_eq!(clock_text(u32::MAX, 0), "06:28");
+    }
+
+    #[test]
+    fn utc_offset_shifts_and_wraps_the_day() {
+        assert_eq!(clock_text(1_700_000_000, 3600), "23:13");
+        assert_eq!(clock_text(1_700_000_000, 2 * 3600), "00:13");
+        assert_eq!(clock_text(1_700_000_000, -5 * 3600), "17:13");
+        // Negative offsets before the first midnight wrap to the previous day
+        assert_eq!(clock_text(0, -30 * 60), "23:30");
+    }
+
+    #[test]
+    fn set_time_reports_only_minute_changes() {
+        let mut clock = Clock::new(Rectangle::new(Point::zero(), Size::new(30, 10)));
+        assert_eq!(clock.text(), UNKNOWN_TIME_TEXT);
+
+        assert!(clock.set_time(1_700_000_000));
+        assert_eq!(clock.text(), "22:13");
+        clock.mark_clean();
+
+        // Same minute: nothing to redraw
+        assert!(!clock.set_time(1_700_000_039));
+        assert!(!clock.is_dirty());
+
+        assert!(clock.set_time(1_700_000_040));
+        assert_eq!(clock.text(), "22:14");
+        assert!(clock.is_dirty());
+    }
+}
@@ -0,0 +1,287 @@
+// src/ui/components/gauge.rs
+//! Arc gauge showing a value between a minimum and maximum
+
+use crate::ui::core::{DirtyRegion, Drawable};
+use crate::ui::styling::ColorPalette;
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::mono_font::iso_8859_1::FONT_10X20;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Arc, PrimitiveStyleBuilder, Rectangle, StrokeAlignment};
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+
+extern crate alloc;
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Default arc start angle in degrees (0° = 3 o'clock, clockwise)
+///
+/// 135° starts the arc at the lower left so a 270° sweep leaves the gap at the bottom.
+const DEFAULT_START_ANGLE_DEG: f32 = 135.0;
+
+/// Default arc sweep in degrees
+const DEFAULT_SWEEP_DEG: f32 = 270.0;
+
+/// Default arc stroke width in pixels
+const DEFAULT_STROKE_WIDTH_PX: u32 = 8;
+
+/// Label shown when there is no reading
+const NO_VALUE_LABEL: &str = "--";
+
+/// Arc gauge with a proportional fill and a centered numeric label
+///
+/// The track spans the full configured sweep in the palette's border color;
+/// the fill covers the part of the sweep proportional to the value's position
+/// between `min` and `max`. Values outside the range are clamped. The fill
+/// color is set by the caller, typically from a `QualityLevel`.
+///
+/// # Examples
+/// ```ignore
+/// let mut gauge = Gauge::new(Rectangle::new(Point::new(10, 40), Size::new(120, 120)), 400.0, 2000.0);
+/// gauge.set_value(Some(co2_ppm));
+/// gauge.set_color(QualityLevel::assess(SensorType::Co2, co2_ppm).foreground_color());
+/// ```
+pub struct Gauge {
+    bounds: Rectangle,
+    min: f32,
+    max: f32,
+    value: Option<f32>,
+    start_angle_deg: f32,
+    sweep_deg: f32,
+    stroke_width: u32,
+    decimals: usize,
+    color: Rgb565,
+    palette: ColorPalette,
+    dirty: bool,
+}
+
+impl Gauge {
+    /// Create a gauge over `min..=max` with no reading
+    pub fn new(bounds: Rectangle, min: f32, max: f32) -> Self {
+        let palette = ColorPalette::default();
+        Self {
+            bounds,
+            min,
+            max,
+            value: None,
+            start_angle_deg: DEFAULT_START_ANGLE_DEG,
+            sweep_deg: DEFAULT_SWEEP_DEG,
+            stroke_width: DEFAULT_STROKE_WIDTH_PX,
+            decimals: 0,
+            color: palette.primary,
+            palette,
+            dirty: true,
+        }
+    }
+
+    /// Set the arc start angle and sweep in degrees (0° = 3 o'clock, clockwise)
+    pub fn with_sweep(mut self, start_angle_deg: f32, sweep_deg: f32) -> Self {
+        self.start_angle_deg = start_angle_deg;
+        self.sweep_deg = sweep_deg;
+        self.dirty = true;
+        self
+    }
+
+    /// Set the arc stroke width in pixels (at least 1).
+    pub fn with_stroke_width(mut self, width: u32) -> Self {
+        self.stroke_width = width.max(1);
+        self.dirty = true;
+        self
+    }
+
+    /// Set the number of decimal places shown in the label.
+    pub fn with_decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self.dirty = true;
+        self
+    }
+
+    /// Set the gauge's color palette.
+    pub fn with_palette(mut self, palette: ColorPalette) -> Self {
+        self.palette = palette;
+        self.dirty = true;
+        self
+    }
+
+    /// Current reading, if any
+    pub fn value(&self) -> Option<f32> {
+        self.value
+    }
+
+    /// Update the reading, marking dirty only if it changes
+    pub fn set_value(&mut self, value: Option<f32>) {
+        if self.value != value {
+            self.value = value;
+            self.dirty = true;
+        }
+    }
+
+    /// Update the fill color, marking dirty only if it changes
+    pub fn set_color(&mut self, color: Rgb565) {
+        if self.color != color {
+            self.color = color;
+            self.dirty = true;
+        }
+    }
+
+    /// Sweep in degrees covered by the fill for `value`
+    ///
+    /// 0 at or below `min`, the full configured sweep at or above `max`, and
+    /// linear in between. A degenerate range (`max <= min`) or a non-finite
+    /// value yields 0.
+    pub fn fill_sweep_deg(&self, value: f32) -> f32 {
+        let span = self.max - self.min;
+        if span.is_nan() || span <= 0.0 || !value.is_finite() {
+            return 0.0;
+        }
+        ((value - self.min) / span).clamp(0.0, 1.0) * self.sweep_deg
+    }
+
+    /// Largest square centered in the bounds, which the arc is inscribed in
+    fn arc_square(&self) -> (Point, u32) {
+        let diameter = self.bounds.size.width.min(self.bounds.size.height);
+        let top_left = self.bounds.center() - Point::new(diameter as i32 / 2, diameter as i32 / 2);
+        (top_left, diameter)
+    }
+
+    fn draw_arc<D: DrawTarget<Color = Rgb565>>(
+        &self,
+        display: &mut D,
+        sweep_deg: f32,
+        color: Rgb565,
+    ) -> Result<(), D::Error> {
+        let (top_left, diameter) = self.arc_square();
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(color)
+            .stroke_width(self.stroke_width)
+            .stroke_alignment(StrokeAlignment::Inside)
+            .build();
+
+        Arc::new(
+            top_left,
+            diameter,
+            Angle::from_degrees(self.start_angle_deg),
+            Angle::from_degrees(sweep_deg),
+        )
+        .into_styled(style)
+        .draw(display)?;
+
+        Ok(())
+    }
+}
+
+impl Drawable for Gauge {
+    fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        // Track, then the fill on top of it
+        self.draw_arc(display, self.sweep_deg, self.palette.border)?;
+
+        let mut label = String::new();
+        match self.value {
+            Some(value) if value.is_finite() => {
+                let fill_sweep = self.fill_sweep_deg(value);
+                if fill_sweep > 0.0 {
+                    self.draw_arc(display, fill_sweep, self.color)?;
+                }
+                let _ = write!(label, "{:.*}", self.decimals, value);
+            }
+            _ => label.push_str(NO_VALUE_LABEL),
+        }
+
+        let character_style = MonoTextStyle::new(&FONT_10X20, self.palette.text_primary);
+        let text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Middle)
+            .build();
+        Text::with_text_style(&label, self.bounds.center(), character_style, text_style)
+            .draw(display)?;
+
+        Ok(())
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    fn dirty_region(&self) -> Option<DirtyRegion> {
+        if self.dirty {
+            Some(DirtyRegion::new(self.bounds))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framebuffer::FrameBuffer;
+
+    fn gauge() -> Gauge {
+        Gauge::new(
+            Rectangle::new(Point::zero(), Size::new(60, 60)),
+            400.0,
+            2000.0,
+        )
+    }
+
+    #[test]
+    fn sweep_is_zero_at_min_and_full_at_max() {
+        let gauge = gauge();
+        assert_eq!(gauge.fill_sweep_deg(400.0), 0.0);
+        assert_eq!(gauge.fill_sweep_deg(2000.0), DEFAULT_SWEEP_DEG);
+        assert_eq!(gauge.fill_sweep_deg(1200.0), DEFAULT_SWEEP_DEG / 2.0);
+
+        let custom = gauge.with_sweep(180.0, 180.0);
+        assert_eq!(custom.fill_sweep_deg(400.0), 0.0);
+        assert_eq!(custom.fill_sweep_deg(2000.0), 180.0);
+    }
+
+    #[test]
+    fn sweep_clamps_out_of_range_and_degenerate_inputs() {
+        let gauge = gauge();
+        assert_eq!(gauge.fill_sweep_deg(0.0), 0.0);
+        assert_eq!(gauge.fill_sweep_deg(5000.0), DEFAULT_SWEEP_DEG);
+        assert_eq!(gauge.fill_sweep_deg(f32::NAN), 0.0);
+
+        let flat = Gauge::new(Rectangle::zero(), 10.0, 10.0);
+        assert_eq!(flat.fill_sweep_deg(10.0), 0.0);
+    }
+
+    #[test]
+    fn fill_color_only_appears_with_a_value_above_min() {
+        let fill = Rgb565::RED;
+        let count_fill = |gauge: &Gauge| {
+            let mut fb = FrameBuffer::<60, 60>::new();
+            gauge.draw(&mut fb).unwrap();
+            Rectangle::new(Point::zero(), Size::new(60, 60))
+                .points()
+                .filter(|p| fb.pixel(p.x as usize, p.y as usize) == Some(fill))
+                .count()
+        };
+
+        let mut gauge = gauge();
+        gauge.set_color(fill);
+        gauge.set_value(Some(400.0));
+        assert_eq!(count_fill(&gauge), 0);
+
+        gauge.set_value(Some(1200.0));
+        let half = count_fill(&gauge);
+        gauge.set_value(Some(2000.0));
+        let full = count_fill(&gauge);
+        assert!(half > 0);
+        assert!(full > half);
+    }
+}
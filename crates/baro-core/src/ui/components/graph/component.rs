@@ -6,7 +6,7 @@ use embedded_graphics::Drawable as EgDrawable;
 use embedded_graphics::mono_font::MonoTextStyle;
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
-use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+use embedded_graphics::primitives::{Line, PrimitiveStyle, Rectangle};
 use embedded_graphics::text::{Alignment, Text};
 
 extern crate alloc;
@@ -16,11 +16,15 @@ use alloc::vec::Vec;
 use crate::ui::core::Drawable;
 
 use super::axis::{AxisConfig, XAxisConfig, YAxisConfig, draw_x_axis_labels, draw_y_axis_labels};
-use super::constants::AUTO_SCALE_MARGIN_FACTOR;
+use super::constants::{
+    AUTO_SCALE_MARGIN_FACTOR, CURRENT_VALUE_LABEL_GAP_PX, X_MARKER_TICK_HEIGHT_PX,
+};
 use super::grid::{GridConfig, draw_grid};
 use super::interpolation::{
-    draw_linear_fill, draw_linear_series, draw_smooth_fill, draw_smooth_series,
+    draw_latest_marker, draw_linear_fill, draw_linear_series, draw_smooth_fill, draw_smooth_series,
+    resolve_interpolation,
 };
+use super::legend::{LegendConfig, draw_legend};
 use super::series::{DataPoint, DataSeries, InterpolationType, SeriesCollection};
 use super::viewport::{DataBounds, Viewport, ViewportPadding};
 use super::{GraphError, GraphResult};
@@ -42,6 +46,59 @@ pub enum CurrentValuePosition {
         /// Vertical offset from top edge in pixels
         offset_y: u32,
     },
+    /// Bottom right corner with offset
+    BottomRight {
+        /// Horizontal offset from right edge in pixels
+        offset_x: u32,
+        /// Vertical offset from bottom edge to the label baseline in pixels
+        offset_y: u32,
+    },
+    /// Bottom left corner with offset
+    BottomLeft {
+        /// Horizontal offset from left edge in pixels
+        offset_x: u32,
+        /// Vertical offset from bottom edge to the label baseline in pixels
+        offset_y: u32,
+    },
+}
+
+impl CurrentValuePosition {
+    /// Baseline anchor of the value text within `bounds`, and its alignment
+    ///
+    /// The label is drawn `CURRENT_VALUE_LABEL_GAP_PX` below the value, so
+    /// bottom positions lift the value by that much to keep the label
+    /// `offset_y` above the bottom edge.
+    pub fn anchor(self, bounds: Rectangle) -> (Point, Alignment) {
+        let left = bounds.top_left.x;
+        let right = bounds.top_left.x + bounds.size.width as i32;
+        let top = bounds.top_left.y;
+        let bottom = bounds.top_left.y + bounds.size.height as i32;
+
+        match self {
+            Self::TopRight { offset_x, offset_y } => (
+                Point::new(right - offset_x as i32, top + offset_y as i32),
+                Alignment::Right,
+            ),
+            Self::TopLeft { offset_x, offset_y } => (
+                Point::new(left + offset_x as i32, top + offset_y as i32),
+                Alignment::Left,
+            ),
+            Self::BottomRight { offset_x, offset_y } => (
+                Point::new(
+                    right - offset_x as i32,
+                    bottom - offset_y as i32 - CURRENT_VALUE_LABEL_GAP_PX,
+                ),
+                Alignment::Right,
+            ),
+            Self::BottomLeft { offset_x, offset_y } => (
+                Point::new(
+                    left + offset_x as i32,
+                    bottom - offset_y as i32 - CURRENT_VALUE_LABEL_GAP_PX,
+                ),
+                Alignment::Left,
+            ),
+        }
+    }
 }
 
 /// Current value display configuration
@@ -74,6 +131,18 @@ pub struct Graph<const MAX_SERIES: usize, const MAX_POINTS: usize> {
     viewport: Viewport,
     /// Optional current value display
     current_value_display: Option<CurrentValueDisplay>,
+    /// Optional legend listing labelled series
+    legend: Option<LegendConfig>,
+    /// Pinned Y-axis range (min, max); `None` auto-scales to the data
+    fixed_y_bounds: Option<(f32, f32)>,
+    /// Running extents of every series point, grown as points are pushed
+    data_extents: Option<DataBounds>,
+    /// Set when points were removed and `data_extents` may be too wide
+    bounds_dirty: bool,
+    /// X positions (data space) marked with a tick at the plot bottom
+    x_markers: Vec<f32>,
+    /// Color of the X-axis marker ticks
+    x_marker_color: Rgb565,
     /// Background color
     background_color: Rgb565,
     /// Dirty flag for rendering optimization
@@ -94,6 +163,12 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> Graph<MAX_SERIES, MAX_POI
             axis_config: AxisConfig::default(),
             viewport,
             current_value_display: None,
+            legend: None,
+            fixed_y_bounds: None,
+            data_extents: None,
+            bounds_dirty: false,
+            x_markers: Vec::new(),
+            x_marker_color: Rgb565::WHITE,
             background_color: Rgb565::BLACK,
             dirty: true,
         }
@@ -129,6 +204,12 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> Graph<MAX_SERIES, MAX_POI
         self
     }
 
+    /// Show a legend of the labelled, visible series
+    pub fn with_legend(mut self, config: LegendConfig) -> Self {
+        self.legend = Some(config);
+        self
+    }
+
     /// Set viewport padding
     pub fn with_padding(mut self, padding: ViewportPadding) -> Self {
         self.viewport = self.viewport.with_padding(padding);
@@ -137,11 +218,16 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> Graph<MAX_SERIES, MAX_POI
 
     /// Add a data series to the graph
     ///
-    /// Returns the series index on success, or error if at capacity.
+    /// Returns the series index on success, or error if at capacity or the
+    /// series' interpolation parameters are invalid.
     pub fn add_series(&mut self, series: DataSeries<MAX_POINTS>) -> GraphResult<usize> {
+        let series_extents = DataBounds::extents(series.points());
         let result = self.series_collection.add(series);
         if result.is_ok() {
-            // Recalculate viewport to fit the new series data
+            // Widen the cached extents to fit the new series data
+            if let Some(extents) = series_extents {
+                self.extend_extents(&extents);
+            }
             let _ = self.recalculate_viewport();
             self.dirty = true;
         }
@@ -155,7 +241,8 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> Graph<MAX_SERIES, MAX_POI
 
     /// Push a data point to a specific series
     ///
-    /// Automatically recalculates viewport bounds.
+    /// Grows the cached data bounds by the new point rather than rescanning
+    /// every series.
     pub fn push_point(&mut self, series_idx: usize, point: DataPoint) -> GraphResult<()> {
         let series = self
             .series_collection
@@ -166,6 +253,7 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> Graph<MAX_SERIES, MAX_POI
             .push(point)
             .map_err(|_| GraphError::PointCapacityExceeded { max: MAX_POINTS })?;
 
+        self.extend_extents(&DataBounds::from_point(point));
         self.recalculate_viewport()?;
         self.dirty = true;
         Ok(())
@@ -189,6 +277,8 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> Graph<MAX_SERIES, MAX_POI
             }
         }
 
+        // Replaced points may have defined the old extents
+        self.bounds_dirty = true;
         self.recalculate_viewport()?;
         self.dirty = true;
         Ok(())
@@ -230,36 +320,105 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> Graph<MAX_SERIES, MAX_POI
         Ok(())
     }
 
+    /// Pin the Y-axis range, disabling Y auto-scaling.
+    ///
+    /// Stays in effect as data changes; values outside the range are clipped
+    /// at the plot edge rather than rescaling the axis.
+    pub fn set_y_bounds(&mut self, y_min: f32, y_max: f32) -> GraphResult<()> {
+        if y_min >= y_max || !y_min.is_finite() || !y_max.is_finite() {
+            return Err(GraphError::InvalidDataBounds);
+        }
+
+        self.fixed_y_bounds = Some((y_min, y_max));
+        let mut bounds = *self.viewport.data_bounds();
+        bounds.y_min = y_min;
+        bounds.y_max = y_max;
+        self.viewport.set_data_bounds(bounds);
+        self.dirty = true;
+        Ok(())
+    }
+
+    /// Return to auto-scaling the Y axis from the data.
+    pub fn clear_y_bounds(&mut self) {
+        self.fixed_y_bounds = None;
+        let _ = self.recalculate_viewport();
+        self.dirty = true;
+    }
+
+    /// Mark X positions with short ticks rising from the bottom of the plot
+    ///
+    /// Replaces any previous markers; positions outside the X bounds are
+    /// skipped when drawing.
+    pub fn set_x_markers(&mut self, xs: &[f32], color: Rgb565) {
+        self.x_markers.clear();
+        self.x_markers.extend_from_slice(xs);
+        self.x_marker_color = color;
+        self.dirty = true;
+    }
+
+    /// Remove all X-axis marker ticks
+    pub fn clear_x_markers(&mut self) {
+        self.x_markers.clear();
+        self.dirty = true;
+    }
+
     /// Clear current value display
     pub fn clear_current_value(&mut self) {
         self.current_value_display = None;
         self.dirty = true;
     }
 
-    /// Recalculate viewport bounds from all series data
-    fn recalculate_viewport(&mut self) -> GraphResult<()> {
-        // Collect all points from all series
-        // Note: We use a large fixed capacity since const generic expressions
-        // are not yet stable in Rust
-        let max_total_points = MAX_SERIES * MAX_POINTS;
-        let mut all_points: Vec<DataPoint> = Vec::with_capacity(max_total_points);
+    /// Discard the cached data bounds and rebuild them from every series
+    ///
+    /// Pushes only ever grow the cached bounds, so call this after points
+    /// leave a series by some path the graph doesn't see.
+    pub fn mark_bounds_dirty(&mut self) {
+        self.bounds_dirty = true;
+        let _ = self.recalculate_viewport();
+        self.dirty = true;
+    }
+
+    /// Grow the cached extents, unless a full rescan is already pending
+    fn extend_extents(&mut self, extents: &DataBounds) {
+        if self.bounds_dirty {
+            return;
+        }
+        match &mut self.data_extents {
+            Some(current) => current.union(extents),
+            None => self.data_extents = Some(*extents),
+        }
+    }
 
+    /// Rebuild the cached extents by scanning every series point
+    fn rescan_extents(&mut self) {
+        let mut extents: Option<DataBounds> = None;
         for series in self.series_collection.iter() {
-            for point in series.points() {
-                if all_points.len() >= max_total_points {
-                    break;
+            if let Some(series_extents) = DataBounds::extents(series.points()) {
+                match &mut extents {
+                    Some(current) => current.union(&series_extents),
+                    None => extents = Some(series_extents),
                 }
-                all_points.push(*point);
             }
         }
+        self.data_extents = extents;
+        self.bounds_dirty = false;
+    }
 
-        if all_points.is_empty() {
-            return Err(GraphError::NoData);
+    /// Recalculate viewport bounds from the cached data extents
+    fn recalculate_viewport(&mut self) -> GraphResult<()> {
+        if self.bounds_dirty {
+            self.rescan_extents();
         }
 
         // Calculate bounds with margin
-        let bounds = DataBounds::from_points(&all_points, AUTO_SCALE_MARGIN_FACTOR)
-            .ok_or(GraphError::NoData)?;
+        let mut bounds = self
+            .data_extents
+            .ok_or(GraphError::NoData)?
+            .with_margin(AUTO_SCALE_MARGIN_FACTOR);
+        if let Some((y_min, y_max)) = self.fixed_y_bounds {
+            bounds.y_min = y_min;
+            bounds.y_max = y_max;
+        }
 
         self.viewport.set_data_bounds(bounds);
         Ok(())
@@ -282,9 +441,12 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> Graph<MAX_SERIES, MAX_POI
                 continue;
             }
 
+            let interpolation =
+                resolve_interpolation(series.interpolation(), series.points(), &self.viewport);
+
             if let Some(fill) = &series.style().fill {
-                match series.interpolation() {
-                    InterpolationType::Linear => {
+                match interpolation {
+                    InterpolationType::Linear | InterpolationType::Auto => {
                         draw_linear_fill(
                             series.points(),
                             &self.viewport,
@@ -306,8 +468,8 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> Graph<MAX_SERIES, MAX_POI
                 }
             }
 
-            match series.interpolation() {
-                InterpolationType::Linear => {
+            match interpolation {
+                InterpolationType::Linear | InterpolationType::Auto => {
                     draw_linear_series(series.points(), &self.viewport, series.style(), display)?;
                 }
                 InterpolationType::Smooth { tension } => {
@@ -320,6 +482,29 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> Graph<MAX_SERIES, MAX_POI
                     )?;
                 }
             }
+
+            if series.shows_latest_marker() {
+                draw_latest_marker(series.points(), &self.viewport, series.style(), display)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draw the X-axis marker ticks
+    fn draw_x_markers<D: DrawTarget<Color = Rgb565>>(
+        &self,
+        display: &mut D,
+    ) -> Result<(), D::Error> {
+        let y_min = self.viewport.data_bounds().y_min;
+        let style = PrimitiveStyle::with_stroke(self.x_marker_color, 1);
+
+        for &x in &self.x_markers {
+            let Some(base) = self.viewport.data_to_screen(DataPoint::new(x, y_min)) else {
+                continue;
+            };
+            let top = base - Point::new(0, X_MARKER_TICK_HEIGHT_PX as i32 - 1);
+            Line::new(base, top).into_styled(style).draw(display)?;
         }
 
         Ok(())
@@ -331,35 +516,19 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> Graph<MAX_SERIES, MAX_POI
         display: &mut D,
     ) -> Result<(), D::Error> {
         if let Some(ref config) = self.current_value_display {
-            let (x, y, alignment) = match config.position {
-                CurrentValuePosition::TopRight { offset_x, offset_y } => (
-                    self.bounds.top_left.x + self.bounds.size.width as i32 - offset_x as i32,
-                    self.bounds.top_left.y + offset_y as i32,
-                    Alignment::Right,
-                ),
-                CurrentValuePosition::TopLeft { offset_x, offset_y } => (
-                    self.bounds.top_left.x + offset_x as i32,
-                    self.bounds.top_left.y + offset_y as i32,
-                    Alignment::Left,
-                ),
-            };
+            let (anchor, alignment) = config.position.anchor(self.bounds);
 
             // Draw value (large)
             let mut value_str = String::new();
             let _ = core::fmt::write(&mut value_str, format_args!("{:.0}", config.value));
 
-            Text::with_alignment(
-                value_str.as_str(),
-                Point::new(x, y),
-                config.value_style,
-                alignment,
-            )
-            .draw(display)?;
+            Text::with_alignment(value_str.as_str(), anchor, config.value_style, alignment)
+                .draw(display)?;
 
             // Draw label (small, below value)
             Text::with_alignment(
                 config.label.as_str(),
-                Point::new(x, y + 15),
+                anchor + Point::new(0, CURRENT_VALUE_LABEL_GAP_PX),
                 config.label_style,
                 alignment,
             )
@@ -376,6 +545,7 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> Drawable for Graph<MAX_SE
         self.draw_background(display)?;
         draw_grid(&self.grid_config, &self.viewport, display)?;
         self.draw_series(display)?;
+        self.draw_x_markers(display)?;
 
         if let Some(ref x_axis) = self.axis_config.x_axis {
             draw_x_axis_labels(x_axis, &self.viewport, display)?;
@@ -385,6 +555,16 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> Drawable for Graph<MAX_SE
             draw_y_axis_labels(y_axis, &self.viewport, display)?;
         }
 
+        if let Some(ref legend) = self.legend {
+            let entries: Vec<(&str, Rgb565)> = self
+                .series_collection
+                .iter()
+                .filter(|series| series.is_visible())
+                .filter_map(|series| Some((series.label()?, series.style().color)))
+                .collect();
+            draw_legend(legend, &entries, self.viewport.plot_area(), display)?;
+        }
+
         self.draw_current_value(display)?;
 
         Ok(())
@@ -406,3 +586,185 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> Drawable for Graph<MAX_SE
         self.dirty = true;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::components::graph::series::SeriesStyle;
+
+    /// 200x100 graph whose top-left corner is at (10, 20)
+    fn bounds() -> Rectangle {
+        Rectangle::new(Point::new(10, 20), Size::new(200, 100))
+    }
+
+    #[test]
+    fn top_positions_anchor_below_the_top_edge() {
+        let top_right = CurrentValuePosition::TopRight {
+            offset_x: 8,
+            offset_y: 12,
+        };
+        assert_eq!(
+            top_right.anchor(bounds()),
+            (Point::new(202, 32), Alignment::Right)
+        );
+
+        let top_left = CurrentValuePosition::TopLeft {
+            offset_x: 8,
+            offset_y: 12,
+        };
+        assert_eq!(
+            top_left.anchor(bounds()),
+            (Point::new(18, 32), Alignment::Left)
+        );
+    }
+
+    #[test]
+    fn bottom_positions_keep_the_label_offset_above_the_bottom_edge() {
+        // The bottom edge is y = 120; the label baseline lands at 120 - 6
+        let bottom_right = CurrentValuePosition::BottomRight {
+            offset_x: 8,
+            offset_y: 6,
+        };
+        let (anchor, alignment) = bottom_right.anchor(bounds());
+        assert_eq!(anchor, Point::new(202, 114 - CURRENT_VALUE_LABEL_GAP_PX));
+        assert_eq!(alignment, Alignment::Right);
+        assert_eq!(anchor.y + CURRENT_VALUE_LABEL_GAP_PX, 114);
+
+        let bottom_left = CurrentValuePosition::BottomLeft {
+            offset_x: 8,
+            offset_y: 6,
+        };
+        assert_eq!(
+            bottom_left.anchor(bounds()),
+            (
+                Point::new(18, 114 - CURRENT_VALUE_LABEL_GAP_PX),
+                Alignment::Left
+            )
+        );
+    }
+
+    /// Linear red series at 50, 150, 50 over x = 0..20 on a 0-100 Y axis
+    fn pinned_graph() -> Graph<1, 8> {
+        let mut graph: Graph<1, 8> = Graph::new(bounds());
+        graph.set_y_bounds(0.0, 100.0).unwrap();
+
+        let mut series = DataSeries::new().with_style(SeriesStyle {
+            color: Rgb565::RED,
+            ..SeriesStyle::default()
+        });
+        for (x, y) in [(0.0, 50.0), (10.0, 150.0), (20.0, 50.0)] {
+            series.push(DataPoint::new(x, y)).unwrap();
+        }
+        graph.add_series(series).unwrap();
+        graph
+    }
+
+    #[test]
+    fn fixed_y_range_is_not_rescaled_by_out_of_range_values() {
+        let mut graph = pinned_graph();
+        let bounds = graph.viewport.data_bounds();
+        assert_eq!((bounds.y_min, bounds.y_max), (0.0, 100.0));
+        // X still auto-scales to the data
+        assert!(bounds.x_min <= 0.0 && bounds.x_max >= 20.0);
+
+        graph.push_point(0, DataPoint::new(30.0, -40.0)).unwrap();
+        let bounds = graph.viewport.data_bounds();
+        assert_eq!((bounds.y_min, bounds.y_max), (0.0, 100.0));
+        assert!(bounds.x_max >= 30.0);
+    }
+
+    #[test]
+    fn out_of_range_value_is_clipped_at_the_plot_edge() {
+        use crate::framebuffer::FrameBuffer;
+        use crate::pages::test_harness::count_color;
+
+        let graph = pinned_graph();
+        let plot_area = graph.viewport.plot_area();
+        let mut fb: FrameBuffer = FrameBuffer::new();
+        graph.draw(&mut fb).unwrap();
+
+        // The spike runs up to the top edge of the plot...
+        let top_row = Rectangle::new(plot_area.top_left, Size::new(plot_area.size.width, 1));
+        assert!(count_color(&fb, top_row, Rgb565::RED) > 0);
+        // ...and no further
+        let above = Rectangle::new(
+            bounds().top_left,
+            Size::new(
+                bounds().size.width,
+                (plot_area.top_left.y - bounds().top_left.y) as u32,
+            ),
+        );
+        assert_eq!(count_color(&fb, above, Rgb565::RED), 0);
+    }
+
+    #[test]
+    fn clearing_the_fixed_range_rescales_to_the_data() {
+        let mut graph = pinned_graph();
+        graph.clear_y_bounds();
+
+        let bounds = graph.viewport.data_bounds();
+        assert!(bounds.y_max >= 150.0, "{bounds:?}");
+    }
+
+    #[test]
+    fn empty_or_inverted_ranges_are_rejected() {
+        let mut graph: Graph<1, 8> = Graph::new(bounds());
+        for (min, max) in [(10.0, 10.0), (100.0, 0.0), (f32::NAN, 1.0)] {
+            assert!(matches!(
+                graph.set_y_bounds(min, max),
+                Err(GraphError::InvalidDataBounds)
+            ));
+        }
+        assert_eq!(graph.fixed_y_bounds, None);
+    }
+
+    #[test]
+    fn incremental_pushes_match_a_full_rescan() {
+        let points = [
+            DataPoint::new(0.0, 21.0),
+            DataPoint::new(10.0, 19.5),
+            DataPoint::new(20.0, 24.0),
+            DataPoint::new(30.0, -3.0),
+            DataPoint::new(40.0, 22.0),
+        ];
+        let mut incremental: Graph<2, 8> = Graph::new(bounds());
+        incremental.add_series(DataSeries::new()).unwrap();
+        incremental.add_series(DataSeries::new()).unwrap();
+        let mut rescanned: Graph<2, 8> = Graph::new(bounds());
+        rescanned.add_series(DataSeries::new()).unwrap();
+        rescanned.add_series(DataSeries::new()).unwrap();
+
+        for (i, point) in points.iter().enumerate() {
+            let series = i % 2;
+            incremental.push_point(series, *point).unwrap();
+            rescanned.push_point(series, *point).unwrap();
+            rescanned.mark_bounds_dirty();
+
+            assert_eq!(
+                incremental.viewport.data_bounds(),
+                rescanned.viewport.data_bounds(),
+                "after {} points",
+                i + 1
+            );
+        }
+    }
+
+    #[test]
+    fn replacing_points_shrinks_the_cached_bounds() {
+        let mut graph: Graph<1, 8> = Graph::new(bounds());
+        graph.add_series(DataSeries::new()).unwrap();
+        graph.push_point(0, DataPoint::new(0.0, 100.0)).unwrap();
+        graph.push_point(0, DataPoint::new(10.0, 0.0)).unwrap();
+
+        graph
+            .set_series_points(0, &[DataPoint::new(0.0, 10.0), DataPoint::new(10.0, 20.0)])
+            .unwrap();
+
+        let mut fresh: Graph<1, 8> = Graph::new(bounds());
+        let mut series = DataSeries::new();
+        series.push(DataPoint::new(0.0, 10.0)).unwrap();
+        series.push(DataPoint::new(10.0, 20.0)).unwrap();
+        fresh.add_series(series).unwrap();
+        assert_eq!(graph.viewport.data_bounds(), fresh.viewport.data_bounds());
+    }
+}
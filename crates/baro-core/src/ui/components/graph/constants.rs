@@ -15,6 +15,11 @@ pub const DEFAULT_SMOOTH_SUBDIVISIONS: usize = 5;
 /// Default number of vertical grid lines
 pub const DEFAULT_VERTICAL_GRID_COUNT: usize = 5;
 
+/// Most grid lines drawn along one axis
+///
+/// Bounds `GridSpacing::Every` when the step is tiny relative to the data range.
+pub const MAX_GRID_LINES: usize = 32;
+
 /// Default grid line color (subtle dark gray)
 pub const DEFAULT_GRID_COLOR: Rgb565 = DARK_GRAY;
 
@@ -47,8 +52,40 @@ pub const MIN_DATA_RANGE: f32 = 0.001;
 /// 0.0 = loose curve, 0.5 = balanced, 1.0 = tight through points
 pub const DEFAULT_CURVE_TENSION: f32 = 0.5;
 
+/// Average on-screen spacing between points below which
+/// `InterpolationType::Auto` draws straight lines (pixels)
+///
+/// Splines through points only a few pixels apart overshoot on sensor noise
+/// without looking any smoother.
+pub const AUTO_SMOOTH_MIN_SPACING_PX: f32 = 8.0;
+
 /// Margin factor for auto-scaling bounds (10% padding)
 pub const AUTO_SCALE_MARGIN_FACTOR: f32 = 0.1;
 
+/// Distance from the current value's baseline down to its label's baseline
+/// in pixels
+pub const CURRENT_VALUE_LABEL_GAP_PX: i32 = 15;
+
+/// Side length of a legend color swatch in pixels
+pub const LEGEND_SWATCH_SIZE_PX: u32 = 8;
+
+/// Gap between a legend swatch and its label in pixels
+pub const LEGEND_SWATCH_GAP_PX: u32 = 4;
+
+/// Height of one legend entry in pixels
+pub const LEGEND_ROW_HEIGHT_PX: u32 = 12;
+
+/// Padding inside the legend box in pixels
+pub const LEGEND_PADDING_PX: u32 = 4;
+
+/// Distance between the legend box and the plot area corner in pixels
+pub const LEGEND_MARGIN_PX: u32 = 4;
+
+/// Diameter of the marker drawn on a series' latest point in pixels
+pub const LATEST_MARKER_DIAMETER_PX: u32 = 7;
+
+/// Height of the X-axis marker ticks, rising from the plot bottom, in pixels
+pub const X_MARKER_TICK_HEIGHT_PX: u32 = 5;
+
 /// Default series line width in pixels
 pub const DEFAULT_SERIES_LINE_WIDTH_PX: u32 = 2;
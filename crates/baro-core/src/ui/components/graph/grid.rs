@@ -6,9 +6,10 @@
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::{Line, PrimitiveStyle};
+use heapless::Vec;
 
 use super::constants::{
-    DEFAULT_GRID_COLOR, DEFAULT_GRID_LINE_WIDTH_PX, DEFAULT_VERTICAL_GRID_COUNT,
+    DEFAULT_GRID_COLOR, DEFAULT_GRID_LINE_WIDTH_PX, DEFAULT_VERTICAL_GRID_COUNT, MAX_GRID_LINES,
 };
 use super::viewport::Viewport;
 
@@ -26,11 +27,31 @@ pub enum LineStyle {
     },
 }
 
+impl LineStyle {
+    /// Single-pixel dots two pixels apart, for unobtrusive minor lines
+    pub const fn dotted() -> Self {
+        Self::Dashed {
+            dash_length: 0,
+            gap_length: 2,
+        }
+    }
+}
+
+/// How grid lines are spaced along an axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridSpacing {
+    /// This many evenly spaced lines between the plot edges
+    Count(usize),
+    /// A line at every multiple of this step in data units (at most
+    /// `MAX_GRID_LINES`); non-positive steps draw nothing
+    Every(f32),
+}
+
 /// Configuration for vertical grid lines
 #[derive(Debug, Clone, Copy)]
 pub struct VerticalGridLines {
-    /// Number of vertical grid lines
-    pub count: usize,
+    /// Where the vertical grid lines go
+    pub spacing: GridSpacing,
     /// Line color
     pub color: Rgb565,
     /// Line width in pixels
@@ -42,7 +63,7 @@ pub struct VerticalGridLines {
 impl Default for VerticalGridLines {
     fn default() -> Self {
         Self {
-            count: DEFAULT_VERTICAL_GRID_COUNT,
+            spacing: GridSpacing::Count(DEFAULT_VERTICAL_GRID_COUNT),
             color: DEFAULT_GRID_COLOR,
             width: DEFAULT_GRID_LINE_WIDTH_PX,
             style: LineStyle::Solid,
@@ -53,8 +74,8 @@ impl Default for VerticalGridLines {
 /// Configuration for horizontal grid lines
 #[derive(Debug, Clone, Copy)]
 pub struct HorizontalGridLines {
-    /// Number of horizontal grid lines
-    pub count: usize,
+    /// Where the horizontal grid lines go
+    pub spacing: GridSpacing,
     /// Line color
     pub color: Rgb565,
     /// Line width in pixels
@@ -66,7 +87,7 @@ pub struct HorizontalGridLines {
 impl Default for HorizontalGridLines {
     fn default() -> Self {
         Self {
-            count: DEFAULT_VERTICAL_GRID_COUNT,
+            spacing: GridSpacing::Count(DEFAULT_VERTICAL_GRID_COUNT),
             color: DEFAULT_GRID_COLOR,
             width: DEFAULT_GRID_LINE_WIDTH_PX,
             style: LineStyle::Solid,
@@ -101,21 +122,15 @@ pub(super) fn draw_grid<D: DrawTarget<Color = Rgb565>>(
     display: &mut D,
 ) -> Result<(), D::Error> {
     let plot_area = viewport.plot_area();
+    let bottom = plot_area.top_left.y + plot_area.size.height as i32;
+    let right = plot_area.top_left.x + plot_area.size.width as i32;
 
     // Draw vertical grid lines
-    if let Some(ref vlines) = config.vertical_lines
-        && vlines.count > 0
-    {
-        let spacing = plot_area.size.width / (vlines.count + 1) as u32;
-
-        for i in 1..=vlines.count {
-            let x = plot_area.top_left.x + (spacing * i as u32) as i32;
-            let start = Point::new(x, plot_area.top_left.y);
-            let end = Point::new(x, plot_area.top_left.y + plot_area.size.height as i32);
-
+    if let Some(ref vlines) = config.vertical_lines {
+        for x in vertical_grid_positions(vlines.spacing, viewport) {
             draw_line(
-                start,
-                end,
+                Point::new(x, plot_area.top_left.y),
+                Point::new(x, bottom),
                 vlines.color,
                 vlines.width,
                 vlines.style,
@@ -125,19 +140,11 @@ pub(super) fn draw_grid<D: DrawTarget<Color = Rgb565>>(
     }
 
     // Draw horizontal grid lines
-    if let Some(ref hlines) = config.horizontal_lines
-        && hlines.count > 0
-    {
-        let spacing = plot_area.size.height / (hlines.count + 1) as u32;
-
-        for i in 1..=hlines.count {
-            let y = plot_area.top_left.y + (spacing * i as u32) as i32;
-            let start = Point::new(plot_area.top_left.x, y);
-            let end = Point::new(plot_area.top_left.x + plot_area.size.width as i32, y);
-
+    if let Some(ref hlines) = config.horizontal_lines {
+        for y in horizontal_grid_positions(hlines.spacing, viewport) {
             draw_line(
-                start,
-                end,
+                Point::new(plot_area.top_left.x, y),
+                Point::new(right, y),
                 hlines.color,
                 hlines.width,
                 hlines.style,
@@ -149,6 +156,71 @@ pub(super) fn draw_grid<D: DrawTarget<Color = Rgb565>>(
     Ok(())
 }
 
+/// Screen X positions of vertical grid lines, left to right
+pub(super) fn vertical_grid_positions(
+    spacing: GridSpacing,
+    viewport: &Viewport,
+) -> Vec<i32, MAX_GRID_LINES> {
+    let plot_area = viewport.plot_area();
+    let bounds = viewport.data_bounds();
+    let span = plot_area.size.width.saturating_sub(1) as f32;
+
+    line_fractions(spacing, bounds.x_min, bounds.x_max)
+        .iter()
+        .map(|fraction| plot_area.top_left.x + (fraction * span) as i32)
+        .collect()
+}
+
+/// Screen Y positions of horizontal grid lines, bottom to top
+///
+/// Screen Y grows downward, so data-unit spacing is measured up from the
+/// bottom edge where `y_min` sits.
+pub(super) fn horizontal_grid_positions(
+    spacing: GridSpacing,
+    viewport: &Viewport,
+) -> Vec<i32, MAX_GRID_LINES> {
+    let plot_area = viewport.plot_area();
+    let bounds = viewport.data_bounds();
+    let span = plot_area.size.height.saturating_sub(1) as f32;
+
+    line_fractions(spacing, bounds.y_min, bounds.y_max)
+        .iter()
+        .map(|fraction| plot_area.top_left.y + ((1.0 - fraction) * span) as i32)
+        .collect()
+}
+
+/// Grid line positions as fractions of the axis length, strictly inside (0, 1)
+fn line_fractions(spacing: GridSpacing, data_min: f32, data_max: f32) -> Vec<f32, MAX_GRID_LINES> {
+    let mut fractions = Vec::new();
+
+    match spacing {
+        GridSpacing::Count(count) => {
+            for i in 1..=count.min(MAX_GRID_LINES) {
+                let _ = fractions.push(i as f32 / (count + 1) as f32);
+            }
+        }
+        GridSpacing::Every(step) => {
+            let range = data_max - data_min;
+            if !(step > 0.0 && range > 0.0) {
+                return fractions;
+            }
+
+            // First multiple of `step` above the minimum (the cast truncates
+            // toward zero, which is at most one step below it)
+            let mut value = (data_min / step) as i64 as f32 * step;
+            if value <= data_min {
+                value += step;
+            }
+            while value < data_max && !fractions.is_full() {
+                let _ = fractions.push((value - data_min) / range);
+                value += step;
+            }
+        }
+    }
+
+    fractions
+}
+
 /// Draw a single line with specified style
 fn draw_line<D: DrawTarget<Color = Rgb565>>(
     start: Point,
@@ -235,3 +307,77 @@ fn draw_dashed_line<D: DrawTarget<Color = Rgb565>>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framebuffer::FrameBuffer;
+    use crate::ui::components::graph::viewport::{DataBounds, ViewportPadding};
+    use embedded_graphics::primitives::Rectangle;
+
+    /// 0..30 x 0..60 data on a 220x120 screen with a 10px border, leaving
+    /// a 200x100 plot area at (10, 10)
+    fn viewport() -> Viewport {
+        Viewport::new(
+            DataBounds::new(0.0, 30.0, 0.0, 60.0),
+            Rectangle::new(Point::zero(), Size::new(220, 120)),
+        )
+        .with_padding(ViewportPadding::uniform(10))
+    }
+
+    #[test]
+    fn count_of_five_gives_five_evenly_spaced_positions_inside_the_plot() {
+        let viewport = viewport();
+        let plot_area = viewport.plot_area();
+        let left = plot_area.top_left.x;
+        let right = left + plot_area.size.width as i32 - 1;
+
+        let xs = vertical_grid_positions(GridSpacing::Count(5), &viewport);
+
+        assert_eq!(xs.len(), 5);
+        assert!(xs.iter().all(|&x| x > left && x < right), "{xs:?}");
+        let gaps: Vec<i32, MAX_GRID_LINES> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+        assert!(gaps.iter().all(|&gap| gap == gaps[0]), "{xs:?}");
+        // The margins either side match the gaps between lines, give or
+        // take a pixel of truncation
+        assert!((xs[0] - left - gaps[0]).abs() <= 1, "{xs:?}");
+        assert!((right - xs[4] - gaps[0]).abs() <= 1, "{xs:?}");
+    }
+
+    #[test]
+    fn count_is_capped_at_the_line_limit() {
+        let xs = vertical_grid_positions(GridSpacing::Count(MAX_GRID_LINES + 4), &viewport());
+        assert_eq!(xs.len(), MAX_GRID_LINES);
+    }
+
+    #[test]
+    fn draw_grid_puts_a_full_height_line_at_each_position() {
+        let viewport = viewport();
+        let plot_area = viewport.plot_area();
+        let config = GridConfig {
+            vertical_lines: Some(VerticalGridLines {
+                spacing: GridSpacing::Count(5),
+                ..VerticalGridLines::default()
+            }),
+            horizontal_lines: None,
+        };
+        let mut fb: FrameBuffer<220, 120> = FrameBuffer::new();
+
+        draw_grid(&config, &viewport, &mut fb).unwrap();
+
+        let top = plot_area.top_left.y;
+        let bottom = top + plot_area.size.height as i32;
+        for x in vertical_grid_positions(GridSpacing::Count(5), &viewport) {
+            for y in top..=bottom {
+                assert_eq!(
+                    fb.pixel(x as usize, y as usize),
+                    Some(DEFAULT_GRID_COLOR),
+                    "({x}, {y})"
+                );
+            }
+        }
+        // Nothing outside the plot area
+        assert_ne!(fb.pixel(5, 60), Some(DEFAULT_GRID_COLOR));
+        assert_ne!(fb.pixel(215, 60), Some(DEFAULT_GRID_COLOR));
+    }
+}
@@ -5,15 +5,62 @@
 
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
-use embedded_graphics::primitives::{Line, PrimitiveStyle};
+use embedded_graphics::primitives::{Circle, Line, PrimitiveStyle};
 
 extern crate alloc;
 use alloc::vec::Vec;
 
-use super::constants::DEFAULT_SMOOTH_SUBDIVISIONS;
-use super::series::{DataPoint, GradientFill, SeriesStyle};
+use crate::ui::styling::ColorPalette;
+
+use super::constants::{
+    AUTO_SMOOTH_MIN_SPACING_PX, DEFAULT_CURVE_TENSION, DEFAULT_SMOOTH_SUBDIVISIONS,
+    LATEST_MARKER_DIAMETER_PX,
+};
+use super::series::{DataPoint, GradientFill, InterpolationType, SeriesStyle};
 use super::viewport::Viewport;
 
+/// Resolve `InterpolationType::Auto` to the concrete type used for `points`
+///
+/// Other interpolation types are returned unchanged.
+pub(super) fn resolve_interpolation(
+    interpolation: InterpolationType,
+    points: &[DataPoint],
+    viewport: &Viewport,
+) -> InterpolationType {
+    match interpolation {
+        InterpolationType::Auto => match average_spacing_px(points, viewport) {
+            Some(spacing_px) => interpolation_for_spacing(spacing_px),
+            None => InterpolationType::Linear,
+        },
+        other => other,
+    }
+}
+
+/// Interpolation `Auto` picks for points `spacing_px` apart on screen
+fn interpolation_for_spacing(spacing_px: f32) -> InterpolationType {
+    if spacing_px < AUTO_SMOOTH_MIN_SPACING_PX {
+        InterpolationType::Linear
+    } else {
+        InterpolationType::Smooth {
+            tension: DEFAULT_CURVE_TENSION,
+        }
+    }
+}
+
+/// Average horizontal screen distance between consecutive points
+///
+/// `None` for fewer than 2 points or a degenerate viewport.
+fn average_spacing_px(points: &[DataPoint], viewport: &Viewport) -> Option<f32> {
+    if points.len() < 2 {
+        return None;
+    }
+    let (first, last) = (points[0], points[points.len() - 1]);
+
+    let span_px = (last.x - first.x).abs() * viewport.x_pixels_per_unit();
+    let spacing_px = span_px / (points.len() - 1) as f32;
+    spacing_px.is_finite().then_some(spacing_px)
+}
+
 /// Draw a data series with linear interpolation (straight lines)
 ///
 /// Connects consecutive data points with straight Line primitives.
@@ -23,32 +70,60 @@ pub(super) fn draw_linear_series<D: DrawTarget<Color = Rgb565>>(
     style: &SeriesStyle,
     display: &mut D,
 ) -> Result<(), D::Error> {
-    if points.len() < 2 {
-        return Ok(());
-    }
-
     let line_style = PrimitiveStyle::with_stroke(style.color, style.line_width);
+    draw_clipped_polyline(points.iter().copied(), viewport, line_style, display)
+}
 
-    // Convert data points to screen coordinates
-    let mut prev_screen: Option<Point> = None;
+/// Connect consecutive points with lines clipped to the viewport
+///
+/// Segments leaving the data bounds are cut at the plot edge (see
+/// [`Viewport::clip_segment`]) so a spike past the axis range stays
+/// connected instead of leaving a gap.
+fn draw_clipped_polyline<D: DrawTarget<Color = Rgb565>>(
+    points: impl Iterator<Item = DataPoint>,
+    viewport: &Viewport,
+    line_style: PrimitiveStyle<Rgb565>,
+    display: &mut D,
+) -> Result<(), D::Error> {
+    let mut previous: Option<DataPoint> = None;
 
-    for point in points.iter() {
-        if let Some(screen_point) = viewport.data_to_screen(*point) {
-            if let Some(prev) = prev_screen {
-                Line::new(prev, screen_point)
-                    .into_styled(line_style)
-                    .draw(display)?;
-            }
-            prev_screen = Some(screen_point);
-        } else {
-            // Point is out of viewport, reset previous point
-            prev_screen = None;
+    for point in points {
+        if let Some(prev) = previous
+            && let Some((start, end)) = viewport.clip_segment(prev, point)
+        {
+            Line::new(start, end)
+                .into_styled(line_style)
+                .draw(display)?;
         }
+        previous = Some(point);
     }
 
     Ok(())
 }
 
+/// Screen position of the latest point, or `None` when it is off-viewport
+pub(super) fn latest_marker_center(points: &[DataPoint], viewport: &Viewport) -> Option<Point> {
+    viewport.data_to_screen(*points.last()?)
+}
+
+/// Draw a filled circle on the latest point so "now" is easy to find
+///
+/// Nothing is drawn when the latest point lies outside the viewport.
+pub(super) fn draw_latest_marker<D: DrawTarget<Color = Rgb565>>(
+    points: &[DataPoint],
+    viewport: &Viewport,
+    style: &SeriesStyle,
+    display: &mut D,
+) -> Result<(), D::Error> {
+    let Some(center) = latest_marker_center(points, viewport) else {
+        return Ok(());
+    };
+
+    Circle::with_center(center, LATEST_MARKER_DIAMETER_PX)
+        .into_styled(PrimitiveStyle::with_fill(style.color))
+        .draw(display)
+}
+
 /// Draw a gradient fill under a linearly interpolated series
 pub(super) fn draw_linear_fill<D: DrawTarget<Color = Rgb565>>(
     points: &[DataPoint],
@@ -63,8 +138,9 @@ pub(super) fn draw_linear_fill<D: DrawTarget<Color = Rgb565>>(
 
 /// Draw a data series with smooth Catmull-Rom spline interpolation
 ///
-/// Creates smooth curves through data points using Catmull-Rom basis.
-/// Requires at least 4 points for proper interpolation.
+/// Creates a smooth curve passing through every data point, including the
+/// first and last (see [`smooth_curve_points`]). `tension` is expected to be
+/// validated already (`InterpolationType::validate`).
 pub(super) fn draw_smooth_series<D: DrawTarget<Color = Rgb565>>(
     points: &[DataPoint],
     viewport: &Viewport,
@@ -72,46 +148,13 @@ pub(super) fn draw_smooth_series<D: DrawTarget<Color = Rgb565>>(
     tension: f32,
     display: &mut D,
 ) -> Result<(), D::Error> {
-    if points.len() < 2 {
-        return Ok(());
-    }
-
-    // For less than 4 points, fall back to linear interpolation
-    if points.len() < 4 {
-        return draw_linear_series(points, viewport, style, display);
-    }
-
     let line_style = PrimitiveStyle::with_stroke(style.color, style.line_width);
-    let step = 1.0 / DEFAULT_SMOOTH_SUBDIVISIONS as f32;
-
-    // Iterate through segments (need 4 control points per segment)
-    for i in 0..points.len().saturating_sub(3) {
-        let p0 = points[i];
-        let p1 = points[i + 1];
-        let p2 = points[i + 2];
-        let p3 = points[i + 3];
-
-        let mut prev_screen: Option<Point> = None;
-
-        // Generate subdivisions along the curve segment
-        for j in 0..=DEFAULT_SMOOTH_SUBDIVISIONS {
-            let t = j as f32 * step;
-            let interpolated = catmull_rom_point(p0, p1, p2, p3, t, tension);
-
-            if let Some(screen_point) = viewport.data_to_screen(interpolated) {
-                if let Some(prev) = prev_screen {
-                    Line::new(prev, screen_point)
-                        .into_styled(line_style)
-                        .draw(display)?;
-                }
-                prev_screen = Some(screen_point);
-            } else {
-                prev_screen = None;
-            }
-        }
-    }
-
-    Ok(())
+    draw_clipped_polyline(
+        smooth_curve_points(points, tension),
+        viewport,
+        line_style,
+        display,
+    )
 }
 
 /// Draw a gradient fill under a smoothly interpolated series
@@ -131,7 +174,7 @@ fn collect_linear_screen_points(points: &[DataPoint], viewport: &Viewport) -> Ve
     let mut screen_points = Vec::with_capacity(points.len());
 
     for point in points.iter() {
-        if let Some(screen_point) = viewport.data_to_screen(*point)
+        if let Some(screen_point) = viewport.data_to_screen_clamped(*point)
             && screen_points.last().copied() != Some(screen_point)
         {
             screen_points.push(screen_point);
@@ -146,38 +189,42 @@ fn collect_smooth_screen_points(
     viewport: &Viewport,
     tension: f32,
 ) -> Vec<Point> {
-    if points.len() < 2 {
-        return Vec::new();
-    }
-
-    if points.len() < 4 {
-        return collect_linear_screen_points(points, viewport);
-    }
-
     let mut screen_points = Vec::with_capacity(points.len() * DEFAULT_SMOOTH_SUBDIVISIONS);
-    let step = 1.0 / DEFAULT_SMOOTH_SUBDIVISIONS as f32;
 
-    for i in 0..points.len().saturating_sub(3) {
-        let p0 = points[i];
-        let p1 = points[i + 1];
-        let p2 = points[i + 2];
-        let p3 = points[i + 3];
-
-        for j in 0..=DEFAULT_SMOOTH_SUBDIVISIONS {
-            let t = j as f32 * step;
-            let interpolated = catmull_rom_point(p0, p1, p2, p3, t, tension);
-
-            if let Some(screen_point) = viewport.data_to_screen(interpolated)
-                && screen_points.last().copied() != Some(screen_point)
-            {
-                screen_points.push(screen_point);
-            }
+    for interpolated in smooth_curve_points(points, tension) {
+        if let Some(screen_point) = viewport.data_to_screen_clamped(interpolated)
+            && screen_points.last().copied() != Some(screen_point)
+        {
+            screen_points.push(screen_point);
         }
     }
 
     screen_points
 }
 
+/// Sample a Catmull-Rom curve through every point in `points`
+///
+/// Each segment between neighbouring points needs the points on either side
+/// as control points. The first and last points are duplicated as phantom
+/// control points so the outer segments are drawn too and the curve starts
+/// and ends exactly on the data. Yields nothing for fewer than 2 points.
+fn smooth_curve_points(points: &[DataPoint], tension: f32) -> impl Iterator<Item = DataPoint> + '_ {
+    let last = points.len().saturating_sub(1);
+    let step = 1.0 / DEFAULT_SMOOTH_SUBDIVISIONS as f32;
+
+    (0..last).flat_map(move |i| {
+        let p0 = points[i.saturating_sub(1)];
+        let p1 = points[i];
+        let p2 = points[i + 1];
+        let p3 = points[(i + 2).min(last)];
+
+        // Segments after the first start where the previous one ended
+        let first = if i == 0 { 0 } else { 1 };
+        (first..=DEFAULT_SMOOTH_SUBDIVISIONS)
+            .map(move |j| catmull_rom_point(p0, p1, p2, p3, j as f32 * step, tension))
+    })
+}
+
 fn draw_gradient_fill_from_screen_points<D: DrawTarget<Color = Rgb565>>(
     screen_points: &[Point],
     viewport: &Viewport,
@@ -254,12 +301,12 @@ fn build_gradient_colors(fill: &GradientFill, background: Rgb565) -> Vec<Rgb565>
     let start_color = if fill.opacity == u8::MAX {
         fill.start_color
     } else {
-        lerp_color(background, fill.start_color, alpha)
+        ColorPalette::lerp(background, fill.start_color, alpha)
     };
     let end_color = if fill.opacity == u8::MAX {
         fill.end_color
     } else {
-        lerp_color(background, fill.end_color, alpha)
+        ColorPalette::lerp(background, fill.end_color, alpha)
     };
     let mut colors = Vec::with_capacity(bands);
     for i in 0..bands {
@@ -268,40 +315,11 @@ fn build_gradient_colors(fill: &GradientFill, background: Rgb565) -> Vec<Rgb565>
         } else {
             1.0
         };
-        colors.push(lerp_color(start_color, end_color, t));
+        colors.push(ColorPalette::lerp(start_color, end_color, t));
     }
     colors
 }
 
-fn lerp_color(start: Rgb565, end: Rgb565, t: f32) -> Rgb565 {
-    let t = t.clamp(0.0, 1.0);
-    let (r0, g0, b0) = rgb565_to_rgb888(start);
-    let (r1, g1, b1) = rgb565_to_rgb888(end);
-
-    let r = r0 as f32 + (r1 as f32 - r0 as f32) * t;
-    let g = g0 as f32 + (g1 as f32 - g0 as f32) * t;
-    let b = b0 as f32 + (b1 as f32 - b0 as f32) * t;
-
-    rgb888_to_rgb565(r as u8, g as u8, b as u8)
-}
-
-fn rgb565_to_rgb888(color: Rgb565) -> (u8, u8, u8) {
-    let raw = color.into_storage();
-    let r5 = ((raw >> 11) & 0x1f) as u8;
-    let g6 = ((raw >> 5) & 0x3f) as u8;
-    let b5 = (raw & 0x1f) as u8;
-
-    let r8 = (r5 << 3) | (r5 >> 2);
-    let g8 = (g6 << 2) | (g6 >> 4);
-    let b8 = (b5 << 3) | (b5 >> 2);
-
-    (r8, g8, b8)
-}
-
-fn rgb888_to_rgb565(r8: u8, g8: u8, b8: u8) -> Rgb565 {
-    Rgb565::new(r8 >> 3, g8 >> 2, b8 >> 3)
-}
-
 /// Calculate a point on a Catmull-Rom spline curve
 ///
 /// Uses the standard Catmull-Rom basis matrix for smooth interpolation.
@@ -326,9 +344,8 @@ fn catmull_rom_point(
     let t2 = t * t;
     let t3 = t2 * t;
 
-    // Clamp tension and use it to scale tangents: higher tension -> tighter curve
-    let tau = tension.clamp(0.0, 1.0);
-    let tangent_scale = (1.0 - tau) * 0.5;
+    // Scale tangents by tension: higher tension -> tighter curve
+    let tangent_scale = (1.0 - tension) * 0.5;
 
     // Tangents at p1 and p2 (Cardinal spline / Catmull-Rom when tension = 0.0)
     let m1x = tangent_scale * (p2.x - p0.x);
@@ -346,3 +363,211 @@ fn catmull_rom_point(
     let y = h00 * p1.y + h10 * m1y + h01 * p2.y + h11 * m2y;
     DataPoint { x, y }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framebuffer::FrameBuffer;
+    use crate::ui::components::graph::GraphError;
+    use crate::ui::components::graph::series::{DataSeries, SeriesCollection};
+    use crate::ui::components::graph::viewport::{DataBounds, ViewportPadding};
+    use embedded_graphics::primitives::Rectangle;
+
+    const FOUR_POINTS: [DataPoint; 4] = [
+        DataPoint::new(0.0, 10.0),
+        DataPoint::new(10.0, 40.0),
+        DataPoint::new(20.0, 20.0),
+        DataPoint::new(30.0, 50.0),
+    ];
+
+    /// 0..30 x 0..60 data mapped onto a 100x70 plot with no padding
+    fn viewport() -> Viewport {
+        Viewport::new(
+            DataBounds::new(0.0, 30.0, 0.0, 60.0),
+            Rectangle::new(Point::zero(), Size::new(100, 70)),
+        )
+        .with_padding(ViewportPadding::uniform(0))
+    }
+
+    #[test]
+    fn smooth_curve_passes_through_every_point_including_the_ends() {
+        let curve: Vec<DataPoint> =
+            smooth_curve_points(&FOUR_POINTS, DEFAULT_CURVE_TENSION).collect();
+
+        // Three segments, sharing their joints
+        assert_eq!(curve.len(), 3 * DEFAULT_SMOOTH_SUBDIVISIONS + 1);
+        assert_eq!(curve[0], FOUR_POINTS[0]);
+        assert_eq!(curve[curve.len() - 1], FOUR_POINTS[3]);
+        for (i, point) in FOUR_POINTS.iter().enumerate() {
+            assert_eq!(curve[i * DEFAULT_SMOOTH_SUBDIVISIONS], *point);
+        }
+    }
+
+    #[test]
+    fn smooth_series_draws_on_both_endpoints() {
+        let viewport = viewport();
+        let style = SeriesStyle {
+            color: Rgb565::RED,
+            line_width: 1,
+            ..SeriesStyle::default()
+        };
+        let mut fb = FrameBuffer::<100, 70>::new();
+        draw_smooth_series(
+            &FOUR_POINTS,
+            &viewport,
+            &style,
+            DEFAULT_CURVE_TENSION,
+            &mut fb,
+        )
+        .unwrap();
+
+        for point in [FOUR_POINTS[0], FOUR_POINTS[3]] {
+            let screen = viewport.data_to_screen(point).unwrap();
+            assert_eq!(
+                fb.pixel(screen.x as usize, screen.y as usize),
+                Some(Rgb565::RED),
+                "{point:?} at {screen:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn short_series_yield_no_curve() {
+        assert_eq!(smooth_curve_points(&FOUR_POINTS[..1], 0.5).count(), 0);
+        assert_eq!(smooth_curve_points(&[], 0.5).count(), 0);
+        // Two points still give a segment from one to the other
+        let two: Vec<DataPoint> = smooth_curve_points(&FOUR_POINTS[..2], 0.5).collect();
+        assert_eq!(two[0], FOUR_POINTS[0]);
+        assert_eq!(two[two.len() - 1], FOUR_POINTS[1]);
+    }
+
+    #[test]
+    fn tension_outside_zero_to_one_is_rejected() {
+        assert!(matches!(
+            InterpolationType::smooth(2.0),
+            Err(GraphError::InvalidInterpolationParameter { .. })
+        ));
+        assert!(InterpolationType::smooth(-0.1).is_err());
+        assert!(InterpolationType::smooth(f32::NAN).is_err());
+        assert!(InterpolationType::smooth(0.0).is_ok());
+        assert!(InterpolationType::smooth(1.0).is_ok());
+
+        // Built without the checked constructor, the series is refused when added
+        let series =
+            DataSeries::<8>::new().with_interpolation(InterpolationType::Smooth { tension: 2.0 });
+        let mut collection = SeriesCollection::<2, 8>::new();
+        assert!(matches!(
+            collection.add(series),
+            Err(GraphError::InvalidInterpolationParameter { .. })
+        ));
+        assert!(collection.is_empty());
+    }
+
+    /// `count` points evenly spread over the 0..30 viewport (~99 px wide)
+    fn spread(count: usize) -> Vec<DataPoint> {
+        (0..count)
+            .map(|i| DataPoint::new(30.0 * i as f32 / (count - 1) as f32, 20.0))
+            .collect()
+    }
+
+    #[test]
+    fn auto_picks_smooth_for_sparse_points() {
+        // 4 points over 99 px are 33 px apart
+        let resolved = resolve_interpolation(InterpolationType::Auto, &spread(4), &viewport());
+        assert_eq!(
+            resolved,
+            InterpolationType::Smooth {
+                tension: DEFAULT_CURVE_TENSION
+            }
+        );
+    }
+
+    #[test]
+    fn auto_picks_linear_for_dense_points() {
+        // 50 points over 99 px are about 2 px apart
+        let resolved = resolve_interpolation(InterpolationType::Auto, &spread(50), &viewport());
+        assert_eq!(resolved, InterpolationType::Linear);
+
+        // Too few points to measure spacing
+        let resolved = resolve_interpolation(InterpolationType::Auto, &spread(2)[..1], &viewport());
+        assert_eq!(resolved, InterpolationType::Linear);
+    }
+
+    #[test]
+    fn spacing_threshold_and_explicit_types() {
+        assert_eq!(
+            interpolation_for_spacing(AUTO_SMOOTH_MIN_SPACING_PX - 0.1),
+            InterpolationType::Linear
+        );
+        assert!(matches!(
+            interpolation_for_spacing(AUTO_SMOOTH_MIN_SPACING_PX),
+            InterpolationType::Smooth { .. }
+        ));
+
+        // Explicit choices are never overridden, however dense the data
+        let explicit = InterpolationType::Smooth { tension: 0.2 };
+        assert_eq!(
+            resolve_interpolation(explicit, &spread(50), &viewport()),
+            explicit
+        );
+        assert_eq!(
+            resolve_interpolation(InterpolationType::Linear, &spread(4), &viewport()),
+            InterpolationType::Linear
+        );
+    }
+
+    #[test]
+    fn latest_marker_sits_on_the_last_point() {
+        let viewport = viewport();
+
+        assert_eq!(
+            latest_marker_center(&FOUR_POINTS, &viewport),
+            viewport.data_to_screen(FOUR_POINTS[3])
+        );
+        assert!(latest_marker_center(&FOUR_POINTS, &viewport).is_some());
+        assert_eq!(latest_marker_center(&[], &viewport), None);
+    }
+
+    #[test]
+    fn latest_marker_is_drawn_centred_in_the_series_color() {
+        let viewport = viewport();
+        let style = SeriesStyle {
+            color: Rgb565::GREEN,
+            ..SeriesStyle::default()
+        };
+        let mut fb: FrameBuffer<100, 70> = FrameBuffer::new();
+
+        draw_latest_marker(&FOUR_POINTS[..3], &viewport, &style, &mut fb).unwrap();
+
+        let center = viewport.data_to_screen(FOUR_POINTS[2]).unwrap();
+        assert_eq!(
+            fb.pixel(center.x as usize, center.y as usize),
+            Some(Rgb565::GREEN)
+        );
+        let marker = Rectangle::with_center(
+            center,
+            Size::new(LATEST_MARKER_DIAMETER_PX, LATEST_MARKER_DIAMETER_PX),
+        );
+        let outside = (0..100)
+            .flat_map(|x| (0..70).map(move |y| Point::new(x, y)))
+            .filter(|&p| !marker.contains(p))
+            .filter(|p| fb.pixel(p.x as usize, p.y as usize) == Some(Rgb565::GREEN))
+            .count();
+        assert_eq!(outside, 0);
+    }
+
+    #[test]
+    fn latest_marker_is_suppressed_off_viewport() {
+        let viewport = viewport();
+        let points = [DataPoint::new(0.0, 10.0), DataPoint::new(40.0, 80.0)];
+        let style = SeriesStyle {
+            color: Rgb565::GREEN,
+            ..SeriesStyle::default()
+        };
+        let mut fb: FrameBuffer<100, 70> = FrameBuffer::new();
+
+        assert_eq!(latest_marker_center(&points, &viewport), None);
+        draw_latest_marker(&points, &viewport, &style, &mut fb).unwrap();
+        assert!((0..100).all(|x| (0..70).all(|y| fb.pixel(x, y) == Some(Rgb565::BLACK))));
+    }
+}
@@ -0,0 +1,232 @@
+//! Series legend rendering
+//!
+//! Lists every visible, labelled series with a color swatch in a corner of
+//! the plot area, sized to the longest label.
+
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::mono_font::iso_8859_1::FONT_6X10;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+use embedded_graphics::text::{Baseline, Text};
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use super::constants::{
+    LEGEND_MARGIN_PX, LEGEND_PADDING_PX, LEGEND_ROW_HEIGHT_PX, LEGEND_SWATCH_GAP_PX,
+    LEGEND_SWATCH_SIZE_PX,
+};
+
+/// Corner of the plot area the legend is anchored to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LegendPosition {
+    /// Top left corner
+    #[default]
+    TopLeft,
+    /// Top right corner
+    TopRight,
+    /// Bottom left corner
+    BottomLeft,
+    /// Bottom right corner
+    BottomRight,
+}
+
+/// Legend configuration
+#[derive(Debug, Clone, Copy)]
+pub struct LegendConfig {
+    /// Corner of the plot area to draw in
+    pub position: LegendPosition,
+    /// Label text color
+    pub text_color: Rgb565,
+    /// Box fill behind the entries (None = transparent)
+    pub background: Option<Rgb565>,
+}
+
+impl Default for LegendConfig {
+    fn default() -> Self {
+        Self {
+            position: LegendPosition::default(),
+            text_color: Rgb565::WHITE,
+            background: None,
+        }
+    }
+}
+
+/// Computed legend geometry
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct LegendLayout {
+    /// Box enclosing every entry, including padding
+    pub(super) bounds: Rectangle,
+    /// Color swatch for each entry, top to bottom
+    pub(super) swatches: Vec<Rectangle>,
+}
+
+/// Lay out a legend whose labels are `label_chars` characters long
+///
+/// The box is as wide as the longest label and sits `LEGEND_MARGIN_PX`
+/// inside the chosen corner of `plot_area`. Returns `None` without entries.
+pub(super) fn legend_layout(
+    label_chars: &[usize],
+    plot_area: Rectangle,
+    position: LegendPosition,
+) -> Option<LegendLayout> {
+    let longest = *label_chars.iter().max()?;
+
+    let char_width = FONT_6X10.character_size.width + FONT_6X10.character_spacing;
+    let width = LEGEND_PADDING_PX * 2
+        + LEGEND_SWATCH_SIZE_PX
+        + LEGEND_SWATCH_GAP_PX
+        + longest as u32 * char_width;
+    let height = LEGEND_PADDING_PX * 2 + label_chars.len() as u32 * LEGEND_ROW_HEIGHT_PX;
+
+    let left = plot_area.top_left.x + LEGEND_MARGIN_PX as i32;
+    let right = plot_area.top_left.x + plot_area.size.width as i32 - LEGEND_MARGIN_PX as i32;
+    let top = plot_area.top_left.y + LEGEND_MARGIN_PX as i32;
+    let bottom = plot_area.top_left.y + plot_area.size.height as i32 - LEGEND_MARGIN_PX as i32;
+
+    let top_left = match position {
+        LegendPosition::TopLeft => Point::new(left, top),
+        LegendPosition::TopRight => Point::new(right - width as i32, top),
+        LegendPosition::BottomLeft => Point::new(left, bottom - height as i32),
+        LegendPosition::BottomRight => Point::new(right - width as i32, bottom - height as i32),
+    };
+
+    // Swatches are vertically centred within their row
+    let swatch_inset = (LEGEND_ROW_HEIGHT_PX - LEGEND_SWATCH_SIZE_PX) / 2;
+    let swatches = (0..label_chars.len())
+        .map(|row| {
+            Rectangle::new(
+                top_left
+                    + Point::new(
+                        LEGEND_PADDING_PX as i32,
+                        (LEGEND_PADDING_PX + row as u32 * LEGEND_ROW_HEIGHT_PX + swatch_inset)
+                            as i32,
+                    ),
+                Size::new(LEGEND_SWATCH_SIZE_PX, LEGEND_SWATCH_SIZE_PX),
+            )
+        })
+        .collect();
+
+    Some(LegendLayout {
+        bounds: Rectangle::new(top_left, Size::new(width, height)),
+        swatches,
+    })
+}
+
+/// Draw a legend for `entries` (label, series color) inside `plot_area`
+pub(super) fn draw_legend<D: DrawTarget<Color = Rgb565>>(
+    config: &LegendConfig,
+    entries: &[(&str, Rgb565)],
+    plot_area: Rectangle,
+    display: &mut D,
+) -> Result<(), D::Error> {
+    let label_chars: Vec<usize> = entries
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .collect();
+    let Some(layout) = legend_layout(&label_chars, plot_area, config.position) else {
+        return Ok(());
+    };
+
+    if let Some(background) = config.background {
+        layout
+            .bounds
+            .into_styled(PrimitiveStyle::with_fill(background))
+            .draw(display)?;
+    }
+
+    let text_style = MonoTextStyle::new(&FONT_6X10, config.text_color);
+    for ((label, color), swatch) in entries.iter().zip(layout.swatches.iter()) {
+        swatch
+            .into_styled(PrimitiveStyle::with_fill(*color))
+            .draw(display)?;
+
+        let text_origin = Point::new(
+            swatch.top_left.x + (LEGEND_SWATCH_SIZE_PX + LEGEND_SWATCH_GAP_PX) as i32,
+            swatch.center().y,
+        );
+        Text::with_baseline(label, text_origin, text_style, Baseline::Middle).draw(display)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framebuffer::FrameBuffer;
+
+    /// 200x100 plot area at (10, 20)
+    const PLOT_AREA: Rectangle = Rectangle::new(Point::new(10, 20), Size::new(200, 100));
+
+    /// "Temp" and "Humidity": the box is sized by the 8-character label
+    const TWO_LABELS: [usize; 2] = [4, 8];
+
+    #[test]
+    fn two_series_legend_in_the_top_right_corner() {
+        let layout = legend_layout(&TWO_LABELS, PLOT_AREA, LegendPosition::TopRight).unwrap();
+
+        // 4px padding each side + 8px swatch + 4px gap + 8 chars * 6px,
+        // by 4px padding each side + 2 rows of 12px
+        assert_eq!(
+            layout.bounds,
+            Rectangle::new(Point::new(138, 24), Size::new(68, 32))
+        );
+        // Inset by the padding and centred in their 12px rows
+        assert_eq!(
+            layout.swatches,
+            [
+                Rectangle::new(Point::new(142, 30), Size::new(8, 8)),
+                Rectangle::new(Point::new(142, 42), Size::new(8, 8)),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_corner_keeps_the_margin_inside_the_plot_area() {
+        let expected = [
+            (LegendPosition::TopLeft, Point::new(14, 24)),
+            (LegendPosition::TopRight, Point::new(138, 24)),
+            (LegendPosition::BottomLeft, Point::new(14, 84)),
+            (LegendPosition::BottomRight, Point::new(138, 84)),
+        ];
+
+        for (position, top_left) in expected {
+            let layout = legend_layout(&TWO_LABELS, PLOT_AREA, position).unwrap();
+            assert_eq!(layout.bounds.top_left, top_left, "{position:?}");
+            assert_eq!(layout.bounds.size, Size::new(68, 32), "{position:?}");
+        }
+    }
+
+    #[test]
+    fn no_entries_gives_no_layout() {
+        assert_eq!(legend_layout(&[], PLOT_AREA, LegendPosition::TopLeft), None);
+    }
+
+    #[test]
+    fn draw_fills_each_swatch_with_its_series_color() {
+        let entries = [("Temp", Rgb565::RED), ("Humidity", Rgb565::BLUE)];
+        let config = LegendConfig {
+            position: LegendPosition::TopRight,
+            ..LegendConfig::default()
+        };
+        let mut fb: FrameBuffer<220, 130> = FrameBuffer::new();
+
+        draw_legend(&config, &entries, PLOT_AREA, &mut fb).unwrap();
+
+        let layout = legend_layout(&TWO_LABELS, PLOT_AREA, config.position).unwrap();
+        for ((_, color), swatch) in entries.iter().zip(layout.swatches.iter()) {
+            for point in swatch.points() {
+                assert_eq!(
+                    fb.pixel(point.x as usize, point.y as usize),
+                    Some(*color),
+                    "{point:?}"
+                );
+            }
+        }
+        // Transparent by default: nothing drawn outside the box
+        assert_eq!(fb.pixel(130, 22), Some(Rgb565::BLACK));
+    }
+}
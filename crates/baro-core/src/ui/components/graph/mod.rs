@@ -8,6 +8,7 @@
 //! - Configurable grid lines (vertical/horizontal)
 //! - Automatic axis scaling with custom label formatters
 //! - Current value display overlays
+//! - Series legend with color swatches
 //!
 //! # Memory Characteristics
 //!
@@ -33,7 +34,7 @@
 //!         line_width: 2,
 //!         show_points: false,
 //!     })
-//!     .with_interpolation(InterpolationType::Smooth { tension: 0.5 });
+//!     .with_interpolation(InterpolationType::smooth(0.5)?);
 //!
 //! graph.add_series(series)?;
 //! graph.push_point(0, DataPoint { x: 100.0, y: 22.5 })?;
@@ -47,13 +48,15 @@ mod component;
 pub mod constants;
 mod grid;
 mod interpolation;
+mod legend;
 pub mod series;
 pub mod viewport;
 
 // Re-export main types
 pub use axis::{AxisConfig, LabelFormatter, XAxisConfig, YAxisConfig};
 pub use component::{CurrentValueDisplay, CurrentValuePosition, Graph};
-pub use grid::{GridConfig, HorizontalGridLines, LineStyle, VerticalGridLines};
+pub use grid::{GridConfig, GridSpacing, HorizontalGridLines, LineStyle, VerticalGridLines};
+pub use legend::{LegendConfig, LegendPosition};
 pub use series::{
     DataPoint, DataSeries, GradientFill, InterpolationType, SeriesCollection, SeriesStyle,
 };
@@ -6,6 +6,7 @@
 use embedded_graphics::pixelcolor::Rgb565;
 
 extern crate alloc;
+use alloc::string::String;
 use alloc::vec::Vec;
 use embedded_graphics::prelude::RgbColor;
 
@@ -41,6 +42,32 @@ pub enum InterpolationType {
         /// Curve tension (0.0 = loose, 0.5 = balanced, 1.0 = tight)
         tension: f32,
     },
+    /// Linear when points are dense on screen, smooth (default tension)
+    /// otherwise; decided on every draw from the current viewport
+    Auto,
+}
+
+impl InterpolationType {
+    /// Smooth interpolation with a validated `tension`
+    ///
+    /// Returns `GraphError::InvalidInterpolationParameter` unless `tension`
+    /// lies in `[0.0, 1.0]`.
+    pub fn smooth(tension: f32) -> GraphResult<Self> {
+        let interpolation = Self::Smooth { tension };
+        interpolation.validate()?;
+        Ok(interpolation)
+    }
+
+    /// Check that the interpolation parameters are usable
+    pub fn validate(&self) -> GraphResult<()> {
+        match *self {
+            Self::Linear | Self::Auto => Ok(()),
+            Self::Smooth { tension } if (0.0..=1.0).contains(&tension) => Ok(()),
+            Self::Smooth { .. } => Err(GraphError::InvalidInterpolationParameter {
+                param: "tension must be within [0.0, 1.0]",
+            }),
+        }
+    }
 }
 
 /// Visual style configuration for a data series
@@ -108,6 +135,10 @@ pub struct DataSeries<const MAX_POINTS: usize> {
     pub(super) interpolation: InterpolationType,
     /// Whether this series should be rendered
     pub(super) visible: bool,
+    /// Name shown in the legend (unlabelled series are left out)
+    pub(super) label: Option<String>,
+    /// Whether to mark the latest point with a filled circle
+    pub(super) show_latest_marker: bool,
 }
 
 impl<const MAX_POINTS: usize> DataSeries<MAX_POINTS> {
@@ -118,9 +149,23 @@ impl<const MAX_POINTS: usize> DataSeries<MAX_POINTS> {
             style: SeriesStyle::default(),
             interpolation: InterpolationType::Linear,
             visible: true,
+            label: None,
+            show_latest_marker: false,
         }
     }
 
+    /// Mark the latest point with a filled circle in the series color
+    pub fn with_latest_marker(mut self, show: bool) -> Self {
+        self.show_latest_marker = show;
+        self
+    }
+
+    /// Set the name shown in the graph legend
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = Some(String::from(label));
+        self
+    }
+
     /// Set the visual style
     pub fn with_style(mut self, style: SeriesStyle) -> Self {
         self.style = style;
@@ -166,6 +211,16 @@ impl<const MAX_POINTS: usize> DataSeries<MAX_POINTS> {
         self.interpolation
     }
 
+    /// Legend name, if any
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Whether the latest point is marked
+    pub fn shows_latest_marker(&self) -> bool {
+        self.show_latest_marker
+    }
+
     /// Check if this series is visible
     pub fn is_visible(&self) -> bool {
         self.visible
@@ -199,8 +254,11 @@ impl<const MAX_SERIES: usize, const MAX_POINTS: usize> SeriesCollection<MAX_SERI
 
     /// Add a series to the collection
     ///
-    /// Returns error if at capacity
+    /// Returns error if at capacity or the series' interpolation parameters
+    /// are invalid
     pub fn add(&mut self, series: DataSeries<MAX_POINTS>) -> GraphResult<usize> {
+        series.interpolation.validate()?;
+
         let index = self.series.len();
         if index >= MAX_SERIES {
             return Err(GraphError::SeriesCapacityExceeded { max: MAX_SERIES });
@@ -38,34 +38,57 @@ impl DataBounds {
 
     /// Calculate bounds from a slice of data points with optional margin
     pub fn from_points(points: &[DataPoint], margin_factor: f32) -> Option<Self> {
-        if points.is_empty() {
-            return None;
-        }
+        Self::extents(points).map(|extents| extents.with_margin(margin_factor))
+    }
 
-        let mut x_min = points[0].x;
-        let mut x_max = points[0].x;
-        let mut y_min = points[0].y;
-        let mut y_max = points[0].y;
+    /// Tight bounds enclosing every point, without margin
+    pub fn extents(points: &[DataPoint]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
 
-        for point in points.iter().skip(1) {
-            x_min = x_min.min(point.x);
-            x_max = x_max.max(point.x);
-            y_min = y_min.min(point.y);
-            y_max = y_max.max(point.y);
+        let mut extents = Self::from_point(*first);
+        for point in rest {
+            extents.include(*point);
         }
+        Some(extents)
+    }
+
+    /// Zero-sized bounds around a single point
+    pub const fn from_point(point: DataPoint) -> Self {
+        Self::new(point.x, point.x, point.y, point.y)
+    }
 
-        // Add margin
-        let x_range = (x_max - x_min).max(MIN_DATA_RANGE);
-        let y_range = (y_max - y_min).max(MIN_DATA_RANGE);
+    /// Grow the bounds to enclose `point`
+    pub fn include(&mut self, point: DataPoint) {
+        self.x_min = self.x_min.min(point.x);
+        self.x_max = self.x_max.max(point.x);
+        self.y_min = self.y_min.min(point.y);
+        self.y_max = self.y_max.max(point.y);
+    }
+
+    /// Grow the bounds to enclose `other`
+    pub fn union(&mut self, other: &Self) {
+        self.x_min = self.x_min.min(other.x_min);
+        self.x_max = self.x_max.max(other.x_max);
+        self.y_min = self.y_min.min(other.y_min);
+        self.y_max = self.y_max.max(other.y_max);
+    }
+
+    /// Pad each axis by `margin_factor` of its range
+    ///
+    /// Degenerate ranges are widened to `MIN_DATA_RANGE` first so a flat
+    /// series still gets a visible band.
+    pub fn with_margin(&self, margin_factor: f32) -> Self {
+        let x_range = (self.x_max - self.x_min).max(MIN_DATA_RANGE);
+        let y_range = (self.y_max - self.y_min).max(MIN_DATA_RANGE);
         let x_margin = x_range * margin_factor;
         let y_margin = y_range * margin_factor;
 
-        Some(Self {
-            x_min: x_min - x_margin,
-            x_max: x_max + x_margin,
-            y_min: y_min - y_margin,
-            y_max: y_max + y_margin,
-        })
+        Self {
+            x_min: self.x_min - x_margin,
+            x_max: self.x_max + x_margin,
+            y_min: self.y_min - y_margin,
+            y_max: self.y_max + y_margin,
+        }
     }
 
     /// Get the X range (width)
@@ -214,6 +237,76 @@ impl Viewport {
         Some(Point::new(screen_x, screen_y))
     }
 
+    /// Transform a data point to screen coordinates, clamped to the plot area
+    ///
+    /// Points outside the data bounds land on the nearest plot edge instead
+    /// of being dropped. Returns `None` only for non-finite coordinates or an
+    /// empty plot area.
+    pub fn data_to_screen_clamped(&self, point: DataPoint) -> Option<Point> {
+        let bounds = self.data_bounds;
+        let clamped = DataPoint::new(
+            point.x.clamp(bounds.x_min, bounds.x_max),
+            point.y.clamp(bounds.y_min, bounds.y_max),
+        );
+        self.data_to_screen(clamped)
+    }
+
+    /// Clip the segment `start`-`end` to the data bounds (Liang-Barsky)
+    ///
+    /// Returns the screen endpoints of the visible part, or `None` when the
+    /// segment lies entirely outside. A line leaving the top of the plot
+    /// therefore ends on the top edge instead of breaking off at the last
+    /// in-range point.
+    pub fn clip_segment(&self, start: DataPoint, end: DataPoint) -> Option<(Point, Point)> {
+        let bounds = self.data_bounds;
+        let dx = end.x - start.x;
+        let dy = end.y - start.y;
+
+        // Entry and exit parameters along the segment
+        let mut t_enter = 0.0f32;
+        let mut t_exit = 1.0f32;
+
+        // (p, q) per edge: left, right, bottom, top
+        let edges = [
+            (-dx, start.x - bounds.x_min),
+            (dx, bounds.x_max - start.x),
+            (-dy, start.y - bounds.y_min),
+            (dy, bounds.y_max - start.y),
+        ];
+
+        for (p, q) in edges {
+            if p == 0.0 {
+                // Parallel to this edge: reject if outside it
+                if q < 0.0 {
+                    return None;
+                }
+                continue;
+            }
+
+            let t = q / p;
+            if p < 0.0 {
+                t_enter = t_enter.max(t);
+            } else {
+                t_exit = t_exit.min(t);
+            }
+            if t_enter > t_exit {
+                return None;
+            }
+        }
+
+        let along = |t: f32| DataPoint::new(start.x + dx * t, start.y + dy * t);
+        Some((
+            self.data_to_screen_clamped(along(t_enter))?,
+            self.data_to_screen_clamped(along(t_exit))?,
+        ))
+    }
+
+    /// Horizontal screen pixels per data unit
+    pub fn x_pixels_per_unit(&self) -> f32 {
+        let width = self.plot_area().size.width.saturating_sub(1) as f32;
+        width / self.data_bounds.x_range()
+    }
+
     /// Get the data bounds
     pub fn data_bounds(&self) -> &DataBounds {
         &self.data_bounds
@@ -234,3 +327,75 @@ impl Viewport {
         self.data_bounds = bounds;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0..10 on both axes over a 101x101 plot: ten pixels per data unit
+    fn viewport() -> Viewport {
+        Viewport::new(
+            DataBounds::new(0.0, 10.0, 0.0, 10.0),
+            Rectangle::new(Point::zero(), Size::new(101, 101)),
+        )
+        .with_padding(ViewportPadding::uniform(0))
+    }
+
+    #[test]
+    fn segment_crossing_the_top_edge_ends_on_the_boundary() {
+        let viewport = viewport();
+
+        // Leaves y_max = 10 halfway along, at x = 4
+        let (start, end) = viewport
+            .clip_segment(DataPoint::new(2.0, 5.0), DataPoint::new(6.0, 15.0))
+            .unwrap();
+
+        assert_eq!(start, Point::new(20, 50));
+        assert_eq!(end, Point::new(40, 0));
+        assert_eq!(end.y, viewport.plot_area().top_left.y);
+    }
+
+    #[test]
+    fn segment_entering_through_the_top_edge_starts_on_the_boundary() {
+        let (start, end) = viewport()
+            .clip_segment(DataPoint::new(6.0, 15.0), DataPoint::new(2.0, 5.0))
+            .unwrap();
+
+        assert_eq!(start, Point::new(40, 0));
+        assert_eq!(end, Point::new(20, 50));
+    }
+
+    #[test]
+    fn segment_inside_the_bounds_is_unchanged() {
+        let viewport = viewport();
+        let (a, b) = (DataPoint::new(1.0, 2.0), DataPoint::new(8.0, 7.0));
+
+        assert_eq!(
+            viewport.clip_segment(a, b),
+            Some((
+                viewport.data_to_screen(a).unwrap(),
+                viewport.data_to_screen(b).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn segment_entirely_above_the_plot_is_dropped() {
+        assert_eq!(
+            viewport().clip_segment(DataPoint::new(1.0, 12.0), DataPoint::new(9.0, 20.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn clamped_projection_pins_out_of_range_points_to_the_edge() {
+        let viewport = viewport();
+        let spike = DataPoint::new(5.0, 20.0);
+
+        assert_eq!(viewport.data_to_screen(spike), None);
+        assert_eq!(
+            viewport.data_to_screen_clamped(spike),
+            Some(Point::new(50, 0))
+        );
+    }
+}
@@ -0,0 +1,269 @@
+// src/ui/components/icon.rs
+//! Small monochrome icons drawn from built-in 1-bpp bitmaps
+
+use crate::ui::core::{DirtyRegion, Drawable};
+use crate::ui::styling::ColorPalette;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+
+/// Width and height of every glyph bitmap in pixels (before scaling)
+pub const ICON_SIZE_PX: u32 = 16;
+
+/// One row per `u16`, most significant bit on the left
+type GlyphBitmap = [u16; ICON_SIZE_PX as usize];
+
+static THERMOMETER: GlyphBitmap = [
+    0b00000011_10000000,
+    0b00000100_01000000,
+    0b00000100_01000000,
+    0b00000101_01000000,
+    0b00000101_01000000,
+    0b00000101_01000000,
+    0b00000101_01000000,
+    0b00000101_01000000,
+    0b00000101_01000000,
+    0b00001011_10100000,
+    0b00010111_11010000,
+    0b00010111_11010000,
+    0b00010111_11010000,
+    0b00001011_10100000,
+    0b00000100_01000000,
+    0b00000011_10000000,
+];
+
+static DROPLET: GlyphBitmap = [
+    0b00000001_10000000,
+    0b00000001_10000000,
+    0b00000011_11000000,
+    0b00000011_11000000,
+    0b00000111_11100000,
+    0b00000111_11100000,
+    0b00001111_11110000,
+    0b00011111_11111000,
+    0b00011111_11111000,
+    0b00111111_11111100,
+    0b00111111_11111100,
+    0b00111111_11111100,
+    0b00111111_11111100,
+    0b00011111_11111000,
+    0b00001111_11110000,
+    0b00000011_11000000,
+];
+
+static CLOUD: GlyphBitmap = [
+    0b00000000_00000000,
+    0b00000000_00000000,
+    0b00000000_00000000,
+    0b00000011_11000000,
+    0b00000111_11100000,
+    0b00001111_11110000,
+    0b00111111_11111000,
+    0b01111111_11111100,
+    0b11111111_11111111,
+    0b11111111_11111111,
+    0b11111111_11111111,
+    0b11111111_11111111,
+    0b01111111_11111110,
+    0b00000000_00000000,
+    0b00000000_00000000,
+    0b00000000_00000000,
+];
+
+/// Built-in icon glyphs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconGlyph {
+    /// Temperature
+    Thermometer,
+    /// Humidity
+    Droplet,
+    /// CO₂ / air quality
+    Cloud,
+}
+
+impl IconGlyph {
+    fn bitmap(self) -> &'static GlyphBitmap {
+        match self {
+            IconGlyph::Thermometer => &THERMOMETER,
+            IconGlyph::Droplet => &DROPLET,
+            IconGlyph::Cloud => &CLOUD,
+        }
+    }
+
+    /// Whether the unscaled glyph pixel at (`x`, `y`) is set
+    ///
+    /// Coordinates outside the `ICON_SIZE_PX` square are never set.
+    pub fn is_set(self, x: u32, y: u32) -> bool {
+        if x >= ICON_SIZE_PX || y >= ICON_SIZE_PX {
+            return false;
+        }
+        let row = self.bitmap()[y as usize];
+        row & (1 << (ICON_SIZE_PX - 1 - x)) != 0
+    }
+}
+
+/// Single-color icon drawn from a built-in glyph
+///
+/// Set pixels are drawn in the icon color; clear pixels are left untouched,
+/// so the icon sits on whatever background is already drawn. Each glyph
+/// pixel is drawn as a `scale` x `scale` block.
+///
+/// # Examples
+/// ```ignore
+/// let icon = Icon::new(Point::new(8, 8), IconGlyph::Droplet)
+///     .with_color(palette.primary)
+///     .with_scale(2);
+/// ```
+pub struct Icon {
+    top_left: Point,
+    glyph: IconGlyph,
+    color: Rgb565,
+    scale: u32,
+    dirty: bool,
+}
+
+impl Icon {
+    /// Create an icon at `top_left` at its native size
+    pub fn new(top_left: Point, glyph: IconGlyph) -> Self {
+        Self {
+            top_left,
+            glyph,
+            color: ColorPalette::default().text_primary,
+            scale: 1,
+            dirty: true,
+        }
+    }
+
+    /// Set the icon color.
+    pub fn with_color(mut self, color: Rgb565) -> Self {
+        self.color = color;
+        self.dirty = true;
+        self
+    }
+
+    /// Set the integer scale factor (at least 1).
+    pub fn with_scale(mut self, scale: u32) -> Self {
+        self.scale = scale.max(1);
+        self.dirty = true;
+        self
+    }
+
+    /// Update the icon color, marking dirty only if it changes
+    pub fn set_color(&mut self, color: Rgb565) {
+        if self.color != color {
+            self.color = color;
+            self.dirty = true;
+        }
+    }
+
+    /// Update the glyph, marking dirty only if it changes
+    pub fn set_glyph(&mut self, glyph: IconGlyph) {
+        if self.glyph != glyph {
+            self.glyph = glyph;
+            self.dirty = true;
+        }
+    }
+
+    /// Side length of the drawn icon in pixels
+    pub fn size_px(&self) -> u32 {
+        ICON_SIZE_PX * self.scale
+    }
+}
+
+impl Drawable for Icon {
+    fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        let glyph = self.glyph;
+        let scale = self.scale;
+        let origin = self.top_left;
+        let color = self.color;
+
+        let pixels = (0..self.size_px()).flat_map(move |py| {
+            (0..ICON_SIZE_PX * scale)
+                .filter(move |&px| glyph.is_set(px / scale, py / scale))
+                .map(move |px| Pixel(origin + Point::new(px as i32, py as i32), color))
+        });
+        display.draw_iter(pixels)
+    }
+
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(self.top_left, Size::new(self.size_px(), self.size_px()))
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    fn dirty_region(&self) -> Option<DirtyRegion> {
+        if self.dirty {
+            Some(DirtyRegion::new(self.bounds()))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framebuffer::FrameBuffer;
+
+    type IconBuffer = FrameBuffer<40, 40>;
+
+    fn lit(fb: &IconBuffer) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..40).flat_map(move |y| {
+            (0..40)
+                .filter(move |&x| fb.pixel(x, y) == Some(Rgb565::GREEN))
+                .map(move |x| (x, y))
+        })
+    }
+
+    #[test]
+    fn thermometer_sets_the_expected_pixels() {
+        let mut fb = IconBuffer::new();
+        Icon::new(Point::new(2, 3), IconGlyph::Thermometer)
+            .with_color(Rgb565::GREEN)
+            .draw(&mut fb)
+            .unwrap();
+
+        // Top row of the bulb outline is glyph columns 6..=8
+        let top_row: alloc::vec::Vec<_> = lit(&fb).filter(|&(_, y)| y == 3).collect();
+        assert_eq!(top_row, [(8, 3), (9, 3), (10, 3)]);
+
+        // Every lit pixel matches the bitmap, and nothing lands outside it
+        let expected = (0..ICON_SIZE_PX)
+            .flat_map(|y| (0..ICON_SIZE_PX).map(move |x| (x, y)))
+            .filter(|&(x, y)| IconGlyph::Thermometer.is_set(x, y))
+            .count();
+        assert_eq!(lit(&fb).count(), expected);
+        assert!(lit(&fb).all(|(x, y)| IconGlyph::Thermometer.is_set(x as u32 - 2, y as u32 - 3)));
+    }
+
+    #[test]
+    fn scaling_draws_each_glyph_pixel_as_a_block() {
+        let mut native = IconBuffer::new();
+        let mut scaled = IconBuffer::new();
+        let icon = Icon::new(Point::zero(), IconGlyph::Droplet).with_color(Rgb565::GREEN);
+        icon.draw(&mut native).unwrap();
+        let icon = icon.with_scale(2);
+        icon.draw(&mut scaled).unwrap();
+
+        assert_eq!(icon.bounds().size, Size::new(32, 32));
+        assert_eq!(lit(&scaled).count(), lit(&native).count() * 4);
+        assert!(lit(&scaled).all(|(x, y)| IconGlyph::Droplet.is_set(x as u32 / 2, y as u32 / 2)));
+    }
+
+    #[test]
+    fn pixels_outside_the_glyph_are_never_set() {
+        assert!(!IconGlyph::Cloud.is_set(ICON_SIZE_PX, 8));
+        assert!(!IconGlyph::Cloud.is_set(0, ICON_SIZE_PX));
+        assert!(IconGlyph::Cloud.is_set(0, 8));
+    }
+}
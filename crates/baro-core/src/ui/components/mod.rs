@@ -2,9 +2,29 @@
 //! UI components library
 
 pub mod button;
+pub mod clock;
+pub mod gauge;
 pub mod graph;
+pub mod icon;
+pub mod signal_bars;
+pub mod slider;
+pub mod sparkline;
+pub mod stepper;
+pub mod storage_banner;
 pub mod text;
+pub mod time_sync_banner;
+pub mod toast;
 
 pub use button::Button;
+pub use clock::{Clock, clock_text};
+pub use gauge::Gauge;
 pub use graph::Graph;
-pub use text::{MultiLineText, TextComponent, TextSize};
+pub use icon::{ICON_SIZE_PX, Icon, IconGlyph};
+pub use signal_bars::SignalBars;
+pub use slider::Slider;
+pub use sparkline::Sparkline;
+pub use stepper::Stepper;
+pub use storage_banner::StorageBanner;
+pub use text::{MARQUEE_GAP_PX, MultiLineText, TextComponent, TextSize};
+pub use time_sync_banner::TimeSyncBanner;
+pub use toast::ToastQueue;
@@ -0,0 +1,213 @@
+// src/ui/components/signal_bars.rs
+//! WiFi signal-strength indicator drawn as 0–4 rising bars
+
+use crate::ui::core::{DirtyRegion, Drawable};
+use crate::ui::styling::ColorPalette;
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+
+/// Number of bars shown for a full-strength signal
+pub const SIGNAL_BARS_MAX: u8 = 4;
+
+/// Minimum RSSI (dBm) for each bar count from 4 down to 1; anything weaker shows 0 bars
+const RSSI_BAR_THRESHOLDS_DBM: [i8; SIGNAL_BARS_MAX as usize] = [-55, -67, -78, -88];
+
+/// Horizontal gap between bars in pixels
+const BAR_GAP_PX: u32 = 2;
+
+/// Map an RSSI reading in dBm to a bar count in `0..=4`
+///
+/// Thresholds follow the usual WiFi quality bands: excellent (≥ -55 dBm),
+/// good (≥ -67), fair (≥ -78), weak (≥ -88), and unusable below that.
+pub const fn bars_for_rssi(rssi_dbm: i8) -> u8 {
+    let mut i = 0;
+    while i < RSSI_BAR_THRESHOLDS_DBM.len() {
+        if rssi_dbm >= RSSI_BAR_THRESHOLDS_DBM[i] {
+            return SIGNAL_BARS_MAX - i as u8;
+        }
+        i += 1;
+    }
+    0
+}
+
+/// Signal-strength indicator
+///
+/// Draws `SIGNAL_BARS_MAX` bars of increasing height across its bounds. Lit
+/// bars use the palette's primary color and unlit bars its border color. With
+/// no reading (`None`) every bar is drawn unlit.
+///
+/// # Examples
+/// ```ignore
+/// let mut bars = SignalBars::new(Rectangle::new(Point::new(290, 10), Size::new(18, 16)));
+/// bars.set_rssi(Some(-70)); // 2 bars
+/// ```
+pub struct SignalBars {
+    bounds: Rectangle,
+    rssi_dbm: Option<i8>,
+    palette: ColorPalette,
+    dirty: bool,
+}
+
+impl SignalBars {
+    /// Create an indicator with no reading
+    pub fn new(bounds: Rectangle) -> Self {
+        Self {
+            bounds,
+            rssi_dbm: None,
+            palette: ColorPalette::default(),
+            dirty: true,
+        }
+    }
+
+    /// Set the indicator's color palette.
+    pub fn with_palette(mut self, palette: ColorPalette) -> Self {
+        self.palette = palette;
+        self.dirty = true;
+        self
+    }
+
+    /// Latest RSSI reading in dBm, if any
+    pub fn rssi(&self) -> Option<i8> {
+        self.rssi_dbm
+    }
+
+    /// Number of lit bars for the current reading
+    pub fn bars(&self) -> u8 {
+        self.rssi_dbm.map_or(0, bars_for_rssi)
+    }
+
+    /// Update the reading, marking dirty only if the bar count changes
+    pub fn set_rssi(&mut self, rssi_dbm: Option<i8>) {
+        let previous_bars = self.bars();
+        let was_known = self.rssi_dbm.is_some();
+        self.rssi_dbm = rssi_dbm;
+        if self.bars() != previous_bars || rssi_dbm.is_some() != was_known {
+            self.dirty = true;
+        }
+    }
+}
+
+impl Drawable for SignalBars {
+    fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        let bar_count = SIGNAL_BARS_MAX as u32;
+        let bar_width = self
+            .bounds
+            .size
+            .width
+            .saturating_sub(BAR_GAP_PX * (bar_count - 1))
+            / bar_count;
+        let height = self.bounds.size.height;
+        let bottom = self.bounds.top_left.y + height as i32;
+        let lit = self.bars() as u32;
+
+        for i in 0..bar_count {
+            let bar_height = (height * (i + 1) / bar_count).max(1);
+            let color = if i < lit {
+                self.palette.primary
+            } else {
+                self.palette.border
+            };
+
+            Rectangle::new(
+                Point::new(
+                    self.bounds.top_left.x + (i * (bar_width + BAR_GAP_PX)) as i32,
+                    bottom - bar_height as i32,
+                ),
+                Size::new(bar_width.max(1), bar_height),
+            )
+            .into_styled(PrimitiveStyle::with_fill(color))
+            .draw(display)?;
+        }
+
+        Ok(())
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    fn dirty_region(&self) -> Option<DirtyRegion> {
+        if self.dirty {
+            Some(DirtyRegion::new(self.bounds))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framebuffer::FrameBuffer;
+    use crate::pages::test_harness::count_color;
+
+    #[test]
+    fn representative_readings_map_to_bars() {
+        assert_eq!(bars_for_rssi(-50), 4);
+        assert_eq!(bars_for_rssi(-70), 2);
+        assert_eq!(bars_for_rssi(-90), 0);
+    }
+
+    #[test]
+    fn thresholds_are_inclusive() {
+        assert_eq!(bars_for_rssi(-55), 4);
+        assert_eq!(bars_for_rssi(-56), 3);
+        assert_eq!(bars_for_rssi(-88), 1);
+        assert_eq!(bars_for_rssi(-89), 0);
+        assert_eq!(bars_for_rssi(i8::MAX), 4);
+        assert_eq!(bars_for_rssi(i8::MIN), 0);
+    }
+
+    #[test]
+    fn reading_within_same_band_stays_clean() {
+        let mut bars = SignalBars::new(Rectangle::zero());
+        bars.set_rssi(Some(-60));
+        bars.mark_clean();
+
+        bars.set_rssi(Some(-62));
+        assert!(!bars.is_dirty());
+
+        bars.set_rssi(Some(-70));
+        assert!(bars.is_dirty());
+    }
+
+    #[test]
+    fn losing_the_reading_marks_dirty() {
+        let mut bars = SignalBars::new(Rectangle::zero());
+        bars.set_rssi(Some(-95));
+        bars.mark_clean();
+
+        bars.set_rssi(None);
+        assert!(bars.is_dirty());
+        assert_eq!(bars.bars(), 0);
+    }
+
+    #[test]
+    fn draws_lit_bars_in_primary_color() {
+        let palette = ColorPalette::default();
+        // Four 4 px wide bars, 4, 8, 12 and 16 px tall
+        let bounds = Rectangle::new(Point::zero(), Size::new(22, 16));
+        let mut bars = SignalBars::new(bounds).with_palette(palette);
+        bars.set_rssi(Some(-70));
+
+        let mut fb: FrameBuffer = FrameBuffer::new();
+        bars.draw(&mut fb).unwrap();
+
+        assert_eq!(count_color(&fb, bounds, palette.primary), 4 * 4 + 4 * 8);
+        assert_eq!(count_color(&fb, bounds, palette.border), 4 * 12 + 4 * 16);
+    }
+}
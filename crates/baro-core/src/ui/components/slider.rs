@@ -0,0 +1,363 @@
+// src/ui/components/slider.rs
+//! Horizontal slider component reporting a 0–100 value
+
+use crate::ui::core::{
+    Action, DirtyRegion, Drawable, TouchEvent, TouchPoint, TouchResult, Touchable,
+};
+use crate::ui::styling::ColorPalette;
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Circle, PrimitiveStyle, Rectangle, RoundedRectangle};
+
+/// Largest value a slider can report
+pub const SLIDER_MAX_VALUE: u8 = 100;
+
+/// Default snap increment for slider values
+const DEFAULT_STEP: u8 = 5;
+
+/// Height of the slider track in pixels
+const TRACK_HEIGHT_PX: u32 = 4;
+
+/// Diameter of the draggable knob in pixels
+const KNOB_DIAMETER_PX: u32 = 14;
+
+/// Horizontal slider with a draggable knob
+///
+/// Maps the touch X coordinate across the slider bounds to a value in
+/// `0..=100`, snapped to a configurable step. Each time the snapped value
+/// changes, the slider emits the action produced by its `on_change` mapping.
+/// An optional `on_release` mapping is emitted once when the finger lifts,
+/// for work too costly to repeat on every step (such as persisting it).
+///
+/// # Touch Behavior
+/// - A press inside the bounds jumps the knob to that position and starts tracking
+/// - Drags while tracking move the knob (clamped to the track ends)
+/// - A release while tracking emits `on_release` if the value changed since the press
+/// - A press outside the bounds stops tracking
+///
+/// # Examples
+/// ```ignore
+/// let slider = Slider::new(
+///     Rectangle::new(Point::new(20, 100), Size::new(200, 20)),
+///     80,
+///     Action::SetBrightness,
+/// );
+/// ```
+pub struct Slider {
+    bounds: Rectangle,
+    value: u8,
+    step: u8,
+    on_change: fn(u8) -> Action,
+    on_release: Option<fn(u8) -> Action>,
+    palette: ColorPalette,
+    tracking: bool,
+    /// Value when the current press started, to detect a changed drag on release
+    press_value: u8,
+    dirty: bool,
+}
+
+impl Slider {
+    /// Create a new slider with the given bounds, initial value, and action mapping.
+    ///
+    /// The initial value is clamped and snapped like any touch-driven value.
+    pub fn new(bounds: Rectangle, value: u8, on_change: fn(u8) -> Action) -> Self {
+        let value = Self::snap(value, DEFAULT_STEP);
+        Self {
+            bounds,
+            value,
+            step: DEFAULT_STEP,
+            on_change,
+            on_release: None,
+            palette: ColorPalette::default(),
+            tracking: false,
+            press_value: value,
+            dirty: true,
+        }
+    }
+
+    /// Emit the action produced by `on_release` when a drag that changed the
+    /// value ends.
+    pub fn with_on_release(mut self, on_release: fn(u8) -> Action) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Set the snap increment (a step of 0 is treated as 1).
+    pub fn with_step(mut self, step: u8) -> Self {
+        self.step = step.max(1);
+        self.value = Self::snap(self.value, self.step);
+        self.dirty = true;
+        self
+    }
+
+    /// Set the slider's color palette.
+    pub fn with_palette(mut self, palette: ColorPalette) -> Self {
+        self.palette = palette;
+        self.dirty = true;
+        self
+    }
+
+    /// Set the slider's bounds (for dynamic repositioning)
+    pub fn set_bounds(&mut self, bounds: Rectangle) {
+        if self.bounds != bounds {
+            self.bounds = bounds;
+            self.dirty = true;
+        }
+    }
+
+    /// Current slider value (`0..=100`)
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+
+    /// Set the slider value programmatically (clamped and snapped, no action emitted).
+    pub fn set_value(&mut self, value: u8) {
+        let snapped = Self::snap(value, self.step);
+        if self.value != snapped {
+            self.value = snapped;
+            self.dirty = true;
+        }
+    }
+
+    /// Clamp a value to `0..=100` and round it to the nearest multiple of `step`.
+    fn snap(value: u8, step: u8) -> u8 {
+        let step = step.max(1) as u16;
+        let clamped = value.min(SLIDER_MAX_VALUE) as u16;
+        let snapped = (clamped + step / 2) / step * step;
+        snapped.min(SLIDER_MAX_VALUE as u16) as u8
+    }
+
+    /// Horizontal span usable by the knob center: (left x, width in pixels).
+    fn track_span(&self) -> (i32, u32) {
+        let inset = KNOB_DIAMETER_PX / 2;
+        let left = self.bounds.top_left.x + inset as i32;
+        let width = self
+            .bounds
+            .size
+            .width
+            .saturating_sub(KNOB_DIAMETER_PX)
+            .max(1);
+        (left, width)
+    }
+
+    /// Map a touch X coordinate to a snapped slider value.
+    ///
+    /// Positions left of the track map to 0 and right of the track to 100.
+    pub fn value_for_x(&self, x: i32) -> u8 {
+        let (left, width) = self.track_span();
+        let offset = (x - left).clamp(0, width as i32) as u32;
+        let percent = (offset * SLIDER_MAX_VALUE as u32 + width / 2) / width;
+        Self::snap(percent as u8, self.step)
+    }
+
+    /// X coordinate of the knob center for the current value.
+    fn knob_x(&self) -> i32 {
+        let (left, width) = self.track_span();
+        left + (width * self.value as u32 / SLIDER_MAX_VALUE as u32) as i32
+    }
+
+    /// Move the knob to a touch position, returning the action if the value changed.
+    fn update_from_point(&mut self, point: TouchPoint) -> TouchResult {
+        let next = self.value_for_x(point.x as i32);
+        if next == self.value {
+            return TouchResult::Handled;
+        }
+
+        self.value = next;
+        self.dirty = true;
+        TouchResult::Action((self.on_change)(next))
+    }
+}
+
+impl Drawable for Slider {
+    fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        let (left, width) = self.track_span();
+        let center_y = self.bounds.center().y;
+        let track_top = center_y - (TRACK_HEIGHT_PX / 2) as i32;
+        let corner = Size::new(TRACK_HEIGHT_PX / 2, TRACK_HEIGHT_PX / 2);
+
+        // Full track
+        RoundedRectangle::with_equal_corners(
+            Rectangle::new(
+                Point::new(left, track_top),
+                Size::new(width, TRACK_HEIGHT_PX),
+            ),
+            corner,
+        )
+        .into_styled(PrimitiveStyle::with_fill(self.palette.border))
+        .draw(display)?;
+
+        // Filled portion up to the knob
+        let knob_x = self.knob_x();
+        let filled_width = (knob_x - left).max(0) as u32;
+        if filled_width > 0 {
+            RoundedRectangle::with_equal_corners(
+                Rectangle::new(
+                    Point::new(left, track_top),
+                    Size::new(filled_width, TRACK_HEIGHT_PX),
+                ),
+                corner,
+            )
+            .into_styled(PrimitiveStyle::with_fill(self.palette.primary))
+            .draw(display)?;
+        }
+
+        // Knob
+        let radius = (KNOB_DIAMETER_PX / 2) as i32;
+        Circle::new(
+            Point::new(knob_x - radius, center_y - radius),
+            KNOB_DIAMETER_PX,
+        )
+        .into_styled(PrimitiveStyle::with_fill(self.palette.text_primary))
+        .draw(display)?;
+
+        Ok(())
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    fn dirty_region(&self) -> Option<DirtyRegion> {
+        if self.dirty {
+            Some(DirtyRegion::new(self.bounds))
+        } else {
+            None
+        }
+    }
+}
+
+impl Touchable for Slider {
+    fn contains_point(&self, point: TouchPoint) -> bool {
+        self.bounds.contains(point.to_point())
+    }
+
+    fn handle_touch(&mut self, event: TouchEvent) -> TouchResult {
+        match event {
+            TouchEvent::Press(point) if self.contains_point(point) => {
+                self.tracking = true;
+                self.press_value = self.value;
+                self.update_from_point(point)
+            }
+            TouchEvent::Press(_) => {
+                self.tracking = false;
+                TouchResult::NotHandled
+            }
+            TouchEvent::Drag(point) if self.tracking => self.update_from_point(point),
+            TouchEvent::Drag(_) => TouchResult::NotHandled,
+            TouchEvent::Release(_) if self.tracking => {
+                self.tracking = false;
+                match self.on_release {
+                    Some(on_release) if self.value != self.press_value => {
+                        TouchResult::Action(on_release(self.value))
+                    }
+                    _ => TouchResult::Handled,
+                }
+            }
+            TouchEvent::Release(_) => TouchResult::NotHandled,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 114 px wide, so the knob center spans x = 7..=107 (one pixel per percent)
+    fn slider(value: u8) -> Slider {
+        Slider::new(
+            Rectangle::new(Point::zero(), Size::new(100 + KNOB_DIAMETER_PX, 20)),
+            value,
+            Action::PreviewBrightness,
+        )
+        .with_on_release(Action::SetBrightness)
+    }
+
+    fn at(x: u16) -> TouchPoint {
+        TouchPoint::new(x, 10)
+    }
+
+    #[test]
+    fn track_ends_map_to_zero_and_full() {
+        let slider = slider(50);
+        assert_eq!(slider.value_for_x(7), 0);
+        assert_eq!(slider.value_for_x(107), SLIDER_MAX_VALUE);
+    }
+
+    #[test]
+    fn positions_past_track_ends_clamp() {
+        let slider = slider(50);
+        assert_eq!(slider.value_for_x(-40), 0);
+        assert_eq!(slider.value_for_x(400), SLIDER_MAX_VALUE);
+    }
+
+    #[test]
+    fn positions_snap_to_nearest_step() {
+        let slider = slider(50);
+        assert_eq!(slider.value_for_x(7 + 42), 40);
+        assert_eq!(slider.value_for_x(7 + 43), 45);
+        assert_eq!(slider.with_step(10).value_for_x(7 + 44), 40);
+    }
+
+    #[test]
+    fn initial_value_is_clamped_and_snapped() {
+        assert_eq!(slider(250).value(), SLIDER_MAX_VALUE);
+        assert_eq!(slider(52).value(), 50);
+    }
+
+    #[test]
+    fn drag_previews_and_release_commits() {
+        let mut slider = slider(50);
+
+        assert_eq!(
+            slider.handle_touch(TouchEvent::Press(at(7 + 60))),
+            TouchResult::Action(Action::PreviewBrightness(60))
+        );
+        assert_eq!(
+            slider.handle_touch(TouchEvent::Drag(at(7 + 80))),
+            TouchResult::Action(Action::PreviewBrightness(80))
+        );
+        assert_eq!(
+            slider.handle_touch(TouchEvent::Release(at(7 + 80))),
+            TouchResult::Action(Action::SetBrightness(80))
+        );
+    }
+
+    #[test]
+    fn release_without_change_commits_nothing() {
+        let mut slider = slider(50);
+
+        slider.handle_touch(TouchEvent::Press(at(7 + 70)));
+        slider.handle_touch(TouchEvent::Drag(at(7 + 50)));
+        assert_eq!(
+            slider.handle_touch(TouchEvent::Release(at(7 + 50))),
+            TouchResult::Handled
+        );
+    }
+
+    #[test]
+    fn press_outside_is_not_handled() {
+        let mut slider = slider(50);
+        assert_eq!(
+            slider.handle_touch(TouchEvent::Press(TouchPoint::new(7, 200))),
+            TouchResult::NotHandled
+        );
+        assert_eq!(
+            slider.handle_touch(TouchEvent::Release(TouchPoint::new(7, 200))),
+            TouchResult::NotHandled
+        );
+    }
+}
@@ -0,0 +1,247 @@
+// src/ui/components/sparkline.rs
+//! Compact trend line for one sensor, without axes or labels
+
+use crate::sensors::SensorType;
+use crate::ui::core::{DirtyRegion, Drawable, PageEvent};
+use crate::ui::styling::ColorPalette;
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Polyline, PrimitiveStyle, Rectangle};
+use heapless::{Deque, Vec};
+
+/// Sparkline over the last `N` readings of a sensor
+///
+/// Values are kept in a ring buffer; once full, each new reading evicts the
+/// oldest. The line is scaled so the oldest point sits on the left edge, the
+/// newest on the right edge, and the lowest and highest values on the bottom
+/// and top edges. A flat series is drawn through the vertical middle.
+///
+/// # Examples
+/// ```ignore
+/// let mut spark = Sparkline::<30>::new(tile_bounds, SensorType::Co2)
+///     .with_color(QualityLevel::Good.foreground_color());
+/// spark.on_event(&PageEvent::SensorUpdate(data));
+/// ```
+pub struct Sparkline<const N: usize> {
+    bounds: Rectangle,
+    sensor: SensorType,
+    values: Deque<f32, N>,
+    color: Rgb565,
+    dirty: bool,
+}
+
+impl<const N: usize> Sparkline<N> {
+    /// Create an empty sparkline for `sensor`
+    pub fn new(bounds: Rectangle, sensor: SensorType) -> Self {
+        Self {
+            bounds,
+            sensor,
+            values: Deque::new(),
+            color: ColorPalette::default().primary,
+            dirty: true,
+        }
+    }
+
+    /// Set the line color.
+    pub fn with_color(mut self, color: Rgb565) -> Self {
+        self.color = color;
+        self.dirty = true;
+        self
+    }
+
+    /// Update the line color, marking dirty only if it changes
+    pub fn set_color(&mut self, color: Rgb565) {
+        if self.color != color {
+            self.color = color;
+            self.dirty = true;
+        }
+    }
+
+    /// Number of buffered readings
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether no readings have been buffered yet
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Append a reading, evicting the oldest when full (non-finite values are ignored)
+    pub fn push(&mut self, value: f32) {
+        if !value.is_finite() || N == 0 {
+            return;
+        }
+        if self.values.is_full() {
+            self.values.pop_front();
+        }
+        let _ = self.values.push_back(value);
+        self.dirty = true;
+    }
+
+    /// Apply a page event, returning whether the sparkline changed
+    ///
+    /// Only `PageEvent::SensorUpdate` carrying a reading for this sensor is used.
+    pub fn on_event(&mut self, event: &PageEvent) -> bool {
+        match event {
+            PageEvent::SensorUpdate(data) => match data.value(self.sensor) {
+                Some(value) => {
+                    self.push(value);
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Screen coordinates of the buffered readings, oldest first
+    pub fn points(&self) -> Vec<Point, N> {
+        let mut points = Vec::new();
+        let Some((min, max)) = self.value_range() else {
+            return points;
+        };
+
+        let left = self.bounds.top_left.x;
+        let bottom = self.bounds.top_left.y + self.bounds.size.height.saturating_sub(1) as i32;
+        let width = self.bounds.size.width.saturating_sub(1) as f32;
+        let height = self.bounds.size.height.saturating_sub(1) as f32;
+        let last_index = (self.values.len() - 1).max(1) as f32;
+        let range = max - min;
+
+        for (i, &value) in self.values.iter().enumerate() {
+            let x = left + (i as f32 / last_index * width) as i32;
+            let frac = if range > 0.0 {
+                (value - min) / range
+            } else {
+                0.5
+            };
+            let y = bottom - (frac * height) as i32;
+            let _ = points.push(Point::new(x, y));
+        }
+
+        points
+    }
+
+    /// Lowest and highest buffered values
+    fn value_range(&self) -> Option<(f32, f32)> {
+        let mut values = self.values.iter().copied();
+        let first = values.next()?;
+        Some(values.fold((first, first), |(min, max), v| (min.min(v), max.max(v))))
+    }
+}
+
+impl<const N: usize> Drawable for Sparkline<N> {
+    fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        let points = self.points();
+        if points.len() < 2 {
+            return Ok(());
+        }
+
+        Polyline::new(&points)
+            .into_styled(PrimitiveStyle::with_stroke(self.color, 1))
+            .draw(display)?;
+
+        Ok(())
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    fn dirty_region(&self) -> Option<DirtyRegion> {
+        if self.dirty {
+            Some(DirtyRegion::new(self.bounds))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::core::SensorData;
+
+    fn sparkline<const N: usize>() -> Sparkline<N> {
+        // 41x21 so both axes have an even pixel span (40 and 20)
+        Sparkline::new(
+            Rectangle::new(Point::new(10, 5), Size::new(41, 21)),
+            SensorType::Co2,
+        )
+    }
+
+    fn co2_update(ppm: f32) -> PageEvent {
+        PageEvent::SensorUpdate(SensorData {
+            temperature: Some(21.0),
+            humidity: None,
+            co2: Some(ppm),
+            lux: None,
+            timestamp: 0,
+        })
+    }
+
+    #[test]
+    fn endpoints_map_to_the_corners_of_the_value_range() {
+        let mut spark = sparkline::<8>();
+        for value in [400.0, 800.0, 600.0, 1200.0] {
+            spark.push(value);
+        }
+
+        let points = spark.points();
+        assert_eq!(points.len(), 4);
+        // Oldest (the minimum) at bottom-left, newest (the maximum) at top-right
+        assert_eq!(points[0], Point::new(10, 25));
+        assert_eq!(points[3], Point::new(50, 5));
+        // 800 is halfway up the 400..1200 range, a third of the way across
+        assert_eq!(points[1], Point::new(23, 15));
+    }
+
+    #[test]
+    fn flat_series_runs_through_the_middle() {
+        let mut spark = sparkline::<4>();
+        spark.push(500.0);
+        spark.push(500.0);
+
+        let points = spark.points();
+        assert_eq!(points[0], Point::new(10, 15));
+        assert_eq!(points[1], Point::new(50, 15));
+    }
+
+    #[test]
+    fn full_buffer_evicts_the_oldest_reading() {
+        let mut spark = sparkline::<3>();
+        for value in [100.0, 200.0, 300.0, 400.0] {
+            spark.push(value);
+        }
+        assert_eq!(spark.len(), 3);
+        // 200 is now the oldest and the minimum
+        let points = spark.points();
+        assert_eq!(points[0], Point::new(10, 25));
+        assert_eq!(points[2], Point::new(50, 5));
+    }
+
+    #[test]
+    fn sensor_updates_feed_only_the_matching_sensor() {
+        let mut spark = sparkline::<4>();
+        assert!(spark.on_event(&co2_update(420.0)));
+        assert_eq!(spark.len(), 1);
+
+        let mut lux = Sparkline::<4>::new(Rectangle::zero(), SensorType::Lux);
+        assert!(!lux.on_event(&co2_update(420.0)));
+        assert!(lux.is_empty());
+    }
+}
@@ -0,0 +1,324 @@
+// src/ui/components/stepper.rs
+//! Bounded numeric entry with `-`/`+` buttons
+
+use crate::ui::core::{
+    Action, DirtyRegion, Drawable, TouchEvent, TouchPoint, TouchResult, Touchable,
+};
+use crate::ui::styling::ColorPalette;
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::mono_font::iso_8859_1::FONT_6X10;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle, RoundedRectangle};
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+
+use core::fmt::Write;
+
+/// Corner radius of the `-`/`+` buttons in pixels
+const BUTTON_CORNER_RADIUS_PX: u32 = 4;
+
+/// Numeric stepper with a centered value between `-` and `+` buttons
+///
+/// The buttons are squares as tall as the bounds at the left and right ends.
+/// A press on a button moves the value by the step, clamped to `min..=max`,
+/// and emits the action produced by its `on_change` mapping. A press that
+/// cannot move the value (e.g. `+` at `max`) is handled without an action.
+///
+/// # Examples
+/// ```ignore
+/// let stepper = Stepper::new(
+///     Rectangle::new(Point::new(180, 100), Size::new(120, 28)),
+///     30,
+///     5,
+///     300,
+///     |secs| Action::SetSampleInterval(secs as u32),
+/// )
+/// .with_step(5)
+/// .with_unit("s");
+/// ```
+pub struct Stepper {
+    bounds: Rectangle,
+    value: i32,
+    min: i32,
+    max: i32,
+    step: i32,
+    unit: &'static str,
+    on_change: fn(i32) -> Action,
+    palette: ColorPalette,
+    dirty: bool,
+}
+
+impl Stepper {
+    /// Create a stepper over `min..=max` with a step of 1.
+    ///
+    /// The initial value is clamped to the range; if `max < min` the range
+    /// collapses to `min`.
+    pub fn new(
+        bounds: Rectangle,
+        value: i32,
+        min: i32,
+        max: i32,
+        on_change: fn(i32) -> Action,
+    ) -> Self {
+        let max = max.max(min);
+        Self {
+            bounds,
+            value: value.clamp(min, max),
+            min,
+            max,
+            step: 1,
+            unit: "",
+            on_change,
+            palette: ColorPalette::default(),
+            dirty: true,
+        }
+    }
+
+    /// Set the change per press (a step below 1 is treated as 1).
+    pub fn with_step(mut self, step: i32) -> Self {
+        self.step = step.max(1);
+        self
+    }
+
+    /// Set a unit suffix shown after the value.
+    pub fn with_unit(mut self, unit: &'static str) -> Self {
+        self.unit = unit;
+        self.dirty = true;
+        self
+    }
+
+    /// Set the stepper's color palette.
+    pub fn with_palette(mut self, palette: ColorPalette) -> Self {
+        self.palette = palette;
+        self.dirty = true;
+        self
+    }
+
+    /// Current value
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// Set the value programmatically (clamped, no action emitted).
+    pub fn set_value(&mut self, value: i32) {
+        let clamped = value.clamp(self.min, self.max);
+        if self.value != clamped {
+            self.value = clamped;
+            self.dirty = true;
+        }
+    }
+
+    /// Screen bounds of the `-` button
+    pub fn decrement_bounds(&self) -> Rectangle {
+        let side = self.button_side();
+        Rectangle::new(self.bounds.top_left, Size::new(side, side))
+    }
+
+    /// Screen bounds of the `+` button
+    pub fn increment_bounds(&self) -> Rectangle {
+        let side = self.button_side();
+        Rectangle::new(
+            Point::new(
+                self.bounds.top_left.x + self.bounds.size.width as i32 - side as i32,
+                self.bounds.top_left.y,
+            ),
+            Size::new(side, side),
+        )
+    }
+
+    /// Button side length: the bounds height, limited to half the width
+    fn button_side(&self) -> u32 {
+        self.bounds.size.height.min(self.bounds.size.width / 2)
+    }
+
+    /// Move the value by `delta`, returning the action if it changed.
+    fn step_by(&mut self, delta: i32) -> TouchResult {
+        let next = self.value.saturating_add(delta).clamp(self.min, self.max);
+        if next == self.value {
+            return TouchResult::Handled;
+        }
+
+        self.value = next;
+        self.dirty = true;
+        TouchResult::Action((self.on_change)(next))
+    }
+
+    fn draw_button<D: DrawTarget<Color = Rgb565>>(
+        &self,
+        display: &mut D,
+        bounds: Rectangle,
+        glyph: &str,
+        enabled: bool,
+    ) -> Result<(), D::Error> {
+        RoundedRectangle::with_equal_corners(
+            bounds,
+            Size::new(BUTTON_CORNER_RADIUS_PX, BUTTON_CORNER_RADIUS_PX),
+        )
+        .into_styled(PrimitiveStyle::with_fill(self.palette.control))
+        .draw(display)?;
+
+        let color = if enabled {
+            self.palette.text_primary
+        } else {
+            self.palette.text_secondary
+        };
+        Text::with_text_style(
+            glyph,
+            bounds.center(),
+            MonoTextStyle::new(&FONT_6X10, color),
+            TextStyleBuilder::new()
+                .alignment(Alignment::Center)
+                .baseline(Baseline::Middle)
+                .build(),
+        )
+        .draw(display)?;
+
+        Ok(())
+    }
+}
+
+impl Drawable for Stepper {
+    fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        self.draw_button(display, self.decrement_bounds(), "-", self.value > self.min)?;
+        self.draw_button(display, self.increment_bounds(), "+", self.value < self.max)?;
+
+        let mut label = heapless::String::<16>::new();
+        let _ = write!(label, "{}{}", self.value, self.unit);
+        Text::with_text_style(
+            &label,
+            self.bounds.center(),
+            MonoTextStyle::new(&FONT_6X10, self.palette.text_primary),
+            TextStyleBuilder::new()
+                .alignment(Alignment::Center)
+                .baseline(Baseline::Middle)
+                .build(),
+        )
+        .draw(display)?;
+
+        Ok(())
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    fn dirty_region(&self) -> Option<DirtyRegion> {
+        if self.dirty {
+            Some(DirtyRegion::new(self.bounds))
+        } else {
+            None
+        }
+    }
+}
+
+impl Touchable for Stepper {
+    fn contains_point(&self, point: TouchPoint) -> bool {
+        self.bounds.contains(point.to_point())
+    }
+
+    fn handle_touch(&mut self, event: TouchEvent) -> TouchResult {
+        let TouchEvent::Press(point) = event else {
+            return TouchResult::NotHandled;
+        };
+
+        let p = point.to_point();
+        if self.decrement_bounds().contains(p) {
+            self.step_by(-self.step)
+        } else if self.increment_bounds().contains(p) {
+            self.step_by(self.step)
+        } else if self.contains_point(point) {
+            TouchResult::Handled
+        } else {
+            TouchResult::NotHandled
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 120x20: `-` covers x 10..30, `+` covers x 110..130
+    fn stepper(value: i32) -> Stepper {
+        Stepper::new(
+            Rectangle::new(Point::new(10, 10), Size::new(120, 20)),
+            value,
+            0,
+            100,
+            |value| Action::SetSampleInterval(value as u32),
+        )
+        .with_step(5)
+    }
+
+    fn press(x: u16, y: u16) -> TouchEvent {
+        TouchEvent::Press(TouchPoint::new(x, y))
+    }
+
+    #[test]
+    fn plus_at_max_is_a_no_op() {
+        let mut stepper = stepper(100);
+        stepper.mark_clean();
+
+        assert_eq!(stepper.handle_touch(press(120, 20)), TouchResult::Handled);
+        assert_eq!(stepper.value(), 100);
+        assert!(!stepper.is_dirty());
+    }
+
+    #[test]
+    fn minus_decrements_by_the_step() {
+        let mut stepper = stepper(50);
+
+        assert_eq!(
+            stepper.handle_touch(press(15, 20)),
+            TouchResult::Action(Action::SetSampleInterval(45))
+        );
+        assert_eq!(stepper.value(), 45);
+        assert!(stepper.is_dirty());
+    }
+
+    #[test]
+    fn steps_clamp_to_the_range() {
+        let mut low = stepper(3);
+        assert_eq!(
+            low.handle_touch(press(15, 20)),
+            TouchResult::Action(Action::SetSampleInterval(0))
+        );
+        assert_eq!(low.handle_touch(press(15, 20)), TouchResult::Handled);
+
+        let mut high = stepper(98);
+        assert_eq!(
+            high.handle_touch(press(120, 20)),
+            TouchResult::Action(Action::SetSampleInterval(100))
+        );
+    }
+
+    #[test]
+    fn touches_outside_the_buttons() {
+        let mut stepper = stepper(50);
+        // Between the buttons: consumed but the value is unchanged
+        assert_eq!(stepper.handle_touch(press(70, 20)), TouchResult::Handled);
+        // Outside the stepper and non-press events pass through
+        assert_eq!(
+            stepper.handle_touch(press(200, 200)),
+            TouchResult::NotHandled
+        );
+        assert_eq!(
+            stepper.handle_touch(TouchEvent::Release(TouchPoint::new(15, 20))),
+            TouchResult::NotHandled
+        );
+        assert_eq!(stepper.value(), 50);
+    }
+}
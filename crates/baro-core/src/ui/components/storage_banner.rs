@@ -0,0 +1,134 @@
+// src/ui/components/storage_banner.rs
+//! Warning strip shown while data is only kept in RAM
+
+use crate::ui::core::{DirtyRegion, Drawable};
+use crate::ui::styling::{COLOR_POOR_BACKGROUND, COLOR_POOR_FOREGROUND};
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::mono_font::iso_8859_1::FONT_6X10;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+
+/// Height of the banner strip in pixels
+const BANNER_HEIGHT_PX: u32 = 14;
+
+/// Message shown while storage is not persistent
+const BANNER_MESSAGE: &str = "No SD card - data lost on reboot";
+
+/// Strip along the bottom of the screen warning that data won't survive a reboot
+///
+/// Visible while the storage manager runs on its RAM backend. Uses the fixed
+/// "poor" status colors like [`TimeSyncBanner`](super::TimeSyncBanner); when
+/// both are shown, raise this one above it with `set_bottom_inset_px`.
+///
+/// # Examples
+/// ```ignore
+/// let mut banner = StorageBanner::new(screen_bounds);
+/// banner.set_persistent(false); // now visible
+/// ```
+pub struct StorageBanner {
+    bounds: Rectangle,
+    bottom_inset_px: u32,
+    visible: bool,
+    dirty: bool,
+}
+
+impl StorageBanner {
+    /// Create a hidden banner drawing along the bottom of `bounds`
+    pub fn new(bounds: Rectangle) -> Self {
+        Self {
+            bounds,
+            bottom_inset_px: 0,
+            visible: false,
+            dirty: false,
+        }
+    }
+
+    /// Whether the banner is shown
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Apply whether storage is persistent, returning whether visibility changed
+    pub fn set_persistent(&mut self, persistent: bool) -> bool {
+        let visible = !persistent;
+        if self.visible == visible {
+            return false;
+        }
+        self.visible = visible;
+        self.dirty = true;
+        true
+    }
+
+    /// Leave `inset_px` free below the strip, returning whether it moved
+    pub fn set_bottom_inset_px(&mut self, inset_px: u32) -> bool {
+        if self.bottom_inset_px == inset_px {
+            return false;
+        }
+        self.bottom_inset_px = inset_px;
+        self.dirty = true;
+        true
+    }
+
+    /// Bounds of the strip, `bottom_inset_px` above the bottom edge
+    fn strip_bounds(&self) -> Rectangle {
+        let inset = self.bottom_inset_px.min(self.bounds.size.height);
+        let height = BANNER_HEIGHT_PX.min(self.bounds.size.height - inset);
+        Rectangle::new(
+            Point::new(
+                self.bounds.top_left.x,
+                self.bounds.top_left.y + (self.bounds.size.height - inset - height) as i32,
+            ),
+            Size::new(self.bounds.size.width, height),
+        )
+    }
+}
+
+impl Drawable for StorageBanner {
+    fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        if !self.visible {
+            return Ok(());
+        }
+
+        let bounds = self.strip_bounds();
+        bounds
+            .into_styled(PrimitiveStyle::with_fill(COLOR_POOR_BACKGROUND))
+            .draw(display)?;
+
+        let character_style = MonoTextStyle::new(&FONT_6X10, COLOR_POOR_FOREGROUND);
+        let text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Middle)
+            .build();
+        Text::with_text_style(BANNER_MESSAGE, bounds.center(), character_style, text_style)
+            .draw(display)?;
+
+        Ok(())
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.strip_bounds()
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    fn dirty_region(&self) -> Option<DirtyRegion> {
+        if self.dirty {
+            Some(DirtyRegion::new(self.strip_bounds()))
+        } else {
+            None
+        }
+    }
+}
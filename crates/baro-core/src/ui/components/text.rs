@@ -4,11 +4,14 @@
 use crate::ui::core::{DirtyRegion, Drawable};
 use crate::ui::styling::Style;
 use embedded_graphics::Drawable as EgDrawable;
-use embedded_graphics::mono_font::{MonoFont, MonoTextStyle, ascii::FONT_6X10};
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyle, iso_8859_1::FONT_6X10};
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::Rectangle;
-use embedded_graphics::text::{Alignment, Text as EgText};
+use embedded_graphics::text::{Alignment, Baseline, Text as EgText};
+
+extern crate alloc;
+use alloc::string::String;
 
 /// Text size variants
 ///
@@ -24,15 +27,26 @@ pub enum TextSize {
 }
 
 impl TextSize {
+    /// The next size up (`Large` stays `Large`)
+    pub const fn larger(self) -> Self {
+        match self {
+            TextSize::Small => TextSize::Medium,
+            TextSize::Medium | TextSize::Large => TextSize::Large,
+        }
+    }
+
     pub fn font(&self) -> &'static MonoFont<'static> {
         match self {
-            TextSize::Small => &embedded_graphics::mono_font::ascii::FONT_5X8,
+            TextSize::Small => &embedded_graphics::mono_font::iso_8859_1::FONT_5X8,
             TextSize::Medium => &FONT_6X10,
-            TextSize::Large => &embedded_graphics::mono_font::ascii::FONT_10X20,
+            TextSize::Large => &embedded_graphics::mono_font::iso_8859_1::FONT_10X20,
         }
     }
 }
 
+/// Blank space between the end of a scrolling label and its repeat, in pixels
+pub const MARQUEE_GAP_PX: u32 = 24;
+
 /// Text component for displaying styled text
 ///
 /// A simple text display component with configurable size, alignment, and styling.
@@ -43,6 +57,7 @@ impl TextSize {
 /// - Left, Center, or Right alignment
 /// - Optional background and border styling
 /// - Automatic dirty tracking when text changes
+/// - Optional marquee scrolling for text wider than the bounds
 ///
 /// # Examples
 /// ```ignore
@@ -60,6 +75,10 @@ pub struct TextComponent {
     size: TextSize,
     alignment: Alignment,
     style: Style,
+    /// Marquee speed in pixels per `update()`, if enabled
+    marquee_speed_px: Option<u32>,
+    /// Current marquee offset into the text + gap cycle
+    scroll_offset_px: u32,
     dirty: bool,
 }
 
@@ -74,6 +93,8 @@ impl TextComponent {
             size,
             alignment: Alignment::Left,
             style: Style::default(),
+            marquee_speed_px: None,
+            scroll_offset_px: 0,
             dirty: true,
         }
     }
@@ -100,6 +121,8 @@ impl TextComponent {
             size,
             alignment: Alignment::Left,
             style: Style::default(),
+            marquee_speed_px: None,
+            scroll_offset_px: 0,
             dirty: true,
         }
     }
@@ -115,6 +138,67 @@ impl TextComponent {
         self
     }
 
+    /// Scroll the text horizontally when it is wider than the bounds.
+    ///
+    /// The text moves left by `speed_px` pixels on every `update()` and
+    /// repeats after a `MARQUEE_GAP_PX` gap. Text that fits is drawn normally.
+    pub fn with_marquee(mut self, speed_px: u32) -> Self {
+        self.marquee_speed_px = Some(speed_px.max(1));
+        self.dirty = true;
+        self
+    }
+
+    /// Advance the marquee, returning whether the component needs a redraw.
+    ///
+    /// Does nothing unless marquee scrolling is enabled and the text overflows.
+    pub fn update(&mut self) -> bool {
+        let Some(speed_px) = self.marquee_speed_px else {
+            return false;
+        };
+
+        if !self.is_scrolling() {
+            if self.scroll_offset_px != 0 {
+                self.scroll_offset_px = 0;
+                self.dirty = true;
+                return true;
+            }
+            return false;
+        }
+
+        self.scroll_offset_px = (self.scroll_offset_px + speed_px) % self.marquee_cycle_px();
+        self.dirty = true;
+        true
+    }
+
+    /// Whether the text is currently scrolling
+    pub fn is_scrolling(&self) -> bool {
+        self.marquee_speed_px.is_some() && self.text_width_px() > self.content_width_px()
+    }
+
+    /// Current marquee offset in pixels (0 when not scrolling)
+    pub fn scroll_offset_px(&self) -> u32 {
+        self.scroll_offset_px
+    }
+
+    /// Distance the marquee travels before it repeats: the text width plus the gap
+    pub fn marquee_cycle_px(&self) -> u32 {
+        self.text_width_px() + MARQUEE_GAP_PX
+    }
+
+    /// Width of the text in pixels
+    fn text_width_px(&self) -> u32 {
+        let font = self.size.font();
+        self.text.chars().count() as u32 * (font.character_size.width + font.character_spacing)
+    }
+
+    /// Width available for text inside the horizontal padding
+    fn content_width_px(&self) -> u32 {
+        self.bounds
+            .size
+            .width
+            .saturating_sub(self.style.padding.left + self.style.padding.right)
+    }
+
     /// Update the displayed text.
     ///
     /// Automatically marks the component as dirty if the text changed.
@@ -124,6 +208,7 @@ impl TextComponent {
 
         if self.text != new_text {
             self.text = new_text;
+            self.scroll_offset_px = 0;
             self.dirty = true;
         }
     }
@@ -164,6 +249,8 @@ impl TextComponent {
 
 impl Drawable for TextComponent {
     fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        self.style.draw_shadow(self.bounds, 0, display)?;
+
         // Draw background if specified
         if self.style.background_color.is_some() {
             self.bounds
@@ -177,7 +264,30 @@ impl Drawable for TextComponent {
 
         let position = self.text_position();
 
-        EgText::with_alignment(&self.text, position, text_style, self.alignment).draw(display)?;
+        if self.is_scrolling() {
+            // Draw the text and its repeat, clipped to the padded content area
+            let content = Rectangle::new(
+                Point::new(
+                    self.bounds.top_left.x + self.style.padding.left as i32,
+                    self.bounds.top_left.y,
+                ),
+                Size::new(self.content_width_px(), self.bounds.size.height),
+            );
+            let mut clipped = display.clipped(&content);
+            let start_x = content.top_left.x - self.scroll_offset_px as i32;
+            for x in [start_x, start_x + self.marquee_cycle_px() as i32] {
+                EgText::with_alignment(
+                    &self.text,
+                    Point::new(x, position.y),
+                    text_style,
+                    Alignment::Left,
+                )
+                .draw(&mut clipped)?;
+            }
+        } else {
+            EgText::with_alignment(&self.text, position, text_style, self.alignment)
+                .draw(display)?;
+        }
 
         Ok(())
     }
@@ -207,10 +317,24 @@ impl Drawable for TextComponent {
     }
 }
 
+/// Maximum number of wrapped lines kept by `MultiLineText`
+const MAX_LINES: usize = 16;
+
+/// Maximum characters per wrapped line kept by `MultiLineText`
+const MAX_LINE_CHARS: usize = 64;
+
 /// Multi-line text component with word wrapping
+///
+/// Text is split on explicit newlines, then each paragraph is word-wrapped
+/// to the number of characters that fit the bounds width (less horizontal
+/// padding) in the mono font. Lines break at spaces; a word longer than a
+/// whole line is hard-broken across lines. The text is re-wrapped whenever
+/// the bounds or style change. Lines that do not fit the bounds height are
+/// not drawn.
 pub struct MultiLineText {
     bounds: Rectangle,
-    lines: heapless::Vec<heapless::String<64>, 16>,
+    text: String,
+    lines: heapless::Vec<heapless::String<MAX_LINE_CHARS>, MAX_LINES>,
     size: TextSize,
     line_spacing: u32,
     style: Style,
@@ -221,6 +345,7 @@ impl MultiLineText {
     pub fn new(bounds: Rectangle, text: &str, size: TextSize) -> Self {
         let mut component = Self {
             bounds,
+            text: String::new(),
             lines: heapless::Vec::new(),
             size,
             line_spacing: 2,
@@ -234,6 +359,7 @@ impl MultiLineText {
 
     pub fn with_style(mut self, style: Style) -> Self {
         self.style = style;
+        self.wrap();
         self
     }
 
@@ -243,55 +369,100 @@ impl MultiLineText {
     }
 
     pub fn set_text(&mut self, text: &str) {
+        self.text.clear();
+        self.text.push_str(text);
+        self.wrap();
+    }
+
+    /// Set the bounds (for dynamic repositioning by layout containers)
+    pub fn set_bounds(&mut self, bounds: Rectangle) {
+        if self.bounds != bounds {
+            self.bounds = bounds;
+            self.wrap();
+        }
+    }
+
+    /// Wrapped lines, in order
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(|line| line.as_str())
+    }
+
+    /// Number of wrapped lines
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Number of wrapped lines that fit the bounds height and are drawn
+    pub fn visible_line_count(&self) -> usize {
+        let line_height = self.size.font().character_size.height + self.line_spacing;
+        let available = self
+            .bounds
+            .size
+            .height
+            .saturating_sub(self.style.padding.top + self.style.padding.bottom)
+            + self.line_spacing;
+        ((available / line_height.max(1)) as usize).min(self.lines.len())
+    }
+
+    /// Characters that fit on one line
+    fn max_chars(&self) -> usize {
+        let font = self.size.font();
+        let advance = font.character_size.width + font.character_spacing;
+        let width = self
+            .bounds
+            .size
+            .width
+            .saturating_sub(self.style.padding.left + self.style.padding.right);
+        ((width / advance.max(1)) as usize).clamp(1, MAX_LINE_CHARS)
+    }
+
+    /// Re-wrap the stored text into lines for the current bounds
+    fn wrap(&mut self) {
+        let max_chars = self.max_chars();
         self.lines.clear();
 
-        // Simple line breaking by newlines and character limit
-        let max_chars =
-            (self.bounds.size.width / (self.size.font().character_size.width + 1)) as usize;
-
-        for line in text.split('\n') {
-            if line.len() <= max_chars {
-                let mut line_string = heapless::String::new();
-                line_string.push_str(line).ok();
-                self.lines.push(line_string).ok();
-            } else {
-                // Simple word wrapping
-                let mut current_line = heapless::String::<64>::new();
-                for word in line.split_whitespace() {
-                    if current_line.len() + word.len() < max_chars {
-                        if !current_line.is_empty() {
-                            current_line.push(' ').ok();
-                        }
-                        current_line.push_str(word).ok();
-                    } else {
-                        if !current_line.is_empty() {
-                            self.lines.push(current_line.clone()).ok();
-                        }
-                        current_line.clear();
-                        current_line.push_str(word).ok();
-                    }
+        for paragraph in self.text.split('\n') {
+            let mut current = heapless::String::<MAX_LINE_CHARS>::new();
+            let mut current_chars = 0;
+
+            for word in paragraph.split(' ').filter(|word| !word.is_empty()) {
+                let word_chars = word.chars().count();
+
+                if current_chars > 0 && current_chars + 1 + word_chars <= max_chars {
+                    current.push(' ').ok();
+                    current.push_str(word).ok();
+                    current_chars += 1 + word_chars;
+                    continue;
+                }
+
+                if current_chars > 0 {
+                    self.lines.push(core::mem::take(&mut current)).ok();
+                    current_chars = 0;
                 }
-                if !current_line.is_empty() {
-                    self.lines.push(current_line).ok();
+
+                // Hard-break words longer than a line; the remainder starts the next line
+                for ch in word.chars() {
+                    if current_chars == max_chars {
+                        self.lines.push(core::mem::take(&mut current)).ok();
+                        current_chars = 0;
+                    }
+                    current.push(ch).ok();
+                    current_chars += 1;
                 }
             }
+
+            // Empty paragraphs keep their blank line
+            self.lines.push(current).ok();
         }
 
         self.dirty = true;
     }
-
-    /// Set the bounds (for dynamic repositioning by layout containers)
-    /// Set the bounds (for dynamic repositioning by layout containers)
-    pub fn set_bounds(&mut self, bounds: Rectangle) {
-        if self.bounds != bounds {
-            self.bounds = bounds;
-            self.dirty = true;
-        }
-    }
 }
 
 impl Drawable for MultiLineText {
     fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        self.style.draw_shadow(self.bounds, 0, display)?;
+
         // Draw background if specified
         if self.style.background_color.is_some() {
             self.bounds
@@ -307,14 +478,10 @@ impl Drawable for MultiLineText {
         let mut y = self.bounds.top_left.y + self.style.padding.top as i32;
         let x = self.bounds.top_left.x + self.style.padding.left as i32;
 
-        for line in &self.lines {
-            EgText::new(line, Point::new(x, y), text_style).draw(display)?;
+        for line in self.lines.iter().take(self.visible_line_count()) {
+            EgText::with_baseline(line, Point::new(x, y), text_style, Baseline::Top)
+                .draw(display)?;
             y += line_height as i32;
-
-            // Stop if we exceed bounds
-            if y > self.bounds.top_left.y + self.bounds.size.height as i32 {
-                break;
-            }
         }
 
         Ok(())
@@ -344,3 +511,143 @@ impl Drawable for MultiLineText {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    const SENTENCE: &str = "The quick brown fox jumps over the lazy dog";
+
+    /// Medium text (6 px per character) wrapped to `chars` characters per line
+    fn wrapped(text: &str, chars: u32) -> MultiLineText {
+        MultiLineText::new(
+            Rectangle::new(Point::zero(), Size::new(chars * 6, 200)),
+            text,
+            TextSize::Medium,
+        )
+    }
+
+    fn lines(text: &MultiLineText) -> Vec<&str> {
+        text.lines().collect()
+    }
+
+    #[test]
+    fn wraps_a_sentence_at_spaces() {
+        let narrow = wrapped(SENTENCE, 10);
+        assert_eq!(narrow.line_count(), 5);
+        assert_eq!(
+            lines(&narrow),
+            ["The quick", "brown fox", "jumps over", "the lazy", "dog"]
+        );
+
+        let medium = wrapped(SENTENCE, 20);
+        assert_eq!(medium.line_count(), 3);
+        assert_eq!(
+            lines(&medium),
+            ["The quick brown fox", "jumps over the lazy", "dog"]
+        );
+
+        let wide = wrapped(SENTENCE, 50);
+        assert_eq!(lines(&wide), [SENTENCE]);
+    }
+
+    #[test]
+    fn hard_breaks_words_longer_than_a_line() {
+        let text = wrapped("a supercalifragilistic b", 6);
+        assert_eq!(lines(&text), ["a", "superc", "alifra", "gilist", "ic b"]);
+    }
+
+    #[test]
+    fn keeps_explicit_newlines_and_blank_lines() {
+        let text = wrapped("first\n\nthird line", 20);
+        assert_eq!(lines(&text), ["first", "", "third line"]);
+    }
+
+    #[test]
+    fn rewraps_when_the_bounds_change() {
+        let mut text = wrapped(SENTENCE, 50);
+        assert_eq!(text.line_count(), 1);
+
+        text.set_bounds(Rectangle::new(Point::zero(), Size::new(60, 200)));
+        assert_eq!(text.line_count(), 5);
+
+        // Two 10 px lines plus one 2 px gap fit in 22 px; a third does not
+        text.set_bounds(Rectangle::new(Point::zero(), Size::new(60, 22)));
+        assert_eq!(text.line_count(), 5);
+        assert_eq!(text.visible_line_count(), 2);
+    }
+
+    /// Medium text (6 px per character) in a 60 px wide label
+    fn marquee(text: &str) -> TextComponent {
+        TextComponent::new(
+            Rectangle::new(Point::new(10, 0), Size::new(60, 12)),
+            text,
+            TextSize::Medium,
+        )
+        .with_marquee(5)
+    }
+
+    #[test]
+    fn marquee_offset_advances_and_wraps_at_the_cycle_width() {
+        // 20 characters = 120 px of text, plus the gap
+        let mut label = marquee("Outdoor temperature!");
+        assert!(label.is_scrolling());
+        assert_eq!(label.marquee_cycle_px(), 120 + MARQUEE_GAP_PX);
+
+        assert!(label.update());
+        assert_eq!(label.scroll_offset_px(), 5);
+        for _ in 1..28 {
+            label.update();
+        }
+        assert_eq!(label.scroll_offset_px(), 140);
+
+        // 145 wraps past the 144 px cycle
+        label.mark_clean();
+        assert!(label.update());
+        assert_eq!(label.scroll_offset_px(), 1);
+        assert!(label.is_dirty());
+    }
+
+    #[test]
+    fn marquee_stays_still_when_the_text_fits() {
+        let mut label = marquee("22.5 C");
+        label.mark_clean();
+        assert!(!label.is_scrolling());
+        assert!(!label.update());
+        assert_eq!(label.scroll_offset_px(), 0);
+        assert!(!label.is_dirty());
+
+        let mut plain = TextComponent::new(
+            Rectangle::new(Point::zero(), Size::new(10, 12)),
+            "far too long to fit",
+            TextSize::Medium,
+        );
+        assert!(!plain.is_scrolling());
+        assert!(!plain.update());
+    }
+
+    #[test]
+    fn new_text_restarts_the_marquee() {
+        let mut label = marquee("Outdoor temperature!");
+        label.update();
+        label.set_text("Indoor temperature!!");
+        assert_eq!(label.scroll_offset_px(), 0);
+    }
+
+    #[test]
+    fn scrolling_text_is_clipped_to_the_label() {
+        let mut fb = crate::framebuffer::FrameBuffer::<100, 12>::new();
+        let mut label = marquee("Outdoor temperature!");
+        label.update();
+        label.draw(&mut fb).unwrap();
+
+        let outside = (0..12).any(|y| {
+            (0..100)
+                .filter(|x| !(10..70).contains(x))
+                .any(|x| fb.pixel(x, y) != Some(Rgb565::BLACK))
+        });
+        assert!(!outside);
+        assert!((0..12).any(|y| (10..70).any(|x| fb.pixel(x, y) == Some(Rgb565::WHITE))));
+    }
+}
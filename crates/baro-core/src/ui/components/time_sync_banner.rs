@@ -0,0 +1,189 @@
+// src/ui/components/time_sync_banner.rs
+//! Warning strip shown while the clock is not NTP-synchronized
+
+use crate::app_state::TimeStatus;
+use crate::ui::core::{DirtyRegion, Drawable};
+use crate::ui::styling::{COLOR_POOR_BACKGROUND, COLOR_POOR_FOREGROUND};
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::mono_font::iso_8859_1::FONT_6X10;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+
+/// Height of the banner strip in pixels
+const BANNER_HEIGHT_PX: u32 = 14;
+
+/// Message shown while time is not synced
+const BANNER_MESSAGE: &str = "Time not synced - timestamps unreliable";
+
+/// Strip along the bottom of the screen warning that timestamps are unreliable
+///
+/// Visible until the time status becomes `TimeStatus::Synced`; an estimated
+/// time (restored from the SD card) still shows the warning. Uses the fixed
+/// "poor" status colors so it reads the same in both themes.
+///
+/// # Examples
+/// ```ignore
+/// let mut banner = TimeSyncBanner::new(screen_bounds);
+/// banner.set_time_status(TimeStatus::Estimated); // now visible
+/// banner.set_time_status(TimeStatus::Synced); // hidden again
+/// ```
+pub struct TimeSyncBanner {
+    bounds: Rectangle,
+    visible: bool,
+    dirty: bool,
+}
+
+impl TimeSyncBanner {
+    /// Create a hidden banner drawing along the bottom of `bounds`
+    ///
+    /// The banner stays hidden until the first `set_time_status` so nothing
+    /// is shown before the time sync has been attempted.
+    pub fn new(bounds: Rectangle) -> Self {
+        Self {
+            bounds,
+            visible: false,
+            dirty: false,
+        }
+    }
+
+    /// Whether the banner is shown
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Apply the latest time status, returning whether visibility changed
+    pub fn set_time_status(&mut self, status: TimeStatus) -> bool {
+        let visible = status != TimeStatus::Synced;
+        if self.visible == visible {
+            return false;
+        }
+        self.visible = visible;
+        self.dirty = true;
+        true
+    }
+
+    /// Bounds of the strip along the bottom edge
+    fn strip_bounds(&self) -> Rectangle {
+        let height = BANNER_HEIGHT_PX.min(self.bounds.size.height);
+        Rectangle::new(
+            Point::new(
+                self.bounds.top_left.x,
+                self.bounds.top_left.y + (self.bounds.size.height - height) as i32,
+            ),
+            Size::new(self.bounds.size.width, height),
+        )
+    }
+}
+
+impl Drawable for TimeSyncBanner {
+    fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        if !self.visible {
+            return Ok(());
+        }
+
+        let bounds = self.strip_bounds();
+        bounds
+            .into_styled(PrimitiveStyle::with_fill(COLOR_POOR_BACKGROUND))
+            .draw(display)?;
+
+        let character_style = MonoTextStyle::new(&FONT_6X10, COLOR_POOR_FOREGROUND);
+        let text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Middle)
+            .build();
+        Text::with_text_style(BANNER_MESSAGE, bounds.center(), character_style, text_style)
+            .draw(display)?;
+
+        Ok(())
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.strip_bounds()
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    fn dirty_region(&self) -> Option<DirtyRegion> {
+        if self.dirty {
+            Some(DirtyRegion::new(self.strip_bounds()))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framebuffer::FrameBuffer;
+    use crate::pages::test_harness::{assert_filled, count_color, screen_bounds};
+
+    fn render(banner: &TimeSyncBanner) -> FrameBuffer {
+        let mut fb = FrameBuffer::new();
+        banner.draw(&mut fb).unwrap();
+        fb
+    }
+
+    #[test]
+    fn banner_shows_until_time_is_synced() {
+        let mut banner = TimeSyncBanner::new(screen_bounds());
+        assert!(!banner.is_visible());
+        assert!(!banner.is_dirty());
+
+        assert!(banner.set_time_status(TimeStatus::Estimated));
+        assert!(banner.is_visible());
+        assert!(banner.is_dirty());
+
+        // Still unsynced: nothing changes
+        banner.mark_clean();
+        assert!(!banner.set_time_status(TimeStatus::Unknown));
+        assert!(!banner.is_dirty());
+
+        assert!(banner.set_time_status(TimeStatus::Synced));
+        assert!(!banner.is_visible());
+        assert!(banner.is_dirty());
+    }
+
+    #[test]
+    fn visible_banner_fills_the_bottom_strip_only() {
+        let mut banner = TimeSyncBanner::new(screen_bounds());
+        let hidden = render(&banner);
+        assert_eq!(
+            count_color(&hidden, screen_bounds(), COLOR_POOR_BACKGROUND),
+            0
+        );
+
+        banner.set_time_status(TimeStatus::Unknown);
+        let fb = render(&banner);
+        let strip = banner.bounds();
+        assert_eq!(strip.size.height, BANNER_HEIGHT_PX);
+        assert_eq!(
+            strip.bottom_right().unwrap().y,
+            screen_bounds().bottom_right().unwrap().y
+        );
+
+        let above = Rectangle::new(
+            Point::zero(),
+            Size::new(
+                screen_bounds().size.width,
+                screen_bounds().size.height - BANNER_HEIGHT_PX,
+            ),
+        );
+        assert_filled(&fb, above, Rgb565::BLACK);
+        assert!(count_color(&fb, strip, COLOR_POOR_FOREGROUND) > 0);
+        assert!(count_color(&fb, strip, COLOR_POOR_BACKGROUND) > 0);
+    }
+}
@@ -0,0 +1,297 @@
+// src/ui/components/toast.rs
+//! Transient notification overlay drawn on top of the current page
+
+use crate::ui::core::{DirtyRegion, Drawable, PageEvent, SystemEvent};
+use crate::ui::styling::ColorPalette;
+use embedded_graphics::Drawable as EgDrawable;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::mono_font::iso_8859_1::FONT_6X10;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyleBuilder, Rectangle, RoundedRectangle};
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+use heapless::Deque;
+
+/// How long each toast stays on screen
+pub const TOAST_DURATION_MS: u64 = 3_000;
+
+/// Maximum number of toasts waiting or on screen
+pub const MAX_QUEUED_TOASTS: usize = 4;
+
+/// Height of the toast pill in pixels
+const TOAST_HEIGHT_PX: u32 = 28;
+
+/// Gap between the toast and the edges of the bounds in pixels
+const TOAST_MARGIN_PX: u32 = 8;
+
+/// Corner radius of the toast pill in pixels
+const TOAST_CORNER_RADIUS_PX: u32 = 8;
+
+/// Queue of transient messages shown one at a time at the bottom of the screen
+///
+/// Messages are shown in the order they arrive. The oldest message is on
+/// screen until `TOAST_DURATION_MS` has passed since it appeared, then the
+/// next one takes its place. The clock starts on the first `update()` after a
+/// message reaches the front of the queue, so a message is never timed out
+/// before it has been drawn. When the queue is full, the oldest message is
+/// dropped to make room.
+///
+/// # Examples
+/// ```ignore
+/// let mut toasts = ToastQueue::new(screen_bounds);
+/// toasts.push("WiFi reconnected");
+/// toasts.update(now_ms); // starts the clock
+/// toasts.update(now_ms + TOAST_DURATION_MS); // dismisses it
+/// ```
+pub struct ToastQueue {
+    bounds: Rectangle,
+    queue: Deque<&'static str, MAX_QUEUED_TOASTS>,
+    /// When the front message appeared, once it has been shown
+    shown_at_ms: Option<u64>,
+    palette: ColorPalette,
+    dirty: bool,
+}
+
+impl ToastQueue {
+    /// Create an empty queue drawing inside `bounds` (normally the whole screen)
+    pub fn new(bounds: Rectangle) -> Self {
+        Self {
+            bounds,
+            queue: Deque::new(),
+            shown_at_ms: None,
+            palette: ColorPalette::default(),
+            dirty: false,
+        }
+    }
+
+    /// Set the toast color palette
+    pub fn set_palette(&mut self, palette: ColorPalette) {
+        self.palette = palette;
+        self.dirty = true;
+    }
+
+    /// Message currently on screen, if any
+    pub fn current(&self) -> Option<&'static str> {
+        self.queue.front().copied()
+    }
+
+    /// Whether a message is on screen
+    pub fn is_active(&self) -> bool {
+        !self.queue.is_empty()
+    }
+
+    /// Number of messages on screen or waiting
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Whether no messages are on screen or waiting
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Queue a message behind any already waiting
+    ///
+    /// A message identical to the last one queued is ignored so a flapping
+    /// condition does not fill the queue with repeats.
+    pub fn push(&mut self, message: &'static str) {
+        if self.queue.back() == Some(&message) {
+            return;
+        }
+        if self.queue.is_full() {
+            self.queue.pop_front();
+            self.shown_at_ms = None;
+        }
+        let _ = self.queue.push_back(message);
+        self.dirty = true;
+    }
+
+    /// Apply a page event, returning whether the queue changed
+    ///
+    /// Only `SystemEvent::Notify` is used.
+    pub fn on_event(&mut self, event: &PageEvent) -> bool {
+        match event {
+            PageEvent::SystemEvent(SystemEvent::Notify(message)) => {
+                self.push(message);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Advance the expiry clock, returning whether the visible message changed
+    ///
+    /// Starts timing a newly visible message at `now_ms` and dismisses the
+    /// visible message once it has been shown for `TOAST_DURATION_MS`.
+    pub fn update(&mut self, now_ms: u64) -> bool {
+        if self.queue.is_empty() {
+            self.shown_at_ms = None;
+            return false;
+        }
+
+        match self.shown_at_ms {
+            None => {
+                self.shown_at_ms = Some(now_ms);
+                false
+            }
+            Some(shown_at_ms) if now_ms.saturating_sub(shown_at_ms) >= TOAST_DURATION_MS => {
+                self.queue.pop_front();
+                self.shown_at_ms = if self.queue.is_empty() {
+                    None
+                } else {
+                    Some(now_ms)
+                };
+                self.dirty = true;
+                true
+            }
+            Some(_) => false,
+        }
+    }
+
+    /// Time at which the visible message expires, if its clock has started
+    pub fn deadline_ms(&self) -> Option<u64> {
+        self.shown_at_ms
+            .map(|shown_at_ms| shown_at_ms + TOAST_DURATION_MS)
+    }
+
+    /// Bounds of the toast pill, centered along the bottom of the queue's bounds
+    fn toast_bounds(&self) -> Rectangle {
+        let width = self.bounds.size.width.saturating_sub(TOAST_MARGIN_PX * 2);
+        Rectangle::new(
+            Point::new(
+                self.bounds.top_left.x + TOAST_MARGIN_PX as i32,
+                self.bounds.top_left.y + self.bounds.size.height as i32
+                    - (TOAST_HEIGHT_PX + TOAST_MARGIN_PX) as i32,
+            ),
+            Size::new(width, TOAST_HEIGHT_PX),
+        )
+    }
+}
+
+impl Drawable for ToastQueue {
+    fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        let Some(message) = self.current() else {
+            return Ok(());
+        };
+
+        let bounds = self.toast_bounds();
+        RoundedRectangle::with_equal_corners(
+            bounds,
+            Size::new(TOAST_CORNER_RADIUS_PX, TOAST_CORNER_RADIUS_PX),
+        )
+        .into_styled(
+            PrimitiveStyleBuilder::new()
+                .fill_color(self.palette.surface)
+                .stroke_color(self.palette.border)
+                .stroke_width(1)
+                .build(),
+        )
+        .draw(display)?;
+
+        let character_style = MonoTextStyle::new(&FONT_6X10, self.palette.text_primary);
+        let text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Middle)
+            .build();
+        Text::with_text_style(message, bounds.center(), character_style, text_style)
+            .draw(display)?;
+
+        Ok(())
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.toast_bounds()
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    fn dirty_region(&self) -> Option<DirtyRegion> {
+        if self.dirty {
+            Some(DirtyRegion::new(self.toast_bounds()))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue() -> ToastQueue {
+        ToastQueue::new(Rectangle::new(Point::zero(), Size::new(320, 240)))
+    }
+
+    #[test]
+    fn clock_starts_on_first_update_and_expires_after_the_duration() {
+        let mut toasts = queue();
+        toasts.push("WiFi reconnected");
+        assert_eq!(toasts.deadline_ms(), None);
+
+        // Pushed long before it is drawn: the clock only starts now
+        assert!(!toasts.update(10_000));
+        assert_eq!(toasts.deadline_ms(), Some(10_000 + TOAST_DURATION_MS));
+
+        assert!(!toasts.update(10_000 + TOAST_DURATION_MS - 1));
+        assert_eq!(toasts.current(), Some("WiFi reconnected"));
+
+        assert!(toasts.update(10_000 + TOAST_DURATION_MS));
+        assert!(!toasts.is_active());
+        assert_eq!(toasts.deadline_ms(), None);
+        assert!(!toasts.update(20_000));
+    }
+
+    #[test]
+    fn messages_are_shown_in_arrival_order() {
+        let mut toasts = queue();
+        toasts.push("first");
+        toasts.push("second");
+        toasts.push("third");
+        toasts.update(0);
+
+        assert_eq!(toasts.current(), Some("first"));
+        assert!(toasts.update(TOAST_DURATION_MS));
+        assert_eq!(toasts.current(), Some("second"));
+        // The next message gets its full duration from when it appeared
+        assert!(!toasts.update(2 * TOAST_DURATION_MS - 1));
+        assert!(toasts.update(2 * TOAST_DURATION_MS));
+        assert_eq!(toasts.current(), Some("third"));
+    }
+
+    #[test]
+    fn full_queue_drops_the_oldest_and_repeats_are_ignored() {
+        let mut toasts = queue();
+        toasts.push("a");
+        toasts.push("a");
+        assert_eq!(toasts.len(), 1);
+        toasts.update(0);
+        assert!(toasts.deadline_ms().is_some());
+
+        for message in ["b", "c", "d", "e"] {
+            toasts.push(message);
+        }
+        assert_eq!(toasts.len(), MAX_QUEUED_TOASTS);
+        assert_eq!(toasts.current(), Some("b"));
+        // The dropped message's clock does not carry over to the new front
+        assert_eq!(toasts.deadline_ms(), None);
+    }
+
+    #[test]
+    fn notify_events_queue_a_toast() {
+        let mut toasts = queue();
+        assert!(toasts.on_event(&PageEvent::SystemEvent(SystemEvent::Notify("SD full"))));
+        assert!(!toasts.on_event(&PageEvent::SystemEvent(SystemEvent::LowMemory)));
+        assert_eq!(toasts.current(), Some("SD full"));
+        assert!(toasts.is_dirty());
+    }
+}
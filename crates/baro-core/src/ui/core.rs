@@ -4,7 +4,11 @@
 extern crate alloc;
 use alloc::boxed::Box;
 
-use crate::config::{HomePageMode, TemperatureUnit};
+use crate::app_state::AppRunState;
+use crate::config::{HomePageMode, TemperatureUnit, ThemeMode};
+use crate::log_ring::LogLevel;
+use crate::sensors::{SensorHealthReport, SensorType};
+use crate::storage::TimeWindow;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::Rectangle;
 
@@ -32,6 +36,8 @@ pub enum TouchEvent {
     Press(TouchPoint),
     /// Touch drag to a new point
     Drag(TouchPoint),
+    /// Touch lifted, at the last reported point
+    Release(TouchPoint),
 }
 
 /// Result from handling a touch event
@@ -62,6 +68,29 @@ pub enum Action {
     UpdateHomePageMode(HomePageMode),
     /// Update the temperature display unit (Celsius vs Fahrenheit)
     UpdateTemperatureUnit(TemperatureUnit),
+    /// Update the sensor sampling interval (seconds, already clamped)
+    SetSampleInterval(u32),
+    /// Show a backlight brightness while it is being adjusted, without
+    /// persisting it (percent, 0-100)
+    PreviewBrightness(u8),
+    /// Update and persist the display backlight brightness (percent, 0-100)
+    SetBrightness(u8),
+    /// Update the touch controller detection threshold (already clamped)
+    SetTouchThreshold(u8),
+    /// Switch the display color scheme (all pages redraw in the new theme)
+    SetThemeMode(ThemeMode),
+    /// Update a sensor's calibration offset (in the sensor's stored milli-unit)
+    SetCalibrationOffset { sensor: SensorType, offset: i32 },
+    /// Enable or disable the CO₂ sensor's automatic self-calibration
+    SetCo2AutoCalibration(bool),
+    /// Recalibrate the CO₂ sensor to a known level (ppm) it is sitting in
+    ForceCo2Recalibration(u16),
+    /// Ask for a sensor's stored history over a time window (answered with
+    /// `DisplayRequest::LoadHistory`)
+    RequestHistory {
+        sensor: SensorType,
+        window: TimeWindow,
+    },
 }
 
 /// Page identifier for navigation
@@ -81,8 +110,13 @@ pub enum PageId {
     TrendHumidity,
     TrendCo2,
     TrendLux,
+    TrendPressure,
     /// Combined WiFi status page (connecting + error states)
     WifiStatus,
+    /// Boot progress shown until the sensors start
+    Splash,
+    /// Full-screen application error with a suggested recovery
+    Error,
 }
 
 /// Dirty region tracking for efficient rendering
@@ -213,6 +247,8 @@ pub enum PageEvent {
     RollupEvent(Box<crate::storage::accumulator::RollupEvent>),
     /// System event
     SystemEvent(SystemEvent),
+    /// Per-sensor health changed
+    SensorHealth(SensorHealthReport),
 }
 
 /// Sensor data for event system
@@ -225,6 +261,19 @@ pub struct SensorData {
     pub timestamp: u64,
 }
 
+impl SensorData {
+    /// Reading for `sensor`, if present (pressure is not carried in live updates)
+    pub const fn value(&self, sensor: SensorType) -> Option<f32> {
+        match sensor {
+            SensorType::Temperature => self.temperature,
+            SensorType::Humidity => self.humidity,
+            SensorType::Co2 => self.co2,
+            SensorType::Lux => self.lux,
+            SensorType::Pressure => None,
+        }
+    }
+}
+
 /// Storage events for live monitoring
 #[derive(Debug, Clone)]
 pub enum StorageEvent {
@@ -246,4 +295,18 @@ pub enum SystemEvent {
     LowMemory,
     NetworkConnected,
     NetworkDisconnected,
+    /// Latest WiFi signal strength in dBm
+    WifiRssi(i8),
+    /// Transient message for the user, shown as a toast
+    Notify(&'static str),
+    /// Current Unix time in seconds, sent periodically once the clock is known
+    Time(u32),
+    /// Boot sequence advanced to a new stage
+    RunState(AppRunState),
+    /// Free-form status line from a system task, appended to the monitor log
+    LogLine(LogLevel, heapless::String<64>),
+    /// The sensor task stopped storing samples, so readings on screen are stale
+    SensorStalled,
+    /// Samples are being stored again after `SensorStalled`
+    SensorResumed,
 }
@@ -496,6 +496,9 @@ impl<const N: usize> Container<N> {
 
 impl<const N: usize> Drawable for Container<N> {
     fn draw<D: DrawTarget<Color = Rgb565>>(&self, display: &mut D) -> Result<(), D::Error> {
+        self.style
+            .draw_shadow(self.bounds, self.corner_radius, display)?;
+
         // Background/border.
         if self.style.background_color.is_some() || self.style.border_color.is_some() {
             let corner_size = Size::new(self.corner_radius, self.corner_radius);
@@ -564,7 +567,7 @@ impl<const N: usize> Touchable for Container<N> {
     fn handle_touch(&mut self, event: TouchEvent) -> TouchResult {
         // Forward to children (top-most last wins).
         let point = match event {
-            TouchEvent::Press(p) | TouchEvent::Drag(p) => p,
+            TouchEvent::Press(p) | TouchEvent::Drag(p) | TouchEvent::Release(p) => p,
         };
 
         for child in self.children.iter_mut().rev() {
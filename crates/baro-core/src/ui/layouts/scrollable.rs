@@ -288,6 +288,8 @@ impl Drawable for ScrollableContainer {
         &self,
         display: &mut D,
     ) -> Result<(), D::Error> {
+        self.style.draw_shadow(self.viewport, 0, display)?;
+
         // Draw container background
         if self.style.background_color.is_some() || self.style.border_color.is_some() {
             self.viewport
@@ -356,6 +358,13 @@ impl Touchable for ScrollableContainer {
                     TouchResult::NotHandled
                 }
             }
+            TouchEvent::Release(_) => {
+                if self.last_touch.take().is_some() {
+                    TouchResult::Handled
+                } else {
+                    TouchResult::NotHandled
+                }
+            }
         }
     }
 }
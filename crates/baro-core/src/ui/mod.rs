@@ -52,11 +52,15 @@ pub mod components;
 pub mod core;
 pub mod elements;
 pub mod layouts;
+pub mod rotation;
 pub mod styling;
 
 // Re-export commonly used items.
-pub use crate::config::{HomePageMode, TemperatureUnit};
-pub use components::{Button, MultiLineText, TextComponent, TextSize};
+pub use crate::config::{HomePageMode, TemperatureUnit, ThemeMode};
+pub use components::{
+    Button, Clock, Gauge, ICON_SIZE_PX, Icon, IconGlyph, MultiLineText, SignalBars, Slider,
+    Sparkline, Stepper, StorageBanner, TextComponent, TextSize, TimeSyncBanner, ToastQueue,
+};
 pub use core::{
     Action, DirtyRegion, Drawable, Interactive, PageEvent, PageId, SensorData, StorageEvent,
     SystemEvent, TouchEvent, TouchPoint, TouchResult, Touchable,
@@ -66,8 +70,9 @@ pub use layouts::{
     Alignment, Container, Direction, MainAxisAlignment, ScrollDirection, ScrollableContainer,
     SizeConstraint,
 };
+pub use rotation::{DisplayRotation, RotatedTarget};
 pub use styling::{
-    BorderRadius, ButtonVariant, ColorPalette, DISPLAY_HEIGHT_PX, DISPLAY_WIDTH_PX,
-    FONT_6X10_CHAR_HEIGHT_PX, FONT_6X10_CHAR_WIDTH_PX, FONT_6X10_LINE_HEIGHT_PX,
-    FONT_10X20_CHAR_HEIGHT_PX, Padding, Spacing, Style, Theme, WHITE,
+    BorderRadius, ButtonVariant, ColorPalette, DISPLAY_HEIGHT_PX, DISPLAY_ROTATION,
+    DISPLAY_WIDTH_PX, FONT_6X10_CHAR_HEIGHT_PX, FONT_6X10_CHAR_WIDTH_PX, FONT_6X10_LINE_HEIGHT_PX,
+    FONT_10X20_CHAR_HEIGHT_PX, LOGICAL_DISPLAY_SIZE, Padding, Shadow, Spacing, Style, Theme, WHITE,
 };
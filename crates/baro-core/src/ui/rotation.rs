@@ -0,0 +1,277 @@
+// src/ui/rotation.rs
+//! Display rotation for enclosures that mount the panel turned
+//!
+//! Pages always lay out in *logical* coordinates, the screen as the user sees
+//! it. [`RotatedTarget`] maps their drawing onto the *physical* panel, and
+//! [`DisplayRotation::touch_to_logical`] maps touch-controller readings back,
+//! so nothing above the display manager needs to know how the panel is
+//! mounted. The rotation in use is `DISPLAY_ROTATION` in the styling layout
+//! constants.
+
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+
+use crate::ui::core::{TouchEvent, TouchPoint};
+
+// ---------------------------------------------------------------------------
+// DisplayRotation
+// ---------------------------------------------------------------------------
+
+/// Clockwise rotation of the logical screen relative to the physical panel
+///
+/// With `R90` the logical top-left corner lands on the physical top-right
+/// pixel, and a 320×240 panel is laid out as a 240×320 screen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DisplayRotation {
+    #[default]
+    R0,
+    R90,
+    R180,
+    R270,
+}
+
+impl DisplayRotation {
+    /// Whether logical width and height are swapped relative to the panel
+    pub const fn swaps_axes(self) -> bool {
+        matches!(self, Self::R90 | Self::R270)
+    }
+
+    /// Logical screen size for a panel of `physical` size
+    pub const fn logical_size(self, physical: Size) -> Size {
+        if self.swaps_axes() {
+            Size::new(physical.height, physical.width)
+        } else {
+            physical
+        }
+    }
+
+    /// Physical pixel showing logical `point` on a panel of `physical` size
+    pub const fn to_physical(self, point: Point, physical: Size) -> Point {
+        let max_x = physical.width as i32 - 1;
+        let max_y = physical.height as i32 - 1;
+        match self {
+            Self::R0 => point,
+            Self::R90 => Point::new(max_x - point.y, point.x),
+            Self::R180 => Point::new(max_x - point.x, max_y - point.y),
+            Self::R270 => Point::new(point.y, max_y - point.x),
+        }
+    }
+
+    /// Logical point shown at physical pixel `point` on a panel of `physical` size
+    pub const fn to_logical(self, point: Point, physical: Size) -> Point {
+        let max_x = physical.width as i32 - 1;
+        let max_y = physical.height as i32 - 1;
+        match self {
+            Self::R0 => point,
+            Self::R90 => Point::new(point.y, max_x - point.x),
+            Self::R180 => Point::new(max_x - point.x, max_y - point.y),
+            Self::R270 => Point::new(max_y - point.y, point.x),
+        }
+    }
+
+    /// Physical area covered by logical `area`
+    pub fn rect_to_physical(self, area: &Rectangle, physical: Size) -> Rectangle {
+        match area.bottom_right() {
+            Some(bottom_right) => Rectangle::with_corners(
+                self.to_physical(area.top_left, physical),
+                self.to_physical(bottom_right, physical),
+            ),
+            None => Rectangle::zero(),
+        }
+    }
+
+    /// Map a touch reported in panel coordinates into logical coordinates
+    pub fn touch_to_logical(self, event: TouchEvent, physical: Size) -> TouchEvent {
+        let map = |point: TouchPoint| {
+            let logical = self.to_logical(point.to_point(), physical);
+            TouchPoint::new(logical.x.max(0) as u16, logical.y.max(0) as u16)
+        };
+        match event {
+            TouchEvent::Press(point) => TouchEvent::Press(map(point)),
+            TouchEvent::Drag(point) => TouchEvent::Drag(map(point)),
+            TouchEvent::Release(point) => TouchEvent::Release(map(point)),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// RotatedTarget
+// ---------------------------------------------------------------------------
+
+/// `DrawTarget` wrapper presenting a physical target in logical coordinates
+///
+/// Reports the logical size and maps every pixel and filled rectangle onto
+/// the wrapped target. `R0` passes straight through.
+///
+/// # Examples
+/// ```ignore
+/// let mut target = RotatedTarget::new(&mut framebuffer, DisplayRotation::R90);
+/// page.draw_page(&mut target)?;
+/// ```
+pub struct RotatedTarget<'a, T> {
+    target: &'a mut T,
+    rotation: DisplayRotation,
+}
+
+impl<'a, T> RotatedTarget<'a, T>
+where
+    T: DrawTarget<Color = Rgb565>,
+{
+    /// Wrap `target`, drawing with `rotation` applied
+    pub fn new(target: &'a mut T, rotation: DisplayRotation) -> Self {
+        Self { target, rotation }
+    }
+
+    /// Size of the wrapped (physical) target
+    fn physical_size(&self) -> Size {
+        self.target.bounding_box().size
+    }
+}
+
+impl<T> OriginDimensions for RotatedTarget<'_, T>
+where
+    T: DrawTarget<Color = Rgb565>,
+{
+    fn size(&self) -> Size {
+        self.rotation.logical_size(self.physical_size())
+    }
+}
+
+impl<T> DrawTarget for RotatedTarget<'_, T>
+where
+    T: DrawTarget<Color = Rgb565>,
+{
+    type Color = Rgb565;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let physical = self.physical_size();
+        let rotation = self.rotation;
+        self.target.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(point, color)| Pixel(rotation.to_physical(point, physical), color)),
+        )
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        // Rotation reorders the pixels, so only R0 keeps the fast path
+        if self.rotation == DisplayRotation::R0 {
+            return self.target.fill_contiguous(area, colors);
+        }
+        self.draw_iter(
+            area.points()
+                .zip(colors)
+                .map(|(point, color)| Pixel(point, color)),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        // A rotated rectangle is still axis-aligned, so it stays one fill
+        let area = self.rotation.rect_to_physical(area, self.physical_size());
+        self.target.fill_solid(&area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.target.clear(color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framebuffer::FrameBuffer;
+    use embedded_graphics::pixelcolor::RgbColor;
+    use embedded_graphics::primitives::PrimitiveStyle;
+
+    const PANEL: Size = Size::new(320, 240);
+
+    const ALL: [DisplayRotation; 4] = [
+        DisplayRotation::R0,
+        DisplayRotation::R90,
+        DisplayRotation::R180,
+        DisplayRotation::R270,
+    ];
+
+    #[test]
+    fn r90_logical_top_left_is_the_physical_top_right() {
+        let rotation = DisplayRotation::R90;
+        assert_eq!(rotation.logical_size(PANEL), Size::new(240, 320));
+        assert_eq!(
+            rotation.to_physical(Point::zero(), PANEL),
+            Point::new(319, 0)
+        );
+        // Logical x runs down the panel, logical y runs right to left
+        assert_eq!(
+            rotation.to_physical(Point::new(10, 0), PANEL),
+            Point::new(319, 10)
+        );
+        assert_eq!(
+            rotation.to_physical(Point::new(0, 10), PANEL),
+            Point::new(309, 0)
+        );
+        assert_eq!(
+            rotation.to_physical(Point::new(239, 319), PANEL),
+            Point::new(0, 239)
+        );
+    }
+
+    #[test]
+    fn r90_target_draws_logical_top_left_on_the_physical_top_right() {
+        let mut fb: FrameBuffer = FrameBuffer::default();
+        let mut target = RotatedTarget::new(&mut fb, DisplayRotation::R90);
+        assert_eq!(target.size(), Size::new(240, 320));
+
+        Pixel(Point::zero(), Rgb565::RED).draw(&mut target).unwrap();
+        // A 4x2 logical block becomes a 2x4 physical one
+        Rectangle::new(Point::new(20, 0), Size::new(4, 2))
+            .into_styled(PrimitiveStyle::with_fill(Rgb565::GREEN))
+            .draw(&mut target)
+            .unwrap();
+
+        assert_eq!(fb.pixel(319, 0), Some(Rgb565::RED));
+        assert_eq!(fb.pixel(0, 0), Some(Rgb565::BLACK));
+        for (x, y) in [(318, 20), (319, 20), (318, 23), (319, 23)] {
+            assert_eq!(fb.pixel(x, y), Some(Rgb565::GREEN), "({x}, {y})");
+        }
+        assert_eq!(fb.pixel(317, 20), Some(Rgb565::BLACK));
+        assert_eq!(fb.pixel(319, 24), Some(Rgb565::BLACK));
+    }
+
+    #[test]
+    fn every_rotation_round_trips_points_and_corners() {
+        for rotation in ALL {
+            let logical = rotation.logical_size(PANEL);
+            let far = Point::new(logical.width as i32 - 1, logical.height as i32 - 1);
+            for point in [Point::zero(), Point::new(17, 5), far] {
+                let physical = rotation.to_physical(point, PANEL);
+                assert!(
+                    Rectangle::new(Point::zero(), PANEL).contains(physical),
+                    "{rotation:?} {point:?}"
+                );
+                assert_eq!(rotation.to_logical(physical, PANEL), point, "{rotation:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn touches_map_back_to_logical_coordinates() {
+        // The physical top-right corner is the logical top-left under R90
+        let event = TouchEvent::Press(TouchPoint::new(319, 0));
+        assert!(matches!(
+            DisplayRotation::R90.touch_to_logical(event, PANEL),
+            TouchEvent::Press(TouchPoint { x: 0, y: 0 })
+        ));
+        assert!(matches!(
+            DisplayRotation::R0.touch_to_logical(event, PANEL),
+            TouchEvent::Press(TouchPoint { x: 319, y: 0 })
+        ));
+    }
+}
@@ -11,6 +11,7 @@
 //! To convert from 8-bit RGB: R>>3, G>>2, B>>3
 
 use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
 
 // ============================================================================
 // Base Colors
@@ -25,6 +26,56 @@ pub const COLOR_FOREGROUND: Rgb565 = Rgb565::new(26 >> 3, 32 >> 2, 33 >> 3);
 /// Border/stroke color - medium gray
 pub const COLOR_STROKE: Rgb565 = Rgb565::new(43 >> 3, 55 >> 2, 57 >> 3);
 
+/// Raised control color (buttons, slider tracks) on a dark surface
+pub const COLOR_CONTROL: Rgb565 = Rgb565::new(64 >> 3, 64 >> 2, 64 >> 3);
+
+// ============================================================================
+// Light Mode Colors
+// ============================================================================
+
+/// Light mode background - pale cool gray
+pub const COLOR_LIGHT_BACKGROUND: Rgb565 = Rgb565::new(236 >> 3, 240 >> 2, 241 >> 3);
+
+/// Light mode surface - white cards on the pale background
+pub const COLOR_LIGHT_SURFACE: Rgb565 = WHITE;
+
+/// Light mode border/stroke - soft gray
+pub const COLOR_LIGHT_STROKE: Rgb565 = Rgb565::new(196 >> 3, 203 >> 2, 206 >> 3);
+
+/// Light mode raised control color
+pub const COLOR_LIGHT_CONTROL: Rgb565 = Rgb565::new(218 >> 3, 224 >> 2, 226 >> 3);
+
+/// Light mode primary text - the dark background color, for maximum contrast
+pub const COLOR_LIGHT_TEXT_PRIMARY: Rgb565 = COLOR_BACKGROUND;
+
+/// Light mode secondary text - slate gray
+pub const COLOR_LIGHT_TEXT_SECONDARY: Rgb565 = Rgb565::new(96 >> 3, 106 >> 2, 110 >> 3);
+
+/// Light mode primary accent - darker teal so it reads on white
+pub const COLOR_LIGHT_PRIMARY: Rgb565 = Rgb565::new(36 >> 3, 138 >> 2, 96 >> 3);
+
+/// Light mode secondary accent - darker green
+pub const COLOR_LIGHT_SECONDARY: Rgb565 = Rgb565::new(44 >> 3, 112 >> 2, 76 >> 3);
+
+/// Light mode error - deeper red
+pub const COLOR_LIGHT_ERROR: Rgb565 = Rgb565::new(176 >> 3, 56 >> 2, 56 >> 3);
+
+// ============================================================================
+// High Contrast Colors
+// ============================================================================
+
+/// High contrast background - pure black
+pub const COLOR_HIGH_CONTRAST_BACKGROUND: Rgb565 = Rgb565::new(0, 0, 0);
+
+/// High contrast primary accent - saturated yellow
+pub const COLOR_HIGH_CONTRAST_PRIMARY: Rgb565 = Rgb565::new(255 >> 3, 221 >> 2, 0);
+
+/// High contrast secondary accent - bright cyan
+pub const COLOR_HIGH_CONTRAST_SECONDARY: Rgb565 = Rgb565::new(0, 221 >> 2, 255 >> 3);
+
+/// High contrast error - bright red
+pub const COLOR_HIGH_CONTRAST_ERROR: Rgb565 = Rgb565::new(255 >> 3, 85 >> 2, 85 >> 3);
+
 // ============================================================================
 // Status Level Colors
 // ============================================================================
@@ -112,6 +163,9 @@ pub struct ColorPalette {
 
     /// Border color for separators and outlines
     pub border: Rgb565,
+
+    /// Fill for buttons and tracks drawn on top of a surface
+    pub control: Rgb565,
 }
 
 impl Default for ColorPalette {
@@ -136,6 +190,7 @@ impl ColorPalette {
             text_primary: WHITE,
             text_secondary: LIGHT_GRAY,
             border: COLOR_STROKE,
+            control: COLOR_CONTROL,
         }
     }
 
@@ -145,14 +200,100 @@ impl ColorPalette {
     /// bright environments or user preference.
     pub fn light() -> Self {
         Self {
-            primary: COLOR_EXCELLENT_FOREGROUND,
-            secondary: COLOR_GOOD_FOREGROUND,
-            background: WHITE,
-            surface: COLOR_FOREGROUND,
-            error: COLOR_BAD_FOREGROUND,
-            text_primary: COLOR_BACKGROUND,
-            text_secondary: DARK_GRAY,
-            border: COLOR_STROKE,
+            primary: COLOR_LIGHT_PRIMARY,
+            secondary: COLOR_LIGHT_SECONDARY,
+            background: COLOR_LIGHT_BACKGROUND,
+            surface: COLOR_LIGHT_SURFACE,
+            error: COLOR_LIGHT_ERROR,
+            text_primary: COLOR_LIGHT_TEXT_PRIMARY,
+            text_secondary: COLOR_LIGHT_TEXT_SECONDARY,
+            border: COLOR_LIGHT_STROKE,
+            control: COLOR_LIGHT_CONTROL,
+        }
+    }
+
+    /// Creates a high-contrast palette
+    ///
+    /// Pure white text and borders on black with saturated accents, for
+    /// low-vision users. Surfaces match the background; white borders mark
+    /// out cards and controls instead.
+    pub fn high_contrast() -> Self {
+        Self {
+            primary: COLOR_HIGH_CONTRAST_PRIMARY,
+            secondary: COLOR_HIGH_CONTRAST_SECONDARY,
+            background: COLOR_HIGH_CONTRAST_BACKGROUND,
+            surface: COLOR_HIGH_CONTRAST_BACKGROUND,
+            error: COLOR_HIGH_CONTRAST_ERROR,
+            text_primary: WHITE,
+            text_secondary: WHITE,
+            border: WHITE,
+            control: COLOR_CONTROL,
         }
     }
+
+    /// Linearly blend from `a` to `b`
+    ///
+    /// `t` is clamped to `0.0..=1.0`; 0 returns `a` and 1 returns `b`. Channels
+    /// are blended in 8-bit space so the 5- and 6-bit channels step evenly.
+    pub fn lerp(a: Rgb565, b: Rgb565, t: f32) -> Rgb565 {
+        let t = t.clamp(0.0, 1.0);
+        let (r0, g0, b0) = rgb565_to_rgb888(a);
+        let (r1, g1, b1) = rgb565_to_rgb888(b);
+
+        let red = r0 as f32 + (r1 as f32 - r0 as f32) * t;
+        let green = g0 as f32 + (g1 as f32 - g0 as f32) * t;
+        let blue = b0 as f32 + (b1 as f32 - b0 as f32) * t;
+
+        Rgb565::new(red as u8 >> 3, green as u8 >> 2, blue as u8 >> 3)
+    }
+}
+
+/// Expand an RGB565 color to 8 bits per channel, replicating the high bits
+fn rgb565_to_rgb888(color: Rgb565) -> (u8, u8, u8) {
+    let raw = color.into_storage();
+    let r5 = ((raw >> 11) & 0x1f) as u8;
+    let g6 = ((raw >> 5) & 0x3f) as u8;
+    let b5 = (raw & 0x1f) as u8;
+
+    let r8 = (r5 << 3) | (r5 >> 2);
+    let g8 = (g6 << 2) | (g6 >> 4);
+    let b8 = (b5 << 3) | (b5 >> 2);
+
+    (r8, g8, b8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lerp_hits_the_endpoints() {
+        let a = COLOR_GOOD_FOREGROUND;
+        let b = COLOR_BAD_FOREGROUND;
+        assert_eq!(ColorPalette::lerp(a, b, 0.0), a);
+        assert_eq!(ColorPalette::lerp(a, b, 1.0), b);
+        assert_eq!(ColorPalette::lerp(Rgb565::BLACK, WHITE, 0.0), Rgb565::BLACK);
+        assert_eq!(ColorPalette::lerp(Rgb565::BLACK, WHITE, 1.0), WHITE);
+    }
+
+    #[test]
+    fn lerp_midpoint_is_halfway_per_channel() {
+        // 127.5 truncates to 127 on the 8-bit scale, then quantizes to 565
+        assert_eq!(
+            ColorPalette::lerp(Rgb565::BLACK, WHITE, 0.5),
+            Rgb565::new(127 >> 3, 127 >> 2, 127 >> 3)
+        );
+        assert_eq!(
+            ColorPalette::lerp(Rgb565::RED, Rgb565::BLUE, 0.5),
+            Rgb565::new(127 >> 3, 0, 127 >> 3)
+        );
+    }
+
+    #[test]
+    fn lerp_clamps_t() {
+        let a = COLOR_GOOD_FOREGROUND;
+        let b = COLOR_BAD_FOREGROUND;
+        assert_eq!(ColorPalette::lerp(a, b, -1.0), a);
+        assert_eq!(ColorPalette::lerp(a, b, 2.0), b);
+    }
 }
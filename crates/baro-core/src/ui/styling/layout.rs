@@ -3,6 +3,10 @@
 //! This module provides spacing constants, padding utilities, and layout
 //! helpers to maintain visual consistency throughout the UI.
 
+use embedded_graphics::prelude::Size;
+
+use crate::ui::rotation::DisplayRotation;
+
 // ============================================================================
 // Spacing
 // ============================================================================
@@ -215,12 +219,25 @@ impl Padding {
 // Display Dimensions
 // ============================================================================
 
+// The single source of the panel size: the framebuffer, display manager,
+// firmware display driver, and simulator window all read these, and page
+// layouts derive from them or from their bounds. Change them here to target a
+// different panel. Pages lay out in `LOGICAL_DISPLAY_SIZE`, which swaps the
+// axes when `DISPLAY_ROTATION` turns the panel by 90°.
+
 /// Physical display width in pixels (M5Stack CoreS3 SE ILI9342C)
 pub const DISPLAY_WIDTH_PX: u16 = 320;
 
 /// Physical display height in pixels (M5Stack CoreS3 SE ILI9342C)
 pub const DISPLAY_HEIGHT_PX: u16 = 240;
 
+/// How the panel is turned in the enclosure
+pub const DISPLAY_ROTATION: DisplayRotation = DisplayRotation::R0;
+
+/// Screen size pages lay out in, after applying [`DISPLAY_ROTATION`]
+pub const LOGICAL_DISPLAY_SIZE: Size =
+    DISPLAY_ROTATION.logical_size(Size::new(DISPLAY_WIDTH_PX as u32, DISPLAY_HEIGHT_PX as u32));
+
 // ============================================================================
 // Font Metrics
 // ============================================================================
@@ -236,3 +253,60 @@ pub const FONT_6X10_LINE_HEIGHT_PX: u32 = 12;
 
 /// Character height for FONT_10X20 in pixels
 pub const FONT_10X20_CHAR_HEIGHT_PX: u32 = 20;
+
+/// Offset from a box's vertical center to the FONT_6X10 baseline that
+/// centers one line of text in it
+pub const FONT_6X10_CENTERED_BASELINE_PX: u32 = 4;
+
+// ============================================================================
+// List Rows
+// ============================================================================
+
+// Metrics of the two-line (label over value) rows used by list pages such as
+// settings. Text offsets are baselines, as `Text` positions them.
+
+/// Height of one list row in pixels
+pub const LIST_ROW_HEIGHT_PX: u32 = 40;
+
+/// Vertical gap between list rows in pixels
+pub const LIST_ROW_GAP_PX: u32 = 2;
+
+/// Corner radius of a list row's pill background in pixels
+pub const LIST_ROW_CORNER_RADIUS_PX: u32 = 6;
+
+/// Left inset of a list row's text in pixels
+pub const LIST_ROW_TEXT_INSET_PX: u32 = 12;
+
+/// Offset from a list row's top to its label baseline in pixels
+pub const LIST_ROW_LABEL_BASELINE_PX: u32 = 16;
+
+/// Offset from a list row's label baseline to its value baseline in pixels
+pub const LIST_ROW_VALUE_SPACING_PX: u32 = 14;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framebuffer::FrameBuffer;
+    use embedded_graphics::prelude::OriginDimensions;
+
+    const PANEL_SIZE: Size = Size::new(DISPLAY_WIDTH_PX as u32, DISPLAY_HEIGHT_PX as u32);
+
+    #[test]
+    fn logical_size_is_the_panel_size_under_the_configured_rotation() {
+        assert_eq!(
+            LOGICAL_DISPLAY_SIZE,
+            DISPLAY_ROTATION.logical_size(PANEL_SIZE)
+        );
+        // Rotation never changes how many pixels there are
+        assert_eq!(
+            LOGICAL_DISPLAY_SIZE.width * LOGICAL_DISPLAY_SIZE.height,
+            PANEL_SIZE.width * PANEL_SIZE.height
+        );
+    }
+
+    #[test]
+    fn default_framebuffer_covers_the_panel() {
+        let fb: FrameBuffer = FrameBuffer::default();
+        assert_eq!(fb.size(), PANEL_SIZE);
+    }
+}
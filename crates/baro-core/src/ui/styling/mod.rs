@@ -44,8 +44,11 @@ pub use colors::{
     LIGHT_GRAY, WHITE,
 };
 pub use layout::{
-    BorderRadius, DISPLAY_HEIGHT_PX, DISPLAY_WIDTH_PX, FONT_6X10_CHAR_HEIGHT_PX,
-    FONT_6X10_CHAR_WIDTH_PX, FONT_6X10_LINE_HEIGHT_PX, FONT_10X20_CHAR_HEIGHT_PX, Padding, Spacing,
+    BorderRadius, DISPLAY_HEIGHT_PX, DISPLAY_ROTATION, DISPLAY_WIDTH_PX,
+    FONT_6X10_CENTERED_BASELINE_PX, FONT_6X10_CHAR_HEIGHT_PX, FONT_6X10_CHAR_WIDTH_PX,
+    FONT_6X10_LINE_HEIGHT_PX, FONT_10X20_CHAR_HEIGHT_PX, LIST_ROW_CORNER_RADIUS_PX,
+    LIST_ROW_GAP_PX, LIST_ROW_HEIGHT_PX, LIST_ROW_LABEL_BASELINE_PX, LIST_ROW_TEXT_INSET_PX,
+    LIST_ROW_VALUE_SPACING_PX, LOGICAL_DISPLAY_SIZE, Padding, Spacing,
 };
-pub use style::{ButtonVariant, Style};
+pub use style::{ButtonVariant, Shadow, Style};
 pub use theme::Theme;
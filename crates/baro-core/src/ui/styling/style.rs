@@ -1,14 +1,52 @@
 //! Style configuration for UI elements
 //!
 //! Provides the core `Style` struct and builder methods for defining the
-//! visual appearance of UI components (colors, borders, padding).
+//! visual appearance of UI components (colors, borders, padding, shadows).
 
+use embedded_graphics::Drawable as EgDrawable;
 use embedded_graphics::pixelcolor::Rgb565;
-use embedded_graphics::primitives::{PrimitiveStyle, PrimitiveStyleBuilder};
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{
+    PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, RoundedRectangle,
+};
 
 use super::colors::{ColorPalette, WHITE};
 use super::layout::Padding;
 
+/// How far a pressed button's background is blended towards black (0.0-1.0)
+const PRESSED_DARKEN_FRACTION: f32 = 0.3;
+
+// ============================================================================
+// Shadow
+// ============================================================================
+
+/// Drop shadow drawn behind an element
+///
+/// Rendered as a single filled copy of the element's shape shifted by
+/// `offset`, with no blur, so it costs one extra fill per draw.
+///
+/// # Examples
+///
+/// ```ignore
+/// // 2px shadow below and to the right
+/// let shadow = Shadow::new(COLOR_BACKGROUND, Point::new(2, 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shadow {
+    /// Shadow fill color
+    pub color: Rgb565,
+
+    /// Displacement from the element's bounds in pixels
+    pub offset: Point,
+}
+
+impl Shadow {
+    /// Creates a shadow with the given color and offset
+    pub const fn new(color: Rgb565, offset: Point) -> Self {
+        Self { color, offset }
+    }
+}
+
 // ============================================================================
 // Style
 // ============================================================================
@@ -33,7 +71,7 @@ use super::layout::Padding;
 ///     .with_border(COLOR_STROKE, 2)
 ///     .with_padding(Padding::all(8));
 /// ```
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Style {
     /// Background fill color (if any)
     pub background_color: Option<Rgb565>,
@@ -49,6 +87,9 @@ pub struct Style {
 
     /// Internal padding around content
     pub padding: Padding,
+
+    /// Drop shadow drawn behind the element (if any)
+    pub shadow: Option<Shadow>,
 }
 
 impl Default for Style {
@@ -60,6 +101,7 @@ impl Default for Style {
             border_color: None,
             border_width: 0,
             padding: Padding::default(),
+            shadow: None,
         }
     }
 }
@@ -154,6 +196,54 @@ impl Style {
         self
     }
 
+    /// Sets a drop shadow behind the element
+    ///
+    /// # Arguments
+    /// * `shadow` - Shadow color and offset
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let card_style = Style::new()
+    ///     .with_background(COLOR_FOREGROUND)
+    ///     .with_shadow(Shadow::new(COLOR_BACKGROUND, Point::new(2, 2)));
+    /// ```
+    pub fn with_shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Draws this style's shadow (if any) for an element occupying `bounds`
+    ///
+    /// Call before drawing the element's background so the shadow ends up
+    /// behind it. The shadow extends past `bounds` by its offset, so it is
+    /// not covered by the element's dirty region.
+    ///
+    /// # Arguments
+    /// * `bounds` - The element's bounds
+    /// * `corner_radius` - Corner radius of the element's shape (0 for square)
+    /// * `display` - Draw target
+    pub fn draw_shadow<D>(
+        &self,
+        bounds: Rectangle,
+        corner_radius: u32,
+        display: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Rgb565>,
+    {
+        let Some(shadow) = self.shadow else {
+            return Ok(());
+        };
+
+        RoundedRectangle::with_equal_corners(
+            bounds.translate(shadow.offset),
+            Size::new(corner_radius, corner_radius),
+        )
+        .into_styled(PrimitiveStyle::with_fill(shadow.color))
+        .draw(display)
+    }
+
     /// Converts this style to a `PrimitiveStyle` for embedded-graphics drawing
     ///
     /// This method is used internally when rendering styled shapes and backgrounds.
@@ -250,4 +340,108 @@ impl ButtonVariant {
             ButtonVariant::Pill(fg_color) => Style::new().with_background(*fg_color),
         }
     }
+
+    /// Style shown while the button is held down
+    ///
+    /// The variant's background is darkened. Variants without a background
+    /// (`Text`) get a darkened control fill so the press is still visible.
+    pub fn to_pressed_style(&self, palette: &ColorPalette) -> Style {
+        let base = self.to_style(palette);
+        let background = base.background_color.unwrap_or(palette.control);
+        base.with_background(ColorPalette::lerp(
+            background,
+            Rgb565::BLACK,
+            PRESSED_DARKEN_FRACTION,
+        ))
+    }
+
+    /// Style shown while the button is disabled
+    ///
+    /// Every variant is flattened onto the surface color with secondary text,
+    /// keeping its padding and border width, and drops any shadow.
+    pub fn to_disabled_style(&self, palette: &ColorPalette) -> Style {
+        let mut style = self
+            .to_style(palette)
+            .with_background(palette.surface)
+            .with_foreground(palette.text_secondary);
+        if style.border_color.is_some() {
+            style.border_color = Some(palette.border);
+        }
+        style.shadow = None;
+        style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framebuffer::FrameBuffer;
+    use crate::ui::core::Drawable;
+    use crate::ui::{Container, Direction};
+
+    type SmallBuffer = FrameBuffer<32, 24>;
+
+    const SHADOW_COLOR: Rgb565 = Rgb565::BLUE;
+    const FILL: Rgb565 = Rgb565::RED;
+
+    fn element_bounds() -> Rectangle {
+        Rectangle::new(Point::new(4, 4), Size::new(12, 8))
+    }
+
+    fn count(fb: &SmallBuffer, region: Rectangle, color: Rgb565) -> usize {
+        region
+            .points()
+            .filter(|p| fb.pixel(p.x as usize, p.y as usize) == Some(color))
+            .count()
+    }
+
+    #[test]
+    fn shadow_touches_pixels_below_and_right_of_the_bounds() {
+        let bounds = element_bounds();
+        let style = Style::new()
+            .with_background(FILL)
+            .with_shadow(Shadow::new(SHADOW_COLOR, Point::new(2, 2)));
+        let container = Container::<1>::new(bounds, Direction::Vertical).with_style(style);
+
+        let mut fb = SmallBuffer::new();
+        container.draw(&mut fb).unwrap();
+
+        // The element itself covers its bounds
+        let area = (bounds.size.width * bounds.size.height) as usize;
+        assert_eq!(count(&fb, bounds, FILL), area);
+
+        // Two-pixel strips past the bottom and right edges hold the shadow
+        let bottom_right = bounds.bottom_right().unwrap();
+        let below = Rectangle::new(
+            Point::new(bounds.top_left.x + 2, bottom_right.y + 1),
+            Size::new(bounds.size.width, 2),
+        );
+        let right = Rectangle::new(
+            Point::new(bottom_right.x + 1, bounds.top_left.y + 2),
+            Size::new(2, bounds.size.height),
+        );
+        assert_eq!(
+            count(&fb, below, SHADOW_COLOR),
+            below.size.width as usize * 2
+        );
+        assert_eq!(
+            count(&fb, right, SHADOW_COLOR),
+            right.size.height as usize * 2
+        );
+
+        // Nothing lands above or to the left
+        let above = Rectangle::new(Point::new(0, 0), Size::new(32, 4));
+        let left = Rectangle::new(Point::new(0, 0), Size::new(4, 24));
+        assert_eq!(count(&fb, above, SHADOW_COLOR), 0);
+        assert_eq!(count(&fb, left, SHADOW_COLOR), 0);
+    }
+
+    #[test]
+    fn no_shadow_draws_nothing_outside_the_bounds() {
+        let style = Style::new().with_background(FILL);
+        let mut fb = SmallBuffer::new();
+        style.draw_shadow(element_bounds(), 0, &mut fb).unwrap();
+        let everything = Rectangle::new(Point::zero(), Size::new(32, 24));
+        assert_eq!(count(&fb, everything, Rgb565::BLACK), 32 * 24);
+    }
 }
@@ -5,6 +5,14 @@
 
 use super::colors::ColorPalette;
 use super::layout::{BorderRadius, Spacing};
+use crate::config::ThemeMode;
+use crate::ui::components::TextSize;
+
+/// Outline stroke width in the standard themes, in pixels
+const DEFAULT_BORDER_WIDTH_PX: u32 = 1;
+
+/// Outline stroke width in the high-contrast theme, in pixels
+const HIGH_CONTRAST_BORDER_WIDTH_PX: u32 = 2;
 
 // ============================================================================
 // Theme
@@ -34,9 +42,15 @@ use super::layout::{BorderRadius, Spacing};
 ///
 /// // Or create a light theme
 /// let light_theme = Theme::light();
+///
+/// // Pages take the theme at construction and copy its palette
+/// let page = MonitorPage::new(bounds, &theme);
 /// ```
 #[derive(Debug, Clone, Copy)]
 pub struct Theme {
+    /// Which color scheme the palette belongs to
+    pub mode: ThemeMode,
+
     /// The active color palette (dark or light)
     pub palette: ColorPalette,
 
@@ -45,6 +59,12 @@ pub struct Theme {
 
     /// Border radius options for rounded corners
     pub border_radius: BorderRadius,
+
+    /// Stroke width for outlines and separators, in pixels
+    pub border_width_px: u32,
+
+    /// Whether text is drawn one size larger than the page asks for
+    pub large_text: bool,
 }
 
 impl Default for Theme {
@@ -65,9 +85,12 @@ impl Theme {
     /// A `Theme` configured with dark color palette and standard spacing/radii.
     pub fn dark() -> Self {
         Self {
+            mode: ThemeMode::Dark,
             palette: ColorPalette::dark(),
             spacing: Spacing::default(),
             border_radius: BorderRadius::default(),
+            border_width_px: DEFAULT_BORDER_WIDTH_PX,
+            large_text: false,
         }
     }
 
@@ -81,9 +104,155 @@ impl Theme {
     /// A `Theme` configured with light color palette and standard spacing/radii.
     pub fn light() -> Self {
         Self {
+            mode: ThemeMode::Light,
             palette: ColorPalette::light(),
             spacing: Spacing::default(),
             border_radius: BorderRadius::default(),
+            border_width_px: DEFAULT_BORDER_WIDTH_PX,
+            large_text: false,
+        }
+    }
+
+    /// Creates a high-contrast theme
+    ///
+    /// Pure white text on black with thicker borders and every text size
+    /// bumped up one step, for users with low vision.
+    ///
+    /// # Returns
+    ///
+    /// A `Theme` configured with the high-contrast palette, thick borders, and
+    /// large text.
+    pub fn high_contrast() -> Self {
+        Self {
+            mode: ThemeMode::HighContrast,
+            palette: ColorPalette::high_contrast(),
+            spacing: Spacing::default(),
+            border_radius: BorderRadius::default(),
+            border_width_px: HIGH_CONTRAST_BORDER_WIDTH_PX,
+            large_text: true,
+        }
+    }
+
+    /// Creates the theme for `mode`
+    pub fn for_mode(mode: ThemeMode) -> Self {
+        match mode {
+            ThemeMode::Dark => Self::dark(),
+            ThemeMode::Light => Self::light(),
+            ThemeMode::HighContrast => Self::high_contrast(),
+        }
+    }
+
+    /// Size to draw text the page would draw at `size`
+    pub const fn text_size(&self, size: TextSize) -> TextSize {
+        if self.large_text { size.larger() } else { size }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::pixelcolor::{Rgb565, Rgb888, RgbColor};
+
+    /// Perceived brightness of `color` in 0..=255 (Rec. 601 weights)
+    fn luma(color: Rgb565) -> i32 {
+        let c = Rgb888::from(color);
+        (299 * c.r() as i32 + 587 * c.g() as i32 + 114 * c.b() as i32) / 1000
+    }
+
+    /// Text, accents and borders must stand out from the background they sit on
+    fn assert_sane(palette: &ColorPalette) {
+        let bg = luma(palette.background);
+        assert!(
+            (luma(palette.text_primary) - bg).abs() >= 150,
+            "{palette:?}"
+        );
+        assert!(
+            (luma(palette.text_secondary) - bg).abs() >= 60,
+            "{palette:?}"
+        );
+        assert!((luma(palette.primary) - bg).abs() >= 40, "{palette:?}");
+        assert!((luma(palette.error) - bg).abs() >= 40, "{palette:?}");
+        assert_ne!(palette.border, palette.background);
+    }
+
+    /// WCAG relative luminance of `color` in 0.0..=1.0
+    fn relative_luminance(color: Rgb565) -> f32 {
+        let c = Rgb888::from(color);
+        let linear = |channel: u8| {
+            let v = channel as f32 / 255.0;
+            if v <= 0.040_45 {
+                v / 12.92
+            } else {
+                ((v + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(c.r()) + 0.7152 * linear(c.g()) + 0.0722 * linear(c.b())
+    }
+
+    /// WCAG contrast ratio between two colors, from 1.0 to 21.0
+    fn contrast_ratio(a: Rgb565, b: Rgb565) -> f32 {
+        let (a, b) = (relative_luminance(a), relative_luminance(b));
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    #[test]
+    fn high_contrast_text_meets_wcag_aaa_against_the_background() {
+        let palette = Theme::high_contrast().palette;
+
+        // WCAG AAA asks for 7:1 for body text; white on black is the 21:1 maximum
+        let text = contrast_ratio(palette.text_primary, palette.background);
+        assert!(text >= 20.9, "{text}");
+        assert!(contrast_ratio(palette.text_secondary, palette.background) >= 7.0);
+        assert!(contrast_ratio(palette.border, palette.background) >= 7.0);
+        assert!(contrast_ratio(palette.text_primary, palette.surface) >= 7.0);
+        // Accents still need large-text contrast (4.5:1)
+        assert!(contrast_ratio(palette.primary, palette.background) >= 4.5);
+        assert!(contrast_ratio(palette.error, palette.background) >= 4.5);
+    }
+
+    #[test]
+    fn high_contrast_beats_the_dark_theme_for_secondary_text() {
+        let ratio =
+            |palette: ColorPalette| contrast_ratio(palette.text_secondary, palette.background);
+        assert!(ratio(Theme::high_contrast().palette) > ratio(Theme::dark().palette));
+    }
+
+    #[test]
+    fn high_contrast_uses_thicker_borders_and_larger_text() {
+        let high_contrast = Theme::high_contrast();
+        let dark = Theme::dark();
+
+        assert!(high_contrast.border_width_px > dark.border_width_px);
+        assert_eq!(high_contrast.text_size(TextSize::Small), TextSize::Medium);
+        assert_eq!(high_contrast.text_size(TextSize::Large), TextSize::Large);
+        assert_eq!(dark.text_size(TextSize::Small), TextSize::Small);
+    }
+
+    #[test]
+    fn light_and_dark_palettes_are_distinct() {
+        let dark = Theme::dark().palette;
+        let light = Theme::light().palette;
+
+        assert_ne!(dark, light);
+        assert_ne!(dark.background, light.background);
+        assert_ne!(dark.text_primary, light.text_primary);
+        // Dark mode is light-on-dark and light mode the reverse
+        assert!(luma(dark.background) < luma(dark.text_primary));
+        assert!(luma(light.background) > luma(light.text_primary));
+    }
+
+    #[test]
+    fn every_palette_is_legible() {
+        for mode in [ThemeMode::Dark, ThemeMode::Light, ThemeMode::HighContrast] {
+            assert_sane(&Theme::for_mode(mode).palette);
+        }
+    }
+
+    #[test]
+    fn for_mode_matches_the_mode() {
+        for mode in [ThemeMode::Dark, ThemeMode::Light, ThemeMode::HighContrast] {
+            assert_eq!(Theme::for_mode(mode).mode, mode);
         }
+        assert_eq!(Theme::default().palette, ColorPalette::default());
     }
 }
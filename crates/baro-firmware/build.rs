@@ -3,6 +3,10 @@ fn main() {
     let _ = dotenvy::dotenv();
 
     load_wifi_secrets();
+    load_mqtt_settings();
+    load_ntp_settings();
+    load_sample_stream_settings();
+    declare_sensor_cfg();
 
     linker_be_nice();
     // make sure linkall.x is the last linker script (otherwise might cause problems with flip-link)
@@ -18,6 +22,62 @@ fn load_wifi_secrets() {
 
     println!("cargo:rustc-env=WIFI_SSID={}", ssid);
     println!("cargo:rustc-env=WIFI_PASSWORD={}", password);
+
+    // Optional fallback networks, tried in order after the primary one
+    for suffix in ["_2", "_3"] {
+        for key in ["WIFI_SSID", "WIFI_PASSWORD"] {
+            let name = format!("{key}{suffix}");
+            println!("cargo:rerun-if-env-changed={}", name);
+            if let Ok(value) = std::env::var(&name) {
+                println!("cargo:rustc-env={}={}", name, value);
+            }
+        }
+    }
+}
+
+fn load_mqtt_settings() {
+    // Optional; the MQTT publisher only runs when MQTT_BROKER is set
+    for name in [
+        "MQTT_BROKER",
+        "MQTT_PORT",
+        "MQTT_CLIENT_ID",
+        "MQTT_USERNAME",
+        "MQTT_PASSWORD",
+        "MQTT_TOPIC_PREFIX",
+    ] {
+        println!("cargo:rerun-if-env-changed={}", name);
+        if let Ok(value) = std::env::var(name) {
+            println!("cargo:rustc-env={}={}", name, value);
+        }
+    }
+}
+
+fn load_ntp_settings() {
+    // Optional; time sync falls back to the built-in servers without it
+    println!("cargo:rerun-if-env-changed=NTP_SERVERS");
+    if let Ok(value) = std::env::var("NTP_SERVERS") {
+        println!("cargo:rustc-env=NTP_SERVERS={}", value);
+    }
+}
+
+fn load_sample_stream_settings() {
+    // Optional; the sample stream only runs when SAMPLE_STREAM_ADDR is set
+    println!("cargo:rerun-if-env-changed=SAMPLE_STREAM_ADDR");
+    if let Ok(value) = std::env::var("SAMPLE_STREAM_ADDR") {
+        println!("cargo:rustc-env=SAMPLE_STREAM_ADDR={}", value);
+    }
+}
+
+fn declare_sensor_cfg() {
+    // `cfg(any_sensor)` is set when at least one sensor driver is enabled,
+    // matching baro_core::sensors::any_sensor_configured()
+    println!("cargo:rustc-check-cfg=cfg(any_sensor)");
+    let any_sensor = ["SHT40", "SCD41", "BH1750", "BME280"]
+        .iter()
+        .any(|sensor| std::env::var_os(format!("CARGO_FEATURE_SENSOR_{sensor}")).is_some());
+    if any_sensor {
+        println!("cargo:rustc-cfg=any_sensor");
+    }
 }
 
 fn linker_be_nice() {
@@ -45,6 +45,9 @@ pub enum HardwareError {
     #[error("I2C bus creation failed")]
     I2cBus,
 
+    #[error("I2C mux channel {channel} selection failed")]
+    MuxChannel { channel: u8 },
+
     #[error("SPI bus creation failed")]
     SpiBus,
 
@@ -59,18 +62,32 @@ use crate::dual_mode_pin::{
     DualModePin, DualModePinAsOutput, InputModeSpiDevice, OutputModeSpiDevice,
 };
 use baro_core::async_i2c_bus::AsyncI2cDevice;
+#[cfg(feature = "i2c-scan")]
+use baro_core::async_i2c_bus::scan_i2c;
+use baro_core::touch_scan::TouchScanner;
 
 pub type Tca9548SpiMultiplexer<'a> =
     Tca9548aAsync<AsyncI2cDevice<'a, esp_hal::i2c::master::I2c<'a, esp_hal::Async>>>;
 
+pub type PowerManagementChip<'a> =
+    AsyncAxp2101<AsyncI2cDevice<'a, esp_hal::i2c::master::I2c<'a, esp_hal::Async>>>;
+
+/// DLDO1 voltage driving the backlight at 0% brightness (still faintly visible)
+const BACKLIGHT_MIN_MILLIVOLTS: u16 = 2500;
+
+/// DLDO1 voltage driving the backlight at 100% brightness
+const BACKLIGHT_MAX_MILLIVOLTS: u16 = 3300;
+
 /// Container for I2C-based hardware components
 pub struct I2cHardware<'a> {
-    pub power_mgmt: AsyncAxp2101<AsyncI2cDevice<'a, esp_hal::i2c::master::I2c<'a, esp_hal::Async>>>,
+    pub power_mgmt: PowerManagementChip<'a>,
     pub gpio_expander: aw9523_embedded::r#async::Aw9523Async<
         embedded_hal::i2c::SevenBitAddress,
         AsyncI2cDevice<'a, esp_hal::i2c::master::I2c<'a, esp_hal::Async>>,
     >,
-    pub touch_interface: FT6336U<AsyncI2cDevice<'a, esp_hal::i2c::master::I2c<'a, esp_hal::Async>>>,
+    /// Burst reader for the FT6336U touch registers
+    pub touch_scanner:
+        TouchScanner<AsyncI2cDevice<'a, esp_hal::i2c::master::I2c<'a, esp_hal::Async>>>,
 }
 
 /// Container for SPI-based hardware components
@@ -232,15 +249,48 @@ pub async fn init_i2c_hardware(
         library_version, chip_id, g_mode
     );
 
+    // Scan once everything on the bus is powered, so missing parts show up
+    #[cfg(feature = "i2c-scan")]
+    {
+        let found = scan_i2c(&mut AsyncI2cDevice::new(i2c0_bus)).await;
+        info!(
+            "I2C scan found {} device(s): {:02X?}",
+            found.len(),
+            found.as_slice()
+        );
+    }
+
     let hardware = I2cHardware {
         power_mgmt: power_mgmt_chip,
         gpio_expander,
-        touch_interface,
+        // The driver is only needed for setup; scans use a single burst read
+        touch_scanner: TouchScanner::new(AsyncI2cDevice::new(i2c0_bus)),
     };
 
     (hardware, i2c_for_sensors)
 }
 
+/// Set the display backlight brightness
+///
+/// The backlight is powered from the AXP2101 DLDO1 rail, so brightness is
+/// controlled by scaling that rail's voltage linearly between
+/// `BACKLIGHT_MIN_MILLIVOLTS` and `BACKLIGHT_MAX_MILLIVOLTS`.
+pub async fn set_backlight_brightness(
+    power_mgmt: &mut PowerManagementChip<'_>,
+    percent: u8,
+) -> Result<(), HardwareError> {
+    let percent = percent.min(100) as u16;
+    let millivolts = BACKLIGHT_MIN_MILLIVOLTS
+        + (BACKLIGHT_MAX_MILLIVOLTS - BACKLIGHT_MIN_MILLIVOLTS) * percent / 100;
+
+    power_mgmt.set_dldo1_voltage(millivolts).await.map_err(|e| {
+        error!("Failed to set DLDO1 voltage to {}mV: {:?}", millivolts, e);
+        HardwareError::PowerManagement {
+            operation: "set backlight voltage",
+        }
+    })
+}
+
 /// Initialize the I2C bus hardware
 ///
 /// Creates the I2C peripheral with proper configuration
@@ -2,20 +2,38 @@
 
 use baro_core::async_i2c_bus::AsyncI2cDevice;
 
+#[cfg(any_sensor)]
+use baro_core::sensors::SensorSlot;
 #[cfg(feature = "sensor-bh1750")]
 use baro_core::sensors::{BH1750Indexed, BH1750Sensor};
+#[cfg(feature = "sensor-bme280")]
+use baro_core::sensors::{BME280Indexed, BME280Sensor};
 #[cfg(feature = "sensor-scd41")]
-use baro_core::sensors::{SCD41Indexed, SCD41Sensor};
+use baro_core::sensors::{
+    SCD41_WARMUP_SECS, SCD41Indexed, SCD41Sensor, Scd41Settings, SensorWarmup,
+    scd41_set_forced_recalibration,
+};
 #[cfg(feature = "sensor-sht40")]
 use baro_core::sensors::{SHT40Indexed, SHT40Sensor};
 
-use baro_core::sensors::SensorError;
+#[cfg(any_sensor)]
+use baro_core::sensors::select_mux_channel;
+use baro_core::sensors::{
+    SensorCalibration, SensorError, SensorHealth, SensorHealthReport, SensorHealthTracker,
+    SensorRegistry, SensorType,
+};
 use log::error;
 
+#[cfg(feature = "sensor-scd41")]
+use baro_core::config::DEFAULT_CO2_TEMPERATURE_OFFSET_MILLI_C;
+
 use tca9548a_embedded::r#async::{I2cChannelAsync, Tca9548aAsync};
 
 type AsyncI2cDeviceType<'a> = AsyncI2cDevice<'a, esp_hal::i2c::master::I2c<'a, esp_hal::Async>>;
 
+/// The sensor I2C mux, handed to each sensor slot in turn
+type SensorMux<'a> = Tca9548aAsync<AsyncI2cDeviceType<'a>>;
+
 type I2CChannelAsyncDeviceType<'a> =
     I2cChannelAsync<'a, AsyncI2cDeviceType<'a>, esp_hal::i2c::master::Error>;
 
@@ -25,6 +43,9 @@ type SHT40IndexedAsyncI2CDeviceType<'a> = SHT40Indexed<I2CChannelAsyncDeviceType
 #[cfg(feature = "sensor-bh1750")]
 type BH1750IndexedAsyncI2CDeviceType<'a> = BH1750Indexed<I2CChannelAsyncDeviceType<'a>>;
 
+#[cfg(feature = "sensor-bme280")]
+type BME280IndexedAsyncI2CDeviceType<'a> = BME280Indexed<I2CChannelAsyncDeviceType<'a>>;
+
 #[cfg(feature = "sensor-scd41")]
 type SCD41IndexedAsyncI2CDeviceType<'a> = SCD41Indexed<I2CChannelAsyncDeviceType<'a>>;
 
@@ -36,125 +57,320 @@ type SCD41IndexedAsyncI2CDeviceType<'a> = SCD41Indexed<I2CChannelAsyncDeviceType
 /// where its data is stored in the values array and which I2C mux
 /// channel they reside on.
 pub struct SensorsState<'a> {
-    mux: Tca9548aAsync<AsyncI2cDeviceType<'a>>,
+    mux: SensorMux<'a>,
+    health: SensorHealthTracker,
+    /// SCD41 warm-up clock (sensors are recreated per read, so it lives here)
+    #[cfg(feature = "sensor-scd41")]
+    scd41_warmup: SensorWarmup,
+    /// SCD41 self-calibration and temperature offset, sent by
+    /// `apply_co2_settings` at start-up and after they change
+    #[cfg(feature = "sensor-scd41")]
+    scd41_settings: Scd41Settings,
 }
 
-impl<'a> SensorsState<'a> {
-    /// Create a new sensors state container
-    ///
-    /// The I2C mux is stored and sensors are created on-demand during reads.
-    /// Each sensor type knows its own mux channel via compile-time const generics.
-    pub fn new(mux: Tca9548aAsync<AsyncI2cDeviceType<'a>>) -> Self {
-        Self { mux }
+/// Switch the mux to `channel` for `sensor`
+///
+/// Failures are reported as `SensorError::MuxChannel` so a wiring fault on
+/// one channel is told apart from the sensor failing to respond.
+#[cfg(any_sensor)]
+fn select_channel<'m, 'a>(
+    mux: &'m mut SensorMux<'a>,
+    sensor: &'static str,
+    channel: u8,
+) -> Result<I2cChannelAsync<'m, AsyncI2cDeviceType<'a>, esp_hal::i2c::master::Error>, SensorError> {
+    let selection = mux.channel(channel);
+    if let Err(e) = &selection {
+        error!(
+            "{} ({}): {:?}",
+            super::HardwareError::MuxChannel { channel },
+            sensor,
+            e
+        );
     }
+    select_mux_channel(selection, sensor, channel)
+}
+
+// ---------------------------------------------------------------------------
+// Sensor slots
+// ---------------------------------------------------------------------------
 
-    #[cfg(feature = "sensor-sht40")]
-    async fn read_sht40(
+/// SHT40 temperature/humidity sensor, created on its mux channel per read
+#[cfg(feature = "sensor-sht40")]
+struct Sht40Slot;
+
+#[cfg(feature = "sensor-sht40")]
+impl<'a> SensorSlot<SensorMux<'a>> for Sht40Slot {
+    const SENSOR_TYPES: &'static [SensorType] = &[SensorType::Temperature, SensorType::Humidity];
+
+    async fn read_into(
         &mut self,
-        into: &mut [i32; baro_core::storage::MAX_SENSORS],
-    ) -> Result<(), SensorError> {
+        mux: &mut SensorMux<'a>,
+        values: &mut [i32; baro_core::storage::MAX_SENSORS],
+        calibration: &SensorCalibration,
+    ) -> Result<SensorHealth, SensorError> {
         let channel = SHT40IndexedAsyncI2CDeviceType::mux_channel();
-        let sht40_i2c = self.mux.channel(channel).map_err(|e| {
-            error!(
-                "Failed to select mux channel {} for SHT40: {:?}",
-                channel, e
-            );
-            SensorError::I2cError {
-                sensor: "SHT40",
-                channel,
-                details: "Failed to select mux channel",
-            }
-        })?;
+        let sht40_i2c = select_channel(mux, "SHT40", channel)?;
         let mut sht40 = SHT40Indexed::from(SHT40Sensor::new(sht40_i2c));
 
-        sht40.read_into(into).await.map_err(|e| {
-            error!("Failed to read SHT40 on I2C mux channel {}: {}", channel, e);
-            e
-        })
+        sht40
+            .read_into_with_retry(values, calibration)
+            .await
+            .map_err(|e| {
+                error!("Failed to read SHT40 on I2C mux channel {}: {}", channel, e);
+                e
+            })?;
+
+        Ok(sht40.health().await)
     }
+}
 
-    #[cfg(feature = "sensor-scd41")]
-    async fn read_scd41(
+/// SCD41 CO2 sensor, whose readings are held back until `warmup` elapses
+#[cfg(feature = "sensor-scd41")]
+struct Scd41Slot<'w> {
+    warmup: &'w mut SensorWarmup,
+}
+
+#[cfg(feature = "sensor-scd41")]
+impl<'a> SensorSlot<SensorMux<'a>> for Scd41Slot<'_> {
+    const SENSOR_TYPES: &'static [SensorType] = &[SensorType::Co2];
+
+    async fn read_into(
         &mut self,
-        into: &mut [i32; baro_core::storage::MAX_SENSORS],
-    ) -> Result<(), SensorError> {
+        mux: &mut SensorMux<'a>,
+        values: &mut [i32; baro_core::storage::MAX_SENSORS],
+        calibration: &SensorCalibration,
+    ) -> Result<SensorHealth, SensorError> {
         let channel = SCD41IndexedAsyncI2CDeviceType::mux_channel();
-        let scd41_i2c = self.mux.channel(channel).map_err(|e| {
-            error!(
-                "Failed to select mux channel {} for SCD41: {:?}",
-                channel, e
-            );
-            SensorError::I2cError {
-                sensor: "SCD41",
-                channel,
-                details: "Failed to select mux channel",
-            }
-        })?;
+        let scd41_i2c = select_channel(mux, "SCD41", channel)?;
         let mut scd41 = SCD41Indexed::from(SCD41Sensor::new(scd41_i2c));
 
-        scd41.read_into(into).await.map_err(|e| {
-            error!("Failed to read SCD41 on I2C mux channel {}: {}", channel, e);
-            e
-        })
+        scd41
+            .read_into_with_warmup(values, calibration, self.warmup)
+            .await
+            .map_err(|e| {
+                error!("Failed to read SCD41 on I2C mux channel {}: {}", channel, e);
+                e
+            })?;
+
+        Ok(scd41.health().await)
     }
+}
+
+/// BH1750 ambient light sensor, created on its mux channel per read
+#[cfg(feature = "sensor-bh1750")]
+struct Bh1750Slot;
+
+#[cfg(feature = "sensor-bh1750")]
+impl<'a> SensorSlot<SensorMux<'a>> for Bh1750Slot {
+    const SENSOR_TYPES: &'static [SensorType] = &[SensorType::Lux];
 
-    #[cfg(feature = "sensor-bh1750")]
-    async fn read_bh1750(
+    async fn read_into(
         &mut self,
-        into: &mut [i32; baro_core::storage::MAX_SENSORS],
-    ) -> Result<(), SensorError> {
+        mux: &mut SensorMux<'a>,
+        values: &mut [i32; baro_core::storage::MAX_SENSORS],
+        calibration: &SensorCalibration,
+    ) -> Result<SensorHealth, SensorError> {
         let channel = BH1750IndexedAsyncI2CDeviceType::mux_channel();
-        let bh1750_i2c = self.mux.channel(channel).map_err(|e| {
-            error!(
-                "Failed to select mux channel {} for BH1750: {:?}",
-                channel, e
-            );
-            SensorError::I2cError {
-                sensor: "BH1750",
-                channel,
-                details: "Failed to select mux channel",
-            }
-        })?;
+        let bh1750_i2c = select_channel(mux, "BH1750", channel)?;
         let mut bh1750 = BH1750Indexed::from(BH1750Sensor::new(bh1750_i2c));
 
-        bh1750.read_into(into).await.map_err(|e| {
-            error!(
-                "Failed to read BH1750 on I2C mux channel {}: {}",
-                channel, e
-            );
-            e
-        })
+        bh1750
+            .read_into_with_retry(values, calibration)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to read BH1750 on I2C mux channel {}: {}",
+                    channel, e
+                );
+                e
+            })?;
+
+        Ok(bh1750.health().await)
+    }
+}
+
+/// BME280 pressure sensor, created on its mux channel per read
+#[cfg(feature = "sensor-bme280")]
+struct Bme280Slot;
+
+#[cfg(feature = "sensor-bme280")]
+impl<'a> SensorSlot<SensorMux<'a>> for Bme280Slot {
+    const SENSOR_TYPES: &'static [SensorType] = &[SensorType::Pressure];
+
+    async fn read_into(
+        &mut self,
+        mux: &mut SensorMux<'a>,
+        values: &mut [i32; baro_core::storage::MAX_SENSORS],
+        calibration: &SensorCalibration,
+    ) -> Result<SensorHealth, SensorError> {
+        let channel = BME280IndexedAsyncI2CDeviceType::mux_channel();
+        let bme280_i2c = select_channel(mux, "BME280", channel)?;
+        let mut bme280 = BME280Indexed::from(BME280Sensor::new(bme280_i2c));
+
+        bme280
+            .read_into_with_retry(values, calibration)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to read BME280 on I2C mux channel {}: {}",
+                    channel, e
+                );
+                e
+            })?;
+
+        Ok(bme280.health().await)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// SensorsState
+// ---------------------------------------------------------------------------
+
+impl<'a> SensorsState<'a> {
+    /// Create a new sensors state container
+    ///
+    /// The I2C mux is stored and sensors are created on-demand during reads.
+    /// Each sensor type knows its own mux channel via compile-time const generics.
+    pub fn new(mux: SensorMux<'a>) -> Self {
+        Self {
+            mux,
+            health: SensorHealthTracker::new(),
+            #[cfg(feature = "sensor-scd41")]
+            scd41_warmup: SensorWarmup::new(SCD41_WARMUP_SECS),
+            #[cfg(feature = "sensor-scd41")]
+            scd41_settings: Scd41Settings::new(true, DEFAULT_CO2_TEMPERATURE_OFFSET_MILLI_C),
+        }
     }
 
     /// Read all sensors into the provided values array
     ///
-    /// This method reads each sensor in sequence and stores the results
-    /// at their designated indices in the array.
+    /// Every enabled sensor is registered in a `SensorRegistry` and read in
+    /// registration order, each writing only the indices its `IndexedSensor`
+    /// type declares.
     ///
-    /// Each sensor knows its own mux channel and array indices at compile time,
-    /// ensuring type-safe sensor management as the system expands.
+    /// Readings are corrected with `calibration` before being stored, so the
+    /// returned values are already calibrated.
+    ///
+    /// Each sensor is retried with backoff (see `IndexedSensor::read_into_with_retry`).
+    /// A sensor that keeps failing has its slots set to `MISSING_SENSOR_VALUE`
+    /// while the other sensors' values are still returned. An error is only
+    /// returned when every sensor failed, so no usable sample exists.
     ///
     /// Sensors that are disabled via feature flags will have their values remain as 0.
     pub async fn read_all(
         &mut self,
+        calibration: &SensorCalibration,
     ) -> Result<[i32; baro_core::storage::MAX_SENSORS], SensorError> {
-        let mut values = [0_i32; baro_core::storage::MAX_SENSORS];
+        let Self {
+            mux,
+            health,
+            #[cfg(feature = "sensor-scd41")]
+            scd41_warmup,
+            ..
+        } = self;
 
-        // Read SHT40 using compile-time channel info
-        // The sensor type itself knows it's on channel 0
+        let registry = SensorRegistry::new();
         #[cfg(feature = "sensor-sht40")]
-        self.read_sht40(&mut values).await?;
+        let registry = registry.register(Sht40Slot);
+        #[cfg(feature = "sensor-scd41")]
+        let registry = registry.register(Scd41Slot {
+            warmup: scd41_warmup,
+        });
+        #[cfg(feature = "sensor-bh1750")]
+        let registry = registry.register(Bh1750Slot);
+        #[cfg(feature = "sensor-bme280")]
+        let registry = registry.register(Bme280Slot);
+
+        let mut values = [0_i32; baro_core::storage::MAX_SENSORS];
+        let mut first_error: Option<SensorError> = None;
+        let mut any_success = false;
+
+        // Record each outcome in the health tracker, keeping the first error
+        let mut record_health =
+            |sensors: &'static [SensorType], result: Result<SensorHealth, SensorError>| match result
+            {
+                Ok(sensor_health) => {
+                    health.record_success(sensors, sensor_health);
+                    any_success = true;
+                }
+                Err(e) => {
+                    health.record_failure(sensors);
+                    first_error.get_or_insert(e);
+                }
+            };
+
+        registry
+            .read_each(mux, &mut values, calibration, &mut record_health)
+            .await;
+
+        match first_error {
+            Some(e) if !any_success => Err(e),
+            _ => Ok(values),
+        }
+    }
 
-        // Read SCD41 using compile-time channel info
-        // The sensor type itself knows it's on channel 1
+    /// Current health of every sensor, based on recent read outcomes
+    pub fn health_report(&self) -> SensorHealthReport {
+        self.health.report()
+    }
+
+    /// Turn CO2 automatic self-calibration on or off
+    ///
+    /// Sent by the next `apply_co2_settings`; setting the value already in
+    /// use sends nothing. A no-op without the `sensor-scd41` feature.
+    pub fn set_co2_auto_calibration(&mut self, enabled: bool) {
         #[cfg(feature = "sensor-scd41")]
-        self.read_scd41(&mut values).await?;
+        self.scd41_settings.set_auto_calibration(enabled);
+        #[cfg(not(feature = "sensor-scd41"))]
+        let _ = enabled;
+    }
 
-        // Read BH1750 using compile-time channel info
-        // The sensor type itself knows it's on channel 2
-        #[cfg(feature = "sensor-bh1750")]
-        self.read_bh1750(&mut values).await?;
+    /// Set the CO2 sensor's temperature offset in m°C
+    ///
+    /// Sent by the next `apply_co2_settings`; setting the value already in
+    /// use sends nothing. A no-op without the `sensor-scd41` feature.
+    pub fn set_co2_temperature_offset(&mut self, milli_c: u32) {
+        #[cfg(feature = "sensor-scd41")]
+        self.scd41_settings.set_temperature_offset(milli_c);
+        #[cfg(not(feature = "sensor-scd41"))]
+        let _ = milli_c;
+    }
+
+    /// Send CO2 sensor settings that changed since they were last sent
+    ///
+    /// Both settings are sent on the first call after boot, then only after
+    /// a setter changes one; otherwise this touches neither the mux nor the
+    /// sensor. Call between reads. A setting that fails to send is retried
+    /// by the next call.
+    pub async fn apply_co2_settings(&mut self) -> Result<(), SensorError> {
+        #[cfg(feature = "sensor-scd41")]
+        if self.scd41_settings.is_pending() {
+            let channel = SCD41IndexedAsyncI2CDeviceType::mux_channel();
+            let mut scd41_i2c = select_channel(&mut self.mux, "SCD41", channel)?;
+            self.scd41_settings.apply(&mut scd41_i2c).await?;
+        }
+        Ok(())
+    }
 
-        Ok(values)
+    /// Force-recalibrate the CO2 sensor to `target_ppm`, returning the applied correction
+    ///
+    /// Call between reads, with the device in air at `target_ppm` for a few
+    /// minutes. Fails with `SensorError::InitializationFailed` when the
+    /// firmware is built without the `sensor-scd41` feature.
+    pub async fn force_co2_recalibration(&mut self, target_ppm: u16) -> Result<i16, SensorError> {
+        #[cfg(feature = "sensor-scd41")]
+        {
+            let channel = SCD41IndexedAsyncI2CDeviceType::mux_channel();
+            let mut scd41_i2c = select_channel(&mut self.mux, "SCD41", channel)?;
+            scd41_set_forced_recalibration(&mut scd41_i2c, target_ppm).await
+        }
+        #[cfg(not(feature = "sensor-scd41"))]
+        {
+            let _ = target_ppm;
+            Err(SensorError::InitializationFailed {
+                sensor: "SCD41",
+                details: "CO2 sensor not built in",
+            })
+        }
     }
 }
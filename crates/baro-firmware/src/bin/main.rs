@@ -10,36 +10,52 @@
 // due to Future state machines. These are monitored but not denied.
 
 use alloc::boxed::Box;
+use baro_core::config::DeviceConfig;
+use baro_core::config::InternetConfig;
 use baro_core::display_manager::{
-    DisplayManager, DisplayRequest, get_display_receiver, get_display_sender,
+    BRIGHTNESS_SIGNAL, CO2_RECALIBRATION_SIGNAL, DisplayManager, DisplayRequest,
+    TOUCH_THRESHOLD_SIGNAL, get_display_receiver, get_display_sender,
 };
-use baro_core::storage::{MAX_SENSORS, manager::StorageManager, sd_card::SdCardManager};
+use baro_core::log_ring::LogLevel;
+use baro_core::ntp;
+use baro_core::reconnect::{NetworkConnector, ReconnectBackoff, connect_first};
+use baro_core::sensor_watchdog::{SENSOR_WATCHDOG_POLL_SECS, SensorWatchdog, SensorWatchdogEvent};
+use baro_core::sensors::{SensorHealthReport, SpikeFilter, reject_implausible};
+use baro_core::storage::{
+    MAX_SENSORS, Timestamp,
+    accumulator::RollupEvent,
+    manager::{StorageManager, estimate_time_from_last_known},
+    sd_card::SdCardManager,
+};
+use baro_core::touch_scan::{TOUCH_MONITOR_AFTER_SECS, TouchCtrlMode, TouchPowerPolicy};
 use baro_core::ui::core::PageId;
 use baro_core::ui::{DISPLAY_HEIGHT_PX, DISPLAY_WIDTH_PX};
 use baro_firmware::app_state::{
-    AppError, AppRunState, AppState, GlobalStateType, ROLLUP_CHANNEL, SensorsState, TimeSyncError,
-    create_i2c_bus, init_i2c_hardware, init_spi_peripherals,
+    AppError, AppRunState, AppState, GlobalStateType, PowerManagementChip, ROLLUP_CHANNEL,
+    SensorsState, TimeStatus, TimeSyncError, create_i2c_bus, init_i2c_hardware,
+    init_spi_peripherals, set_backlight_brightness,
 };
 use embassy_executor::Spawner;
+use embassy_futures::select::{Either, select};
 use embassy_net::udp::{PacketMetadata, UdpSocket};
 use embassy_net::{Config as EmbassyNetConfig, IpListenEndpoint, Runner, StackResources};
 use embassy_net::{IpAddress, IpEndpoint};
 use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
 use embassy_sync::mutex::Mutex as AsyncMutex;
-use embassy_time::{Duration, Timer};
+use embassy_sync::signal::Signal;
+use embassy_time::{Duration, Instant, Timer};
 use esp_hal::{clock::CpuClock, gpio::Output, spi::master::Spi, timer::timg::TimerGroup};
 use esp_radio::Controller;
-use esp_radio::wifi::{ClientConfig, WifiController, WifiDevice};
+use esp_radio::wifi::{ClientConfig, WifiController, WifiDevice, WifiEvent};
 use static_cell::StaticCell;
 
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 
 use baro_firmware::{
     dual_mode_pin::{DualModePin, DualModePinAsOutput, InputModeSpiDevice, OutputModeSpiDevice},
-    wifi_secrets,
+    ntp_servers, wifi_secrets,
 };
 use embedded_hal_bus::spi::CriticalSectionDevice as SpiCriticalSectionDevice;
-use ft6336u_driver::{FT6336U, TouchStatus};
 use mipidsi::{interface::SpiInterface, models::ILI9342CRgb565};
 
 // ====== Concrete Type Definitions for App State ======
@@ -88,6 +104,44 @@ static NET_RESOURCES: StaticCell<StackResources<8>> = StaticCell::new();
 static WIFI_CONTROLLER: StaticCell<WifiController<'static>> = StaticCell::new();
 static RADIO_INIT: StaticCell<Controller<'static>> = StaticCell::new();
 
+/// Latest WiFi link state published by the WiFi supervisor task
+static WIFI_LINK_SIGNAL: Signal<CriticalSectionRawMutex, bool> = Signal::new();
+
+/// How often the WiFi supervisor samples signal strength while connected
+const WIFI_RSSI_POLL_INTERVAL_SECS: u64 = 10;
+
+/// Seconds per minute; the header clock ticks on each minute boundary
+const SECS_PER_MINUTE: u32 = 60;
+
+/// TCP port the HTTP API listens on
+#[cfg(feature = "http-api")]
+const HTTP_API_PORT: u16 = 80;
+
+/// Idle timeout for a single HTTP API connection
+#[cfg(feature = "http-api")]
+const HTTP_API_TIMEOUT_SECS: u64 = 5;
+
+/// Delay before reconnecting to the MQTT broker after a failure
+#[cfg(feature = "mqtt")]
+const MQTT_RECONNECT_DELAY_SECS: u64 = 10;
+
+/// Time a broker connect, CONNACK or publish may stall before the connection is dropped
+#[cfg(feature = "mqtt")]
+const MQTT_SOCKET_TIMEOUT_SECS: u64 = 10;
+
+/// Delay before retrying after the sample stream socket fails to bind
+#[cfg(feature = "sample-stream")]
+const SAMPLE_STREAM_RETRY_DELAY_SECS: u64 = 10;
+
+/// Touch poll interval while the controller is in active mode
+const TOUCH_POLL_ACTIVE_MS: u64 = 5;
+
+/// Touch poll interval while the controller is in monitor mode
+const TOUCH_POLL_MONITOR_MS: u64 = 50;
+
+/// Touch controller report rate in monitor mode (Hz)
+const TOUCH_MONITOR_RATE_HZ: u8 = 25;
+
 // Static dual-mode pin for GPIO35 (shared between SD card MISO and display DC)
 static GPIO35_PIN: DualModePin<35> = DualModePin::new();
 
@@ -103,11 +157,14 @@ esp_bootloader_esp_idf::esp_app_desc!();
 
 /// Synchronize time with an NTP server using UDP
 ///
-/// This function sends an NTP request and parses the response to get the current
-/// Unix timestamp. The time can then be used to set the system clock for accurate
-/// timestamping of sensor data and rollups.
+/// Walks `servers` in order for `ntp::NTP_ROUNDS` passes, giving each attempt a
+/// jittered timeout. Responses are validated by `ntp::parse_response`, so a
+/// bogus reply moves on to the next server instead of seeding a bad clock.
 #[allow(clippy::large_stack_frames)]
-async fn udp_time_sync(stack: &embassy_net::Stack<'static>) -> Result<u32, AppError> {
+async fn udp_time_sync(
+    stack: &embassy_net::Stack<'static>,
+    servers: &[[u8; 4]],
+) -> Result<u32, AppError> {
     use embassy_time::with_timeout;
 
     // Wait for network to be configured
@@ -124,80 +181,72 @@ async fn udp_time_sync(stack: &embassy_net::Stack<'static>) -> Result<u32, AppEr
         error!("WARNING: No IPv4 config available yet");
     }
 
-    // NTP servers to try (pool.ntp.org and time.google.com)
-    let ntp_servers = [
-        IpEndpoint::new(IpAddress::v4(162, 159, 200, 1), 123), // pool.ntp.org
-        IpEndpoint::new(IpAddress::v4(216, 239, 35, 0), 123),  // time.google.com
-        IpEndpoint::new(IpAddress::v4(216, 239, 35, 4), 123),  // time.google.com
-    ];
-
-    // Try each server
-    for (i, &ntp_server) in ntp_servers.iter().enumerate() {
-        info!("Trying NTP server #{}: {}", i + 1, ntp_server);
-
-        // UDP socket buffers
-        let mut rx_meta: [PacketMetadata; 4] = [PacketMetadata::EMPTY; 4];
-        let mut rx_buf: [u8; 128] = [0; 128];
-        let mut tx_meta: [PacketMetadata; 4] = [PacketMetadata::EMPTY; 4];
-        let mut tx_buf: [u8; 128] = [0; 128];
-
-        let mut socket =
-            UdpSocket::new(*stack, &mut rx_meta, &mut rx_buf, &mut tx_meta, &mut tx_buf);
-
-        // Bind to any port (let OS choose)
-        if let Err(e) = socket.bind(IpListenEndpoint {
-            addr: None, // Changed from Some to None - let stack choose
-            port: 0,    // Changed from fixed port to 0 - let OS assign
-        }) {
-            info!("UDP bind failed: {:?}", e);
-            continue;
-        }
-
-        info!("Socket bound successfully");
-
-        // NTP request packet (48 bytes, first byte 0x1B)
-        // 0x1B = LI=0 (no warning), VN=3 (version 3), Mode=3 (client)
-        let mut ntp_packet = [0u8; 48];
-        ntp_packet[0] = 0x1B;
-
-        info!("Sending NTP request to {}", ntp_server);
-
-        if let Err(e) = socket.send_to(&ntp_packet, ntp_server).await {
-            error!("UDP send failed: {:?}", e);
-            continue;
-        }
+    let rng = esp_hal::rng::Rng::new();
+
+    for round in 0..ntp::NTP_ROUNDS {
+        for (i, &[a, b, c, d]) in servers.iter().enumerate() {
+            let ntp_server = IpEndpoint::new(IpAddress::v4(a, b, c, d), ntp::NTP_PORT);
+            info!(
+                "Trying NTP server #{} (round {}): {}",
+                i + 1,
+                round + 1,
+                ntp_server
+            );
+
+            // UDP socket buffers
+            let mut rx_meta: [PacketMetadata; 4] = [PacketMetadata::EMPTY; 4];
+            let mut rx_buf: [u8; 128] = [0; 128];
+            let mut tx_meta: [PacketMetadata; 4] = [PacketMetadata::EMPTY; 4];
+            let mut tx_buf: [u8; 128] = [0; 128];
+
+            let mut socket =
+                UdpSocket::new(*stack, &mut rx_meta, &mut rx_buf, &mut tx_meta, &mut tx_buf);
+
+            // Bind to any port (let OS choose)
+            if let Err(e) = socket.bind(IpListenEndpoint {
+                addr: None,
+                port: 0,
+            }) {
+                info!("UDP bind failed: {:?}", e);
+                continue;
+            }
 
-        info!("NTP request sent successfully, waiting for response...");
-        // Add timeout to recv_from (5 seconds)
-        let mut recv_buf = [0u8; 64];
-        let recv_result =
-            with_timeout(Duration::from_secs(5), socket.recv_from(&mut recv_buf)).await;
+            if let Err(e) = socket.send_to(&ntp::request_packet(), ntp_server).await {
+                error!("UDP send failed: {:?}", e);
+                continue;
+            }
 
-        match recv_result {
-            Ok(Ok((len, endpoint))) => {
-                info!("NTP response received from {} ({} bytes)", endpoint, len);
+            let timeout_ms = ntp::attempt_timeout_ms(rng.random());
+            let mut recv_buf = [0u8; 64];
+            let recv_result = with_timeout(
+                Duration::from_millis(timeout_ms),
+                socket.recv_from(&mut recv_buf),
+            )
+            .await;
 
-                if len < 48 {
-                    info!("NTP response too short: {} bytes", len);
+            match recv_result {
+                Ok(Ok((len, endpoint))) => {
+                    info!("NTP response received from {} ({} bytes)", endpoint, len);
+
+                    match ntp::parse_response(&recv_buf[..len]) {
+                        Ok(unix_time) => {
+                            info!("NTP time: {} (unix)", unix_time);
+                            return Ok(unix_time);
+                        }
+                        Err(e) => {
+                            error!("Rejected NTP response: {}", e);
+                            continue;
+                        }
+                    }
+                }
+                Ok(Err(e)) => {
+                    error!("UDP recv failed: {:?}", e);
+                    continue;
+                }
+                Err(_) => {
+                    error!("NTP request timed out after {} ms", timeout_ms);
                     continue;
                 }
-
-                // Parse NTP response (Transmit Timestamp: bytes 40..44)
-                let secs =
-                    u32::from_be_bytes([recv_buf[40], recv_buf[41], recv_buf[42], recv_buf[43]]);
-                // NTP epoch starts in 1900, Unix in 1970
-                let unix_time = secs.wrapping_sub(2_208_988_800);
-                info!("NTP time: {} (unix)", unix_time);
-
-                return Ok(unix_time);
-            }
-            Ok(Err(e)) => {
-                error!("UDP recv failed: {:?}", e);
-                continue;
-            }
-            Err(_) => {
-                error!("NTP request timed out after 5 seconds");
-                continue;
             }
         }
     }
@@ -265,53 +314,75 @@ impl embedded_sdmmc::TimeSource for SimpleTimeSource {
         }
     }
 }
+/// Connects the WiFi controller to candidate networks for `connect_first`
+struct WifiConnector<'c> {
+    controller: &'c mut WifiController<'static>,
+}
+
+impl NetworkConnector for WifiConnector<'_> {
+    type Error = esp_radio::wifi::WifiError;
+
+    async fn connect(&mut self, network: &InternetConfig<'_>) -> Result<(), Self::Error> {
+        let client_config = ClientConfig::default()
+            .with_ssid(network.ssid.into())
+            .with_password(network.password.into());
+
+        self.controller
+            .set_config(&esp_radio::wifi::ModeConfig::Client(client_config))?;
+        if !self.controller.is_started()? {
+            self.controller.start_async().await?;
+        }
+        self.controller.connect_async().await
+    }
+}
+
+/// Try every configured network in order, returning whether one connected
+async fn connect_any_network(controller: &mut WifiController<'static>) -> bool {
+    let networks = wifi_secrets::wifi_networks();
+    let mut connector = WifiConnector { controller };
+
+    match connect_first(&mut connector, &networks).await {
+        Some(index) => {
+            info!("WiFi connected to {}", networks[index].ssid);
+            true
+        }
+        None => {
+            error!("WiFi connection failed for all {} networks", networks.len());
+            false
+        }
+    }
+}
+
 /// Initialize and connect WiFi
 ///
 /// This function:
 /// - Initializes the radio and WiFi peripheral
-/// - Configures WiFi client with SSID and password
-/// - Attempts to connect to the network
+/// - Tries each configured network (see `wifi_secrets::wifi_networks`) until one connects
 ///
 /// # Returns
-/// A tuple of (interfaces, wifi_connected) where:
+/// A tuple of (interfaces, controller, wifi_connected) where:
 /// - interfaces: Network interfaces
+/// - controller: WiFi controller, handed to the supervisor task for reconnects
 /// - wifi_connected: Whether connection was successful
 #[allow(clippy::large_stack_frames)]
 async fn setup_wifi(
     radio_init: &'static mut Controller<'static>,
     wifi_peripheral: esp_hal::peripherals::WIFI<'static>,
-) -> (esp_radio::wifi::Interfaces<'static>, bool) {
+) -> (
+    esp_radio::wifi::Interfaces<'static>,
+    &'static mut WifiController<'static>,
+    bool,
+) {
     info!("Configuring radio...");
     let (wifi, interfaces) = esp_radio::wifi::new(radio_init, wifi_peripheral, Default::default())
         .expect("WiFi init failed");
     let wifi = WIFI_CONTROLLER.init(wifi);
 
     info!("Radio ready");
-    info!("Connecting to WiFi SSID: {}", wifi_secrets::WIFI_SSID);
 
-    let client_config = ClientConfig::default()
-        .with_ssid(wifi_secrets::WIFI_SSID.into())
-        .with_password(wifi_secrets::WIFI_PASSWORD.into());
+    let wifi_connected = connect_any_network(wifi).await;
 
-    if let Err(e) = wifi.set_config(&esp_radio::wifi::ModeConfig::Client(client_config)) {
-        error!("WiFi configuration failed: {:?}", e);
-        return (interfaces, false);
-    }
-    if let Err(e) = wifi.start_async().await {
-        error!("WiFi start failed: {:?}", e);
-        return (interfaces, false);
-    }
-
-    let wifi_result = wifi.connect_async().await;
-    let wifi_connected = wifi_result.is_ok();
-
-    if wifi_connected {
-        info!("WiFi connected");
-    } else {
-        error!("WiFi connection failed: {:?}", wifi_result.err());
-    }
-
-    (interfaces, wifi_connected)
+    (interfaces, wifi, wifi_connected)
 }
 
 /// Setup network stack and wait for configuration
@@ -364,27 +435,75 @@ async fn setup_network_stack(
 #[allow(clippy::large_stack_frames)]
 async fn sync_time(stack: &embassy_net::Stack<'static>) -> Option<u32> {
     info!("Performing time sync...");
-    match udp_time_sync(stack).await {
+    match udp_time_sync(stack, &ntp_servers::ntp_servers()).await {
         Ok(timestamp) => {
             info!("Time sync successful: {}", timestamp);
+            log_to_display(LogLevel::Info, format_args!("[Time] Synced: {}", timestamp));
             Some(timestamp)
         }
         Err(e) => {
             error!("Time sync failed: {:?}", e);
+            log_to_display(LogLevel::Warn, format_args!("[Time] Sync failed"));
             None
         }
     }
 }
+/// Pick the boot time, falling back to the last time persisted on the SD card
+///
+/// With NTP the synced time is used and persisted right away. Without it, the
+/// clock is seeded from `estimate_time_from_last_known` so timestamps keep
+/// increasing across reboots; if nothing was persisted the time stays unknown
+/// and counts up from 0.
+///
+/// # Returns
+/// A tuple of (initial_time, time_status)
+async fn resolve_boot_time(
+    app_state: &'static ConcreteGlobalStateType,
+    synced: Option<u32>,
+) -> (u32, TimeStatus) {
+    let mut state = app_state.lock().await;
+    let Some(storage) = state.storage_manager_mut() else {
+        return synced.map_or((0, TimeStatus::Unknown), |t| (t, TimeStatus::Synced));
+    };
+
+    if let Some(t) = synced {
+        if let Err(e) = storage.persist_time_if_due(t) {
+            error!("Failed to persist synced time: {:?}", e);
+        }
+        return (t, TimeStatus::Synced);
+    }
+
+    match storage.load_last_known_time() {
+        Ok(Some(last_known)) => {
+            let estimate = estimate_time_from_last_known(last_known);
+            warn!(
+                "NTP unavailable, estimating time {} from last known {}",
+                estimate, last_known
+            );
+            (estimate, TimeStatus::Estimated)
+        }
+        Ok(None) => {
+            error!("NTP unavailable and no persisted time, timestamps start at 0");
+            (0, TimeStatus::Unknown)
+        }
+        Err(e) => {
+            error!("Failed to load last known time: {:?}", e);
+            (0, TimeStatus::Unknown)
+        }
+    }
+}
+
 /// Initialize application state with storage manager
 ///
 /// This function sets up the application state including:
 /// - SimpleTimeSource with synced time
 /// - SD card manager with time source
-/// - Storage manager with rollup loading
+/// - Storage manager with rollup loading (in RAM only if there is no card)
 /// - App state with WiFi and time status
 ///
 /// # Arguments
 /// - `sd_card`: The SD card instance
+/// - `sd_card_size`: Card size in bytes, 0 if no card was found
 /// - `time`: Optional Unix timestamp from NTP sync
 /// - `wifi_connected`: Whether WiFi connection was successful
 ///
@@ -394,6 +513,7 @@ async fn sync_time(stack: &embassy_net::Stack<'static>) -> Option<u32> {
 /// - initial_time: The Unix timestamp to use for sensor readings (0 if no sync)
 async fn setup_app_state(
     sd_card: embedded_sdmmc::SdCard<SdCardSpiDevice, DelayImpl>,
+    sd_card_size: u64,
     time: Option<u32>,
     wifi_connected: bool,
 ) -> (
@@ -405,8 +525,12 @@ async fn setup_app_state(
 ) {
     let initial_time = time.unwrap_or(0);
     let time_source = SimpleTimeSource::new(initial_time);
-    let sd_card_manager = SdCardManager::new(sd_card, time_source);
-    let mut storage_manager = StorageManager::new(sd_card_manager);
+    let mut storage_manager = if sd_card_size > 0 {
+        StorageManager::new(SdCardManager::new(sd_card, time_source))
+    } else {
+        warn!("No SD card — keeping data in RAM until reboot");
+        StorageManager::in_memory()
+    };
 
     if let Some(t) = time {
         info!("Initializing storage manager with synced time: {}", t);
@@ -418,10 +542,24 @@ async fn setup_app_state(
         error!("Storage manager initialized without time sync (using fallback)");
     }
 
+    // Restore persisted device settings (falls back to defaults on failure)
+    let device_config = match storage_manager.load_device_config() {
+        Ok(config) => config,
+        Err(e) => {
+            error!("Failed to load device config, using defaults: {:?}", e);
+            DeviceConfig::default()
+        }
+    };
+
     static APP_STATE: StaticCell<ConcreteGlobalStateType> = StaticCell::new();
     let mut app_state = AppState::new();
     app_state.wifi_connected = wifi_connected;
-    app_state.time_known = time.is_some();
+    app_state.time_status = if time.is_some() {
+        TimeStatus::Synced
+    } else {
+        TimeStatus::Unknown
+    };
+    app_state.device_config = device_config;
     app_state.run_state = if wifi_connected {
         AppRunState::WifiConnected
     } else {
@@ -499,133 +637,235 @@ async fn main(spawner: Spawner) -> ! {
     info!("Spawning concurrent initialization tasks...");
 
     // Both futures should complete around the same time
-    let ((interfaces, wifi_connected), (i2c_hardware, i2c_mux, spi_hardware)) =
+    let ((interfaces, wifi_controller, wifi_connected), (i2c_hardware, i2c_mux, spi_hardware)) =
         embassy_futures::join::join(wifi_future, hardware_future).await;
 
     info!("=== Concurrent initialization complete ===\n");
 
-    let touch_interface = i2c_hardware.touch_interface;
+    let touch_scanner = i2c_hardware.touch_scanner;
+    let power_mgmt = i2c_hardware.power_mgmt;
     let display = spi_hardware.display;
     let sd_card = spi_hardware.sd_card;
-    #[cfg(any(feature = "sensor-sht40", feature = "sensor-scd41"))]
     let sd_card_size = spi_hardware.sd_card_size;
-    #[cfg(not(any(feature = "sensor-sht40", feature = "sensor-scd41")))]
-    let _sd_card_size = spi_hardware.sd_card_size;
 
     // === Application State Setup (does NOT require WiFi) ===
     // Set up app state early so DisplayManager can reference it.
     // WiFi status and time will be updated once connectivity is resolved.
-    #[cfg(any(feature = "sensor-sht40", feature = "sensor-scd41"))]
-    let (app_state_ref, _initial_time_placeholder) = setup_app_state(sd_card, None, false).await;
-
-    #[cfg(not(any(feature = "sensor-sht40", feature = "sensor-scd41")))]
-    let (_app_state_ref, _initial_time_placeholder) = setup_app_state(sd_card, None, false).await;
+    let (app_state_ref, _initial_time_placeholder) =
+        setup_app_state(sd_card, sd_card_size, None, false).await;
 
     // === Spawn Display + Touch IMMEDIATELY ===
-    // The display starts on WifiStatus(Connecting) so the user sees
-    // feedback right away, regardless of WiFi outcome.
+    // The display starts on the splash page so the user sees boot progress
+    // right away, regardless of WiFi outcome.
 
     // Start touch polling task
-    if spawner.spawn(touch_polling_task(touch_interface)).is_err() {
+    if spawner.spawn(touch_polling_task(touch_scanner)).is_err() {
         error!("Failed to spawn touch polling task");
     }
 
-    #[cfg(any(feature = "sensor-sht40", feature = "sensor-scd41"))]
+    // Start display manager task with the persisted settings
+    let device_config = app_state_ref.lock().await.device_config;
+    let mut display_manager = DisplayManager::new(display);
+    display_manager.set_home_page_mode(device_config.home_page_mode);
+    display_manager.set_temperature_unit(device_config.temperature_unit);
+    display_manager.set_sample_interval(device_config.sample_interval_secs);
+    display_manager.set_brightness(device_config.brightness_percent);
+    display_manager.set_touch_threshold(device_config.touch_threshold);
+    display_manager.set_trend_windows(device_config.trend_windows);
+    display_manager.set_theme_mode(device_config.theme_mode);
+    display_manager.set_co2_auto_calibration(device_config.co2_auto_calibration);
+    #[cfg(feature = "log-feed-rtt")]
+    display_manager.set_log_sink(&baro_firmware::log_feed::RTT_LOG_SINK);
+    BRIGHTNESS_SIGNAL.signal(device_config.brightness_percent);
+    TOUCH_THRESHOLD_SIGNAL.signal(device_config.touch_threshold);
+    if spawner
+        .spawn(display_manager_task(display_manager, app_state_ref))
+        .is_err()
     {
-        let display_manager = DisplayManager::new(display);
-        if spawner
-            .spawn(display_manager_task(display_manager, app_state_ref))
-            .is_err()
-        {
-            error!("Failed to spawn display manager task");
-        }
+        error!("Failed to spawn display manager task");
+    }
+
+    // Start backlight task (applies the persisted brightness, then follows the slider)
+    if spawner.spawn(backlight_task(power_mgmt)).is_err() {
+        error!("Failed to spawn backlight task");
     }
 
-    #[cfg(not(any(feature = "sensor-sht40", feature = "sensor-scd41")))]
+    // Start WiFi supervisor (reconnects with backoff whenever the link drops)
+    if spawner
+        .spawn(wifi_supervisor_task(
+            wifi_controller,
+            app_state_ref,
+            wifi_connected,
+        ))
+        .is_err()
     {
-        let display_manager = DisplayManager::new(display);
-        if spawner
-            .spawn(display_manager_task(display_manager, _app_state_ref))
-            .is_err()
-        {
-            error!("Failed to spawn display manager task");
-        }
+        error!("Failed to spawn WiFi supervisor task");
     }
 
-    info!("Display now showing WiFi connecting page");
+    info!("Display now showing splash page");
 
-    // === Network Stack & Time Sync (only if WiFi connected) ===
+    // === Network Stack & Time Sync (once WiFi is connected) ===
     let display_sender = get_display_sender();
 
-    if wifi_connected {
-        let stack_ref = setup_network_stack(interfaces, &spawner).await;
-        let time = sync_time(stack_ref).await;
-        let initial_time = time.unwrap_or(0);
-
-        // Update app state with WiFi + time info
-        #[cfg(any(feature = "sensor-sht40", feature = "sensor-scd41"))]
-        {
-            let mut state = app_state_ref.lock().await;
-            state.wifi_connected = true;
-            state.time_known = time.is_some();
-            state.run_state = AppRunState::WifiConnected;
-
-            // Re-init storage with the real time if available
-            if let Some(t) = time
-                && let Some(storage) = state.storage_manager_mut()
-            {
-                match storage.init(t).await {
-                    Ok(_) => info!("Storage re-initialized with synced time: {}", t),
-                    Err(e) => error!("Storage re-init failed: {:?}", e),
-                }
-            }
-        }
+    // Warn on screen when nothing will survive a reboot
+    let storage_persistent = app_state_ref
+        .lock()
+        .await
+        .storage_manager()
+        .is_some_and(|storage| storage.is_persistent());
+    display_sender
+        .send(DisplayRequest::UpdateStoragePersistence(storage_persistent))
+        .await;
+
+    if !wifi_connected {
+        // WiFi failed — show the error page until the supervisor reconnects
+        info!("WiFi connection failed — navigating to WiFi error page");
+        set_run_state(app_state_ref, AppRunState::WifiConnecting).await;
+        display_sender
+            .send(DisplayRequest::NavigateToPage(PageId::WifiStatus))
+            .await;
 
-        // Navigate to Home page now that WiFi is up
-        info!("WiFi connected — navigating to Home page");
+        while !WIFI_LINK_SIGNAL.wait().await {}
+        info!("WiFi connected after retry — continuing startup");
         display_sender
-            .send(DisplayRequest::NavigateToPage(PageId::Home))
+            .send(DisplayRequest::NavigateToPage(PageId::Splash))
             .await;
+    }
+    set_run_state(app_state_ref, AppRunState::WifiConnected).await;
 
-        // Spawn sensor + storage tasks
-        #[cfg(any(feature = "sensor-sht40", feature = "sensor-scd41"))]
-        if sd_card_size > 0 {
-            info!("Starting sensor and storage tasks...");
+    let stack_ref = setup_network_stack(interfaces, &spawner).await;
+    set_run_state(app_state_ref, AppRunState::TimeSyncing).await;
+    let time = sync_time(stack_ref).await;
 
-            let sensors = SensorsState::new(i2c_mux);
+    #[cfg(feature = "mqtt")]
+    match baro_firmware::mqtt_secrets::mqtt_config() {
+        Some(config) => {
+            if spawner.spawn(mqtt_publish_task(stack_ref, config)).is_err() {
+                error!("Failed to spawn MQTT publish task");
+            }
+        }
+        None => warn!("MQTT enabled but MQTT_BROKER is missing or invalid"),
+    }
 
+    #[cfg(feature = "sample-stream")]
+    match baro_firmware::sample_stream::sample_stream_target() {
+        Some(target) => {
             if spawner
-                .spawn(background_sensor_reading_task(
-                    sensors,
-                    app_state_ref,
-                    initial_time,
-                ))
+                .spawn(sample_stream_task(stack_ref, target))
                 .is_err()
             {
-                error!("Failed to spawn sensor reading task");
+                error!("Failed to spawn sample stream task");
             }
+        }
+        None => warn!("Sample stream enabled but SAMPLE_STREAM_ADDR is missing or invalid"),
+    }
 
-            if spawner
-                .spawn(storage_event_processing_task(app_state_ref))
-                .is_err()
-            {
-                error!("Failed to spawn storage event processing task");
+    #[cfg(feature = "http-api")]
+    if spawner
+        .spawn(http_api_task(stack_ref, app_state_ref))
+        .is_err()
+    {
+        error!("Failed to spawn HTTP API task");
+    }
+    let (initial_time, time_status) = resolve_boot_time(app_state_ref, time).await;
+
+    // Update app state with WiFi + time info
+    #[cfg(any_sensor)]
+    let mut startup_error: Option<AppError> = None;
+    #[cfg(any_sensor)]
+    {
+        let mut state = app_state_ref.lock().await;
+        state.wifi_connected = true;
+        state.time_status = time_status;
+
+        // Re-init storage with the real (or estimated) time if available
+        if time_status.is_known()
+            && let Some(storage) = state.storage_manager_mut()
+        {
+            match storage.init(initial_time).await {
+                Ok(_) => info!(
+                    "Storage re-initialized with {:?} time: {}",
+                    time_status, initial_time
+                ),
+                Err(e) => {
+                    error!("Storage re-init failed: {:?}", e);
+                    startup_error = Some(AppError::from(e));
+                }
             }
+        }
+    }
+
+    set_run_state(app_state_ref, AppRunState::after_time_sync(time_status)).await;
+
+    // Warn on screen if timestamps cannot be trusted
+    display_sender
+        .send(DisplayRequest::UpdateTimeStatus(time_status))
+        .await;
+
+    // Drive the header clock once the wall-clock time is known
+    if time_status.is_known() && spawner.spawn(clock_tick_task(initial_time)).is_err() {
+        error!("Failed to spawn clock tick task");
+    }
+
+    // Spawn sensor + storage tasks (storage falls back to RAM without an SD card)
+    #[cfg(any_sensor)]
+    let sensors_started = {
+        info!("Starting sensor and storage tasks...");
+
+        let sensors = SensorsState::new(i2c_mux);
+
+        if spawner
+            .spawn(background_sensor_reading_task(
+                sensors,
+                app_state_ref,
+                initial_time,
+            ))
+            .is_err()
+        {
+            error!("Failed to spawn sensor reading task");
+        }
+
+        if spawner
+            .spawn(sensor_watchdog_task(app_state_ref, Instant::now()))
+            .is_err()
+        {
+            error!("Failed to spawn sensor watchdog task");
+        }
 
-            info!("Sensor and storage tasks started");
-        } else {
-            info!("Skipping sensor tasks — SD card unavailable");
+        if spawner
+            .spawn(storage_event_processing_task(app_state_ref))
+            .is_err()
+        {
+            error!("Failed to spawn storage event processing task");
         }
+
+        info!("Sensor and storage tasks started");
+        true
+    };
+
+    #[cfg(not(any_sensor))]
+    let sensors_started = {
+        info!("No sensors enabled — sensor tasks will not start");
+        false
+    };
+
+    if sensors_started {
+        // The splash page moves on to Home by itself
+        set_run_state(app_state_ref, AppRunState::SensorsRunning).await;
     } else {
-        // WiFi failed — navigate to WifiStatus(Error)
-        info!("WiFi connection failed — navigating to WiFi error page");
+        info!("Startup finished without sensors — navigating to Home page");
         display_sender
-            .send(DisplayRequest::NavigateToPage(PageId::WifiStatus))
+            .send(DisplayRequest::NavigateToPage(PageId::Home))
             .await;
     }
 
-    #[cfg(not(any(feature = "sensor-sht40", feature = "sensor-scd41")))]
-    info!("No sensors enabled — sensor tasks will not start");
+    // Explain a storage failure on screen instead of only in the log
+    #[cfg(any_sensor)]
+    if let Some(app_error) = startup_error {
+        display_sender
+            .send(DisplayRequest::ShowError(Box::new(app_error)))
+            .await;
+    }
 
     info!("All tasks spawned\n");
 
@@ -636,6 +876,304 @@ async fn main(spawner: Spawner) -> ! {
     }
 }
 
+/// Keeps the WiFi link up, reconnecting with exponential backoff
+///
+/// While connected, samples RSSI every `WIFI_RSSI_POLL_INTERVAL_SECS` until the
+/// station disconnects, then retries every configured network on the
+/// `ReconnectBackoff` schedule until one connects. Each link change updates
+/// `AppState::wifi_connected`, notifies the display, and is published on
+/// `WIFI_LINK_SIGNAL`. Retries only sleep this task, so sensors, storage, and
+/// the UI keep running while the link is down.
+#[embassy_executor::task]
+async fn wifi_supervisor_task(
+    controller: &'static mut WifiController<'static>,
+    app_state: &'static ConcreteGlobalStateType,
+    mut connected: bool,
+) {
+    info!("WiFi supervisor task started");
+    let mut backoff = ReconnectBackoff::default();
+
+    loop {
+        if connected {
+            // Sample signal strength until the station drops off the network
+            loop {
+                let disconnected = controller.wait_for_event(WifiEvent::StaDisconnected);
+                let poll = Timer::after(Duration::from_secs(WIFI_RSSI_POLL_INTERVAL_SECS));
+                match select(disconnected, poll).await {
+                    Either::First(()) => break,
+                    Either::Second(()) => publish_wifi_rssi(controller, app_state).await,
+                }
+            }
+            warn!("WiFi link lost");
+            log_to_display(LogLevel::Warn, format_args!("[WiFi] Link lost"));
+            connected = false;
+            publish_wifi_state(app_state, false).await;
+        }
+
+        let delay_secs = backoff.next_delay_secs();
+        info!(
+            "WiFi reconnect attempt {} in {}s",
+            backoff.attempts(),
+            delay_secs
+        );
+        log_to_display(
+            LogLevel::Info,
+            format_args!("[WiFi] Retry {} in {}s", backoff.attempts(), delay_secs),
+        );
+        Timer::after(Duration::from_secs(delay_secs as u64)).await;
+
+        if connect_any_network(controller).await {
+            info!("WiFi reconnected");
+            log_to_display(LogLevel::Info, format_args!("[WiFi] Reconnected"));
+            backoff.reset();
+            connected = true;
+            publish_wifi_state(app_state, true).await;
+        }
+    }
+}
+
+/// Read the current RSSI and share it with the app state and display
+async fn publish_wifi_rssi(
+    controller: &WifiController<'static>,
+    app_state: &'static ConcreteGlobalStateType,
+) {
+    match controller.rssi() {
+        Ok(rssi) => {
+            let rssi_dbm = rssi.clamp(i8::MIN as i32, i8::MAX as i32) as i8;
+            debug!("WiFi RSSI: {} dBm", rssi_dbm);
+            app_state.lock().await.wifi_rssi = Some(rssi_dbm);
+            let _ = get_display_sender().try_send(DisplayRequest::UpdateWifiRssi(rssi_dbm));
+        }
+        Err(e) => debug!("WiFi RSSI unavailable: {:?}", e),
+    }
+}
+
+/// Record a WiFi link change in the app state and notify the display and main task
+///
+/// Only called by the supervisor after the initial connection, so every call
+/// is a change the user may not have noticed and gets a toast.
+async fn publish_wifi_state(app_state: &'static ConcreteGlobalStateType, connected: bool) {
+    app_state.lock().await.wifi_connected = connected;
+    WIFI_LINK_SIGNAL.signal(connected);
+    let sender = get_display_sender();
+    let _ = sender.try_send(DisplayRequest::UpdateWifi(connected));
+    let message = if connected {
+        "WiFi reconnected"
+    } else {
+        "WiFi disconnected"
+    };
+    let _ = sender.try_send(DisplayRequest::Notify(message));
+}
+
+/// Append a status line to the monitor page's log, truncated to 64 bytes
+///
+/// Dropped if the display channel is full; the line is informational only.
+fn log_to_display(level: LogLevel, args: core::fmt::Arguments<'_>) {
+    let mut line = heapless::String::<64>::new();
+    let _ = core::fmt::write(&mut line, args);
+    let _ = get_display_sender().try_send(DisplayRequest::LogLine(level, line));
+}
+
+/// Record a boot stage in the app state and show it on the splash page
+async fn set_run_state(app_state: &'static ConcreteGlobalStateType, run_state: AppRunState) {
+    app_state.lock().await.run_state = run_state;
+    get_display_sender()
+        .send(DisplayRequest::UpdateRunState(run_state))
+        .await;
+}
+
+/// Serves `baro_core::http_api` routes, one connection at a time
+///
+/// Each connection carries a single request: the first line is routed, the
+/// response is written, and the socket is closed.
+#[cfg(feature = "http-api")]
+#[allow(clippy::large_stack_frames)]
+#[embassy_executor::task]
+async fn http_api_task(
+    stack: &'static embassy_net::Stack<'static>,
+    app_state: &'static ConcreteGlobalStateType,
+) {
+    use baro_core::http_api;
+    use baro_core::log_ring::LOG_RING;
+    use embassy_net::tcp::TcpSocket;
+    use embedded_io_async::Write as _;
+
+    info!("HTTP API listening on port {}", HTTP_API_PORT);
+
+    let mut rx_buf = [0u8; 512];
+    let mut tx_buf = [0u8; 1024];
+
+    loop {
+        let mut socket = TcpSocket::new(*stack, &mut rx_buf, &mut tx_buf);
+        socket.set_timeout(Some(Duration::from_secs(HTTP_API_TIMEOUT_SECS)));
+
+        if let Err(e) = socket.accept(HTTP_API_PORT).await {
+            warn!("HTTP accept failed: {:?}", e);
+            continue;
+        }
+
+        let mut request = [0u8; 256];
+        let len = match socket.read(&mut request).await {
+            Ok(len) => len,
+            Err(e) => {
+                warn!("HTTP read failed: {:?}", e);
+                continue;
+            }
+        };
+        let route = http_api::parse_request(&request[..len]);
+        debug!("HTTP request routed to {:?}", route);
+
+        let (status, body) = {
+            let state = app_state.lock().await;
+            http_api::respond(route, state.storage_manager(), &LOG_RING)
+        };
+
+        let mut response = alloc::string::String::new();
+        http_api::write_response(&mut response, status, route.content_type(), &body);
+        if let Err(e) = socket.write_all(response.as_bytes()).await {
+            warn!("HTTP write failed: {:?}", e);
+        }
+        let _ = socket.flush().await;
+        socket.close();
+    }
+}
+
+/// Sends every raw sample to `target` as one UDP datagram
+///
+/// The payload is the fixed 96-byte `RawSample::to_bytes` encoding. Delivery is
+/// best-effort: failed sends are logged and the sample is dropped.
+#[cfg(feature = "sample-stream")]
+#[embassy_executor::task]
+async fn sample_stream_task(
+    stack: &'static embassy_net::Stack<'static>,
+    target: core::net::SocketAddrV4,
+) {
+    use baro_core::storage::RAW_SAMPLE_BYTES;
+
+    let [a, b, c, d] = target.ip().octets();
+    let endpoint = IpEndpoint::new(IpAddress::v4(a, b, c, d), target.port());
+
+    let mut rx_meta: [PacketMetadata; 1] = [PacketMetadata::EMPTY; 1];
+    let mut rx_buf: [u8; 16] = [0; 16];
+    let mut tx_meta: [PacketMetadata; 4] = [PacketMetadata::EMPTY; 4];
+    let mut tx_buf = [0u8; 4 * RAW_SAMPLE_BYTES];
+    let mut socket = UdpSocket::new(*stack, &mut rx_meta, &mut rx_buf, &mut tx_meta, &mut tx_buf);
+
+    while let Err(e) = socket.bind(IpListenEndpoint {
+        addr: None,
+        port: 0,
+    }) {
+        warn!("Sample stream: UDP bind failed: {:?}", e);
+        Timer::after(Duration::from_secs(SAMPLE_STREAM_RETRY_DELAY_SECS)).await;
+    }
+
+    // Subscribe only once the socket can send, so a bind retry never holds a
+    // subscriber slot that nothing reads
+    let Ok(mut subscriber) = ROLLUP_CHANNEL.subscriber() else {
+        error!("Sample stream: no rollup channel subscriber slot available");
+        return;
+    };
+    info!("Streaming raw samples to {}", endpoint);
+
+    loop {
+        let RollupEvent::RawSample(sample) = subscriber.next_message_pure().await else {
+            continue;
+        };
+        if let Err(e) = socket.send_to(&sample.to_bytes(), endpoint).await {
+            warn!("Sample stream send failed: {:?}", e);
+        }
+    }
+}
+
+/// Publishes every `RollupEvent` to the configured MQTT broker at QoS 0
+///
+/// Topics and payloads come from `baro_core::mqtt::event_messages`. If the
+/// broker is unreachable or the connection drops, the task waits
+/// `MQTT_RECONNECT_DELAY_SECS` and reconnects; events published meanwhile are
+/// dropped rather than buffered. The rollup channel subscriber is only held
+/// while connected, so a dead broker never leaves a slot unread and lagging.
+#[cfg(feature = "mqtt")]
+#[allow(clippy::large_stack_frames)]
+#[embassy_executor::task]
+async fn mqtt_publish_task(
+    stack: &'static embassy_net::Stack<'static>,
+    config: baro_core::config::MqttConfig<'static>,
+) {
+    use alloc::vec::Vec;
+    use baro_core::mqtt;
+    use embassy_net::tcp::TcpSocket;
+    use embedded_io_async::Write as _;
+
+    let [a, b, c, d] = config.broker;
+    let broker = IpEndpoint::new(IpAddress::v4(a, b, c, d), config.port);
+    info!(
+        "MQTT publisher started (broker {}, prefix {})",
+        broker, config.topic_prefix
+    );
+
+    let mut rx_buf = [0u8; 256];
+    let mut tx_buf = [0u8; 1024];
+    let mut packet = Vec::new();
+
+    loop {
+        let mut socket = TcpSocket::new(*stack, &mut rx_buf, &mut tx_buf);
+        socket.set_timeout(Some(Duration::from_secs(MQTT_SOCKET_TIMEOUT_SECS)));
+
+        if let Err(e) = socket.connect(broker).await {
+            warn!("MQTT connect to {} failed: {:?}", broker, e);
+            Timer::after(Duration::from_secs(MQTT_RECONNECT_DELAY_SECS)).await;
+            continue;
+        }
+
+        packet.clear();
+        mqtt::encode_connect(
+            &mut packet,
+            config.client_id,
+            config.username,
+            config.password,
+            mqtt::MQTT_KEEP_ALIVE_SECS,
+        );
+        let mut connack = [0u8; 4];
+        let accepted = socket.write_all(&packet).await.is_ok()
+            && matches!(
+                socket.read(&mut connack).await,
+                Ok(len) if mqtt::is_connack_accepted(&connack[..len])
+            );
+        if !accepted {
+            warn!("MQTT broker {} rejected the connection", broker);
+            socket.abort();
+            Timer::after(Duration::from_secs(MQTT_RECONNECT_DELAY_SECS)).await;
+            continue;
+        }
+        info!("MQTT connected to {}", broker);
+
+        // Subscribe only now; dropped with the connection below
+        let Ok(mut subscriber) = ROLLUP_CHANNEL.subscriber() else {
+            error!("MQTT: no rollup channel subscriber slot available");
+            socket.abort();
+            Timer::after(Duration::from_secs(MQTT_RECONNECT_DELAY_SECS)).await;
+            continue;
+        };
+
+        // Raw samples always arrive within the keep-alive, so no PINGREQ is needed
+        loop {
+            let event = subscriber.next_message_pure().await;
+            packet.clear();
+            for message in mqtt::event_messages(config.topic_prefix, &event) {
+                mqtt::encode_publish(&mut packet, &message.topic, message.payload.as_bytes());
+            }
+            if let Err(e) = socket.write_all(&packet).await {
+                warn!("MQTT publish failed, reconnecting: {:?}", e);
+                break;
+            }
+        }
+        drop(subscriber);
+
+        socket.abort();
+        Timer::after(Duration::from_secs(MQTT_RECONNECT_DELAY_SECS)).await;
+    }
+}
+
 #[embassy_executor::task]
 async fn task_wifi_runner(mut runner: Runner<'static, WifiDevice<'static>>) {
     info!("WiFi runner task started");
@@ -645,9 +1183,13 @@ async fn task_wifi_runner(mut runner: Runner<'static, WifiDevice<'static>>) {
 /// Background task for reading sensors and publishing rollup events
 ///
 /// This task:
-/// 1. Reads all sensors every 10 seconds
+/// 1. Reads all sensors every sample interval (10 seconds by default)
 /// 2. Creates a RawSample with the current timestamp
 /// 3. Dispatches the sample to the accumulator via the app state
+/// 4. Publishes per-sensor health to the display when it changes
+///
+/// The interval is re-read from `AppState::device_config` on every cycle so
+/// changes made on the settings page take effect without a restart.
 #[allow(clippy::large_stack_frames)]
 #[embassy_executor::task]
 async fn background_sensor_reading_task(
@@ -660,23 +1202,81 @@ async fn background_sensor_reading_task(
         initial_unix_time
     );
 
-    let mut timestamp: u32 = initial_unix_time;
+    let started_at = Instant::now();
+    let mut next_read_at = started_at;
+    let mut last_health = SensorHealthReport::default();
+    let mut spikes = SpikeFilter::default();
 
     loop {
+        // Stamp from the clock rather than counting intervals, so slow reads
+        // and retries don't drift stored samples away from real time
+        let elapsed_secs = (Instant::now() - started_at).as_secs() as u32;
+        let timestamp = Timestamp::new(initial_unix_time)
+            .advance(elapsed_secs)
+            .secs();
         debug!("Sensor task: Starting read cycle at {}", timestamp);
+        // Copy the settings out so the lock isn't held across sensor I/O
+        let (calibration, device_config) = {
+            let state = app_state.lock().await;
+            (state.calibration, state.device_config)
+        };
+        sensors.set_co2_auto_calibration(device_config.co2_auto_calibration);
+        sensors.set_co2_temperature_offset(device_config.co2_temperature_offset_milli_c);
+
+        // Sent on the first cycle and after a settings change, never per read
+        if let Err(e) = sensors.apply_co2_settings().await {
+            error!("Failed to apply CO2 sensor settings: {:?}", e);
+        }
+
+        // A recalibration requested from the settings page runs between reads
+        if let Some(target_ppm) = CO2_RECALIBRATION_SIGNAL.try_take() {
+            match sensors.force_co2_recalibration(target_ppm).await {
+                Ok(correction) => log_to_display(
+                    LogLevel::Info,
+                    format_args!(
+                        "[CO2] Recalibrated to {} ppm ({:+} ppm)",
+                        target_ppm, correction
+                    ),
+                ),
+                Err(e) => log_to_display(
+                    LogLevel::Error,
+                    format_args!("[CO2] Recalibration failed: {}", e),
+                ),
+            }
+        }
+
         // Read all sensors
-        let values = match sensors.read_all().await {
+        let read_result = sensors.read_all(&calibration).await;
+
+        // Publish health whenever it changes so pages can flag faulty sensors
+        let health = sensors.health_report();
+        if health != last_health {
+            info!("Sensor health changed: {:?}", health);
+            get_display_sender()
+                .send(DisplayRequest::UpdateHealth(health))
+                .await;
+            last_health = health;
+        }
+
+        let mut values = match read_result {
             Ok(v) => {
                 debug!("Sensor task: Read successful");
                 v
             }
             Err(e) => {
                 error!("Sensor read error: {:?}", e);
-                Timer::after(Duration::from_secs(10)).await;
+                let interval_secs = app_state.lock().await.device_config.sample_interval_secs;
+                next_read_at = next_sample_deadline(next_read_at, interval_secs);
+                Timer::at(next_read_at).await;
                 continue;
             }
         };
 
+        // Glitched readings (0 ppm CO2, -45 °C) must never reach storage
+        reject_implausible(&mut values);
+        // ...and neither should one in-range read far from its neighbours
+        spikes.filter(&mut values);
+
         debug!(
             "Sensor readings at {} (unix time): {:?}",
             timestamp,
@@ -684,17 +1284,72 @@ async fn background_sensor_reading_task(
         );
 
         // Add sample to accumulator via app state
-        {
+        let interval_secs = {
             debug!("Sensor task: Adding sample to accumulator");
             let mut state = app_state.lock().await;
             if let Some(accumulator) = state.accumulator_mut() {
                 accumulator.add_sample(timestamp, &values).await;
             }
+            state.last_sample_at = Some(Instant::now());
             debug!("Sensor task: Sample added, accumulator updated");
-        }
+            state.device_config.sample_interval_secs
+        };
 
-        timestamp = timestamp.wrapping_add(10);
-        Timer::after(Duration::from_secs(10)).await;
+        next_read_at = next_sample_deadline(next_read_at, interval_secs);
+        Timer::at(next_read_at).await;
+    }
+}
+
+/// When the read after the one scheduled at `previous` is due
+///
+/// Reads are scheduled on a fixed grid so the time spent reading doesn't
+/// stretch the interval. A read that overran its slot starts immediately
+/// instead of firing a burst of catch-up reads.
+fn next_sample_deadline(previous: Instant, interval_secs: u32) -> Instant {
+    (previous + Duration::from_secs(interval_secs as u64)).max(Instant::now())
+}
+
+/// Supervisor that flags the sensor task when it stops storing samples
+///
+/// Every `SENSOR_WATCHDOG_POLL_SECS`, compares `AppState::last_sample_at`
+/// against the stall timeout for the current sample interval. A stall is
+/// logged and raised on the display once, then cleared once samples resume.
+#[embassy_executor::task]
+async fn sensor_watchdog_task(app_state: &'static ConcreteGlobalStateType, started_at: Instant) {
+    info!("Sensor watchdog task started");
+    let mut watchdog = SensorWatchdog::new(started_at);
+
+    loop {
+        Timer::after(Duration::from_secs(SENSOR_WATCHDOG_POLL_SECS)).await;
+
+        let (last_sample_at, interval_secs) = {
+            let state = app_state.lock().await;
+            (
+                state.last_sample_at,
+                state.device_config.sample_interval_secs,
+            )
+        };
+
+        match watchdog.check(last_sample_at, Instant::now(), interval_secs) {
+            Some(SensorWatchdogEvent::Stalled { silent_secs }) => {
+                error!("Sensor task stalled: no sample for {}s", silent_secs);
+                log_to_display(
+                    LogLevel::Error,
+                    format_args!("[Sensors] No sample for {}s", silent_secs),
+                );
+                get_display_sender()
+                    .send(DisplayRequest::UpdateSensorStalled(true))
+                    .await;
+            }
+            Some(SensorWatchdogEvent::Resumed) => {
+                info!("Sensor task resumed");
+                log_to_display(LogLevel::Info, format_args!("[Sensors] Sampling resumed"));
+                get_display_sender()
+                    .send(DisplayRequest::UpdateSensorStalled(false))
+                    .await;
+            }
+            None => {}
+        }
     }
 }
 
@@ -713,10 +1368,20 @@ async fn storage_event_processing_task(app_state: &'static ConcreteGlobalStateTy
         // Process through storage manager
         {
             let mut state = app_state.lock().await;
-            if let Some(storage) = state.storage_manager_mut()
-                && let Err(e) = storage.process_event(event).await
-            {
-                error!("Storage write failed: {:?}", e);
+            if let Some(storage) = state.storage_manager_mut() {
+                if let Err(e) = storage.process_event(event).await {
+                    error!("Storage write failed: {:?}", e);
+                }
+
+                // Each raw sample carries the current time; use it to age out old
+                // records (hourly) and to keep the last known time fresh for
+                // offline boots
+                if let RollupEvent::RawSample(sample) = event {
+                    storage.prune_if_due(sample.timestamp);
+                    if let Err(e) = storage.persist_time_if_due(sample.timestamp) {
+                        error!("Failed to persist last known time: {:?}", e);
+                    }
+                }
             }
         }
 
@@ -729,7 +1394,7 @@ async fn storage_event_processing_task(app_state: &'static ConcreteGlobalStateTy
 #[allow(clippy::large_stack_frames)]
 #[embassy_executor::task]
 async fn touch_polling_task(
-    mut touch: FT6336U<
+    mut touch_scanner: baro_core::touch_scan::TouchScanner<
         baro_core::async_i2c_bus::AsyncI2cDevice<
             'static,
             esp_hal::i2c::master::I2c<'static, esp_hal::Async>,
@@ -738,38 +1403,74 @@ async fn touch_polling_task(
 ) {
     info!("Touch polling task started");
 
+    // Last reported point while a finger is down, used for the release event
+    let mut last_touch: Option<baro_core::ui::TouchPoint> = None;
+
+    if let Err(e) = touch_scanner
+        .write_monitor_period(TOUCH_MONITOR_RATE_HZ)
+        .await
+    {
+        warn!("Touch monitor rate write failed: {:?}", e);
+    }
+
+    // Drops the controller to monitor mode after a stretch without touches
+    let mut power_policy =
+        TouchPowerPolicy::new(TOUCH_MONITOR_AFTER_SECS * 1000, Instant::now().as_millis());
+
     loop {
-        match touch.scan().await {
+        // Apply a new threshold from settings (or the persisted one at boot)
+        if let Some(threshold) = TOUCH_THRESHOLD_SIGNAL.try_take() {
+            match touch_scanner.write_touch_threshold(threshold).await {
+                Ok(()) => debug!("Touch threshold set to {}", threshold),
+                Err(e) => error!("Touch threshold update failed: {:?}", e),
+            }
+        }
+
+        match touch_scanner.read_touch_frame().await {
             Ok(touch_data) => {
-                if touch_data.touch_count > 0 {
+                let touched = touch_data.touch_count > 0;
+                if let Some(mode) = power_policy.update(Instant::now().as_millis(), touched) {
+                    match touch_scanner.write_ctrl_mode(mode).await {
+                        Ok(()) => info!("Touch controller mode: {:?}", mode),
+                        Err(e) => error!("Touch ctrl mode write failed: {:?}", e),
+                    }
+                }
+
+                if !touched {
+                    // The controller only reports active points, so a release is
+                    // the touch count dropping back to zero.
+                    if let Some(touch_point) = last_touch.take() {
+                        debug!(
+                            "Touch task: Release at ({}, {})",
+                            touch_point.x, touch_point.y
+                        );
+                        let display_sender = baro_core::display_manager::get_display_sender();
+                        let _ = display_sender.try_send(DisplayRequest::HandleTouch(
+                            baro_core::ui::TouchEvent::Release(touch_point),
+                        ));
+                    }
+                } else {
                     debug!(
                         "Touch task: Detected {} touch points",
                         touch_data.touch_count
                     );
-                    for i in 0..touch_data.touch_count as usize {
-                        let point = &touch_data.points[i];
-
+                    // The first frame of a touch is a press, later frames are drags
+                    let was_down = last_touch.is_some();
+                    for point in touch_data.iter() {
                         // Convert touch to our TouchEvent and send to display
                         let touch_point = baro_core::ui::TouchPoint {
                             x: point.x,
                             y: point.y,
                         };
 
-                        // TODO: Handle Release events properly
-                        // For now, always send a Press event
-                        let event = match point.status {
-                            TouchStatus::Touch => {
-                                debug!("Touch task: Press at ({}, {})", point.x, point.y);
-                                baro_core::ui::TouchEvent::Press(touch_point)
-                            }
-                            TouchStatus::Stream => {
-                                debug!("Touch task: Drag at ({}, {})", point.x, point.y);
-                                baro_core::ui::TouchEvent::Drag(touch_point)
-                            }
-                            _ => {
-                                debug!("Touch task: Other status at ({}, {})", point.x, point.y);
-                                baro_core::ui::TouchEvent::Press(touch_point)
-                            } // <- Release does not ever be fired (?)
+                        last_touch = Some(touch_point);
+
+                        let event = if was_down {
+                            debug!("Touch task: Drag at ({}, {})", point.x, point.y);
+                            baro_core::ui::TouchEvent::Drag(touch_point)
+                        } else {
+                            debug!("Touch task: Press at ({}, {})", point.x, point.y);
+                            baro_core::ui::TouchEvent::Press(touch_point)
                         };
 
                         let display_sender = baro_core::display_manager::get_display_sender();
@@ -783,7 +1484,56 @@ async fn touch_polling_task(
             }
         }
 
-        Timer::after(Duration::from_millis(5)).await;
+        let poll_ms = match power_policy.mode() {
+            TouchCtrlMode::KeepActive => TOUCH_POLL_ACTIVE_MS,
+            TouchCtrlMode::SwitchToMonitor => TOUCH_POLL_MONITOR_MS,
+        };
+        Timer::after(Duration::from_millis(poll_ms)).await;
+    }
+}
+
+/// Async task keeping the header clock current
+///
+/// Extends `synced_unix_time` by the monotonic time elapsed since the task
+/// started and sends it to the display, then sleeps until the next minute
+/// boundary so the clock flips on the minute.
+#[embassy_executor::task]
+async fn clock_tick_task(synced_unix_time: u32) {
+    let synced_at = Instant::now();
+    let display_sender = get_display_sender();
+
+    loop {
+        let elapsed_secs = u32::try_from(synced_at.elapsed().as_secs()).unwrap_or(u32::MAX);
+        let unix_time = Timestamp::new(synced_unix_time)
+            .advance(elapsed_secs)
+            .secs();
+
+        if display_sender
+            .try_send(DisplayRequest::UpdateTime(unix_time))
+            .is_err()
+        {
+            warn!("Display queue full, dropped clock tick");
+        }
+
+        let until_next_minute = SECS_PER_MINUTE - unix_time % SECS_PER_MINUTE;
+        Timer::after(Duration::from_secs(u64::from(until_next_minute))).await;
+    }
+}
+
+/// Async task applying backlight brightness changes
+///
+/// Waits on `BRIGHTNESS_SIGNAL`, so a burst of slider updates collapses into
+/// a single AXP2101 write with the latest level.
+#[embassy_executor::task]
+async fn backlight_task(mut power_mgmt: PowerManagementChip<'static>) {
+    info!("Backlight task started");
+
+    loop {
+        let percent = BRIGHTNESS_SIGNAL.wait().await;
+        match set_backlight_brightness(&mut power_mgmt, percent).await {
+            Ok(()) => debug!("Backlight set to {}%", percent),
+            Err(e) => error!("Backlight update failed: {:?}", e),
+        }
     }
 }
 
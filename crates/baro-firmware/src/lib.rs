@@ -10,4 +10,11 @@ extern crate alloc;
 
 pub mod app_state;
 pub mod dual_mode_pin;
+#[cfg(feature = "log-feed-rtt")]
+pub mod log_feed;
+#[cfg(feature = "mqtt")]
+pub mod mqtt_secrets;
+pub mod ntp_servers;
+#[cfg(feature = "sample-stream")]
+pub mod sample_stream;
 pub mod wifi_secrets;
@@ -0,0 +1,34 @@
+//! Mirrors the monitor page's log feed to the RTT log channel
+//!
+//! Each entry is logged as one JSON line under the `log_feed` target, e.g.
+//! `{"ts":1700000000,"level":"info","msg":"[Raw] temperature: 22.50"}`, so it
+//! can be filtered out of the regular firmware log on the host. The entry's
+//! level picks the logger level.
+
+use alloc::string::String;
+
+use baro_core::log_ring::{LogLevel, write_log_entry_json};
+use baro_core::pages::monitor::LogSink;
+
+/// Log target used for mirrored entries
+const LOG_FEED_TARGET: &str = "log_feed";
+
+/// `LogSink` that writes structured lines to the RTT logger
+pub struct RttLogSink;
+
+impl LogSink for RttLogSink {
+    fn log_entry(&self, timestamp: u64, level: LogLevel, message: &str) {
+        let mut line = String::new();
+        if write_log_entry_json(&mut line, timestamp, level, message).is_ok() {
+            let log_level = match level {
+                LogLevel::Info => log::Level::Info,
+                LogLevel::Warn => log::Level::Warn,
+                LogLevel::Error => log::Level::Error,
+            };
+            log::log!(target: LOG_FEED_TARGET, log_level, "{}", line);
+        }
+    }
+}
+
+/// Shared sink instance handed to the display manager
+pub static RTT_LOG_SINK: RttLogSink = RttLogSink;
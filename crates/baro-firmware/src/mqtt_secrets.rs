@@ -0,0 +1,40 @@
+//! MQTT broker settings baked in at build time
+//!
+//! `MQTT_BROKER` (an IPv4 address such as `192.168.1.10`) is required for the
+//! publisher to run. `MQTT_PORT`, `MQTT_CLIENT_ID`, `MQTT_USERNAME`,
+//! `MQTT_PASSWORD`, and `MQTT_TOPIC_PREFIX` are optional and fall back to
+//! `MqttConfig::default()`.
+
+use baro_core::config::MqttConfig;
+
+const MQTT_BROKER: Option<&str> = option_env!("MQTT_BROKER");
+const MQTT_PORT: Option<&str> = option_env!("MQTT_PORT");
+const MQTT_CLIENT_ID: Option<&str> = option_env!("MQTT_CLIENT_ID");
+const MQTT_USERNAME: Option<&str> = option_env!("MQTT_USERNAME");
+const MQTT_PASSWORD: Option<&str> = option_env!("MQTT_PASSWORD");
+const MQTT_TOPIC_PREFIX: Option<&str> = option_env!("MQTT_TOPIC_PREFIX");
+
+/// Parse a dotted-quad IPv4 address
+fn parse_ipv4(s: &str) -> Option<[u8; 4]> {
+    let mut octets = [0u8; 4];
+    let mut parts = s.trim().split('.');
+    for octet in &mut octets {
+        *octet = parts.next()?.parse().ok()?;
+    }
+    parts.next().is_none().then_some(octets)
+}
+
+/// Broker settings, or `None` if no valid broker address was configured
+pub fn mqtt_config() -> Option<MqttConfig<'static>> {
+    let defaults = MqttConfig::default();
+    Some(MqttConfig {
+        broker: parse_ipv4(MQTT_BROKER?)?,
+        port: MQTT_PORT
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(defaults.port),
+        client_id: MQTT_CLIENT_ID.unwrap_or(defaults.client_id),
+        username: MQTT_USERNAME.unwrap_or(defaults.username),
+        password: MQTT_PASSWORD.unwrap_or(defaults.password),
+        topic_prefix: MQTT_TOPIC_PREFIX.unwrap_or(defaults.topic_prefix),
+    })
+}
@@ -0,0 +1,22 @@
+//! NTP server list baked in at build time
+//!
+//! `NTP_SERVERS` is an optional comma-separated list of IPv4 addresses (such
+//! as `192.168.1.1,162.159.200.1`), tried in order. Without it, or if it does
+//! not parse, time sync uses `ntp::DEFAULT_NTP_SERVERS`.
+
+use baro_core::ntp::{self, DEFAULT_NTP_SERVERS, MAX_NTP_SERVERS};
+use heapless::Vec;
+use log::warn;
+
+const NTP_SERVERS: Option<&str> = option_env!("NTP_SERVERS");
+
+/// Servers to sync time against, in the order to try them
+pub fn ntp_servers() -> Vec<[u8; 4], MAX_NTP_SERVERS> {
+    if let Some(list) = NTP_SERVERS {
+        match ntp::parse_server_list(list) {
+            Some(servers) => return servers,
+            None => warn!("NTP_SERVERS is invalid, using the default servers"),
+        }
+    }
+    DEFAULT_NTP_SERVERS.iter().copied().collect()
+}
@@ -0,0 +1,15 @@
+//! Target of the raw-sample UDP stream, baked in at build time
+//!
+//! `SAMPLE_STREAM_ADDR` (an IPv4 socket address such as `192.168.1.20:7878`)
+//! is required for the stream to run. Each `RawSample` is sent as one datagram
+//! holding its `RawSample::to_bytes` encoding, which the desktop simulator can
+//! receive to mirror a live device.
+
+use core::net::SocketAddrV4;
+
+const SAMPLE_STREAM_ADDR: Option<&str> = option_env!("SAMPLE_STREAM_ADDR");
+
+/// Stream destination, or `None` if no valid address was configured
+pub fn sample_stream_target() -> Option<SocketAddrV4> {
+    SAMPLE_STREAM_ADDR?.trim().parse().ok()
+}
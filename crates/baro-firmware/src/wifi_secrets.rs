@@ -1,2 +1,35 @@
+//! WiFi credentials baked in at build time
+//!
+//! `WIFI_SSID`/`WIFI_PASSWORD` are required. Up to two fallback networks can
+//! be added with `WIFI_SSID_2`/`WIFI_PASSWORD_2` and `WIFI_SSID_3`/`WIFI_PASSWORD_3`;
+//! they are tried in that order when the primary network is unavailable.
+
+use alloc::vec::Vec;
+use baro_core::config::InternetConfig;
+
 pub const WIFI_SSID: &str = env!("WIFI_SSID");
 pub const WIFI_PASSWORD: &str = env!("WIFI_PASSWORD");
+
+const FALLBACK_NETWORKS: [(Option<&str>, Option<&str>); 2] = [
+    (option_env!("WIFI_SSID_2"), option_env!("WIFI_PASSWORD_2")),
+    (option_env!("WIFI_SSID_3"), option_env!("WIFI_PASSWORD_3")),
+];
+
+/// Candidate networks in connection order (primary first)
+///
+/// A fallback without a password is treated as an open network.
+pub fn wifi_networks() -> Vec<InternetConfig<'static>> {
+    let primary = InternetConfig {
+        ssid: WIFI_SSID,
+        password: WIFI_PASSWORD,
+    };
+
+    core::iter::once(primary)
+        .chain(FALLBACK_NETWORKS.iter().filter_map(|&(ssid, password)| {
+            Some(InternetConfig {
+                ssid: ssid.filter(|s| !s.is_empty())?,
+                password: password.unwrap_or(""),
+            })
+        }))
+        .collect()
+}
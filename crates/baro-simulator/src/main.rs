@@ -3,6 +3,10 @@
 //! Renders baro-core pages in an SDL2 window via `embedded-graphics-simulator`.
 //! Generates synthetic sensor data so pages can be exercised without hardware.
 //!
+//! Set `BARO_SIM_UDP_BIND` (e.g. `0.0.0.0:7878`) to mirror a live device
+//! instead: the simulator then listens for the raw samples streamed by
+//! firmware built with the `sample-stream` feature.
+//!
 //! # Key bindings
 //!
 //! | Key | Action                       |
@@ -16,10 +20,37 @@
 //! | 7   | WiFi status                  |
 //! | 8   | Home Grid page               |
 //! | 9   | Monitor page                 |
+//! | T   | Toggle temperature fault     |
+//! | H   | Toggle humidity fault        |
+//! | C   | Toggle CO₂ fault             |
+//! | P   | Cycle mock data profile      |
 //! | Q   | Quit                         |
 //!
+//! A faulted channel reads as missing in mock samples and history, the same
+//! as a dead sensor on the device. Faults do not affect streamed samples.
+//!
 //! Mouse clicks are forwarded as touch events.
+//!
+//! # Headless rendering
+//!
+//! `baro-simulator --render <page> <out.png>` draws a single frame of the
+//! named page (e.g. `home`, `trend-temperature`, `settings`) to a PNG at
+//! native resolution and exits without opening a window.
+//!
+//! # Reproducible data
+//!
+//! `--seed <u64>` shifts the phase of every mock signal by an amount derived
+//! from the seed, and `--epoch <unix secs>` replaces the wall clock with a
+//! virtual one starting at that time. With both set, every run (and every
+//! `--render`) produces identical samples and timestamps.
+//!
+//! `--profile <name>` picks the mock data shape (`normal`, `high-co2`,
+//! `volatile`), and `--drift <scale>` / `--noise <scale>` multiply the slow
+//! and fast components of every signal on top of it.
 
+use std::io::ErrorKind;
+use std::net::UdpSocket;
+use std::path::Path;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use embedded_graphics::pixelcolor::Rgb565;
@@ -28,20 +59,23 @@ use embedded_graphics::primitives::Rectangle;
 use embedded_graphics_simulator::{
     OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window, sdl2::Keycode,
 };
-use log::info;
+use log::{info, warn};
 
-use baro_core::config::{HomePageMode, TemperatureUnit};
-use baro_core::pages::home::grid::HomeGridPage;
-use baro_core::pages::monitor::MonitorPage;
+use baro_core::config::{
+    DEFAULT_BRIGHTNESS_PERCENT, DEFAULT_SAMPLE_INTERVAL_SECS, DEFAULT_TOUCH_THRESHOLD,
+    DEFAULT_TREND_WINDOWS, HomePageMode, TemperatureUnit, ThemeMode,
+};
+use baro_core::metrics::fixed::{to_float, to_milli};
 use baro_core::pages::page::Page;
-use baro_core::pages::settings::DisplaySettingsPage;
-use baro_core::pages::wifi_status::WifiState;
-use baro_core::pages::{HomePage, PageWrapper, SettingsPage, TrendPage, WifiStatusPage};
+use baro_core::pages::{self, PageContext, PageWrapper};
 use baro_core::sensor_store::SensorDataStore;
-use baro_core::sensors::SensorType;
-use baro_core::storage::{RawSample, TimeWindow};
+use baro_core::sensors::{SensorCalibration, SensorType};
+use baro_core::storage::{
+    HistoryData, MAX_SENSORS, MISSING_SENSOR_VALUE, RAW_SAMPLE_BYTES, RawSample, RollupTier,
+    TimeWindow,
+};
 use baro_core::ui::{
-    Action, DISPLAY_HEIGHT_PX, DISPLAY_WIDTH_PX, PageEvent, PageId, SensorData, TouchEvent,
+    Action, LOGICAL_DISPLAY_SIZE, PageEvent, PageId, SensorData, SystemEvent, Theme, TouchEvent,
     TouchPoint,
 };
 
@@ -61,6 +95,161 @@ const FRAME_DURATION: Duration = Duration::from_millis(33);
 /// Interval between synthetic sensor samples.
 const MOCK_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
 
+/// Environment variable naming the local address to receive streamed samples on.
+const UDP_BIND_ENV: &str = "BARO_SIM_UDP_BIND";
+
+/// Command-line flag selecting headless render-to-PNG mode.
+const RENDER_FLAG: &str = "--render";
+
+/// Command-line flag seeding the mock signal phases.
+const SEED_FLAG: &str = "--seed";
+
+/// Command-line flag pinning the mock clock to a fixed start epoch.
+const EPOCH_FLAG: &str = "--epoch";
+
+/// Command-line flag selecting a named mock data profile.
+const PROFILE_FLAG: &str = "--profile";
+
+/// Command-line flag scaling the slow drift of every mock signal.
+const DRIFT_FLAG: &str = "--drift";
+
+/// Command-line flag scaling the fast noise of every mock signal.
+const NOISE_FLAG: &str = "--noise";
+
+/// Largest phase shift a seed can apply to the mock signals, in seconds.
+///
+/// Longer than the slowest signal period so seeds spread across a full cycle.
+const MAX_SEED_PHASE_SECS: u64 = 3_600;
+
+// ---------------------------------------------------------------------------
+// Mock data profiles
+// ---------------------------------------------------------------------------
+
+/// One synthetic signal: a slow sinusoidal drift plus a faster ripple.
+#[derive(Debug, Clone, Copy)]
+struct MockSignal {
+    /// Centre value the signal oscillates around.
+    base: f64,
+    /// Amplitude of the slow drift.
+    drift: f64,
+    /// Time constant of the slow drift, in seconds per radian.
+    drift_period_secs: f64,
+    /// Amplitude of the fast ripple.
+    noise: f64,
+    /// Time constant of the fast ripple, in seconds per radian.
+    noise_period_secs: f64,
+}
+
+impl MockSignal {
+    const fn new(
+        base: f64,
+        drift: f64,
+        drift_period_secs: f64,
+        noise: f64,
+        noise_period_secs: f64,
+    ) -> Self {
+        Self {
+            base,
+            drift,
+            drift_period_secs,
+            noise,
+            noise_period_secs,
+        }
+    }
+
+    /// Signal value at time `t`, with drift and noise scaled as given.
+    fn at(&self, t: f64, drift_scale: f64, noise_scale: f64) -> f64 {
+        self.base
+            + drift_scale * self.drift * (t / self.drift_period_secs).sin()
+            + noise_scale * self.noise * (t / self.noise_period_secs).cos()
+    }
+}
+
+/// Shape of the mock data: one signal per sensor plus global scale factors.
+#[derive(Debug, Clone, Copy)]
+struct MockProfile {
+    /// Name accepted by `--profile` and shown when cycling.
+    name: &'static str,
+    temperature: MockSignal,
+    humidity: MockSignal,
+    co2: MockSignal,
+    lux: MockSignal,
+    pressure: MockSignal,
+    /// Multiplier on every signal's drift amplitude.
+    drift_scale: f64,
+    /// Multiplier on every signal's noise amplitude.
+    noise_scale: f64,
+}
+
+impl MockProfile {
+    /// Comfortable indoor air: every reading stays in its excellent band.
+    const NORMAL: Self = Self {
+        name: "normal",
+        // 20–26 °C
+        temperature: MockSignal::new(23.0, 3.0, 120.0, 0.5, 37.0),
+        // 40–60 %
+        humidity: MockSignal::new(50.0, 10.0, 180.0, 2.0, 23.0),
+        // 400–800 ppm
+        co2: MockSignal::new(600.0, 200.0, 300.0, 30.0, 41.0),
+        // 200–600 lux
+        lux: MockSignal::new(400.0, 200.0, 240.0, 50.0, 31.0),
+        // ~1007–1019 hPa
+        pressure: MockSignal::new(1013.0, 6.0, 600.0, 0.4, 47.0),
+        drift_scale: 1.0,
+        noise_scale: 1.0,
+    };
+
+    /// Poorly ventilated room: CO₂ sits well inside the bad band.
+    const HIGH_CO2: Self = Self {
+        name: "high-co2",
+        // 1600–2000 ppm
+        co2: MockSignal::new(1800.0, 150.0, 300.0, 50.0, 41.0),
+        ..Self::NORMAL
+    };
+
+    /// Large, fast swings on every channel to stress graph auto-scaling.
+    const VOLATILE: Self = Self {
+        name: "volatile",
+        drift_scale: 3.0,
+        noise_scale: 4.0,
+        ..Self::NORMAL
+    };
+
+    /// Every named profile, in `P`-key cycling order.
+    const ALL: [Self; 3] = [Self::NORMAL, Self::HIGH_CO2, Self::VOLATILE];
+
+    /// Look up a profile by its `--profile` name.
+    fn by_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|profile| profile.name == name)
+    }
+
+    /// The named profile after this one, wrapping around.
+    fn next(&self) -> Self {
+        let pos = Self::ALL
+            .iter()
+            .position(|profile| profile.name == self.name)
+            .unwrap_or(0);
+        Self::ALL[(pos + 1) % Self::ALL.len()]
+    }
+
+    /// Scale the drift of every signal.
+    fn with_drift_scale(mut self, scale: f64) -> Self {
+        self.drift_scale = scale;
+        self
+    }
+
+    /// Scale the noise of every signal.
+    fn with_noise_scale(mut self, scale: f64) -> Self {
+        self.noise_scale = scale;
+        self
+    }
+
+    /// Value of `signal` at time `t` under this profile's scaling.
+    fn value(&self, signal: &MockSignal, t: f64) -> f64 {
+        signal.at(t, self.drift_scale, self.noise_scale)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Mock data generation
 // ---------------------------------------------------------------------------
@@ -69,40 +258,101 @@ const MOCK_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
 struct MockSensorGenerator {
     /// Monotonic seconds counter used as the fake "epoch".
     elapsed_secs: f64,
+    /// Seed-derived offset added to the signal time, in seconds.
+    phase_secs: f64,
+    /// Virtual clock start; `None` stamps samples with the wall clock.
+    fixed_epoch: Option<u64>,
+    /// Channels that emit the missing sentinel, indexed by sensor index.
+    faults: [bool; MAX_SENSORS],
+    /// Shape of the generated signals.
+    profile: MockProfile,
 }
 
 impl MockSensorGenerator {
     fn new() -> Self {
-        Self { elapsed_secs: 0.0 }
+        Self {
+            elapsed_secs: 0.0,
+            phase_secs: 0.0,
+            fixed_epoch: None,
+            faults: [false; MAX_SENSORS],
+            profile: MockProfile::NORMAL,
+        }
     }
 
-    /// Advance the internal clock and return a new sample.
-    fn next_sample(&mut self, dt_secs: f64) -> SensorData {
-        self.elapsed_secs += dt_secs;
-        let t = self.elapsed_secs;
+    /// Generate signals shaped by `profile`.
+    fn with_profile(mut self, profile: MockProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Switch to `profile` from the next sample on.
+    fn set_profile(&mut self, profile: MockProfile) {
+        self.profile = profile;
+    }
+
+    /// Profile currently shaping the signals.
+    fn profile(&self) -> &MockProfile {
+        &self.profile
+    }
+
+    /// Flip the injected fault on `sensor`, returning whether it is now faulted.
+    fn toggle_fault(&mut self, sensor: SensorType) -> bool {
+        let faulted = &mut self.faults[sensor.index()];
+        *faulted = !*faulted;
+        *faulted
+    }
+
+    /// Whether `sensor` currently reads as missing.
+    fn is_faulted(&self, sensor: SensorType) -> bool {
+        self.faults[sensor.index()]
+    }
+
+    /// `value`, or `None` if `sensor` is faulted.
+    fn unless_faulted(&self, sensor: SensorType, value: f64) -> Option<f32> {
+        (!self.is_faulted(sensor)).then_some(value as f32)
+    }
+
+    /// Shift every signal by a phase derived from `seed`.
+    fn with_seed(mut self, seed: u64) -> Self {
+        self.phase_secs = (splitmix64(seed) % MAX_SEED_PHASE_SECS) as f64;
+        self
+    }
 
-        // Temperature: 20–26 °C sinusoidal with slow drift
-        let temperature = 23.0 + 3.0 * (t / 120.0).sin() + 0.5 * (t / 37.0).cos();
+    /// Stamp samples from a virtual clock starting at `epoch_secs`.
+    fn with_fixed_epoch(mut self, epoch_secs: u64) -> Self {
+        self.fixed_epoch = Some(epoch_secs);
+        self
+    }
 
-        // Humidity: 40–60 % with different period
-        let humidity = 50.0 + 10.0 * (t / 180.0).sin() + 2.0 * (t / 23.0).cos();
+    /// Current time in Unix seconds, from the virtual clock if one is set.
+    fn now_secs(&self) -> u64 {
+        match self.fixed_epoch {
+            Some(epoch) => epoch + self.elapsed_secs as u64,
+            None => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        }
+    }
 
-        // CO₂: 400–800 ppm with a longer cycle
-        let co2 = 600.0 + 200.0 * (t / 300.0).sin() + 30.0 * (t / 41.0).cos();
+    /// Advance the internal clock and return a new sample.
+    fn next_sample(&mut self, dt_secs: f64) -> SensorData {
+        self.elapsed_secs += dt_secs;
+        let t = self.elapsed_secs + self.phase_secs;
+        let profile = &self.profile;
 
-        // Lux: 200–600 lux with a medium cycle
-        let lux = 400.0 + 200.0 * (t / 240.0).sin() + 50.0 * (t / 31.0).cos();
+        let temperature = profile.value(&profile.temperature, t);
+        let humidity = profile.value(&profile.humidity, t);
+        let co2 = profile.value(&profile.co2, t);
+        let lux = profile.value(&profile.lux, t);
 
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
+        let timestamp = self.now_secs();
 
         SensorData {
-            temperature: Some(temperature as f32),
-            humidity: Some(humidity as f32),
-            co2: Some(co2 as f32),
-            lux: Some(lux as f32),
+            temperature: self.unless_faulted(SensorType::Temperature, temperature),
+            humidity: self.unless_faulted(SensorType::Humidity, humidity),
+            co2: self.unless_faulted(SensorType::Co2, co2),
+            lux: self.unless_faulted(SensorType::Lux, lux),
             timestamp,
         }
     }
@@ -120,16 +370,14 @@ impl MockSensorGenerator {
         (0..count)
             .map(|i| {
                 let ts = start_ts + (i as u32) * interval_secs;
-                let t = ts as f64;
+                let t = ts as f64 + self.phase_secs;
+                let profile = &self.profile;
 
-                let temp_mc =
-                    ((23.0 + 3.0 * (t / 120.0).sin() + 0.5 * (t / 37.0).cos()) * 1000.0) as i32;
-                let hum_mp =
-                    ((50.0 + 10.0 * (t / 180.0).sin() + 2.0 * (t / 23.0).cos()) * 1000.0) as i32;
-                let co2_mp =
-                    ((600.0 + 200.0 * (t / 300.0).sin() + 30.0 * (t / 41.0).cos()) * 1000.0) as i32;
-                let lux_ml =
-                    ((400.0 + 200.0 * (t / 240.0).sin() + 50.0 * (t / 31.0).cos()) * 1000.0) as i32;
+                let temp_mc = to_milli(profile.value(&profile.temperature, t) as f32);
+                let hum_mp = to_milli(profile.value(&profile.humidity, t) as f32);
+                let co2_mp = to_milli(profile.value(&profile.co2, t) as f32);
+                let lux_ml = to_milli(profile.value(&profile.lux, t) as f32);
+                let press_mh = to_milli(profile.value(&profile.pressure, t) as f32);
 
                 let mut sample = RawSample::default();
                 sample.timestamp = ts;
@@ -137,6 +385,13 @@ impl MockSensorGenerator {
                 sample.values[baro_core::sensors::HUMIDITY] = hum_mp;
                 sample.values[baro_core::sensors::CO2] = co2_mp;
                 sample.values[baro_core::sensors::LUX] = lux_ml;
+                sample.values[baro_core::sensors::PRESSURE] = press_mh;
+
+                for (value, &faulted) in sample.values.iter_mut().zip(&self.faults) {
+                    if faulted {
+                        *value = MISSING_SENSOR_VALUE;
+                    }
+                }
 
                 sample
             })
@@ -144,132 +399,215 @@ impl MockSensorGenerator {
     }
 }
 
+/// SplitMix64 finalizer, used to spread small seeds across the phase range.
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+// ---------------------------------------------------------------------------
+// Live data sources
+// ---------------------------------------------------------------------------
+
+/// Receives raw samples streamed from a device over UDP.
+///
+/// Each datagram carries one [`RawSample::to_bytes`] record; anything else is
+/// logged and dropped.
+struct UdpSampleReceiver {
+    socket: UdpSocket,
+}
+
+impl UdpSampleReceiver {
+    /// Bind a non-blocking socket to `addr`.
+    fn bind(addr: &str) -> std::io::Result<Self> {
+        let socket = UdpSocket::bind(addr)?;
+        socket.set_nonblocking(true)?;
+        Ok(Self { socket })
+    }
+
+    /// Drain every datagram received since the last call.
+    fn poll(&mut self) -> Vec<SensorData> {
+        // One spare byte so oversized datagrams are detected instead of truncated
+        let mut buf = [0u8; RAW_SAMPLE_BYTES + 1];
+        let mut samples = Vec::new();
+
+        loop {
+            match self.socket.recv_from(&mut buf) {
+                Ok((len, from)) => match RawSample::from_bytes(&buf[..len]) {
+                    Some(sample) => samples.push(sensor_data_from_raw(&sample)),
+                    None => warn!("Ignoring {}-byte datagram from {}", len, from),
+                },
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) => {
+                    warn!("UDP receive failed: {}", e);
+                    break;
+                }
+            }
+        }
+
+        samples
+    }
+}
+
+/// Convert a streamed sample from milli-units to display units.
+fn sensor_data_from_raw(sample: &RawSample) -> SensorData {
+    let value = |index: usize| {
+        let milli = sample.values[index];
+        (milli != MISSING_SENSOR_VALUE).then(|| to_float(milli))
+    };
+
+    SensorData {
+        temperature: value(baro_core::sensors::TEMPERATURE),
+        humidity: value(baro_core::sensors::HUMIDITY),
+        co2: value(baro_core::sensors::CO2),
+        lux: value(baro_core::sensors::LUX),
+        timestamp: sample.timestamp as u64,
+    }
+}
+
+/// Where live sensor updates come from.
+enum SampleSource {
+    /// Synthetic readings from [`MockSensorGenerator`] every [`MOCK_SAMPLE_INTERVAL`].
+    Mock { last_sample: Instant },
+    /// Samples streamed from a device.
+    Udp(UdpSampleReceiver),
+}
+
+impl SampleSource {
+    /// Use the UDP receiver if [`UDP_BIND_ENV`] is set, otherwise mock data.
+    fn from_env() -> Self {
+        let Ok(addr) = std::env::var(UDP_BIND_ENV) else {
+            return Self::Mock {
+                last_sample: Instant::now(),
+            };
+        };
+
+        match UdpSampleReceiver::bind(&addr) {
+            Ok(receiver) => {
+                info!("Receiving streamed samples on {}", addr);
+                Self::Udp(receiver)
+            }
+            Err(e) => {
+                warn!("Failed to bind {} ({}), using mock data", addr, e);
+                Self::Mock {
+                    last_sample: Instant::now(),
+                }
+            }
+        }
+    }
+
+    /// Collect the samples that are due this frame.
+    fn poll(&mut self, sensor_gen: &mut MockSensorGenerator) -> Vec<SensorData> {
+        match self {
+            Self::Mock { last_sample } => {
+                if last_sample.elapsed() < MOCK_SAMPLE_INTERVAL {
+                    return Vec::new();
+                }
+                *last_sample = Instant::now();
+                vec![sensor_gen.next_sample(MOCK_SAMPLE_INTERVAL.as_secs_f64())]
+            }
+            Self::Udp(receiver) => receiver.poll(),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Page helpers
 // ---------------------------------------------------------------------------
 
-/// Full-screen bounding rectangle.
+/// Full-screen bounding rectangle, in logical (rotated) coordinates.
+///
+/// The simulator window shows the screen as the user sees it in the
+/// enclosure, so it uses the logical size and needs no rotation transform.
 fn screen_bounds() -> Rectangle {
-    Rectangle::new(
-        Point::zero(),
-        Size::new(DISPLAY_WIDTH_PX as u32, DISPLAY_HEIGHT_PX as u32),
-    )
+    Rectangle::new(Point::zero(), LOGICAL_DISPLAY_SIZE)
 }
 
-/// Current home page mode for the simulator (mutable state).
-static mut SIM_HOME_PAGE_MODE: HomePageMode = HomePageMode::Outdoor;
+/// Settings changed from the settings pages, kept in place of the
+/// firmware's persisted device config.
+struct SimSettings {
+    home_page_mode: HomePageMode,
+    temperature_unit: TemperatureUnit,
+    sample_interval_secs: u32,
+    brightness_percent: u8,
+    touch_threshold: u8,
+    co2_auto_calibration: bool,
+    calibration: SensorCalibration,
+    theme_mode: ThemeMode,
+}
 
-/// Current temperature unit for the simulator (mutable state).
-static mut SIM_TEMP_UNIT: TemperatureUnit = TemperatureUnit::Celsius;
+impl Default for SimSettings {
+    fn default() -> Self {
+        Self {
+            home_page_mode: HomePageMode::Outdoor,
+            temperature_unit: TemperatureUnit::Celsius,
+            sample_interval_secs: DEFAULT_SAMPLE_INTERVAL_SECS,
+            brightness_percent: DEFAULT_BRIGHTNESS_PERCENT,
+            touch_threshold: DEFAULT_TOUCH_THRESHOLD,
+            co2_auto_calibration: true,
+            calibration: SensorCalibration::identity(),
+            theme_mode: ThemeMode::Dark,
+        }
+    }
+}
 
 /// Create a new page of the given kind, optionally pre-loaded with history.
 fn create_page(
     page_id: PageId,
+    settings: &SimSettings,
     sensor_gen: &mut MockSensorGenerator,
     sensor_store: &SensorDataStore,
 ) -> PageWrapper {
     let bounds = screen_bounds();
+    let theme = Theme::for_mode(settings.theme_mode);
+    let context = PageContext {
+        theme: &theme,
+        home_page_mode: settings.home_page_mode,
+        temperature_unit: settings.temperature_unit,
+        sample_interval_secs: settings.sample_interval_secs,
+        brightness_percent: settings.brightness_percent,
+        touch_threshold: settings.touch_threshold,
+        trend_windows: DEFAULT_TREND_WINDOWS,
+        co2_auto_calibration: settings.co2_auto_calibration,
+        calibration: &settings.calibration,
+        sensor_store,
+        log_sink: None,
+    };
 
-    match page_id {
-        PageId::Home => {
-            // Navigate to the correct home page based on current mode
-            // SAFETY: single-threaded simulator, no data races
-            let mode = unsafe { SIM_HOME_PAGE_MODE };
-            match mode {
-                HomePageMode::Outdoor => {
-                    let mut page = HomePage::new(bounds);
-                    page.init();
-                    page.load_from_store(sensor_store);
-                    PageWrapper::Home(Box::new(page))
-                }
-                HomePageMode::Home => {
-                    let mut page = HomeGridPage::new(bounds);
-                    page.load_from_store(sensor_store);
-                    PageWrapper::HomeGrid(Box::new(page))
-                }
-            }
-        }
-        PageId::HomeGrid => {
-            let mut page = HomeGridPage::new(bounds);
-            page.load_from_store(sensor_store);
-            PageWrapper::HomeGrid(Box::new(page))
-        }
-        PageId::Settings => {
-            let mut page = SettingsPage::new(bounds);
-            page.init();
-            PageWrapper::Settings(Box::new(page))
-        }
-        PageId::DisplaySettings => {
-            // SAFETY: single-threaded simulator
-            let mode = unsafe { SIM_HOME_PAGE_MODE };
-            let temp_unit = unsafe { SIM_TEMP_UNIT };
-            PageWrapper::DisplaySettings(Box::new(DisplaySettingsPage::new(
-                bounds, mode, temp_unit,
-            )))
-        }
-        PageId::Monitor => {
-            let mut page = MonitorPage::new(bounds);
-            page.init();
-            page.load_from_store(sensor_store);
-            PageWrapper::Monitor(Box::new(page))
-        }
-        PageId::TrendTemperature => create_trend_page(
-            bounds,
-            SensorType::Temperature,
-            TimeWindow::FiveMinutes,
-            sensor_gen,
-        ),
-        PageId::TrendHumidity => create_trend_page(
-            bounds,
-            SensorType::Humidity,
-            TimeWindow::OneHour,
-            sensor_gen,
-        ),
-        PageId::TrendCo2 => create_trend_page(
-            bounds,
-            SensorType::Co2,
-            TimeWindow::ThirtyMinutes,
-            sensor_gen,
-        ),
-        PageId::TrendLux => create_trend_page(
-            bounds,
-            SensorType::Lux,
-            TimeWindow::ThirtyMinutes,
-            sensor_gen,
-        ),
-        PageId::WifiStatus => {
-            PageWrapper::WifiStatus(Box::new(WifiStatusPage::new(WifiState::Error)))
-        }
-        // Fallback: show home for any unhandled page ID
-        _ => {
-            let mut page = HomePage::new(bounds);
-            page.init();
-            PageWrapper::Home(Box::new(page))
+    // Fallback: show home for any page ID without a page of its own
+    let mut page = pages::build(page_id, bounds, &context).unwrap_or_else(|| {
+        pages::build(PageId::Home, bounds, &context).expect("home page always builds")
+    });
+
+    // Answer the page's activation requests with synthetic data
+    page.on_activate();
+    while let Some(action) = page.take_action() {
+        if let (Action::RequestHistory { window, .. }, PageWrapper::TrendPage(trend)) =
+            (action, &mut page)
+        {
+            trend.load_history(
+                RollupTier::RawSample,
+                &HistoryData::RawSamples(synthetic_history(window, sensor_gen)),
+            );
         }
     }
+
+    page
 }
 
-/// Create a [`TrendPage`] pre-loaded with synthetic historical data.
-fn create_trend_page(
-    bounds: Rectangle,
-    sensor: SensorType,
+/// Generate raw samples covering `window`, ending at the generator's now.
+fn synthetic_history(
     window: TimeWindow,
     sensor_gen: &mut MockSensorGenerator,
-) -> PageWrapper {
-    let mut page = TrendPage::new(bounds, sensor, window);
-
-    let now_ts = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs() as u32;
+) -> alloc::vec::Vec<RawSample> {
+    let now_ts = sensor_gen.now_secs() as u32;
 
     // Generate enough history to fill the requested window
     let sample_interval_secs: u32 = 10;
     let count = (window.duration_secs() / sample_interval_secs) as usize;
-    let samples = sensor_gen.generate_history(count, sample_interval_secs, now_ts);
-
-    page.load_historical_raw_samples(&samples, now_ts);
-    PageWrapper::TrendPage(Box::new(page))
+    sensor_gen.generate_history(count, sample_interval_secs, now_ts)
 }
 
 // ---------------------------------------------------------------------------
@@ -288,45 +626,195 @@ fn keycode_to_page(keycode: Keycode) -> Option<PageId> {
         Keycode::Num7 | Keycode::Kp7 => Some(PageId::WifiStatus),
         Keycode::Num8 | Keycode::Kp8 => Some(PageId::HomeGrid),
         Keycode::Num9 | Keycode::Kp9 => Some(PageId::Monitor),
+        Keycode::Num0 | Keycode::Kp0 => Some(PageId::TrendPressure),
+        _ => None,
+    }
+}
+
+/// Map a `--render` page name to its page.
+fn page_from_name(name: &str) -> Option<PageId> {
+    match name {
+        "home" => Some(PageId::Home),
+        "home-grid" => Some(PageId::HomeGrid),
+        "settings" => Some(PageId::Settings),
+        "monitor" => Some(PageId::Monitor),
+        "wifi" => Some(PageId::WifiStatus),
+        "splash" => Some(PageId::Splash),
+        "trend-temperature" => Some(PageId::TrendTemperature),
+        "trend-humidity" => Some(PageId::TrendHumidity),
+        "trend-co2" => Some(PageId::TrendCo2),
+        "trend-lux" => Some(PageId::TrendLux),
+        "trend-pressure" => Some(PageId::TrendPressure),
+        _ => None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Headless rendering
+// ---------------------------------------------------------------------------
+
+/// Draw one frame of `page_id` offscreen and save it as a PNG at `out`.
+///
+/// One mock sample is pushed first so live-value pages have something to
+/// show. The image is written at native resolution, unscaled.
+fn render_to_png(
+    page_id: PageId,
+    out: &Path,
+    mut sensor_gen: MockSensorGenerator,
+) -> Result<(), String> {
+    let mut display = SimulatorDisplay::<Rgb565>::new(LOGICAL_DISPLAY_SIZE);
+
+    let mut sensor_store = SensorDataStore::new();
+    let data = sensor_gen.next_sample(MOCK_SAMPLE_INTERVAL.as_secs_f64());
+    sensor_store.push(&data);
+
+    let settings = SimSettings::default();
+    let mut page = create_page(page_id, &settings, &mut sensor_gen, &sensor_store);
+    Page::on_event(&mut page, &PageEvent::SensorUpdate(data));
+    Page::update(&mut page);
+
+    let background = Theme::for_mode(settings.theme_mode).palette.background;
+    let _ = display.clear(background);
+    Page::draw_page(&mut page, &mut display).map_err(|e| format!("draw error: {:?}", e))?;
+
+    let output_settings = OutputSettingsBuilder::new().scale(1).build();
+    display
+        .to_rgb_output_image(&output_settings)
+        .save_png(out)
+        .map_err(|e| format!("failed to write {}: {}", out.display(), e))
+}
+
+/// Handle `--render <page> <out.png>`, returning the process exit code.
+fn run_render(args: &[String], sensor_gen: MockSensorGenerator) -> i32 {
+    let [page_name, out] = args else {
+        eprintln!("usage: baro-simulator {} <page> <out.png>", RENDER_FLAG);
+        return 2;
+    };
+    let Some(page_id) = page_from_name(page_name) else {
+        eprintln!("unknown page '{}'", page_name);
+        return 2;
+    };
+
+    match render_to_png(page_id, Path::new(out), sensor_gen) {
+        Ok(()) => {
+            info!("Rendered {:?} to {}", page_id, out);
+            0
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            1
+        }
+    }
+}
+
+/// Map an SDL keycode to the sensor whose fault it toggles.
+fn keycode_to_fault(keycode: Keycode) -> Option<SensorType> {
+    match keycode {
+        Keycode::T => Some(SensorType::Temperature),
+        Keycode::H => Some(SensorType::Humidity),
+        Keycode::C => Some(SensorType::Co2),
         _ => None,
     }
 }
 
+// ---------------------------------------------------------------------------
+// Command line
+// ---------------------------------------------------------------------------
+
+/// Remove `flag` and its value from `args`, parsing the value.
+fn take_flag<T>(args: &mut Vec<String>, flag: &str) -> Result<Option<T>, String>
+where
+    T: core::str::FromStr,
+    T::Err: core::fmt::Display,
+{
+    let Some(pos) = args.iter().position(|arg| arg == flag) else {
+        return Ok(None);
+    };
+    let value = args
+        .get(pos + 1)
+        .ok_or_else(|| format!("{} needs a value", flag))?
+        .parse::<T>()
+        .map_err(|e| format!("invalid {} value: {}", flag, e))?;
+    args.drain(pos..pos + 2);
+    Ok(Some(value))
+}
+
+/// Strip the mock data flags from `args` and build the mock generator.
+///
+/// Whatever remains (e.g. a `--render` request) is left in `args`.
+fn mock_generator_from_args(args: &mut Vec<String>) -> Result<MockSensorGenerator, String> {
+    let mut sensor_gen = MockSensorGenerator::new();
+
+    if let Some(seed) = take_flag::<u64>(args, SEED_FLAG)? {
+        sensor_gen = sensor_gen.with_seed(seed);
+    }
+    if let Some(epoch) = take_flag::<u64>(args, EPOCH_FLAG)? {
+        sensor_gen = sensor_gen.with_fixed_epoch(epoch);
+    }
+
+    let mut profile = match take_flag::<String>(args, PROFILE_FLAG)? {
+        Some(name) => {
+            MockProfile::by_name(&name).ok_or_else(|| format!("unknown profile '{}'", name))?
+        }
+        None => MockProfile::NORMAL,
+    };
+    if let Some(scale) = take_flag::<f64>(args, DRIFT_FLAG)? {
+        profile = profile.with_drift_scale(scale);
+    }
+    if let Some(scale) = take_flag::<f64>(args, NOISE_FLAG)? {
+        profile = profile.with_noise_scale(scale);
+    }
+
+    Ok(sensor_gen.with_profile(profile))
+}
+
 // ---------------------------------------------------------------------------
 // Entry point
 // ---------------------------------------------------------------------------
 
 fn main() {
     env_logger::init();
+
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    // Sensor data generator (also seeds trend-page history)
+    let mut sensor_gen = match mock_generator_from_args(&mut args) {
+        Ok(sensor_gen) => sensor_gen,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+
+    if args.first().map(String::as_str) == Some(RENDER_FLAG) {
+        std::process::exit(run_render(&args[1..], sensor_gen));
+    }
+
     info!("Starting baro-rs simulator");
     info!(
         "Display: {}×{} (scale {}×)",
-        DISPLAY_WIDTH_PX, DISPLAY_HEIGHT_PX, WINDOW_SCALE
+        LOGICAL_DISPLAY_SIZE.width, LOGICAL_DISPLAY_SIZE.height, WINDOW_SCALE
     );
     info!(
-        "Keys: 1=Home  2=TempTrend  3=HumTrend  4=CO2Trend  5=LuxTrend  6=Settings  7=WiFi  8=HomeGrid  9=Monitor  Q=Quit"
+        "Keys: 1=Home  2=TempTrend  3=HumTrend  4=CO2Trend  5=LuxTrend  6=Settings  7=WiFi  8=HomeGrid  9=Monitor  0=PressTrend  T/H/C=Toggle fault  P=Profile  Q=Quit"
     );
 
     // SDL2 display and window
-    let mut display = SimulatorDisplay::<Rgb565>::new(Size::new(
-        DISPLAY_WIDTH_PX as u32,
-        DISPLAY_HEIGHT_PX as u32,
-    ));
+    let mut display = SimulatorDisplay::<Rgb565>::new(LOGICAL_DISPLAY_SIZE);
 
     let output_settings = OutputSettingsBuilder::new().scale(WINDOW_SCALE).build();
     let mut window = Window::new("Baro Simulator", &output_settings);
 
-    // Sensor data generator
-    let mut sensor_gen = MockSensorGenerator::new();
+    // Live sample feed: mock data or a device streaming over UDP
+    let mut sample_source = SampleSource::from_env();
 
     // Centralized sensor data store — survives page navigation
     let mut sensor_store = SensorDataStore::new();
 
-    // Start on the home page
-    let mut current_page = create_page(PageId::Home, &mut sensor_gen, &sensor_store);
+    // Settings changed on the settings pages — survive page navigation
+    let mut settings = SimSettings::default();
 
-    // Timing
-    let mut last_sample = Instant::now();
+    // Start on the home page
+    let mut current_page = create_page(PageId::Home, &settings, &mut sensor_gen, &sensor_store);
 
     /// Minimum interval between successive mouse presses (touch debounce).
     const TOUCH_DEBOUNCE: Duration = Duration::from_millis(250);
@@ -358,9 +846,25 @@ fn main() {
 
                     if let Some(target) = keycode_to_page(keycode) {
                         info!("Navigating to {:?}", target);
-                        current_page = create_page(target, &mut sensor_gen, &sensor_store);
+                        current_page =
+                            create_page(target, &settings, &mut sensor_gen, &sensor_store);
                         needs_redraw = true;
                     }
+
+                    if let Some(sensor) = keycode_to_fault(keycode) {
+                        if sensor_gen.toggle_fault(sensor) {
+                            warn!("Injected fault: {} now reads as missing", sensor.name());
+                        } else {
+                            info!("Cleared fault: {} reads normally again", sensor.name());
+                        }
+                    }
+
+                    if keycode == Keycode::P {
+                        // Drift/noise overrides reset with the preset
+                        let profile = sensor_gen.profile().next();
+                        info!("Mock data profile → {}", profile.name);
+                        sensor_gen.set_profile(profile);
+                    }
                 }
 
                 SimulatorEvent::MouseButtonDown { point, .. } => {
@@ -379,7 +883,8 @@ fn main() {
                         match action {
                             Action::NavigateToPage(page_id) => {
                                 info!("Touch → navigate to {:?}", page_id);
-                                current_page = create_page(page_id, &mut sensor_gen, &sensor_store);
+                                current_page =
+                                    create_page(page_id, &settings, &mut sensor_gen, &sensor_store);
                                 needs_redraw = true;
                             }
                             Action::GoBack => {
@@ -390,25 +895,70 @@ fn main() {
                                     _ => PageId::Home,
                                 };
                                 info!("Touch → go back to {:?}", target);
-                                current_page = create_page(target, &mut sensor_gen, &sensor_store);
+                                current_page =
+                                    create_page(target, &settings, &mut sensor_gen, &sensor_store);
                                 needs_redraw = true;
                             }
                             Action::UpdateHomePageMode(mode) => {
                                 info!("Touch → update home page mode to {:?}", mode);
-                                // SAFETY: single-threaded simulator
-                                unsafe {
-                                    SIM_HOME_PAGE_MODE = mode;
-                                }
-                                current_page =
-                                    create_page(PageId::Home, &mut sensor_gen, &sensor_store);
+                                settings.home_page_mode = mode;
+                                current_page = create_page(
+                                    PageId::Home,
+                                    &settings,
+                                    &mut sensor_gen,
+                                    &sensor_store,
+                                );
                                 needs_redraw = true;
                             }
                             Action::UpdateTemperatureUnit(unit) => {
                                 info!("Touch → update temperature unit to {:?}", unit);
-                                // SAFETY: single-threaded simulator
-                                unsafe {
-                                    SIM_TEMP_UNIT = unit;
-                                }
+                                settings.temperature_unit = unit;
+                            }
+                            Action::SetSampleInterval(secs) => {
+                                // The mock generator keeps its fast cadence so
+                                // the UI stays lively; only the setting is stored.
+                                info!("Touch → update sample interval to {}s", secs);
+                                settings.sample_interval_secs = secs;
+                                needs_redraw = true;
+                            }
+                            Action::SetCalibrationOffset { sensor, offset } => {
+                                // Mock readings are not calibrated; only the setting is stored.
+                                info!(
+                                    "Touch → update {} calibration offset to {}",
+                                    sensor.name(),
+                                    offset
+                                );
+                                settings.calibration.offset[sensor.index()] = offset;
+                                needs_redraw = true;
+                            }
+                            Action::SetThemeMode(mode) => {
+                                info!("Touch → update theme to {:?}", mode);
+                                settings.theme_mode = mode;
+                                // Pages copy the palette at construction, so rebuild this one
+                                let current_id = Page::id(&current_page);
+                                current_page = create_page(
+                                    current_id,
+                                    &settings,
+                                    &mut sensor_gen,
+                                    &sensor_store,
+                                );
+                                needs_redraw = true;
+                            }
+                            Action::PreviewBrightness(_) => {
+                                // The SDL window has no backlight; the slider just moves.
+                                needs_redraw = true;
+                            }
+                            Action::SetTouchThreshold(threshold) => {
+                                // The mouse has no touch controller; only the setting is stored.
+                                info!("Touch → update touch threshold to {}", threshold);
+                                settings.touch_threshold = threshold;
+                                needs_redraw = true;
+                            }
+                            Action::SetCo2AutoCalibration(enabled) => {
+                                // Mock CO2 has no baseline to drift; only the setting is stored.
+                                info!("Touch → CO2 self-calibration {}", enabled);
+                                settings.co2_auto_calibration = enabled;
+                                needs_redraw = true;
                             }
                             other => {
                                 info!("Touch → action {:?}", other);
@@ -417,14 +967,28 @@ fn main() {
                     }
                 }
 
+                SimulatorEvent::MouseButtonUp { point, .. } => {
+                    let touch = TouchEvent::Release(TouchPoint::new(
+                        point.x.max(0) as u16,
+                        point.y.max(0) as u16,
+                    ));
+                    // The brightness slider commits its value on release
+                    if let Some(Action::SetBrightness(percent)) =
+                        Page::handle_touch(&mut current_page, touch)
+                    {
+                        // The SDL window has no backlight; only the setting is stored.
+                        info!("Touch → update brightness to {}%", percent);
+                        settings.brightness_percent = percent;
+                        needs_redraw = true;
+                    }
+                }
+
                 _ => {}
             }
         }
 
-        // --- Mock sensor data ---------------------------------------------
-        if last_sample.elapsed() >= MOCK_SAMPLE_INTERVAL {
-            let data = sensor_gen.next_sample(MOCK_SAMPLE_INTERVAL.as_secs_f64());
-
+        // --- Sensor data ---------------------------------------------------
+        for data in sample_source.poll(&mut sensor_gen) {
             // Persist into the centralized store
             sensor_store.push(&data);
 
@@ -433,7 +997,13 @@ fn main() {
             if Page::on_event(&mut current_page, &event) {
                 needs_redraw = true;
             }
-            last_sample = Instant::now();
+        }
+
+        // --- Clock ----------------------------------------------------------
+        // Pages only redraw when the displayed minute changes
+        let time_event = PageEvent::SystemEvent(SystemEvent::Time(sensor_gen.now_secs() as u32));
+        if Page::on_event(&mut current_page, &time_event) {
+            needs_redraw = true;
         }
 
         // --- Page update tick ---------------------------------------------
@@ -441,7 +1011,8 @@ fn main() {
 
         // --- Render -------------------------------------------------------
         if needs_redraw || Page::is_dirty(&current_page) {
-            let _ = display.clear(Rgb565::BLACK);
+            let background = Theme::for_mode(settings.theme_mode).palette.background;
+            let _ = display.clear(background);
             if let Err(e) = Page::draw_page(&mut current_page, &mut display) {
                 log::error!("Draw error: {:?}", e);
             }
@@ -460,3 +1031,228 @@ fn main() {
 
     info!("Simulator exiting");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use baro_core::storage::MAX_SENSORS;
+
+    /// Wait briefly for loopback datagrams to arrive on the non-blocking receiver.
+    fn poll_until_received(receiver: &mut UdpSampleReceiver, count: usize) -> Vec<SensorData> {
+        let mut samples = Vec::new();
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while samples.len() < count && Instant::now() < deadline {
+            samples.extend(receiver.poll());
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        samples
+    }
+
+    #[test]
+    fn streamed_sample_round_trips_over_udp() {
+        let mut receiver = UdpSampleReceiver::bind("127.0.0.1:0").unwrap();
+        let target = receiver.socket.local_addr().unwrap();
+
+        let mut values = [MISSING_SENSOR_VALUE; MAX_SENSORS];
+        values[baro_core::sensors::TEMPERATURE] = 22_500;
+        values[baro_core::sensors::CO2] = 415_000;
+        let sample = RawSample::new(1_700_000_000, &values);
+
+        let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+        // A short datagram is dropped without disturbing the next one
+        sender.send_to(&[0; 8], target).unwrap();
+        sender.send_to(&sample.to_bytes(), target).unwrap();
+
+        let received = poll_until_received(&mut receiver, 1);
+        assert_eq!(received.len(), 1);
+        let data = received[0];
+        assert_eq!(data.timestamp, 1_700_000_000);
+        assert_eq!(data.temperature, Some(22.5));
+        assert_eq!(data.co2, Some(415.0));
+        assert_eq!(data.humidity, None);
+        assert_eq!(data.lux, None);
+    }
+
+    #[test]
+    fn simulator_screen_matches_the_core_display_dimensions() {
+        use baro_core::ui::{DISPLAY_HEIGHT_PX, DISPLAY_ROTATION, DISPLAY_WIDTH_PX};
+
+        let panel = Size::new(DISPLAY_WIDTH_PX.into(), DISPLAY_HEIGHT_PX.into());
+        assert_eq!(screen_bounds().size, DISPLAY_ROTATION.logical_size(panel));
+        assert_eq!(screen_bounds().top_left, Point::zero());
+
+        let display = SimulatorDisplay::<Rgb565>::new(LOGICAL_DISPLAY_SIZE);
+        assert_eq!(display.size(), screen_bounds().size);
+    }
+
+    #[test]
+    fn rendering_the_home_page_writes_a_screen_sized_png() {
+        let out = std::env::temp_dir().join(format!("baro-sim-home-{}.png", std::process::id()));
+        let sensor_gen = MockSensorGenerator::new().with_fixed_epoch(1_700_000_000);
+
+        render_to_png(PageId::Home, &out, sensor_gen).unwrap();
+        let png = std::fs::read(&out).unwrap();
+        let _ = std::fs::remove_file(&out);
+
+        // Signature, then the IHDR chunk holding big-endian width and height
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        let width = u32::from_be_bytes(png[16..20].try_into().unwrap());
+        let height = u32::from_be_bytes(png[20..24].try_into().unwrap());
+        assert_eq!(Size::new(width, height), LOGICAL_DISPLAY_SIZE);
+        // More than headers: the image data made it out
+        assert!(png.len() > 100, "{} bytes", png.len());
+    }
+
+    /// Sample fields as a comparable tuple
+    type SampleFields = (Option<f32>, Option<f32>, Option<f32>, Option<f32>, u64);
+
+    /// `count` one-second samples from a generator with `seed` on a fixed clock
+    fn seeded_samples(seed: u64, count: usize) -> Vec<SampleFields> {
+        let mut sensor_gen = MockSensorGenerator::new()
+            .with_seed(seed)
+            .with_fixed_epoch(1_700_000_000);
+        (0..count)
+            .map(|_| {
+                let data = sensor_gen.next_sample(1.0);
+                (
+                    data.temperature,
+                    data.humidity,
+                    data.co2,
+                    data.lux,
+                    data.timestamp,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn same_seed_gives_identical_samples() {
+        let first = seeded_samples(42, 50);
+        let second = seeded_samples(42, 50);
+
+        assert_eq!(first, second);
+        assert_eq!(first[0].4, 1_700_000_001);
+        assert_eq!(first[49].4, 1_700_000_050);
+    }
+
+    #[test]
+    fn different_seeds_shift_the_signals() {
+        assert_ne!(seeded_samples(1, 10), seeded_samples(2, 10));
+    }
+
+    #[test]
+    fn same_seed_gives_identical_history() {
+        let history = |seed| {
+            MockSensorGenerator::new()
+                .with_seed(seed)
+                .generate_history(30, 10, 1_700_000_000)
+        };
+
+        let first = history(7);
+        let second = history(7);
+        assert_eq!(first.len(), 30);
+        assert!(
+            first
+                .iter()
+                .zip(&second)
+                .all(|(a, b)| a.to_bytes() == b.to_bytes())
+        );
+    }
+
+    #[test]
+    fn injected_fault_blanks_only_the_toggled_channel() {
+        let mut sensor_gen = MockSensorGenerator::new().with_fixed_epoch(1_700_000_000);
+        assert!(sensor_gen.toggle_fault(SensorType::Co2));
+
+        let data = sensor_gen.next_sample(1.0);
+        assert_eq!(data.co2, None);
+        assert!(data.temperature.is_some());
+        assert!(data.humidity.is_some());
+        assert!(data.lux.is_some());
+
+        let history = sensor_gen.generate_history(5, 10, 1_700_000_100);
+        for sample in &history {
+            for (index, &value) in sample.values.iter().enumerate() {
+                let missing = value == MISSING_SENSOR_VALUE;
+                // Unused indices are zero, never the sentinel
+                assert_eq!(missing, index == baro_core::sensors::CO2, "index {index}");
+            }
+        }
+    }
+
+    #[test]
+    fn toggling_a_fault_again_restores_the_channel() {
+        let mut sensor_gen = MockSensorGenerator::new().with_fixed_epoch(1_700_000_000);
+        assert!(sensor_gen.toggle_fault(SensorType::Temperature));
+        assert!(!sensor_gen.toggle_fault(SensorType::Temperature));
+
+        assert!(!sensor_gen.is_faulted(SensorType::Temperature));
+        assert!(sensor_gen.next_sample(1.0).temperature.is_some());
+    }
+
+    #[test]
+    fn fault_keys_map_to_their_sensors() {
+        assert_eq!(keycode_to_fault(Keycode::T), Some(SensorType::Temperature));
+        assert_eq!(keycode_to_fault(Keycode::H), Some(SensorType::Humidity));
+        assert_eq!(keycode_to_fault(Keycode::C), Some(SensorType::Co2));
+        assert_eq!(keycode_to_fault(Keycode::P), None);
+    }
+
+    #[test]
+    fn high_co2_profile_stays_past_the_poor_quality_threshold() {
+        use baro_core::metrics::QualityLevel;
+
+        let mut sensor_gen = MockSensorGenerator::new()
+            .with_fixed_epoch(1_700_000_000)
+            .with_profile(MockProfile::by_name("high-co2").unwrap());
+
+        // Two full slow-drift cycles, one sample a second
+        for _ in 0..3_600 {
+            let co2 = sensor_gen.next_sample(1.0).co2.unwrap();
+            assert!(co2 > 1_500.0, "{co2} ppm");
+            assert_eq!(
+                QualityLevel::assess(SensorType::Co2, co2),
+                QualityLevel::Bad
+            );
+        }
+
+        let history = sensor_gen.generate_history(360, 10, 1_700_003_600);
+        assert!(
+            history
+                .iter()
+                .all(|sample| sample.values[baro_core::sensors::CO2] > 1_500_000)
+        );
+    }
+
+    #[test]
+    fn drift_and_noise_scales_widen_the_swing() {
+        let swing = |profile: MockProfile| {
+            let mut sensor_gen = MockSensorGenerator::new()
+                .with_fixed_epoch(1_700_000_000)
+                .with_profile(profile);
+            let values: Vec<f32> = (0..3_600)
+                .map(|_| sensor_gen.next_sample(1.0).temperature.unwrap())
+                .collect();
+            let min = values.iter().copied().fold(f32::INFINITY, f32::min);
+            let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+            max - min
+        };
+
+        let normal = swing(MockProfile::NORMAL);
+        let scaled = swing(
+            MockProfile::NORMAL
+                .with_drift_scale(2.0)
+                .with_noise_scale(2.0),
+        );
+        assert!(scaled > normal * 1.5, "{normal} vs {scaled}");
+    }
+
+    #[test]
+    fn profiles_cycle_in_order_and_wrap() {
+        assert_eq!(MockProfile::NORMAL.next().name, "high-co2");
+        assert_eq!(MockProfile::HIGH_CO2.next().name, "volatile");
+        assert_eq!(MockProfile::VOLATILE.next().name, "normal");
+        assert!(MockProfile::by_name("stuffy").is_none());
+    }
+}